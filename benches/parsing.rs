@@ -0,0 +1,240 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use sputnik::{css, html};
+use test_support::Profile;
+
+const SMALL_SIZE: usize = 1024;
+const MEDIUM_SIZE: usize = 100 * 1024;
+const LARGE_SIZE: usize = 1024 * 1024;
+
+const HTML_FRAGMENTS: &[&str] = &[
+    "<div class=\"card\" id=\"item-1\">\n",
+    "<h2 class=\"card-title\">Lorem &amp; ipsum</h2>\n",
+    "<p>Dolor sit amet, <a href=\"/path?a=1&b=2\" title=\"&copy; Sputnik\">consectetur</a> adipiscing elit.</p>\n",
+    "<p><b>Sed</b> do <i>eiusmod</i> tempor <span data-x='y'>incididunt</span> &lt;ut&gt; labore.</p>\n",
+    "<ul><li>First</li><li>Second &mdash; item</li><li>Third</li></ul>\n",
+    "<img src=\"/images/photo.png\" alt=\"A photo\"><br>\n",
+    "</div>\n",
+];
+
+const CSS_FRAGMENTS: &[&str] = &[
+    ":root { --primary: #0d6efd; --spacing: 1rem; }\n",
+    "body { margin: 0; font-family: system-ui, -apple-system, \"Segoe UI\", Roboto, sans-serif; line-height: 1.5; }\n",
+    ".btn { display: inline-block; padding: .375rem .75rem; border: 1px solid transparent; border-radius: .25rem; }\n",
+    ".btn-primary:hover, .btn-primary:focus { color: #fff; background-color: var(--primary); }\n",
+    "@media (min-width: 768px) { .container { max-width: 720px; } .col-md-6 { flex: 0 0 auto; width: 50%; } }\n",
+    "a:not([href]):not([class]) { color: inherit; text-decoration: none; }\n",
+    "/* Utilities */\n.mt-3 { margin-top: calc(var(--spacing) * 1) !important; }\n",
+    "input[type=\"checkbox\"] + label::before { content: \"\u{2713}\"; width: 12.5%; }\n",
+];
+
+fn repeat_fragments(fragments: &[&str], size: usize) -> String {
+    let mut output = String::with_capacity(size);
+    for fragment in fragments.iter().cycle() {
+        if output.len() >= size {
+            break;
+        }
+        output.push_str(fragment);
+    }
+    output
+}
+
+fn html_document(size: usize) -> String {
+    let mut document =
+        String::from("<!DOCTYPE html><html><head><title>Benchmark</title></head><body>\n");
+    document.push_str(&repeat_fragments(HTML_FRAGMENTS, size));
+    document.push_str("</body></html>\n");
+    document
+}
+
+fn html_documents() -> Vec<(&'static str, String)> {
+    vec![
+        ("small", html_document(SMALL_SIZE)),
+        ("medium", html_document(MEDIUM_SIZE)),
+        ("large", html_document(LARGE_SIZE)),
+    ]
+}
+
+fn count_html_tokens(input: &str) -> usize {
+    let mut tokenizer = html::tokenizer::Tokenizer::new(input);
+    let mut count = 0;
+    while tokenizer.next_token().is_some() {
+        count += 1;
+    }
+    count
+}
+
+fn html_tokenizer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("html_tokenizer");
+    for (name, document) in html_documents() {
+        // The token count must not change between runs, otherwise we are not measuring the same work.
+        let expected_token_count = count_html_tokens(&document);
+
+        group.throughput(Throughput::Bytes(document.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &document,
+            |b, document| {
+                b.iter(|| {
+                    let token_count = count_html_tokens(black_box(document));
+                    assert_eq!(token_count, expected_token_count);
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The number of elements in the parsed document and the text of its body.
+fn parse_summary(input: &str) -> (usize, String) {
+    let arena = typed_arena::Arena::new();
+    let parser = html::parser::Parser::new(arena, input);
+    let document = parser.parse();
+
+    let elements = document
+        .descendants()
+        .filter(|node| node.is_element())
+        .count();
+    let body_text = document
+        .descendants()
+        .find(|node| node.is_element_with_tag("body"))
+        .and_then(|body| body.text_content())
+        .unwrap_or_default();
+    (elements, body_text)
+}
+
+fn html_parser(c: &mut Criterion) {
+    let mut group = c.benchmark_group("html_parser");
+    for (name, document) in html_documents() {
+        // Check that the parser builds the tree we expect, so we don't measure a parser that
+        // gives up early.
+        let (elements, body_text) = parse_summary(&document);
+        let cards = document.matches("<div class=\"card\"").count();
+        assert!(cards > 0);
+        assert!(elements >= cards * HTML_FRAGMENTS.len());
+        assert_eq!(body_text.matches("Lorem & ipsum").count(), cards);
+        assert!(!body_text.contains("&amp;"));
+
+        group.throughput(Throughput::Bytes(document.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &document,
+            |b, document| {
+                b.iter(|| {
+                    let arena = typed_arena::Arena::new();
+                    let parser = html::parser::Parser::new(arena, black_box(document));
+                    black_box(parser.parse());
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn html_tokenizer_profiles(c: &mut Criterion) {
+    // NOTE: The seed is fixed, so every run tokenizes the same documents.
+    const SEED: u64 = 0x5907_4e1c;
+
+    let mut group = c.benchmark_group("html_tokenizer_profiles");
+    for profile in Profile::ALL {
+        let document = test_support::generate_document(SEED, MEDIUM_SIZE, profile);
+        let expected_token_count = count_html_tokens(&document);
+
+        group.throughput(Throughput::Bytes(document.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(profile.name()),
+            &document,
+            |b, document| {
+                b.iter(|| {
+                    let token_count = count_html_tokens(black_box(document));
+                    assert_eq!(token_count, expected_token_count);
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn nested_html_document(depth: usize) -> String {
+    let mut document = String::from("<!DOCTYPE html><html><head></head><body>");
+    document.push_str(&"<div>".repeat(depth));
+    document.push_str("Deep");
+    document.push_str(&"</div>".repeat(depth));
+    document.push_str("</body></html>\n");
+    document
+}
+
+/// Deeply nested SVG, ended by an HTML start tag that breaks out of foreign content and has to
+/// pop every foreign element.
+fn nested_svg_document(depth: usize) -> String {
+    let mut document = String::from("<!DOCTYPE html><html><head></head><body><svg>");
+    document.push_str(&"<g>".repeat(depth));
+    document.push_str("<p>Deep</p></body></html>\n");
+    document
+}
+
+fn html_parser_nested(c: &mut Criterion) {
+    // Deep nesting makes every walk over the stack of open elements expensive.
+    const DEPTH: usize = 1000;
+    let documents = [
+        ("1000-divs", "div", nested_html_document(DEPTH)),
+        ("1000-svg-groups", "g", nested_svg_document(DEPTH)),
+    ];
+    // NOTE: The default maximum tree depth would ignore most of the nested start tags.
+    let options = html::parser::ParserOptions {
+        max_tree_depth: 2 * DEPTH,
+        ..Default::default()
+    };
+    let parse = |document: &str| {
+        let arena = typed_arena::Arena::new();
+        let parser = html::parser::Parser::with_options(arena, document, options);
+        black_box(parser.parse());
+    };
+
+    let mut group = c.benchmark_group("html_parser_nested");
+    for (name, nested_tag, document) in documents {
+        let parser =
+            html::parser::Parser::with_options(typed_arena::Arena::new(), &document, options);
+        let nested_elements = parser
+            .parse()
+            .descendants()
+            .filter(|node| node.is_element_with_tag(nested_tag))
+            .count();
+        assert_eq!(nested_elements, DEPTH);
+
+        group.throughput(Throughput::Bytes(document.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &document,
+            |b, document| b.iter(|| parse(black_box(document))),
+        );
+    }
+    group.finish();
+}
+
+fn css_tokenizer(c: &mut Criterion) {
+    let stylesheet = repeat_fragments(CSS_FRAGMENTS, MEDIUM_SIZE);
+
+    let mut group = c.benchmark_group("css_tokenizer");
+    group.throughput(Throughput::Bytes(stylesheet.len() as u64));
+    group.bench_with_input(
+        BenchmarkId::from_parameter("framework"),
+        &stylesheet,
+        |b, stylesheet| {
+            b.iter(|| {
+                let mut tokenizer = css::tokenizer::Tokenizer::new(black_box(stylesheet));
+                black_box(tokenizer.tokenize());
+            })
+        },
+    );
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    html_tokenizer,
+    html_tokenizer_profiles,
+    html_parser,
+    html_parser_nested,
+    css_tokenizer
+);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+/// The named character references table from the HTML standard.
+///
+/// https://html.spec.whatwg.org/entities.json
+const ENTITIES_PATH: &str = "src/html/tokenizer/entities.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={ENTITIES_PATH}");
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let table = generate_named_character_references(ENTITIES_PATH);
+    std::fs::write(
+        Path::new(&out_dir).join("named_character_references.rs"),
+        table,
+    )
+    .unwrap();
+}
+
+/// Generates the named character references table that the tokenizer looks identifiers up in,
+/// from the `entities.json` file at `path`.
+///
+/// The entries are sorted by their identifier, without the leading U+0026 AMPERSAND (&), so the
+/// table can be binary searched.
+fn generate_named_character_references(path: &str) -> String {
+    let json = std::fs::read_to_string(path).unwrap();
+    let entities: BTreeMap<String, Entity> = serde_json::from_str::<serde_json::Value>(&json)
+        .unwrap()
+        .as_object()
+        .expect("entities.json should be an object")
+        .iter()
+        .map(|(name, entity)| (name.clone(), Entity::from_json(name, entity)))
+        .collect();
+
+    let mut table = String::new();
+    writeln!(
+        table,
+        "pub(crate) static NAMED_CHARACTER_REFERENCES: [(&str, NamedCharacterReference); {}] = [",
+        entities.len()
+    )
+    .unwrap();
+    for (name, entity) in &entities {
+        let identifier = name
+            .strip_prefix('&')
+            .unwrap_or_else(|| panic!("entity {name:?} should start with '&'"));
+        let characters: String = entity
+            .characters
+            .chars()
+            .map(|c| format!("\\u{{{:04X}}}", c as u32))
+            .collect();
+        writeln!(
+            table,
+            "    ({identifier:?}, NamedCharacterReference::new(\"{characters}\")),"
+        )
+        .unwrap();
+    }
+    writeln!(table, "];").unwrap();
+    table
+}
+
+struct Entity {
+    characters: String,
+}
+
+impl Entity {
+    fn from_json(name: &str, json: &serde_json::Value) -> Self {
+        let characters = json["characters"]
+            .as_str()
+            .unwrap_or_else(|| panic!("entity {name:?} should have characters"))
+            .to_string();
+
+        // NOTE: The code points are redundant with the characters, but checking that they agree
+        //       catches a mangled file.
+        let codepoints: Vec<u32> = json["codepoints"]
+            .as_array()
+            .unwrap_or_else(|| panic!("entity {name:?} should have code points"))
+            .iter()
+            .map(|codepoint| codepoint.as_u64().unwrap() as u32)
+            .collect();
+        assert_eq!(
+            characters.chars().map(|c| c as u32).collect::<Vec<_>>(),
+            codepoints,
+            "the characters of entity {name:?} don't match its code points"
+        );
+
+        Self { characters }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ArenaTree;
+use crate::arena_tree::ArenaTree;
 
 use super::node::TreeNode;
 
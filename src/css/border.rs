@@ -0,0 +1,152 @@
+use crate::css::color::CssColorValue;
+use crate::css::length::parse_line_width;
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+
+/// The style of a border line.
+///
+/// https://drafts.csswg.org/css-backgrounds-3/#typedef-line-style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineStyle {
+    #[default]
+    None,
+    Hidden,
+    Dotted,
+    Dashed,
+    Solid,
+    Double,
+    Groove,
+    Ridge,
+    Inset,
+    Outset,
+}
+
+impl LineStyle {
+    /// Parses a single <line-style> keyword.
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        let ComponentValue::PreservedToken(Token::Ident { value }) = value else {
+            return None;
+        };
+
+        match value.to_ascii_lowercase().as_str() {
+            "none" => Some(Self::None),
+            "hidden" => Some(Self::Hidden),
+            "dotted" => Some(Self::Dotted),
+            "dashed" => Some(Self::Dashed),
+            "solid" => Some(Self::Solid),
+            "double" => Some(Self::Double),
+            "groove" => Some(Self::Groove),
+            "ridge" => Some(Self::Ridge),
+            "inset" => Some(Self::Inset),
+            "outset" => Some(Self::Outset),
+            _ => None,
+        }
+    }
+
+    /// SPEC: If the border style is none or hidden, the border width computes to zero.
+    pub fn hides_border(&self) -> bool {
+        matches!(self, Self::None | Self::Hidden)
+    }
+}
+
+/// https://drafts.csswg.org/css-ui-4/#outline-style
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlineStyle {
+    /// https://drafts.csswg.org/css-ui-4/#valdef-outline-style-auto
+    Auto,
+    LineStyle(LineStyle),
+}
+
+impl Default for OutlineStyle {
+    fn default() -> Self {
+        Self::LineStyle(LineStyle::None)
+    }
+}
+
+impl OutlineStyle {
+    /// Parses a single `auto` or <outline-line-style> keyword.
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        if let ComponentValue::PreservedToken(Token::Ident { value }) = value {
+            if value.eq_ignore_ascii_case("auto") {
+                return Some(Self::Auto);
+            }
+        }
+
+        match LineStyle::parse_component_value(value)? {
+            // SPEC: <outline-line-style> accepts the same values as <line-style> with the same
+            //       meaning, except that hidden is not a legal outline style.
+            LineStyle::Hidden => None,
+            line_style => Some(Self::LineStyle(line_style)),
+        }
+    }
+}
+
+/// The width, style and color of one side of a border, as set by the `border` shorthands.
+///
+/// https://drafts.csswg.org/css-backgrounds-3/#border-shorthands
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderSide {
+    pub width: f64,
+    pub style: LineStyle,
+    pub color: CssColorValue,
+}
+
+impl Default for BorderSide {
+    fn default() -> Self {
+        Self {
+            width: 3.0,
+            style: LineStyle::None,
+            color: CssColorValue::CurrentColor,
+        }
+    }
+}
+
+impl BorderSide {
+    /// Parses the value of a `border` shorthand.
+    ///
+    /// <line-width> || <line-style> || <color>
+    ///
+    /// SPEC: Omitted values are set to their initial values.
+    pub fn parse(values: &[ComponentValue]) -> Option<Self> {
+        let mut width = None;
+        let mut style = None;
+        let mut color = None;
+
+        let values = values
+            .iter()
+            .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace));
+        for value in values {
+            // NOTE: Each of the components may appear at most once, in any order.
+            if width.is_none() {
+                if let Some(parsed) = parse_line_width(value) {
+                    width = Some(parsed);
+                    continue;
+                }
+            }
+            if style.is_none() {
+                if let Some(parsed) = LineStyle::parse_component_value(value) {
+                    style = Some(parsed);
+                    continue;
+                }
+            }
+            if color.is_none() {
+                if let Some(parsed) = CssColorValue::parse(std::slice::from_ref(value)) {
+                    color = Some(parsed);
+                    continue;
+                }
+            }
+            return None;
+        }
+
+        if width.is_none() && style.is_none() && color.is_none() {
+            return None;
+        }
+
+        let initial = Self::default();
+        Some(Self {
+            width: width.unwrap_or(initial.width),
+            style: style.unwrap_or(initial.style),
+            color: color.unwrap_or(initial.color),
+        })
+    }
+}
@@ -0,0 +1,533 @@
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+
+/// A color in the sRGB color space, with an alpha channel.
+///
+/// https://drafts.csswg.org/css-color-4/#color-type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a color from channels in the range 0.0 to 1.0, clamping them if they are out of range.
+    pub fn from_unit_channels(r: f64, g: f64, b: f64, a: f64) -> Self {
+        let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::rgba(channel(r), channel(g), channel(b), channel(a))
+    }
+
+    /// https://drafts.csswg.org/css-color-4/#hsl-to-rgb
+    ///
+    /// `hue` is in degrees, `saturation` and `lightness` are in the range 0.0 to 1.0.
+    pub fn from_hsla(hue: f64, saturation: f64, lightness: f64, alpha: f64) -> Self {
+        let hue = hue.rem_euclid(360.0);
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let f = |n: f64| {
+            let k = (n + hue / 30.0) % 12.0;
+            let a = saturation * lightness.min(1.0 - lightness);
+            lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+
+        Self::from_unit_channels(f(0.0), f(8.0), f(4.0), alpha)
+    }
+
+    /// https://drafts.csswg.org/css-color-4/#named-colors
+    pub fn from_named_color(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        NAMED_COLORS
+            .binary_search_by_key(&name.as_str(), |(name, _)| name)
+            .ok()
+            .map(|index| {
+                let [_, r, g, b] = NAMED_COLORS[index].1.to_be_bytes();
+                Self::rgb(r, g, b)
+            })
+    }
+
+    /// https://drafts.csswg.org/css-color-4/#hex-notation
+    ///
+    /// `digits` are the hexadecimal digits without the leading "#".
+    pub fn from_hex(digits: &str) -> Option<Self> {
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let digit = |index: usize| u8::from_str_radix(&digits[index..index + 1], 16).unwrap();
+        let pair = |index: usize| u8::from_str_radix(&digits[index..index + 2], 16).unwrap();
+
+        match digits.len() {
+            // SPEC: The three-digit RGB notation (#RGB) is converted into six-digit form (#RRGGBB)
+            //       by replicating digits, not by adding zeros.
+            3 => Some(Self::rgb(digit(0) * 17, digit(1) * 17, digit(2) * 17)),
+            4 => Some(Self::rgba(
+                digit(0) * 17,
+                digit(1) * 17,
+                digit(2) * 17,
+                digit(3) * 17,
+            )),
+            6 => Some(Self::rgb(pair(0), pair(2), pair(4))),
+            8 => Some(Self::rgba(pair(0), pair(2), pair(4), pair(6))),
+            _ => None,
+        }
+    }
+}
+
+/// A specified <color> value.
+///
+/// https://drafts.csswg.org/css-color-4/#typedef-color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssColorValue {
+    Absolute(Color),
+    /// https://drafts.csswg.org/css-color-4/#currentcolor-color
+    CurrentColor,
+    /// https://drafts.csswg.org/css-color-4/#transparent-color
+    Transparent,
+}
+
+impl CssColorValue {
+    /// Parses a <color> from the component values of a declaration.
+    ///
+    /// Returns `None` if the values are not exactly one valid <color>.
+    pub fn parse(values: &[ComponentValue]) -> Option<Self> {
+        match non_whitespace_values(values).as_slice() {
+            [ComponentValue::PreservedToken(Token::Ident { value })] => {
+                match value.to_ascii_lowercase().as_str() {
+                    "currentcolor" => Some(Self::CurrentColor),
+                    "transparent" => Some(Self::Transparent),
+                    name => Color::from_named_color(name).map(Self::Absolute),
+                }
+            }
+            [ComponentValue::PreservedToken(Token::Hash { value, .. })] => {
+                Color::from_hex(value).map(Self::Absolute)
+            }
+            [ComponentValue::Function(function)] => {
+                let arguments = non_whitespace_values(&function.value);
+                match function.name.to_ascii_lowercase().as_str() {
+                    "rgb" | "rgba" => parse_rgb_function(&arguments).map(Self::Absolute),
+                    "hsl" | "hsla" => parse_hsl_function(&arguments).map(Self::Absolute),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolves the value to the color it computes to.
+    ///
+    /// SPEC: The keyword currentcolor represents value of the color property on the same element.
+    ///       If currentcolor is the specified value of the color property,
+    ///       it is treated as if the specified value was inherit.
+    ///
+    /// `current_color` is the computed value of the color property on the element,
+    /// or the inherited one when resolving the color property itself.
+    pub fn to_computed(&self, current_color: Color) -> Color {
+        match self {
+            Self::Absolute(color) => *color,
+            Self::CurrentColor => current_color,
+            Self::Transparent => Color::TRANSPARENT,
+        }
+    }
+}
+
+fn non_whitespace_values(values: &[ComponentValue]) -> Vec<&ComponentValue> {
+    values
+        .iter()
+        .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+        .collect()
+}
+
+/// Splits the arguments of a color function into its channels and its alpha value.
+///
+/// Returns the channels, the alpha value (if any) and whether the legacy comma-separated syntax was used.
+#[allow(clippy::type_complexity)]
+fn split_color_function_arguments<'a>(
+    arguments: &[&'a ComponentValue],
+) -> Option<(Vec<&'a ComponentValue>, Option<&'a ComponentValue>, bool)> {
+    let is_comma =
+        |value: &&ComponentValue| **value == ComponentValue::PreservedToken(Token::Comma);
+    let is_slash = |value: &&ComponentValue| {
+        **value == ComponentValue::PreservedToken(Token::Delim { value: '/' })
+    };
+
+    if arguments.iter().any(is_comma) {
+        // SPEC: <legacy-rgb-syntax> = rgb( <percentage>#{3} , <alpha-value>? ) |
+        //                             rgb( <number>#{3} , <alpha-value>? )
+        let parts = arguments.split(is_comma).collect::<Vec<_>>();
+        if parts.iter().any(|part| part.len() != 1) {
+            return None;
+        }
+        let values = parts.iter().map(|part| part[0]).collect::<Vec<_>>();
+        return match values.as_slice() {
+            [a, b, c] => Some((vec![*a, *b, *c], None, true)),
+            [a, b, c, alpha] => Some((vec![*a, *b, *c], Some(*alpha), true)),
+            _ => None,
+        };
+    }
+
+    // SPEC: <modern-rgb-syntax> = rgb( [ <number> | <percentage> | none]{3} [ / [<alpha-value> | none] ]? )
+    match arguments {
+        [a, b, c] => Some((vec![*a, *b, *c], None, false)),
+        [a, b, c, slash, alpha] if is_slash(slash) => Some((vec![*a, *b, *c], Some(*alpha), false)),
+        _ => None,
+    }
+}
+
+fn is_none_keyword(value: &ComponentValue) -> bool {
+    matches!(value, ComponentValue::PreservedToken(Token::Ident { value }) if value.eq_ignore_ascii_case("none"))
+}
+
+/// <alpha-value> = <number> | <percentage>
+///
+/// Returns the alpha value in the range 0.0 to 1.0.
+fn parse_alpha_value(value: Option<&ComponentValue>, legacy: bool) -> Option<f64> {
+    match value {
+        None => Some(1.0),
+        Some(ComponentValue::PreservedToken(Token::Number { value, .. })) => {
+            Some(value.clamp(0.0, 1.0))
+        }
+        Some(ComponentValue::PreservedToken(Token::Percentage { value, .. })) => {
+            Some((value / 100.0).clamp(0.0, 1.0))
+        }
+        // SPEC: none is not allowed in the legacy syntax.
+        Some(value) if !legacy && is_none_keyword(value) => Some(0.0),
+        Some(_) => None,
+    }
+}
+
+/// https://drafts.csswg.org/css-color-4/#rgb-functions
+fn parse_rgb_function(arguments: &[&ComponentValue]) -> Option<Color> {
+    let (channels, alpha, legacy) = split_color_function_arguments(arguments)?;
+
+    // SPEC: The legacy syntax doesn't allow mixing numbers and percentages.
+    if legacy {
+        let all_numbers = channels.iter().all(|channel| {
+            matches!(
+                channel,
+                ComponentValue::PreservedToken(Token::Number { .. })
+            )
+        });
+        let all_percentages = channels.iter().all(|channel| {
+            matches!(
+                channel,
+                ComponentValue::PreservedToken(Token::Percentage { .. })
+            )
+        });
+        if !all_numbers && !all_percentages {
+            return None;
+        }
+    }
+
+    let channel = |value: &ComponentValue| match value {
+        // SPEC: The number values range from 0 to 255.
+        ComponentValue::PreservedToken(Token::Number { value, .. }) => Some(value / 255.0),
+        // SPEC: The percentage values range from 0% to 100%.
+        ComponentValue::PreservedToken(Token::Percentage { value, .. }) => Some(value / 100.0),
+        value if !legacy && is_none_keyword(value) => Some(0.0),
+        _ => None,
+    };
+
+    Some(Color::from_unit_channels(
+        channel(channels[0])?,
+        channel(channels[1])?,
+        channel(channels[2])?,
+        parse_alpha_value(alpha, legacy)?,
+    ))
+}
+
+/// https://drafts.csswg.org/css-color-4/#the-hsl-notation
+fn parse_hsl_function(arguments: &[&ComponentValue]) -> Option<Color> {
+    let (channels, alpha, legacy) = split_color_function_arguments(arguments)?;
+
+    // <hue> = <number> | <angle>
+    let hue = match channels[0] {
+        ComponentValue::PreservedToken(Token::Number { value, .. }) => *value,
+        ComponentValue::PreservedToken(Token::Dimension { value, unit, .. }) => {
+            match unit.to_ascii_lowercase().as_str() {
+                "deg" => *value,
+                "grad" => value * 360.0 / 400.0,
+                "rad" => value.to_degrees(),
+                "turn" => value * 360.0,
+                _ => return None,
+            }
+        }
+        value if !legacy && is_none_keyword(value) => 0.0,
+        _ => return None,
+    };
+
+    // SPEC: The legacy syntax only allows percentages for saturation and lightness,
+    //       the modern syntax also allows numbers, where 100 is equal to 100%.
+    let percentage = |value: &ComponentValue| match value {
+        ComponentValue::PreservedToken(Token::Percentage { value, .. }) => Some(value / 100.0),
+        ComponentValue::PreservedToken(Token::Number { value, .. }) if !legacy => {
+            Some(value / 100.0)
+        }
+        value if !legacy && is_none_keyword(value) => Some(0.0),
+        _ => None,
+    };
+
+    Some(Color::from_hsla(
+        hue,
+        percentage(channels[1])?,
+        percentage(channels[2])?,
+        parse_alpha_value(alpha, legacy)?,
+    ))
+}
+
+/// https://drafts.csswg.org/css-color-4/#named-colors
+///
+/// NOTE: This list is sorted by name, so we can binary search it.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::parser::Parser;
+
+    fn parse(css: &str) -> Option<CssColorValue> {
+        CssColorValue::parse(&Parser::parse_a_list_of_component_values(css))
+    }
+
+    fn absolute(css: &str) -> Color {
+        match parse(css) {
+            Some(CssColorValue::Absolute(color)) => color,
+            other => panic!("expected an absolute color for {css:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn hsl_to_rgb() {
+        assert_eq!(absolute("hsl(0, 100%, 50%)"), Color::rgb(255, 0, 0));
+        assert_eq!(absolute("hsl(120deg 100% 25%)"), Color::rgb(0, 128, 0));
+        assert_eq!(absolute("hsl(240 100% 50%)"), Color::rgb(0, 0, 255));
+        assert_eq!(absolute("hsl(60, 100%, 50%)"), Color::rgb(255, 255, 0));
+        assert_eq!(absolute("hsl(0, 0%, 75%)"), Color::rgb(191, 191, 191));
+        assert_eq!(absolute("hsl(-120, 100%, 50%)"), Color::rgb(0, 0, 255));
+        assert_eq!(
+            absolute("hsla(210, 50%, 40%, 0.5)"),
+            Color::rgba(51, 102, 153, 128)
+        );
+    }
+
+    #[test]
+    fn hsl_matches_named_colors() {
+        for (hsl, name) in [
+            ("hsl(300 100% 50%)", "fuchsia"),
+            ("hsl(180, 100%, 50%)", "cyan"),
+            ("hsl(0 0% 100%)", "white"),
+            ("hsl(123 45% 0%)", "black"),
+        ] {
+            assert_eq!(
+                absolute(hsl),
+                absolute(name),
+                "{hsl} should be the same as {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn rgb_syntaxes() {
+        assert_eq!(
+            absolute("rgb(100% 0% 0% / 50%)"),
+            Color::rgba(255, 0, 0, 128)
+        );
+        assert_eq!(absolute("rgb(255, 128, 0)"), Color::rgb(255, 128, 0));
+        assert_eq!(
+            absolute("rgba(0, 0, 255, 0.25)"),
+            Color::rgba(0, 0, 255, 64)
+        );
+        assert_eq!(absolute("rgb(0 0 255 / 0.25)"), Color::rgba(0, 0, 255, 64));
+        assert_eq!(absolute("#f80"), Color::rgb(255, 136, 0));
+        assert_eq!(absolute("#ff880080"), Color::rgba(255, 136, 0, 128));
+        assert_eq!(absolute("RebeccaPurple"), Color::rgb(102, 51, 153));
+    }
+
+    #[test]
+    fn invalid_colors() {
+        for css in [
+            "rgb(255, 0 0)",
+            "rgb(1 2)",
+            "#ff00f",
+            "notacolor",
+            "red blue",
+        ] {
+            assert_eq!(parse(css), None, "{css} should be invalid");
+        }
+    }
+
+    #[test]
+    fn keywords_compute_to_a_color() {
+        let current_color = Color::rgb(1, 2, 3);
+        assert_eq!(
+            parse("currentColor").unwrap().to_computed(current_color),
+            current_color
+        );
+        assert_eq!(
+            parse("transparent").unwrap().to_computed(current_color),
+            Color::TRANSPARENT
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+use crate::html::Namespace;
+
+/// https://drafts.csswg.org/css-display-3/#the-display-properties
+///
+/// FIXME: Only the keyword values that the rest of the pipeline cares about are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Display {
+    /// https://drafts.csswg.org/css-display-3/#valdef-display-none
+    None,
+    /// https://drafts.csswg.org/css-display-3/#valdef-display-block
+    Block,
+    /// https://drafts.csswg.org/css-display-3/#valdef-display-inline
+    #[default]
+    Inline,
+    /// https://drafts.csswg.org/css-display-3/#valdef-display-inline-block
+    InlineBlock,
+    /// https://drafts.csswg.org/css-display-3/#valdef-display-list-item
+    ListItem,
+}
+
+impl Display {
+    /// Parses the value of the display property.
+    ///
+    /// Returns `None` if the value is not one of the supported keywords.
+    pub fn parse(values: &[ComponentValue]) -> Option<Self> {
+        let values = values
+            .iter()
+            .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+            .collect::<Vec<_>>();
+
+        match values.as_slice() {
+            [ComponentValue::PreservedToken(Token::Ident { value })] => {
+                match value.to_ascii_lowercase().as_str() {
+                    "none" => Some(Self::None),
+                    "block" => Some(Self::Block),
+                    "inline" => Some(Self::Inline),
+                    "inline-block" => Some(Self::InlineBlock),
+                    "list-item" => Some(Self::ListItem),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// SPEC: The element and its descendants generate no boxes or text runs.
+    pub fn generates_box(&self) -> bool {
+        *self != Self::None
+    }
+
+    /// https://drafts.csswg.org/css-display-3/#block-level
+    pub fn is_block_level(&self) -> bool {
+        matches!(self, Self::Block | Self::ListItem)
+    }
+
+    /// https://drafts.csswg.org/css-display-3/#inline-level
+    pub fn is_inline_level(&self) -> bool {
+        matches!(self, Self::Inline | Self::InlineBlock)
+    }
+
+    /// https://drafts.csswg.org/css-display-3/#atomic-inline
+    pub fn is_atomic_inline(&self) -> bool {
+        *self == Self::InlineBlock
+    }
+
+    /// The display value of a box that is floated, or the root element.
+    ///
+    /// https://www.w3.org/TR/CSS2/visuren.html#dis-pos-flo
+    pub fn blockified(&self) -> Self {
+        match self {
+            Self::Inline | Self::InlineBlock => Self::Block,
+            display => *display,
+        }
+    }
+
+    /// SPEC: The list-item keyword causes the element to generate a ::marker pseudo-element box
+    ///       with the content specified by its list-style properties.
+    pub fn generates_marker(&self) -> bool {
+        *self == Self::ListItem
+    }
+}
+
+/// https://drafts.csswg.org/css-display-3/#visibility
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    #[default]
+    Visible,
+    Hidden,
+    Collapse,
+}
+
+impl Visibility {
+    /// Parses a single `visibility` keyword.
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        let ComponentValue::PreservedToken(Token::Ident { value }) = value else {
+            return None;
+        };
+
+        match value.to_ascii_lowercase().as_str() {
+            "visible" => Some(Self::Visible),
+            "hidden" => Some(Self::Hidden),
+            "collapse" => Some(Self::Collapse),
+            _ => None,
+        }
+    }
+}
+
+/// The display value the user agent stylesheet gives to an element with `local_name` in
+/// `namespace`.
+///
+/// NOTE: The user agent stylesheet only has display rules for elements in the HTML namespace, so
+///       all other elements keep the initial value.
+/// FIXME: This should come from a real user agent stylesheet once we have a cascade.
+///
+/// https://html.spec.whatwg.org/multipage/rendering.html#the-css-user-agent-style-sheet-and-presentational-hints
+pub fn user_agent_display(local_name: &str, namespace: Namespace) -> Display {
+    if namespace != Namespace::Html {
+        return Display::default();
+    }
+
+    match local_name {
+        // https://html.spec.whatwg.org/multipage/rendering.html#hidden-elements
+        "area" | "base" | "basefont" | "datalist" | "head" | "link" | "meta" | "noembed"
+        | "noframes" | "param" | "rp" | "script" | "style" | "template" | "title" => Display::None,
+
+        // https://html.spec.whatwg.org/multipage/rendering.html#lists
+        "li" => Display::ListItem,
+
+        // https://html.spec.whatwg.org/multipage/rendering.html#flow-content-3
+        // https://html.spec.whatwg.org/multipage/rendering.html#sections-and-headings
+        // https://html.spec.whatwg.org/multipage/rendering.html#lists
+        "html" | "address" | "blockquote" | "center" | "dialog" | "div" | "figure"
+        | "figcaption" | "footer" | "form" | "header" | "hr" | "legend" | "listing" | "main"
+        | "p" | "plaintext" | "pre" | "search" | "xmp" | "article" | "aside" | "h1" | "h2"
+        | "h3" | "h4" | "h5" | "h6" | "hgroup" | "nav" | "section" | "dir" | "dd" | "dl" | "dt"
+        | "menu" | "ol" | "ul" | "body" | "details" | "summary" | "fieldset" | "optgroup"
+        | "option" => Display::Block,
+
+        // FIXME: Tables and ruby have their own display types that we don't support yet.
+        _ => Display::Inline,
+    }
+}
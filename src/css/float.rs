@@ -0,0 +1,64 @@
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+
+/// https://www.w3.org/TR/CSS2/visuren.html#float-position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Float {
+    Left,
+    Right,
+    #[default]
+    None,
+}
+
+impl Float {
+    /// Parses a single `float` keyword.
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        let ComponentValue::PreservedToken(Token::Ident { value }) = value else {
+            return None;
+        };
+
+        match value.to_ascii_lowercase().as_str() {
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// https://www.w3.org/TR/CSS2/visuren.html#flow-control
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Clear {
+    #[default]
+    None,
+    Left,
+    Right,
+    Both,
+}
+
+impl Clear {
+    /// Parses a single `clear` keyword.
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        let ComponentValue::PreservedToken(Token::Ident { value }) = value else {
+            return None;
+        };
+
+        match value.to_ascii_lowercase().as_str() {
+            "none" => Some(Self::None),
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+
+    /// Whether boxes with this value have to be placed below earlier floats on `side`.
+    pub fn clears(&self, side: Float) -> bool {
+        match self {
+            Self::None => false,
+            Self::Left => side == Float::Left,
+            Self::Right => side == Float::Right,
+            Self::Both => side != Float::None,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::css::font_face::{parse_family_name, split_on_commas};
+use crate::css::length::LengthPercentage;
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+
+/// The font size of the `medium` keyword in CSS pixels, which is also the initial font size.
+///
+/// https://drafts.csswg.org/css-fonts-4/#valdef-font-size-medium
+pub const MEDIUM_FONT_SIZE: f64 = 16.0;
+
+/// Parses a value of the `font-size` property to CSS pixels, where relative sizes are relative
+/// to `parent_font_size`.
+///
+/// https://drafts.csswg.org/css-fonts-4/#font-size-prop
+pub fn parse_font_size(value: &ComponentValue, parent_font_size: f64) -> Option<f64> {
+    if let ComponentValue::PreservedToken(Token::Ident { value }) = value {
+        // SPEC: <absolute-size>
+        //       An <absolute-size> keyword refers to an entry in a table of font sizes computed
+        //       and kept by the user agent.
+        // NOTE: These are the scaling factors from the table in the spec.
+        let scale = match value.to_ascii_lowercase().as_str() {
+            "xx-small" => 3.0 / 5.0,
+            "x-small" => 3.0 / 4.0,
+            "small" => 8.0 / 9.0,
+            "medium" => 1.0,
+            "large" => 6.0 / 5.0,
+            "x-large" => 3.0 / 2.0,
+            "xx-large" => 2.0,
+            "xxx-large" => 3.0,
+            // SPEC: <relative-size>
+            //       A <relative-size> keyword is interpreted relative to the computed font-size
+            //       of the parent element and possibly the table of font sizes.
+            // NOTE: We don't snap to the table, and use a ratio of 1.2 like other user agents.
+            "larger" => return Some(parent_font_size * 1.2),
+            "smaller" => return Some(parent_font_size / 1.2),
+            _ => return None,
+        };
+        return Some(MEDIUM_FONT_SIZE * scale);
+    }
+
+    // SPEC: <length-percentage [0,∞]>
+    //       [...] A percentage value specifies an absolute font size relative to the parent
+    //       element's computed font-size.
+    Some(LengthPercentage::parse_component_value(value, false)?.resolve(parent_font_size))
+}
+
+/// Parses a value of the `font-family` property into the list of family names.
+///
+/// [ <family-name> | <generic-family> ]#
+///
+/// https://drafts.csswg.org/css-fonts-4/#font-family-prop
+pub fn parse_font_family(values: &[ComponentValue]) -> Option<Vec<String>> {
+    let values = values
+        .iter()
+        .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+        .collect::<Vec<_>>();
+
+    // NOTE: Generic families are keywords, so they parse like a single <custom-ident>.
+    split_on_commas(&values)
+        .iter()
+        .map(|family| parse_family_name(family))
+        .collect()
+}
+
+/// https://drafts.csswg.org/css-inline-3/#line-height-property
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LineHeight {
+    #[default]
+    Normal,
+    /// A multiple of the font size of the element. Descendants inherit the number itself.
+    Number(f64),
+    /// A length in CSS pixels.
+    Length(f64),
+    /// A percentage of the font size of the element.
+    ///
+    /// NOTE: This only appears while cascading. It computes to a length once the font size of
+    ///       the element is known.
+    Percentage(f64),
+}
+
+impl LineHeight {
+    /// Parses a single `line-height` value.
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        match value {
+            ComponentValue::PreservedToken(Token::Ident { value })
+                if value.eq_ignore_ascii_case("normal") =>
+            {
+                Some(Self::Normal)
+            }
+            // SPEC: Negative values are invalid.
+            ComponentValue::PreservedToken(Token::Number { value, .. }) if *value >= 0.0 => {
+                Some(Self::Number(*value))
+            }
+            value => match LengthPercentage::parse_component_value(value, false)? {
+                LengthPercentage::Px(px) => Some(Self::Length(px)),
+                LengthPercentage::Percentage(percentage) => Some(Self::Percentage(percentage)),
+            },
+        }
+    }
+
+    /// Resolves percentages against `font_size`, like computing the value does.
+    ///
+    /// SPEC: Computed value: the keyword normal or a number or an absolute length
+    pub fn to_computed(self, font_size: f64) -> Self {
+        match self {
+            Self::Percentage(percentage) => Self::Length(font_size * percentage / 100.0),
+            line_height => line_height,
+        }
+    }
+
+    /// Returns the height of a line box in CSS pixels, for an element with the given font size.
+    pub fn used_value(self, font_size: f64) -> f64 {
+        match self {
+            // SPEC: normal
+            //       Determine the preferred line height automatically based on font metrics.
+            // NOTE: We don't have font metrics, so we use 1.2 like the user agents that use the
+            //       metrics of a typical font.
+            Self::Normal => font_size * 1.2,
+            // SPEC: <number [0,∞]>
+            //       The used value of the property is this number multiplied by the element's
+            //       computed font size.
+            Self::Number(number) => font_size * number,
+            Self::Length(length) => length,
+            Self::Percentage(percentage) => font_size * percentage / 100.0,
+        }
+    }
+}
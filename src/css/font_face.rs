@@ -0,0 +1,380 @@
+use std::ops::RangeInclusive;
+
+use crate::css::parser::types::{AtRule, ComponentValue, Declaration, Rule, StyleSheet};
+use crate::css::tokenizer::Token;
+
+/// https://drafts.csswg.org/css-fonts-4/#font-face-rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFaceRule {
+    pub family: String,
+    pub sources: Vec<FontSource>,
+    pub weight: RangeInclusive<f64>,
+    pub style: FontStyle,
+    pub unicode_ranges: Vec<RangeInclusive<u32>>,
+}
+
+/// https://drafts.csswg.org/css-fonts-4/#font-face-src-parsing
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    Url { url: String, format: Option<String> },
+    Local(String),
+}
+
+/// https://drafts.csswg.org/css-fonts-4/#font-prop-desc
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+    /// The range of oblique angles in degrees.
+    Oblique(RangeInclusive<f64>),
+}
+
+impl FontFaceRule {
+    /// Interprets an at-rule named "font-face" as a [`FontFaceRule`].
+    ///
+    /// Descriptors that are invalid are dropped. Returns `None` if the at-rule is not a @font-face rule,
+    /// or if it doesn't have a valid font-family and src descriptor.
+    pub fn from_at_rule(at_rule: &AtRule) -> Option<Self> {
+        if !at_rule.name.eq_ignore_ascii_case("font-face") {
+            return None;
+        }
+
+        let mut family = None;
+        let mut sources = None;
+        let mut weight = 400.0..=400.0;
+        let mut style = FontStyle::Normal;
+        let mut unicode_ranges = vec![0..=0x10FFFF];
+
+        for declaration in &at_rule.declarations {
+            // SPEC: Descriptors are parsed like properties, but !important is not allowed on them.
+            if declaration.important {
+                continue;
+            }
+
+            let values = non_whitespace_values(declaration);
+            match declaration.name.to_ascii_lowercase().as_str() {
+                "font-family" => family = parse_family_name(&values).or(family),
+                "src" => sources = parse_sources(&values).or(sources),
+                "font-weight" => weight = parse_weight(&values).unwrap_or(weight),
+                "font-style" => style = parse_style(&values).unwrap_or(style),
+                "unicode-range" => {
+                    unicode_ranges = parse_unicode_ranges(&values).unwrap_or(unicode_ranges)
+                }
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            family: family?,
+            sources: sources?,
+            weight,
+            style,
+            unicode_ranges,
+        })
+    }
+}
+
+impl StyleSheet {
+    /// All valid @font-face rules in the stylesheet, in order.
+    ///
+    /// FIXME: @font-face rules nested inside conditional group rules are not included.
+    pub fn font_face_rules(&self) -> Vec<FontFaceRule> {
+        self.rules
+            .iter()
+            .filter_map(|rule| match rule {
+                Rule::AtRule(at_rule) => FontFaceRule::from_at_rule(at_rule),
+                Rule::QualifiedRule(_) => None,
+            })
+            .collect()
+    }
+}
+
+fn non_whitespace_values(declaration: &Declaration) -> Vec<&ComponentValue> {
+    declaration
+        .value
+        .iter()
+        .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+        .collect()
+}
+
+pub(super) fn split_on_commas<'a>(values: &[&'a ComponentValue]) -> Vec<Vec<&'a ComponentValue>> {
+    values
+        .split(|value| **value == ComponentValue::PreservedToken(Token::Comma))
+        .map(|values| values.to_vec())
+        .collect()
+}
+
+/// <family-name> = <string> | <custom-ident>+
+pub(super) fn parse_family_name(values: &[&ComponentValue]) -> Option<String> {
+    match values {
+        [ComponentValue::PreservedToken(Token::String { value })] => Some(value.clone()),
+        [] => None,
+        values => values
+            .iter()
+            .map(|value| match value {
+                ComponentValue::PreservedToken(Token::Ident { value }) => Some(value.as_str()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|idents| idents.join(" ")),
+    }
+}
+
+/// <font-src-list> = [ <url> [ format(<font-format>)]? [ tech( <font-tech>#)]? | local(<family-name>) ]#
+///
+/// Sources that fail to parse are dropped. If none of them parse, the descriptor is invalid.
+fn parse_sources(values: &[&ComponentValue]) -> Option<Vec<FontSource>> {
+    let sources = split_on_commas(values)
+        .iter()
+        .filter_map(|source| parse_source(source))
+        .collect::<Vec<_>>();
+
+    if sources.is_empty() {
+        return None;
+    }
+    Some(sources)
+}
+
+fn parse_source(values: &[&ComponentValue]) -> Option<FontSource> {
+    let (first, rest) = values.split_first()?;
+
+    if let ComponentValue::Function(function) = first {
+        if function.name.eq_ignore_ascii_case("local") {
+            if !rest.is_empty() {
+                return None;
+            }
+            let arguments = function
+                .value
+                .iter()
+                .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+                .collect::<Vec<_>>();
+            return parse_family_name(&arguments).map(FontSource::Local);
+        }
+    }
+
+    let url = match first {
+        ComponentValue::PreservedToken(Token::Url { value }) => value.clone(),
+        ComponentValue::Function(function) if function.name.eq_ignore_ascii_case("url") => {
+            match function
+                .value
+                .iter()
+                .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                [ComponentValue::PreservedToken(Token::String { value })] => value.clone(),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    let mut format = None;
+    for (index, value) in rest.iter().enumerate() {
+        match value {
+            ComponentValue::Function(function)
+                if index == 0 && function.name.eq_ignore_ascii_case("format") =>
+            {
+                format = match function
+                    .value
+                    .iter()
+                    .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+                    .collect::<Vec<_>>()
+                    .as_slice()
+                {
+                    [ComponentValue::PreservedToken(
+                        Token::String { value } | Token::Ident { value },
+                    )] => Some(value.to_ascii_lowercase()),
+                    _ => return None,
+                };
+            }
+            // FIXME: Check whether the UA supports the font technologies.
+            ComponentValue::Function(function) if function.name.eq_ignore_ascii_case("tech") => {}
+            _ => return None,
+        }
+    }
+
+    Some(FontSource::Url { url, format })
+}
+
+/// auto | <font-weight-absolute>{1,2}
+///
+/// <font-weight-absolute> = [normal | bold | <number [1,1000]>]
+fn parse_weight(values: &[&ComponentValue]) -> Option<RangeInclusive<f64>> {
+    let absolute = |value: &ComponentValue| match value {
+        ComponentValue::PreservedToken(Token::Ident { value }) => {
+            match value.to_ascii_lowercase().as_str() {
+                "normal" => Some(400.0),
+                "bold" => Some(700.0),
+                _ => None,
+            }
+        }
+        ComponentValue::PreservedToken(Token::Number { value, .. })
+            if (1.0..=1000.0).contains(value) =>
+        {
+            Some(*value)
+        }
+        _ => None,
+    };
+
+    match values {
+        // FIXME: auto should use the weight range of the font itself.
+        [ComponentValue::PreservedToken(Token::Ident { value })]
+            if value.eq_ignore_ascii_case("auto") =>
+        {
+            Some(400.0..=400.0)
+        }
+        [weight] => absolute(weight).map(|weight| weight..=weight),
+        [start, end] => {
+            let (start, end) = (absolute(start)?, absolute(end)?);
+            // SPEC: User agents must swap the computed value of the startpoint and endpoint of the range
+            //       in order to forbid decreasing ranges.
+            Some(start.min(end)..=start.max(end))
+        }
+        _ => None,
+    }
+}
+
+/// auto | normal | italic | oblique [ <angle [-90deg,90deg]>{1,2} ]?
+fn parse_style(values: &[&ComponentValue]) -> Option<FontStyle> {
+    // FIXME: Support other angle units than deg.
+    let angle = |value: &ComponentValue| match value {
+        ComponentValue::PreservedToken(Token::Dimension { value, unit, .. })
+            if unit.eq_ignore_ascii_case("deg") && (-90.0..=90.0).contains(value) =>
+        {
+            Some(*value)
+        }
+        _ => None,
+    };
+
+    let (keyword, angles) = match values.split_first()? {
+        (ComponentValue::PreservedToken(Token::Ident { value }), angles) => {
+            (value.to_ascii_lowercase(), angles)
+        }
+        _ => return None,
+    };
+
+    match (keyword.as_str(), angles) {
+        ("auto" | "normal", []) => Some(FontStyle::Normal),
+        ("italic", []) => Some(FontStyle::Italic),
+        ("oblique", []) => Some(FontStyle::Oblique(14.0..=14.0)),
+        ("oblique", [angle_value]) => {
+            let angle = angle(angle_value)?;
+            Some(FontStyle::Oblique(angle..=angle))
+        }
+        ("oblique", [start, end]) => {
+            let (start, end) = (angle(start)?, angle(end)?);
+            Some(FontStyle::Oblique(start.min(end)..=start.max(end)))
+        }
+        _ => None,
+    }
+}
+
+/// <unicode-range-token>#
+fn parse_unicode_ranges(values: &[&ComponentValue]) -> Option<Vec<RangeInclusive<u32>>> {
+    split_on_commas(values)
+        .iter()
+        .map(|range| match range.as_slice() {
+            // SPEC: If the end of the range is less than the start, or the end is larger than the
+            //       maximum allowed code point, the <urange> is invalid.
+            [ComponentValue::PreservedToken(Token::UnicodeRange { start, end })]
+                if start <= end && *end <= 0x10FFFF =>
+            {
+                Some(*start..=*end)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::parser::token_streams::TokenStream;
+    use crate::css::parser::Parser;
+    use crate::css::tokenizer::Tokenizer;
+
+    fn font_face_rules(css: &str) -> Vec<FontFaceRule> {
+        let input = TokenStream::from_tokenizer(Tokenizer::new(css));
+        Parser::parse_a_stylesheet(&input, None).font_face_rules()
+    }
+
+    #[test]
+    fn webfont_with_fallback_sources() {
+        let rules = font_face_rules(
+            r#"
+            @font-face {
+                font-family: "Open Sans";
+                src: local("Open Sans Regular"),
+                     url(/fonts/OpenSans.woff2) format("woff2"),
+                     url("/fonts/OpenSans.woff") format(woff),
+                     url(/fonts/OpenSans.ttf) format("truetype") tech(variations);
+                font-weight: 300 700;
+                font-style: oblique 20deg 10deg;
+                unicode-range: U+0000-00FF, U+0131, U+0152-0153;
+            }
+            "#,
+        );
+
+        assert_eq!(
+            rules,
+            [FontFaceRule {
+                family: "Open Sans".to_string(),
+                sources: vec![
+                    FontSource::Local("Open Sans Regular".to_string()),
+                    FontSource::Url {
+                        url: "/fonts/OpenSans.woff2".to_string(),
+                        format: Some("woff2".to_string()),
+                    },
+                    FontSource::Url {
+                        url: "/fonts/OpenSans.woff".to_string(),
+                        format: Some("woff".to_string()),
+                    },
+                    FontSource::Url {
+                        url: "/fonts/OpenSans.ttf".to_string(),
+                        format: Some("truetype".to_string()),
+                    },
+                ],
+                weight: 300.0..=700.0,
+                style: FontStyle::Oblique(10.0..=20.0),
+                unicode_ranges: vec![0..=0xFF, 0x131..=0x131, 0x152..=0x153],
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_descriptors_are_dropped() {
+        let rules = font_face_rules(
+            "@font-face { font-family: Inter Display; src: url(a.woff2) bogus, url(b.woff);
+                font-weight: 1001; font-style: italic !important; unicode-range: U+00FF-0000 }",
+        );
+
+        assert_eq!(
+            rules,
+            [FontFaceRule {
+                family: "Inter Display".to_string(),
+                sources: vec![FontSource::Url {
+                    url: "b.woff".to_string(),
+                    format: None,
+                }],
+                weight: 400.0..=400.0,
+                style: FontStyle::Normal,
+                unicode_ranges: vec![0..=0x10FFFF],
+            }]
+        );
+    }
+
+    #[test]
+    fn rules_without_family_or_src_are_discarded() {
+        assert_eq!(
+            font_face_rules(
+                "@font-face { src: url(a.woff) }
+                 @font-face { font-family: A; src: url(a.woff) foo }
+                 @font-face { font-family: B !important; src: url(b.woff) }
+                 @media print { @font-face { font-family: C; src: url(c.woff) } }
+                 p { font-family: D; src: url(d.woff) }"
+            ),
+            []
+        );
+    }
+}
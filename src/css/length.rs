@@ -0,0 +1,207 @@
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+
+/// A <length-percentage>, with the length already converted to CSS pixels.
+///
+/// FIXME: Font-relative and viewport-percentage units are not supported, as we don't know
+///        the font size or the viewport when computing styles yet.
+///
+/// https://drafts.csswg.org/css-values-4/#typedef-length-percentage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LengthPercentage {
+    Px(f64),
+    /// A percentage, where `100.0` is 100%.
+    Percentage(f64),
+}
+
+impl Default for LengthPercentage {
+    fn default() -> Self {
+        Self::Px(0.0)
+    }
+}
+
+impl LengthPercentage {
+    /// Parses a single component value as a <length-percentage>.
+    ///
+    /// Returns `None` if the value is not a supported <length-percentage>, or if it is negative
+    /// and `allow_negative` is false.
+    pub fn parse_component_value(value: &ComponentValue, allow_negative: bool) -> Option<Self> {
+        let length = match value {
+            ComponentValue::PreservedToken(Token::Dimension { value, unit, .. }) => {
+                Self::Px(px_from_absolute_length(*value, unit)?)
+            }
+            ComponentValue::PreservedToken(Token::Percentage { value, .. }) => {
+                Self::Percentage(*value)
+            }
+            // SPEC: For zero lengths the unit identifier is optional.
+            ComponentValue::PreservedToken(Token::Number { value, .. }) if *value == 0.0 => {
+                Self::Px(0.0)
+            }
+            _ => return None,
+        };
+
+        match length {
+            Self::Px(value) | Self::Percentage(value) if value < 0.0 && !allow_negative => None,
+            length => Some(length),
+        }
+    }
+
+    /// Resolves the value to CSS pixels, with percentages relative to `basis`.
+    pub fn resolve(&self, basis: f64) -> f64 {
+        match self {
+            Self::Px(px) => *px,
+            Self::Percentage(percentage) => basis * percentage / 100.0,
+        }
+    }
+}
+
+/// A <length-percentage> or the `auto` keyword, like the values of `width` and `margin-*`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LengthPercentageAuto {
+    #[default]
+    Auto,
+    LengthPercentage(LengthPercentage),
+}
+
+impl LengthPercentageAuto {
+    /// Parses a single component value as a <length-percentage> or `auto`.
+    pub fn parse_component_value(value: &ComponentValue, allow_negative: bool) -> Option<Self> {
+        match value {
+            ComponentValue::PreservedToken(Token::Ident { value })
+                if value.eq_ignore_ascii_case("auto") =>
+            {
+                Some(Self::Auto)
+            }
+            value => LengthPercentage::parse_component_value(value, allow_negative)
+                .map(Self::LengthPercentage),
+        }
+    }
+
+    pub fn px(px: f64) -> Self {
+        Self::LengthPercentage(LengthPercentage::Px(px))
+    }
+
+    pub fn is_auto(&self) -> bool {
+        *self == Self::Auto
+    }
+
+    /// Resolves the value to CSS pixels, with percentages relative to `basis`,
+    /// or `None` if it is `auto`.
+    pub fn resolve(&self, basis: f64) -> Option<f64> {
+        match self {
+            Self::Auto => None,
+            Self::LengthPercentage(length) => Some(length.resolve(basis)),
+        }
+    }
+}
+
+/// Parses a <line-width>, as used by the `border-*-width` properties, to CSS pixels.
+///
+/// https://drafts.csswg.org/css-backgrounds-3/#typedef-line-width
+pub fn parse_line_width(value: &ComponentValue) -> Option<f64> {
+    match value {
+        // SPEC: The thin, medium, and thick keywords are equivalent to 1px, 3px, and 5px,
+        //       respectively.
+        ComponentValue::PreservedToken(Token::Ident { value }) => {
+            match value.to_ascii_lowercase().as_str() {
+                "thin" => Some(1.0),
+                "medium" => Some(3.0),
+                "thick" => Some(5.0),
+                _ => None,
+            }
+        }
+        value => match LengthPercentage::parse_component_value(value, false)? {
+            LengthPercentage::Px(px) => Some(px),
+            // NOTE: Border widths can't be percentages.
+            LengthPercentage::Percentage(_) => None,
+        },
+    }
+}
+
+/// https://drafts.csswg.org/css-values-4/#absolute-lengths
+fn px_from_absolute_length(value: f64, unit: &str) -> Option<f64> {
+    let px_per_unit = match unit.to_ascii_lowercase().as_str() {
+        "px" => 1.0,
+        "cm" => 96.0 / 2.54,
+        "mm" => 96.0 / 25.4,
+        "q" => 96.0 / 101.6,
+        "in" => 96.0,
+        "pt" => 96.0 / 72.0,
+        "pc" => 96.0 / 6.0,
+        _ => return None,
+    };
+    Some(value * px_per_unit)
+}
+
+/// A value for each side of a box, like the ones set by the `margin` and `padding` properties.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Sides<T> {
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+    pub left: T,
+}
+
+impl<T: Copy> Sides<T> {
+    pub fn all(value: T) -> Self {
+        Self {
+            top: value,
+            right: value,
+            bottom: value,
+            left: value,
+        }
+    }
+
+    /// Parses the value of a shorthand like `margin` that sets all four sides at once, using
+    /// `parse` for every individual value.
+    ///
+    /// SPEC: If there is only one component value, it applies to all sides. If there are two
+    ///       values, the top and bottom are set to the first value and the right and left are
+    ///       set to the second. If there are three values, the top is set to the first value,
+    ///       the left and right are set to the second, and the bottom is set to the third. If
+    ///       there are four values they apply to the top, right, bottom, and left, respectively.
+    ///
+    /// https://drafts.csswg.org/css-box-4/#margin-shorthand
+    pub fn parse_shorthand(
+        values: &[ComponentValue],
+        parse: impl Fn(&ComponentValue) -> Option<T>,
+    ) -> Option<Self> {
+        let values = non_whitespace_values(values)
+            .into_iter()
+            .map(parse)
+            .collect::<Option<Vec<_>>>()?;
+
+        let (top, right, bottom, left) = match values.as_slice() {
+            [all] => (*all, *all, *all, *all),
+            [vertical, horizontal] => (*vertical, *horizontal, *vertical, *horizontal),
+            [top, horizontal, bottom] => (*top, *horizontal, *bottom, *horizontal),
+            [top, right, bottom, left] => (*top, *right, *bottom, *left),
+            _ => return None,
+        };
+
+        Some(Self {
+            top,
+            right,
+            bottom,
+            left,
+        })
+    }
+}
+
+/// Parses the value of a property that takes a single component value.
+pub fn parse_single_value<T>(
+    values: &[ComponentValue],
+    parse: impl Fn(&ComponentValue) -> Option<T>,
+) -> Option<T> {
+    match non_whitespace_values(values).as_slice() {
+        [value] => parse(value),
+        _ => None,
+    }
+}
+
+fn non_whitespace_values(values: &[ComponentValue]) -> Vec<&ComponentValue> {
+    values
+        .iter()
+        .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+        .collect()
+}
@@ -0,0 +1,176 @@
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+use crate::dom::NodeRef;
+
+/// https://drafts.csswg.org/css-lists-3/#text-markers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListStyleType {
+    #[default]
+    Disc,
+    Circle,
+    Square,
+    Decimal,
+    None,
+}
+
+impl ListStyleType {
+    /// Parses a single `list-style-type` keyword.
+    ///
+    /// FIXME: Support the other predefined counter styles, <string> and symbols().
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        let ComponentValue::PreservedToken(Token::Ident { value }) = value else {
+            return None;
+        };
+
+        match value.to_ascii_lowercase().as_str() {
+            "disc" => Some(Self::Disc),
+            "circle" => Some(Self::Circle),
+            "square" => Some(Self::Square),
+            "decimal" => Some(Self::Decimal),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// The contents of the marker of a list item with the given ordinal value, or `None` if the
+    /// list item has no marker.
+    ///
+    /// https://drafts.csswg.org/css-counter-styles-3/#predefined-counters
+    pub fn marker_text(&self, ordinal: i64) -> Option<String> {
+        // SPEC: The disc, circle and square counter styles use the symbols U+2022 BULLET,
+        //       U+25E6 WHITE BULLET and U+25AA BLACK SMALL SQUARE, with a suffix of " ".
+        //       The decimal counter style uses a suffix of ". ".
+        match self {
+            Self::Disc => Some("\u{2022} ".to_string()),
+            Self::Circle => Some("\u{25E6} ".to_string()),
+            Self::Square => Some("\u{25AA} ".to_string()),
+            Self::Decimal => Some(format!("{ordinal}. ")),
+            // SPEC: none: The element has no marker string.
+            Self::None => None,
+        }
+    }
+}
+
+/// The `list-style-type` the user agent stylesheet gives to `element`, if it gives it one.
+///
+/// FIXME: This should come from a real user agent stylesheet once we have a cascade.
+///
+/// https://html.spec.whatwg.org/multipage/rendering.html#lists
+pub fn user_agent_list_style_type_for_html_element(element: NodeRef) -> Option<ListStyleType> {
+    // SPEC: ol { list-style-type: decimal; }
+    //       ul, menu, dir { list-style-type: disc; }
+    //       :is(ul, ol, menu, dir) :is(ul, menu, dir) { list-style-type: circle; }
+    //       :is(ul, ol, menu, dir) :is(ul, ol, menu, dir) :is(ul, menu, dir) {
+    //           list-style-type: square;
+    //       }
+    if element.is_element_with_tag("ol") {
+        return Some(ListStyleType::Decimal);
+    }
+    if !is_unordered_list(element) {
+        return None;
+    }
+
+    let mut depth = 0;
+    let mut ancestor = element.parent_element();
+    while let Some(current) = ancestor {
+        if is_unordered_list(current) || current.is_element_with_tag("ol") {
+            depth += 1;
+        }
+        ancestor = current.parent_element();
+    }
+
+    Some(match depth {
+        0 => ListStyleType::Disc,
+        1 => ListStyleType::Circle,
+        _ => ListStyleType::Square,
+    })
+}
+
+fn is_unordered_list(node: NodeRef) -> bool {
+    node.is_element_with_tag("ul")
+        || node.is_element_with_tag("menu")
+        || node.is_element_with_tag("dir")
+}
+
+/// Keeps track of the ordinal values of the list items among the children of a single element.
+///
+/// NOTE: We number list items among their list-item siblings, which for HTML lists is the same as
+///       numbering the li children of their owner ol.
+///
+/// https://html.spec.whatwg.org/multipage/grouping-content.html#ordinal-value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListItemOrdinals {
+    next: i64,
+}
+
+impl ListItemOrdinals {
+    /// The ordinals of the list items among the children of `parent`.
+    pub fn new(parent: NodeRef) -> Self {
+        // SPEC: 2. If owner has a start attribute whose value can be successfully parsed as
+        //          an integer, let numbering be that integer. Otherwise, let numbering be 1.
+        // FIXME: Count down for reversed lists.
+        let start = parent
+            .as_element()
+            .filter(|_| parent.is_element_with_tag("ol"))
+            .and_then(|element| element.get_attribute("start"))
+            .and_then(|start| parse_integer(&start));
+
+        Self {
+            next: start.unwrap_or(1),
+        }
+    }
+
+    /// The ordinal value of `list_item`, the next list item among the children.
+    pub fn next(&mut self, list_item: NodeRef) -> i64 {
+        // SPEC: 1. If item is an li element that has a value attribute, and parsing that
+        //          attribute's value as an integer does not return an error, then set numbering
+        //          to that integer.
+        let value = list_item
+            .as_element()
+            .filter(|_| list_item.is_element_with_tag("li"))
+            .and_then(|element| element.get_attribute("value"))
+            .and_then(|value| parse_integer(&value));
+        if let Some(value) = value {
+            self.next = value;
+        }
+
+        // SPEC: 2. Set item's ordinal value to numbering.
+        //       3. Increment numbering by one.
+        let ordinal = self.next;
+        self.next = self.next.saturating_add(1);
+        ordinal
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#rules-for-parsing-integers
+fn parse_integer(input: &str) -> Option<i64> {
+    // 3. Let sign have the value "positive".
+    // 4. Skip ASCII whitespace within input given position.
+    let input = input.trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+    // 6. If the character indicated by position (the first character) is a U+002D HYPHEN-MINUS
+    //    character (-): let sign be "negative". Otherwise, if it is a U+002B PLUS SIGN character
+    //    (+): advance position to the next character.
+    let (negative, input) = match input.as_bytes().first() {
+        Some(b'-') => (true, &input[1..]),
+        Some(b'+') => (false, &input[1..]),
+        _ => (false, input),
+    };
+
+    // 8. If the character indicated by position is not an ASCII digit, then return an error.
+    // 9. Collect a sequence of code points that are ASCII digits from input given position, and
+    //    interpret the resulting sequence as a base-ten integer.
+    // NOTE: Anything after the digits is ignored.
+    let digits = input
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(input, |end| &input[..end]);
+    if digits.is_empty() {
+        return None;
+    }
+    // FIXME: Values that don't fit in an i64 should saturate instead of being an error.
+    let value = digits.parse::<i64>().ok()?;
+
+    // 10. If sign is "positive", return value, otherwise return the result of subtracting value
+    //     from zero.
+    Some(if negative { -value } else { value })
+}
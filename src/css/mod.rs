@@ -1,2 +1,16 @@
+pub mod border;
+pub mod color;
+pub mod display;
+pub mod float;
+pub mod font;
+pub mod font_face;
+pub mod length;
+pub mod list;
+pub mod origin;
 pub mod parser;
+pub mod position;
+pub mod selectors;
+pub mod serializer;
+pub mod supports;
+pub mod text;
 pub mod tokenizer;
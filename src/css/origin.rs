@@ -0,0 +1,40 @@
+/// Where a style rule comes from. Each origin has its own precedence in the cascade.
+///
+/// https://drafts.csswg.org/css-cascade-5/#origin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Origin {
+    /// https://drafts.csswg.org/css-cascade-5/#cascade-origin-ua
+    UserAgent,
+    /// https://drafts.csswg.org/css-cascade-5/#cascade-origin-user
+    User,
+    /// https://drafts.csswg.org/css-cascade-5/#cascade-origin-author
+    #[default]
+    Author,
+}
+
+impl Origin {
+    /// The precedence of a declaration from this origin, with higher values winning.
+    ///
+    /// https://drafts.csswg.org/css-cascade-5/#cascade-origin
+    pub fn precedence(self, important: bool) -> u8 {
+        // SPEC: Declarations from origins earlier in this list win over declarations from later
+        //       origins:
+        //       1. Transition declarations
+        //       2. Important user agent declarations
+        //       3. Important user declarations
+        //       4. Important author declarations
+        //       5. Animation declarations
+        //       6. Normal author declarations
+        //       7. Normal user declarations
+        //       8. Normal user agent declarations
+        // NOTE: We don't support transitions or animations.
+        match (important, self) {
+            (true, Origin::UserAgent) => 5,
+            (true, Origin::User) => 4,
+            (true, Origin::Author) => 3,
+            (false, Origin::Author) => 2,
+            (false, Origin::User) => 1,
+            (false, Origin::UserAgent) => 0,
+        }
+    }
+}
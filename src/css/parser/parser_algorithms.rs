@@ -1,14 +1,14 @@
+use crate::css::origin::Origin;
 use crate::css::parser::types::{ComponentValue, Function};
-use crate::css::tokenizer::Token;
-use crate::html::parser::log_parser_error;
+use crate::css::tokenizer::{log_parse_error, Token};
 
 use super::token_streams::{ProcessResult, TokenStream};
-use super::types::{Declaration, QualifiedRule, SimpleBlock};
+use super::types::{AtRule, Declaration, QualifiedRule, Rule, SimpleBlock};
 
 /// 5.5.1. Consume a stylesheet's contents
 ///
 /// https://drafts.csswg.org/css-syntax-3/#consume-a-stylesheets-contents
-pub(crate) fn consume_a_stylesheets_content(input: &TokenStream) -> Vec<QualifiedRule> {
+pub(crate) fn consume_a_stylesheets_content(input: &TokenStream) -> Vec<Rule> {
     // Let rules be an initially empty list of rules.
     let mut rules = vec![];
 
@@ -28,19 +28,88 @@ pub(crate) fn consume_a_stylesheets_content(input: &TokenStream) -> Vec<Qualifie
             input.discard_a_token();
             ProcessResult::Continue
         }
-        Token::AtKeyword { .. } => todo!(),
+        Token::AtKeyword { .. } => {
+            // Consume an at-rule from input.
+            let at_rule = consume_an_at_rule(input, false);
+            // If anything is returned, append it to rules.
+            if let Some(at_rule) = at_rule {
+                rules.push(Rule::AtRule(at_rule));
+            }
+            ProcessResult::Continue
+        }
         _ => {
             // Consume a qualified rule from input.
             let qualified_rule = consume_a_qualified_rule(input, None, false);
             // If anything is returned, append it to rules.
             if let Some(qualified_rule) = qualified_rule {
-                rules.push(qualified_rule);
+                rules.push(Rule::QualifiedRule(qualified_rule));
             }
             ProcessResult::Continue
         }
     })
 }
 
+/// 5.5.2. Consume an at-rule
+///
+/// https://drafts.csswg.org/css-syntax-3/#consume-at-rule
+pub(crate) fn consume_an_at_rule(input: &TokenStream, nested: bool) -> Option<AtRule> {
+    // Assert: The next token is an <at-keyword-token>.
+    // Consume a token from input, and let rule be a new at-rule with its name set to the returned token’s value,
+    // its prelude initially set to an empty list, and no declarations or child rules.
+    let name = match input.consume_a_token() {
+        Token::AtKeyword { value } => value,
+        _ => panic!("An at-keyword token was expected while consuming an at-rule"),
+    };
+    let mut rule = AtRule {
+        name,
+        prelude: vec![],
+        declarations: vec![],
+        child_rules: vec![],
+        origin: Origin::default(),
+    };
+
+    input.process(|token| match token {
+        Token::Semicolon | Token::EndOfFile => {
+            // Discard a token from input.
+            input.discard_a_token();
+            // If rule is valid in the current context, return it; otherwise return nothing.
+            // FIXME: Check if rule is valid in the current context.
+            ProcessResult::Return(Some(rule.clone()))
+        }
+        Token::RightCurlyBracket => {
+            // If nested is true:
+            if nested {
+                // If rule is valid in the current context, return it.
+                // FIXME: Check if rule is valid in the current context.
+                return ProcessResult::Return(Some(rule.clone()));
+            }
+
+            // Otherwise, consume a token and append the result to rule’s prelude.
+            rule.prelude
+                .push(ComponentValue::PreservedToken(input.consume_a_token()));
+
+            ProcessResult::Continue
+        }
+        Token::LeftCurlyBracket => {
+            // Consume a block from input, and assign the results to rule’s lists of declarations and child rules.
+            let (decls, rules) = consume_a_block(input);
+            rule.declarations = decls;
+            rule.child_rules = rules;
+
+            // If rule is valid in the current context, return it. Otherwise, return nothing.
+            // FIXME: Check if rule is valid in the current context.
+            ProcessResult::Return(Some(rule.clone()))
+        }
+        _ => {
+            // Consume a component value from input and append the returned value to rule’s prelude.
+            let component_value = consume_a_component_value(input);
+            rule.prelude.push(component_value);
+
+            ProcessResult::Continue
+        }
+    })
+}
+
 /// 5.5.3. Consume a qualified rule
 ///
 /// https://drafts.csswg.org/css-syntax-3/#consume-qualified-rule
@@ -54,19 +123,23 @@ pub(crate) fn consume_a_qualified_rule(
         prelude: vec![],
         declarations: vec![],
         child_rules: vec![],
+        origin: Origin::default(),
     };
 
     input.process(|token| match token {
         token if token == &Token::EndOfFile || Some(token) == stop_token => {
             // This is a parse error.
-            log_parser_error!("Unexpected EOF while parsing a qualified rule.");
+            log_parse_error!(match token {
+                Token::EndOfFile => "Unexpected EOF while parsing a qualified rule.".to_string(),
+                _ => format!("Unexpected {:?} while parsing a qualified rule.", token),
+            });
 
             // Return nothing.
             ProcessResult::Return(None)
         }
         Token::RightCurlyBracket => {
             // This is a parse error.
-            log_parser_error!("Unexpected '{{' while parsing a qualified rule.");
+            log_parse_error!("Unexpected '}' while parsing a qualified rule.");
 
             // If nested is true, return nothing.
             if nested {
@@ -74,17 +147,25 @@ pub(crate) fn consume_a_qualified_rule(
             }
 
             // Otherwise, consume a token and append the result to rule’s prelude.
-            rule.prelude.push(ComponentValue::PreservedToken(
-                input.consume_a_token().clone(),
-            ));
+            rule.prelude
+                .push(ComponentValue::PreservedToken(input.consume_a_token()));
 
             ProcessResult::Continue
         }
         Token::LeftCurlyBracket => {
-            // FIXME: If the first two non-<whitespace-token> values of rule’s prelude are an <ident-token>
-            //        whose value starts with "--" followed by a <colon-token>,
-            //        consume the remnants of a bad declaration from input, with nested,
-            //        and return nothing.
+            // If the first two non-<whitespace-token> values of rule’s prelude are an <ident-token>
+            // whose value starts with "--" followed by a <colon-token>, then:
+            if prelude_looks_like_a_custom_property(&rule.prelude) {
+                // If nested is true, consume the remnants of a bad declaration from input, with nested set to true, and return nothing.
+                if nested {
+                    consume_the_remnants_of_a_bad_declaration(input, true);
+                    return ProcessResult::Return(None);
+                }
+
+                // If nested is false, consume a block from input, and return nothing.
+                consume_a_block(input);
+                return ProcessResult::Return(None);
+            }
 
             // Otherwise, consume a block from input, and assign the results to rule’s lists of declarations and child rules.
             let (decls, rules) = consume_a_block(input);
@@ -106,12 +187,31 @@ pub(crate) fn consume_a_qualified_rule(
     })
 }
 
+/// Whether the first two non-<whitespace-token> values of `prelude` are an <ident-token> whose
+/// value starts with "--" followed by a <colon-token>.
+///
+/// NOTE: Such a rule is almost certainly a custom property whose value contains a {}-block,
+///       like `--foo: {}`, so it should not be parsed as a style rule.
+fn prelude_looks_like_a_custom_property(prelude: &[ComponentValue]) -> bool {
+    let mut values = prelude
+        .iter()
+        .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace));
+
+    matches!(
+        (values.next(), values.next()),
+        (
+            Some(ComponentValue::PreservedToken(Token::Ident { value })),
+            Some(ComponentValue::PreservedToken(Token::Colon)),
+        ) if value.starts_with("--")
+    )
+}
+
 /// 5.5.4. Consume a block
 ///
 /// https://drafts.csswg.org/css-syntax-3/#consume-block
-pub(crate) fn consume_a_block(input: &TokenStream) -> (Vec<Declaration>, Vec<QualifiedRule>) {
+pub(crate) fn consume_a_block(input: &TokenStream) -> (Vec<Declaration>, Vec<Rule>) {
     // Assert: The next token is a <{-token>.
-    assert_eq!(input.next_token(), &Token::LeftCurlyBracket);
+    assert_eq!(input.next_token(), Token::LeftCurlyBracket);
 
     // Let decls be an empty list of declarations, and rules be an empty list of rules.
     // NOTE: We create them from the return value of consume_a_blocks_contents instead.
@@ -131,9 +231,7 @@ pub(crate) fn consume_a_block(input: &TokenStream) -> (Vec<Declaration>, Vec<Qua
 /// 5.5.5. Consume a block's contents
 ///
 /// https://drafts.csswg.org/css-syntax-3/#consume-block-contents
-pub(crate) fn consume_a_blocks_contents(
-    input: &TokenStream,
-) -> (Vec<Declaration>, Vec<QualifiedRule>) {
+pub(crate) fn consume_a_blocks_contents(input: &TokenStream) -> (Vec<Declaration>, Vec<Rule>) {
     let mut decls = vec![];
     let mut rules = vec![];
 
@@ -147,7 +245,15 @@ pub(crate) fn consume_a_blocks_contents(
             // Return decls and rules.
             ProcessResult::Return((decls.clone(), rules.clone()))
         }
-        Token::AtKeyword { .. } => todo!(),
+        Token::AtKeyword { .. } => {
+            // Consume an at-rule from input, with nested set to true.
+            let at_rule = consume_an_at_rule(input, true);
+            // If a rule was returned, append it to rules.
+            if let Some(at_rule) = at_rule {
+                rules.push(Rule::AtRule(at_rule));
+            }
+            ProcessResult::Continue
+        }
         _ => {
             // Mark input.
             input.mark();
@@ -167,7 +273,7 @@ pub(crate) fn consume_a_blocks_contents(
                 let qualified_rule = consume_a_qualified_rule(input, Some(&Token::Semicolon), true);
                 // If a rule was returned, append it to rules.
                 if let Some(qualified_rule) = qualified_rule {
-                    rules.push(qualified_rule);
+                    rules.push(Rule::QualifiedRule(qualified_rule));
                 }
 
                 ProcessResult::Continue
@@ -191,22 +297,24 @@ pub(crate) fn consume_a_declaration(input: &TokenStream, nested: bool) -> Option
     if let Token::Ident { value } = input.next_token() {
         // consume a token from input and set decl’s name to the token’s value.
         input.consume_a_token();
-        decl.name = value.clone();
+        decl.name = value;
     } else {
         // Otherwise, consume the remnants of a bad declaration from input, with nested,
         // and return nothing.
-        todo!()
+        consume_the_remnants_of_a_bad_declaration(input, nested);
+        return None;
     }
 
     // 2. Discard whitespace from input.
     input.discard_whitespace();
 
     // 3. If the next token is a <colon-token>, discard a token from input.
-    if input.next_token() == &Token::Colon {
+    if input.next_token() == Token::Colon {
         input.discard_a_token();
     } else {
         // Otherwise, consume the remnants of a bad declaration from input, with nested, and return nothing.
-        todo!()
+        consume_the_remnants_of_a_bad_declaration(input, nested);
+        return None;
     }
 
     // 4. Discard whitespace from input.
@@ -220,7 +328,29 @@ pub(crate) fn consume_a_declaration(input: &TokenStream, nested: bool) -> Option
     if decl.name.starts_with("--") {
         // then set decl’s original text to the segment of the original source text string corresponding
         // to the tokens returned by the consume a list of component values call.
-        todo!()
+        // FIXME: We don't keep the original source text around, so we can't set the original text.
+    }
+    // Otherwise, if decl’s value contains a top-level simple block with an associated token of <{-token>,
+    // and also contains any other non-<whitespace-token> value, return nothing.
+    // (That is, a top-level {}-block is only allowed as the entire value of a non-custom property.)
+    else if decl.value.iter().any(|value| {
+        matches!(value, ComponentValue::SimpleBlock(block) if block.associated_token == Token::LeftCurlyBracket)
+    }) && decl
+        .value
+        .iter()
+        .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+        .count()
+        > 1
+    {
+        return None;
+    }
+
+    // NOTE: No property grammar accepts a <bad-string-token> or <bad-url-token>, not even the
+    //       <declaration-value> of a custom property, so a declaration containing one is always
+    //       invalid. Dropping it here keeps it from overriding an earlier valid declaration.
+    //       https://drafts.csswg.org/css-syntax-3/#typedef-declaration-value
+    if contains_bad_token(&decl.value) {
+        return None;
     }
 
     // If decl’s name is an ASCII case-insensitive match for "unicode-range",
@@ -228,13 +358,34 @@ pub(crate) fn consume_a_declaration(input: &TokenStream, nested: bool) -> Option
         // consume the value of a unicode-range descriptor from the segment of
         // the original source text string corresponding to the tokens
         // returned by the consume a list of component values call,and replace decl’s value with the result.
-        todo!()
+        // NOTE: The tokenizer already allows unicode-range tokens while tokenizing the value
+        //       of a unicode-range declaration, so decl's value is already correct.
     }
 
     // 6. If the last two non-<whitespace-token>s in decl’s value are a <delim-token> with the value "!"
     // followed by an <ident-token> with a value that is an ASCII case-insensitive match for "important",
     // remove them from decl’s value and set decl’s important flag.
-    // FIXME: Implement
+    let mut non_whitespace_indices = decl
+        .value
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, value)| **value != ComponentValue::PreservedToken(Token::Whitespace))
+        .map(|(index, _)| index);
+    if let (Some(ident_index), Some(delim_index)) =
+        (non_whitespace_indices.next(), non_whitespace_indices.next())
+    {
+        let is_bang =
+            decl.value[delim_index] == ComponentValue::PreservedToken(Token::Delim { value: '!' });
+        let is_important = matches!(
+            &decl.value[ident_index],
+            ComponentValue::PreservedToken(Token::Ident { value }) if value.eq_ignore_ascii_case("important")
+        );
+        if is_bang && is_important {
+            decl.value.truncate(delim_index);
+            decl.important = true;
+        }
+    }
 
     // 7. While the last item in decl’s value is a <whitespace-token>, remove that token.
     while decl.value.last() == Some(&ComponentValue::PreservedToken(Token::Whitespace)) {
@@ -246,6 +397,94 @@ pub(crate) fn consume_a_declaration(input: &TokenStream, nested: bool) -> Option
     Some(decl)
 }
 
+/// Whether `values` contains a <bad-string-token> or <bad-url-token>, at any depth.
+fn contains_bad_token(values: &[ComponentValue]) -> bool {
+    values.iter().any(|value| match value {
+        ComponentValue::PreservedToken(token) => {
+            matches!(token, Token::BadString | Token::BadUrl)
+        }
+        ComponentValue::Function(function) => contains_bad_token(&function.value),
+        ComponentValue::SimpleBlock(block) => contains_bad_token(&block.values),
+    })
+}
+
+/// https://drafts.csswg.org/css-syntax-3/#consume-the-remnants-of-a-bad-declaration
+pub(crate) fn consume_the_remnants_of_a_bad_declaration(input: &TokenStream, nested: bool) {
+    input.process(|token| match token {
+        Token::EndOfFile | Token::Semicolon => {
+            // Discard a token from input, and return nothing.
+            input.discard_a_token();
+            ProcessResult::Return(())
+        }
+        Token::RightCurlyBracket => {
+            // If nested is true, return nothing.
+            if nested {
+                return ProcessResult::Return(());
+            }
+
+            // Otherwise, discard a token.
+            input.discard_a_token();
+            ProcessResult::Continue
+        }
+        _ => {
+            // Consume a component value from input, and do nothing.
+            consume_a_component_value(input);
+            ProcessResult::Continue
+        }
+    })
+}
+
+/// Consume a list of declarations
+///
+/// NOTE: The current draft parses declaration lists as a block's contents. We still need the
+///       version from the 2021 Candidate Recommendation for lists that are not inside a block,
+///       like the value of a `style` attribute.
+///
+/// https://www.w3.org/TR/css-syntax-3/#consume-list-of-declarations
+pub(crate) fn consume_a_list_of_declarations(input: &TokenStream) -> Vec<Declaration> {
+    // Create an initially empty list of declarations.
+    let mut declarations = vec![];
+
+    // Repeatedly consume the next input token:
+    input.process(|token| match token {
+        Token::Whitespace | Token::Semicolon => {
+            // Do nothing.
+            input.discard_a_token();
+            ProcessResult::Continue
+        }
+        Token::EndOfFile => {
+            // Return the list of declarations.
+            ProcessResult::Return(declarations.clone())
+        }
+        Token::AtKeyword { .. } => {
+            // Reconsume the current input token. Consume an at-rule. Append the returned rule to the list of declarations.
+            // FIXME: Keep the at-rule once declaration lists can contain rules (e.g. @page margin rules).
+            consume_an_at_rule(input, false);
+            ProcessResult::Continue
+        }
+        Token::Ident { .. } => {
+            // Initialize a temporary list initially filled with the current input token. As long as the next input token is anything other than a <semicolon-token> or <EOF-token>, consume a component value and append it to the temporary list.
+            // Consume a declaration from the temporary list. If anything was returned, append it to the list of declarations.
+            // NOTE: Consuming a declaration from the input directly stops at the same <semicolon-token>.
+            if let Some(declaration) = consume_a_declaration(input, false) {
+                declarations.push(declaration);
+            }
+            ProcessResult::Continue
+        }
+        _ => {
+            // This is a parse error.
+            log_parse_error!(format!(
+                "Unexpected token in list of declarations: {:?}",
+                token
+            ));
+
+            // Reconsume the current input token. As long as the next input token is anything other than a <semicolon-token> or <EOF-token>, consume a component value and throw away the returned value.
+            consume_the_remnants_of_a_bad_declaration(input, false);
+            ProcessResult::Continue
+        }
+    })
+}
+
 /// 5.5.7. Consume a list of component value
 ///
 /// https://drafts.csswg.org/css-syntax-3/#consume-component-value
@@ -268,11 +507,9 @@ pub(crate) fn consume_a_list_of_component_values(
             }
 
             // Otherwise, this is a parse error.
-            log_parser_error!();
+            log_parse_error!();
             // Consume a token from input and append the result to values.
-            values.push(ComponentValue::PreservedToken(
-                input.consume_a_token().clone(),
-            ));
+            values.push(ComponentValue::PreservedToken(input.consume_a_token()));
 
             ProcessResult::Continue
         }
@@ -299,9 +536,7 @@ pub(crate) fn consume_a_component_value(input: &TokenStream) -> ComponentValue {
         }
         _ => {
             // Consume a token from input and return the result.
-            ProcessResult::Return(ComponentValue::PreservedToken(
-                input.consume_a_token().clone(),
-            ))
+            ProcessResult::Return(ComponentValue::PreservedToken(input.consume_a_token()))
         }
     })
 }
@@ -325,7 +560,7 @@ pub(crate) fn consume_a_simple_block(input: &TokenStream) -> SimpleBlock {
     // Let block be a new simple block with its associated token set
     // to the next token and with its value initially set to an empty list.
     let mut block = SimpleBlock {
-        associated_token: input.next_token().clone(),
+        associated_token: input.next_token(),
         values: vec![],
     };
 
@@ -385,3 +620,126 @@ pub(crate) fn consume_a_function(input: &TokenStream) -> ComponentValue {
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::css::parser::token_streams::TokenStream;
+    use crate::css::parser::types::Rule;
+    use crate::css::parser::Parser;
+    use crate::css::tokenizer::Tokenizer;
+
+    /// The names of the declarations of each style rule in `css`.
+    fn declaration_names(css: &str) -> Vec<Vec<String>> {
+        let input = TokenStream::from_tokenizer(Tokenizer::new(css));
+        Parser::parse_a_stylesheet(&input, None)
+            .rules
+            .iter()
+            .map(|rule| match rule {
+                Rule::QualifiedRule(rule) => rule
+                    .declarations
+                    .iter()
+                    .map(|declaration| declaration.name.clone())
+                    .collect(),
+                Rule::AtRule(rule) => panic!("expected a style rule, got {rule:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bad_url_drops_only_its_declaration() {
+        assert_eq!(
+            declaration_names("a{color:red;background:url(a b);margin:0}"),
+            [["color", "margin"]]
+        );
+    }
+
+    #[test]
+    fn bad_string_drops_only_its_declaration() {
+        assert_eq!(
+            declaration_names("a{color:red;content:\"a\nb;margin:0}p{color:blue}"),
+            [vec!["color", "margin"], vec!["color"]]
+        );
+    }
+
+    #[test]
+    fn bad_tokens_in_a_style_attribute() {
+        let names: Vec<_> = Parser::parse_a_list_of_declarations(
+            "color:red;background:url(a b);content:\"a\nb;margin:0",
+        )
+        .into_iter()
+        .map(|declaration| declaration.name)
+        .collect();
+        assert_eq!(names, ["color", "margin"]);
+    }
+
+    #[test]
+    fn malformed_declarations_are_skipped_up_to_the_next_semicolon() {
+        assert_eq!(
+            declaration_names("a { color: red; : bad; margin: 0 }"),
+            [["color", "margin"]]
+        );
+
+        let names = |css: &str| -> Vec<String> {
+            Parser::parse_a_list_of_declarations(css)
+                .into_iter()
+                .map(|declaration| declaration.name)
+                .collect()
+        };
+        assert_eq!(names("color: red; : bad; margin: 0"), ["color", "margin"]);
+        assert_eq!(names("12px; color red; 'a': b; padding: 1px"), ["padding"]);
+        // NOTE: Semicolons inside blocks don't end the bad declaration.
+        assert_eq!(names("[a; b]: c; (d;): e; {f;}; width: 0"), ["width"]);
+        assert_eq!(names("@page { margin: 0 } color: red"), ["color"]);
+        assert_eq!(names(";;  ; top: 0;;"), ["top"]);
+    }
+
+    #[test]
+    fn qualified_rule_prelude_and_block() {
+        use crate::css::parser::types::ComponentValue;
+        use crate::css::selectors::SelectorList;
+        use crate::css::tokenizer::Token;
+
+        let input = TokenStream::from_tokenizer(Tokenizer::new("h1, h2 { color: red }"));
+        let rules = Parser::parse_a_stylesheet(&input, None).rules;
+        let [Rule::QualifiedRule(rule)] = rules.as_slice() else {
+            panic!("expected a single style rule, got {rules:?}");
+        };
+
+        let ident = |value: &str| {
+            ComponentValue::PreservedToken(Token::Ident {
+                value: value.to_string(),
+            })
+        };
+        let whitespace = ComponentValue::PreservedToken(Token::Whitespace);
+        assert_eq!(
+            rule.prelude,
+            [
+                ident("h1"),
+                ComponentValue::PreservedToken(Token::Comma),
+                whitespace.clone(),
+                ident("h2"),
+                whitespace.clone(),
+            ]
+        );
+        assert_eq!(
+            SelectorList::parse(&rule.prelude).map(|list| list.selectors.len()),
+            Some(2)
+        );
+
+        assert_eq!(rule.declarations.len(), 1);
+        assert_eq!(rule.declarations[0].name, "color");
+        assert_eq!(rule.declarations[0].value, [ident("red")]);
+        assert!(rule.child_rules.is_empty());
+    }
+
+    #[test]
+    fn qualified_rules_without_a_block_are_dropped() {
+        assert_eq!(declaration_names("h1 { color: red } h2"), [["color"]]);
+        assert_eq!(declaration_names("h1 { color: red } h2, h3"), [["color"]]);
+        // NOTE: A prelude that looks like a custom property drops the whole rule, block included.
+        assert_eq!(
+            declaration_names("--x: { color: blue } h1 { color: red }"),
+            [["color"]]
+        );
+    }
+}
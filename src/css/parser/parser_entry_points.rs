@@ -1,7 +1,42 @@
-use super::parser_algorithms::consume_a_stylesheets_content;
+use super::parser_algorithms::{
+    consume_a_declaration, consume_a_list_of_component_values, consume_a_list_of_declarations,
+    consume_a_stylesheets_content,
+};
 use super::token_streams::TokenStream;
-use super::types::StyleSheet;
+use super::types::{ComponentValue, CssParseError, Declaration, StyleSheet};
 use super::Parser;
+use crate::css::selectors::{ComplexSelector, SelectorList, SelectorParseError};
+use crate::css::tokenizer::{Token, Tokenizer};
+
+/// Input that the parser entry points accept: a string, or tokens that have already been
+/// tokenized.
+///
+/// https://drafts.csswg.org/css-syntax-3/#normalize-into-a-token-stream
+pub trait NormalizeIntoTokenStream<'a> {
+    fn normalize_into_a_token_stream(self) -> TokenStream<'a>;
+}
+
+impl<'a> NormalizeIntoTokenStream<'a> for TokenStream<'a> {
+    fn normalize_into_a_token_stream(self) -> TokenStream<'a> {
+        // If input is already a token stream, return it.
+        self
+    }
+}
+
+impl<'a> NormalizeIntoTokenStream<'a> for Vec<Token> {
+    fn normalize_into_a_token_stream(self) -> TokenStream<'a> {
+        // If input is a list of CSS tokens and/or component values, create a new token stream with input as its tokens, and return it.
+        TokenStream::new(self)
+    }
+}
+
+impl<'a> NormalizeIntoTokenStream<'a> for &'a str {
+    fn normalize_into_a_token_stream(self) -> TokenStream<'a> {
+        // If input is a string, then filter code points from input, tokenize the result, and create a new token stream with those tokens as its tokens. Return it.
+        // NOTE: The tokenizer filters the code points itself, and produces its tokens as the parser needs them.
+        TokenStream::from_tokenizer(Tokenizer::new(self))
+    }
+}
 
 /// 5.4. Parser Entry Points
 ///
@@ -20,10 +55,229 @@ impl Parser {
         // 3. Create a new stylesheet, with its location set to location (or null, if location was not passed).
         let mut stylesheet = StyleSheet::new(location);
 
+        // NOTE: A leading @charset rule only declares the encoding of the stylesheet's bytes,
+        //       so we record it instead of keeping it as a rule.
+        stylesheet.charset = consume_a_charset_rule(input);
+
         // 4. Consume a stylesheet’s contents from input, and set the stylesheet’s rules to the result.
         stylesheet.rules = consume_a_stylesheets_content(input);
 
         // 5. Return the stylesheet.
         stylesheet
     }
+
+    /// 5.3.8. Parse a list of declarations
+    ///
+    /// "Parse a list of declarations" is for the contents of a style attribute, which parses text into the contents of a single style rule.
+    ///
+    /// Declarations that are invalid are dropped, and parsing continues after the next `;`.
+    ///
+    /// https://www.w3.org/TR/css-syntax-3/#parse-list-of-declarations
+    pub fn parse_a_list_of_declarations<'a>(
+        input: impl NormalizeIntoTokenStream<'a>,
+    ) -> Vec<Declaration> {
+        // 1. Normalize input, and set input to the result.
+        let input = input.normalize_into_a_token_stream();
+
+        // 2. Consume a list of declarations from input, and return the result.
+        consume_a_list_of_declarations(&input)
+    }
+
+    /// 5.4.6. Parse a declaration
+    ///
+    /// "Parse a declaration" is used in @supports conditions.
+    ///
+    /// https://drafts.csswg.org/css-syntax-3/#parse-declaration
+    pub fn parse_a_declaration<'a>(
+        input: impl NormalizeIntoTokenStream<'a>,
+    ) -> Result<Declaration, CssParseError> {
+        // 1. Normalize input, and set input to the result.
+        let input = input.normalize_into_a_token_stream();
+
+        // 2. Discard whitespace from input.
+        input.discard_whitespace();
+
+        // 3. Consume a declaration from input. If anything was returned, return it. Otherwise, return a syntax error.
+        consume_a_declaration(&input, false).ok_or(CssParseError::SyntaxError)
+    }
+
+    /// 5.4.9. Parse a list of component values
+    ///
+    /// "Parse a list of component values" is for the contents of presentational attributes, which parse text into a single declaration’s value, or for parsing a stand-alone selector or list of Media Queries.
+    ///
+    /// https://drafts.csswg.org/css-syntax-3/#parse-list-of-component-values
+    pub fn parse_a_list_of_component_values<'a>(
+        input: impl NormalizeIntoTokenStream<'a>,
+    ) -> Vec<ComponentValue> {
+        // 1. Normalize input, and set input to the result.
+        let input = input.normalize_into_a_token_stream();
+
+        // 2. Consume a list of component values from input, and return the result.
+        consume_a_list_of_component_values(&input, None, false)
+    }
+
+    /// Parses `input` as a <selector-list>, for matching selectors against the DOM outside of a
+    /// stylesheet, like `querySelectorAll` does.
+    ///
+    /// https://drafts.csswg.org/selectors-4/#parse-a-selector
+    pub fn parse_selector_list(input: &str) -> Result<Vec<ComplexSelector>, SelectorParseError> {
+        // 1. Let selector be the result of parsing source as a <selector-list>.
+        //    If this returns failure, it's an invalid selector; return failure.
+        let values = Self::parse_a_list_of_component_values(input);
+        let selector = SelectorList::try_parse(&values)?;
+
+        // 2. Return selector.
+        Ok(selector.selectors)
+    }
+}
+
+/// Consumes a `@charset "<label>";` rule at the very start of `input`, and returns its label.
+/// Leaves `input` untouched and returns `None` if it doesn't start with one.
+///
+/// SPEC: The @charset rule is only recognized if the stylesheet starts with exactly
+///       `@charset "`, followed by the label and `";`.
+/// NOTE: We look at tokens instead of bytes, so any whitespace between the keyword and the label
+///       is accepted, but an escaped `@charset` keyword or one after other tokens is not.
+///
+/// https://drafts.csswg.org/css-syntax-3/#charset-rule
+fn consume_a_charset_rule(input: &TokenStream) -> Option<String> {
+    input.mark();
+
+    let tokens = [
+        input.consume_a_token(),
+        input.consume_a_token(),
+        input.consume_a_token(),
+        input.consume_a_token(),
+    ];
+    match tokens {
+        [Token::AtKeyword { value: name }, Token::Whitespace, Token::String { value: label }, Token::Semicolon]
+            if name == "charset" =>
+        {
+            input.discard_a_mark();
+            Some(label)
+        }
+        _ => {
+            input.restore_a_mark();
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::parser::types::{ComponentValue, Rule};
+    use crate::css::selectors::Combinator;
+    use crate::css::tokenizer::Token;
+
+    fn declarations(input: &str) -> Vec<(String, usize, bool)> {
+        Parser::parse_a_list_of_declarations(input)
+            .into_iter()
+            .map(|declaration| {
+                (
+                    declaration.name,
+                    declaration.value.len(),
+                    declaration.important,
+                )
+            })
+            .collect()
+    }
+
+    fn stylesheet(input: &str) -> StyleSheet {
+        Parser::parse_a_stylesheet(&TokenStream::from_tokenizer(Tokenizer::new(input)), None)
+    }
+
+    fn red() -> ComponentValue {
+        ComponentValue::PreservedToken(Token::Ident {
+            value: "red".to_string(),
+        })
+    }
+
+    #[test]
+    fn list_of_declarations() {
+        assert_eq!(
+            declarations("color: red;;; margin: 0 auto;"),
+            [
+                ("color".to_string(), 1, false),
+                ("margin".to_string(), 3, false)
+            ]
+        );
+        // NOTE: A missing value is still a declaration, it's up to the property to reject it.
+        assert_eq!(
+            declarations("color:; margin"),
+            [("color".to_string(), 0, false)]
+        );
+        assert_eq!(
+            declarations("/* a */color/* b */:/* c */red/* d */ !/* e */important/* f */;"),
+            [("color".to_string(), 1, true)]
+        );
+        assert_eq!(declarations(""), []);
+    }
+
+    #[test]
+    fn single_declaration() {
+        let declaration = Parser::parse_a_declaration("  color : red  ").unwrap();
+        assert_eq!(declaration.name, "color");
+        assert_eq!(declaration.value, [red()]);
+        assert!(!declaration.important);
+
+        let declaration = Parser::parse_a_declaration("color:/**/red/**/!IMPORTANT;").unwrap();
+        assert_eq!(declaration.value, [red()]);
+        assert!(declaration.important);
+
+        assert_eq!(
+            Parser::parse_a_declaration("--empty:").map(|declaration| declaration.value),
+            Ok(vec![])
+        );
+        for input in ["", "color", ": red", "/* color: red */", "1px: red"] {
+            assert_eq!(
+                Parser::parse_a_declaration(input),
+                Err(CssParseError::SyntaxError),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn leading_charset_rule() {
+        let sheet = stylesheet("@charset \"utf-8\"; h1{}");
+        assert_eq!(sheet.charset.as_deref(), Some("utf-8"));
+        assert_eq!(sheet.rules.len(), 1);
+        assert!(matches!(sheet.rules[0], Rule::QualifiedRule(_)));
+
+        // NOTE: Anything but the exact byte sequence at the very start is an ordinary at-rule.
+        for input in [
+            " @charset \"utf-8\"; h1{}",
+            "h1{} @charset \"utf-8\";",
+            "@CHARSET \"utf-8\"; h1{}",
+        ] {
+            let sheet = stylesheet(input);
+            assert_eq!(sheet.charset, None, "{input:?}");
+            assert_eq!(sheet.rules.len(), 2, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn selector_list() {
+        let selectors = Parser::parse_selector_list("a, b > c").unwrap();
+        assert_eq!(selectors.len(), 2);
+        assert!(selectors[0].rest.is_empty());
+        assert_eq!(selectors[1].rest.len(), 1);
+        assert_eq!(selectors[1].rest[0].0, Combinator::Child);
+
+        for input in ["a,,b", "a,", "", "  "] {
+            assert_eq!(
+                Parser::parse_selector_list(input).map(|_| ()),
+                Err(SelectorParseError::EmptySelector),
+                "{input:?}"
+            );
+        }
+        for input in ["a {", "a $", "> a"] {
+            assert_eq!(
+                Parser::parse_selector_list(input).map(|_| ()),
+                Err(SelectorParseError::InvalidSelector),
+                "{input:?}"
+            );
+        }
+    }
 }
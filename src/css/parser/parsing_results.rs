@@ -1,6 +1,6 @@
 use super::Parser;
 
 /// 5.2. CSS Parsing Results
-/// 
+///
 /// https://drafts.csswg.org/css-syntax-3/#css-tree
 impl Parser {}
@@ -1,15 +1,25 @@
 use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 
-use crate::css::tokenizer::Token;
+use crate::css::tokenizer::{Token, Tokenizer};
 
 /// 5.3. Token Streams
 ///
 /// https://drafts.csswg.org/css-syntax-3/#parser-definitions
-pub struct TokenStream {
+pub struct TokenStream<'a> {
     /// A list of tokens and/or component values.
     ///
+    /// NOTE: We only keep the tokens from the first marked index (or the index, if there are no marks) onwards,
+    ///       so consumed tokens don't stay around while parsing huge stylesheets.
+    ///
     /// https://drafts.csswg.org/css-syntax-3/#token-stream-tokens
-    tokens: Vec<Token>,
+    tokens: RefCell<VecDeque<Token>>,
+
+    /// The index of the first item that is still in tokens.
+    tokens_offset: Cell<usize>,
+
+    /// The tokenizer that produces the remaining tokens on demand, if the stream wraps a live tokenizer.
+    tokenizer: RefCell<Option<Tokenizer<'a>>>,
 
     /// An index into the tokens, representing the progress of parsing. It starts at 0 initially.
     ///
@@ -22,36 +32,65 @@ pub struct TokenStream {
     marked_indexes: RefCell<Vec<usize>>,
 }
 
-impl TokenStream {
-    pub fn new(tokens: Vec<Token>) -> TokenStream {
+impl<'a> TokenStream<'a> {
+    /// Creates a token stream over a list of tokens that have already been tokenized.
+    pub fn new(tokens: Vec<Token>) -> Self {
         TokenStream {
-            tokens,
+            tokens: RefCell::new(tokens.into()),
+            tokens_offset: Cell::new(0),
+            tokenizer: RefCell::new(None),
+            index: Cell::new(0),
+            marked_indexes: RefCell::new(vec![]),
+        }
+    }
+
+    /// Creates a token stream that pulls its tokens from `tokenizer` as the parser needs them,
+    /// instead of tokenizing the whole input up front.
+    pub fn from_tokenizer(tokenizer: Tokenizer<'a>) -> Self {
+        TokenStream {
+            tokens: RefCell::new(VecDeque::new()),
+            tokens_offset: Cell::new(0),
+            tokenizer: RefCell::new(Some(tokenizer)),
             index: Cell::new(0),
             marked_indexes: RefCell::new(vec![]),
         }
     }
 
     /// https://drafts.csswg.org/css-syntax-3/#token-stream-next-token
-    pub fn next_token(&self) -> &Token {
+    pub fn next_token(&self) -> Token {
+        let index = self.index.get() - self.tokens_offset.get();
+        let mut tokens = self.tokens.borrow_mut();
+
+        // Tokenize more of the input until index is within the bounds of tokens.
+        if let Some(tokenizer) = self.tokenizer.borrow_mut().as_mut() {
+            while tokens.len() <= index {
+                match tokenizer.next() {
+                    Some(token) => tokens.push_back(token),
+                    None => break,
+                }
+            }
+        }
+
         // The item of tokens at index.
-        self.tokens
-            .get(self.index.get())
+        tokens
+            .get(index)
+            .cloned()
             // If that index would be out-of-bounds past the end of the list, it’s instead an <eof-token>.
-            .unwrap_or(&Token::EndOfFile)
+            .unwrap_or(Token::EndOfFile)
     }
 
     /// https://drafts.csswg.org/css-syntax-3/#token-stream-empty
     pub fn empty(&self) -> bool {
         // A token stream is empty if the next token is an <eof-token>.
-        self.next_token() == &Token::EndOfFile
+        self.next_token() == Token::EndOfFile
     }
 
     /// https://drafts.csswg.org/css-syntax-3/#token-stream-consume-a-token
-    pub fn consume_a_token(&self) -> &Token {
+    pub fn consume_a_token(&self) -> Token {
         // Let token be the next token.
         let token = self.next_token();
         // Increment index,
-        self.index.set(self.index.get() + 1);
+        self.increment_index();
         // then return token.
         token
     }
@@ -60,7 +99,19 @@ impl TokenStream {
     pub fn discard_a_token(&self) {
         // If the token stream is not empty, increment index.
         if !self.empty() {
-            self.index.set(self.index.get() + 1);
+            self.increment_index();
+        }
+    }
+
+    fn increment_index(&self) {
+        self.index.set(self.index.get() + 1);
+
+        // We can never return to the tokens before index if there are no marked indexes, so drop them.
+        if self.marked_indexes.borrow().is_empty() {
+            let mut tokens = self.tokens.borrow_mut();
+            let count = (self.index.get() - self.tokens_offset.get()).min(tokens.len());
+            tokens.drain(..count);
+            self.tokens_offset.set(self.tokens_offset.get() + count);
         }
     }
 
@@ -86,7 +137,7 @@ impl TokenStream {
     /// https://drafts.csswg.org/css-syntax-3/#token-stream-discard-whitespace
     pub fn discard_whitespace(&self) {
         // While the next token is a <whitespace-token>, discard a token.
-        while self.next_token() == &Token::Whitespace {
+        while self.next_token() == Token::Whitespace {
             self.discard_a_token();
         }
     }
@@ -97,7 +148,7 @@ impl TokenStream {
         A: FnMut(&Token) -> ProcessResult<R>,
     {
         loop {
-            match action(self.next_token()) {
+            match action(&self.next_token()) {
                 ProcessResult::Return(result) => return result,
                 ProcessResult::Continue => {}
             }
@@ -109,3 +160,103 @@ pub enum ProcessResult<T> {
     Continue,
     Return(T),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TokenStream;
+    use crate::css::tokenizer::{Token, Tokenizer};
+
+    fn ident(value: &str) -> Token {
+        Token::Ident {
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn nested_marks_restore_in_reverse_order() {
+        let stream = TokenStream::from_tokenizer(Tokenizer::new("a b c d"));
+
+        stream.mark();
+        assert_eq!(stream.consume_a_token(), ident("a"));
+        stream.discard_whitespace();
+
+        stream.mark();
+        assert_eq!(stream.consume_a_token(), ident("b"));
+        stream.discard_whitespace();
+        assert_eq!(stream.consume_a_token(), ident("c"));
+
+        // NOTE: The inner mark goes back to b, the outer one to a.
+        stream.restore_a_mark();
+        assert_eq!(stream.next_token(), ident("b"));
+        stream.restore_a_mark();
+        assert_eq!(stream.consume_a_token(), ident("a"));
+    }
+
+    #[test]
+    fn discarding_an_inner_mark_keeps_the_outer_one() {
+        let stream = TokenStream::new(Tokenizer::new("a b c").collect());
+
+        stream.mark();
+        stream.discard_a_token();
+        stream.mark();
+        stream.discard_a_token();
+        stream.discard_a_mark();
+        assert_eq!(stream.consume_a_token(), ident("b"));
+
+        stream.restore_a_mark();
+        assert_eq!(stream.next_token(), ident("a"));
+    }
+
+    #[test]
+    fn restore_after_a_failed_alternative() {
+        let stream = TokenStream::from_tokenizer(Tokenizer::new("a b; c"));
+
+        // NOTE: Try to consume an ident followed by a colon, like the start of a declaration.
+        stream.mark();
+        assert_eq!(stream.consume_a_token(), ident("a"));
+        stream.discard_whitespace();
+        assert_ne!(stream.next_token(), Token::Colon);
+        stream.restore_a_mark();
+
+        // NOTE: The tokens of the failed attempt are still there for the next one, even though
+        //       they were pulled from the tokenizer while the mark was set.
+        let mut tokens = vec![];
+        while !stream.empty() {
+            tokens.push(stream.consume_a_token());
+        }
+        assert_eq!(
+            tokens,
+            [
+                ident("a"),
+                Token::Whitespace,
+                ident("b"),
+                Token::Semicolon,
+                Token::Whitespace,
+                ident("c")
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizer_and_token_list_parse_the_same() {
+        use crate::css::parser::Parser;
+
+        let css = r#"
+            @charset "utf-8";
+            @import url("base.css") screen;
+            /* Layout */
+            :root { --gutter: 1.5rem; --empty:; }
+            body { margin: 0; font: 400 16px/1.5 "Helvetica Neue", sans-serif }
+            .card > h2:not([lang="en"]), a:hover { color: #0d6efd !important; padding: calc(var(--gutter) * 2) }
+            @media (min-width: 600px) { .card { float: left; width: 50% } }
+            @font-face { font-family: Inter; src: url(inter.woff2) format("woff2"), local(Inter); unicode-range: U+0000-00FF }
+            p { background: url(a b); color: red }
+        "#;
+
+        let from_tokenizer = TokenStream::from_tokenizer(Tokenizer::new(css));
+        let from_tokens = TokenStream::new(Tokenizer::new(css).tokenize());
+        let stylesheet = Parser::parse_a_stylesheet(&from_tokenizer, None);
+        assert_eq!(stylesheet.rules.len(), 8);
+        assert_eq!(stylesheet, Parser::parse_a_stylesheet(&from_tokens, None));
+    }
+}
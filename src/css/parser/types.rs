@@ -1,21 +1,75 @@
+use crate::css::origin::Origin;
 use crate::css::tokenizer::Token;
 
 /// https://drafts.csswg.org/css-syntax-3/#css-stylesheet
 #[derive(Debug, Clone, PartialEq)]
 pub struct StyleSheet {
     pub location: Option<String>,
-    pub rules: Vec<QualifiedRule>,
+    /// The encoding label declared by a leading `@charset` rule, if there is one.
+    ///
+    /// https://drafts.csswg.org/css-syntax-3/#charset-rule
+    pub charset: Option<String>,
+    pub rules: Vec<Rule>,
 }
 
 impl StyleSheet {
     pub fn new(location: Option<&str>) -> Self {
         Self {
             location: location.map(|s| s.to_string()),
+            charset: None,
             rules: vec![],
         }
     }
+
+    /// Marks every rule in this stylesheet as coming from `origin`.
+    ///
+    /// NOTE: The parser doesn't know where a stylesheet comes from, so all parsed rules are
+    ///       author rules until this is called.
+    pub fn set_origin(&mut self, origin: Origin) {
+        fn set_origin_of_rules(rules: &mut [Rule], origin: Origin) {
+            for rule in rules {
+                match rule {
+                    Rule::AtRule(rule) => {
+                        rule.origin = origin;
+                        set_origin_of_rules(&mut rule.child_rules, origin);
+                    }
+                    Rule::QualifiedRule(rule) => {
+                        rule.origin = origin;
+                        set_origin_of_rules(&mut rule.child_rules, origin);
+                    }
+                }
+            }
+        }
+
+        set_origin_of_rules(&mut self.rules, origin);
+    }
+
+    /// Appends the rules of `other` after the rules of this stylesheet.
+    ///
+    /// Every rule keeps its own origin, so the cascade still orders the merged rules by origin
+    /// first, and only falls back to the order of appearance within the same origin.
+    pub fn merge(&mut self, other: &StyleSheet) {
+        self.rules.extend(other.rules.iter().cloned());
+    }
+}
+
+/// The error a parser entry point returns when the input doesn't match what it parses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssParseError {
+    /// https://drafts.csswg.org/css-syntax-3/#css-syntax-error
+    SyntaxError,
+}
+
+impl std::fmt::Display for CssParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssParseError::SyntaxError => write!(f, "syntax error"),
+        }
+    }
 }
 
+impl std::error::Error for CssParseError {}
+
 /// https://drafts.csswg.org/css-syntax-3/#css-rule
 #[derive(Debug, Clone, PartialEq)]
 pub enum Rule {
@@ -26,7 +80,11 @@ pub enum Rule {
 /// https://drafts.csswg.org/css-syntax-3/#at-rule
 #[derive(Debug, Clone, PartialEq)]
 pub struct AtRule {
-    // FIXME: Implement members
+    pub name: String,
+    pub prelude: Vec<ComponentValue>,
+    pub declarations: Vec<Declaration>,
+    pub child_rules: Vec<Rule>,
+    pub origin: Origin,
 }
 
 /// https://www.w3.org/TR/css-syntax-3/#qualified-rule
@@ -34,7 +92,8 @@ pub struct AtRule {
 pub struct QualifiedRule {
     pub prelude: Vec<ComponentValue>,
     pub declarations: Vec<Declaration>,
-    pub child_rules: Vec<QualifiedRule>,
+    pub child_rules: Vec<Rule>,
+    pub origin: Origin,
 }
 
 /// https://drafts.csswg.org/css-syntax-3/#declaration
@@ -56,6 +115,35 @@ pub enum ComponentValue {
     SimpleBlock(SimpleBlock),
 }
 
+impl ComponentValue {
+    /// Turns the component value back into the tokens it was consumed from,
+    /// so it can be fed into a [`TokenStream`](super::token_streams::TokenStream) again.
+    pub fn to_tokens(&self) -> Vec<Token> {
+        match self {
+            ComponentValue::PreservedToken(token) => vec![token.clone()],
+            ComponentValue::Function(function) => {
+                let mut tokens = vec![Token::Function {
+                    value: function.name.clone(),
+                }];
+                tokens.extend(function.value.iter().flat_map(ComponentValue::to_tokens));
+                tokens.push(Token::RightParenthesis);
+                tokens
+            }
+            ComponentValue::SimpleBlock(block) => {
+                let ending_token = match block.associated_token {
+                    Token::LeftCurlyBracket => Token::RightCurlyBracket,
+                    Token::LeftSquareBracket => Token::RightSquareBracket,
+                    _ => Token::RightParenthesis,
+                };
+                let mut tokens = vec![block.associated_token.clone()];
+                tokens.extend(block.values.iter().flat_map(ComponentValue::to_tokens));
+                tokens.push(ending_token);
+                tokens
+            }
+        }
+    }
+}
+
 /// https://drafts.csswg.org/css-syntax-3/#function
 #[derive(Debug, Clone, PartialEq)]
 pub struct Function {
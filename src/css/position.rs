@@ -0,0 +1,29 @@
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+
+/// https://www.w3.org/TR/CSS2/visuren.html#choose-position
+///
+/// FIXME: Support `fixed` and `sticky` positioning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Position {
+    #[default]
+    Static,
+    Relative,
+    Absolute,
+}
+
+impl Position {
+    /// Parses a single `position` keyword.
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        let ComponentValue::PreservedToken(Token::Ident { value }) = value else {
+            return None;
+        };
+
+        match value.to_ascii_lowercase().as_str() {
+            "static" => Some(Self::Static),
+            "relative" => Some(Self::Relative),
+            "absolute" => Some(Self::Absolute),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,602 @@
+use crate::css::parser::types::{ComponentValue, SimpleBlock};
+use crate::css::tokenizer::{HashType, Token};
+use crate::dom::NodeRef;
+use crate::html::Namespace;
+
+/// https://drafts.csswg.org/selectors-4/#typedef-selector-list
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectorList {
+    pub selectors: Vec<ComplexSelector>,
+}
+
+/// https://drafts.csswg.org/selectors-4/#typedef-complex-selector
+///
+/// The compound selectors are stored from left to right, each with the combinator that
+/// connects it to the compound selector on its left.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplexSelector {
+    pub first: CompoundSelector,
+    pub rest: Vec<(Combinator, CompoundSelector)>,
+}
+
+/// https://drafts.csswg.org/selectors-4/#specificity
+///
+/// The number of id selectors, the number of class, attribute and pseudo-class selectors,
+/// and the number of type selectors, in that order. Specificities are compared lexicographically.
+pub type Specificity = (u32, u32, u32);
+
+/// https://drafts.csswg.org/selectors-4/#typedef-combinator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    /// https://drafts.csswg.org/selectors-4/#descendant-combinators
+    Descendant,
+    /// https://drafts.csswg.org/selectors-4/#child-combinators
+    Child,
+    /// https://drafts.csswg.org/selectors-4/#adjacent-sibling-combinators
+    NextSibling,
+    /// https://drafts.csswg.org/selectors-4/#general-sibling-combinators
+    SubsequentSibling,
+}
+
+/// https://drafts.csswg.org/selectors-4/#typedef-compound-selector
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompoundSelector {
+    pub type_selector: Option<TypeSelector>,
+    pub subclass_selectors: Vec<SubclassSelector>,
+}
+
+/// https://drafts.csswg.org/selectors-4/#typedef-type-selector
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeSelector {
+    Universal,
+    Name(String),
+}
+
+/// https://drafts.csswg.org/selectors-4/#typedef-subclass-selector
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubclassSelector {
+    Id(String),
+    Class(String),
+    Attribute(AttributeSelector),
+    PseudoClass(PseudoClass),
+}
+
+/// https://drafts.csswg.org/selectors-4/#typedef-attribute-selector
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeSelector {
+    pub name: String,
+    pub matcher: Option<(AttributeMatcher, String)>,
+    pub case_insensitive: bool,
+}
+
+/// https://drafts.csswg.org/selectors-4/#typedef-attr-matcher
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeMatcher {
+    /// [att=val]
+    Equals,
+    /// [att~=val]
+    Includes,
+    /// [att|=val]
+    DashMatch,
+    /// [att^=val]
+    Prefix,
+    /// [att$=val]
+    Suffix,
+    /// [att*=val]
+    Substring,
+}
+
+/// https://drafts.csswg.org/selectors-4/#pseudo-classes
+#[derive(Debug, Clone, PartialEq)]
+pub enum PseudoClass {
+    /// https://drafts.csswg.org/selectors-4/#negation
+    ///
+    /// FIXME: The argument should be a complex selector list, we only support compound selectors for now.
+    Not(Vec<CompoundSelector>),
+}
+
+/// The reason a selector list failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorParseError {
+    /// One of the selectors in the list is empty, like the middle one in `a,,b`.
+    EmptySelector,
+    /// One of the selectors in the list is not a valid <complex-selector>.
+    InvalidSelector,
+}
+
+impl std::fmt::Display for SelectorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectorParseError::EmptySelector => write!(f, "empty selector"),
+            SelectorParseError::InvalidSelector => write!(f, "invalid selector"),
+        }
+    }
+}
+
+impl std::error::Error for SelectorParseError {}
+
+impl SelectorList {
+    /// <selector-list> = <complex-selector-list>
+    ///
+    /// Returns `None` if any of the selectors is invalid, which makes the whole list invalid.
+    pub fn parse(values: &[ComponentValue]) -> Option<Self> {
+        Self::try_parse(values).ok()
+    }
+
+    /// Like [`SelectorList::parse`], but returns why the list is invalid.
+    pub fn try_parse(values: &[ComponentValue]) -> Result<Self, SelectorParseError> {
+        let selectors = split_on_commas(values)
+            .into_iter()
+            .map(|values| {
+                if trim_whitespace(values).is_empty() {
+                    return Err(SelectorParseError::EmptySelector);
+                }
+                ComplexSelector::parse(values).ok_or(SelectorParseError::InvalidSelector)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { selectors })
+    }
+
+    /// Whether any of the selectors in the list matches `element`.
+    pub fn matches(&self, element: NodeRef) -> bool {
+        self.selectors
+            .iter()
+            .any(|selector| selector.matches(element))
+    }
+}
+
+impl ComplexSelector {
+    /// <complex-selector> = <compound-selector> [ <combinator>? <compound-selector> ]*
+    pub fn parse(values: &[ComponentValue]) -> Option<Self> {
+        let mut values = trim_whitespace(values);
+
+        let first = CompoundSelector::consume(&mut values)?;
+        let mut rest = vec![];
+
+        while !values.is_empty() {
+            let mut combinator = Combinator::Descendant;
+            values = trim_leading_whitespace(values);
+            if let Some((ComponentValue::PreservedToken(Token::Delim { value }), tail)) =
+                values.split_first()
+            {
+                let explicit_combinator = match value {
+                    '>' => Some(Combinator::Child),
+                    '+' => Some(Combinator::NextSibling),
+                    '~' => Some(Combinator::SubsequentSibling),
+                    _ => None,
+                };
+                if let Some(explicit_combinator) = explicit_combinator {
+                    combinator = explicit_combinator;
+                    values = trim_leading_whitespace(tail);
+                }
+            }
+
+            rest.push((combinator, CompoundSelector::consume(&mut values)?));
+        }
+
+        Some(Self { first, rest })
+    }
+
+    /// https://drafts.csswg.org/selectors-4/#specificity-rules
+    pub fn specificity(&self) -> Specificity {
+        std::iter::once(&self.first)
+            .chain(self.rest.iter().map(|(_, compound)| compound))
+            .map(CompoundSelector::specificity)
+            .fold((0, 0, 0), add_specificities)
+    }
+
+    /// https://drafts.csswg.org/selectors-4/#match-a-complex-selector-against-an-element
+    pub fn matches(&self, element: NodeRef) -> bool {
+        // NOTE: Complex selectors are evaluated from right to left,
+        //       starting with the rightmost compound selector against the element itself.
+        let mut compounds = vec![&self.first];
+        compounds.extend(self.rest.iter().map(|(_, compound)| compound));
+        let combinators = self
+            .rest
+            .iter()
+            .map(|(combinator, _)| *combinator)
+            .collect::<Vec<_>>();
+
+        matches_from(&compounds, &combinators, compounds.len() - 1, element)
+    }
+}
+
+/// Whether the compound selectors up to and including `index` match, with `compounds[index]` matching `element`.
+fn matches_from(
+    compounds: &[&CompoundSelector],
+    combinators: &[Combinator],
+    index: usize,
+    element: NodeRef,
+) -> bool {
+    if !compounds[index].matches(element) {
+        return false;
+    }
+
+    if index == 0 {
+        return true;
+    }
+
+    match combinators[index - 1] {
+        Combinator::Child => element
+            .parent_element()
+            .is_some_and(|parent| matches_from(compounds, combinators, index - 1, parent)),
+        Combinator::Descendant => {
+            let mut ancestor = element.parent_element();
+            while let Some(current) = ancestor {
+                if matches_from(compounds, combinators, index - 1, current) {
+                    return true;
+                }
+                ancestor = current.parent_element();
+            }
+            false
+        }
+        Combinator::NextSibling => element
+            .previous_element_sibling()
+            .is_some_and(|sibling| matches_from(compounds, combinators, index - 1, sibling)),
+        Combinator::SubsequentSibling => {
+            let mut sibling = element.previous_element_sibling();
+            while let Some(current) = sibling {
+                if matches_from(compounds, combinators, index - 1, current) {
+                    return true;
+                }
+                sibling = current.previous_element_sibling();
+            }
+            false
+        }
+    }
+}
+
+impl CompoundSelector {
+    /// <compound-selector> = [ <type-selector>? <subclass-selector>* ]!
+    ///
+    /// Consumes the compound selector from the start of `values`, up to the next whitespace or combinator.
+    fn consume(values: &mut &[ComponentValue]) -> Option<Self> {
+        let mut compound = CompoundSelector::default();
+
+        match values.first() {
+            Some(ComponentValue::PreservedToken(Token::Ident { value })) => {
+                compound.type_selector = Some(TypeSelector::Name(value.clone()));
+                *values = &values[1..];
+            }
+            Some(ComponentValue::PreservedToken(Token::Delim { value: '*' })) => {
+                compound.type_selector = Some(TypeSelector::Universal);
+                *values = &values[1..];
+            }
+            _ => {}
+        }
+
+        loop {
+            let subclass_selector = match *values {
+                [ComponentValue::PreservedToken(Token::Hash {
+                    value,
+                    hash_type: HashType::Id,
+                }), ..] => {
+                    *values = &values[1..];
+                    SubclassSelector::Id(value.clone())
+                }
+                [ComponentValue::PreservedToken(Token::Delim { value: '.' }), ComponentValue::PreservedToken(Token::Ident { value }), ..] =>
+                {
+                    *values = &values[2..];
+                    SubclassSelector::Class(value.clone())
+                }
+                [ComponentValue::SimpleBlock(block), ..]
+                    if block.associated_token == Token::LeftSquareBracket =>
+                {
+                    *values = &values[1..];
+                    SubclassSelector::Attribute(AttributeSelector::parse(block)?)
+                }
+                [ComponentValue::PreservedToken(Token::Colon), pseudo_class, ..] => {
+                    *values = &values[2..];
+                    SubclassSelector::PseudoClass(PseudoClass::parse(pseudo_class)?)
+                }
+                [] | [ComponentValue::PreservedToken(Token::Whitespace), ..] => break,
+                [ComponentValue::PreservedToken(Token::Delim {
+                    value: '>' | '+' | '~',
+                }), ..] => break,
+                _ => return None,
+            };
+            compound.subclass_selectors.push(subclass_selector);
+        }
+
+        if compound.type_selector.is_none() && compound.subclass_selectors.is_empty() {
+            return None;
+        }
+
+        Some(compound)
+    }
+
+    fn specificity(&self) -> Specificity {
+        // SPEC: count the number of type selectors and pseudo-elements in the selector (= C)
+        //       Ignore the universal selector.
+        let type_specificity = match self.type_selector {
+            Some(TypeSelector::Name(_)) => (0, 0, 1),
+            Some(TypeSelector::Universal) | None => (0, 0, 0),
+        };
+
+        self.subclass_selectors
+            .iter()
+            .map(|selector| match selector {
+                // SPEC: count the number of ID selectors in the selector (= A)
+                SubclassSelector::Id(_) => (1, 0, 0),
+                // SPEC: count the number of class selectors, attributes selectors, and pseudo-classes in the selector (= B)
+                SubclassSelector::Class(_) | SubclassSelector::Attribute(_) => (0, 1, 0),
+                // SPEC: The specificity of an :is(), :not(), or :has() pseudo-class is replaced by
+                //       the specificity of the most specific complex selector in its selector list argument.
+                SubclassSelector::PseudoClass(PseudoClass::Not(compounds)) => compounds
+                    .iter()
+                    .map(CompoundSelector::specificity)
+                    .max()
+                    .unwrap_or((0, 0, 0)),
+            })
+            .fold(type_specificity, add_specificities)
+    }
+
+    fn matches(&self, element: NodeRef) -> bool {
+        let Some(element_data) = element.as_element() else {
+            return false;
+        };
+
+        let type_matches = match &self.type_selector {
+            None | Some(TypeSelector::Universal) => true,
+            Some(TypeSelector::Name(name)) => match element_data.namespace() {
+                // NOTE: Type selectors are ASCII case-insensitive for elements in the HTML namespace.
+                Namespace::Html => name.eq_ignore_ascii_case(&element_data.local_name()),
+                _ => *name == element_data.local_name(),
+            },
+        };
+
+        type_matches
+            && self
+                .subclass_selectors
+                .iter()
+                .all(|selector| selector.matches(element))
+    }
+}
+
+impl SubclassSelector {
+    fn matches(&self, element: NodeRef) -> bool {
+        let Some(element_data) = element.as_element() else {
+            return false;
+        };
+
+        // FIXME: Ids and classes should be matched ASCII case-insensitively in quirks mode.
+        match self {
+            SubclassSelector::Id(id) => element_data.get_attribute("id").as_deref() == Some(id),
+            SubclassSelector::Class(class) => element_data.class_list().contains(class),
+            SubclassSelector::Attribute(attribute) => attribute.matches(element),
+            SubclassSelector::PseudoClass(pseudo_class) => pseudo_class.matches(element),
+        }
+    }
+}
+
+impl AttributeSelector {
+    /// <attribute-selector> = '[' <wq-name> ']' |
+    ///                        '[' <wq-name> <attr-matcher> [ <string-token> | <ident-token> ] <attr-modifier>? ']'
+    ///
+    /// FIXME: Namespace prefixes are not supported.
+    fn parse(block: &SimpleBlock) -> Option<Self> {
+        let values = block
+            .values
+            .iter()
+            .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+            .collect::<Vec<_>>();
+
+        let (name, rest) = match values.split_first()? {
+            (ComponentValue::PreservedToken(Token::Ident { value }), rest) => (value.clone(), rest),
+            _ => return None,
+        };
+
+        let delim = |value: &ComponentValue| match value {
+            ComponentValue::PreservedToken(Token::Delim { value }) => Some(*value),
+            _ => None,
+        };
+
+        let (matcher, rest) = match rest {
+            [] => {
+                return Some(Self {
+                    name,
+                    matcher: None,
+                    case_insensitive: false,
+                })
+            }
+            [first, rest @ ..] if delim(first) == Some('=') => (AttributeMatcher::Equals, rest),
+            [first, second, rest @ ..] if delim(second) == Some('=') => {
+                let matcher = match delim(first)? {
+                    '~' => AttributeMatcher::Includes,
+                    '|' => AttributeMatcher::DashMatch,
+                    '^' => AttributeMatcher::Prefix,
+                    '$' => AttributeMatcher::Suffix,
+                    '*' => AttributeMatcher::Substring,
+                    _ => return None,
+                };
+                (matcher, rest)
+            }
+            _ => return None,
+        };
+
+        let (value, rest) = match rest.split_first()? {
+            (
+                ComponentValue::PreservedToken(Token::String { value } | Token::Ident { value }),
+                rest,
+            ) => (value.clone(), rest),
+            _ => return None,
+        };
+
+        // <attr-modifier> = i | s
+        let case_insensitive = match rest {
+            [] => false,
+            [ComponentValue::PreservedToken(Token::Ident { value })] => {
+                match value.to_ascii_lowercase().as_str() {
+                    "i" => true,
+                    "s" => false,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            name,
+            matcher: Some((matcher, value)),
+            case_insensitive,
+        })
+    }
+
+    fn matches(&self, element: NodeRef) -> bool {
+        // FIXME: Attribute names should be matched ASCII case-insensitively for elements in the HTML namespace.
+        let Some(attribute_value) = element
+            .as_element()
+            .and_then(|element| element.get_attribute(&self.name))
+        else {
+            return false;
+        };
+
+        let Some((matcher, value)) = &self.matcher else {
+            return true;
+        };
+
+        let (attribute_value, value) = if self.case_insensitive {
+            (
+                attribute_value.to_ascii_lowercase(),
+                value.to_ascii_lowercase(),
+            )
+        } else {
+            (attribute_value, value.clone())
+        };
+
+        match matcher {
+            AttributeMatcher::Equals => attribute_value == value,
+            AttributeMatcher::Includes => {
+                !value.is_empty()
+                    && attribute_value
+                        .split_ascii_whitespace()
+                        .any(|word| word == value)
+            }
+            AttributeMatcher::DashMatch => {
+                attribute_value == value || attribute_value.starts_with(&format!("{}-", value))
+            }
+            AttributeMatcher::Prefix => !value.is_empty() && attribute_value.starts_with(&value),
+            AttributeMatcher::Suffix => !value.is_empty() && attribute_value.ends_with(&value),
+            AttributeMatcher::Substring => !value.is_empty() && attribute_value.contains(&value),
+        }
+    }
+}
+
+impl PseudoClass {
+    fn parse(value: &ComponentValue) -> Option<Self> {
+        match value {
+            ComponentValue::Function(function) if function.name.eq_ignore_ascii_case("not") => {
+                let compounds = split_on_commas(&function.value)
+                    .into_iter()
+                    .map(|values| {
+                        let mut values = trim_whitespace(values);
+                        let compound = CompoundSelector::consume(&mut values)?;
+                        values.is_empty().then_some(compound)
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Self::Not(compounds))
+            }
+            // FIXME: Support other pseudo-classes.
+            _ => None,
+        }
+    }
+
+    fn matches(&self, element: NodeRef) -> bool {
+        match self {
+            // SPEC: The negation pseudo-class, :not(), is a functional pseudo-class taking a selector list as an argument.
+            //       It represents an element that is not represented by its argument.
+            PseudoClass::Not(compounds) => {
+                !compounds.iter().any(|compound| compound.matches(element))
+            }
+        }
+    }
+}
+
+fn add_specificities(a: Specificity, b: Specificity) -> Specificity {
+    (a.0 + b.0, a.1 + b.1, a.2 + b.2)
+}
+
+fn split_on_commas(values: &[ComponentValue]) -> Vec<&[ComponentValue]> {
+    values
+        .split(|value| *value == ComponentValue::PreservedToken(Token::Comma))
+        .collect()
+}
+
+fn trim_leading_whitespace(values: &[ComponentValue]) -> &[ComponentValue] {
+    let start = values
+        .iter()
+        .position(|value| *value != ComponentValue::PreservedToken(Token::Whitespace))
+        .unwrap_or(values.len());
+    &values[start..]
+}
+
+fn trim_whitespace(values: &[ComponentValue]) -> &[ComponentValue] {
+    let values = trim_leading_whitespace(values);
+    let end = values
+        .iter()
+        .rposition(|value| *value != ComponentValue::PreservedToken(Token::Whitespace))
+        .map_or(0, |index| index + 1);
+    &values[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::parser::Parser as CssParser;
+    use crate::html::parser::Parser;
+
+    /// The serialized elements in the body of `html` that `selector` matches, in tree order.
+    fn matching(selector: &str, html: &str) -> Vec<String> {
+        let selector = SelectorList::parse(&CssParser::parse_a_list_of_component_values(selector))
+            .expect("selector should be valid");
+        let parser = Parser::new(typed_arena::Arena::new(), html);
+        let document = parser.parse();
+        let body = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("body"))
+            .unwrap();
+        let matches = body
+            .descendants()
+            .filter(|node| node.is_element() && selector.matches(node))
+            .map(|node| node.serialize_html())
+            .collect();
+        matches
+    }
+
+    #[test]
+    fn negation() {
+        let html = r#"<div>a</div><div class="x">b</div><div class="y x">c</div><p>d</p>"#;
+        assert_eq!(matching("div:not(.x)", html), ["<div>a</div>"]);
+        assert_eq!(matching(":not(div)", html), ["<p>d</p>"]);
+        assert_eq!(matching("div:not(.y, [class=x])", html), ["<div>a</div>"]);
+        assert_eq!(matching("div:not(.x.y)", html).len(), 2);
+    }
+
+    #[test]
+    fn negation_only_accepts_compound_selectors() {
+        for selector in ["div:not(p > a)", "div:not()", "div:not(:hover)"] {
+            assert_eq!(
+                SelectorList::parse(&CssParser::parse_a_list_of_component_values(selector)),
+                None,
+                "{selector} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn sibling_combinators() {
+        let html = "<h2>t</h2><p>a</p><p>b</p>";
+        assert_eq!(matching("h2 + p", html), ["<p>a</p>"]);
+        assert_eq!(matching("h2 ~ p", html), ["<p>a</p>", "<p>b</p>"]);
+        assert_eq!(matching("p + p", html), ["<p>b</p>"]);
+        assert_eq!(matching("p ~ h2", html), Vec::<String>::new());
+    }
+
+    #[test]
+    fn sibling_combinators_skip_non_element_siblings() {
+        let html = "<h2>t</h2> text <!-- c --><p>a</p><div></div><p>b</p>";
+        assert_eq!(matching("h2 + p", html), ["<p>a</p>"]);
+        assert_eq!(matching("h2 ~ p", html), ["<p>a</p>", "<p>b</p>"]);
+        assert_eq!(matching("h2 + p + div ~ p", html), ["<p>b</p>"]);
+    }
+}
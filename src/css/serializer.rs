@@ -0,0 +1,509 @@
+use std::fmt::Write;
+
+use crate::css::parser::types::{
+    AtRule, ComponentValue, Declaration, QualifiedRule, Rule, StyleSheet,
+};
+use crate::css::tokenizer::{HashType, NumberType, Token};
+
+/// Writes a parsed CSS construct back out as CSS text.
+///
+/// Parsing the serialized text again produces the same construct.
+///
+/// https://drafts.csswg.org/css-syntax-3/#serialization
+pub trait ToCss {
+    fn to_css(&self, dest: &mut String);
+
+    fn to_css_string(&self) -> String {
+        let mut dest = String::new();
+        self.to_css(&mut dest);
+        dest
+    }
+}
+
+impl ToCss for Token {
+    fn to_css(&self, dest: &mut String) {
+        match self {
+            Token::EndOfFile => {}
+            Token::Ident { value } => serialize_an_identifier(value, dest),
+            Token::Function { value } => {
+                serialize_an_identifier(value, dest);
+                dest.push('(');
+            }
+            Token::AtKeyword { value } => {
+                dest.push('@');
+                serialize_an_identifier(value, dest);
+            }
+            Token::Hash { value, hash_type } => {
+                dest.push('#');
+                match hash_type {
+                    HashType::Id => serialize_an_identifier(value, dest),
+                    HashType::Unrestricted => serialize_a_name(value, dest),
+                }
+            }
+            Token::String { value } => serialize_a_string(value, dest),
+            // NOTE: A newline ends a string without ending the rest of the declaration.
+            Token::BadString => dest.push_str("\"\n"),
+            Token::Url { value } => {
+                dest.push_str("url(");
+                for character in value.chars() {
+                    match character {
+                        '\n' | '\t' | ' ' | '"' | '\'' | '(' | ')' | '\\' => {
+                            serialize_as_code_point_escape(character, dest)
+                        }
+                        character if is_non_printable(character) => {
+                            serialize_as_code_point_escape(character, dest)
+                        }
+                        character => dest.push(character),
+                    }
+                }
+                dest.push(')');
+            }
+            // NOTE: An opening parenthesis inside a url makes it a <bad-url-token>.
+            Token::BadUrl => dest.push_str("url(())"),
+            // NOTE: A delim-token can only contain a U+005C REVERSE SOLIDUS (\) if it is followed by a newline.
+            Token::Delim { value: '\\' } => dest.push_str("\\\n"),
+            Token::Delim { value } => dest.push(*value),
+            Token::Number {
+                value,
+                sign,
+                number_type,
+                ..
+            } => serialize_a_number(*value, *sign, number_type, dest),
+            Token::Percentage { value, sign } => {
+                serialize_a_number(*value, *sign, &NumberType::Integer, dest);
+                dest.push('%');
+            }
+            Token::Dimension {
+                value,
+                sign,
+                number_type,
+                unit,
+                ..
+            } => {
+                serialize_a_number(*value, *sign, number_type, dest);
+
+                // If the unit starts like an exponent, escape its first code point
+                // so that it doesn't get consumed as part of the number.
+                let mut chars = unit.chars();
+                let starts_like_exponent = matches!(chars.next(), Some('e' | 'E'))
+                    && match chars.next() {
+                        Some('0'..='9') => true,
+                        Some('+' | '-') => matches!(chars.next(), Some('0'..='9')),
+                        _ => false,
+                    };
+                if starts_like_exponent {
+                    let (first, rest) = unit.split_at(1);
+                    serialize_as_code_point_escape(first.chars().next().unwrap(), dest);
+                    serialize_a_name(rest, dest);
+                } else {
+                    serialize_an_identifier(unit, dest);
+                }
+            }
+            Token::UnicodeRange { start, end } => {
+                let _ = write!(dest, "U+{:X}", start);
+                if start != end {
+                    let _ = write!(dest, "-{:X}", end);
+                }
+            }
+            Token::Whitespace => dest.push(' '),
+            Token::Cdo => dest.push_str("<!--"),
+            Token::Cdc => dest.push_str("-->"),
+            Token::Colon => dest.push(':'),
+            Token::Semicolon => dest.push(';'),
+            Token::Comma => dest.push(','),
+            Token::LeftSquareBracket => dest.push('['),
+            Token::RightSquareBracket => dest.push(']'),
+            Token::LeftParenthesis => dest.push('('),
+            Token::RightParenthesis => dest.push(')'),
+            Token::LeftCurlyBracket => dest.push('{'),
+            Token::RightCurlyBracket => dest.push('}'),
+        }
+    }
+}
+
+impl ToCss for [Token] {
+    fn to_css(&self, dest: &mut String) {
+        let mut previous: Option<&Token> = None;
+        for token in self {
+            // For any consecutive pair of tokens, if the first token shows up in the row headings of
+            // the serialization table and the second token shows up in the column headings, and there’s a ✗
+            // in the cell denoted by the intersection of the chosen row and column, the pair of tokens must be
+            // serialized with a comment between them.
+            if previous.is_some_and(|previous| needs_comment_between(previous, token)) {
+                dest.push_str("/**/");
+            }
+            token.to_css(dest);
+            previous = Some(token);
+        }
+    }
+}
+
+impl ToCss for [ComponentValue] {
+    fn to_css(&self, dest: &mut String) {
+        self.iter()
+            .flat_map(ComponentValue::to_tokens)
+            .collect::<Vec<_>>()
+            .to_css(dest);
+    }
+}
+
+impl ToCss for ComponentValue {
+    fn to_css(&self, dest: &mut String) {
+        self.to_tokens().to_css(dest);
+    }
+}
+
+impl ToCss for Declaration {
+    fn to_css(&self, dest: &mut String) {
+        serialize_an_identifier(&self.name, dest);
+        dest.push(':');
+        if !self.value.is_empty() {
+            dest.push(' ');
+            self.value.to_css(dest);
+        }
+        if self.important {
+            dest.push_str(" !important");
+        }
+    }
+}
+
+impl ToCss for QualifiedRule {
+    fn to_css(&self, dest: &mut String) {
+        self.prelude.to_css(dest);
+        serialize_a_block(&self.declarations, &self.child_rules, dest);
+    }
+}
+
+impl ToCss for AtRule {
+    fn to_css(&self, dest: &mut String) {
+        dest.push('@');
+        serialize_an_identifier(&self.name, dest);
+        self.prelude.to_css(dest);
+
+        // FIXME: We don't remember whether the at-rule had a block, so we assume that an at-rule
+        //        without any declarations or child rules ended with a semicolon.
+        if self.declarations.is_empty() && self.child_rules.is_empty() {
+            dest.push(';');
+            return;
+        }
+        serialize_a_block(&self.declarations, &self.child_rules, dest);
+    }
+}
+
+impl ToCss for Rule {
+    fn to_css(&self, dest: &mut String) {
+        match self {
+            Rule::AtRule(at_rule) => at_rule.to_css(dest),
+            Rule::QualifiedRule(qualified_rule) => qualified_rule.to_css(dest),
+        }
+    }
+}
+
+impl ToCss for StyleSheet {
+    fn to_css(&self, dest: &mut String) {
+        if let Some(charset) = &self.charset {
+            dest.push_str("@charset ");
+            serialize_a_string(charset, dest);
+            dest.push(';');
+            if !self.rules.is_empty() {
+                dest.push('\n');
+            }
+        }
+
+        for (index, rule) in self.rules.iter().enumerate() {
+            if index > 0 {
+                dest.push('\n');
+            }
+            rule.to_css(dest);
+        }
+    }
+}
+
+fn serialize_a_block(declarations: &[Declaration], child_rules: &[Rule], dest: &mut String) {
+    if declarations.is_empty() && child_rules.is_empty() {
+        dest.push_str("{}");
+        return;
+    }
+
+    dest.push('{');
+    for declaration in declarations {
+        dest.push(' ');
+        declaration.to_css(dest);
+        dest.push(';');
+    }
+    for rule in child_rules {
+        dest.push(' ');
+        rule.to_css(dest);
+    }
+    dest.push_str(" }");
+}
+
+/// https://drafts.csswg.org/css-syntax-3/#serialization
+fn needs_comment_between(first: &Token, second: &Token) -> bool {
+    let is_delim = |token: &Token, value: char| token == &Token::Delim { value };
+
+    // The columns shared by most rows: ident, function, url, bad url, -, number, percentage, dimension.
+    let ident_like_or_numeric = matches!(
+        second,
+        Token::Ident { .. }
+            | Token::Function { .. }
+            | Token::Url { .. }
+            | Token::BadUrl
+            | Token::Number { .. }
+            | Token::Percentage { .. }
+            | Token::Dimension { .. }
+    ) || is_delim(second, '-');
+
+    match first {
+        Token::Ident { .. } => {
+            ident_like_or_numeric || matches!(second, Token::Cdc | Token::LeftParenthesis)
+        }
+        Token::AtKeyword { .. } | Token::Hash { .. } | Token::Dimension { .. } => {
+            ident_like_or_numeric || second == &Token::Cdc
+        }
+        Token::Delim { value: '#' | '-' } => ident_like_or_numeric,
+        Token::Number { .. } => ident_like_or_numeric || is_delim(second, '%'),
+        Token::Delim { value: '@' } => {
+            matches!(
+                second,
+                Token::Ident { .. }
+                    | Token::Function { .. }
+                    | Token::Url { .. }
+                    | Token::BadUrl
+                    | Token::Cdc
+            ) || is_delim(second, '-')
+        }
+        Token::Delim { value: '.' | '+' } => matches!(
+            second,
+            Token::Number { .. } | Token::Percentage { .. } | Token::Dimension { .. }
+        ),
+        Token::Delim { value: '/' } => is_delim(second, '*'),
+        _ => false,
+    }
+}
+
+fn serialize_a_number(value: f64, sign: Option<char>, number_type: &NumberType, dest: &mut String) {
+    if value.is_sign_negative() {
+        dest.push('-');
+    } else if sign == Some('+') {
+        dest.push('+');
+    }
+
+    let repr = value.abs().to_string();
+    dest.push_str(&repr);
+
+    // Keep the number a <number> if it has an integral value, instead of turning it into an integer.
+    if *number_type == NumberType::Number && !repr.contains('.') {
+        dest.push_str(".0");
+    }
+}
+
+/// https://drafts.csswg.org/cssom-1/#serialize-an-identifier
+fn serialize_an_identifier(identifier: &str, dest: &mut String) {
+    let mut chars = identifier.chars().peekable();
+    let first = chars.peek().copied();
+    let mut index = 0;
+
+    while let Some(character) = chars.next() {
+        match character {
+            // If the character is NULL (U+0000), then the REPLACEMENT CHARACTER (U+FFFD).
+            '\u{0000}' => dest.push('\u{FFFD}'),
+            // If the character is in the range [\1-\1f] (U+0001 to U+001F) or is U+007F,
+            // then the character escaped as code point.
+            '\u{0001}'..='\u{001F}' | '\u{007F}' => serialize_as_code_point_escape(character, dest),
+            // If the character is the first character and is in the range [0-9] (U+0030 to U+0039),
+            // then the character escaped as code point.
+            '0'..='9' if index == 0 => serialize_as_code_point_escape(character, dest),
+            // If the character is the second character and is in the range [0-9] (U+0030 to U+0039)
+            // and the first character is a "-" (U+002D), then the character escaped as code point.
+            '0'..='9' if index == 1 && first == Some('-') => {
+                serialize_as_code_point_escape(character, dest)
+            }
+            // If the character is the first character and is a "-" (U+002D),
+            // and there is no second character, then the escaped character.
+            '-' if index == 0 && chars.peek().is_none() => {
+                dest.push('\\');
+                dest.push(character);
+            }
+            // If the character is not handled by one of the above rules and is greater than or equal to U+0080,
+            // is "-" (U+002D) or "_" (U+005F), or is in one of the ranges [0-9] (U+0030 to U+0039),
+            // [A-Z] (U+0041 to U+005A), or [a-z] (U+0061 to U+007A), then the character itself.
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '\u{0080}'.. => dest.push(character),
+            // Otherwise, the escaped character.
+            _ => {
+                dest.push('\\');
+                dest.push(character);
+            }
+        }
+        index += 1;
+    }
+}
+
+/// Like serializing an identifier, but without the special cases for the start of an identifier,
+/// for values that don't have to start an ident sequence.
+fn serialize_a_name(name: &str, dest: &mut String) {
+    for character in name.chars() {
+        match character {
+            '\u{0000}' => dest.push('\u{FFFD}'),
+            '\u{0001}'..='\u{001F}' | '\u{007F}' => serialize_as_code_point_escape(character, dest),
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '\u{0080}'.. => dest.push(character),
+            _ => {
+                dest.push('\\');
+                dest.push(character);
+            }
+        }
+    }
+}
+
+/// https://drafts.csswg.org/cssom-1/#serialize-a-string
+fn serialize_a_string(string: &str, dest: &mut String) {
+    dest.push('"');
+    for character in string.chars() {
+        match character {
+            // If the character is NULL (U+0000), then the REPLACEMENT CHARACTER (U+FFFD).
+            '\u{0000}' => dest.push('\u{FFFD}'),
+            // If the character is in the range [\1-\1f] (U+0001 to U+001F) or is U+007F,
+            // the character escaped as code point.
+            '\u{0001}'..='\u{001F}' | '\u{007F}' => serialize_as_code_point_escape(character, dest),
+            // If the character is '"' (U+0022) or "\" (U+005C), the escaped character.
+            '"' | '\\' => {
+                dest.push('\\');
+                dest.push(character);
+            }
+            // Otherwise, the character itself.
+            _ => dest.push(character),
+        }
+    }
+    dest.push('"');
+}
+
+/// https://drafts.csswg.org/cssom-1/#escape-a-character-as-code-point
+fn serialize_as_code_point_escape(character: char, dest: &mut String) {
+    // To escape a character as code point means to create a string of "\" (U+005C), followed by the
+    // Unicode code point as the smallest possible number of hexadecimal digits in the range 0-9 a-f
+    // (U+0030 to U+0039 and U+0061 to U+0066) to represent the code point in base 16, followed by a single SPACE (U+0020).
+    let _ = write!(dest, "\\{:x} ", character as u32);
+}
+
+fn is_non_printable(character: char) -> bool {
+    matches!(
+        character,
+        '\u{0000}'..='\u{0008}' | '\u{000B}' | '\u{000E}'..='\u{001F}' | '\u{007F}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::parser::token_streams::TokenStream;
+    use crate::css::parser::Parser;
+    use crate::css::tokenizer::Tokenizer;
+
+    fn parse(css: &str) -> StyleSheet {
+        let input = TokenStream::from_tokenizer(Tokenizer::new(css));
+        Parser::parse_a_stylesheet(&input, None)
+    }
+
+    fn tokenize(css: &str) -> Vec<Token> {
+        let mut tokens = Tokenizer::new(css).tokenize();
+        tokens.retain(|token| *token != Token::EndOfFile);
+        tokens
+    }
+
+    const STYLESHEETS: &[&str] = &[
+        "",
+        "p { color: red }",
+        "@charset \"utf-8\";\nbody { margin: 0; padding: 0 }",
+        ":root { --primary: #0d6efd; --empty:; }",
+        "h1, h2 > .title:not([lang=\"en\"]) { font: bold 12.5px/1.5 \"Helvetica Neue\", sans-serif !important }",
+        "@media (min-width: 768px) { .container { max-width: 720px } .col { width: 50% } }",
+        "@import url(\"theme.css\") screen;\n@font-face { font-family: Sputnik; src: url(fonts/sputnik.woff2) }",
+        "a::before { content: \"\\2713  quote \\\" and \\\\ backslash\"; margin: -1e3px +.5em 1e-2% 0 }",
+        "#\\31 23, .\\@at, .a\\ b, .-\\-x { transform: rotate(-45deg) translate(calc(100% - 2em), 0) }",
+        "@supports (display: grid) and (not (display: inline-grid)) { div { display: grid } }",
+        "u { unicode-range: U+0025-00FF, U+4?? } .e { width: 1\\65 3px }",
+        "<!-- p { color: blue } -->",
+        "a { b: c; d { e: f } }",
+    ];
+
+    #[test]
+    fn parse_serialize_parse_round_trips() {
+        for css in STYLESHEETS {
+            let stylesheet = parse(css);
+            let serialized = stylesheet.to_css_string();
+            assert_eq!(
+                parse(&serialized),
+                stylesheet,
+                "{css:?} was serialized as {serialized:?}"
+            );
+            // NOTE: The first serialization is already normalized, so serializing again doesn't
+            //       change it.
+            assert_eq!(parse(&serialized).to_css_string(), serialized);
+        }
+    }
+
+    #[test]
+    fn serialize_a_stylesheet() {
+        assert_eq!(
+            parse(
+                "@charset \"utf-8\";  p  ,a{color:red;;margin : 0 !IMPORTANT}  @media print{p{}}"
+            )
+            .to_css_string(),
+            "@charset \"utf-8\";\np ,a{ color: red; margin: 0 !important; }\n@media print{ p{} }"
+        );
+    }
+
+    #[test]
+    fn tokens_that_would_merge_are_separated_by_a_comment() {
+        let tokens = [
+            Token::Ident {
+                value: "a".to_string(),
+            },
+            Token::Ident {
+                value: "b".to_string(),
+            },
+            Token::LeftParenthesis,
+            Token::Delim { value: '/' },
+            Token::Delim { value: '*' },
+            Token::Delim { value: '#' },
+            Token::Ident {
+                value: "c".to_string(),
+            },
+        ];
+        let serialized = tokens.to_css_string();
+        assert_eq!(serialized, "a/**/b/**/(//**/*#/**/c");
+        assert_eq!(tokenize(&serialized), tokens);
+    }
+
+    #[test]
+    fn identifiers_and_strings_are_escaped() {
+        let tokens = [
+            Token::Ident {
+                value: "1st".to_string(),
+            },
+            Token::Whitespace,
+            Token::Ident {
+                value: "-".to_string(),
+            },
+            Token::Whitespace,
+            Token::Hash {
+                value: "a b".to_string(),
+                hash_type: HashType::Id,
+            },
+            Token::Whitespace,
+            Token::String {
+                value: "say \"hi\"\n\\".to_string(),
+            },
+            Token::Whitespace,
+            Token::Url {
+                value: "a (b).png".to_string(),
+            },
+        ];
+        let serialized = tokens.to_css_string();
+        assert_eq!(
+            serialized,
+            "\\31 st \\- #a\\ b \"say \\\"hi\\\"\\a \\\\\" url(a\\20 \\28 b\\29 .png)"
+        );
+        assert_eq!(tokenize(&serialized), tokens);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::css::parser::types::{AtRule, ComponentValue, Declaration, Rule};
+use crate::css::parser::Parser;
+use crate::css::tokenizer::Token;
+
+/// https://drafts.csswg.org/css-conditional-3/#at-supports
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupportsRule {
+    pub condition: SupportsCondition,
+    pub rules: Vec<Rule>,
+}
+
+impl SupportsRule {
+    /// Interprets an at-rule named "supports" as a [`SupportsRule`].
+    ///
+    /// Returns `None` if the at-rule is not an @supports rule, or if its prelude does not match the
+    /// `<supports-condition>` grammar, in which case the whole rule is invalid.
+    pub fn from_at_rule(at_rule: &AtRule) -> Option<Self> {
+        if !at_rule.name.eq_ignore_ascii_case("supports") {
+            return None;
+        }
+
+        Some(Self {
+            condition: SupportsCondition::parse(&at_rule.prelude)?,
+            rules: at_rule.child_rules.clone(),
+        })
+    }
+}
+
+/// https://drafts.csswg.org/css-conditional-3/#typedef-supports-condition
+#[derive(Debug, Clone, PartialEq)]
+pub enum SupportsCondition {
+    Not(Box<SupportsCondition>),
+    And(Vec<SupportsCondition>),
+    Or(Vec<SupportsCondition>),
+    /// https://drafts.csswg.org/css-conditional-3/#typedef-supports-decl
+    Declaration(Declaration),
+    /// https://drafts.csswg.org/mediaqueries-4/#typedef-general-enclosed
+    GeneralEnclosed,
+}
+
+impl SupportsCondition {
+    /// <supports-condition> = not <supports-in-parens>
+    ///                      | <supports-in-parens> [ and <supports-in-parens> ]*
+    ///                      | <supports-in-parens> [ or <supports-in-parens> ]*
+    pub fn parse(values: &[ComponentValue]) -> Option<Self> {
+        let values = values
+            .iter()
+            .filter(|value| **value != ComponentValue::PreservedToken(Token::Whitespace))
+            .collect::<Vec<_>>();
+
+        let keyword = |value: &ComponentValue| match value {
+            ComponentValue::PreservedToken(Token::Ident { value }) => {
+                Some(value.to_ascii_lowercase())
+            }
+            _ => None,
+        };
+
+        match values.as_slice() {
+            [] => None,
+            [first, rest @ ..] if keyword(first).as_deref() == Some("not") => match rest {
+                [in_parens] => Some(Self::Not(Box::new(Self::parse_in_parens(in_parens)?))),
+                _ => None,
+            },
+            [first, rest @ ..] => {
+                let mut conditions = vec![Self::parse_in_parens(first)?];
+
+                // The keywords can't be mixed without parentheses, so the first one decides.
+                let combinator = rest.first().and_then(|value| keyword(value));
+                if !matches!(combinator.as_deref(), None | Some("and") | Some("or")) {
+                    return None;
+                }
+
+                for pair in rest.chunks(2) {
+                    match pair {
+                        [operator, in_parens] if keyword(operator) == combinator => {
+                            conditions.push(Self::parse_in_parens(in_parens)?);
+                        }
+                        _ => return None,
+                    }
+                }
+
+                match combinator.as_deref() {
+                    None => conditions.pop(),
+                    Some("and") => Some(Self::And(conditions)),
+                    _ => Some(Self::Or(conditions)),
+                }
+            }
+        }
+    }
+
+    /// <supports-in-parens> = ( <supports-condition> ) | <supports-feature> | <general-enclosed>
+    fn parse_in_parens(value: &ComponentValue) -> Option<Self> {
+        match value {
+            ComponentValue::SimpleBlock(block)
+                if block.associated_token == Token::LeftParenthesis =>
+            {
+                // ( <supports-condition> )
+                if let Some(condition) = Self::parse(&block.values) {
+                    return Some(condition);
+                }
+
+                // <supports-decl> = ( <declaration> )
+                let tokens = block
+                    .values
+                    .iter()
+                    .flat_map(ComponentValue::to_tokens)
+                    .collect::<Vec<_>>();
+                if let Ok(declaration) = Parser::parse_a_declaration(tokens) {
+                    return Some(Self::Declaration(declaration));
+                }
+
+                // <general-enclosed> = ( <any-value>? )
+                Some(Self::GeneralEnclosed)
+            }
+            // <general-enclosed> = [ <function-token> <any-value>? ) ]
+            // FIXME: Support the selector() function from css-conditional-4.
+            ComponentValue::Function(_) => Some(Self::GeneralEnclosed),
+            _ => None,
+        }
+    }
+
+    /// https://drafts.csswg.org/css-conditional-3/#evaluate-a-supports-condition
+    ///
+    /// `supports_declaration` decides whether a single declaration is supported, which is the case if
+    /// the UA parses its property name and value successfully.
+    pub fn evaluate(&self, supports_declaration: &impl Fn(&Declaration) -> bool) -> bool {
+        match self {
+            // The result is the negation of the <supports-in-parens> term.
+            Self::Not(condition) => !condition.evaluate(supports_declaration),
+            // The result is true if all of the <supports-in-parens> child terms are true, and false otherwise.
+            Self::And(conditions) => conditions
+                .iter()
+                .all(|condition| condition.evaluate(supports_declaration)),
+            // The result is true if any of the <supports-in-parens> child terms are true, and false otherwise.
+            Self::Or(conditions) => conditions
+                .iter()
+                .any(|condition| condition.evaluate(supports_declaration)),
+            Self::Declaration(declaration) => supports_declaration(declaration),
+            // The result is false.
+            Self::GeneralEnclosed => false,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::css::parser::types::ComponentValue;
+use crate::css::tokenizer::Token;
+
+/// https://drafts.csswg.org/css-text-3/#white-space-property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhiteSpace {
+    #[default]
+    Normal,
+    Pre,
+    Nowrap,
+    PreWrap,
+    PreLine,
+}
+
+impl WhiteSpace {
+    /// Parses a single `white-space` keyword.
+    ///
+    /// FIXME: Support break-spaces and the longhands `white-space-collapse` and `text-wrap-mode`.
+    pub fn parse_component_value(value: &ComponentValue) -> Option<Self> {
+        let ComponentValue::PreservedToken(Token::Ident { value }) = value else {
+            return None;
+        };
+
+        match value.to_ascii_lowercase().as_str() {
+            "normal" => Some(Self::Normal),
+            "pre" => Some(Self::Pre),
+            "nowrap" => Some(Self::Nowrap),
+            "pre-wrap" => Some(Self::PreWrap),
+            "pre-line" => Some(Self::PreLine),
+            _ => None,
+        }
+    }
+
+    /// Whether spaces and tabs are collapsible, so that a sequence of them is shown as a single
+    /// space.
+    ///
+    /// https://drafts.csswg.org/css-text-3/#white-space-collapsing
+    pub fn collapses_spaces(&self) -> bool {
+        matches!(self, Self::Normal | Self::Nowrap | Self::PreLine)
+    }
+
+    /// Whether segment breaks (newlines in the source) are preserved as forced line breaks,
+    /// instead of being collapsed like spaces.
+    ///
+    /// https://drafts.csswg.org/css-text-3/#line-break-transform
+    pub fn preserves_segment_breaks(&self) -> bool {
+        matches!(self, Self::Pre | Self::PreWrap | Self::PreLine)
+    }
+
+    /// Whether lines may wrap at soft wrap opportunities, instead of only at forced line breaks.
+    ///
+    /// https://drafts.csswg.org/css-text-4/#text-wrap-mode
+    pub fn wraps(&self) -> bool {
+        matches!(self, Self::Normal | Self::PreWrap | Self::PreLine)
+    }
+}
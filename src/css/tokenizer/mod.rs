@@ -1,5 +1,7 @@
 #![allow(clippy::manual_is_ascii_check)]
 
+use std::borrow::Cow;
+
 pub use token::{HashType, NumberType, Token};
 
 pub mod token;
@@ -11,6 +13,9 @@ macro_rules! definition {
     (digit) => {
         '0'..='9'
     };
+    (hex_digit) => {
+        definition!(digit) | 'A'..='F' | 'a'..='f'
+    };
     (uppercase_letter) => {
         'A'..='Z'
     };
@@ -29,8 +34,14 @@ macro_rules! definition {
     (ident_code_point) => {
         definition!(ident_start_code_point) | definition!(digit) | '-'
     };
+    (newline) => {
+        '\n'
+    };
     (whitespace) => {
-        '\n' | '\t' | ' '
+        definition!(newline) | '\t' | ' '
+    };
+    (non_printable_code_point) => {
+        '\u{0000}'..='\u{0008}' | '\u{000B}' | '\u{000E}'..='\u{001F}' | '\u{007F}'
     };
 }
 
@@ -56,35 +67,63 @@ macro_rules! log_parse_error {
     };
 }
 
+pub(crate) use log_parse_error;
+
+/// Filters the input code points before tokenizing.
+///
+/// SPEC: Replace any U+000D CARRIAGE RETURN (CR) code points, U+000C FORM FEED (FF) code points,
+///       or pairs of U+000D CARRIAGE RETURN (CR) followed by U+000A LINE FEED (LF) in input by a
+///       single U+000A LINE FEED (LF) code point.
+///       Replace any U+0000 NULL or surrogate code points in input with U+FFFD REPLACEMENT
+///       CHARACTER (�).
+///
+/// NOTE: A &str can't contain surrogates, and most input doesn't need any replacements, so we
+///       only allocate when we have to.
+///
+/// https://drafts.csswg.org/css-syntax-3/#input-preprocessing
+fn preprocess(input: &str) -> Cow<'_, str> {
+    if !input.contains(['\r', '\u{000C}', '\0']) {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(
+        input
+            .replace("\r\n", "\n")
+            .replace(['\r', '\u{000C}'], "\n")
+            .replace('\0', "\u{FFFD}"),
+    )
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Tokenizer<'a> {
-    input: &'a str,
+    /// The preprocessed input.
+    input: Cow<'a, str>,
     position: isize,
+    unicode_ranges_allowed: bool,
+    after_unicode_range_name: bool,
 }
 
 impl<'a> Tokenizer<'a> {
     pub fn new(input: &'a str) -> Self {
+        // NOTE: Decoding the stylesheet bytes strips a leading BOM, but the input we get has already
+        //       been decoded, so we strip it here.
+        // https://encoding.spec.whatwg.org/#decode
+        let input = input.strip_prefix('\u{FEFF}').unwrap_or(input);
+
         Self {
-            input,
+            input: preprocess(input),
             position: -1,
+            unicode_ranges_allowed: false,
+            after_unicode_range_name: false,
         }
     }
 
+    /// Tokenizes the whole input at once. The returned tokens always end with a [`Token::EndOfFile`].
+    ///
+    /// Use the [`Iterator`] implementation to tokenize the input on demand instead.
     pub fn tokenize(&mut self) -> Vec<Token> {
-        let mut tokens = vec![];
-
-        loop {
-            let token = self.consume_a_token();
-            log_current_token!(token);
-            match token {
-                Token::EndOfFile => {
-                    tokens.push(token);
-                    break;
-                }
-                _ => tokens.push(token),
-            }
-        }
-
+        let mut tokens = self.by_ref().collect::<Vec<_>>();
+        tokens.push(Token::EndOfFile);
         tokens
     }
 
@@ -131,14 +170,14 @@ impl<'a> Tokenizer<'a> {
                 }
                 '#' => {
                     // If the next input code point is an ident code point
-                    // FIXME: or the next two input code points are a valid escape, then:
+                    // or the next two input code points are a valid escape, then:
                     if self
                         .next_input_code_point()
-                        .map_or(false, |c| matches!(c, definition!(ident_code_point)))
+                        .is_some_and(|c| matches!(c, definition!(ident_code_point)))
+                        || self
+                            .check_if_two_code_points_are_a_valid_escape(self.peek(1), self.peek(2))
                     {
-                        let (first, second, third) = self
-                            .next_three_input_code_points()
-                            .expect("FIXME: Implement invalid EOF case.");
+                        let (first, second, third) = self.next_three_input_code_points();
 
                         // 1. Create a <hash-token>.
                         let hash_token = Token::Hash {
@@ -190,10 +229,7 @@ impl<'a> Tokenizer<'a> {
                     }
 
                     // Otherwise, if the next 2 input code points are U+002D HYPHEN-MINUS U+003E GREATER-THAN SIGN (->),
-                    if let ('-', '>') = self
-                        .next_two_input_code_points()
-                        .expect("FIXME: Implement invalid EOF case.")
-                    {
+                    if let Some(('-', '>')) = self.next_two_input_code_points() {
                         // consume them and return a <CDC-token>.
                         self.consume_next_input_code_point();
                         self.consume_next_input_code_point();
@@ -225,12 +261,22 @@ impl<'a> Tokenizer<'a> {
                 }
                 ':' => Token::Colon,
                 ';' => Token::Semicolon,
-                '<' => todo!(),
+                '<' => {
+                    // If the next 3 input code points are U+0021 EXCLAMATION MARK U+002D HYPHEN-MINUS U+002D HYPHEN-MINUS (!--),
+                    if let (Some('!'), Some('-'), Some('-')) = self.next_three_input_code_points() {
+                        // consume them and return a <CDO-token>.
+                        self.consume_next_input_code_point();
+                        self.consume_next_input_code_point();
+                        self.consume_next_input_code_point();
+                        return Token::Cdo;
+                    }
+
+                    // Otherwise, return a <delim-token> with its value set to the current input code point.
+                    Token::Delim { value: code_point }
+                }
                 '@' => {
                     // If the next 3 input code points would start an ident sequence,
-                    let (first, second, third) = self
-                        .next_three_input_code_points()
-                        .expect("FIXME: Implement invalid EOF case.");
+                    let (first, second, third) = self.next_three_input_code_points();
                     if self.check_if_three_code_points_would_start_an_ident_sequence(
                         first, second, third,
                     ) {
@@ -245,7 +291,20 @@ impl<'a> Tokenizer<'a> {
                     Token::Delim { value: code_point }
                 }
                 '[' => Token::LeftSquareBracket,
-                '\\' => todo!(),
+                '\\' => {
+                    // If the input stream starts with a valid escape,
+                    if self.stream_starts_with_a_valid_escape() {
+                        // reconsume the current input code point,
+                        self.reconsume_current_input_code_point();
+                        // consume an ident-like token, and return it.
+                        return self.consume_an_ident_like_token();
+                    }
+
+                    // Otherwise, this is a parse error.
+                    log_parse_error!("Invalid escape");
+                    // Return a <delim-token> with its value set to the current input code point.
+                    Token::Delim { value: code_point }
+                }
                 ']' => Token::RightSquareBracket,
                 '{' => Token::LeftCurlyBracket,
                 '}' => Token::RightCurlyBracket,
@@ -255,6 +314,19 @@ impl<'a> Tokenizer<'a> {
                     // consume a numeric token, and return it.
                     self.consume_a_numeric_token()
                 }
+                'U' | 'u'
+                    if self.unicode_ranges_allowed
+                        && self.check_if_three_code_points_would_start_a_unicode_range(
+                            Some(code_point),
+                            self.peek(1),
+                            self.peek(2),
+                        ) =>
+                {
+                    // If unicode ranges allowed is true and the input stream would start a unicode-range,
+                    // reconsume the current input code point, consume a unicode-range token, and return it.
+                    self.reconsume_current_input_code_point();
+                    self.consume_a_unicode_range_token()
+                }
                 definition!(ident_start_code_point) => {
                     // Reconsume the current input code point,
                     self.reconsume_current_input_code_point();
@@ -282,15 +354,24 @@ impl<'a> Tokenizer<'a> {
             loop {
                 // up to and including the first U+002A ASTERISK (*) followed by a U+002F SOLIDUS (/),
                 // or up to an EOF code point.
-                let (inner_first, inner_second) = self
-                    .next_two_input_code_points()
-                    .expect("FIXME: Implement invalid EOF case.");
-                if inner_first == '*' && inner_second == '/' {
-                    self.consume_next_input_code_point();
-                    self.consume_next_input_code_point();
-                    break;
+                match self.next_two_input_code_points() {
+                    Some(('*', '/')) => {
+                        self.consume_next_input_code_point();
+                        self.consume_next_input_code_point();
+                        break;
+                    }
+                    Some(_) => {
+                        self.consume_next_input_code_point();
+                    }
+                    None => {
+                        // If the preceding paragraph ended by consuming an EOF code point, this is a parse error.
+                        log_parse_error!("EOF in comment");
+                        while self.peek(1).is_some() {
+                            self.consume_next_input_code_point();
+                        }
+                        return;
+                    }
                 }
-                self.consume_next_input_code_point();
                 // Return to the start of this step.
             }
         }
@@ -299,16 +380,19 @@ impl<'a> Tokenizer<'a> {
     // https://www.w3.org/TR/css-syntax-3/#consume-numeric-token
     fn consume_a_numeric_token(&mut self) -> Token {
         // Consume a number and let number be the result.
-        let number = self.consume_a_number();
+        let number = self.consume_a_number().unwrap_or_else(|error| {
+            log_parse_error!(format!("Failed to convert number: {}", error));
+            CssNumber::default()
+        });
 
         // If the next 3 input code points would start an ident sequence, then:
-        let (first, second, third) = self
-            .next_three_input_code_points()
-            .expect("FIXME: Implement invalid EOF case.");
+        let (first, second, third) = self.next_three_input_code_points();
         if self.check_if_three_code_points_would_start_an_ident_sequence(first, second, third) {
-            // 1. Create a <dimension-token> with the same value and type flag as number, and a unit set initially to the empty string.
+            // 1. Create a <dimension-token> with the same value, type flag, and sign character as number, and a unit set initially to the empty string.
             let dimension_token = Token::Dimension {
                 value: number.value,
+                integer_value: number.integer_value,
+                sign: number.sign,
                 number_type: number.number_type,
                 // 2. Consume an ident sequence. Set the <dimension-token>’s unit to the returned value.
                 unit: self.consume_an_ident_sequence(),
@@ -325,15 +409,18 @@ impl<'a> Tokenizer<'a> {
         {
             // consume it.
             self.consume_next_input_code_point();
-            // Create a <percentage-token> with the same value as number, and return it.
+            // Create a <percentage-token> with the same value and sign character as number, and return it.
             return Token::Percentage {
                 value: number.value,
+                sign: number.sign,
             };
         }
 
-        // Otherwise, create a <number-token> with the same value and type flag as number, and return it.
+        // Otherwise, create a <number-token> with the same value, type flag, and sign character as number, and return it.
         Token::Number {
             value: number.value,
+            integer_value: number.integer_value,
+            sign: number.sign,
             number_type: number.number_type,
         }
     }
@@ -343,18 +430,31 @@ impl<'a> Tokenizer<'a> {
         // Consume an ident sequence, and let string be the result.
         let string = self.consume_an_ident_sequence();
 
-        // FIXME: Implement
         // If string’s value is an ASCII case-insensitive match for "url",
         // and the next input code point is U+0028 LEFT PARENTHESIS ((),
-        // consume it.
-        // While the next two input code points are whitespace,
-        // consume the next input code point.
-        // If the next one or two input code points are U+0022 QUOTATION MARK ("), U+0027 APOSTROPHE ('),
-        // or whitespace followed by U+0022 QUOTATION MARK (") or U+0027 APOSTROPHE ('),
-        // then create a <function-token> with its value set to string and return it.
-        // Otherwise, consume a url token, and return it.
-        if string.eq_ignore_ascii_case("url") {
-            todo!()
+        if string.eq_ignore_ascii_case("url") && self.next_input_code_point() == Some('(') {
+            // consume it.
+            self.consume_next_input_code_point();
+
+            // While the next two input code points are whitespace,
+            // consume the next input code point.
+            while let (Some(definition!(whitespace)), Some(definition!(whitespace))) =
+                (self.peek(1), self.peek(2))
+            {
+                self.consume_next_input_code_point();
+            }
+
+            // If the next one or two input code points are U+0022 QUOTATION MARK ("), U+0027 APOSTROPHE ('),
+            // or whitespace followed by U+0022 QUOTATION MARK (") or U+0027 APOSTROPHE ('),
+            // then create a <function-token> with its value set to string and return it.
+            if let (Some('"' | '\''), _) | (Some(definition!(whitespace)), Some('"' | '\'')) =
+                (self.peek(1), self.peek(2))
+            {
+                return Token::Function { value: string };
+            }
+
+            // Otherwise, consume a url token, and return it.
+            return self.consume_a_url_token();
         }
 
         // Otherwise, if the next input code point is U+0028 LEFT PARENTHESIS ((),
@@ -372,6 +472,173 @@ impl<'a> Tokenizer<'a> {
         Token::Ident { value: string }
     }
 
+    // https://drafts.csswg.org/css-syntax-3/#consume-url-token
+    fn consume_a_url_token(&mut self) -> Token {
+        // NOTE: This algorithm assumes that the initial "url(" has already been consumed.
+
+        // 1. Initially create a <url-token> with its value set to the empty string.
+        let mut value = String::new();
+
+        // 2. Consume as much whitespace as possible.
+        self.consume_as_much_whitespace_as_possible();
+
+        // 3. Repeatedly consume the next input code point from the stream:
+        loop {
+            match self.consume_next_input_code_point() {
+                Some(')') => {
+                    // Return the <url-token>.
+                    return Token::Url { value };
+                }
+                None => {
+                    // This is a parse error. Return the <url-token>.
+                    log_parse_error!("EOF in url token");
+                    return Token::Url { value };
+                }
+                Some(definition!(whitespace)) => {
+                    // Consume as much whitespace as possible.
+                    self.consume_as_much_whitespace_as_possible();
+
+                    // If the next input code point is U+0029 RIGHT PARENTHESIS ()) or EOF,
+                    // consume it and return the <url-token> (if EOF was encountered, this is a parse error);
+                    match self.next_input_code_point() {
+                        Some(')') => {
+                            self.consume_next_input_code_point();
+                            return Token::Url { value };
+                        }
+                        None => {
+                            log_parse_error!("EOF in url token");
+                            return Token::Url { value };
+                        }
+                        _ => {
+                            // otherwise, consume the remnants of a bad url, create a <bad-url-token>, and return it.
+                            self.consume_the_remnants_of_a_bad_url();
+                            return Token::BadUrl;
+                        }
+                    }
+                }
+                Some('"' | '\'' | '(' | definition!(non_printable_code_point)) => {
+                    // This is a parse error.
+                    log_parse_error!("Unexpected character in url token");
+                    // Consume the remnants of a bad url, create a <bad-url-token>, and return it.
+                    self.consume_the_remnants_of_a_bad_url();
+                    return Token::BadUrl;
+                }
+                Some('\\') => {
+                    // If the stream starts with a valid escape,
+                    if self.stream_starts_with_a_valid_escape() {
+                        // consume an escaped code point and append the returned code point to the <url-token>’s value.
+                        value.push(self.consume_an_escaped_code_point());
+                        continue;
+                    }
+
+                    // Otherwise, this is a parse error.
+                    log_parse_error!("Invalid escape in url token");
+                    // Consume the remnants of a bad url, create a <bad-url-token>, and return it.
+                    self.consume_the_remnants_of_a_bad_url();
+                    return Token::BadUrl;
+                }
+                Some(code_point) => {
+                    // Append the current input code point to the <url-token>’s value.
+                    value.push(code_point);
+                }
+            }
+        }
+    }
+
+    // https://drafts.csswg.org/css-syntax-3/#consume-remnants-of-bad-url
+    fn consume_the_remnants_of_a_bad_url(&mut self) {
+        // Repeatedly consume the next input code point from the stream:
+        loop {
+            match self.consume_next_input_code_point() {
+                // U+0029 RIGHT PARENTHESIS ()) or EOF: Return.
+                Some(')') | None => return,
+                // the input stream starts with a valid escape: Consume an escaped code point.
+                // NOTE: This allows an escaped right parenthesis ("\)") to be encountered without ending the <bad-url-token>.
+                Some('\\') if self.stream_starts_with_a_valid_escape() => {
+                    self.consume_an_escaped_code_point();
+                }
+                // anything else: Do nothing.
+                _ => {}
+            }
+        }
+    }
+
+    // https://drafts.csswg.org/css-syntax-3/#starts-a-unicode-range
+    fn check_if_three_code_points_would_start_a_unicode_range(
+        &self,
+        first: Option<char>,
+        second: Option<char>,
+        third: Option<char>,
+    ) -> bool {
+        // 1. The first code point is either U+0055 LATIN CAPITAL LETTER U (U) or U+0075 LATIN SMALL LETTER U (u).
+        // 2. The second code point is U+002B PLUS SIGN (+).
+        // 3. The third code point is either U+003F QUESTION MARK (?) or a hex digit.
+        matches!(
+            (first, second, third),
+            (
+                Some('U' | 'u'),
+                Some('+'),
+                Some('?' | definition!(hex_digit))
+            )
+        )
+    }
+
+    // https://drafts.csswg.org/css-syntax-3/#consume-unicode-range-token
+    fn consume_a_unicode_range_token(&mut self) -> Token {
+        // 1. Consume the next two input code points and discard them.
+        self.consume_next_input_code_point();
+        self.consume_next_input_code_point();
+
+        // 2. Consume as many hex digits as possible, but no more than 6.
+        let mut first_segment = String::new();
+        while first_segment.len() < 6
+            && matches!(self.next_input_code_point(), Some(definition!(hex_digit)))
+        {
+            first_segment.extend(self.consume_next_input_code_point());
+        }
+        //    If less than 6 hex digits were consumed, consume as many U+003F QUESTION MARK (?) code points as possible,
+        //    but no more than enough to make the total of hex digits and U+003F QUESTION MARK (?) code points equal to 6.
+        //    Let first segment be the consumed code points.
+        while first_segment.len() < 6 && self.next_input_code_point() == Some('?') {
+            first_segment.extend(self.consume_next_input_code_point());
+        }
+
+        // 3. If first segment contains any question mark code points, then:
+        if first_segment.contains('?') {
+            // 1. Replace the question marks in first segment with U+0030 DIGIT ZERO (0) code points,
+            //    and interpret the result as a hexadecimal number. Let this be start of range.
+            let start = u32::from_str_radix(&first_segment.replace('?', "0"), 16).unwrap_or(0);
+            // 2. Replace the question marks in first segment with U+0046 LATIN CAPITAL LETTER F (F) code points,
+            //    and interpret the result as a hexadecimal number. Let this be end of range.
+            let end = u32::from_str_radix(&first_segment.replace('?', "F"), 16).unwrap_or(0);
+            // 3. Return a new <unicode-range-token> starting at start of range and ending at end of range.
+            return Token::UnicodeRange { start, end };
+        }
+
+        // 4. Otherwise, interpret the consumed code points as a hexadecimal number. Let this be start of range.
+        let start = u32::from_str_radix(&first_segment, 16).unwrap_or(0);
+
+        // 5. If the next 2 input code points are U+002D HYPHEN-MINUS (-) followed by a hex digit, then:
+        if let (Some('-'), Some(definition!(hex_digit))) = (self.peek(1), self.peek(2)) {
+            // 1. Consume the next input code point.
+            self.consume_next_input_code_point();
+            // 2. Consume as many hex digits as possible, but no more than 6.
+            //    Interpret the consumed code points as a hexadecimal number. Let this be end of range.
+            let mut second_segment = String::new();
+            while second_segment.len() < 6
+                && matches!(self.next_input_code_point(), Some(definition!(hex_digit)))
+            {
+                second_segment.extend(self.consume_next_input_code_point());
+            }
+            let end = u32::from_str_radix(&second_segment, 16).unwrap_or(0);
+            // 3. Return a new <unicode-range-token> starting at start of range and ending at end of range.
+            return Token::UnicodeRange { start, end };
+        }
+
+        // 6. Otherwise, return a new <unicode-range-token> both starting and ending at start of range.
+        Token::UnicodeRange { start, end: start }
+    }
+
     // https://www.w3.org/TR/css-syntax-3/#consume-string-token
     fn consume_a_string_token(&mut self, ending_code_point: Option<char>) -> Token {
         // This algorithm may be called with an ending code point,
@@ -411,12 +678,22 @@ impl<'a> Tokenizer<'a> {
                     // create a <bad-string-token>, and return it.
                     return Token::BadString;
                 }
-                Some('\\') => {
-                    // FIXME: If the next input code point is EOF, do nothing.
-                    // FIXME: Otherwise, if the next input code point is a newline, consume it.
-                    // FIXME: Otherwise, (the stream starts with a valid escape) consume an escaped code point and append the returned code point to the <string-token>’s value.
-                    todo!()
-                }
+                Some('\\') => match self.next_input_code_point() {
+                    // If the next input code point is EOF, do nothing.
+                    None => {}
+                    // Otherwise, if the next input code point is a newline, consume it.
+                    Some(definition!(newline)) => {
+                        self.consume_next_input_code_point();
+                    }
+                    // Otherwise, (the stream starts with a valid escape) consume an escaped code point
+                    // and append the returned code point to the <string-token>’s value.
+                    Some(_) => {
+                        let code_point = self.consume_an_escaped_code_point();
+                        if let Token::String { value } = &mut string_token {
+                            value.push(code_point);
+                        }
+                    }
+                },
                 _ => {
                     // Append the current input code point to the <string-token>’s value.
                     if let Token::String { value } = &mut string_token {
@@ -432,25 +709,24 @@ impl<'a> Tokenizer<'a> {
     // https://www.w3.org/TR/css-syntax-3/#check-if-three-code-points-would-start-an-ident-sequence
     fn check_if_three_code_points_would_start_an_ident_sequence(
         &self,
-        first: char,
-        second: char,
-        _third: char,
+        first: Option<char>,
+        second: Option<char>,
+        third: Option<char>,
     ) -> bool {
         // Look at the first code point:
         match first {
-            '-' => {
+            Some('-') => {
                 // If the second code point is an ident-start code point or a U+002D HYPHEN-MINUS,
                 match second {
-                    definition!(ident_start_code_point) | '-' => true,
-                    // FIXME: or the second and third code points are a valid escape, return true. Otherwise, return false.
-                    '\\' => true,
-                    _ => false,
+                    Some(definition!(ident_start_code_point) | '-') => true,
+                    // or the second and third code points are a valid escape, return true. Otherwise, return false.
+                    _ => self.check_if_two_code_points_are_a_valid_escape(second, third),
                 }
             }
-            definition!(ident_start_code_point) => true,
-            '\\' => {
-                // FIXME: If the first and second code points are a valid escape, return true. Otherwise, return false.
-                true
+            Some(definition!(ident_start_code_point)) => true,
+            Some('\\') => {
+                // If the first and second code points are a valid escape, return true. Otherwise, return false.
+                self.check_if_two_code_points_are_a_valid_escape(first, second)
             }
             _ => false,
         }
@@ -461,41 +737,102 @@ impl<'a> Tokenizer<'a> {
         // the current input code point and
         // the next two input code points,
         // in that order.
-        let first = match self.current_input_code_point() {
-            Some(first) => first,
-            None => return false,
-        };
-        let (second, third) = match self.next_two_input_code_points() {
-            Some(pair) => pair,
-            None => return false,
-        };
+        let first = self.current_input_code_point();
+        let (second, third) = (self.peek(1), self.peek(2));
 
         self.check_if_three_code_points_would_start_an_ident_sequence(first, second, third)
     }
 
+    // https://drafts.csswg.org/css-syntax-3/#starts-with-a-valid-escape
+    fn check_if_two_code_points_are_a_valid_escape(
+        &self,
+        first: Option<char>,
+        second: Option<char>,
+    ) -> bool {
+        // If the first code point is not U+005C REVERSE SOLIDUS (\), return false.
+        // Otherwise, if the second code point is a newline, return false.
+        // Otherwise, return true.
+        first == Some('\\') && !matches!(second, Some(definition!(newline)))
+    }
+
+    fn stream_starts_with_a_valid_escape(&self) -> bool {
+        // the two code points in question are
+        // the current input code point and
+        // the next input code point,
+        // in that order.
+        self.check_if_two_code_points_are_a_valid_escape(
+            self.current_input_code_point(),
+            self.next_input_code_point(),
+        )
+    }
+
+    // https://drafts.csswg.org/css-syntax-3/#consume-escaped-code-point
+    fn consume_an_escaped_code_point(&mut self) -> char {
+        // NOTE: This algorithm assumes that the U+005C REVERSE SOLIDUS (\) has already been consumed
+        //       and that the next input code point has already been verified to be part of a valid escape.
+
+        // Consume the next input code point.
+        match self.consume_next_input_code_point() {
+            Some(first @ definition!(hex_digit)) => {
+                // Consume as many hex digits as possible, but no more than 5.
+                // Note that this means 1-6 hex digits have been consumed in total.
+                let mut hex_digits = first.to_string();
+                while hex_digits.len() < 6
+                    && matches!(self.next_input_code_point(), Some(definition!(hex_digit)))
+                {
+                    hex_digits.extend(self.consume_next_input_code_point());
+                }
+
+                // If the next input code point is whitespace, consume it as well.
+                if let Some(definition!(whitespace)) = self.next_input_code_point() {
+                    self.consume_next_input_code_point();
+                }
+
+                // Interpret the hex digits as a hexadecimal number.
+                let number = u32::from_str_radix(&hex_digits, 16).unwrap_or(0);
+
+                // If this number is zero, or is for a surrogate,
+                // or is greater than the maximum allowed code point, return U+FFFD REPLACEMENT CHARACTER (�).
+                // Otherwise, return the code point with that value.
+                // NOTE: char::from_u32 returns None for surrogates and for numbers greater than the maximum allowed code point.
+                match number {
+                    0 => '\u{FFFD}',
+                    number => char::from_u32(number).unwrap_or('\u{FFFD}'),
+                }
+            }
+            None => {
+                // This is a parse error. Return U+FFFD REPLACEMENT CHARACTER (�).
+                log_parse_error!("EOF in escape");
+                '\u{FFFD}'
+            }
+            // Return the current input code point.
+            Some(code_point) => code_point,
+        }
+    }
+
     // https://www.w3.org/TR/css-syntax-3/#starts-with-a-number
     fn check_if_three_code_points_would_start_a_number(
         &self,
-        first: char,
-        second: char,
-        third: char,
+        first: Option<char>,
+        second: Option<char>,
+        third: Option<char>,
     ) -> bool {
         // Look at the first code point:
         match first {
-            '+' | '-' => match second {
+            Some('+' | '-') => match second {
                 // If the second code point is a digit, return true.
-                definition!(digit) => true,
+                Some(definition!(digit)) => true,
                 // Otherwise, if the second code point is a U+002E FULL STOP (.)
                 // and the third code point is a digit, return true.
-                '.' => definition!(digit).contains(&third),
+                Some('.') => matches!(third, Some(definition!(digit))),
                 _ => false,
             },
-            '.' => {
+            Some('.') => {
                 // If the second code point is a digit, return true.
                 // Otherwise, return false.
-                definition!(digit).contains(&second)
+                matches!(second, Some(definition!(digit)))
             }
-            definition!(digit) => true,
+            Some(definition!(digit)) => true,
             _ => false,
         }
     }
@@ -505,14 +842,8 @@ impl<'a> Tokenizer<'a> {
         // the current input code point and
         // the next two input code points,
         // in that order.
-        let first = match self.current_input_code_point() {
-            Some(first) => first,
-            None => return false,
-        };
-        let (second, third) = match self.next_two_input_code_points() {
-            Some(pair) => pair,
-            None => return false,
-        };
+        let first = self.current_input_code_point();
+        let (second, third) = (self.peek(1), self.peek(2));
 
         self.check_if_three_code_points_would_start_a_number(first, second, third)
     }
@@ -529,7 +860,11 @@ impl<'a> Tokenizer<'a> {
                     // Append the code point to result.
                     result.push(input);
                 }
-                '\\' => todo!(), // FIXME: This should use a seperate function to check if it really is an escape function.
+                // the stream starts with a valid escape
+                '\\' if self.stream_starts_with_a_valid_escape() => {
+                    // Consume an escaped code point. Append the returned code point to result.
+                    result.push(self.consume_an_escaped_code_point());
+                }
                 _ => {
                     // Reconsume the current input code point.
                     self.reconsume_current_input_code_point();
@@ -541,10 +876,11 @@ impl<'a> Tokenizer<'a> {
         result
     }
 
-    // https://www.w3.org/TR/css-syntax-3/#consume-number
-    fn consume_a_number(&mut self) -> CssNumber {
-        // 1. Initially set type to "integer". Let repr be the empty string.
+    // https://drafts.csswg.org/css-syntax/#consume-number
+    fn consume_a_number(&mut self) -> Result<CssNumber, std::num::ParseFloatError> {
+        // 1. Let number be a number initially set to 0. Let type be "integer". Let sign be undefined. Let repr be the empty string.
         let mut number_type = NumberType::Integer;
+        let mut sign = None;
         let mut repr = "".to_string();
 
         macro_rules! consume_and_append_to_repr {
@@ -557,17 +893,14 @@ impl<'a> Tokenizer<'a> {
 
         macro_rules! next_codepoint_is_digit {
             () => {
-                self.next_input_code_point()
-                    .is_some_and(|code_point| definition!(digit).contains(&code_point))
+                matches!(self.next_input_code_point(), Some(definition!(digit)))
             };
         }
 
-        // 2. If the next input code point is U+002B PLUS SIGN (+) or U+002D HYPHEN-MINUS (-), consume it and append it to repr.
-        if self
-            .next_input_code_point()
-            .is_some_and(|code_point| code_point == '+' || code_point == '-')
-        {
+        // 2. If the next input code point is U+002B PLUS SIGN (+) or U+002D HYPHEN-MINUS (-), consume it and append it to repr. Set sign to the current input code point.
+        if let Some(code_point @ ('+' | '-')) = self.next_input_code_point() {
             consume_and_append_to_repr!();
+            sign = Some(code_point);
         }
 
         // 3. While the next input code point is a digit, consume it and append it to repr.
@@ -576,10 +909,7 @@ impl<'a> Tokenizer<'a> {
         }
 
         // 4. If the next 2 input code points are U+002E FULL STOP (.) followed by a digit, then:
-        let (first, second) = self
-            .next_two_input_code_points()
-            .expect("FIXME: Implement invalid EOF case.");
-        if first == '.' && definition!(digit).contains(&second) {
+        if let (Some('.'), Some(definition!(digit))) = (self.peek(1), self.peek(2)) {
             // 1. Consume them.
             // 2. Append them to repr.
             consume_and_append_to_repr!();
@@ -595,50 +925,33 @@ impl<'a> Tokenizer<'a> {
         }
 
         // 5. If the next 2 or 3 input code points are
-        let (first, second, third) = self
-            .next_three_input_code_points()
-            .expect("FIXME: Implement invalid EOF case.");
-        // U+0045 LATIN CAPITAL LETTER E (E) or U+0065 LATIN SMALL LETTER E (e),
-        if first == 'E' || first == 'e' {
-            macro_rules! handle_digit {
-                ($amount:literal) => {
-                    // 1. Consume them.
-                    // 2. Append them to repr.
-                    for _ in 0..$amount {
-                        consume_and_append_to_repr!();
-                    }
-
-                    // 3. Set type to "number".
-                    number_type = NumberType::Number;
-
-                    // 4. While the next input code point is a digit, consume it and append it to repr.
-                    while next_codepoint_is_digit!() {
-                        consume_and_append_to_repr!();
-                    }
-                };
+        //    U+0045 LATIN CAPITAL LETTER E (E) or U+0065 LATIN SMALL LETTER E (e),
+        //    optionally followed by U+002D HYPHEN-MINUS (-) or U+002B PLUS SIGN (+),
+        //    followed by a digit, then:
+        let exponent_length = match self.next_three_input_code_points() {
+            (Some('E' | 'e'), Some('-' | '+'), Some(definition!(digit))) => Some(3),
+            (Some('E' | 'e'), Some(definition!(digit)), _) => Some(2),
+            _ => None,
+        };
+        if let Some(exponent_length) = exponent_length {
+            // 1. Consume them.
+            // 2. Append them to repr.
+            for _ in 0..exponent_length {
+                consume_and_append_to_repr!();
             }
 
-            // optionally followed by U+002D HYPHEN-MINUS (-) or U+002B PLUS SIGN (+),
-            // followed by a digit, then:
-            if second == '-' || second == '+' {
-                if definition!(digit).contains(&third) {
-                    handle_digit!(3);
-                }
-            } else if definition!(digit).contains(&second) {
-                handle_digit!(2);
+            // 3. Set type to "number".
+            number_type = NumberType::Number;
+
+            // 4. While the next input code point is a digit, consume it and append it to repr.
+            while next_codepoint_is_digit!() {
+                consume_and_append_to_repr!();
             }
         }
 
         // 6. Convert repr to a number, and set the value to the returned value.
-        let value = repr.parse::<f32>().unwrap_or_else(|_| {
-            panic!(
-                "Failed to parse {} as a float. We should have already checked that it was a valid number.",
-                repr
-            )
-        });
-
-        // Return value and type.
-        CssNumber { value, number_type }
+        // 7. Return number, sign, and type.
+        CssNumber::from_repr(&repr, sign, number_type)
     }
 
     fn next_two_input_code_points(&self) -> Option<(char, char)> {
@@ -648,11 +961,8 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
-    fn next_three_input_code_points(&self) -> Option<(char, char, char)> {
-        match (self.peek(1), self.peek(2), self.peek(3)) {
-            (Some(first), Some(second), Some(third)) => Some((first, second, third)),
-            _ => None,
-        }
+    fn next_three_input_code_points(&self) -> (Option<char>, Option<char>, Option<char>) {
+        (self.peek(1), self.peek(2), self.peek(3))
     }
 
     fn peek(&self, offset: isize) -> Option<char> {
@@ -663,18 +973,255 @@ impl<'a> Tokenizer<'a> {
         self.input.chars().nth(n as usize)
     }
 
+    // NOTE: Whitespace is only newlines, tabs and spaces here, not every Unicode whitespace code
+    //       point. Others, like U+00A0 NO-BREAK SPACE, are ident code points.
+    // https://drafts.csswg.org/css-syntax-3/#whitespace
     fn consume_as_much_whitespace_as_possible(&mut self) {
-        while self
-            .next_input_code_point()
-            .is_some_and(|code_point| code_point.is_whitespace())
-        {
+        while let Some(definition!(whitespace)) = self.next_input_code_point() {
             self.consume_next_input_code_point();
         }
     }
 }
 
+impl Iterator for Tokenizer<'_> {
+    type Item = Token;
+
+    /// Consumes the next token from the input. The iterator ends at the [`Token::EndOfFile`],
+    /// which is not yielded itself.
+    fn next(&mut self) -> Option<Token> {
+        let token = self.consume_a_token();
+        log_current_token!(token);
+
+        // FIXME: The value of a unicode-range descriptor should be re-tokenized from the original source text
+        //        with unicode ranges allowed, but the parser doesn't keep the source text around.
+        //        Allow them while tokenizing the value of a unicode-range declaration instead.
+        match &token {
+            Token::Whitespace => {}
+            Token::Ident { value } => {
+                self.after_unicode_range_name = value.eq_ignore_ascii_case("unicode-range");
+            }
+            Token::Colon => {
+                self.unicode_ranges_allowed = self.after_unicode_range_name;
+                self.after_unicode_range_name = false;
+            }
+            Token::Semicolon | Token::LeftCurlyBracket | Token::RightCurlyBracket => {
+                self.unicode_ranges_allowed = false;
+                self.after_unicode_range_name = false;
+            }
+            _ => self.after_unicode_range_name = false,
+        }
+
+        match token {
+            Token::EndOfFile => None,
+            token => Some(token),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct CssNumber {
-    value: f32,
-    number_type: NumberType,
+    pub value: f64,
+    pub integer_value: Option<i32>,
+    pub sign: Option<char>,
+    pub number_type: NumberType,
+}
+
+impl CssNumber {
+    /// Converts the representation that [`Tokenizer::consume_a_number`] built into a number.
+    ///
+    /// NOTE: repr only ever contains a valid float literal, but don't take down the tokenizer if
+    ///       it doesn't.
+    fn from_repr(
+        repr: &str,
+        sign: Option<char>,
+        number_type: NumberType,
+    ) -> Result<Self, std::num::ParseFloatError> {
+        let value = repr.parse::<f64>()?;
+
+        // The integer value is only meaningful for integers that fit, e.g. for <signed-integer> checks in An+B.
+        let integer_value = match number_type {
+            NumberType::Integer => repr.parse::<i32>().ok(),
+            NumberType::Number => None,
+        };
+
+        Ok(CssNumber {
+            value,
+            integer_value,
+            sign,
+            number_type,
+        })
+    }
+}
+
+/// The integer zero, which a numeric token falls back to if its number can't be converted.
+impl Default for CssNumber {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            integer_value: Some(0),
+            sign: None,
+            number_type: NumberType::Integer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(value: f64, integer_value: Option<i32>, sign: Option<char>) -> Token {
+        Token::Number {
+            value,
+            integer_value,
+            sign,
+            number_type: match integer_value {
+                Some(_) => NumberType::Integer,
+                None => NumberType::Number,
+            },
+        }
+    }
+
+    fn tokenize(input: &str) -> Vec<Token> {
+        Tokenizer::new(input)
+            .tokenize()
+            .into_iter()
+            .filter(|token| *token != Token::EndOfFile)
+            .collect()
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(tokenize("+12"), vec![number(12.0, Some(12), Some('+'))]);
+        assert_eq!(tokenize("-0.5"), vec![number(-0.5, None, Some('-'))]);
+        assert_eq!(tokenize("3e2"), vec![number(300.0, None, None)]);
+        assert_eq!(tokenize("4E-1"), vec![number(0.4, None, None)]);
+        assert_eq!(
+            tokenize("2."),
+            vec![number(2.0, Some(2), None), Token::Delim { value: '.' }]
+        );
+        assert_eq!(tokenize(".5"), vec![number(0.5, None, None)]);
+    }
+
+    #[test]
+    fn integer_overflow() {
+        assert_eq!(
+            tokenize("2147483647 -2147483648"),
+            vec![
+                number(2147483647.0, Some(i32::MAX), None),
+                Token::Whitespace,
+                number(-2147483648.0, Some(i32::MIN), Some('-')),
+            ]
+        );
+
+        // NOTE: Integers that don't fit in an i32 keep their value, but have no integer value.
+        assert_eq!(
+            tokenize("99999999999999999999"),
+            vec![Token::Number {
+                value: 1e20,
+                integer_value: None,
+                sign: None,
+                number_type: NumberType::Integer,
+            }]
+        );
+    }
+
+    #[test]
+    fn zero_fallback() {
+        assert!(CssNumber::from_repr("", None, NumberType::Integer).is_err());
+        assert_eq!(
+            CssNumber::from_repr("", None, NumberType::Integer).unwrap_or_default(),
+            CssNumber {
+                value: 0.0,
+                integer_value: Some(0),
+                sign: None,
+                number_type: NumberType::Integer,
+            }
+        );
+    }
+
+    fn ident(value: &str) -> Token {
+        Token::Ident {
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn important() {
+        assert_eq!(
+            tokenize("!important"),
+            vec![Token::Delim { value: '!' }, ident("important")]
+        );
+        assert_eq!(
+            tokenize("red ! IMPORTANT"),
+            vec![
+                ident("red"),
+                Token::Whitespace,
+                Token::Delim { value: '!' },
+                Token::Whitespace,
+                ident("IMPORTANT"),
+            ]
+        );
+        assert_eq!(tokenize("!"), vec![Token::Delim { value: '!' }]);
+        assert_eq!(
+            tokenize("!-"),
+            vec![Token::Delim { value: '!' }, Token::Delim { value: '-' }]
+        );
+    }
+
+    #[test]
+    fn less_than_sign() {
+        assert_eq!(tokenize("<!--"), vec![Token::Cdo]);
+        assert_eq!(
+            tokenize("a<b"),
+            vec![ident("a"), Token::Delim { value: '<' }, ident("b")]
+        );
+        assert_eq!(
+            tokenize("<!-"),
+            vec![
+                Token::Delim { value: '<' },
+                Token::Delim { value: '!' },
+                Token::Delim { value: '-' },
+            ]
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(tokenize("a/* b */c"), vec![ident("a"), ident("c")]);
+        assert_eq!(tokenize("a/**/"), vec![ident("a")]);
+        // NOTE: A comment that isn't closed runs to the end of the input.
+        assert_eq!(tokenize("a /* b"), vec![ident("a"), Token::Whitespace]);
+        assert_eq!(tokenize("a/* b *"), vec![ident("a")]);
+        assert_eq!(tokenize("/*"), vec![]);
+    }
+
+    #[test]
+    fn newlines_are_normalized() {
+        assert_eq!(
+            tokenize("a\r\nb\rc\x0Cd"),
+            [
+                ident("a"),
+                Token::Whitespace,
+                ident("b"),
+                Token::Whitespace,
+                ident("c"),
+                Token::Whitespace,
+                ident("d")
+            ]
+        );
+        // NOTE: An escaped CRLF is a single escaped newline, which a string drops.
+        assert_eq!(
+            tokenize("'a\\\r\nb'"),
+            [Token::String {
+                value: "ab".to_string()
+            }]
+        );
+        assert_eq!(tokenize("'a\r\nb'")[0], Token::BadString);
+    }
+
+    #[test]
+    fn leading_byte_order_mark_is_ignored() {
+        assert_eq!(tokenize("\u{FEFF}a"), [ident("a")]);
+        assert_eq!(tokenize("\u{FEFF}\u{FEFF}a"), [ident("\u{FEFF}a")]);
+    }
 }
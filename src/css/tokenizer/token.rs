@@ -27,17 +27,27 @@ pub enum Token {
         value: char,
     },
     Number {
-        value: f32,
+        value: f64,
+        integer_value: Option<i32>,
+        sign: Option<char>,
         number_type: NumberType,
     },
     Percentage {
-        value: f32,
+        value: f64,
+        sign: Option<char>,
     },
     Dimension {
-        value: f32,
+        value: f64,
+        integer_value: Option<i32>,
+        sign: Option<char>,
         number_type: NumberType,
         unit: String,
     },
+    /// https://drafts.csswg.org/css-syntax-3/#typedef-unicode-range-token
+    UnicodeRange {
+        start: u32,
+        end: u32,
+    },
     Whitespace,
     Cdo,
     Cdc,
@@ -1,5 +1,46 @@
+use crate::html::{Atom, Namespace};
+
+// https://dom.spec.whatwg.org/#interface-attr
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Attr {
-    pub name: String,
+    /// The local name of the attribute.
+    pub name: Atom,
     pub value: String,
+    /// https://dom.spec.whatwg.org/#concept-attribute-namespace
+    pub namespace: Option<Namespace>,
+    /// https://dom.spec.whatwg.org/#concept-attribute-namespace-prefix
+    pub prefix: Option<Atom>,
+}
+
+impl Attr {
+    /// Creates an attribute without a namespace or namespace prefix, like the ones the HTML
+    /// parser creates.
+    pub fn new(name: Atom, value: &str) -> Self {
+        Self {
+            name,
+            value: value.to_string(),
+            namespace: None,
+            prefix: None,
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#concept-attribute-qualified-name
+    pub fn qualified_name(&self) -> String {
+        // An attribute’s qualified name is its local name if its namespace prefix is null, and its namespace prefix, followed by ":", followed by its local name, otherwise.
+        match self.prefix {
+            Some(prefix) => format!("{}:{}", prefix, self.name),
+            None => self.name.to_string(),
+        }
+    }
+
+    /// Whether the qualified name of this attribute is `qualified_name`, without building it.
+    pub fn has_qualified_name(&self, qualified_name: &str) -> bool {
+        match self.prefix {
+            Some(prefix) => qualified_name
+                .strip_prefix(prefix.as_str())
+                .and_then(|rest| rest.strip_prefix(':'))
+                .is_some_and(|local_name| self.name == local_name),
+            None => self.name == qualified_name,
+        }
+    }
 }
@@ -0,0 +1,44 @@
+use std::cell::Cell;
+
+/// https://dom.spec.whatwg.org/#concept-document-mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuirksMode {
+    #[default]
+    NoQuirks,
+    Quirks,
+    LimitedQuirks,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Document {
+    // Unless stated otherwise, a document’s mode is "no-quirks".
+    mode: Cell<QuirksMode>,
+}
+
+impl Document {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// Concepts
+impl Document {
+    // https://dom.spec.whatwg.org/#concept-document-mode
+    pub fn quirks_mode(&self) -> QuirksMode {
+        self.mode.get()
+    }
+
+    pub fn set_quirks_mode(&self, mode: QuirksMode) {
+        self.mode.set(mode);
+    }
+
+    // https://dom.spec.whatwg.org/#concept-document-quirks
+    pub fn in_quirks_mode(&self) -> bool {
+        self.mode.get() == QuirksMode::Quirks
+    }
+
+    // https://dom.spec.whatwg.org/#concept-document-limited-quirks
+    pub fn in_limited_quirks_mode(&self) -> bool {
+        self.mode.get() == QuirksMode::LimitedQuirks
+    }
+}
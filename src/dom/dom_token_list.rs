@@ -0,0 +1,337 @@
+use crate::html::Atom;
+
+use super::exception::DomException;
+use super::Element;
+
+/// A set of space-separated tokens, backed by an attribute of an element.
+///
+/// The tokens are read from the attribute every time, so the list always reflects the current
+/// attribute value, and every mutation writes the serialized set back to the attribute.
+///
+/// https://dom.spec.whatwg.org/#interface-domtokenlist
+#[derive(Debug, Clone, Copy)]
+pub struct DomTokenList<'e, 'a> {
+    element: &'e Element<'a>,
+    local_name: Atom,
+}
+
+impl<'e, 'a> DomTokenList<'e, 'a> {
+    pub fn new(element: &'e Element<'a>, local_name: Atom) -> Self {
+        Self {
+            element,
+            local_name,
+        }
+    }
+
+    /// https://dom.spec.whatwg.org/#concept-dtl-tokens
+    fn token_set(&self) -> Vec<String> {
+        let value = self.element.get_attribute(&self.local_name);
+        parse_ordered_set(value.as_deref().unwrap_or_default())
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    // https://dom.spec.whatwg.org/#concept-dtl-update
+    fn update(&self, token_set: &[String]) {
+        // 1. If the associated element does not have an associated attribute and token set is empty, then return.
+        if self.element.get_attribute(&self.local_name).is_none() && token_set.is_empty() {
+            return;
+        }
+
+        // 2. Set an attribute value for the associated element using associated attribute’s local name and the result of running the ordered set serializer for token set.
+        self.element
+            .set_attribute_value(self.local_name, &token_set.join(" "), None, None);
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-length
+    pub fn length(&self) -> usize {
+        self.token_set().len()
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-item
+    pub fn item(&self, index: usize) -> Option<String> {
+        // 1. If index is equal to or greater than this’s token set’s size, then return null.
+        // 2. Return this’s token set[index].
+        self.token_set().into_iter().nth(index)
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-contains
+    pub fn contains(&self, token: &str) -> bool {
+        // The contains(token) method steps are to return true if this’s token set[token] exists; otherwise false.
+        let value = self.element.get_attribute(&self.local_name);
+        parse_ordered_set(value.as_deref().unwrap_or_default()).contains(&token)
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-add
+    pub fn add(&self, tokens: &[&str]) -> Result<(), DomException> {
+        // 1. For each token in tokens:
+        for token in tokens {
+            validate_token(token)?;
+        }
+
+        // 2. For each token in tokens, append token to this’s token set.
+        let mut token_set = self.token_set();
+        for token in tokens {
+            if !token_set.iter().any(|existing| existing == token) {
+                token_set.push(token.to_string());
+            }
+        }
+
+        // 3. Run the update steps.
+        self.update(&token_set);
+        Ok(())
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-remove
+    pub fn remove(&self, tokens: &[&str]) -> Result<(), DomException> {
+        // 1. For each token in tokens:
+        for token in tokens {
+            validate_token(token)?;
+        }
+
+        // 2. For each token in tokens, remove token from this’s token set.
+        let mut token_set = self.token_set();
+        token_set.retain(|existing| !tokens.contains(&existing.as_str()));
+
+        // 3. Run the update steps.
+        self.update(&token_set);
+        Ok(())
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-toggle
+    pub fn toggle(&self, token: &str, force: Option<bool>) -> Result<bool, DomException> {
+        // 1. If token is the empty string, then throw a "SyntaxError" DOMException.
+        // 2. If token contains any ASCII whitespace, then throw an "InvalidCharacterError" DOMException.
+        validate_token(token)?;
+
+        let mut token_set = self.token_set();
+
+        // 3. If this’s token set[token] exists, then:
+        if token_set.iter().any(|existing| existing == token) {
+            // 3.1. If force is either not given or is false, then remove token from this’s token set, run the update steps and return false.
+            if force != Some(true) {
+                token_set.retain(|existing| existing != token);
+                self.update(&token_set);
+                return Ok(false);
+            }
+
+            // 3.2. Return true.
+            return Ok(true);
+        }
+
+        // 4. Otherwise, if force not given or is true, append token to this’s token set, run the update steps, and return true.
+        if force != Some(false) {
+            token_set.push(token.to_string());
+            self.update(&token_set);
+            return Ok(true);
+        }
+
+        // 5. Return false.
+        Ok(false)
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-replace
+    pub fn replace(&self, token: &str, new_token: &str) -> Result<bool, DomException> {
+        // 1. If either token or newToken is the empty string, then throw a "SyntaxError" DOMException.
+        // 2. If either token or newToken contains any ASCII whitespace, then throw an "InvalidCharacterError" DOMException.
+        if token.is_empty() || new_token.is_empty() {
+            return Err(DomException::SyntaxError);
+        }
+        validate_token(token)?;
+        validate_token(new_token)?;
+
+        // 3. If this’s token set does not contain token, then return false.
+        let mut token_set = self.token_set();
+        let Some(index) = token_set.iter().position(|existing| existing == token) else {
+            return Ok(false);
+        };
+
+        // 4. Replace token in this’s token set with newToken.
+        // SPEC: To replace within an ordered set set, given item and replacement: if set contains
+        //       item or replacement, then replace the first instance of either with replacement
+        //       and remove all other instances.
+        let first = token_set
+            .iter()
+            .position(|existing| existing == new_token)
+            .map_or(index, |new_index| new_index.min(index));
+        token_set[first] = new_token.to_string();
+        let mut position = 0;
+        token_set.retain(|existing| {
+            let keep = position == first || (existing != token && existing != new_token);
+            position += 1;
+            keep
+        });
+
+        // 5. Run the update steps.
+        self.update(&token_set);
+
+        // 6. Return true.
+        Ok(true)
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-value
+    pub fn value(&self) -> String {
+        // The value attribute must return the result of running this’s serialize steps.
+        // SPEC: A DOMTokenList object’s serialize steps are to return the result of running get an attribute value given its associated element and its associated attribute’s local name.
+        self.element
+            .get_attribute(&self.local_name)
+            .unwrap_or_default()
+    }
+
+    // https://dom.spec.whatwg.org/#dom-domtokenlist-value
+    pub fn set_value(&self, value: &str) {
+        // Setting the value attribute must set an attribute value for the associated element using associated attribute’s local name and the given value.
+        self.element
+            .set_attribute_value(self.local_name, value, None, None);
+    }
+}
+
+/// Splits `input` on ASCII whitespace, dropping duplicate tokens.
+///
+/// https://dom.spec.whatwg.org/#concept-ordered-set-parser
+pub fn parse_ordered_set(input: &str) -> Vec<&str> {
+    // 1. Let inputTokens be the result of splitting input on ASCII whitespace.
+    // 2. Let tokens be a new ordered set.
+    let mut tokens: Vec<&str> = vec![];
+
+    // 3. For each token of inputTokens, append token to tokens.
+    // NOTE: Rust's ASCII whitespace is the same set of code points as the Infra standard's.
+    for token in input.split_ascii_whitespace() {
+        if !tokens.contains(&token) {
+            tokens.push(token);
+        }
+    }
+
+    // 4. Return tokens.
+    tokens
+}
+
+fn validate_token(token: &str) -> Result<(), DomException> {
+    // 1. If token is the empty string, then throw a "SyntaxError" DOMException.
+    if token.is_empty() {
+        return Err(DomException::SyntaxError);
+    }
+
+    // 2. If token contains any ASCII whitespace, then throw an "InvalidCharacterError" DOMException.
+    if token.contains(|c: char| c.is_ascii_whitespace()) {
+        return Err(DomException::InvalidCharacterError);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::Namespace;
+    use crate::local_name;
+
+    fn element_with_class(class: Option<&str>) -> Element<'static> {
+        let element = Element::new(local_name!("div"), Namespace::Html);
+        if let Some(class) = class {
+            element.set_attribute("class", class);
+        }
+        element
+    }
+
+    #[test]
+    fn tokens_are_split_on_ascii_whitespace_without_duplicates() {
+        let element = element_with_class(Some("\tb  a\nc\x0Cb\r a "));
+        let class_list = element.class_list();
+        assert_eq!(class_list.length(), 3);
+        assert_eq!(
+            (0..4)
+                .map(|index| class_list.item(index))
+                .collect::<Vec<_>>(),
+            [
+                Some("b".to_string()),
+                Some("a".to_string()),
+                Some("c".to_string()),
+                None
+            ]
+        );
+        assert!(class_list.contains("c"));
+        assert!(!class_list.contains("b a"));
+        // NOTE: Reading doesn't touch the attribute.
+        assert_eq!(class_list.value(), "\tb  a\nc\x0Cb\r a ");
+
+        // NOTE: Non-breaking spaces are not ASCII whitespace.
+        assert_eq!(parse_ordered_set("a\u{A0}b c"), ["a\u{A0}b", "c"]);
+    }
+
+    #[test]
+    fn mutations_write_back_the_serialized_set() {
+        let element = element_with_class(Some("b  a\tb"));
+        let class_list = element.class_list();
+
+        class_list.add(&["c", "a", "c"]).unwrap();
+        assert_eq!(element.get_attribute("class").as_deref(), Some("b a c"));
+
+        class_list.remove(&["b", "missing"]).unwrap();
+        assert_eq!(class_list.value(), "a c");
+
+        assert_eq!(class_list.replace("a", "c"), Ok(true));
+        assert_eq!(class_list.value(), "c");
+        assert_eq!(class_list.replace("missing", "d"), Ok(false));
+
+        assert_eq!(class_list.add(&[""]), Err(DomException::SyntaxError));
+        assert_eq!(
+            class_list.add(&["a b"]),
+            Err(DomException::InvalidCharacterError)
+        );
+        assert_eq!(class_list.value(), "c");
+    }
+
+    #[test]
+    fn toggle() {
+        let element = element_with_class(None);
+        let class_list = element.class_list();
+
+        // NOTE: Removing from a missing attribute doesn't create it.
+        class_list.remove(&["a"]).unwrap();
+        assert_eq!(element.get_attribute("class"), None);
+
+        assert_eq!(class_list.toggle("a", None), Ok(true));
+        assert_eq!(class_list.toggle("b", None), Ok(true));
+        assert_eq!(class_list.value(), "a b");
+        assert_eq!(class_list.toggle("a", None), Ok(false));
+        assert_eq!(class_list.value(), "b");
+
+        assert_eq!(class_list.toggle("b", Some(true)), Ok(true));
+        assert_eq!(class_list.toggle("c", Some(false)), Ok(false));
+        assert_eq!(class_list.value(), "b");
+        assert_eq!(class_list.toggle("b", Some(false)), Ok(false));
+        assert_eq!(element.get_attribute("class").as_deref(), Some(""));
+    }
+
+    #[test]
+    fn class_selectors_use_the_same_tokens() {
+        use crate::css::parser::Parser as CssParser;
+        use crate::css::selectors::SelectorList;
+
+        let parser = crate::html::parser::Parser::new(
+            typed_arena::Arena::new(),
+            "<p class=\"\ta\nb\x0Cc\"></p>",
+        );
+        let document = parser.parse();
+        let p = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("p"))
+            .unwrap();
+        let matches = |selector: &str| {
+            SelectorList::parse(&CssParser::parse_a_list_of_component_values(selector))
+                .unwrap()
+                .matches(p)
+        };
+
+        assert!(matches(".a.b.c"));
+        assert!(!matches(".d"));
+        p.as_element()
+            .unwrap()
+            .class_list()
+            .toggle("d", None)
+            .unwrap();
+        assert!(matches(".d.a"));
+    }
+}
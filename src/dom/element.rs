@@ -1,13 +1,491 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
-use crate::html::Namespace;
+use crate::html::{Atom, Namespace};
+use crate::local_name;
+use crate::url;
 
-use super::Attr;
+use super::{Attr, DomException, DomTokenList, NodeLink, NodeRef};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Element {
+#[derive(Clone)]
+pub struct Element<'a> {
     // FIXME: This does not really follow the spec.
-    pub tag_name: String,
-    pub namespace: Option<Namespace>,
+    local_name: Atom,
+    namespace: Namespace,
     pub attributes: RefCell<Vec<Attr>>,
+    /// https://html.spec.whatwg.org/multipage/scripting.html#template-contents
+    template_contents: NodeLink<'a>,
+    /// Whether the computed style of this element and its descendants has to be recomputed,
+    /// because the element is new or one of its attributes changed since it was last styled.
+    style_dirty: Cell<bool>,
+}
+
+impl<'a> Element<'a> {
+    pub fn new(local_name: Atom, namespace: Namespace) -> Self {
+        Self {
+            local_name,
+            namespace,
+            attributes: RefCell::new(vec![]),
+            template_contents: NodeLink::default(),
+            style_dirty: Cell::new(true),
+        }
+    }
+
+    pub fn is_template(&self) -> bool {
+        self.namespace == Namespace::Html && self.local_name == "template"
+    }
+
+    pub(crate) fn set_template_contents(&self, template_contents: NodeRef<'a>) {
+        self.template_contents.set(Some(template_contents));
+    }
+}
+
+// NOTE: The template contents link back into the document, so they are left out, like the links
+//       of a node (see the `Debug` implementation of `Node`).
+impl std::fmt::Debug for Element<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Element")
+            .field("local_name", &self.local_name)
+            .field("namespace", &self.namespace)
+            .field("attributes", &self.attributes)
+            .finish_non_exhaustive()
+    }
+}
+
+// NOTE: The template contents are not part of the element itself, and whether it needs to be
+//       restyled is bookkeeping, so neither takes part in equality.
+impl PartialEq for Element<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.local_name == other.local_name
+            && self.namespace == other.namespace
+            && self.attributes == other.attributes
+    }
+}
+
+impl Eq for Element<'_> {}
+
+// https://html.spec.whatwg.org/multipage/scripting.html#htmltemplateelement
+impl<'a> Element<'a> {
+    // https://html.spec.whatwg.org/multipage/scripting.html#dom-template-content
+    /// The template contents of a `template` element, or `None` for any other element.
+    pub fn content(&self) -> Option<NodeRef<'a>> {
+        self.template_contents.get()
+    }
+}
+
+// https://html.spec.whatwg.org/multipage/common-dom-interfaces.html#reflecting-content-attributes-in-idl-attributes
+impl Element<'_> {
+    /// Returns the value of the attribute `name`, resolved as a URL against `base`, like the IDL
+    /// attributes that reflect URLs (`href`, `src`, `action`, `poster`, ...) do.
+    ///
+    /// Returns `None` if the attribute is missing or its value can't be resolved.
+    ///
+    /// NOTE: Pass [`Node::document_base_url`](super::Node::document_base_url) as `base` to
+    ///       respect the document's `<base>` element.
+    pub fn resolve_url_attribute(&self, name: &str, base: &str) -> Option<String> {
+        // 1. Let contentAttributeValue be the result of running this's get the content attribute.
+        // 2. If contentAttributeValue is null, then return the empty string.
+        // NOTE: We return None instead, so callers can tell a missing attribute apart.
+        let value = self.get_attribute(name)?;
+
+        // 3. Let urlString be the result of encoding-parsing-and-serializing a URL given contentAttributeValue, relative to this's node document.
+        // NOTE: URL attributes contain a valid URL potentially surrounded by spaces.
+        // 4. If urlString is not failure, then return urlString.
+        // FIXME: 5. Return contentAttributeValue, converted to a scalar value string.
+        url::resolve(base, value.trim_matches(|c: char| c.is_ascii_whitespace()))
+    }
+}
+
+// IDL
+// https://dom.spec.whatwg.org/#interface-element
+impl<'a> Element<'a> {
+    // https://dom.spec.whatwg.org/#dom-element-namespaceuri
+    pub fn namespace(&self) -> Namespace {
+        self.namespace
+    }
+
+    // https://dom.spec.whatwg.org/#dom-element-localname
+    pub fn local_name(&self) -> Atom {
+        self.local_name
+    }
+
+    // https://dom.spec.whatwg.org/#dom-element-tagname
+    pub fn tag_name(&self) -> String {
+        // FIXME: Use the qualified name once we support namespace prefixes.
+        // If this is in the HTML namespace and its node document is an HTML document, then set qualifiedName to qualifiedName in ASCII uppercase.
+        match self.namespace {
+            Namespace::Html => self.local_name.to_ascii_uppercase(),
+            _ => self.local_name.to_string(),
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#dom-element-getattribute
+    pub fn get_attribute(&self, qualified_name: &str) -> Option<String> {
+        // FIXME: If this is in the HTML namespace and its node document is an HTML document, then set qualifiedName to qualifiedName in ASCII lowercase.
+        // 1. Let attr be the result of getting an attribute given qualifiedName and this.
+        // 2. If attr is null, return null.
+        // 3. Return attr's value.
+        self.attributes
+            .borrow()
+            .iter()
+            .find(|attr| attr.has_qualified_name(qualified_name))
+            .map(|attr| attr.value.clone())
+    }
+
+    // https://dom.spec.whatwg.org/#dom-element-hasattribute
+    pub fn has_attribute(&self, qualified_name: &str) -> bool {
+        // 1. If this is in the HTML namespace and its node document is an HTML document, then set qualifiedName to qualifiedName in ASCII lowercase.
+        let qualified_name = match self.namespace {
+            Namespace::Html => qualified_name.to_ascii_lowercase(),
+            _ => qualified_name.to_string(),
+        };
+
+        // 2. Return true if this has an attribute whose qualified name is qualifiedName; otherwise false.
+        self.attributes
+            .borrow()
+            .iter()
+            .any(|attr| attr.has_qualified_name(&qualified_name))
+    }
+
+    // https://dom.spec.whatwg.org/#dom-element-setattribute
+    pub fn set_attribute(&self, qualified_name: &str, value: &str) {
+        // FIXME: 1. If qualifiedName is not a valid attribute local name, then throw an "InvalidCharacterError" DOMException.
+
+        // 2. If this is in the HTML namespace and its node document is an HTML document, then set qualifiedName to qualifiedName in ASCII lowercase.
+        let qualified_name = match self.namespace {
+            Namespace::Html => qualified_name.to_ascii_lowercase(),
+            _ => qualified_name.to_string(),
+        };
+
+        // NOTE: Selectors can match on attributes, so the style of this element may change.
+        self.mark_style_dirty();
+
+        // 3. Let attribute be the first attribute in this’s attribute list whose qualified name is qualifiedName, and null otherwise.
+        let mut attributes = self.attributes.borrow_mut();
+        match attributes
+            .iter_mut()
+            .find(|attr| attr.has_qualified_name(&qualified_name))
+        {
+            // 5. Change attribute to value.
+            Some(attribute) => attribute.value = value.to_string(),
+            // 4. If attribute is null, create an attribute whose local name is qualifiedName, value is value, and node document is this’s node document, then append this attribute to this, and then return.
+            None => attributes.push(Attr::new(Atom::from(qualified_name), value)),
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#dom-element-setattributens
+    pub fn set_attribute_ns(
+        &self,
+        namespace: Option<Namespace>,
+        qualified_name: &str,
+        value: &str,
+    ) -> Result<(), DomException> {
+        // 1. Let namespace, prefix, and localName be the result of passing namespace and qualifiedName to validate and extract.
+        let (namespace, prefix, local_name) = validate_and_extract(namespace, qualified_name)?;
+
+        // 2. Set an attribute value for this using localName, value, and also prefix and namespace.
+        self.set_attribute_value(local_name, value, prefix, namespace);
+        Ok(())
+    }
+
+    // https://dom.spec.whatwg.org/#dom-element-toggleattribute
+    /// Adds the attribute with an empty value if it is missing, or removes it if it is present,
+    /// and returns whether the element has the attribute afterwards. If `force` is given, the
+    /// attribute is only added (for `true`) or removed (for `false`).
+    pub fn toggle_attribute(&self, qualified_name: &str, force: Option<bool>) -> bool {
+        // FIXME: 1. If qualifiedName is not a valid attribute local name, then throw an "InvalidCharacterError" DOMException.
+
+        // 2. If this is in the HTML namespace and its node document is an HTML document, then set qualifiedName to qualifiedName in ASCII lowercase.
+        let qualified_name = match self.namespace {
+            Namespace::Html => qualified_name.to_ascii_lowercase(),
+            _ => qualified_name.to_string(),
+        };
+
+        // NOTE: Selectors can match on attributes, so the style of this element may change.
+        self.mark_style_dirty();
+
+        // 3. Let attribute be the first attribute in this’s attribute list whose qualified name is qualifiedName, and null otherwise.
+        let mut attributes = self.attributes.borrow_mut();
+        let attribute = attributes
+            .iter()
+            .position(|attr| attr.has_qualified_name(&qualified_name));
+
+        let Some(index) = attribute else {
+            // 4. If attribute is null, then:
+            // 4.1. If force is not given or is true, create an attribute whose local name is qualifiedName, value is the empty string, and node document is this’s node document, then append this attribute to this, and then return true.
+            if force != Some(false) {
+                attributes.push(Attr::new(Atom::from(qualified_name), ""));
+                return true;
+            }
+
+            // 4.2. Return false.
+            return false;
+        };
+
+        // 5. Otherwise, if force is not given or is false, remove an attribute given qualifiedName and this, and then return false.
+        if force != Some(true) {
+            attributes.remove(index);
+            return false;
+        }
+
+        // 6. Return true.
+        true
+    }
+
+    // https://dom.spec.whatwg.org/#dom-element-classlist
+    pub fn class_list(&self) -> DomTokenList<'_, 'a> {
+        // The classList getter steps are to return a DOMTokenList object whose associated element is this and whose associated attribute’s local name is class.
+        DomTokenList::new(self, local_name!("class"))
+    }
+}
+
+// Concepts
+impl Element<'_> {
+    // https://dom.spec.whatwg.org/#concept-element-attributes-set-value
+    pub fn set_attribute_value(
+        &self,
+        local_name: Atom,
+        value: &str,
+        prefix: Option<Atom>,
+        namespace: Option<Namespace>,
+    ) {
+        // NOTE: Selectors can match on attributes, so the style of this element may change.
+        self.mark_style_dirty();
+
+        // 1. Let attribute be the result of getting an attribute given namespace, localName, and element.
+        let mut attributes = self.attributes.borrow_mut();
+        match attributes
+            .iter_mut()
+            .find(|attr| attr.namespace == namespace && attr.name == local_name)
+        {
+            // 3. Change attribute to value.
+            Some(attribute) => attribute.value = value.to_string(),
+            // 2. If attribute is null, create an attribute whose namespace is namespace, namespace prefix is prefix, local name is localName, value is value, and node document is element’s node document, then append this attribute to element, and then return.
+            None => attributes.push(Attr {
+                name: local_name,
+                value: value.to_string(),
+                namespace,
+                prefix,
+            }),
+        }
+    }
+}
+
+// Style invalidation
+impl Element<'_> {
+    /// Whether this element and its descendants have to be restyled.
+    pub fn is_style_dirty(&self) -> bool {
+        self.style_dirty.get()
+    }
+
+    /// Marks this element, and with it its descendants, as needing to be restyled.
+    ///
+    /// NOTE: We don't track which selectors depend on which attributes, so any attribute change
+    ///       restyles the whole subtree, which is always enough for the descendant and child
+    ///       combinators.
+    /// FIXME: Also restyle the following siblings, which sibling combinators can depend on.
+    pub fn mark_style_dirty(&self) {
+        self.style_dirty.set(true);
+    }
+
+    /// Marks this element as styled, once its style has been recomputed.
+    pub fn clear_style_dirty(&self) {
+        self.style_dirty.set(false);
+    }
+}
+
+// https://dom.spec.whatwg.org/#validate-and-extract
+fn validate_and_extract(
+    namespace: Option<Namespace>,
+    qualified_name: &str,
+) -> Result<(Option<Namespace>, Option<Atom>, Atom), DomException> {
+    // NOTE: Namespaces are an enum, so there is no empty string namespace to turn into null.
+    // 1. If namespace is the empty string, then set it to null.
+
+    // FIXME: 2. Validate qualifiedName.
+
+    // 3. Let prefix be null.
+    // 4. Let localName be qualifiedName.
+    // 5. If qualifiedName contains a U+003A (:), then strictly split the string on it and set prefix to the part before and localName to the part after.
+    let (prefix, local_name) = match qualified_name.split_once(':') {
+        Some((prefix, local_name)) => (Some(prefix), local_name),
+        None => (None, qualified_name),
+    };
+
+    // 6. If prefix is non-null and namespace is null, then throw a "NamespaceError" DOMException.
+    if prefix.is_some() && namespace.is_none() {
+        return Err(DomException::NamespaceError);
+    }
+
+    // 7. If prefix is "xml" and namespace is not the XML namespace, then throw a "NamespaceError" DOMException.
+    if prefix == Some("xml") && namespace != Some(Namespace::Xml) {
+        return Err(DomException::NamespaceError);
+    }
+
+    // 8. If either qualifiedName or prefix is "xmlns" and namespace is not the XMLNS namespace, then throw a "NamespaceError" DOMException.
+    let is_xmlns = qualified_name == "xmlns" || prefix == Some("xmlns");
+    if is_xmlns && namespace != Some(Namespace::XmlNs) {
+        return Err(DomException::NamespaceError);
+    }
+
+    // 9. If namespace is the XMLNS namespace and neither qualifiedName nor prefix is "xmlns", then throw a "NamespaceError" DOMException.
+    if namespace == Some(Namespace::XmlNs) && !is_xmlns {
+        return Err(DomException::NamespaceError);
+    }
+
+    // 10. Return namespace, prefix, and localName.
+    Ok((namespace, prefix.map(Atom::from), Atom::from(local_name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::node::{Node, NodeType};
+
+    #[test]
+    fn names_and_namespace() {
+        let div = Element::new(local_name!("div"), Namespace::Html);
+        assert_eq!(div.local_name(), local_name!("div"));
+        assert_eq!(div.tag_name(), "DIV");
+        assert_eq!(div.namespace(), Namespace::Html);
+
+        // NOTE: Only HTML elements have an uppercase tag name.
+        let foreign_object = Element::new("foreignObject".into(), Namespace::Svg);
+        assert_eq!(foreign_object.local_name().as_str(), "foreignObject");
+        assert_eq!(foreign_object.tag_name(), "foreignObject");
+        assert_eq!(foreign_object.namespace(), Namespace::Svg);
+    }
+
+    #[test]
+    fn node_helpers_delegate_to_the_element() {
+        let node = Node::new(
+            None,
+            NodeType::Element(Element::new("mi".into(), Namespace::MathMl)),
+        );
+        assert_eq!(node.element_tag_name().as_deref(), Some("mi"));
+        assert!(node.is_element_with_tag("mi"));
+        assert!(node.is_element_with_namespace(Namespace::MathMl));
+        assert!(!node.is_html_element_with_tag("mi"));
+
+        let text = Node::new(
+            None,
+            NodeType::Text {
+                data: RefCell::new("mi".to_string()),
+            },
+        );
+        assert_eq!(text.element_tag_name(), None);
+        assert!(!text.is_element_with_tag("mi"));
+    }
+
+    #[test]
+    fn template_content() {
+        let parser = crate::html::parser::Parser::new(
+            typed_arena::Arena::new(),
+            "<template><p>x</p></template><div></div>",
+        );
+        let document = parser.parse();
+        let element = |tag: &str| {
+            document
+                .descendants()
+                .find(|node| node.is_element_with_tag(tag))
+                .and_then(|node| node.as_element())
+                .unwrap()
+        };
+
+        let content = element("template")
+            .content()
+            .expect("templates have content");
+        assert!(content.is_document_fragment());
+        assert_eq!(content.child_nodes().len(), 1);
+        assert!(content.child_nodes()[0].is_element_with_tag("p"));
+        assert_eq!(
+            content.child_nodes()[0].text_content().as_deref(),
+            Some("x")
+        );
+        assert!(element("div").content().is_none());
+
+        // NOTE: The p is in the template contents, not a child of the template itself.
+        assert!(!document
+            .descendants()
+            .any(|node| node.is_element_with_tag("p")));
+        assert_eq!(
+            document
+                .descendants()
+                .find(|node| node.is_element_with_tag("template"))
+                .unwrap()
+                .serialize_html(),
+            "<template><p>x</p></template>"
+        );
+    }
+
+    #[test]
+    fn resolve_url_attributes_against_the_document_base_url() {
+        let resolved_links = |input: &str| {
+            let parser = crate::html::parser::Parser::new(typed_arena::Arena::new(), input);
+            let document = parser.parse();
+            let base = document.document_base_url("https://example.com/docs/guide/index.html");
+            let links = document
+                .descendants()
+                .filter_map(|node| node.as_element())
+                .filter_map(|element| {
+                    element
+                        .resolve_url_attribute("href", &base)
+                        .or_else(|| element.resolve_url_attribute("src", &base))
+                })
+                .collect::<Vec<_>>();
+            links
+        };
+
+        let body = r#"<a href="intro.html">a</a><a href=" ../api/#Node ">b</a>
+                      <a href="//cdn.example.org/x">c</a><img src="/logo.png"><a>no href</a>"#;
+
+        assert_eq!(
+            resolved_links(body),
+            [
+                "https://example.com/docs/guide/intro.html",
+                "https://example.com/docs/api/#Node",
+                "https://cdn.example.org/x",
+                "https://example.com/logo.png",
+            ]
+        );
+        assert_eq!(
+            resolved_links(&format!("<base href=\"/v2/\">{body}")),
+            [
+                "https://example.com/v2/",
+                "https://example.com/v2/intro.html",
+                "https://example.com/api/#Node",
+                "https://cdn.example.org/x",
+                "https://example.com/logo.png",
+            ]
+        );
+    }
+
+    #[test]
+    fn toggle_attribute() {
+        let input = Element::new(local_name!("input"), Namespace::Html);
+
+        assert!(input.toggle_attribute("disabled", None));
+        assert!(input.has_attribute("disabled"));
+        assert_eq!(input.get_attribute("disabled").as_deref(), Some(""));
+        assert!(!input.toggle_attribute("disabled", None));
+        assert!(!input.has_attribute("disabled"));
+
+        // NOTE: Forcing keeps an existing value and is idempotent.
+        input.set_attribute("required", "required");
+        assert!(input.toggle_attribute("required", Some(true)));
+        assert!(input.toggle_attribute("required", Some(true)));
+        assert_eq!(input.get_attribute("required").as_deref(), Some("required"));
+        assert!(!input.toggle_attribute("required", Some(false)));
+        assert!(!input.toggle_attribute("required", Some(false)));
+        assert!(!input.has_attribute("required"));
+
+        // NOTE: HTML elements lowercase the name first.
+        assert!(input.toggle_attribute("HIDDEN", None));
+        assert!(input.has_attribute("hidden"));
+        assert!(input.has_attribute("Hidden"));
+
+        let svg = Element::new("svg".into(), Namespace::Svg);
+        assert!(svg.toggle_attribute("viewBox", Some(true)));
+        assert!(svg.has_attribute("viewBox"));
+        assert!(!svg.has_attribute("viewbox"));
+    }
 }
@@ -0,0 +1,27 @@
+/// The errors that DOM operations can throw.
+///
+/// https://webidl.spec.whatwg.org/#idl-DOMException-error-names
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DomException {
+    /// The index is not in the allowed range.
+    IndexSizeError,
+    /// The operation would yield an incorrect node tree.
+    HierarchyRequestError,
+    /// The object can not be found here.
+    NotFoundError,
+    /// The string contains invalid characters.
+    InvalidCharacterError,
+    /// The string did not match the expected pattern.
+    SyntaxError,
+    /// The operation is not allowed by Namespaces in XML.
+    NamespaceError,
+}
+
+impl std::fmt::Display for DomException {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // NOTE: The names are spelled like the error names in the spec, e.g. "IndexSizeError".
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for DomException {}
@@ -1,8 +1,16 @@
 pub use attr::Attr;
+pub use document::{Document, QuirksMode};
+pub use dom_token_list::DomTokenList;
 pub use element::Element;
+pub use exception::DomException;
 pub use node::{Node, NodeLink, NodeRef};
 
 pub mod attr;
+pub mod document;
+pub mod dom_token_list;
 pub mod element;
+pub mod exception;
 pub mod mutation_algorithms;
 pub mod node;
+pub mod text;
+pub mod tree_traversal;
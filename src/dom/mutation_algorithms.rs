@@ -1,17 +1,96 @@
-use std::error::Error;
-
-use super::node::{Node, NodeRef};
+use super::exception::DomException;
+use super::node::{Node, NodeRef, NodeType};
 
 // 4.2.3. Mutation algorithms
 // https://dom.spec.whatwg.org/#mutation-algorithms
 impl<'a> Node<'a> {
     // https://dom.spec.whatwg.org/#concept-node-ensure-pre-insertion-validity
     pub fn ensure_pre_insertion_validity(
-        _node: NodeRef<'a>,
-        _parent: NodeRef<'a>,
-        _child: Option<NodeRef<'a>>,
-    ) -> Result<(), Box<dyn Error>> {
-        eprintln!("FIXME: Skipped pre-insertion validity check!");
+        node: NodeRef<'a>,
+        parent: NodeRef<'a>,
+        child: Option<NodeRef<'a>>,
+    ) -> Result<(), DomException> {
+        // 1. If parent is not a Document, DocumentFragment, or Element node, then throw a "HierarchyRequestError" DOMException.
+        if !parent.is_document() && !parent.is_document_fragment() && !parent.is_element() {
+            return Err(DomException::HierarchyRequestError);
+        }
+
+        // 2. If node is a host-including inclusive ancestor of parent, then throw a "HierarchyRequestError" DOMException.
+        // NOTE: We don't have shadow trees, so host-including inclusive ancestors are just inclusive ancestors.
+        if node.contains(parent) {
+            return Err(DomException::HierarchyRequestError);
+        }
+
+        // 3. If child is non-null and its parent is not parent, then throw a "NotFoundError" DOMException.
+        if let Some(child) = child {
+            if !child
+                .parent_node()
+                .is_some_and(|child_parent| std::ptr::eq(child_parent, parent))
+            {
+                return Err(DomException::NotFoundError);
+            }
+        }
+
+        // 4. If node is not a DocumentFragment, DocumentType, Element, or CharacterData node, then throw a "HierarchyRequestError" DOMException.
+        if matches!(node.node_type, NodeType::Document(_) | NodeType::Attr) {
+            return Err(DomException::HierarchyRequestError);
+        }
+
+        // 5. If either node is a Text node and parent is a document, or node is a doctype and parent is not a document, then throw a "HierarchyRequestError" DOMException.
+        if (node.is_text() && parent.is_document())
+            || (node.is_document_type() && !parent.is_document())
+        {
+            return Err(DomException::HierarchyRequestError);
+        }
+
+        // 6. If parent is a document, and any of the statements below, switched on the interface node implements, are true, then throw a "HierarchyRequestError" DOMException.
+        if parent.is_document() {
+            let parent_has_element_child = parent.child_nodes().iter().any(|c| c.is_element());
+            let child_is_doctype = child.is_some_and(|child| child.is_document_type());
+            // NOTE: The children of a document can't have doctype descendants, so only the
+            //       siblings of child can be following or preceding doctypes and elements.
+            let doctype_is_following_child = child.is_some_and(|child| {
+                std::iter::successors(child.next_sibling(), |sibling| sibling.next_sibling())
+                    .any(|sibling| sibling.is_document_type())
+            });
+
+            let is_invalid = match &node.node_type {
+                // DocumentFragment:
+                NodeType::DocumentFragment => {
+                    let element_children = node.child_element_count();
+                    // If node has more than one element child or has a Text node child.
+                    element_children > 1
+                        || node.child_nodes().iter().any(|c| c.is_text())
+                        // Otherwise, if node has one element child and either parent has an element child, child is a doctype, or child is non-null and a doctype is following child.
+                        || (element_children == 1
+                            && (parent_has_element_child
+                                || child_is_doctype
+                                || doctype_is_following_child))
+                }
+                // Element: parent has an element child, child is a doctype, or child is non-null and a doctype is following child.
+                NodeType::Element(_) => {
+                    parent_has_element_child || child_is_doctype || doctype_is_following_child
+                }
+                // DocumentType: parent has a doctype child, child is non-null and an element is preceding child, or child is null and parent has an element child.
+                NodeType::DocumentType { .. } => {
+                    parent.child_nodes().iter().any(|c| c.is_document_type())
+                        || match child {
+                            Some(child) => {
+                                std::iter::successors(child.previous_sibling(), |sibling| {
+                                    sibling.previous_sibling()
+                                })
+                                .any(|sibling| sibling.is_element())
+                            }
+                            None => parent_has_element_child,
+                        }
+                }
+                _ => false,
+            };
+            if is_invalid {
+                return Err(DomException::HierarchyRequestError);
+            }
+        }
+
         Ok(())
     }
 
@@ -20,16 +99,15 @@ impl<'a> Node<'a> {
         node: NodeRef<'a>,
         parent: NodeRef<'a>,
         child: Option<NodeRef<'a>>,
-    ) -> NodeRef<'a> {
+    ) -> Result<NodeRef<'a>, DomException> {
         // 1. Ensure pre-insertion validity of node into parent before child.
-        // FIXME: Propogate error.
-        Node::ensure_pre_insertion_validity(node, parent, child).unwrap();
+        Node::ensure_pre_insertion_validity(node, parent, child)?;
 
         // 2. Let referenceChild be child.
         let mut reference_child = child;
 
         // 3. If referenceChild is node, then set referenceChild to node’s next sibling.
-        if reference_child == Some(node) {
+        if reference_child.is_some_and(|child| std::ptr::eq(child, node)) {
             reference_child = node.next_sibling();
         }
 
@@ -37,7 +115,7 @@ impl<'a> Node<'a> {
         Node::insert(node, parent, reference_child, false);
 
         // 5. Return node.
-        node
+        Ok(node)
     }
 
     pub fn insert(
@@ -63,7 +141,15 @@ impl<'a> Node<'a> {
             return;
         }
 
-        // FIXME: 4. If node is a DocumentFragment node, then:
+        // 4. If node is a DocumentFragment node, then:
+        if node.is_document_fragment() {
+            // 4.1. Remove its children with the suppress observers flag set.
+            for child in nodes.iter() {
+                Node::remove_node(child, true);
+            }
+
+            // FIXME: 4.2. Queue a tree mutation record for node with « », nodes, null, and null.
+        }
 
         // 5. If child is non-null, then:
         if let Some(_child) = child {
@@ -91,7 +177,11 @@ impl<'a> Node<'a> {
                 if let Some(child_previous_sibling) = child.previous_sibling.get() {
                     child_previous_sibling.next_sibling.set(Some(node));
                 }
-                if parent.first_child.get() == Some(child) {
+                if parent
+                    .first_child
+                    .get()
+                    .is_some_and(|first_child| std::ptr::eq(first_child, child))
+                {
                     parent.first_child.set(Some(node));
                 }
 
@@ -102,17 +192,23 @@ impl<'a> Node<'a> {
                 // 7.2. If child is null, then append node to parent’s children.
                 parent.children.borrow_mut().push(node);
 
-                if parent.last_child().is_some() {
-                    parent.last_child.set(Some(node));
+                let last_child = parent.last_child();
+                node.previous_sibling.set(last_child);
+                if let Some(last_child) = last_child {
+                    last_child.next_sibling.set(Some(node));
                 }
-                node.previous_sibling.set(parent.last_child());
                 node.parent.set(Some(parent));
                 parent.last_child.set(Some(node));
                 if parent.first_child().is_none() {
-                    parent.first_child.set(parent.last_child());
+                    parent.first_child.set(Some(node));
                 }
             }
 
+            // NOTE: The node inherits from its new parent now, so it has to be restyled.
+            if let Some(element) = node.as_element() {
+                element.mark_style_dirty();
+            }
+
             // FIXME: 7.4. If parent is a shadow host whose shadow root’s slot assignment is "named" and node is a slottable, then assign a slot for node.
             // FIXME: 7.5. If parent’s root is a shadow root, and parent is a slot whose assigned nodes is the empty list, then run signal a slot change for parent.
             // FIXME: 7.6. Run assign slottables for a tree with node’s root.
@@ -124,12 +220,434 @@ impl<'a> Node<'a> {
     }
 
     // https://dom.spec.whatwg.org/#concept-node-append
-    pub fn append(node: NodeRef<'a>, parent: NodeRef<'a>, _suppress_observers: bool) {
-        Node::pre_insert(node, parent, None);
+    pub fn append(
+        node: NodeRef<'a>,
+        parent: NodeRef<'a>,
+        _suppress_observers: bool,
+    ) -> Result<NodeRef<'a>, DomException> {
+        // To append a node to a parent, pre-insert node into parent before null.
+        Node::pre_insert(node, parent, None)
+    }
+
+    // https://dom.spec.whatwg.org/#concept-node-replace
+    pub fn replace(
+        child: NodeRef<'a>,
+        node: NodeRef<'a>,
+        parent: NodeRef<'a>,
+    ) -> Result<NodeRef<'a>, DomException> {
+        // 1. If parent is not a Document, DocumentFragment, or Element node, then throw a "HierarchyRequestError" DOMException.
+        if !parent.is_document() && !parent.is_document_fragment() && !parent.is_element() {
+            return Err(DomException::HierarchyRequestError);
+        }
+
+        // 2. If node is a host-including inclusive ancestor of parent, then throw a "HierarchyRequestError" DOMException.
+        // NOTE: We don't have shadow trees, so host-including inclusive ancestors are just inclusive ancestors.
+        if node.contains(parent) {
+            return Err(DomException::HierarchyRequestError);
+        }
+
+        // 3. If child’s parent is not parent, then throw a "NotFoundError" DOMException.
+        if !child
+            .parent_node()
+            .is_some_and(|child_parent| std::ptr::eq(child_parent, parent))
+        {
+            return Err(DomException::NotFoundError);
+        }
+
+        // 4. If node is not a DocumentFragment, DocumentType, Element, or CharacterData node, then throw a "HierarchyRequestError" DOMException.
+        if matches!(node.node_type, NodeType::Document(_) | NodeType::Attr) {
+            return Err(DomException::HierarchyRequestError);
+        }
+
+        // 5. If either node is a Text node and parent is a document, or node is a doctype and parent is not a document, then throw a "HierarchyRequestError" DOMException.
+        if (node.is_text() && parent.is_document())
+            || (node.is_document_type() && !parent.is_document())
+        {
+            return Err(DomException::HierarchyRequestError);
+        }
+
+        // 6. If parent is a document, and any of the statements below, switched on the interface node implements, are true, then throw a "HierarchyRequestError" DOMException.
+        if parent.is_document() {
+            let has_element_child_that_is_not_child =
+                parent.child_nodes().iter().any(|parent_child| {
+                    parent_child.is_element() && !std::ptr::eq(*parent_child, child)
+                });
+            // NOTE: The children of a document can't have doctype descendants, so only the
+            //       siblings of child can be following or preceding doctypes and elements.
+            let mut following_siblings =
+                std::iter::successors(child.next_sibling(), |sibling| sibling.next_sibling());
+            let mut preceding_siblings =
+                std::iter::successors(child.previous_sibling(), |sibling| {
+                    sibling.previous_sibling()
+                });
+
+            let is_invalid = match &node.node_type {
+                // DocumentFragment:
+                NodeType::DocumentFragment => {
+                    let element_children = node.child_element_count();
+                    // If node has more than one element child or has a Text node child.
+                    element_children > 1
+                        || node.child_nodes().iter().any(|c| c.is_text())
+                        // Otherwise, if node has one element child and either parent has an element child that is not child or a doctype is following child.
+                        || (element_children == 1
+                            && (has_element_child_that_is_not_child
+                                || following_siblings.any(|s| s.is_document_type())))
+                }
+                // Element: parent has an element child that is not child or a doctype is following child.
+                NodeType::Element(_) => {
+                    has_element_child_that_is_not_child
+                        || following_siblings.any(|s| s.is_document_type())
+                }
+                // DocumentType: parent has a doctype child that is not child, or an element is preceding child.
+                NodeType::DocumentType { .. } => {
+                    parent.child_nodes().iter().any(|parent_child| {
+                        parent_child.is_document_type() && !std::ptr::eq(*parent_child, child)
+                    }) || preceding_siblings.any(|s| s.is_element())
+                }
+                _ => false,
+            };
+            if is_invalid {
+                return Err(DomException::HierarchyRequestError);
+            }
+        }
+
+        // 7. Let referenceChild be child’s next sibling.
+        let mut reference_child = child.next_sibling();
+
+        // 8. If referenceChild is node, then set referenceChild to node’s next sibling.
+        if reference_child.is_some_and(|reference_child| std::ptr::eq(reference_child, node)) {
+            reference_child = node.next_sibling();
+        }
+
+        // FIXME: 9. Let previousSibling be child’s previous sibling.
+        // FIXME: 10. Let removedNodes be the empty set.
+
+        // 11. If child’s parent is non-null, then:
+        if child.parent_node().is_some() {
+            // FIXME: 11.1. Set removedNodes to « child ».
+            // 11.2. Remove child with the suppress observers flag set.
+            Node::remove_node(child, true);
+        }
+
+        // FIXME: 12. Let nodes be node’s children if node is a DocumentFragment node; otherwise « node ».
+
+        // 13. Insert node into parent before referenceChild with the suppress observers flag set.
+        Node::insert(node, parent, reference_child, true);
+
+        // FIXME: 14. Queue a tree mutation record for parent with nodes, removedNodes, previousSibling, and referenceChild.
+
+        // 15. Return child.
+        Ok(child)
     }
 
     // https://dom.spec.whatwg.org/#concept-node-remove
-    pub fn remove(_node: NodeRef<'a>, _suppress_observers: bool) {
-        todo!()
+    /// NOTE: This is named `remove_node` so it doesn't clash with [`Node::remove`], which
+    ///       implements the `remove()` method of the ChildNode interface.
+    pub fn remove_node(node: NodeRef<'a>, _suppress_observers: bool) {
+        // 1. Let parent be node’s parent.
+        // 2. Assert: parent is non-null.
+        let parent = node
+            .parent_node()
+            .expect("only nodes with a parent can be removed");
+
+        // FIXME: 3. Let index be node’s index.
+        // FIXME: 4-7. Update the live ranges.
+        // FIXME: 8. For each NodeIterator object iterator whose root’s node document is node’s node document, run the NodeIterator pre-removing steps given node and iterator.
+        // 9. Let oldPreviousSibling be node’s previous sibling.
+        let old_previous_sibling = node.previous_sibling();
+        // 10. Let oldNextSibling be node’s next sibling.
+        let old_next_sibling = node.next_sibling();
+
+        // 11. Remove node from its parent’s children.
+        parent
+            .children
+            .borrow_mut()
+            .retain(|child| !std::ptr::eq(*child, node));
+
+        match old_previous_sibling {
+            Some(previous_sibling) => previous_sibling.next_sibling.set(old_next_sibling),
+            None => parent.first_child.set(old_next_sibling),
+        }
+        match old_next_sibling {
+            Some(next_sibling) => next_sibling.previous_sibling.set(old_previous_sibling),
+            None => parent.last_child.set(old_previous_sibling),
+        }
+        node.parent.set(None);
+        node.previous_sibling.set(None);
+        node.next_sibling.set(None);
+
+        // FIXME: 12. If node is assigned, then run assign slottables for node’s assigned slot.
+        // FIXME: 13. If parent’s root is a shadow root, and parent is a slot whose assigned nodes is the empty list, then run signal a slot change for parent.
+        // FIXME: 14. If node has an inclusive descendant that is a slot, then:
+        // FIXME: 15. For each inclusive ancestor inclusiveAncestor of node, and then for each registered of inclusiveAncestor’s registered observer list, if registered’s options["subtree"] is true, then append a new transient registered observer whose observer is registered’s observer, options is registered’s options, and source is registered to node’s registered observer list.
+        // FIXME: 16. If suppress observers flag is unset, then queue a tree mutation record for parent with « », « node », oldPreviousSibling, and oldNextSibling.
+        // FIXME: 17. Run the children changed steps for parent.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use crate::dom::exception::DomException;
+    use crate::dom::node::{Node, NodeRef, NodeType};
+    use crate::dom::{Document, Element};
+    use crate::html::Namespace;
+
+    fn document<'a>(arena: &'a typed_arena::Arena<Node<'a>>) -> NodeRef<'a> {
+        arena.alloc(Node::new(None, NodeType::Document(Document::new())))
+    }
+
+    fn element<'a>(
+        arena: &'a typed_arena::Arena<Node<'a>>,
+        document: NodeRef<'a>,
+        tag: &str,
+    ) -> NodeRef<'a> {
+        arena.alloc(Node::new(
+            Some(document),
+            NodeType::Element(Element::new(tag.into(), Namespace::Html)),
+        ))
+    }
+
+    fn fragment<'a>(arena: &'a typed_arena::Arena<Node<'a>>, document: NodeRef<'a>) -> NodeRef<'a> {
+        arena.alloc(Node::new(Some(document), NodeType::DocumentFragment))
+    }
+
+    fn text<'a>(
+        arena: &'a typed_arena::Arena<Node<'a>>,
+        document: NodeRef<'a>,
+        data: &str,
+    ) -> NodeRef<'a> {
+        arena.alloc(Node::new(
+            Some(document),
+            NodeType::Text {
+                data: RefCell::new(data.to_string()),
+            },
+        ))
+    }
+
+    fn doctype<'a>(arena: &'a typed_arena::Arena<Node<'a>>, document: NodeRef<'a>) -> NodeRef<'a> {
+        arena.alloc(Node::new(
+            Some(document),
+            NodeType::DocumentType {
+                name: "html".to_string(),
+                public_id: String::new(),
+                system_id: String::new(),
+            },
+        ))
+    }
+
+    /// The tag names of the children of `parent`, after checking that their sibling and parent
+    /// links agree with the list of children.
+    fn child_tags<'a>(parent: NodeRef<'a>) -> Vec<String> {
+        let children = parent.child_nodes().clone();
+        for (index, child) in children.iter().enumerate() {
+            assert!(same(child.parent_node(), Some(parent)));
+            let previous = index.checked_sub(1).map(|index| children[index]);
+            assert!(same(child.previous_sibling(), previous));
+            assert!(same(child.next_sibling(), children.get(index + 1).copied()));
+        }
+        assert!(same(parent.first_child(), children.first().copied()));
+        assert!(same(parent.last_child(), children.last().copied()));
+
+        children
+            .iter()
+            .map(|child| child.element_tag_name().unwrap().to_string())
+            .collect()
+    }
+
+    fn same<'a>(a: Option<NodeRef<'a>>, b: Option<NodeRef<'a>>) -> bool {
+        match (a, b) {
+            (Some(a), Some(b)) => std::ptr::eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn append_links_siblings() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let parent = element(&arena, document, "div");
+        let [a, b, c] = [
+            element(&arena, document, "a"),
+            element(&arena, document, "b"),
+            element(&arena, document, "c"),
+        ];
+        parent.append_child(a).unwrap();
+        parent.append_child(b).unwrap();
+        parent.append_child(c).unwrap();
+
+        assert!(same(parent.first_child(), Some(a)));
+        assert!(same(parent.last_child(), Some(c)));
+        assert!(same(a.previous_sibling(), None));
+        assert!(same(a.next_sibling(), Some(b)));
+        assert!(same(b.previous_sibling(), Some(a)));
+        assert!(same(b.next_sibling(), Some(c)));
+        assert!(same(c.previous_sibling(), Some(b)));
+        assert!(same(c.next_sibling(), None));
+    }
+
+    #[test]
+    fn insert_before_a_child_that_looks_like_the_first_child() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let parent = element(&arena, document, "div");
+        let [first, second, inserted] = [
+            element(&arena, document, "b"),
+            element(&arena, document, "b"),
+            element(&arena, document, "i"),
+        ];
+        parent.append_child(first).unwrap();
+        parent.append_child(second).unwrap();
+        Node::pre_insert(inserted, parent, Some(second)).unwrap();
+
+        assert!(same(parent.first_child(), Some(first)));
+        assert!(same(first.next_sibling(), Some(inserted)));
+        assert!(same(inserted.next_sibling(), Some(second)));
+        assert!(same(second.previous_sibling(), Some(inserted)));
+    }
+
+    #[test]
+    fn replace_with_a_fragment() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let parent = element(&arena, document, "div");
+        let [a, b, c] = ["a", "b", "c"].map(|tag| element(&arena, document, tag));
+        for child in [a, b, c] {
+            parent.append_child(child).unwrap();
+        }
+        let fragment = fragment(&arena, document);
+        for tag in ["x", "y"] {
+            fragment
+                .append_child(element(&arena, document, tag))
+                .unwrap();
+        }
+
+        let replaced = Node::replace(b, fragment, parent).unwrap();
+
+        assert!(std::ptr::eq(replaced, b));
+        assert_eq!(child_tags(parent), ["a", "x", "y", "c"]);
+        assert!(fragment.child_nodes().is_empty());
+        assert!(same(fragment.first_child(), None));
+        assert!(same(b.parent_node(), None));
+    }
+
+    #[test]
+    fn replace_a_node_with_itself() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let parent = element(&arena, document, "div");
+        let [a, b, c] = ["a", "b", "c"].map(|tag| element(&arena, document, tag));
+        for child in [a, b, c] {
+            parent.append_child(child).unwrap();
+        }
+
+        let replaced = Node::replace(b, b, parent).unwrap();
+
+        assert!(std::ptr::eq(replaced, b));
+        assert_eq!(child_tags(parent), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn replace_with_the_next_sibling_of_the_child() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let parent = element(&arena, document, "div");
+        let [a, b, c] = ["a", "b", "c"].map(|tag| element(&arena, document, tag));
+        for child in [a, b, c] {
+            parent.append_child(child).unwrap();
+        }
+
+        let replaced = Node::replace(b, c, parent).unwrap();
+
+        assert!(std::ptr::eq(replaced, b));
+        assert_eq!(child_tags(parent), ["a", "c"]);
+        assert!(same(b.parent_node(), None));
+    }
+
+    #[test]
+    fn replace_checks_the_hierarchy() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let parent = element(&arena, document, "div");
+        let child = element(&arena, document, "a");
+        parent.append_child(child).unwrap();
+        let stranger = element(&arena, document, "b");
+
+        assert_eq!(
+            Node::replace(child, parent, parent),
+            Err(DomException::HierarchyRequestError)
+        );
+        assert_eq!(
+            Node::replace(stranger, element(&arena, document, "c"), parent),
+            Err(DomException::NotFoundError)
+        );
+        assert_eq!(child_tags(parent), ["a"]);
+    }
+
+    #[test]
+    fn pre_insertion_validity() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let html = element(&arena, document, "html");
+        let body = element(&arena, document, "body");
+        html.append_child(body).unwrap();
+
+        // NOTE: A node can't be inserted into itself or one of its descendants.
+        assert_eq!(
+            Node::ensure_pre_insertion_validity(html, body, None),
+            Err(DomException::HierarchyRequestError)
+        );
+        // NOTE: The child to insert before has to be a child of the parent.
+        assert_eq!(
+            Node::ensure_pre_insertion_validity(element(&arena, document, "p"), html, Some(html)),
+            Err(DomException::NotFoundError)
+        );
+        // NOTE: Text nodes can't be children of a document, and doctypes can only be children
+        //       of a document.
+        assert_eq!(
+            Node::ensure_pre_insertion_validity(text(&arena, document, "x"), document, None),
+            Err(DomException::HierarchyRequestError)
+        );
+        assert_eq!(
+            Node::ensure_pre_insertion_validity(doctype(&arena, document), body, None),
+            Err(DomException::HierarchyRequestError)
+        );
+        assert_eq!(
+            Node::ensure_pre_insertion_validity(text(&arena, document, "x"), body, None),
+            Ok(())
+        );
+
+        // NOTE: A document has at most one element, and its doctype comes before it.
+        assert_eq!(Node::append(html, document, false), Ok(html));
+        assert_eq!(
+            Node::append(element(&arena, document, "html"), document, false),
+            Err(DomException::HierarchyRequestError)
+        );
+        assert_eq!(
+            Node::append(doctype(&arena, document), document, false),
+            Err(DomException::HierarchyRequestError)
+        );
+        let doctype = doctype(&arena, document);
+        assert_eq!(Node::pre_insert(doctype, document, Some(html)), Ok(doctype));
+        assert!(same(document.first_child(), Some(doctype)));
+    }
+
+    #[test]
+    fn adopt_a_node_from_another_document() {
+        let arena = typed_arena::Arena::new();
+        let old_document = document(&arena);
+        let new_document = document(&arena);
+        let div = element(&arena, old_document, "div");
+        let span = element(&arena, old_document, "span");
+        div.append_child(span).unwrap();
+        let parent = element(&arena, new_document, "body");
+
+        parent.append_child(div).unwrap();
+
+        assert!(std::ptr::eq(div.node_document(), new_document));
+        assert!(std::ptr::eq(span.node_document(), new_document));
+        assert_eq!(child_tags(parent), ["div"]);
     }
 }
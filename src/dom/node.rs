@@ -2,33 +2,37 @@ use std::cell::{Cell, Ref, RefCell};
 
 use crate::html::Namespace;
 
-use super::Element;
+use super::exception::DomException;
+use super::{Document, Element};
 
 pub type NodeLink<'a> = Cell<Option<NodeRef<'a>>>;
 pub type NodeRef<'a> = &'a Node<'a>;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum NodeType {
-    Element(Element),
+pub enum NodeType<'a> {
+    Element(Element<'a>),
     Attr,
     Text {
         data: RefCell<String>,
     },
     CDataSection,
     ProcessingInstruction,
-    Comment,
-    Document,
+    Comment {
+        data: RefCell<String>,
+    },
+    Document(Document),
+    /// https://dom.spec.whatwg.org/#interface-documenttype
     DocumentType {
         name: String,
-        public_identifier: String,
-        system_identifier: String,
+        public_id: String,
+        system_id: String,
     },
     DocumentFragment,
 }
 
-#[derive(Debug, Clone, Eq)]
+#[derive(Clone, Eq)]
 pub struct Node<'a> {
-    pub node_type: NodeType,
+    pub node_type: NodeType<'a>,
 
     pub(super) parent: NodeLink<'a>,
     pub(super) first_child: NodeLink<'a>,
@@ -40,27 +44,59 @@ pub struct Node<'a> {
     pub(super) node_document: NodeLink<'a>,
 }
 
+// NOTE: Nodes link to their parent, siblings and children, which link back to them, so a derived
+//       implementation would never finish. Only the node itself is printed.
+impl std::fmt::Debug for Node<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Node")
+            .field("node_type", &self.node_type)
+            .finish_non_exhaustive()
+    }
+}
+
 // IDL
 // https://dom.spec.whatwg.org/#interface-node
 impl<'a> Node<'a> {
     // https://dom.spec.whatwg.org/#dom-node-nodename
     pub fn node_name(&self) -> String {
         match &self.node_type {
-            NodeType::Element(element) => {
-                // FIXME: Implement propperly
-                element.tag_name.to_ascii_uppercase()
-            }
+            NodeType::Element(element) => element.tag_name(),
             NodeType::Attr => todo!(),
             NodeType::Text { .. } => "#text".to_string(),
             NodeType::CDataSection => "#cdata-section".to_string(),
             NodeType::ProcessingInstruction => todo!(),
-            NodeType::Comment => "#comment".to_string(),
-            NodeType::Document => "#document".to_string(),
+            NodeType::Comment { .. } => "#comment".to_string(),
+            NodeType::Document(_) => "#document".to_string(),
             NodeType::DocumentType { name, .. } => name.to_string(),
             NodeType::DocumentFragment => "#document-fragment".to_string(),
         }
     }
 
+    // https://dom.spec.whatwg.org/#dom-node-isconnected
+    pub fn is_connected(&self) -> bool {
+        // The isConnected getter steps are to return true, if this is connected; otherwise false.
+        // An element is connected if its shadow-including root is a document.
+        // FIXME: Use the shadow-including root once we support shadow trees.
+        self.root().is_document()
+    }
+
+    // https://dom.spec.whatwg.org/#dom-node-getrootnode
+    pub fn get_root_node(&self) -> &Node<'a> {
+        // The getRootNode(options) method steps are to return this’s shadow-including root if options["composed"] is true; otherwise this’s root.
+        // FIXME: Support the composed option once we support shadow trees.
+        self.root()
+    }
+
+    // https://dom.spec.whatwg.org/#concept-tree-root
+    pub fn root(&self) -> &Node<'a> {
+        // The root of an object is itself, if its parent is null, or else it is the root of its parent.
+        let mut root = self;
+        while let Some(parent) = root.parent_node() {
+            root = parent;
+        }
+        root
+    }
+
     // https://dom.spec.whatwg.org/#dom-node-parentnode
     pub fn parent_node(&self) -> Option<NodeRef<'a>> {
         self.parent.get()
@@ -76,7 +112,7 @@ impl<'a> Node<'a> {
 
     // https://dom.spec.whatwg.org/#dom-node-childnodes
     // FIXME: Should return a NodeList.
-    pub fn child_nodes(&self) -> Ref<Vec<NodeRef<'a>>> {
+    pub fn child_nodes(&self) -> Ref<'_, Vec<NodeRef<'a>>> {
         self.children.borrow()
     }
 
@@ -100,14 +136,291 @@ impl<'a> Node<'a> {
         self.next_sibling.get()
     }
 
+    // https://dom.spec.whatwg.org/#dom-node-textcontent
+    pub fn text_content(&self) -> Option<String> {
+        // The textContent getter steps are to return the following, switching on the interface this implements:
+        match &self.node_type {
+            // DocumentFragment, Element: The descendant text content of this.
+            NodeType::DocumentFragment | NodeType::Element(_) => {
+                Some(self.descendant_text_content())
+            }
+            // FIXME: Attr: this's value.
+            // CharacterData: this's data.
+            NodeType::Text { data } | NodeType::Comment { data } => Some(data.borrow().clone()),
+            // Otherwise: Null.
+            _ => None,
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#dom-node-normalize
+    pub fn normalize(&self) {
+        // The normalize() method steps are to run these steps for each descendant exclusive Text node node of this:
+        let text_nodes = self
+            .descendants()
+            .filter(|descendant| descendant.is_text())
+            .collect::<Vec<_>>();
+        for node in text_nodes {
+            // NOTE: Nodes that were merged into a preceding Text node have already been removed.
+            if node.parent_node().is_none() {
+                continue;
+            }
+
+            let NodeType::Text { data } = &node.node_type else {
+                unreachable!();
+            };
+
+            // 1. Let length be node’s length.
+            // 2. If length is zero, then remove node and continue with the next exclusive Text node, if any.
+            if data.borrow().is_empty() {
+                Node::remove_node(node, false);
+                continue;
+            }
+
+            // 3. Let data be the concatenation of the data of node’s contiguous exclusive Text nodes (excluding itself), in tree order.
+            // 4. Replace data with node node, offset length, count 0, and data data.
+            // 5. Let currentNode be node’s next sibling.
+            // 6. While currentNode is an exclusive Text node:
+            // 7. Remove node’s contiguous exclusive Text nodes (excluding itself), in tree order.
+            // NOTE: We merge and remove the following Text nodes one at a time.
+            let mut current_node = node.next_sibling();
+            while let Some(current) = current_node.filter(|current| current.is_text()) {
+                if let NodeType::Text { data: current_data } = &current.node_type {
+                    data.borrow_mut().push_str(&current_data.borrow());
+                }
+
+                // FIXME: 6.1-6.4. Update the live ranges.
+
+                // 6.5. Set currentNode to its next sibling.
+                current_node = current.next_sibling();
+                Node::remove_node(current, false);
+            }
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#dom-node-comparedocumentposition
+    pub fn compare_document_position(&self, other: &Node<'a>) -> u16 {
+        // 1. If this is other, then return zero.
+        if std::ptr::eq(self, other) {
+            return 0;
+        }
+
+        // 2. Let node1 be other and node2 be this.
+        let node1 = other;
+        let node2 = self;
+
+        // FIXME: 3-4. Handle Attr nodes once they are part of the tree.
+
+        // 5. If node1 or node2 is null, or node1's root is not node2's root, then return the result of adding DOCUMENT_POSITION_DISCONNECTED, DOCUMENT_POSITION_IMPLEMENTATION_SPECIFIC, and either DOCUMENT_POSITION_PRECEDING or DOCUMENT_POSITION_FOLLOWING, with the constraint that this is to be consistent, together.
+        if !std::ptr::eq(node1.root(), node2.root()) {
+            // NOTE: Comparing the addresses of the roots is consistent for as long as both trees are alive.
+            let direction = match (node1.root() as *const Node) < (node2.root() as *const Node) {
+                true => Self::DOCUMENT_POSITION_PRECEDING,
+                false => Self::DOCUMENT_POSITION_FOLLOWING,
+            };
+            return Self::DOCUMENT_POSITION_DISCONNECTED
+                | Self::DOCUMENT_POSITION_IMPLEMENTATION_SPECIFIC
+                | direction;
+        }
+
+        // 6. If node1 is an ancestor of node2 and attr1 is null, or node1 is node2 and attr2 is non-null, then return the result of adding DOCUMENT_POSITION_CONTAINS to DOCUMENT_POSITION_PRECEDING.
+        if node1.contains(node2) {
+            return Self::DOCUMENT_POSITION_CONTAINS | Self::DOCUMENT_POSITION_PRECEDING;
+        }
+
+        // 7. If node1 is a descendant of node2 and attr2 is null, or node1 is node2 and attr1 is non-null, then return the result of adding DOCUMENT_POSITION_CONTAINED_BY to DOCUMENT_POSITION_FOLLOWING.
+        if node2.contains(node1) {
+            return Self::DOCUMENT_POSITION_CONTAINED_BY | Self::DOCUMENT_POSITION_FOLLOWING;
+        }
+
+        // 8. If node1 is preceding node2, then return DOCUMENT_POSITION_PRECEDING.
+        if node1.is_preceding(node2) {
+            return Self::DOCUMENT_POSITION_PRECEDING;
+        }
+
+        // 9. Return DOCUMENT_POSITION_FOLLOWING.
+        Self::DOCUMENT_POSITION_FOLLOWING
+    }
+
+    /// Compares the position of this node and `other` in tree order.
+    ///
+    /// An ancestor comes before its descendants. Nodes in different trees are ordered the same
+    /// way [`Node::compare_document_position`] orders them.
+    ///
+    /// NOTE: This is the [`std::cmp::Ordering`] counterpart of [`Node::compare_document_position`],
+    ///       which keeps the DOM's name and returns its bitmask instead, so it can be used with
+    ///       `sort_by` and friends.
+    pub fn compare_tree_order(&self, other: &Node<'a>) -> std::cmp::Ordering {
+        let position = self.compare_document_position(other);
+        if position == 0 {
+            std::cmp::Ordering::Equal
+        } else if position & Self::DOCUMENT_POSITION_PRECEDING != 0 {
+            // NOTE: other is preceding this node, so this node comes after it.
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Less
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#dom-node-contains
+    pub fn contains(&self, other: &Node<'a>) -> bool {
+        // The contains(other) method steps are to return true if other is an inclusive descendant of this; otherwise false (including when other is null).
+        // NOTE: A node without children only contains itself. This saves walking all ancestors of
+        //       `other` for a node that was just created, like when the parser inserts an element.
+        if self.first_child().is_none() {
+            return std::ptr::eq(self, other);
+        }
+        let mut node = Some(other);
+        while let Some(current) = node {
+            if std::ptr::eq(current, self) {
+                return true;
+            }
+            node = current.parent_node();
+        }
+        false
+    }
+
     // https://dom.spec.whatwg.org/#dom-node-appendchild
-    pub fn append_child(&'a self, node: NodeRef<'a>) {
+    pub fn append_child(&'a self, node: NodeRef<'a>) -> Result<NodeRef<'a>, DomException> {
+        // The appendChild(node) method steps are to return the result of appending node to this.
         Node::append(node, self, false)
     }
+
+    // https://dom.spec.whatwg.org/#dom-node-replacechild
+    pub fn replace_child(
+        &'a self,
+        node: NodeRef<'a>,
+        child: NodeRef<'a>,
+    ) -> Result<NodeRef<'a>, DomException> {
+        // The replaceChild(node, child) method steps are to return the result of replacing child with node within this.
+        Node::replace(child, node, self)
+    }
+}
+
+// IDL
+// https://dom.spec.whatwg.org/#interface-childnode
+impl<'a> Node<'a> {
+    // https://dom.spec.whatwg.org/#dom-childnode-remove
+    pub fn remove(&'a self) {
+        // 1. If this’s parent is null, then return.
+        if self.parent_node().is_none() {
+            return;
+        }
+
+        // 2. Remove this.
+        Node::remove_node(self, false);
+    }
 }
 
 // Concepts
 impl<'a> Node<'a> {
+    // https://dom.spec.whatwg.org/#dom-node-document_position_disconnected
+    pub const DOCUMENT_POSITION_DISCONNECTED: u16 = 0x01;
+    pub const DOCUMENT_POSITION_PRECEDING: u16 = 0x02;
+    pub const DOCUMENT_POSITION_FOLLOWING: u16 = 0x04;
+    pub const DOCUMENT_POSITION_CONTAINS: u16 = 0x08;
+    pub const DOCUMENT_POSITION_CONTAINED_BY: u16 = 0x10;
+    pub const DOCUMENT_POSITION_IMPLEMENTATION_SPECIFIC: u16 = 0x20;
+
+    /// The number of ancestors of this node.
+    fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut node = self.parent_node();
+        while let Some(parent) = node {
+            depth += 1;
+            node = parent.parent_node();
+        }
+        depth
+    }
+
+    /// Returns the ancestor `levels` levels up from this node, or the node itself for 0.
+    fn ancestor_at(&self, levels: usize) -> &Node<'a> {
+        let mut node = self;
+        for _ in 0..levels {
+            node = node
+                .parent_node()
+                .expect("a node should have at least as many ancestors as its depth");
+        }
+        node
+    }
+
+    /// The deepest node that is an inclusive ancestor of both this node and `other`, or `None`
+    /// if they are in different trees.
+    ///
+    /// https://dom.spec.whatwg.org/#concept-tree-inclusive-ancestor
+    pub fn common_ancestor<'n>(&'n self, other: &'n Node<'a>) -> Option<&'n Node<'a>> {
+        let (depth, other_depth) = (self.depth(), other.depth());
+        let mut node = self.ancestor_at(depth.saturating_sub(other_depth));
+        let mut other = other.ancestor_at(other_depth.saturating_sub(depth));
+
+        loop {
+            if std::ptr::eq(node, other) {
+                return Some(node);
+            }
+            node = node.parent_node()?;
+            other = other.parent_node()?;
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#concept-tree-preceding
+    /// Whether this node comes before `other` in tree order, in the same tree.
+    pub fn is_preceding(&self, other: &Node<'a>) -> bool {
+        // An object A is preceding an object B if A and B are in the same tree and A comes before B in tree order.
+        if std::ptr::eq(self, other) {
+            return false;
+        }
+        let Some(common_ancestor) = self.common_ancestor(other) else {
+            return false;
+        };
+
+        // NOTE: An ancestor comes before its descendants in tree order.
+        if std::ptr::eq(common_ancestor, self) {
+            return true;
+        }
+        if std::ptr::eq(common_ancestor, other) {
+            return false;
+        }
+
+        // NOTE: Otherwise, the order is decided by the children of the common ancestor that
+        //       contain both nodes.
+        let common_depth = common_ancestor.depth();
+        let child_containing_self = self.ancestor_at(self.depth() - common_depth - 1);
+        let child_containing_other = other.ancestor_at(other.depth() - common_depth - 1);
+        let mut sibling = child_containing_self.next_sibling();
+        while let Some(current) = sibling {
+            if std::ptr::eq(current, child_containing_other) {
+                return true;
+            }
+            sibling = current.next_sibling();
+        }
+        false
+    }
+
+    // https://html.spec.whatwg.org/multipage/urls-and-fetching.html#document-base-url
+    /// The base URL of this document, where `document_url` is the URL it was loaded from.
+    ///
+    /// NOTE: We don't know the URL of the document, so it has to be passed in. It is also used
+    ///       as the document's fallback base URL.
+    pub fn document_base_url(&self, document_url: &str) -> String {
+        // 1. If there is no base element that has an href attribute in the Document, then return the Document's fallback base URL.
+        // 2. Otherwise, return the frozen base URL of the first base element in the Document that has an href attribute, in tree order.
+        let base_href = self
+            .descendants()
+            .filter(|node| {
+                node.is_element_with_tag("base") && node.is_element_with_namespace(Namespace::Html)
+            })
+            .find_map(|base| base.as_element()?.get_attribute("href"));
+
+        let Some(base_href) = base_href else {
+            return document_url.to_string();
+        };
+
+        // https://html.spec.whatwg.org/multipage/semantics.html#set-the-frozen-base-url
+        // 3. Let urlRecord be the result of parsing the value of element's href content attribute with document's fallback base URL, and document's character encoding.
+        // 4. If any of the following are true, then set urlRecord to document's fallback base URL: urlRecord is failure; FIXME(the result of running Is base allowed for Document? on the resulting URL record and document is "Blocked").
+        // 5. Set element's frozen base URL to urlRecord.
+        crate::url::resolve(document_url, &base_href).unwrap_or_else(|| document_url.to_string())
+    }
+
     // https://dom.spec.whatwg.org/#concept-node-document
     pub fn node_document(&'a self) -> NodeRef<'a> {
         match self.node_document.get() {
@@ -122,7 +435,7 @@ impl<'a> Node<'a> {
         let mut current = self.previous_sibling();
         while let Some(node) = current {
             index += 1;
-            current = node.next_sibling()
+            current = node.previous_sibling()
         }
         index
     }
@@ -138,11 +451,12 @@ impl<'a> Node<'a> {
 
         // 2. If node’s parent is non-null, then remove node.
         if node.parent_node().is_some() {
-            Node::remove(node, false);
+            Node::remove_node(node, false);
         }
 
         // 3. If document is not oldDocument, then:
-        if self.node_document() == old_document {
+        // NOTE: This is about identity. Comparing the documents with `==` would walk both trees.
+        if !std::ptr::eq(self.node_document(), old_document) {
             // 3.1. For each inclusiveDescendant in node’s shadow-including inclusive descendants:
             for inclusive_descendant in node.shadow_including_inclusive_descendants().iter() {
                 // 3.1.1. Set inclusiveDescendant’s node document to document.
@@ -151,9 +465,8 @@ impl<'a> Node<'a> {
                     .set(Some(self.node_document()));
 
                 // 3.1.2. If inclusiveDescendant is an element, then set the node document of each attribute in inclusiveDescendant’s attribute list to document.
-                if inclusive_descendant.is_element() {
-                    todo!()
-                }
+                // NOTE: Attributes are stored inside of their element instead of being nodes of
+                //       their own, so they don't have a node document to update.
             }
 
             // FIXME: 3.2. For each inclusiveDescendant in node’s shadow-including inclusive descendants that is custom, enqueue a custom element callback reaction with inclusiveDescendant, callback name "adoptedCallback", and an argument list containing oldDocument and document.
@@ -162,9 +475,9 @@ impl<'a> Node<'a> {
     }
 
     // https://dom.spec.whatwg.org/#concept-shadow-including-inclusive-descendant
-    pub fn shadow_including_inclusive_descendants(&self) -> Vec<NodeRef<'a>> {
-        // FIXME: Implement properly.
-        self.child_nodes().to_owned()
+    /// NOTE: We don't have shadow trees, so these are the inclusive descendants, in tree order.
+    pub fn shadow_including_inclusive_descendants(&'a self) -> Vec<NodeRef<'a>> {
+        std::iter::once(self).chain(self.descendants()).collect()
     }
 }
 
@@ -178,7 +491,7 @@ macro_rules! is_node_type {
 
 // Helpers
 impl<'a> Node<'a> {
-    pub fn new(document: Option<NodeRef<'a>>, node_type: NodeType) -> Self {
+    pub fn new(document: Option<NodeRef<'a>>, node_type: NodeType<'a>) -> Self {
         Self {
             parent: Cell::new(None),
             next_sibling: Cell::new(None),
@@ -196,44 +509,126 @@ impl<'a> Node<'a> {
     is_node_type!(is_text, NodeType::Text { .. });
     is_node_type!(is_cdata_section, NodeType::CDataSection);
     is_node_type!(is_processing_instruction, NodeType::ProcessingInstruction);
-    is_node_type!(is_comment, NodeType::Comment);
-    is_node_type!(is_document, NodeType::Document);
+    is_node_type!(is_comment, NodeType::Comment { .. });
+    is_node_type!(is_document, NodeType::Document(_));
     is_node_type!(is_document_type, NodeType::DocumentType { .. });
     is_node_type!(is_document_fragment, NodeType::DocumentFragment);
 
-    pub fn element_tag_name(&self) -> Option<String> {
+    // https://dom.spec.whatwg.org/#concept-descendant-text-content
+    pub fn descendant_text_content(&self) -> String {
+        // The descendant text content of a node node is the concatenation of the data of all the Text node descendants of node, in tree order.
+        self.descendants()
+            .filter_map(|descendant| match &descendant.node_type {
+                NodeType::Text { data } => Some(data.borrow().clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // https://dom.spec.whatwg.org/#concept-child-text-content
+    pub fn child_text_content(&self) -> String {
+        // The child text content of a node node is the concatenation of the data of all the Text node children of node, in tree order.
+        self.child_nodes()
+            .iter()
+            .filter_map(|child| match &child.node_type {
+                NodeType::Text { data } => Some(data.borrow().clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn as_element(&self) -> Option<&Element<'a>> {
         match &self.node_type {
-            NodeType::Element(element) => Some(element.tag_name.to_string()),
+            NodeType::Element(element) => Some(element),
             _ => None,
         }
     }
 
-    pub fn is_element_with_one_of_tags(&self, tags: &[&str]) -> bool {
-        if let NodeType::Element(element) = &self.node_type {
-            return tags.contains(&element.tag_name.as_str());
+    pub fn as_document(&self) -> Option<&Document> {
+        match &self.node_type {
+            NodeType::Document(document) => Some(document),
+            _ => None,
         }
-        false
+    }
+
+    pub fn element_tag_name(&self) -> Option<String> {
+        self.as_element()
+            .map(|element| element.local_name().to_string())
+    }
+
+    pub fn is_element_with_one_of_tags(&self, tags: &[&str]) -> bool {
+        self.as_element()
+            .is_some_and(|element| tags.contains(&element.local_name().as_str()))
     }
 
     pub fn is_element_with_tag(&self, tag: &str) -> bool {
-        if let NodeType::Element(element) = &self.node_type {
-            return element.tag_name == tag;
-        }
-        false
+        self.as_element()
+            .is_some_and(|element| element.local_name() == tag)
+    }
+
+    /// Whether this node is an element in the HTML namespace with `tag` as its local name.
+    pub fn is_html_element_with_tag(&self, tag: &str) -> bool {
+        self.is_element_with_namespace(Namespace::Html) && self.is_element_with_tag(tag)
+    }
+
+    /// Whether this node is an element in the HTML namespace with one of `tags` as its local name.
+    pub fn is_html_element_with_one_of_tags(&self, tags: &[&str]) -> bool {
+        self.is_element_with_namespace(Namespace::Html) && self.is_element_with_one_of_tags(tags)
     }
 
     pub fn is_element_with_namespace(&self, namespace: Namespace) -> bool {
-        if let NodeType::Element(element) = &self.node_type {
-            return Some(namespace) == element.namespace;
-        }
-        false
+        self.as_element()
+            .is_some_and(|element| element.namespace() == namespace)
     }
 
+    /// Prints this node and its descendants, one node per line.
+    ///
+    /// NOTE: The nodes that still have to be printed are kept on an explicit stack instead of
+    ///       recursing, so arbitrarily deep trees don't overflow the call stack.
     pub fn dump(&'a self, settings: DumpSettings) {
-        self.internal_dump("", &settings);
+        let gray = if settings.color { "\x1b[90m" } else { "" };
+        let reset = if settings.color { "\x1b[0m" } else { "" };
+
+        // The steps to take, with the next one at the end.
+        let mut steps = vec![DumpStep::Node(self, String::new())];
+        while let Some(step) = steps.pop() {
+            let (node, indentation) = match step {
+                DumpStep::Line(line) => {
+                    println!("{line}");
+                    continue;
+                }
+                DumpStep::Node(node, indentation) => (node, indentation),
+            };
+
+            println!("{indentation}{}", node.dump_line(&settings));
+
+            let child_indentation = format!("{indentation}{}", settings.indentation);
+            if let Some(closing_marker) = settings.closing_marker {
+                steps.push(DumpStep::Line(format!("{indentation}{closing_marker}")));
+            }
+            // NOTE: Like the html5lib tree format, we show the template contents as a "content" node.
+            if let Some(template_contents) = node.as_element().and_then(|element| element.content())
+            {
+                let content_indentation = format!("{child_indentation}{}", settings.indentation);
+                if let Some(closing_marker) = settings.closing_marker {
+                    steps.push(DumpStep::Line(format!(
+                        "{child_indentation}{closing_marker}"
+                    )));
+                }
+                for child in template_contents.child_nodes().iter().rev() {
+                    steps.push(DumpStep::Node(child, content_indentation.clone()));
+                }
+                steps.push(DumpStep::Line(format!(
+                    "{child_indentation}{gray}content{reset}"
+                )));
+            }
+            for child in node.child_nodes().iter().rev() {
+                steps.push(DumpStep::Node(child, child_indentation.clone()));
+            }
+        }
     }
 
-    fn internal_dump(&'a self, indentation: &str, settings: &DumpSettings) {
+    fn dump_line(&self, settings: &DumpSettings) -> String {
         macro_rules! color {
             ($color:literal) => {
                 if settings.color {
@@ -252,11 +647,22 @@ impl<'a> Node<'a> {
         let green = color!("\x1b[32m");
         let gray = color!("\x1b[90m");
 
-        let opening = match &self.node_type {
-            NodeType::DocumentType { name, .. } => {
-                format!("{yellow}DOCTYPE {white}{}{reset}", name)
+        match &self.node_type {
+            NodeType::DocumentType {
+                name,
+                public_id,
+                system_id,
+            } => {
+                let mut opening = format!("{yellow}DOCTYPE {white}{}{reset}", name);
+                if !public_id.is_empty() || !system_id.is_empty() {
+                    opening.push_str(&format!(
+                        " {gray}\"{white}{}{gray}\" \"{white}{}{gray}\"{reset}",
+                        public_id, system_id
+                    ));
+                }
+                opening
             }
-            NodeType::Text { data } => {
+            NodeType::Text { data } | NodeType::Comment { data } => {
                 format!("{gray}{}: \"{white}{}{gray}\"{reset}", self.node_name(), {
                     let data = data.borrow().clone();
                     match settings.trim_text {
@@ -270,25 +676,25 @@ impl<'a> Node<'a> {
                 for attr in element.attributes.borrow().iter() {
                     attr_string.push_str(&format!(
                         "{cyan}{}{gray}{blue}={gray}\"{green}{}{gray}\" ",
-                        attr.name, attr.value
+                        attr.qualified_name(),
+                        attr.value
                     ));
                 }
                 format!("{yellow}{} {}{reset}", self.node_name(), attr_string)
             }
             _ => format!("{gray}{}{reset}", self.node_name()),
-        };
-        println!("{indentation}{}", opening);
-        for child in self.child_nodes().iter() {
-            let mut indentation = indentation.to_string();
-            indentation.push_str(settings.indentation);
-            child.internal_dump(&indentation, settings);
-        }
-        if let Some(closing_marker) = settings.closing_marker {
-            println!("{indentation}{closing_marker}");
         }
     }
 }
 
+/// A step of [`Node::dump`].
+enum DumpStep<'n, 'a> {
+    /// Print the node, with the indentation in front of it, and then its descendants.
+    Node(&'n Node<'a>, String),
+    /// Print the line as it is.
+    Line(String),
+}
+
 // https://dom.spec.whatwg.org/#concept-node-equals
 impl<'a> PartialEq for Node<'a> {
     fn eq(&self, other: &Self) -> bool {
@@ -303,18 +709,18 @@ impl<'a> PartialEq for Node<'a> {
                 (
                     NodeType::DocumentType {
                         name: name_a,
-                        public_identifier: pub_id_a,
-                        system_identifier: sys_id_a,
+                        public_id: pub_id_a,
+                        system_id: sys_id_a,
                     },
                     NodeType::DocumentType {
                         name: name_b,
-                        public_identifier: pub_id_b,
-                        system_identifier: sys_id_b,
+                        public_id: pub_id_b,
+                        system_id: sys_id_b,
                     },
                 ) => name_a == name_b && pub_id_a == pub_id_b && sys_id_a == sys_id_b,
                 (NodeType::Element(element_a), NodeType::Element(element_b)) => {
-                    element_a.tag_name == element_b.tag_name
-                        && element_a.namespace == element_b.namespace
+                    element_a.local_name() == element_b.local_name()
+                        && element_a.namespace() == element_b.namespace()
                         && element_a.attributes.borrow().len()
                             == element_b.attributes.borrow().len()
                 }
@@ -323,7 +729,9 @@ impl<'a> PartialEq for Node<'a> {
                 (NodeType::Text { data: data_a }, NodeType::Text { data: data_b }) => {
                     data_a == data_b
                 }
-                (NodeType::Comment, NodeType::Comment) => todo!(),
+                (NodeType::Comment { data: data_a }, NodeType::Comment { data: data_b }) => {
+                    data_a == data_b
+                }
                 _ => true,
             }
             && match &self.node_type {
@@ -371,3 +779,264 @@ impl Default for DumpSettings {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::{Document, Element};
+
+    fn document<'a>(arena: &'a typed_arena::Arena<Node<'a>>) -> NodeRef<'a> {
+        arena.alloc(Node::new(None, NodeType::Document(Document::new())))
+    }
+
+    fn element<'a>(
+        arena: &'a typed_arena::Arena<Node<'a>>,
+        document: NodeRef<'a>,
+        tag: &str,
+    ) -> NodeRef<'a> {
+        arena.alloc(Node::new(
+            Some(document),
+            NodeType::Element(Element::new(tag.into(), Namespace::Html)),
+        ))
+    }
+
+    #[test]
+    fn index_counts_preceding_siblings() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let parent = element(&arena, document, "div");
+        let children = [
+            element(&arena, document, "a"),
+            element(&arena, document, "b"),
+            element(&arena, document, "c"),
+        ];
+        for child in children {
+            parent.append_child(child).unwrap();
+        }
+
+        assert_eq!(children.map(|child| child.index()), [0, 1, 2]);
+    }
+
+    #[test]
+    fn parsed_elements_are_connected() {
+        let parser = crate::html::parser::Parser::new(typed_arena::Arena::new(), "<p>a</p>");
+        let document = parser.parse();
+        let p = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("p"))
+            .unwrap();
+        assert!(p.is_connected());
+    }
+
+    #[test]
+    fn is_connected_follows_the_parent_chain() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let html = element(&arena, document, "html");
+        let div = element(&arena, document, "div");
+        assert!(!div.is_connected());
+
+        document.append_child(html).unwrap();
+        assert!(html.is_connected());
+        assert!(!div.is_connected());
+
+        html.append_child(div).unwrap();
+        assert!(div.is_connected());
+        assert!(std::ptr::eq(div.root(), document));
+    }
+
+    #[test]
+    fn tree_position_queries() {
+        use crate::html::parser::Parser;
+
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<div id=a><p id=b><i id=c></i></p><p id=d></p></div>",
+        );
+        let document = parser.parse();
+        let other_parser = Parser::new(typed_arena::Arena::new(), "<p id=e>");
+        let other_document = other_parser.parse();
+        fn by_id<'a>(document: &Node<'a>, id: &str) -> NodeRef<'a> {
+            document
+                .descendants()
+                .find(|node| {
+                    node.as_element()
+                        .is_some_and(|element| element.get_attribute("id").as_deref() == Some(id))
+                })
+                .unwrap()
+        }
+        let (a, b, c, d) = (
+            by_id(&document, "a"),
+            by_id(&document, "b"),
+            by_id(&document, "c"),
+            by_id(&document, "d"),
+        );
+        let e = by_id(&other_document, "e");
+
+        assert!(a.contains(a));
+        assert!(a.contains(c));
+        assert!(!c.contains(a));
+        assert!(!b.contains(d));
+        assert!(!a.contains(e));
+
+        assert!(std::ptr::eq(c.common_ancestor(d).unwrap(), a));
+        assert!(std::ptr::eq(b.common_ancestor(c).unwrap(), b));
+        assert!(std::ptr::eq(d.common_ancestor(d).unwrap(), d));
+        assert!(c.common_ancestor(e).is_none());
+
+        assert_eq!(a.compare_document_position(a), 0);
+        assert_eq!(
+            c.compare_document_position(a),
+            Node::DOCUMENT_POSITION_CONTAINS | Node::DOCUMENT_POSITION_PRECEDING
+        );
+        assert_eq!(
+            a.compare_document_position(c),
+            Node::DOCUMENT_POSITION_CONTAINED_BY | Node::DOCUMENT_POSITION_FOLLOWING
+        );
+        assert_eq!(
+            d.compare_document_position(c),
+            Node::DOCUMENT_POSITION_PRECEDING
+        );
+        assert_eq!(
+            c.compare_document_position(d),
+            Node::DOCUMENT_POSITION_FOLLOWING
+        );
+        assert_eq!(
+            d.compare_document_position(b),
+            Node::DOCUMENT_POSITION_PRECEDING
+        );
+
+        let disconnected = a.compare_document_position(e);
+        assert_eq!(
+            disconnected
+                & (Node::DOCUMENT_POSITION_DISCONNECTED
+                    | Node::DOCUMENT_POSITION_IMPLEMENTATION_SPECIFIC),
+            Node::DOCUMENT_POSITION_DISCONNECTED | Node::DOCUMENT_POSITION_IMPLEMENTATION_SPECIFIC
+        );
+        // NOTE: The direction is arbitrary, but has to be consistent for all nodes in both trees.
+        let direction =
+            disconnected & (Node::DOCUMENT_POSITION_PRECEDING | Node::DOCUMENT_POSITION_FOLLOWING);
+        assert!(
+            direction == Node::DOCUMENT_POSITION_PRECEDING
+                || direction == Node::DOCUMENT_POSITION_FOLLOWING
+        );
+        assert_eq!(c.compare_document_position(e), disconnected);
+        assert_eq!(
+            e.compare_document_position(d),
+            (Node::DOCUMENT_POSITION_PRECEDING | Node::DOCUMENT_POSITION_FOLLOWING) ^ direction
+                | Node::DOCUMENT_POSITION_DISCONNECTED
+                | Node::DOCUMENT_POSITION_IMPLEMENTATION_SPECIFIC
+        );
+    }
+
+    #[test]
+    fn tree_order() {
+        use std::cmp::Ordering;
+
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let div = element(&arena, document, "div");
+        let [a, b, c] = [
+            element(&arena, document, "a"),
+            element(&arena, document, "b"),
+            element(&arena, document, "c"),
+        ];
+        let i = element(&arena, document, "i");
+        let detached = element(&arena, document, "p");
+        document.append_child(div).unwrap();
+        for child in [a, b, c] {
+            div.append_child(child).unwrap();
+        }
+        a.append_child(i).unwrap();
+
+        assert!(std::ptr::eq(i.get_root_node(), document));
+        assert!(std::ptr::eq(detached.get_root_node(), detached));
+
+        assert_eq!(div.compare_tree_order(i), Ordering::Less);
+        assert_eq!(i.compare_tree_order(div), Ordering::Greater);
+        assert_eq!(a.compare_tree_order(c), Ordering::Less);
+        assert_eq!(c.compare_tree_order(b), Ordering::Greater);
+        assert_eq!(i.compare_tree_order(b), Ordering::Less);
+        assert_eq!(b.compare_tree_order(b), Ordering::Equal);
+
+        let mut nodes = [c, i, document, b, div, a];
+        nodes.sort_by(|x, y| x.compare_tree_order(y));
+        assert!(nodes
+            .iter()
+            .zip([document, div, a, i, b, c])
+            .all(|(x, y)| std::ptr::eq(*x, y)));
+
+        // NOTE: Nodes in different trees are ordered arbitrarily, but consistently.
+        let ordering = detached.compare_tree_order(a);
+        assert_ne!(ordering, Ordering::Equal);
+        assert_eq!(detached.compare_tree_order(i), ordering);
+        assert_eq!(c.compare_tree_order(detached), ordering.reverse());
+    }
+
+    #[test]
+    fn remove_detaches_the_node_from_its_parent() {
+        let arena = typed_arena::Arena::new();
+        let document = document(&arena);
+        let parent = element(&arena, document, "div");
+        let [a, b, c] = [
+            element(&arena, document, "a"),
+            element(&arena, document, "b"),
+            element(&arena, document, "c"),
+        ];
+        for child in [a, b, c] {
+            parent.append_child(child).unwrap();
+        }
+
+        b.remove();
+        assert!(b.parent_node().is_none());
+        assert!(b.previous_sibling().is_none() && b.next_sibling().is_none());
+        assert_eq!(parent.child_nodes().len(), 2);
+        assert!(!parent
+            .child_nodes()
+            .iter()
+            .any(|child| std::ptr::eq(*child, b)));
+        assert!(std::ptr::eq(a.next_sibling().unwrap(), c));
+        assert!(std::ptr::eq(c.previous_sibling().unwrap(), a));
+
+        // NOTE: Removing a node that is already detached does nothing.
+        b.remove();
+        assert!(b.parent_node().is_none());
+
+        a.remove();
+        c.remove();
+        assert!(parent.first_child().is_none() && parent.last_child().is_none());
+        assert!(parent.child_nodes().is_empty());
+    }
+
+    #[test]
+    fn debug_does_not_follow_links() {
+        let parser = crate::html::parser::Parser::new(
+            typed_arena::Arena::new(),
+            "<div id=x><p>child text</p><template><b>t</b></template></div>",
+        );
+        let document = parser.parse();
+        let find = |tag: &str| {
+            document
+                .descendants()
+                .find(|node| node.is_element_with_tag(tag))
+                .unwrap()
+        };
+
+        let div = format!("{:?}", find("div"));
+        assert!(div.contains("\"div\"") && div.contains("\"x\""), "{div}");
+        assert!(!div.contains("child text"), "{div}");
+        // NOTE: The template contents are not followed either.
+        let template = format!("{:#?}", find("template"));
+        assert!(template.contains("\"template\""), "{template}");
+        assert!(!template.contains("\"b\""), "{template}");
+
+        let text = find("p").first_child().unwrap();
+        assert!(format!("{text:?}").contains("child text"));
+        assert!(!format!("{text:?}").contains("\"p\""));
+
+        // NOTE: Attributes that are being changed are printed as borrowed instead of panicking.
+        let element = find("div").as_element().unwrap();
+        let _attributes = element.attributes.borrow_mut();
+        assert!(format!("{:?}", find("div")).contains("<borrowed>"));
+    }
+}
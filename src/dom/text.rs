@@ -0,0 +1,167 @@
+use super::exception::DomException;
+use super::node::{Node, NodeRef, NodeType};
+
+// IDL
+// https://dom.spec.whatwg.org/#interface-text
+impl<'a> Node<'a> {
+    // https://dom.spec.whatwg.org/#dom-text-splittext
+    /// Splits this Text node in two at `offset`, and returns the new node that holds the data
+    /// after the offset. The new node is allocated in `arena`.
+    ///
+    /// NOTE: The offset counts characters, not UTF-16 code units like the spec does, so a split
+    ///       always happens at a character boundary.
+    pub fn split_text(
+        &'a self,
+        offset: usize,
+        arena: &'a typed_arena::Arena<Node<'a>>,
+    ) -> Result<NodeRef<'a>, DomException> {
+        let NodeType::Text { data } = &self.node_type else {
+            panic!("only Text nodes can be split");
+        };
+
+        // https://dom.spec.whatwg.org/#concept-text-split
+        // 1. Let length be node’s length.
+        let length = data.borrow().chars().count();
+
+        // 2. If offset is greater than length, then throw an "IndexSizeError" DOMException.
+        if offset > length {
+            return Err(DomException::IndexSizeError);
+        }
+
+        // 3. Let count be length minus offset.
+        // 4. Let new data be the result of substringing data with node node, offset offset, and count count.
+        let byte_offset = data
+            .borrow()
+            .char_indices()
+            .nth(offset)
+            .map_or(data.borrow().len(), |(index, _)| index);
+        let new_data = data.borrow()[byte_offset..].to_string();
+
+        // 5. Let new node be a new Text node, with the same node document as node. Set new node’s data to new data.
+        let new_node = arena.alloc(Node::new(
+            Some(self.node_document()),
+            NodeType::Text {
+                data: new_data.into(),
+            },
+        ));
+
+        // 6. Let parent be node’s parent.
+        // 7. If parent is not null, then:
+        if let Some(parent) = self.parent_node() {
+            // 7.1. Insert new node into parent before node’s next sibling.
+            Node::insert(new_node, parent, self.next_sibling(), false);
+
+            // FIXME: 7.2-7.5. Update the live ranges.
+        }
+
+        // 8. Replace data with node node, offset offset, count count, and data the empty string.
+        data.borrow_mut().truncate(byte_offset);
+
+        // 9. Return new node.
+        Ok(new_node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::{Document, Element};
+    use crate::html::Namespace;
+
+    fn text<'a>(
+        arena: &'a typed_arena::Arena<Node<'a>>,
+        document: NodeRef<'a>,
+        data: &str,
+    ) -> NodeRef<'a> {
+        arena.alloc(Node::new(
+            Some(document),
+            NodeType::Text {
+                data: data.to_string().into(),
+            },
+        ))
+    }
+
+    fn data(nodes: &[NodeRef]) -> Vec<Option<String>> {
+        nodes.iter().map(|node| node.text_content()).collect()
+    }
+
+    #[test]
+    fn normalize_merges_adjacent_text_nodes() {
+        let arena = typed_arena::Arena::new();
+        let document: NodeRef = arena.alloc(Node::new(None, NodeType::Document(Document::new())));
+        let element = |tag: &str| -> NodeRef {
+            arena.alloc(Node::new(
+                Some(document),
+                NodeType::Element(Element::new(tag.into(), Namespace::Html)),
+            ))
+        };
+        let div = element("div");
+        let span = element("span");
+        for child in [
+            text(&arena, document, "a"),
+            text(&arena, document, ""),
+            text(&arena, document, "b"),
+            span,
+            text(&arena, document, ""),
+        ] {
+            div.append_child(child).unwrap();
+        }
+        for data in ["c", "d", "é"] {
+            span.append_child(text(&arena, document, data)).unwrap();
+        }
+
+        div.normalize();
+
+        let children = div.child_nodes().clone();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].text_content().as_deref(), Some("ab"));
+        assert!(std::ptr::eq(children[1], span));
+        assert_eq!(data(&span.child_nodes()), [Some("cdé".to_string())]);
+        assert!(std::ptr::eq(span.previous_sibling().unwrap(), children[0]));
+        assert!(span.next_sibling().is_none());
+    }
+
+    #[test]
+    fn split_text_at_character_offsets() {
+        let arena = typed_arena::Arena::new();
+        let document: NodeRef = arena.alloc(Node::new(None, NodeType::Document(Document::new())));
+        let div: NodeRef = arena.alloc(Node::new(
+            Some(document),
+            NodeType::Element(Element::new("div".into(), Namespace::Html)),
+        ));
+        let first = text(&arena, document, "héllo");
+        let last = text(&arena, document, "!");
+        div.append_child(first).unwrap();
+        div.append_child(last).unwrap();
+
+        // NOTE: "é" is two bytes, so the character offset 2 is the byte offset 3.
+        let second = first.split_text(2, &arena).unwrap();
+        assert_eq!(
+            data(&div.child_nodes()),
+            [
+                Some("hé".to_string()),
+                Some("llo".to_string()),
+                Some("!".to_string())
+            ]
+        );
+        assert!(std::ptr::eq(first.next_sibling().unwrap(), second));
+        assert!(std::ptr::eq(second.next_sibling().unwrap(), last));
+
+        assert_eq!(
+            first.split_text(3, &arena),
+            Err(DomException::IndexSizeError)
+        );
+        let empty = first.split_text(2, &arena).unwrap();
+        assert_eq!(empty.text_content().as_deref(), Some(""));
+        assert_eq!(div.child_nodes().len(), 4);
+
+        // NOTE: A Text node without a parent is split too, the new node just isn't inserted anywhere.
+        let detached = text(&arena, document, "ab");
+        let split = detached.split_text(1, &arena).unwrap();
+        assert_eq!(
+            data(&[detached, split]),
+            [Some("a".to_string()), Some("b".to_string())]
+        );
+        assert!(split.parent_node().is_none());
+    }
+}
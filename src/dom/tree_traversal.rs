@@ -0,0 +1,328 @@
+use super::node::{Node, NodeRef};
+
+// 4.2.1 Node tree
+// https://dom.spec.whatwg.org/#concept-node-tree
+impl<'a> Node<'a> {
+    // https://dom.spec.whatwg.org/#concept-tree-descendant
+    /// Iterates over the descendants of this node in tree order (preorder, depth-first).
+    ///
+    /// NOTE: The traversal keeps the nodes it still has to visit on an explicit stack instead of
+    ///       recursing, so arbitrarily deep trees don't overflow the call stack.
+    pub fn descendants(&self) -> Descendants<'a> {
+        let mut stack = vec![];
+        push_children_in_reverse(self, &mut stack);
+        Descendants { stack }
+    }
+
+    // https://dom.spec.whatwg.org/#concept-tree-following
+    /// Iterates over the nodes that come after this node in tree order, starting with its
+    /// descendants and ending with the last node of its tree.
+    pub fn following(&self) -> Following<'a> {
+        Following {
+            descendants: self.descendants(),
+            pending_sibling: self.next_sibling(),
+            ancestor: self.parent_node(),
+        }
+    }
+}
+
+// 4.2.6 Mixin ParentNode
+// https://dom.spec.whatwg.org/#interface-parentnode
+impl<'a> Node<'a> {
+    // https://dom.spec.whatwg.org/#dom-parentnode-children
+    // FIXME: Should return an HTMLCollection.
+    /// Iterates over the children of this node that are elements, skipping text and comment nodes.
+    pub fn children(&self) -> ElementSiblings<'a> {
+        ElementSiblings {
+            next: self.first_element_child(),
+            direction: SiblingDirection::Next,
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#dom-parentnode-firstelementchild
+    pub fn first_element_child(&self) -> Option<NodeRef<'a>> {
+        let first_child = self.first_child()?;
+        if first_child.is_element() {
+            return Some(first_child);
+        }
+        first_child.next_element_sibling()
+    }
+
+    // https://dom.spec.whatwg.org/#dom-parentnode-lastelementchild
+    pub fn last_element_child(&self) -> Option<NodeRef<'a>> {
+        let last_child = self.last_child()?;
+        if last_child.is_element() {
+            return Some(last_child);
+        }
+        last_child.previous_element_sibling()
+    }
+
+    // https://dom.spec.whatwg.org/#dom-parentnode-childelementcount
+    pub fn child_element_count(&self) -> usize {
+        self.children().count()
+    }
+}
+
+// 4.2.7 Mixin NonDocumentTypeChildNode
+// https://dom.spec.whatwg.org/#interface-nondocumenttypechildnode
+impl<'a> Node<'a> {
+    // https://dom.spec.whatwg.org/#dom-nondocumenttypechildnode-previouselementsibling
+    pub fn previous_element_sibling(&self) -> Option<NodeRef<'a>> {
+        ElementSiblings {
+            next: self.previous_sibling(),
+            direction: SiblingDirection::Previous,
+        }
+        .next()
+    }
+
+    // https://dom.spec.whatwg.org/#dom-nondocumenttypechildnode-nextelementsibling
+    pub fn next_element_sibling(&self) -> Option<NodeRef<'a>> {
+        ElementSiblings {
+            next: self.next_sibling(),
+            direction: SiblingDirection::Next,
+        }
+        .next()
+    }
+}
+
+fn push_children_in_reverse<'a>(node: &Node<'a>, stack: &mut Vec<NodeRef<'a>>) {
+    stack.extend(node.child_nodes().iter().rev());
+}
+
+/// An iterator over the descendants of a node in tree order.
+///
+/// See [`Node::descendants`].
+pub struct Descendants<'a> {
+    /// The nodes we still have to visit, with the next one at the end.
+    stack: Vec<NodeRef<'a>>,
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = NodeRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        push_children_in_reverse(node, &mut self.stack);
+        Some(node)
+    }
+}
+
+/// An iterator over the nodes following a node in tree order.
+///
+/// See [`Node::following`].
+pub struct Following<'a> {
+    /// The descendants of the node we are currently visiting.
+    descendants: Descendants<'a>,
+    /// The next sibling to visit once we are done with the current descendants.
+    pending_sibling: Option<NodeRef<'a>>,
+    /// The ancestor whose following siblings we visit once we run out of siblings.
+    ancestor: Option<NodeRef<'a>>,
+}
+
+impl<'a> Iterator for Following<'a> {
+    type Item = NodeRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(node) = self.descendants.next() {
+                return Some(node);
+            }
+
+            if let Some(sibling) = self.pending_sibling.take() {
+                self.pending_sibling = sibling.next_sibling();
+                self.descendants = sibling.descendants();
+                return Some(sibling);
+            }
+
+            let ancestor = self.ancestor?;
+            self.pending_sibling = ancestor.next_sibling();
+            self.ancestor = ancestor.parent_node();
+        }
+    }
+}
+
+/// An iterator over the siblings of a node that are elements, walking the sibling links in one
+/// direction.
+///
+/// See [`Node::children`].
+pub struct ElementSiblings<'a> {
+    /// The next sibling to look at, which might not be an element.
+    next: Option<NodeRef<'a>>,
+    direction: SiblingDirection,
+}
+
+enum SiblingDirection {
+    Previous,
+    Next,
+}
+
+impl<'a> Iterator for ElementSiblings<'a> {
+    type Item = NodeRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.next {
+            self.next = match self.direction {
+                SiblingDirection::Previous => node.previous_sibling(),
+                SiblingDirection::Next => node.next_sibling(),
+            };
+            if node.is_element() {
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dom::node::NodeType;
+    use crate::dom::{Document, Element, Node, NodeRef};
+    use crate::html::parser::{Parser, ParserOptions};
+    use crate::html::Namespace;
+
+    /// The tag names of `nodes` that are elements.
+    fn tags<'a>(nodes: impl Iterator<Item = &'a Node<'a>>) -> Vec<String> {
+        nodes.filter_map(|node| node.element_tag_name()).collect()
+    }
+
+    #[test]
+    fn descendants_are_in_tree_order() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<div><p><i></i>text</p><span></span></div><em></em>",
+        );
+        let document = parser.parse();
+        let body = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("body"))
+            .unwrap();
+
+        assert_eq!(tags(body.descendants()), ["div", "p", "i", "span", "em"]);
+        assert_eq!(
+            tags(document.descendants()),
+            ["html", "head", "body", "div", "p", "i", "span", "em"]
+        );
+        // NOTE: The node itself is not one of its descendants.
+        let i = body
+            .descendants()
+            .find(|node| node.is_element_with_tag("i"));
+        assert_eq!(i.unwrap().descendants().count(), 0);
+    }
+
+    #[test]
+    fn following_continues_after_the_ancestors() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<div><p><i><b></b></i></p><span></span></div><em></em>",
+        );
+        let document = parser.parse();
+        let i = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("i"))
+            .unwrap();
+
+        assert_eq!(tags(i.following()), ["b", "span", "em"]);
+    }
+
+    #[test]
+    fn element_accessors_skip_text_and_comments() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<div>a<!--c--><p></p>b<span></span><!--d--><em></em>e</div><ul>text<!--only--></ul>",
+        );
+        let document = parser.parse();
+        let find = |tag: &str| {
+            document
+                .descendants()
+                .find(|node| node.is_element_with_tag(tag))
+                .unwrap()
+        };
+        let div = find("div");
+
+        assert_eq!(tags(div.children()), ["p", "span", "em"]);
+        assert_eq!(div.child_element_count(), 3);
+        assert_eq!(div.child_nodes().len(), 8);
+        assert!(std::ptr::eq(div.first_element_child().unwrap(), find("p")));
+        assert!(std::ptr::eq(div.last_element_child().unwrap(), find("em")));
+
+        let span = find("span");
+        assert!(std::ptr::eq(
+            span.previous_element_sibling().unwrap(),
+            find("p")
+        ));
+        assert!(std::ptr::eq(
+            span.next_element_sibling().unwrap(),
+            find("em")
+        ));
+        assert!(find("p").previous_element_sibling().is_none());
+        assert!(find("em").next_element_sibling().is_none());
+        // NOTE: Text and comment nodes have element siblings too.
+        let text = div.first_child().unwrap();
+        assert!(std::ptr::eq(
+            text.next_element_sibling().unwrap(),
+            find("p")
+        ));
+
+        let ul = find("ul");
+        assert_eq!(ul.children().count(), 0);
+        assert_eq!(ul.child_element_count(), 0);
+        assert!(ul.first_element_child().is_none() && ul.last_element_child().is_none());
+        assert!(std::ptr::eq(ul.previous_element_sibling().unwrap(), div));
+    }
+
+    const DEPTH: usize = 20_000;
+
+    #[test]
+    fn very_deep_trees_do_not_overflow_the_stack() {
+        let arena = typed_arena::Arena::new();
+        let document: NodeRef = arena.alloc(Node::new(None, NodeType::Document(Document::new())));
+        let mut parent = document;
+        for _ in 0..DEPTH {
+            let div: NodeRef = arena.alloc(Node::new(
+                Some(document),
+                NodeType::Element(Element::new("div".into(), Namespace::Html)),
+            ));
+            parent.append_child(div).unwrap();
+            parent = div;
+        }
+
+        assert_eq!(document.descendants().count(), DEPTH);
+        assert_eq!(
+            document.first_child().unwrap().following().count(),
+            DEPTH - 1
+        );
+        assert_eq!(
+            document.serialize_html(),
+            format!("{}{}", "<div>".repeat(DEPTH), "</div>".repeat(DEPTH))
+        );
+        // NOTE: Dropping the arena at the end of the test drops the tree, which must not recurse
+        //       either.
+    }
+
+    #[test]
+    fn parse_very_deeply_nested_input() {
+        let input = format!("{}deep{}", "<div>".repeat(DEPTH), "</div>".repeat(DEPTH));
+        let options = ParserOptions::default();
+        let parser = Parser::with_options(typed_arena::Arena::new(), &input, options);
+        let document = parser.parse();
+
+        // NOTE: The parser ignores the start tags that would make the tree deeper than the
+        //       maximum tree depth, together with their end tags.
+        let divs = options.max_tree_depth - 2;
+        assert_eq!(
+            document
+                .descendants()
+                .filter(|node| node.is_element_with_tag("div"))
+                .count(),
+            divs
+        );
+        assert_eq!(
+            document.serialize_html(),
+            format!(
+                "<html><head></head><body>{}deep{}</body></html>",
+                "<div>".repeat(divs),
+                "</div>".repeat(divs)
+            )
+        );
+    }
+}
@@ -0,0 +1,583 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Mutex, OnceLock};
+
+/// An interned tag or attribute name.
+///
+/// Atoms are a single integer, so copying and comparing them is O(1). Names that are known
+/// ahead of time live in a static table, so [`local_name!`](crate::local_name) can create them
+/// at compile time. Other names are interned at runtime the first time they are seen.
+///
+/// NOTE: Atoms are case-sensitive, "div" and "DIV" are different atoms.
+///       The tokenizer lowercases tag and attribute names, so this only matters for
+///       names that were created from elsewhere and for adjusted SVG and MathML names.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Atom(u32);
+
+/// Creates the [`Atom`](crate::html::Atom) for a name from the static table at compile time.
+///
+/// Fails to compile if the name is not in the static table.
+#[macro_export]
+macro_rules! local_name {
+    ($name:literal) => {{
+        const ATOM: $crate::html::Atom = $crate::html::Atom::from_static($name);
+        ATOM
+    }};
+}
+
+impl Atom {
+    /// The empty name.
+    pub const EMPTY: Atom = Atom(0);
+
+    /// Returns the atom for `name`, which has to be in the static table.
+    ///
+    /// This is meant for constant evaluation, use [`local_name!`](crate::local_name) instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not in the static table.
+    pub const fn from_static(name: &str) -> Atom {
+        let mut index = 0;
+        while index < STATIC_ATOMS.len() {
+            if const_str_eq(STATIC_ATOMS[index], name) {
+                return Atom(index as u32);
+            }
+            index += 1;
+        }
+        panic!("name is not in the static atom table");
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        let index = self.0 as usize;
+        match STATIC_ATOMS.get(index) {
+            Some(name) => name,
+            None => dynamic_atoms().lock().unwrap().names[index - STATIC_ATOMS.len()],
+        }
+    }
+
+    /// Returns the atom for the ASCII lowercase version of this name.
+    pub fn to_ascii_lowercase(self) -> Atom {
+        let name = self.as_str();
+        match name.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            true => Atom::from(name.to_ascii_lowercase().as_str()),
+            false => self,
+        }
+    }
+}
+
+impl From<&str> for Atom {
+    fn from(name: &str) -> Self {
+        if let Some(index) = static_atom_indices().get(name) {
+            return Atom(*index);
+        }
+
+        // NOTE: Dynamic atoms are never freed. The number of distinct unknown names in a
+        //       document is small in practice, so this keeps atoms a plain integer.
+        let mut dynamic_atoms = dynamic_atoms().lock().unwrap();
+        if let Some(index) = dynamic_atoms.indices.get(name) {
+            return Atom(*index);
+        }
+
+        let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+        let index = (STATIC_ATOMS.len() + dynamic_atoms.names.len()) as u32;
+        dynamic_atoms.names.push(name);
+        dynamic_atoms.indices.insert(name, index);
+        Atom(index)
+    }
+}
+
+impl From<String> for Atom {
+    fn from(name: String) -> Self {
+        Atom::from(name.as_str())
+    }
+}
+
+impl Default for Atom {
+    fn default() -> Self {
+        Atom::EMPTY
+    }
+}
+
+impl Deref for Atom {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Atom {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for Atom {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Atom {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Atom {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<Atom> for str {
+    fn eq(&self, other: &Atom) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Atom> for &str {
+    fn eq(&self, other: &Atom) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<Atom> for String {
+    fn eq(&self, other: &Atom) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl fmt::Debug for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Default)]
+struct DynamicAtoms {
+    names: Vec<&'static str>,
+    indices: HashMap<&'static str, u32>,
+}
+
+fn dynamic_atoms() -> &'static Mutex<DynamicAtoms> {
+    static DYNAMIC_ATOMS: OnceLock<Mutex<DynamicAtoms>> = OnceLock::new();
+    DYNAMIC_ATOMS.get_or_init(Default::default)
+}
+
+fn static_atom_indices() -> &'static HashMap<&'static str, u32> {
+    static STATIC_ATOM_INDICES: OnceLock<HashMap<&'static str, u32>> = OnceLock::new();
+    STATIC_ATOM_INDICES.get_or_init(|| {
+        STATIC_ATOMS
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (*name, index as u32))
+            .collect()
+    })
+}
+
+const fn const_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Tag and attribute names that are known ahead of time.
+///
+/// NOTE: The empty name has to stay the first entry, so it matches [`Atom::EMPTY`].
+const STATIC_ATOMS: &[&str] = &[
+    "",
+    "a",
+    "abbr",
+    "acronym",
+    "address",
+    "applet",
+    "area",
+    "article",
+    "aside",
+    "audio",
+    "b",
+    "base",
+    "basefont",
+    "bdi",
+    "bdo",
+    "bgsound",
+    "big",
+    "blink",
+    "blockquote",
+    "body",
+    "br",
+    "button",
+    "canvas",
+    "caption",
+    "center",
+    "cite",
+    "code",
+    "col",
+    "colgroup",
+    "data",
+    "datalist",
+    "dd",
+    "del",
+    "details",
+    "dfn",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "embed",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "font",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "i",
+    "iframe",
+    "image",
+    "img",
+    "input",
+    "ins",
+    "isindex",
+    "kbd",
+    "keygen",
+    "label",
+    "legend",
+    "li",
+    "link",
+    "listing",
+    "main",
+    "map",
+    "mark",
+    "marquee",
+    "menu",
+    "menuitem",
+    "meta",
+    "meter",
+    "multicol",
+    "nav",
+    "nextid",
+    "nobr",
+    "noembed",
+    "noframes",
+    "noscript",
+    "object",
+    "ol",
+    "optgroup",
+    "option",
+    "output",
+    "p",
+    "param",
+    "picture",
+    "plaintext",
+    "pre",
+    "progress",
+    "q",
+    "rb",
+    "rp",
+    "rt",
+    "rtc",
+    "ruby",
+    "s",
+    "samp",
+    "script",
+    "search",
+    "section",
+    "select",
+    "slot",
+    "small",
+    "source",
+    "spacer",
+    "span",
+    "strike",
+    "strong",
+    "style",
+    "sub",
+    "summary",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "template",
+    "textarea",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "title",
+    "tr",
+    "track",
+    "tt",
+    "u",
+    "ul",
+    "var",
+    "video",
+    "wbr",
+    "xmp",
+    "svg",
+    "math",
+    "mi",
+    "mo",
+    "mn",
+    "ms",
+    "mtext",
+    "mglyph",
+    "malignmark",
+    "annotation-xml",
+    "desc",
+    "foreignObject",
+    "altGlyph",
+    "altGlyphDef",
+    "altGlyphItem",
+    "animateColor",
+    "animateMotion",
+    "animateTransform",
+    "clipPath",
+    "feBlend",
+    "feColorMatrix",
+    "feComponentTransfer",
+    "feComposite",
+    "feConvolveMatrix",
+    "feDiffuseLighting",
+    "feDisplacementMap",
+    "feDistantLight",
+    "feDropShadow",
+    "feFlood",
+    "feFuncA",
+    "feFuncB",
+    "feFuncG",
+    "feFuncR",
+    "feGaussianBlur",
+    "feImage",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+    "fePointLight",
+    "feSpecularLighting",
+    "feSpotLight",
+    "feTile",
+    "feTurbulence",
+    "glyphRef",
+    "linearGradient",
+    "radialGradient",
+    "textPath",
+    "circle",
+    "ellipse",
+    "g",
+    "line",
+    "path",
+    "polygon",
+    "polyline",
+    "rect",
+    "text",
+    "tspan",
+    "use",
+    "defs",
+    "symbol",
+    "accept",
+    "accept-charset",
+    "accesskey",
+    "action",
+    "align",
+    "alink",
+    "allow",
+    "alt",
+    "async",
+    "autocapitalize",
+    "autocomplete",
+    "autofocus",
+    "autoplay",
+    "background",
+    "bgcolor",
+    "border",
+    "charset",
+    "checked",
+    "class",
+    "clear",
+    "color",
+    "cols",
+    "colspan",
+    "content",
+    "contenteditable",
+    "controls",
+    "coords",
+    "crossorigin",
+    "datetime",
+    "decoding",
+    "default",
+    "defer",
+    "dirname",
+    "disabled",
+    "download",
+    "draggable",
+    "enctype",
+    "encoding",
+    "face",
+    "for",
+    "formaction",
+    "headers",
+    "height",
+    "hidden",
+    "high",
+    "href",
+    "hreflang",
+    "http-equiv",
+    "id",
+    "integrity",
+    "is",
+    "ismap",
+    "itemprop",
+    "kind",
+    "lang",
+    "language",
+    "list",
+    "loading",
+    "loop",
+    "low",
+    "max",
+    "maxlength",
+    "media",
+    "method",
+    "min",
+    "minlength",
+    "multiple",
+    "muted",
+    "name",
+    "nomodule",
+    "nonce",
+    "novalidate",
+    "open",
+    "optimum",
+    "pattern",
+    "ping",
+    "placeholder",
+    "popover",
+    "poster",
+    "preload",
+    "readonly",
+    "referrerpolicy",
+    "rel",
+    "required",
+    "reversed",
+    "role",
+    "rows",
+    "rowspan",
+    "sandbox",
+    "scope",
+    "selected",
+    "shape",
+    "size",
+    "sizes",
+    "spellcheck",
+    "src",
+    "srcdoc",
+    "srclang",
+    "srcset",
+    "start",
+    "step",
+    "tabindex",
+    "target",
+    "translate",
+    "type",
+    "usemap",
+    "value",
+    "width",
+    "wrap",
+    "definitionURL",
+    "definitionurl",
+    "viewBox",
+    "xmlns",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_atoms_are_unique() {
+        assert_eq!(static_atom_indices().len(), STATIC_ATOMS.len());
+    }
+
+    #[test]
+    fn static_and_runtime_atoms_are_equal() {
+        assert_eq!(crate::local_name!("div"), Atom::from("div"));
+        assert_eq!(
+            crate::local_name!("viewBox"),
+            Atom::from(String::from("viewBox"))
+        );
+        assert_eq!(crate::local_name!(""), Atom::EMPTY);
+        assert_eq!(Atom::default(), Atom::EMPTY);
+        assert_ne!(crate::local_name!("div"), crate::local_name!("span"));
+    }
+
+    #[test]
+    fn unknown_names_are_interned_once() {
+        let atom = Atom::from("my-custom-element");
+        assert_eq!(atom, Atom::from("my-custom-element"));
+        assert_eq!(atom.as_str(), "my-custom-element");
+        assert!(std::ptr::eq(
+            atom.as_str(),
+            Atom::from("my-custom-element").as_str()
+        ));
+        assert_ne!(atom, Atom::from("my-other-element"));
+    }
+
+    #[test]
+    fn atoms_are_case_sensitive() {
+        assert_ne!(Atom::from("DIV"), crate::local_name!("div"));
+        assert_eq!(
+            Atom::from("DIV").to_ascii_lowercase(),
+            crate::local_name!("div")
+        );
+        assert_eq!(
+            crate::local_name!("viewBox").to_ascii_lowercase(),
+            Atom::from("viewbox")
+        );
+        assert_eq!(
+            crate::local_name!("div").to_ascii_lowercase(),
+            crate::local_name!("div")
+        );
+    }
+
+    #[test]
+    fn atoms_compare_with_strings() {
+        let atom = crate::local_name!("table");
+        assert_eq!(atom, "table");
+        assert_eq!("table", atom);
+        assert_eq!(atom, String::from("table"));
+        assert_eq!(format!("{atom} {atom:?}"), "table \"table\"");
+        assert!(atom.starts_with("tab"));
+    }
+}
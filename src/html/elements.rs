@@ -0,0 +1,557 @@
+//! The categories of elements that the parser, the serializer and the sanitizer need to agree on.
+//!
+//! All names are local names, which are lowercase for HTML elements.
+
+use crate::dom::Node;
+use crate::html::Namespace;
+
+// https://html.spec.whatwg.org/multipage/syntax.html#void-elements
+/// Whether `local_name` is a void element, which has no end tag and can't have any contents.
+pub fn is_void(local_name: &str) -> bool {
+    matches!(
+        local_name,
+        "area"
+            | "base"
+            | "br"
+            | "col"
+            | "embed"
+            | "hr"
+            | "img"
+            | "input"
+            | "link"
+            | "meta"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
+
+// https://html.spec.whatwg.org/multipage/parsing.html#serializes-as-void
+/// Whether an HTML element with `local_name` serializes as void, which are the void elements and
+/// the legacy elements that the parser treats like them.
+///
+/// SPEC: For the purposes of the following algorithm, an element serializes as void if its
+///       element type is one of the void elements, or is basefont, bgsound, frame, keygen, or
+///       param.
+pub fn serializes_as_void(local_name: &str) -> bool {
+    is_void(local_name)
+        || matches!(
+            local_name,
+            "basefont" | "bgsound" | "frame" | "keygen" | "param"
+        )
+}
+
+// https://html.spec.whatwg.org/multipage/syntax.html#raw-text-elements
+/// Whether `local_name` is a raw text element, which can only contain text that isn't escaped.
+///
+/// NOTE: This is the category from the syntax section. The parser also tokenizes the contents of
+///       `xmp`, `iframe`, `noembed` and `noframes` (and `noscript` with scripting enabled) as raw
+///       text.
+pub fn is_raw_text(local_name: &str) -> bool {
+    matches!(local_name, "script" | "style")
+}
+
+// https://html.spec.whatwg.org/multipage/syntax.html#escapable-raw-text-elements
+/// Whether `local_name` is an escapable raw text element, which can contain text and character
+/// references, but no elements.
+pub fn is_escapable_raw_text(local_name: &str) -> bool {
+    matches!(local_name, "textarea" | "title")
+}
+
+// https://html.spec.whatwg.org/multipage/parsing.html#special
+/// Whether an element with `local_name` in `namespace` is in the special category.
+pub fn is_special(local_name: &str, namespace: Namespace) -> bool {
+    match namespace {
+        Namespace::Html => matches!(
+            local_name,
+            "address"
+                | "applet"
+                | "area"
+                | "article"
+                | "aside"
+                | "base"
+                | "basefont"
+                | "bgsound"
+                | "blockquote"
+                | "body"
+                | "br"
+                | "button"
+                | "caption"
+                | "center"
+                | "col"
+                | "colgroup"
+                | "dd"
+                | "details"
+                | "dir"
+                | "div"
+                | "dl"
+                | "dt"
+                | "embed"
+                | "fieldset"
+                | "figcaption"
+                | "figure"
+                | "footer"
+                | "form"
+                | "frame"
+                | "frameset"
+                | "h1"
+                | "h2"
+                | "h3"
+                | "h4"
+                | "h5"
+                | "h6"
+                | "head"
+                | "header"
+                | "hgroup"
+                | "hr"
+                | "html"
+                | "iframe"
+                | "img"
+                | "input"
+                | "keygen"
+                | "li"
+                | "link"
+                | "listing"
+                | "main"
+                | "marquee"
+                | "menu"
+                | "meta"
+                | "nav"
+                | "noembed"
+                | "noframes"
+                | "noscript"
+                | "object"
+                | "ol"
+                | "p"
+                | "param"
+                | "plaintext"
+                | "pre"
+                | "script"
+                | "search"
+                | "section"
+                | "select"
+                | "source"
+                | "style"
+                | "summary"
+                | "table"
+                | "tbody"
+                | "td"
+                | "template"
+                | "textarea"
+                | "tfoot"
+                | "th"
+                | "thead"
+                | "title"
+                | "tr"
+                | "track"
+                | "ul"
+                | "wbr"
+                | "xmp"
+        ),
+        Namespace::MathMl => matches!(
+            local_name,
+            "mi" | "mo" | "mn" | "ms" | "mtext" | "annotation-xml"
+        ),
+        Namespace::Svg => matches!(local_name, "foreignObject" | "desc" | "title"),
+        _ => false,
+    }
+}
+
+// https://html.spec.whatwg.org/multipage/parsing.html#has-an-element-in-scope
+/// Whether an element with `local_name` in `namespace` is one of the element types that end the
+/// search for an element in scope, which every other kind of scope extends.
+pub fn is_scope_boundary(local_name: &str, namespace: Namespace) -> bool {
+    match namespace {
+        Namespace::Html => matches!(
+            local_name,
+            "applet"
+                | "caption"
+                | "html"
+                | "table"
+                | "td"
+                | "th"
+                | "marquee"
+                | "object"
+                | "template"
+        ),
+        Namespace::MathMl => matches!(
+            local_name,
+            "mi" | "mo" | "mn" | "ms" | "mtext" | "annotation-xml"
+        ),
+        Namespace::Svg => matches!(local_name, "foreignObject" | "desc" | "title"),
+        _ => false,
+    }
+}
+
+// https://html.spec.whatwg.org/multipage/parsing.html#formatting
+/// Whether `local_name` is an HTML element in the formatting category.
+pub fn is_formatting(local_name: &str) -> bool {
+    matches!(
+        local_name,
+        "a" | "b"
+            | "big"
+            | "code"
+            | "em"
+            | "font"
+            | "i"
+            | "nobr"
+            | "s"
+            | "small"
+            | "strike"
+            | "strong"
+            | "tt"
+            | "u"
+    )
+}
+
+// https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-inforeign
+/// Whether a start tag named `local_name` makes the parser leave foreign content and process the
+/// token as HTML.
+///
+/// NOTE: A `font` start tag only does so if it has a `color`, `face` or `size` attribute, so it
+///       isn't in this list.
+pub fn breaks_out_of_foreign_content(local_name: &str) -> bool {
+    matches!(
+        local_name,
+        "b" | "big"
+            | "blockquote"
+            | "body"
+            | "br"
+            | "center"
+            | "code"
+            | "dd"
+            | "div"
+            | "dl"
+            | "dt"
+            | "em"
+            | "embed"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "head"
+            | "hr"
+            | "i"
+            | "img"
+            | "li"
+            | "listing"
+            | "menu"
+            | "meta"
+            | "nobr"
+            | "ol"
+            | "p"
+            | "pre"
+            | "ruby"
+            | "s"
+            | "small"
+            | "span"
+            | "strong"
+            | "strike"
+            | "sub"
+            | "sup"
+            | "table"
+            | "tt"
+            | "u"
+            | "ul"
+            | "var"
+    )
+}
+
+impl Node<'_> {
+    /// Whether this node is an element in the special category.
+    ///
+    /// See [`is_special`].
+    pub fn is_special_element(&self) -> bool {
+        self.as_element()
+            .is_some_and(|element| is_special(&element.local_name(), element.namespace()))
+    }
+
+    /// Whether this node is an element that ends the search for an element in scope.
+    ///
+    /// See [`is_scope_boundary`].
+    pub fn is_scope_boundary_element(&self) -> bool {
+        self.as_element()
+            .is_some_and(|element| is_scope_boundary(&element.local_name(), element.namespace()))
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#mathml-text-integration-point
+    /// Whether this node is a MathML `mi`, `mo`, `mn`, `ms` or `mtext` element.
+    pub fn is_mathml_text_integration_point(&self) -> bool {
+        self.as_element().is_some_and(|element| {
+            element.namespace() == Namespace::MathMl
+                && matches!(&*element.local_name(), "mi" | "mo" | "mn" | "ms" | "mtext")
+        })
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#html-integration-point
+    /// Whether the contents of this node are parsed as HTML, even though it is in foreign content.
+    ///
+    /// NOTE: The spec looks at the attributes of the start tag that created an annotation-xml
+    ///       element. We look at the attributes of the element instead, which are the same unless
+    ///       a script changes them.
+    pub fn is_html_integration_point(&self) -> bool {
+        let Some(element) = self.as_element() else {
+            return false;
+        };
+
+        match element.namespace() {
+            // SPEC: A MathML annotation-xml element whose start tag token had an attribute with the
+            //       name "encoding" whose value was an ASCII case-insensitive match for the string
+            //       "text/html" or "application/xhtml+xml"
+            Namespace::MathMl => {
+                &*element.local_name() == "annotation-xml"
+                    && element.get_attribute("encoding").is_some_and(|encoding| {
+                        encoding.eq_ignore_ascii_case("text/html")
+                            || encoding.eq_ignore_ascii_case("application/xhtml+xml")
+                    })
+            }
+            // SPEC: An SVG foreignObject element, an SVG desc element, an SVG title element
+            Namespace::Svg => matches!(&*element.local_name(), "foreignObject" | "desc" | "title"),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn void_elements() {
+        let void = [
+            "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source",
+            "track", "wbr",
+        ];
+        for name in void {
+            assert!(is_void(name), "{name} should be void");
+            assert!(serializes_as_void(name), "{name} should serialize as void");
+        }
+
+        for name in ["basefont", "bgsound", "frame", "keygen", "param"] {
+            assert!(!is_void(name), "{name} should not be void");
+            assert!(serializes_as_void(name), "{name} should serialize as void");
+        }
+
+        for name in ["div", "image", "template", "AREA"] {
+            assert!(!is_void(name), "{name} should not be void");
+            assert!(
+                !serializes_as_void(name),
+                "{name} should not serialize as void"
+            );
+        }
+    }
+
+    #[test]
+    fn raw_text_elements() {
+        for name in ["script", "style"] {
+            assert!(is_raw_text(name));
+            assert!(!is_escapable_raw_text(name));
+        }
+        for name in ["textarea", "title"] {
+            assert!(is_escapable_raw_text(name));
+            assert!(!is_raw_text(name));
+        }
+        for name in [
+            "xmp",
+            "iframe",
+            "noembed",
+            "noframes",
+            "noscript",
+            "plaintext",
+        ] {
+            assert!(!is_raw_text(name) && !is_escapable_raw_text(name));
+        }
+    }
+
+    #[test]
+    fn special_elements() {
+        let html = [
+            "address",
+            "applet",
+            "area",
+            "article",
+            "aside",
+            "base",
+            "basefont",
+            "bgsound",
+            "blockquote",
+            "body",
+            "br",
+            "button",
+            "caption",
+            "center",
+            "col",
+            "colgroup",
+            "dd",
+            "details",
+            "dir",
+            "div",
+            "dl",
+            "dt",
+            "embed",
+            "fieldset",
+            "figcaption",
+            "figure",
+            "footer",
+            "form",
+            "frame",
+            "frameset",
+            "h1",
+            "h2",
+            "h3",
+            "h4",
+            "h5",
+            "h6",
+            "head",
+            "header",
+            "hgroup",
+            "hr",
+            "html",
+            "iframe",
+            "img",
+            "input",
+            "keygen",
+            "li",
+            "link",
+            "listing",
+            "main",
+            "marquee",
+            "menu",
+            "meta",
+            "nav",
+            "noembed",
+            "noframes",
+            "noscript",
+            "object",
+            "ol",
+            "p",
+            "param",
+            "plaintext",
+            "pre",
+            "script",
+            "search",
+            "section",
+            "select",
+            "source",
+            "style",
+            "summary",
+            "table",
+            "tbody",
+            "td",
+            "template",
+            "textarea",
+            "tfoot",
+            "th",
+            "thead",
+            "title",
+            "tr",
+            "track",
+            "ul",
+            "wbr",
+            "xmp",
+        ];
+        for name in html {
+            assert!(
+                is_special(name, Namespace::Html),
+                "{name} should be special"
+            );
+        }
+        for name in ["mi", "mo", "mn", "ms", "mtext", "annotation-xml"] {
+            assert!(
+                is_special(name, Namespace::MathMl),
+                "{name} should be special"
+            );
+            assert!(
+                !is_special(name, Namespace::Html),
+                "{name} is not special in HTML"
+            );
+        }
+        for name in ["foreignObject", "desc", "title"] {
+            assert!(is_special(name, Namespace::Svg), "{name} should be special");
+        }
+
+        for name in ["a", "b", "span", "option", "optgroup", "ruby"] {
+            assert!(
+                !is_special(name, Namespace::Html),
+                "{name} should not be special"
+            );
+        }
+        assert!(!is_special("div", Namespace::Svg));
+        assert!(!is_special("foreignobject", Namespace::Svg));
+    }
+
+    #[test]
+    fn formatting_elements() {
+        let formatting = [
+            "a", "b", "big", "code", "em", "font", "i", "nobr", "s", "small", "strike", "strong",
+            "tt", "u",
+        ];
+        for name in formatting {
+            assert!(is_formatting(name), "{name} should be formatting");
+        }
+        for name in ["span", "p", "sub", "sup", "mark"] {
+            assert!(!is_formatting(name), "{name} should not be formatting");
+        }
+    }
+
+    #[test]
+    fn scope_boundaries() {
+        let html = [
+            "applet", "caption", "html", "table", "td", "th", "marquee", "object", "template",
+        ];
+        for name in html {
+            assert!(
+                is_scope_boundary(name, Namespace::Html),
+                "{name} should end scope"
+            );
+        }
+        for name in ["mi", "mo", "mn", "ms", "mtext", "annotation-xml"] {
+            assert!(
+                is_scope_boundary(name, Namespace::MathMl),
+                "{name} should end scope"
+            );
+            assert!(
+                !is_scope_boundary(name, Namespace::Html),
+                "{name} is not a boundary in HTML"
+            );
+        }
+        for name in ["foreignObject", "desc", "title"] {
+            assert!(
+                is_scope_boundary(name, Namespace::Svg),
+                "{name} should end scope"
+            );
+            assert!(
+                !is_scope_boundary(name, Namespace::Html),
+                "{name} is not a boundary in HTML"
+            );
+        }
+
+        for name in ["button", "ol", "ul", "p", "div", "tr"] {
+            assert!(
+                !is_scope_boundary(name, Namespace::Html),
+                "{name} should not end scope"
+            );
+        }
+        assert!(!is_scope_boundary("table", Namespace::Svg));
+    }
+
+    #[test]
+    fn elements_that_break_out_of_foreign_content() {
+        for name in [
+            "b", "div", "p", "table", "ul", "h1", "br", "img", "span", "var",
+        ] {
+            assert!(
+                breaks_out_of_foreign_content(name),
+                "{name} should break out"
+            );
+        }
+        for name in ["font", "a", "svg", "math", "title", "foreignObject"] {
+            assert!(
+                !breaks_out_of_foreign_content(name),
+                "{name} should not break out"
+            );
+        }
+    }
+}
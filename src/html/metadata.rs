@@ -0,0 +1,416 @@
+use crate::css::parser::token_streams::TokenStream;
+use crate::css::parser::types::StyleSheet;
+use crate::css::tokenizer::Tokenizer;
+use crate::dom::{Node, NodeRef};
+use crate::html::Namespace;
+
+/// What a `<meta http-equiv="refresh">` pragma asks for.
+///
+/// https://html.spec.whatwg.org/multipage/semantics.html#attr-meta-http-equiv-refresh
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Refresh {
+    /// How many seconds to wait before navigating.
+    pub time: u64,
+    /// The absolute URL to navigate to, which is the document's own URL for a plain reload.
+    pub url: String,
+}
+
+/// The link types of `<link>` elements that are interesting to look at without rendering.
+///
+/// https://html.spec.whatwg.org/multipage/links.html#linkTypes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkType {
+    /// https://html.spec.whatwg.org/multipage/links.html#link-type-alternate
+    Alternate,
+    /// https://html.spec.whatwg.org/multipage/links.html#link-type-canonical
+    Canonical,
+    /// https://html.spec.whatwg.org/multipage/links.html#rel-icon
+    Icon,
+    /// https://html.spec.whatwg.org/multipage/links.html#link-type-stylesheet
+    Stylesheet,
+}
+
+impl LinkType {
+    /// Returns the link type for a keyword of a `rel` attribute, which is ASCII case-insensitive.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.to_ascii_lowercase().as_str() {
+            "alternate" => Some(Self::Alternate),
+            "canonical" => Some(Self::Canonical),
+            "icon" => Some(Self::Icon),
+            "stylesheet" => Some(Self::Stylesheet),
+            _ => None,
+        }
+    }
+}
+
+/// A link from a `<link>` element with one of the [`LinkType`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkRelation<'a> {
+    pub link_type: LinkType,
+    /// The `href` of the link, resolved against the document's base URL.
+    pub href: String,
+    /// The `link` element.
+    pub node: NodeRef<'a>,
+}
+
+impl<'a> Node<'a> {
+    // https://html.spec.whatwg.org/multipage/semantics.html#attr-meta-http-equiv-refresh
+    /// Returns the refresh that the `<meta http-equiv="refresh">` pragmas in this document ask
+    /// for, if any. `document_url` is the URL of the document, which relative URLs are resolved
+    /// against.
+    ///
+    /// NOTE: Every pragma is processed when its element is inserted, but only the first one that
+    ///       parses makes the document refresh.
+    pub fn refresh(&self, document_url: &str) -> Option<Refresh> {
+        let base_url = self.document_base_url(document_url);
+        self.descendants()
+            .filter(|node| {
+                node.is_element_with_tag("meta") && node.is_element_with_namespace(Namespace::Html)
+            })
+            .filter_map(|node| node.as_element())
+            .filter(|meta| {
+                meta.get_attribute("http-equiv")
+                    .is_some_and(|value| value.eq_ignore_ascii_case("refresh"))
+            })
+            .find_map(|meta| {
+                // 1. If the meta element has no content attribute, or if that attribute's value is the empty string, then return.
+                let content = meta.get_attribute("content")?;
+                if content.is_empty() {
+                    return None;
+                }
+
+                // 2. Run the shared declarative refresh steps with document, the content attribute's value, and the meta element.
+                parse_declarative_refresh(&content, document_url, &base_url)
+            })
+    }
+
+    /// Returns the links of the `<link>` elements in this document with one of the [`LinkType`]s,
+    /// in tree order. A link with more than one of them, like `rel="alternate stylesheet"`, gives
+    /// an entry for each.
+    pub fn link_relations(&self, document_url: &str) -> Vec<LinkRelation<'a>> {
+        let base_url = self.document_base_url(document_url);
+        self.descendants()
+            .filter(|node| {
+                node.is_element_with_tag("link") && node.is_element_with_namespace(Namespace::Html)
+            })
+            .flat_map(|node| {
+                let element = node.as_element().expect("node should be a link element");
+
+                // NOTE: A link element without an href, or with an empty one, doesn't create a link.
+                // https://html.spec.whatwg.org/multipage/semantics.html#the-link-element
+                let href = element
+                    .get_attribute("href")
+                    .filter(|href| !href.is_empty())
+                    .and_then(|_| element.resolve_url_attribute("href", &base_url));
+
+                let rel = element.get_attribute("rel").unwrap_or_default();
+                let mut link_types = rel
+                    .split_ascii_whitespace()
+                    .filter_map(LinkType::from_keyword)
+                    .collect::<Vec<_>>();
+                link_types.dedup();
+
+                link_types.into_iter().filter_map(move |link_type| {
+                    Some(LinkRelation {
+                        link_type,
+                        href: href.clone()?,
+                        node,
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Parses the contents of the `style` elements in this document that contain CSS, and returns
+    /// the resulting stylesheets in tree order.
+    ///
+    /// NOTE: The stylesheets are parsed on every call, because we don't keep a list of document
+    ///       style sheets that is updated as style elements are inserted and removed.
+    /// https://html.spec.whatwg.org/multipage/semantics.html#update-a-style-block
+    pub fn stylesheets(&self) -> Vec<StyleSheet> {
+        self.descendants()
+            .filter(|node| is_css_style_element(node))
+            .map(|node| {
+                let text = node.child_text_content();
+                let input = TokenStream::from_tokenizer(Tokenizer::new(&text));
+                crate::css::parser::Parser::parse_a_stylesheet(&input, None)
+            })
+            .collect()
+    }
+}
+
+// https://html.spec.whatwg.org/multipage/semantics.html#update-a-style-block
+/// Whether `node` is a `style` element whose contents are a CSS stylesheet.
+fn is_css_style_element(node: &Node) -> bool {
+    if !node.is_element_with_tag("style")
+        || !(node.is_element_with_namespace(Namespace::Html)
+            || node.is_element_with_namespace(Namespace::Svg))
+    {
+        return false;
+    }
+
+    // SPEC: 5. If element's type attribute is present and its value is neither the empty string
+    //          nor an ASCII case-insensitive match for "text/css", then return.
+    // FIXME: Only create the stylesheet if the media attribute matches the environment.
+    node.as_element()
+        .and_then(|element| element.get_attribute("type"))
+        .is_none_or(|type_| type_.is_empty() || type_.eq_ignore_ascii_case("text/css"))
+}
+
+// https://html.spec.whatwg.org/multipage/document-lifecycle.html#shared-declarative-refresh-steps
+fn parse_declarative_refresh(input: &str, document_url: &str, base_url: &str) -> Option<Refresh> {
+    let skip_ascii_whitespace = |input: &str| {
+        input
+            .trim_start_matches(|c: char| c.is_ascii_whitespace())
+            .to_string()
+    };
+
+    // FIXME: 1. If document's will declaratively refresh is true, then return.
+    // 2. Let position point at the beginning of input.
+    // 3. Skip ASCII whitespace within input given position.
+    let mut rest = skip_ascii_whitespace(input);
+
+    // 4. Let time be 0.
+    // 5. Collect a sequence of code points that are ASCII digits from input given position, and let the result be timeString.
+    let time_length = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let time_string = &rest[..time_length];
+    let time = if time_string.is_empty() {
+        // 6. If timeString is the empty string, then:
+        // 6.1. If the code point in input pointed to by position is not U+002E (.), then return.
+        if !rest.starts_with('.') {
+            return None;
+        }
+        0
+    } else {
+        // 7. Otherwise, set time to the result of parsing timeString using the rules for parsing non-negative integers.
+        // NOTE: The time string is all digits, so it only fails to parse if it is too large.
+        time_string.parse().unwrap_or(u64::MAX)
+    };
+    rest = rest[time_length..].to_string();
+
+    // 8. Collect a sequence of code points that are ASCII digits and U+002E FULL STOP characters (.) from input given position. Ignore any collected characters.
+    rest = rest
+        .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.')
+        .to_string();
+
+    // 9. Let urlRecord be document's URL.
+    let mut url = document_url.to_string();
+
+    // 10. If position is not past the end of input, then:
+    if !rest.is_empty() {
+        // 10.1. If the code point in input pointed to by position is not U+003B (;), U+002C (,), or ASCII whitespace, then return.
+        if !rest.starts_with(|c: char| c == ';' || c == ',' || c.is_ascii_whitespace()) {
+            return None;
+        }
+
+        // 10.2. Skip ASCII whitespace within input given position.
+        rest = skip_ascii_whitespace(&rest);
+
+        // 10.3. If the code point in input pointed to by position is U+003B (;) or U+002C (,), then advance position to the next code point.
+        if let Some(after_separator) = rest.strip_prefix([';', ',']) {
+            rest = after_separator.to_string();
+        }
+
+        // 10.4. Skip ASCII whitespace within input given position.
+        rest = skip_ascii_whitespace(&rest);
+    }
+
+    // 11. If position is not past the end of input, then:
+    if !rest.is_empty() {
+        // 11.1. Let urlString be the code point substring from position to the end of input.
+        let url_string = url_after_url_equals(&rest).unwrap_or(rest);
+
+        // 11.11. Parse: Parse urlString relative to document. If that fails, return. Otherwise, set urlRecord to the resulting URL record.
+        url = crate::url::resolve(base_url, &url_string)?;
+    }
+
+    // FIXME: 12. Set document's will declaratively refresh to true.
+    // FIXME: 13. Perform one or more of the following steps: [...]
+    Some(Refresh { time, url })
+}
+
+/// Steps 11.2 to 11.10 of the shared declarative refresh steps, which strip a `URL=` prefix and
+/// quotes from the URL. Returns `None` if there is no `URL=` prefix, in which case the whole of
+/// `rest` is the URL.
+fn url_after_url_equals(rest: &str) -> Option<String> {
+    // 11.2. If the code point in input pointed to by position is U+0055 (U) or U+0075 (u), then advance position to the next code point. Otherwise, jump to the step labeled skip quotes.
+    // NOTE: Skip quotes is only reached directly when there is no U, in which case the URL can
+    //       still be quoted.
+    let Some(after_u) = rest.strip_prefix(['U', 'u']) else {
+        return Some(skip_quotes(rest));
+    };
+
+    // 11.3. If the code point in input pointed to by position is U+0052 (R) or U+0072 (r), then advance position to the next code point. Otherwise, jump to the step labeled parse.
+    let after_r = after_u.strip_prefix(['R', 'r'])?;
+
+    // 11.4. If the code point in input pointed to by position is U+004C (L) or U+006C (l), then advance position to the next code point. Otherwise, jump to the step labeled parse.
+    let after_l = after_r.strip_prefix(['L', 'l'])?;
+
+    // 11.5. Skip ASCII whitespace within input given position.
+    let after_url = after_l.trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+    // 11.6. If the code point in input pointed to by position is U+003D (=), then advance position to the next code point. Otherwise, jump to the step labeled parse.
+    let after_equals = after_url.strip_prefix('=')?;
+
+    // 11.7. Skip ASCII whitespace within input given position.
+    let after_equals = after_equals.trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+    Some(skip_quotes(after_equals))
+}
+
+/// Steps 11.8 to 11.10 of the shared declarative refresh steps.
+fn skip_quotes(input: &str) -> String {
+    // 11.8. Skip quotes: If the code point in input pointed to by position is U+0027 (') or U+0022 ("), then let quote be that code point, and advance position to the next code point. Otherwise, let quote be the empty string.
+    let Some(quote) = input.chars().next().filter(|c| matches!(c, '\'' | '"')) else {
+        // 11.9. Set urlString to the code point substring from position to the end of input.
+        return input.to_string();
+    };
+
+    // 11.9. Set urlString to the code point substring from position to the end of input.
+    let url_string = &input[quote.len_utf8()..];
+
+    // 11.10. If quote is not the empty string, and there is a code point in urlString equal to quote, then truncate urlString at that code point, so that it and all subsequent code points are removed.
+    match url_string.find(quote) {
+        Some(index) => url_string[..index].to_string(),
+        None => url_string.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::parser::types::Rule;
+    use crate::html::parser::Parser;
+
+    const DOCUMENT_URL: &str = "http://example.com/dir/page.html";
+
+    fn refresh(content: &str) -> Option<(u64, String)> {
+        let input = format!("<meta http-equiv=Refresh content=\"{content}\">");
+        let parser = Parser::new(typed_arena::Arena::new(), &input);
+        let document = parser.parse();
+        let refresh = document.refresh(DOCUMENT_URL);
+        refresh.map(|refresh| (refresh.time, refresh.url))
+    }
+
+    #[test]
+    fn sloppy_refresh_values() {
+        let url = |url: &str| url.to_string();
+        assert_eq!(
+            refresh("5; URL='/next'"),
+            Some((5, url("http://example.com/next")))
+        );
+        assert_eq!(refresh("0"), Some((0, url(DOCUMENT_URL))));
+        assert_eq!(
+            refresh("  3.5 , url = &quot;next.html&quot; trailing"),
+            Some((3, url("http://example.com/dir/next.html")))
+        );
+        assert_eq!(
+            refresh("10;url=next"),
+            Some((10, url("http://example.com/dir/next")))
+        );
+        assert_eq!(
+            refresh("1 next"),
+            Some((1, url("http://example.com/dir/next")))
+        );
+        assert_eq!(
+            refresh(".5; URL=/a"),
+            Some((0, url("http://example.com/a")))
+        );
+        assert_eq!(
+            refresh("5; URL='/a"),
+            Some((5, url("http://example.com/a")))
+        );
+        // NOTE: Without a complete `URL=`, the whole rest of the value is the URL.
+        assert_eq!(
+            refresh("2; Uri=/x"),
+            Some((2, url("http://example.com/dir/Uri=/x")))
+        );
+
+        for content in ["", "x", "5x", "-1", "; URL=/a"] {
+            assert_eq!(refresh(content), None, "{content:?}");
+        }
+    }
+
+    #[test]
+    fn the_first_valid_refresh_wins() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<base href=/base/><meta http-equiv=refresh content=x>\
+             <meta http-equiv=REFRESH content=\"7; url=a\"><meta http-equiv=refresh content=1>",
+        );
+        let document = parser.parse();
+        assert_eq!(
+            document.refresh(DOCUMENT_URL),
+            Some(Refresh {
+                time: 7,
+                url: "http://example.com/base/a".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn link_relations_resolve_against_the_base_url() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<base href=/base/><link rel=Canonical href=page>\
+             <link rel=\"alternate stylesheet\" href=alt.css><link rel=icon href=\"\">\
+             <link rel=preload href=x><link rel=\"icon ICON\" href=/favicon.ico>",
+        );
+        let document = parser.parse();
+        let relations = document
+            .link_relations(DOCUMENT_URL)
+            .into_iter()
+            .map(|relation| (relation.link_type, relation.href))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            relations,
+            [
+                (
+                    LinkType::Canonical,
+                    "http://example.com/base/page".to_string()
+                ),
+                (
+                    LinkType::Alternate,
+                    "http://example.com/base/alt.css".to_string()
+                ),
+                (
+                    LinkType::Stylesheet,
+                    "http://example.com/base/alt.css".to_string()
+                ),
+                (LinkType::Icon, "http://example.com/favicon.ico".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn style_elements_are_parsed_into_stylesheets() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<style>p{color:red}</style><style type=text/plain>a{}</style>\
+             <style type=TEXT/CSS>a{} b{}</style><svg><style>c{}</style></svg>\
+             <math><style>d{}</style></math>",
+        );
+        let document = parser.parse();
+        let stylesheets = document.stylesheets();
+
+        // NOTE: Styles of another type, or inside MathML, are not CSS stylesheets.
+        assert_eq!(
+            stylesheets
+                .iter()
+                .map(|stylesheet| stylesheet.rules.len())
+                .collect::<Vec<_>>(),
+            [1, 2, 1]
+        );
+        let Rule::QualifiedRule(rule) = &stylesheets[0].rules[0] else {
+            panic!("expected a qualified rule");
+        };
+        assert_eq!(
+            rule.prelude,
+            crate::css::parser::Parser::parse_a_list_of_component_values("p")
+        );
+        assert_eq!(rule.declarations.len(), 1);
+        assert_eq!(rule.declarations[0].name, "color");
+    }
+}
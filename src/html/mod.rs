@@ -1,5 +1,15 @@
+pub mod atom;
+pub mod elements;
+pub mod metadata;
 pub mod namespace;
+pub mod outline;
 pub mod parser;
+pub mod sanitizer;
+pub mod serializer;
 pub mod tokenizer;
 
+pub use atom::Atom;
+pub use metadata::{LinkRelation, LinkType, Refresh};
 pub use namespace::Namespace;
+pub use outline::OutlineEntry;
+pub use sanitizer::{sanitize, SanitizePolicy};
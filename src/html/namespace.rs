@@ -8,15 +8,15 @@ pub enum Namespace {
     XmlNs,
 }
 
-impl ToString for Namespace {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Namespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Namespace::Html => "http://www.w3.org/1999/xhtml".to_string(),
-            Namespace::MathMl => "http://www.w3.org/1998/Math/MathML".to_string(),
-            Namespace::Svg => "http://www.w3.org/2000/svg".to_string(),
-            Namespace::XLink => "http://www.w3.org/1999/xlink".to_string(),
-            Namespace::Xml => "http://www.w3.org/XML/1998/namespace".to_string(),
-            Namespace::XmlNs => "http://www.w3.org/2000/xmlns/".to_string(),
+            Namespace::Html => write!(f, "http://www.w3.org/1999/xhtml"),
+            Namespace::MathMl => write!(f, "http://www.w3.org/1998/Math/MathML"),
+            Namespace::Svg => write!(f, "http://www.w3.org/2000/svg"),
+            Namespace::XLink => write!(f, "http://www.w3.org/1999/xlink"),
+            Namespace::Xml => write!(f, "http://www.w3.org/XML/1998/namespace"),
+            Namespace::XmlNs => write!(f, "http://www.w3.org/2000/xmlns/"),
         }
     }
 }
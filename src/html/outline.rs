@@ -0,0 +1,83 @@
+use crate::dom::{Node, NodeRef};
+use crate::html::Namespace;
+
+/// A heading in the outline of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineEntry<'a> {
+    /// The heading's level, from 1 for `h1` to 6 for `h6`.
+    pub level: u8,
+    /// The heading's text content, with ASCII whitespace stripped and collapsed.
+    pub text: String,
+    /// The heading element.
+    pub node: NodeRef<'a>,
+}
+
+impl<'a> Node<'a> {
+    // https://html.spec.whatwg.org/multipage/sections.html#headings-and-outlines-2
+    /// Returns the headings in this document, in tree order.
+    ///
+    /// NOTE: The HTML standard no longer lets `section` and other sectioning content change the
+    ///       level of the headings inside them, so the level is always the one of the `hN`
+    ///       element itself. Levels can be skipped, e.g. an `h3` directly after an `h1`.
+    pub fn outline(&self) -> Vec<OutlineEntry<'a>> {
+        self.descendants()
+            .filter_map(|node| {
+                let element = node.as_element()?;
+                if element.namespace() != Namespace::Html {
+                    return None;
+                }
+
+                // https://html.spec.whatwg.org/multipage/sections.html#heading-level
+                let level = match element.local_name().as_str() {
+                    "h1" => 1,
+                    "h2" => 2,
+                    "h3" => 3,
+                    "h4" => 4,
+                    "h5" => 5,
+                    "h6" => 6,
+                    _ => return None,
+                };
+
+                let text = node.descendant_text_content();
+                Some(OutlineEntry {
+                    level,
+                    text: text.split_ascii_whitespace().collect::<Vec<_>>().join(" "),
+                    node,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::parser::Parser;
+
+    #[test]
+    fn headings_keep_their_own_level() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<h1>Guide</h1><h3>Skipped\n  to  three</h3><section><h2>In <b>a</b> section</h2></section>\
+             <p>Not a heading</p><svg><h5>Out of the svg</h5><title>Not a heading</title></svg>",
+        );
+        let document = parser.parse();
+
+        let outline = document.outline();
+        let entries = outline
+            .iter()
+            .map(|entry| (entry.level, entry.text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            [
+                (1, "Guide"),
+                (3, "Skipped to three"),
+                (2, "In a section"),
+                (5, "Out of the svg"),
+            ]
+        );
+        assert!(outline
+            .iter()
+            .all(|entry| entry.node.is_element_with_tag(&format!("h{}", entry.level))));
+    }
+}
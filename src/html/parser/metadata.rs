@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::dom::Node;
+
+use super::Parser;
+
+/// The parsed document together with the metadata a crawler usually cares about.
+#[derive(Debug)]
+pub struct ParseResult<'a> {
+    pub document: Node<'a>,
+    /// The child text content of the first `title` element, with ASCII whitespace stripped and collapsed.
+    pub title: Option<String>,
+    /// The `content` of every `meta` element with a `name`, keyed by the name in ASCII lowercase.
+    pub meta: HashMap<String, String>,
+    /// The `href` of the first `base` element that has one.
+    pub base_href: Option<String>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn parse_with_metadata(&'a self) -> ParseResult<'a> {
+        let document = self.parse();
+
+        let mut title = None;
+        let mut meta = HashMap::new();
+        let mut base_href = None;
+
+        for node in document.descendants() {
+            let Some(element) = node.as_element() else {
+                continue;
+            };
+
+            match element.local_name().as_str() {
+                // https://html.spec.whatwg.org/multipage/dom.html#document.title
+                "title" if title.is_none() => {
+                    // Strip and collapse ASCII whitespace in value.
+                    let value = node.child_text_content();
+                    title = Some(value.split_ascii_whitespace().collect::<Vec<_>>().join(" "));
+                }
+                // https://html.spec.whatwg.org/multipage/semantics.html#standard-metadata-names
+                "meta" => {
+                    if let (Some(name), Some(content)) = (
+                        element.get_attribute("name"),
+                        element.get_attribute("content"),
+                    ) {
+                        // Names are case-insensitive, and the first one in tree order wins.
+                        meta.entry(name.to_ascii_lowercase()).or_insert(content);
+                    }
+                }
+                // https://html.spec.whatwg.org/multipage/semantics.html#the-base-element
+                "base" if base_href.is_none() => {
+                    base_href = element.get_attribute("href");
+                }
+                _ => {}
+            }
+        }
+
+        ParseResult {
+            document,
+            title,
+            meta,
+            base_href,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_meta_and_base_href() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<!DOCTYPE html><html><head>\
+             <title>  A   page\n title </title><title>Second</title>\
+             <meta name=Description content=\"The first description\">\
+             <meta name=description content=\"The second description\">\
+             <meta name=author content=Sputnik><meta charset=utf-8><meta name=empty>\
+             <base target=_blank><base href=\"https://example.com/docs/\"><base href=/other/>\
+             </head><body><p>Text</p></body></html>",
+        );
+        let result = parser.parse_with_metadata();
+
+        assert_eq!(result.title.as_deref(), Some("A page title"));
+        assert_eq!(
+            result.meta,
+            HashMap::from([
+                (
+                    "description".to_string(),
+                    "The first description".to_string()
+                ),
+                ("author".to_string(), "Sputnik".to_string()),
+            ])
+        );
+        assert_eq!(
+            result.base_href.as_deref(),
+            Some("https://example.com/docs/")
+        );
+        assert!(result.document.is_document());
+    }
+
+    #[test]
+    fn missing_metadata() {
+        let parser = Parser::new(typed_arena::Arena::new(), "<p>No head content");
+        let result = parser.parse_with_metadata();
+
+        assert_eq!(result.title, None);
+        assert!(result.meta.is_empty());
+        assert_eq!(result.base_href, None);
+    }
+}
@@ -1,15 +1,25 @@
 use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 
 use tree_construction::list_of_active_formatting_elements::ListOfActiveFormattingElements;
 use tree_construction::stack_of_open_elements::StackOfOpenElements;
 
 use super::tokenizer::{self, Token};
 use crate::dom::node::{Node, NodeLink, NodeRef, NodeType};
-use crate::dom::Element;
+use crate::dom::{Document, Element};
+use crate::html::elements;
 use crate::html::tokenizer::Tokenizer;
-use crate::html::Namespace;
+use crate::html::{Atom, Namespace};
+use crate::local_name;
 
+pub use metadata::ParseResult;
+pub use trace::TraceEntry;
+pub use tree_sink::TreeSink;
+
+mod metadata;
+mod trace;
 pub(crate) mod tree_construction;
+mod tree_sink;
 
 const fn is_parser_whitespace(string: char) -> bool {
     if let '\t' | '\u{000a}' | '\u{000c}' | '\u{000d}' | '\u{0020}' = string {
@@ -75,10 +85,77 @@ enum GenericParsingAlgorithm {
     RcData,
 }
 
+/// Options that change how a [`Parser`] builds the tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// The maximum number of elements on the stack of open elements.
+    ///
+    /// Start tags that are seen while the stack is this deep are ignored, so pathological input
+    /// (like a lot of unclosed `<div>`s) can't make the tree, and every walk over the stack,
+    /// arbitrarily deep.
+    pub max_tree_depth: usize,
+    /// How many processed tokens [`Parser::trace`] remembers, or 0 to not keep a trace.
+    ///
+    /// The trace is added to the message when tree construction panics or gives up, which is
+    /// usually enough to see how it got there without enabling `PARSER_LOGGING`.
+    pub trace_length: usize,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        // NOTE: This is the same limit other engines use.
+        Self {
+            max_tree_depth: 512,
+            trace_length: 32,
+        }
+    }
+}
+
+/// How many times a single token may be processed by the tree construction stage, counting every
+/// time it is reprocessed in another insertion mode.
+///
+/// NOTE: The longest chains in the spec (like a `td` start tag in "in table" that goes through "in
+///       table body" and "in row") are a handful of steps, so a token that hits this limit is
+///       being passed back and forth between insertion modes by a bug.
+const MAX_PROCESSING_STEPS_PER_TOKEN: usize = 100;
+
+/// Why a [`Parser`] stopped before the end of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParserAbort {
+    /// A token was reprocessed over and over without the tree builder making progress.
+    ReprocessingLimitExceeded {
+        /// The token, formatted with `Debug`.
+        token: String,
+        /// The last insertion modes the token was processed in, oldest first.
+        insertion_modes: Vec<InsertionMode>,
+    },
+    /// The tokenizer got stuck in `state` and ended the input early.
+    TokenizerStalled { state: tokenizer::State },
+}
+
+impl std::fmt::Display for ParserAbort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParserAbort::ReprocessingLimitExceeded {
+                token,
+                insertion_modes,
+            } => write!(
+                f,
+                "token was processed more than {MAX_PROCESSING_STEPS_PER_TOKEN} times, ending in the insertion modes {insertion_modes:?}: {token}"
+            ),
+            ParserAbort::TokenizerStalled { state } => {
+                write!(f, "tokenizer made no progress in the {state:?} state")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParserAbort {}
+
 pub struct Parser<'a> {
     arena: typed_arena::Arena<Node<'a>>,
     tokenizer: RefCell<Tokenizer>,
-    new_tokenizer_state: Cell<Option<tokenizer::State>>,
+    options: ParserOptions,
     document: Node<'a>,
     insertion_mode: Cell<InsertionMode>,
     original_insertion_mode: Cell<Option<InsertionMode>>,
@@ -88,15 +165,41 @@ pub struct Parser<'a> {
     form_element: NodeLink<'a>,
     scripting: bool,
     frameset_ok: Cell<bool>,
+    /// Set by the `pre`, `listing` and `textarea` start tags, which ignore a U+000A LINE FEED
+    /// character token if it is the next token.
+    ignore_next_line_feed: Cell<bool>,
+    foster_parenting: Cell<bool>,
+    pending_table_character_tokens: RefCell<Vec<char>>,
+    template_insertion_modes: RefCell<Vec<InsertionMode>>,
+    /// The context element passed to the HTML fragment parsing algorithm, if this parser was
+    /// created for it.
+    context_element: NodeLink<'a>,
+    /// The insertion modes the current token was processed in so far, including reprocessing.
+    insertion_modes_for_current_token: RefCell<Vec<InsertionMode>>,
+    aborted: RefCell<Option<ParserAbort>>,
+    /// The last processed tokens, see [`Parser::trace`].
+    trace: RefCell<VecDeque<TraceEntry>>,
+    /// An insertion mode whose handler is replaced by one that reprocesses every token in the
+    /// same insertion mode, to test that the parser doesn't hang on a broken handler.
+    #[cfg(test)]
+    broken_insertion_mode: Cell<Option<InsertionMode>>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(arena: typed_arena::Arena<Node<'a>>, input: &str) -> Self {
+        Self::with_options(arena, input, ParserOptions::default())
+    }
+
+    pub fn with_options(
+        arena: typed_arena::Arena<Node<'a>>,
+        input: &str,
+        options: ParserOptions,
+    ) -> Self {
         Self {
             arena,
             tokenizer: RefCell::new(Tokenizer::new(input)),
-            new_tokenizer_state: Cell::new(None),
-            document: Node::new(None, NodeType::Document),
+            options,
+            document: Node::new(None, NodeType::Document(Document::new())),
             insertion_mode: Cell::new(InsertionMode::Initial),
             original_insertion_mode: Cell::new(None),
             open_elements: StackOfOpenElements::new(),
@@ -104,14 +207,38 @@ impl<'a> Parser<'a> {
             head_element: Cell::new(None),
             form_element: Cell::new(None),
             scripting: false,
-            frameset_ok: Cell::new(false),
+            frameset_ok: Cell::new(true),
+            ignore_next_line_feed: Cell::new(false),
+            foster_parenting: Cell::new(false),
+            pending_table_character_tokens: RefCell::new(vec![]),
+            template_insertion_modes: RefCell::new(vec![]),
+            context_element: Cell::new(None),
+            insertion_modes_for_current_token: RefCell::new(vec![]),
+            aborted: RefCell::new(None),
+            trace: RefCell::new(VecDeque::new()),
+            #[cfg(test)]
+            broken_insertion_mode: Cell::new(None),
         }
     }
 
+    /// Switches the tokenizer to `state`.
+    ///
+    /// NOTE: This takes effect immediately, so when it is called while processing a start tag, the
+    ///       tokenizer reads the first character after that tag in the new state.
+    pub(crate) fn switch_the_tokenizer_to(&self, state: tokenizer::State) {
+        self.tokenizer.borrow_mut().switch_to(state);
+    }
+
     pub(crate) fn allocate_node(&'a self, node: Node<'a>) -> NodeRef<'a> {
         self.arena.alloc(node)
     }
 
+    pub(crate) fn document(&self) -> &Document {
+        self.document
+            .as_document()
+            .expect("The parser's document node should be a Document")
+    }
+
     pub(crate) fn process_token_using_the_rules_for(
         &'a self,
         insertion_mode: InsertionMode,
@@ -119,20 +246,78 @@ impl<'a> Parser<'a> {
     ) {
         log_current_process!(format!("{:?}", insertion_mode), token);
 
+        if self.aborted.borrow().is_some() {
+            return;
+        }
+        self.record_trace(Some(insertion_mode), token);
+        let steps = {
+            let mut insertion_modes = self.insertion_modes_for_current_token.borrow_mut();
+            insertion_modes.push(insertion_mode);
+            insertion_modes.len()
+        };
+        if steps > MAX_PROCESSING_STEPS_PER_TOKEN {
+            self.abort_reprocessing(token);
+            return;
+        }
+
+        #[cfg(test)]
+        if self.broken_insertion_mode.get() == Some(insertion_mode) {
+            self.process_token_using_the_rules_for(insertion_mode, token);
+            return;
+        }
+
         match insertion_mode {
             InsertionMode::Initial => self.handle_initial(token),
             InsertionMode::BeforeHtml => self.handle_before_html(token),
             InsertionMode::BeforeHead => self.handle_before_head(token),
             InsertionMode::InHead => self.handle_in_head(token),
+            InsertionMode::InHeadNoscript => self.handle_in_head_noscript(token),
             InsertionMode::AfterHead => self.handle_after_head(token),
             InsertionMode::InBody => self.handle_in_body(token),
+            InsertionMode::InTable => self.handle_in_table(token),
+            InsertionMode::InCaption => self.handle_in_caption(token),
+            InsertionMode::InColumnGroup => self.handle_in_column_group(token),
+            InsertionMode::InTableText => self.handle_in_table_text(token),
+            InsertionMode::InTableBody => self.handle_in_table_body(token),
+            InsertionMode::InRow => self.handle_in_row(token),
+            InsertionMode::InCell => self.handle_in_cell(token),
+            InsertionMode::InSelect => self.handle_in_select(token),
+            InsertionMode::InSelectInTable => self.handle_in_select_in_table(token),
+            InsertionMode::InTemplate => self.handle_in_template(token),
             InsertionMode::AfterBody => self.handle_after_body(token),
+            InsertionMode::InFrameset => self.handle_in_frameset(token),
+            InsertionMode::AfterFrameset => self.handle_after_frameset(token),
             InsertionMode::AfterAfterBody => self.handle_after_after_body(token),
+            InsertionMode::AfterAfterFrameset => self.handle_after_after_frameset(token),
             InsertionMode::Text => self.handle_text(token),
-            _ => todo!("{:?}", insertion_mode),
         }
     }
 
+    /// Stops parsing, because `token` is being reprocessed endlessly.
+    ///
+    /// NOTE: This is always a bug in one of the insertion modes, so debug builds panic, to make it
+    ///       easy to find. Release builds keep the tree built so far instead of hanging or
+    ///       overflowing the stack.
+    fn abort_reprocessing(&self, token: &Token) {
+        // NOTE: A loop between insertion modes repeats, so the last few modes show all of it.
+        let insertion_modes = self.insertion_modes_for_current_token.borrow();
+        let abort = ParserAbort::ReprocessingLimitExceeded {
+            token: format!("{:?}", token),
+            insertion_modes: insertion_modes[insertion_modes.len().saturating_sub(8)..].to_vec(),
+        };
+        if cfg!(debug_assertions) {
+            panic!("{abort}\n{}", self.format_trace());
+        }
+
+        log_parser_error!(format!("{abort}\n{}", self.format_trace()));
+        *self.aborted.borrow_mut() = Some(abort);
+    }
+
+    /// Why parsing stopped before the end of the input, if it did.
+    pub fn aborted(&self) -> Option<ParserAbort> {
+        self.aborted.borrow().clone()
+    }
+
     pub(crate) fn current_node(&'a self) -> NodeRef<'a> {
         self.open_elements.current_node()
     }
@@ -152,11 +337,11 @@ impl<'a> Parser<'a> {
 
         // 2. If the algorithm that was invoked is the generic raw text element parsing algorithm, switch the tokenizer to the RAWTEXT state; otherwise the algorithm invoked was the generic RCDATA element parsing algorithm, switch the tokenizer to the RCDATA state.
         match algorithm {
-            GenericParsingAlgorithm::RawText => self
-                .new_tokenizer_state
-                .set(Some(tokenizer::State::RawText)),
+            GenericParsingAlgorithm::RawText => {
+                self.switch_the_tokenizer_to(tokenizer::State::RawText)
+            }
             GenericParsingAlgorithm::RcData => {
-                self.new_tokenizer_state.set(Some(tokenizer::State::RcData));
+                self.switch_the_tokenizer_to(tokenizer::State::RcData)
             }
         }
 
@@ -171,14 +356,18 @@ impl<'a> Parser<'a> {
     // https://html.spec.whatwg.org/#parsing-main-inforeign
     fn process_using_the_rules_for_foreign_content(&'a self, token: &Token) {
         log_current_process!("Foreign Content", token);
+        self.record_trace(None, token);
 
         macro_rules! pop_invalid_elements {
             ($name:expr) => {
                 // Parse error.
                 log_parser_error!(format!("Invalid start tag '{}' in foreign context", $name));
 
-                // While the current node is not FIXME(a MathML text integration point, an HTML integration point), or an element in the HTML namespace, pop elements from the stack of open elements.
-                while !self.current_node().is_element_with_namespace(Namespace::Html) {
+                // While the current node is not a MathML text integration point, an HTML integration point, or an element in the HTML namespace, pop elements from the stack of open elements.
+                while !(self.current_node().is_mathml_text_integration_point()
+                    || self.current_node().is_html_integration_point()
+                    || self.current_node().is_element_with_namespace(Namespace::Html))
+                {
                     self.open_elements.pop();
                 }
 
@@ -213,58 +402,19 @@ impl<'a> Parser<'a> {
             }
             Token::StartTag {
                 name, attributes, ..
-            } if name == "b"
-                || name == "big"
-                || name == "blockquote"
-                || name == "body"
-                || name == "br"
-                || name == "center"
-                || name == "code"
-                || name == "dd"
-                || name == "div"
-                || name == "dl"
-                || name == "dt"
-                || name == "em"
-                || name == "embed"
-                || name == "h1"
-                || name == "h2"
-                || name == "h3"
-                || name == "h4"
-                || name == "h5"
-                || name == "h6"
-                || name == "head"
-                || name == "hr"
-                || name == "i"
-                || name == "img"
-                || name == "li"
-                || name == "listing"
-                || name == "menu"
-                || name == "meta"
-                || name == "nobr"
-                || name == "ol"
-                || name == "p"
-                || name == "pre"
-                || name == "ruby"
-                || name == "s"
-                || name == "small"
-                || name == "span"
-                || name == "strong"
-                || name == "strike"
-                || name == "sub"
-                || name == "sup"
-                || name == "table"
-                || name == "tt"
-                || name == "u"
-                || name == "ul"
-                || name == "var"
-                || (name == "font"
+            } if elements::breaks_out_of_foreign_content(name)
+                || (*name == local_name!("font")
                     && attributes.iter().any(|attr| {
-                        attr.name == "color" || attr.name == "face" || attr.name == "size"
+                        attr.name == local_name!("color")
+                            || attr.name == local_name!("face")
+                            || attr.name == local_name!("size")
                     })) =>
             {
                 pop_invalid_elements!(name);
             }
-            Token::EndTag { name, .. } if name == "br" || name == "P" => {
+            Token::EndTag { name, .. }
+                if *name == local_name!("br") || *name == local_name!("p") =>
+            {
                 pop_invalid_elements!(name);
             }
             Token::StartTag {
@@ -273,18 +423,38 @@ impl<'a> Parser<'a> {
                 self_closing_acknowledged,
                 ..
             } => {
-                // FIXME: If the adjusted current node is an element in the MathML namespace, adjust MathML attributes for the token. (This fixes the case of MathML attributes that are not all lowercase.)
-                // FIXME: If the adjusted current node is an element in the SVG namespace, and the token's tag name is one of the ones in the first column of the following table, change the tag name to the name given in the corresponding cell in the second column. (This fixes the case of SVG elements that are not all lowercase.)
-                // FIXME: If the adjusted current node is an element in the SVG namespace, adjust SVG attributes for the token. (This fixes the case of SVG attributes that are not all lowercase.)
-                // FIXME: Adjust foreign attributes for the token. (This fixes the use of namespaced attributes, in particular XLink in SVG.)
+                let adjusted_current_node_namespace = self
+                    .adjusted_current_node()
+                    .as_element()
+                    .map(|element| element.namespace());
+                let mut adjusted_token = token.clone();
 
-                // Insert a foreign element for the token, FIXME(in the same namespace as the adjusted current node).
-                self.insert_foreign_element_for_token(token, Namespace::Html);
+                // If the adjusted current node is an element in the MathML namespace, adjust MathML attributes for the token. (This fixes the case of MathML attributes that are not all lowercase.)
+                if adjusted_current_node_namespace == Some(Namespace::MathMl) {
+                    adjusted_token.adjust_mathml_attributes();
+                }
+
+                if adjusted_current_node_namespace == Some(Namespace::Svg) {
+                    // If the adjusted current node is an element in the SVG namespace, and the token's tag name is one of the ones in the first column of the following table, change the tag name to the name given in the corresponding cell in the second column. (This fixes the case of SVG elements that are not all lowercase.)
+                    adjusted_token.adjust_svg_tag_name();
+
+                    // If the adjusted current node is an element in the SVG namespace, adjust SVG attributes for the token. (This fixes the case of SVG attributes that are not all lowercase.)
+                    adjusted_token.adjust_svg_attributes();
+                }
+
+                // Adjust foreign attributes for the token. (This fixes the use of namespaced attributes, in particular XLink in SVG.)
+                adjusted_token.adjust_foreign_attributes();
+
+                // Insert a foreign element for the token, in the same namespace as the adjusted current node.
+                self.insert_foreign_element_for_token(
+                    &adjusted_token,
+                    adjusted_current_node_namespace.unwrap_or(Namespace::Html),
+                );
 
                 // If the token has its self-closing flag set, then run the appropriate steps from the following list:
                 if *self_closing {
                     // -> If the token's tag name is "script", FIXME(and the new current node is in the SVG namespace)
-                    if name == "script" {
+                    if *name == local_name!("script") {
                         // Acknowledge the token's self-closing flag, FIXME(and then act as described in the steps for a "script" end tag below.)
                         self_closing_acknowledged.set(true);
                     }
@@ -296,9 +466,21 @@ impl<'a> Parser<'a> {
                     }
                 }
             }
-            Token::EndTag { name, .. } if name == "script" => {
-                // FIXME: if the current node is an SVG script element
-                todo!()
+            Token::EndTag { name, .. }
+                if *name == local_name!("script")
+                    && self.current_node().is_element_with_tag("script")
+                    && self
+                        .current_node()
+                        .is_element_with_namespace(Namespace::Svg) =>
+            {
+                // Pop the current node off the stack of open elements.
+                self.open_elements.pop();
+
+                // FIXME: Let the old insertion point have the same value as the current insertion point. Let the insertion point be just before the next input character.
+                // FIXME: Increment the parser's script nesting level by one. Set the parser pause flag to true.
+                // FIXME: If the active speculative HTML parser is null and the user agent supports SVG, then Process the SVG script element according to the SVG rules. [SVG]
+                // FIXME: Decrement the parser's script nesting level by one. If the parser's script nesting level is zero, then set the parser pause flag to false.
+                // FIXME: Let the insertion point have the value of the old insertion point. (In other words, restore the insertion point to its previous value. This value might be the "undefined" value.)
             }
             _ => {
                 // 1. Initialize node to be the current node (the bottommost node of the stack).
@@ -317,15 +499,8 @@ impl<'a> Parser<'a> {
                 }
 
                 // 3. Loop: If node is the topmost element in the stack of open elements, then return. (fragment case)
-                for (i, _) in self
-                    .open_elements
-                    .elements
-                    .clone()
-                    .borrow()
-                    .iter()
-                    .enumerate()
-                {
-                    if self.open_elements.first().unwrap() == node {
+                for index in self.open_elements.iter_indices().rev() {
+                    if index == 0 {
                         return;
                     }
                     // 4. If node's tag name, converted to ASCII lowercase, is the same as the tag name of the token, pop elements from the stack of open elements until node has been popped from the stack, and then return.
@@ -337,13 +512,16 @@ impl<'a> Parser<'a> {
                         return;
                     }
                     // 5. Set node to the previous entry in the stack of open elements.
-                    node = self.open_elements.elements.borrow()[i - 1];
+                    node = self
+                        .open_elements
+                        .get(index - 1)
+                        .expect("stack should not change before we stop walking it");
                     // 6. If node is not an element in the HTML namespace, return to the step labeled loop.
                     if !node.is_element_with_namespace(Namespace::Html) {
                         continue;
                     }
 
-                    // 7. Otherwise, process the token according to the rules given in the section   corresponding to the current insertion mode in HTML content.
+                    // 7. Otherwise, process the token according to the rules given in the section corresponding to the current insertion mode in HTML content.
                     self.process_token(token);
                     return;
                 }
@@ -351,36 +529,269 @@ impl<'a> Parser<'a> {
         }
     }
 
+    // https://html.spec.whatwg.org/multipage/parsing.html#adjusted-current-node
+    pub(crate) fn adjusted_current_node(&self) -> NodeRef<'a> {
+        // The adjusted current node is the context element if the parser was created as part of the HTML fragment parsing algorithm and the stack of open elements has only one element in it (fragment case);
+        if let Some(context_element) = self.context_element.get() {
+            if self.open_elements.len() == 1 {
+                return context_element;
+            }
+        }
+        // otherwise, the adjusted current node is the current node.
+        self.open_elements.current_node()
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#tree-construction-dispatcher
     fn token_is_not_in_foreign_context(&self, token: &Token) -> bool {
         // If the stack of open elements is empty
-        self.open_elements.is_empty() ||
-        // FIXME: If the adjusted current node is an element in the HTML namespace
-        self.open_elements.adjusted_current_node().is_element_with_namespace(Namespace::Html) ||
-        // FIXME: If the adjusted current node is a MathML text integration point and the token is a start tag whose tag name is neither "mglyph" nor "malignmark"
-        // FIXME: If the adjusted current node is a MathML text integration point and the token is a character token
-        // FIXME: If the adjusted current node is a MathML annotation-xml element and the token is a start tag whose tag name is "svg"
-        // FIXME: If the adjusted current node is an HTML integration point and the token is a start tag
-        // FIXME: If the adjusted current node is an HTML integration point and the token is a character token
+        if self.open_elements.is_empty() {
+            return true;
+        }
+
+        let node = self.adjusted_current_node();
+        let is_start_tag = matches!(token, Token::StartTag { .. });
+        let is_character = matches!(token, Token::Character { .. });
+
+        // If the adjusted current node is an element in the HTML namespace
+        node.is_element_with_namespace(Namespace::Html) ||
+        // If the adjusted current node is a MathML text integration point and the token is a start tag whose tag name is neither "mglyph" nor "malignmark"
+        (node.is_mathml_text_integration_point()
+            && is_start_tag
+            && !matches!(token.tag_name().as_deref(), Some("mglyph" | "malignmark"))) ||
+        // If the adjusted current node is a MathML text integration point and the token is a character token
+        (node.is_mathml_text_integration_point() && is_character) ||
+        // If the adjusted current node is a MathML annotation-xml element and the token is a start tag whose tag name is "svg"
+        (node.is_element_with_namespace(Namespace::MathMl)
+            && node.is_element_with_tag("annotation-xml")
+            && is_start_tag
+            && token.tag_name().as_deref() == Some("svg")) ||
+        // If the adjusted current node is an HTML integration point and the token is a start tag
+        (node.is_html_integration_point() && is_start_tag) ||
+        // If the adjusted current node is an HTML integration point and the token is a character token
+        (node.is_html_integration_point() && is_character) ||
         // If the token is an end-of-file token
         matches!(token, Token::EndOfFile)
     }
 
+    /// Whether processing the start tag `token` leaves a new element on the stack of open
+    /// elements, which is what [`ParserOptions::max_tree_depth`] limits.
+    ///
+    /// NOTE: Void elements (and self-closing tags in foreign content) are popped right after they
+    ///       are inserted, and the html, head and body start tags only add attributes to existing
+    ///       elements once we are this deep, so they don't make the tree any deeper.
+    /// FIXME: A font start tag with a color, face or size attribute breaks out of foreign
+    ///        content too.
+    fn start_tag_stays_open(&self, token: &Token) -> bool {
+        let Token::StartTag {
+            name, self_closing, ..
+        } = token
+        else {
+            return false;
+        };
+
+        let processed_as_html = self.token_is_not_in_foreign_context(token)
+            || elements::breaks_out_of_foreign_content(name);
+        if !processed_as_html {
+            return !self_closing;
+        }
+
+        // NOTE: An image start tag is inserted as an img element.
+        !(elements::serializes_as_void(name)
+            || matches!(name.as_str(), "image" | "html" | "head" | "body"))
+    }
+
     pub fn parse(&'a self) -> Node<'a> {
-        let mut tokenizer = self.tokenizer.borrow_mut();
-        while let Some(token) = tokenizer.next_token() {
-            if self.token_is_not_in_foreign_context(token) {
-                self.process_token(token)
-            } else {
-                self.process_using_the_rules_for_foreign_content(token)
-            };
+        while self.parse_next_token() {}
+
+        self.document.clone()
+    }
+
+    /// Takes the next token from the tokenizer and runs it through tree construction.
+    ///
+    /// Returns `false` once the tokenizer has no more tokens. Together with
+    /// [`Parser::open_elements_names`] and [`Parser::current_insertion_mode`] this makes it
+    /// possible to look at the state of the tree builder halfway through a document.
+    pub fn parse_next_token(&'a self) -> bool {
+        if self.aborted.borrow().is_some() {
+            return false;
+        }
+        let _trace_on_panic = trace::TraceOnPanic(self);
+
+        // NOTE: The tokenizer is not borrowed while a token is processed, so tree construction can
+        //       switch its state before it reads the character after that token.
+        let token = self.tokenizer.borrow_mut().next_token().cloned();
+        let Some(token) = &token else {
+            return false;
+        };
+        self.insertion_modes_for_current_token.borrow_mut().clear();
 
-            if let Some(new_tokenizer_state) = self.new_tokenizer_state.get() {
-                tokenizer.switch_to(new_tokenizer_state);
-                self.new_tokenizer_state.set(None);
+        // NOTE: Newlines at the start of pre blocks and textarea elements are ignored as an authoring convenience.
+        if self.ignore_next_line_feed.replace(false)
+            && matches!(token, Token::Character { data: '\n' })
+        {
+            return true;
+        }
+
+        if let Token::StartTag { name, .. } = token {
+            if self.open_elements.len() >= self.options.max_tree_depth
+                && self.start_tag_stays_open(token)
+            {
+                log_parser_error!(format!(
+                    "start tag '{}' nests deeper than the maximum tree depth of {}, ignoring it",
+                    name, self.options.max_tree_depth
+                ));
+                return true;
             }
         }
 
-        self.document.clone()
+        if self.token_is_not_in_foreign_context(token) {
+            self.process_token(token)
+        } else {
+            self.process_using_the_rules_for_foreign_content(token)
+        };
+
+        // NOTE: The tokenizer only recognizes CDATA sections in foreign content, which depends on
+        //       the tree built so far.
+        let cdata_sections_allowed = !self.open_elements.is_empty()
+            && !self
+                .adjusted_current_node()
+                .is_element_with_namespace(Namespace::Html);
+        self.tokenizer
+            .borrow_mut()
+            .set_cdata_sections_allowed(cdata_sections_allowed);
+
+        // When a start tag token is emitted with its self-closing flag set, if the flag is not acknowledged when it is processed by the tree construction stage, that is a non-void-html-element-start-tag-with-trailing-solidus parse error.
+        if let Token::StartTag {
+            name,
+            self_closing: true,
+            self_closing_acknowledged,
+            ..
+        } = token
+        {
+            if !self_closing_acknowledged.get() {
+                log_parser_error!(format!(
+                    "non-void-html-element-start-tag-with-trailing-solidus: '{}'",
+                    name
+                ));
+            }
+        }
+
+        // NOTE: A stalled tokenizer ends the input with an end-of-file token, which was processed
+        //       above, so the tree is finished like for any other truncated input.
+        if let Some(state) = self.tokenizer.borrow().stalled_state() {
+            let mut aborted = self.aborted.borrow_mut();
+            if aborted.is_none() {
+                let abort = ParserAbort::TokenizerStalled { state };
+                log_parser_error!(format!("{abort}\n{}", self.format_trace()));
+                *aborted = Some(abort);
+            }
+        }
+
+        true
+    }
+
+    /// The local names of the elements on the stack of open elements, from the bottommost
+    /// (usually `html`) to the current node.
+    ///
+    /// https://html.spec.whatwg.org/multipage/parsing.html#the-stack-of-open-elements
+    pub fn open_elements_names(&self) -> Vec<String> {
+        self.open_elements
+            .elements
+            .borrow()
+            .iter()
+            .filter_map(|element| element.as_element())
+            .map(|element| element.local_name().to_string())
+            .collect()
+    }
+
+    /// https://html.spec.whatwg.org/multipage/parsing.html#insertion-mode
+    pub fn current_insertion_mode(&self) -> InsertionMode {
+        self.insertion_mode.get()
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#html-fragment-parsing-algorithm
+    /// Parses the input as the contents of an HTML element with the given local name and returns
+    /// the resulting nodes.
+    pub fn parse_fragment(&'a self, context_local_name: Atom) -> Vec<NodeRef<'a>> {
+        // 1. Create a new Document node, and mark it as being an HTML document.
+        // NOTE: The parser already owns a new document.
+        let document = &self.document;
+
+        // FIXME: 2. If context's node document is in quirks mode, then let the Document be in quirks mode. Otherwise, if context's node document is in limited-quirks mode, then let the Document be in limited-quirks mode.
+        // NOTE: We create the context element ourselves, so it has no node document to look at.
+        let context = self.create_element(
+            document,
+            context_local_name,
+            Namespace::Html,
+            None,
+            None,
+            false,
+        );
+        self.context_element.set(Some(context));
+
+        // 3. Create a new HTML parser, and associate it with the just created Document node.
+        // NOTE: That is this parser.
+        // 4. Set the state of the HTML parser's tokenization stage as follows, switching on the context element:
+        let state = match context.element_tag_name().as_deref() {
+            // title, textarea: Switch the tokenizer to the RCDATA state.
+            Some("title" | "textarea") => tokenizer::State::RcData,
+            // style, xmp, iframe, noembed, noframes: Switch the tokenizer to the RAWTEXT state.
+            Some("style" | "xmp" | "iframe" | "noembed" | "noframes") => tokenizer::State::RawText,
+            // script: Switch the tokenizer to the script data state.
+            Some("script") => tokenizer::State::ScriptData,
+            // noscript: If the scripting flag is enabled, switch the tokenizer to the RAWTEXT state. Otherwise, leave the tokenizer in the data state.
+            Some("noscript") if self.scripting => tokenizer::State::RawText,
+            // plaintext: Switch the tokenizer to the PLAINTEXT state.
+            Some("plaintext") => tokenizer::State::PlainText,
+            // Any other element: Leave the tokenizer in the data state.
+            _ => tokenizer::State::Data,
+        };
+        self.switch_the_tokenizer_to(state);
+
+        // 5. Let root be the result of creating an element given the Document, "html", the HTML namespace, null, null, false, and the current global object.
+        let root = self.create_element(
+            document,
+            local_name!("html"),
+            Namespace::Html,
+            None,
+            None,
+            false,
+        );
+
+        // 6. Append root to the Document node created above.
+        document
+            .append_child(root)
+            .expect("the new document should not have any children");
+
+        // 7. Set up the HTML parser's stack of open elements so that it contains just the single element root.
+        self.open_elements.push(root);
+
+        // 8. If context is a template element, then push "in template" onto the stack of template insertion modes so that it is the new current template insertion mode.
+        if context.is_html_element_with_tag("template") {
+            self.template_insertion_modes
+                .borrow_mut()
+                .push(InsertionMode::InTemplate);
+        }
+
+        // FIXME: 9. Create a start tag token whose name is the local name of context and whose attributes are the attributes of context.
+        //           Let this start tag token be the start tag token of context; e.g. for the purposes of determining if it is an HTML integration point.
+
+        // 10. Reset the parser's insertion mode appropriately.
+        self.reset_the_insertion_mode_appropriately();
+
+        // 11. Set the HTML parser's form element pointer to the nearest node to context that is a form element (going straight up the ancestor chain, and including the element itself, if it is a form element), if any.
+        // NOTE: Our context element has no ancestors, so it can only be the element itself.
+        if context.is_element_with_tag("form") {
+            self.form_element.set(Some(context));
+        }
+
+        // 12. Place the input into the input stream for the HTML parser just created. The encoding confidence is irrelevant.
+        // NOTE: The input was already given to the tokenizer when this parser was created.
+        // 13. Start the HTML parser and let it run until it has consumed all the characters just inserted into the input stream.
+        self.parse();
+
+        // 14. Return root's children, in tree order.
+        root.child_nodes().clone()
     }
 }
 
@@ -390,21 +801,30 @@ impl<'a> Parser<'a> {
     pub(crate) fn create_element(
         &'a self,
         document: NodeRef<'a>,
-        local_name: &String,
+        local_name: Atom,
         namespace: Namespace,
         _prefix: Option<&String>,
         _is: Option<&String>,
         _synchronous_custom_elements: bool,
     ) -> NodeRef<'a> {
         // FIXME: This does not implement any spec functionality yet!
-        self.allocate_node(Node::new(
+        let element = self.allocate_node(Node::new(
             Some(document),
-            NodeType::Element(Element {
-                tag_name: local_name.to_owned(),
-                namespace: Some(namespace),
-                attributes: RefCell::new(vec![]),
-            }),
-        ))
+            NodeType::Element(Element::new(local_name, namespace)),
+        ));
+
+        // https://html.spec.whatwg.org/multipage/scripting.html#the-template-element:concept-node-create-ext
+        if let Some(template) = element.as_element().filter(|element| element.is_template()) {
+            // 1. Let doc be the template element's node document's appropriate template contents owner document.
+            // FIXME: Use a separate inert document instead of the template element's node document.
+            // 2. Create a DocumentFragment object whose node document is doc and host is the template element.
+            let template_contents =
+                self.allocate_node(Node::new(Some(document), NodeType::DocumentFragment));
+            // 3. Set the template element's template contents to the newly created DocumentFragment object.
+            template.set_template_contents(template_contents);
+        }
+
+        element
     }
 }
 
@@ -426,3 +846,663 @@ impl<'a> Parser<'a> {
         // FIXME: 11. The Document is now ready for post-load tasks.
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{tokenizer, InsertionMode, Parser, ParserOptions};
+    use crate::html::{Atom, Namespace};
+
+    /// Parses `input` as a document and serializes the children of its `body`.
+    pub(crate) fn parse_body(input: &str) -> String {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let body = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("body"))
+            .expect("document should have a body");
+        let html = body
+            .child_nodes()
+            .iter()
+            .map(|child| child.serialize_html())
+            .collect();
+        html
+    }
+
+    /// Parses `input` as a document and returns the namespace, prefix and local name of each
+    /// attribute of the first element with the given local name.
+    fn attribute_names(
+        input: &str,
+        local_name: &str,
+    ) -> Vec<(Option<Namespace>, Option<String>, String)> {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let element = document
+            .descendants()
+            .find(|node| node.is_element_with_tag(local_name))
+            .and_then(|node| node.as_element())
+            .expect("document should contain the element");
+        let names = element
+            .attributes
+            .borrow()
+            .iter()
+            .map(|attr| {
+                (
+                    attr.namespace,
+                    attr.prefix.map(|prefix| prefix.to_string()),
+                    attr.name.to_string(),
+                )
+            })
+            .collect();
+        names
+    }
+
+    #[test]
+    fn head_end_tag_closes_the_head() {
+        let serialize = |input: &str| {
+            let parser = Parser::new(typed_arena::Arena::new(), input);
+            let document = parser.parse();
+            let html = document.first_element_child().unwrap().serialize_html();
+            html
+        };
+
+        assert_eq!(
+            serialize("<head><title>t</title></head><body>b</body>"),
+            "<html><head><title>t</title></head><body>b</body></html>"
+        );
+        // NOTE: Whitespace and comments after the head go into the html element.
+        assert_eq!(
+            serialize("<head></head> <!--c--><body></body>"),
+            "<html><head></head> <!--c--><body></body></html>"
+        );
+        // NOTE: A second head end tag is ignored.
+        assert_eq!(
+            serialize("<head></head></head><p>x"),
+            "<html><head></head><body><p>x</p></body></html>"
+        );
+    }
+
+    #[test]
+    fn only_the_matching_end_tag_ends_rcdata_and_rawtext() {
+        let head = |input: &str| {
+            let parser = Parser::new(typed_arena::Arena::new(), input);
+            let document = parser.parse();
+            let head = document
+                .descendants()
+                .find(|node| node.is_element_with_tag("head"))
+                .unwrap();
+            let html: String = head
+                .child_nodes()
+                .iter()
+                .map(|child| child.serialize_html())
+                .collect();
+            html
+        };
+
+        assert_eq!(
+            head("<title></tit>x</title>"),
+            "<title>&lt;/tit&gt;x</title>"
+        );
+        assert_eq!(head("<style></sty"), "<style></sty</style>");
+        assert_eq!(head("<style></styles></style>"), "<style></styles></style>");
+    }
+
+    #[test]
+    fn adjust_svg_attributes() {
+        let input = r##"<svg viewbox="0 0 1 1" xlink:href="#a" xml:lang="en" xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink">"##;
+        assert_eq!(
+            attribute_names(input, "svg"),
+            vec![
+                (None, None, "viewBox".to_string()),
+                (
+                    Some(Namespace::XLink),
+                    Some("xlink".to_string()),
+                    "href".to_string()
+                ),
+                (
+                    Some(Namespace::Xml),
+                    Some("xml".to_string()),
+                    "lang".to_string()
+                ),
+                (Some(Namespace::XmlNs), None, "xmlns".to_string()),
+                (
+                    Some(Namespace::XmlNs),
+                    Some("xmlns".to_string()),
+                    "xlink".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn adjust_mathml_attributes() {
+        assert_eq!(
+            attribute_names(
+                r#"<math definitionurl="a" xlink:href="b" xml:space="preserve">"#,
+                "math"
+            ),
+            vec![
+                (None, None, "definitionURL".to_string()),
+                (
+                    Some(Namespace::XLink),
+                    Some("xlink".to_string()),
+                    "href".to_string()
+                ),
+                (
+                    Some(Namespace::Xml),
+                    Some("xml".to_string()),
+                    "space".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn html_attributes_are_not_adjusted() {
+        assert_eq!(
+            attribute_names(r#"<div xlink:href="a" viewbox="b">"#, "div"),
+            vec![
+                (None, None, "xlink:href".to_string()),
+                (None, None, "viewbox".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn foreign_attributes_round_trip() {
+        assert_eq!(
+            parse_body(r##"<svg><use xlink:href="#a" xml:lang="en"></use></svg>"##),
+            r##"<svg><use xlink:href="#a" xml:lang="en"></use></svg>"##
+        );
+    }
+
+    #[test]
+    fn cdata_sections_only_in_foreign_content() {
+        assert_eq!(
+            parse_body("<svg><![CDATA[<b>]]></svg><![CDATA[x]]>"),
+            "<svg>&lt;b&gt;</svg><!--[CDATA[x]]-->"
+        );
+    }
+
+    /// The namespace of the first element named `tag` in the document parsed from `input`.
+    fn namespace_of(input: &str, tag: &str) -> Namespace {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let namespace = document
+            .descendants()
+            .find_map(|node| {
+                node.as_element()
+                    .filter(|element| &*element.local_name() == tag)
+                    .map(|element| element.namespace())
+            })
+            .expect("document should have the element");
+        namespace
+    }
+
+    #[test]
+    fn html_integration_points() {
+        assert_eq!(
+            parse_body("<svg><foreignObject><p>x</p></foreignObject></svg>y"),
+            "<svg><foreignObject><p>x</p></foreignObject></svg>y"
+        );
+        assert_eq!(
+            parse_body("<svg><desc>a<b>b</b></desc><title><div>c</div></title></svg>"),
+            "<svg><desc>a<b>b</b></desc><title><div>c</div></title></svg>"
+        );
+        assert_eq!(
+            parse_body(
+                "<math><annotation-xml encoding=\"Text/HTML\"><p>x</p></annotation-xml></math>y"
+            ),
+            "<math><annotation-xml encoding=\"Text/HTML\"><p>x</p></annotation-xml></math>y"
+        );
+        assert_eq!(
+            namespace_of("<svg><foreignObject><p>x</p></foreignObject></svg>", "p"),
+            Namespace::Html
+        );
+
+        // NOTE: Without an HTML encoding, annotation-xml is not an integration point.
+        assert_eq!(
+            parse_body("<math><annotation-xml><p>x"),
+            "<math><annotation-xml></annotation-xml></math><p>x</p>"
+        );
+        assert_eq!(parse_body("<svg><g><p>x"), "<svg><g></g></svg><p>x</p>");
+    }
+
+    #[test]
+    fn mathml_text_integration_points() {
+        assert_eq!(
+            parse_body("<math><mi><b>x</b></mi></math>y"),
+            "<math><mi><b>x</b></mi></math>y"
+        );
+        assert_eq!(
+            namespace_of("<math><mi><b>x</b></mi></math>", "b"),
+            Namespace::Html
+        );
+
+        // NOTE: mglyph and malignmark stay MathML elements.
+        assert_eq!(
+            namespace_of("<math><mi><mglyph></mi></math>", "mglyph"),
+            Namespace::MathMl
+        );
+    }
+
+    #[test]
+    fn scope_boundaries_depend_on_the_namespace() {
+        // NOTE: HTML elements with the names of MathML and SVG scope boundaries don't end the
+        //       scope, so the paragraph is closed.
+        assert_eq!(parse_body("<p><mi></p>x"), "<p><mi></mi></p>x");
+        assert_eq!(parse_body("<p><desc></p>x"), "<p><desc></desc></p>x");
+        assert_eq!(
+            parse_body("<ul><li><title>t</title><li>x"),
+            "<ul><li><title>t</title></li><li>x</li></ul>"
+        );
+
+        // NOTE: The SVG title element does end the scope.
+        assert_eq!(
+            parse_body("<p><svg><title></p>x"),
+            "<p><svg><title><p></p>x</title></svg></p>"
+        );
+    }
+
+    #[test]
+    fn foreign_elements_are_not_html_elements_with_the_same_name() {
+        // NOTE: The MathML template is not a template element, so it doesn't have a template
+        //       insertion mode and is popped with the HTML template at the end of the file.
+        assert_eq!(
+            parse_body("<body><template><math><template>x"),
+            "<template><math><template>x</template></math></template>"
+        );
+        // NOTE: The SVG td doesn't put the parser in the in cell insertion mode.
+        assert_eq!(
+            parse_body("<table><tr><td><svg><td>x</td></svg>y"),
+            "<table><tbody><tr><td><svg><td>x</td></svg>y</td></tr></tbody></table>"
+        );
+    }
+
+    #[test]
+    fn svg_in_annotation_xml() {
+        assert_eq!(
+            namespace_of(
+                "<math><annotation-xml><svg><circle/></svg></annotation-xml></math>",
+                "circle"
+            ),
+            Namespace::Svg
+        );
+    }
+
+    /// Parses `input` as the contents of a `context` element and returns the state the tokenizer
+    /// was left in, together with the text content of the resulting nodes.
+    fn parse_fragment_text(context: &str, input: &str) -> (tokenizer::State, String) {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let text = parser
+            .parse_fragment(Atom::from(context))
+            .iter()
+            .filter_map(|node| node.text_content())
+            .collect();
+        let state = parser.tokenizer.borrow().state();
+        (state, text)
+    }
+
+    #[test]
+    fn fragment_context_sets_the_tokenizer_state() {
+        for (contexts, state) in [
+            (&["title", "textarea"][..], tokenizer::State::RcData),
+            (
+                &["style", "xmp", "iframe", "noembed", "noframes"][..],
+                tokenizer::State::RawText,
+            ),
+            (&["script"][..], tokenizer::State::ScriptData),
+            (&["plaintext"][..], tokenizer::State::PlainText),
+            (&["div", "noscript"][..], tokenizer::State::Data),
+        ] {
+            for context in contexts {
+                assert_eq!(parse_fragment_text(context, "").0, state, "{context}");
+            }
+        }
+    }
+
+    #[test]
+    fn fragment_context_changes_how_the_input_is_tokenized() {
+        let input = "&amp;<b>x</b>";
+        assert_eq!(parse_fragment_text("div", input).1, "&x");
+        assert_eq!(parse_fragment_text("title", input).1, "&<b>x</b>");
+        assert_eq!(parse_fragment_text("style", input).1, "&amp;<b>x</b>");
+        assert_eq!(parse_fragment_text("script", input).1, "&amp;<b>x</b>");
+        assert_eq!(parse_fragment_text("plaintext", input).1, input);
+    }
+
+    #[test]
+    fn max_tree_depth_only_ignores_elements_that_stay_open() {
+        let input = format!(
+            "{}<img><br>x<span>y</span>{}<p>after",
+            "<div>".repeat(1000),
+            "</div>".repeat(1000)
+        );
+        let parser = Parser::new(typed_arena::Arena::new(), &input);
+        let document = parser.parse();
+
+        let divs = document
+            .descendants()
+            .filter(|node| node.is_element_with_tag("div"))
+            .count();
+        // NOTE: The html and body elements are on the stack as well.
+        assert_eq!(divs, ParserOptions::default().max_tree_depth - 2);
+
+        let deepest_div = document
+            .descendants()
+            .filter(|node| node.is_element_with_tag("div"))
+            .last()
+            .unwrap();
+        let contents: String = deepest_div
+            .child_nodes()
+            .iter()
+            .map(|child| child.serialize_html())
+            .collect();
+        assert_eq!(contents, "<img><br>xy");
+
+        let body = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("body"))
+            .unwrap();
+        let last_child = body.child_nodes().last().unwrap().serialize_html();
+        assert_eq!(last_child, "<p>after</p>");
+    }
+
+    #[test]
+    fn max_tree_depth_in_foreign_content() {
+        let options = ParserOptions {
+            max_tree_depth: 4,
+            ..Default::default()
+        };
+        // NOTE: html, body, svg and g fill the stack. The link element stays open in SVG, unlike
+        //       in HTML, so it is ignored, and the self-closing rect is popped right away.
+        let input = "<svg><g><link><rect/><foreignObject></foreignObject></g></svg>";
+        let parser = Parser::with_options(typed_arena::Arena::new(), input, options);
+        let document = parser.parse();
+
+        let g = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("g"))
+            .unwrap();
+        let contents: String = g
+            .child_nodes()
+            .iter()
+            .map(|child| child.serialize_html())
+            .collect();
+        assert_eq!(contents, "<rect></rect>");
+    }
+
+    #[test]
+    fn every_prefix_of_generated_documents_parses() {
+        for profile in test_support::Profile::ALL {
+            let document = test_support::generate_document(1, 1000, profile);
+            for prefix in test_support::all_prefixes(&document) {
+                let parser = Parser::new(typed_arena::Arena::new(), prefix);
+                parser.parse();
+                assert_eq!(
+                    parser.aborted(),
+                    None,
+                    "{} prefix {prefix:?}",
+                    profile.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reprocessing_guard_stops_a_handler_that_never_makes_progress() {
+        let parser = Parser::new(typed_arena::Arena::new(), "<p>a</p>");
+        parser
+            .broken_insertion_mode
+            .set(Some(super::InsertionMode::InBody));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            parser.parse();
+        }));
+
+        // NOTE: Debug builds panic so the bug is easy to find, release builds keep the tree.
+        if cfg!(debug_assertions) {
+            let message = result.expect_err("debug builds should panic");
+            let message = message.downcast_ref::<String>().unwrap();
+            assert!(message.contains("more than 100 times"), "{message}");
+        } else {
+            assert!(matches!(
+                parser.aborted(),
+                Some(super::ParserAbort::ReprocessingLimitExceeded { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn tokenizer_guard_stops_a_state_that_never_makes_progress() {
+        let parser = Parser::new(typed_arena::Arena::new(), "<p>a</p>");
+        parser.tokenizer.borrow_mut().broken_state = Some(tokenizer::State::TagOpen);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            parser.parse();
+        }));
+
+        if cfg!(debug_assertions) {
+            let message = result.expect_err("debug builds should panic");
+            let message = message.downcast_ref::<String>().unwrap();
+            assert!(message.contains("no progress"), "{message}");
+        } else {
+            assert_eq!(
+                parser.aborted(),
+                Some(super::ParserAbort::TokenizerStalled {
+                    state: tokenizer::State::TagOpen
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn end_tags_in_foreign_content() {
+        // The end tag closes the foreign element with the same name, ignoring ASCII case.
+        assert_eq!(
+            parse_body("<svg><g><path></G>x</svg>"),
+            "<svg><g><path></path></g>x</svg>"
+        );
+        // An end tag that matches no foreign element is handled by the HTML rules.
+        assert_eq!(
+            parse_body("<div><svg><g></div>after"),
+            "<div><svg><g></g></svg></div>after"
+        );
+        assert_eq!(parse_body("<svg><g></x>y</g></svg>"), "<svg><g>y</g></svg>");
+        // A breakout start tag pops the foreign elements.
+        assert_eq!(
+            parse_body("<svg><g><g><p>x"),
+            "<svg><g><g></g></g></svg><p>x</p>"
+        );
+    }
+
+    #[test]
+    fn end_tags_walk_the_stack_of_open_elements() {
+        assert_eq!(
+            parse_body("<ul><li>a<div><li>b"),
+            "<ul><li>a<div></div></li><li>b</li></ul>"
+        );
+        assert_eq!(parse_body("<x-a><x-b>1</x-a>2"), "<x-a><x-b>1</x-b></x-a>2");
+        // NOTE: A special element stops the search, so the end tag is ignored.
+        assert_eq!(parse_body("<x-a><div>1</x-a>2"), "<x-a><div>12</div></x-a>");
+    }
+
+    #[test]
+    fn deeply_nested_foreign_content() {
+        const DEPTH: usize = 200;
+        let input = format!(
+            "<svg>{}x{}</svg>y",
+            "<g>".repeat(DEPTH),
+            "</g>".repeat(DEPTH)
+        );
+        assert_eq!(parse_body(&input), input);
+
+        let input = format!("<svg>{}<p>x", "<g>".repeat(DEPTH));
+        assert_eq!(
+            parse_body(&input),
+            format!(
+                "<svg>{}</svg><p>x</p>",
+                "<g>".repeat(DEPTH) + &"</g>".repeat(DEPTH)
+            )
+        );
+    }
+
+    #[test]
+    fn raw_text_ends_at_the_end_tag_of_the_element_that_started_it() {
+        let document = |input: &str| {
+            let parser = Parser::new(typed_arena::Arena::new(), input);
+            let document = parser.parse();
+            document.serialize_html()
+        };
+
+        assert_eq!(
+            document("<style>a</style>"),
+            "<html><head><style>a</style></head><body></body></html>"
+        );
+        assert_eq!(
+            document("<title>t</title><style>s</title></style><p>b"),
+            "<html><head><title>t</title><style>s</title></style></head><body><p>b</p></body></html>"
+        );
+        assert_eq!(parse_body("<xmp></style></xmp>x"), "<xmp></style></xmp>x");
+        assert_eq!(
+            parse_body("<textarea><style></textarea>x"),
+            "<textarea>&lt;style&gt;</textarea>x"
+        );
+        assert_eq!(
+            parse_body("<noembed><style></style></noembed>x"),
+            "<noembed><style></style></noembed>x"
+        );
+    }
+
+    #[test]
+    fn end_of_file_in_every_insertion_mode_completes_the_tree() {
+        let document = |input: &str| {
+            let parser = Parser::new(typed_arena::Arena::new(), input);
+            let document = parser.parse();
+            document.serialize_html()
+        };
+
+        let empty = "<html><head></head><body></body></html>";
+        for input in [
+            "",
+            "<!DOCTYPE html>",
+            "<html>",
+            "<html><head>",
+            "<body>",
+            "</body>",
+            "</html>",
+        ] {
+            assert_eq!(
+                document(input).trim_start_matches("<!DOCTYPE html>"),
+                empty,
+                "{input:?}"
+            );
+        }
+
+        for (input, expected) in [
+            ("<div><p>hi", "<html><head></head><body><div><p>hi</p></div></body></html>"),
+            ("<head><title>x", "<html><head><title>x</title></head><body></body></html>"),
+            ("<head><noscript>", "<html><head><noscript></noscript></head><body></body></html>"),
+            ("<head><script>a", "<html><head><script>a</script></head><body></body></html>"),
+            (
+                "<table><tr><td>x",
+                "<html><head></head><body><table><tbody><tr><td>x</td></tr></tbody></table></body></html>",
+            ),
+            (
+                "<table><caption>x",
+                "<html><head></head><body><table><caption>x</caption></table></body></html>",
+            ),
+            (
+                "<table><colgroup>",
+                "<html><head></head><body><table><colgroup></colgroup></table></body></html>",
+            ),
+            (
+                "<select><option>a",
+                "<html><head></head><body><select><option>a</option></select></body></html>",
+            ),
+            ("<textarea>x", "<html><head></head><body><textarea>x</textarea></body></html>"),
+            ("<svg><g>", "<html><head></head><body><svg><g></g></svg></body></html>"),
+            ("<frameset>", "<html><head></head><frameset></frameset></html>"),
+            ("</body>x", "<html><head></head><body>x</body></html>"),
+            ("</html><!--c-->", "<html><head></head><body></body></html><!--c-->"),
+        ] {
+            assert_eq!(document(input), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn raw_text_starts_right_after_the_start_tag() {
+        let document = |input: &str| {
+            let parser = Parser::new(typed_arena::Arena::new(), input);
+            let document = parser.parse();
+            document.serialize_html()
+        };
+
+        // NOTE: The first character after the start tag has to be tokenized in the new state already.
+        assert_eq!(
+            document("<style><!--</style>x"),
+            "<html><head><style><!--</style></head><body>x</body></html>"
+        );
+        assert_eq!(
+            document("<style><p>a</p></style>"),
+            "<html><head><style><p>a</p></style></head><body></body></html>"
+        );
+        assert_eq!(
+            document("<script></script>x"),
+            "<html><head><script></script></head><body>x</body></html>"
+        );
+        assert_eq!(
+            document("<script>a</script><script>b</script>c"),
+            "<html><head><script>a</script><script>b</script></head><body>c</body></html>"
+        );
+        assert_eq!(
+            parse_body("<p><script><b></script>text"),
+            "<p><script><b></script>text</p>"
+        );
+        assert_eq!(
+            document("<title><b></title>x"),
+            "<html><head><title>&lt;b&gt;</title></head><body>x</body></html>"
+        );
+    }
+
+    #[test]
+    fn open_elements_and_insertion_mode_while_parsing() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<!DOCTYPE html><table><tr><td><b>x</b></td></tr></table><p>y",
+        );
+        assert!(parser.open_elements_names().is_empty());
+        assert_eq!(parser.current_insertion_mode(), InsertionMode::Initial);
+
+        // NOTE: Collects the state after every token, leaving out repeats.
+        let mut states = vec![];
+        while parser.parse_next_token() {
+            let state = (
+                parser.open_elements_names().join(" "),
+                parser.current_insertion_mode(),
+            );
+            if states.last() != Some(&state) {
+                states.push(state);
+            }
+        }
+
+        let states = states
+            .iter()
+            .map(|(names, mode)| format!("{names} {mode:?}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            states,
+            [
+                " BeforeHtml",
+                "html body table InTable",
+                "html body table tbody tr InRow",
+                "html body table tbody tr td InCell",
+                "html body table tbody tr td b InCell",
+                "html body table tbody tr td InCell",
+                "html body table tbody tr InRow",
+                "html body table tbody InTableBody",
+                "html body InBody",
+                "html body p InBody",
+                // NOTE: The end of the file pops all open elements.
+                " InBody",
+            ]
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+
+use super::{InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+
+/// How many characters of a comment or doctype name a [`TraceEntry`] keeps.
+const MAX_SUMMARY_LENGTH: usize = 24;
+
+/// A token that the tree construction stage processed, and the rules it was processed with.
+///
+/// See [`Parser::trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// The insertion mode whose rules processed the token, or `None` for the rules for parsing
+    /// tokens in foreign content.
+    pub insertion_mode: Option<InsertionMode>,
+    /// A short description of the token, like `<div>`, `</p>` or `'a'`.
+    pub token: String,
+}
+
+impl TraceEntry {
+    fn new(insertion_mode: Option<InsertionMode>, token: &Token) -> Self {
+        Self {
+            insertion_mode,
+            token: summarize_token(token),
+        }
+    }
+}
+
+impl std::fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.insertion_mode {
+            Some(insertion_mode) => write!(f, "{insertion_mode:?}: {}", self.token),
+            None => write!(f, "Foreign Content: {}", self.token),
+        }
+    }
+}
+
+/// Describes `token` in a way that is short enough to list a lot of them.
+fn summarize_token(token: &Token) -> String {
+    let truncate = |text: &str| match text.char_indices().nth(MAX_SUMMARY_LENGTH) {
+        Some((index, _)) => format!("{}…", &text[..index]),
+        None => text.to_string(),
+    };
+
+    match token {
+        Token::Doctype { name, .. } => {
+            format!("<!DOCTYPE {}>", truncate(name.as_deref().unwrap_or("")))
+        }
+        Token::StartTag {
+            name, self_closing, ..
+        } => match self_closing {
+            true => format!("<{name}/>"),
+            false => format!("<{name}>"),
+        },
+        Token::EndTag { name, .. } => format!("</{name}>"),
+        Token::Comment { data } => format!("<!--{}-->", truncate(data)),
+        Token::Character { data } => format!("{data:?}"),
+        Token::EndOfFile => "EOF".to_string(),
+    }
+}
+
+/// Prints the trace of the parser to stderr if it is dropped while panicking, so a `todo!()` or
+/// a failed assertion in tree construction shows how the parser got there.
+pub(super) struct TraceOnPanic<'p, 'a>(pub(super) &'p Parser<'a>);
+
+impl Drop for TraceOnPanic<'_, '_> {
+    fn drop(&mut self) {
+        // NOTE: The trace can't be read if the panic happened while it was being written to.
+        if std::thread::panicking() && self.0.trace.try_borrow_mut().is_ok() {
+            eprintln!("{}", self.0.format_trace());
+        }
+    }
+}
+
+impl Parser<'_> {
+    /// The last tokens the tree construction stage processed, oldest first, together with the
+    /// rules they were processed with. A token that is reprocessed shows up once for every
+    /// insertion mode it goes through.
+    ///
+    /// At most [`ParserOptions::trace_length`](super::ParserOptions::trace_length) entries are
+    /// kept.
+    pub fn trace(&self) -> Vec<TraceEntry> {
+        self.trace.borrow().iter().cloned().collect()
+    }
+
+    pub(super) fn record_trace(&self, insertion_mode: Option<InsertionMode>, token: &Token) {
+        let trace_length = self.options.trace_length;
+        if trace_length == 0 {
+            return;
+        }
+
+        let mut trace = self.trace.borrow_mut();
+        if trace.len() == trace_length {
+            trace.pop_front();
+        }
+        trace.push_back(TraceEntry::new(insertion_mode, token));
+    }
+
+    /// Formats the trace with one entry per line, to add to error messages.
+    pub(super) fn format_trace(&self) -> String {
+        let trace: &VecDeque<TraceEntry> = &self.trace.borrow();
+        let mut message = format!("last {} processed tokens (oldest first):", trace.len());
+        for entry in trace {
+            message.push_str("\n  ");
+            message.push_str(&entry.to_string());
+        }
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ParserOptions;
+    use super::*;
+
+    fn trace(input: &str, trace_length: usize) -> Vec<String> {
+        let options = ParserOptions {
+            trace_length,
+            ..Default::default()
+        };
+        let parser = Parser::with_options(typed_arena::Arena::new(), input, options);
+        parser.parse();
+        let trace = parser.trace();
+        trace.iter().map(|entry| entry.to_string()).collect()
+    }
+
+    #[test]
+    fn trace_lists_the_insertion_modes_in_order() {
+        assert_eq!(
+            trace("<!DOCTYPE html><title>t</title><p>a<svg><g/></svg>", 64),
+            [
+                "Initial: <!DOCTYPE html>",
+                // NOTE: A reprocessed token shows up for every insertion mode it goes through.
+                "BeforeHtml: <title>",
+                "BeforeHead: <title>",
+                "InHead: <title>",
+                "Text: 't'",
+                "Text: </title>",
+                "InHead: <p>",
+                "AfterHead: <p>",
+                "InBody: <p>",
+                "InBody: 'a'",
+                "InBody: <svg>",
+                "Foreign Content: <g/>",
+                "Foreign Content: </svg>",
+                "InBody: EOF",
+            ]
+        );
+    }
+
+    #[test]
+    fn trace_keeps_only_the_last_tokens() {
+        assert_eq!(
+            trace("<!DOCTYPE html><p>a</p>", 3),
+            ["InBody: 'a'", "InBody: </p>", "InBody: EOF"]
+        );
+        assert!(trace("<!DOCTYPE html><p>a</p>", 0).is_empty());
+    }
+
+    #[test]
+    fn long_comments_are_truncated() {
+        let comment = "x".repeat(MAX_SUMMARY_LENGTH);
+        assert_eq!(
+            summarize_token(&Token::Comment {
+                data: format!("{comment}yz"),
+            }),
+            format!("<!--{comment}…-->")
+        );
+        assert_eq!(
+            summarize_token(&Token::Comment {
+                data: comment.clone(),
+            }),
+            format!("<!--{comment}-->")
+        );
+    }
+}
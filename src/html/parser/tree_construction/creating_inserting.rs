@@ -3,22 +3,32 @@ use std::cell::{Cell, RefCell};
 use crate::dom::node::NodeType;
 use crate::dom::{Attr, Node, NodeRef};
 use crate::html::parser::Parser;
-use crate::html::tokenizer::Token;
-use crate::html::Namespace;
+use crate::html::tokenizer::{Attribute, Token};
+use crate::html::{Atom, Namespace};
 
-enum InsertionLocation {
+enum InsertionLocation<'a> {
     AfterLastChildIfAny,
+    ImmediatelyBefore(NodeRef<'a>),
 }
 
 pub(crate) struct AdjustedInsertionLocation<'a> {
-    parent: NodeRef<'a>,
-    child: InsertionLocation,
+    pub(crate) parent: NodeRef<'a>,
+    child: InsertionLocation<'a>,
 }
 
 impl<'a> AdjustedInsertionLocation<'a> {
     pub(crate) fn child_node(&self) -> Option<NodeRef<'a>> {
         match self.child {
             InsertionLocation::AfterLastChildIfAny => None,
+            InsertionLocation::ImmediatelyBefore(child) => Some(child),
+        }
+    }
+
+    /// The node immediately before the insertion location, if any.
+    fn node_before(&self) -> Option<NodeRef<'a>> {
+        match self.child {
+            InsertionLocation::AfterLastChildIfAny => self.parent.last_child(),
+            InsertionLocation::ImmediatelyBefore(child) => child.previous_sibling(),
         }
     }
 
@@ -41,24 +51,81 @@ impl<'a> Parser<'a> {
         };
 
         // 2. Determine the adjusted insertion location using the first matching steps from the following list:
-        // FIXME: If foster parenting is enabled and target is a table, tbody, tfoot, thead, or tr element
-        // Otherwise, let adjusted insertion location be inside target, after its last child (if any).
-        let adjusted_insertion_location = AdjustedInsertionLocation {
-            parent: target,
-            child: InsertionLocation::AfterLastChildIfAny,
+        let mut adjusted_insertion_location = if self.foster_parenting.get()
+            && target.is_element_with_one_of_tags(&["table", "tbody", "tfoot", "thead", "tr"])
+        {
+            // If foster parenting is enabled and target is a table, tbody, tfoot, thead, or tr element
+            self.foster_parenting_insertion_location()
+        } else {
+            // Otherwise, let adjusted insertion location be inside target, after its last child (if any).
+            AdjustedInsertionLocation {
+                parent: target,
+                child: InsertionLocation::AfterLastChildIfAny,
+            }
         };
 
-        // FIXME: 3. If the adjusted insertion location is inside a template element, let it instead be inside the template element's template contents, after its last child (if any).
-        if adjusted_insertion_location
+        // 3. If the adjusted insertion location is inside a template element, let it instead be inside the template element's template contents, after its last child (if any).
+        if let Some(template_contents) = adjusted_insertion_location
             .parent
-            .is_element_with_tag("template")
+            .as_element()
+            .and_then(|element| element.content())
         {
-            // FIXME: Implement
+            adjusted_insertion_location = AdjustedInsertionLocation {
+                parent: template_contents,
+                child: InsertionLocation::AfterLastChildIfAny,
+            };
         }
         // 4. Return the adjusted insertion location.
         adjusted_insertion_location
     }
 
+    // https://html.spec.whatwg.org/multipage/parsing.html#foster-parent
+    fn foster_parenting_insertion_location(&'a self) -> AdjustedInsertionLocation<'a> {
+        // 1. Let last template be the last template element in the stack of open elements, if any.
+        let last_template = self.open_elements.last_element_with_tag_name("template");
+        // 2. Let last table be the last table element in the stack of open elements, if any.
+        let last_table = self.open_elements.last_element_with_tag_name("table");
+
+        // 3. If there is a last template and either there is no last table, or there is one, but last template is lower (more recently added) than last table in the stack of open elements, then: let adjusted insertion location be inside last template's template contents, after its last child (if any), and abort these steps.
+        // NOTE: The caller moves the location into the template contents.
+        if let Some((template_index, last_template)) = last_template {
+            if last_table.is_none_or(|(table_index, _)| template_index > table_index) {
+                return AdjustedInsertionLocation {
+                    parent: last_template,
+                    child: InsertionLocation::AfterLastChildIfAny,
+                };
+            }
+        }
+
+        // 4. If there is no last table, then let adjusted insertion location be inside the first element in the stack of open elements (the html element), after its last child (if any), and abort these steps. (fragment case)
+        let Some((_, last_table)) = last_table else {
+            return AdjustedInsertionLocation {
+                parent: self.open_elements.first().unwrap(),
+                child: InsertionLocation::AfterLastChildIfAny,
+            };
+        };
+
+        // 5. If last table has a parent node, then let adjusted insertion location be inside last table's parent node, immediately before last table, and abort these steps.
+        if let Some(parent) = last_table.parent_node() {
+            return AdjustedInsertionLocation {
+                parent,
+                child: InsertionLocation::ImmediatelyBefore(last_table),
+            };
+        }
+
+        // 6. Let previous element be the element immediately above last table in the stack of open elements.
+        let previous_element = self
+            .open_elements
+            .element_immediately_above(last_table)
+            .unwrap();
+
+        // 7. Let adjusted insertion location be inside previous element, after its last child (if any).
+        AdjustedInsertionLocation {
+            parent: previous_element,
+            child: InsertionLocation::AfterLastChildIfAny,
+        }
+    }
+
     // https://html.spec.whatwg.org/multipage/parsing.html#create-an-element-for-the-token
     pub(super) fn create_element_for_token(
         &'a self,
@@ -89,14 +156,16 @@ impl<'a> Parser<'a> {
         };
 
         // 9. Let element be the result of creating an element given document, localName, given namespace, null, and is. If will execute script is true, set the synchronous custom elements flag; otherwise, leave it unset.
-        let element = self.create_element(document, local_name, namespace, None, None, false);
+        let element = self.create_element(document, *local_name, namespace, None, None, false);
 
         // 10. Append each attribute in the given token to element.
-        if let NodeType::Element(element) = &element.node_type {
+        if let Some(element) = element.as_element() {
             for attr in token_attributes {
                 element.attributes.borrow_mut().push(Attr {
-                    name: attr.name.clone(),
+                    name: attr.name,
                     value: attr.value.clone(),
+                    namespace: attr.namespace,
+                    prefix: attr.prefix,
                 });
             }
         }
@@ -109,6 +178,31 @@ impl<'a> Parser<'a> {
         element
     }
 
+    /// Creates an element for the token for which `element` was created.
+    ///
+    /// NOTE: We don't keep the tokens around, but the elements the parser created still have the
+    ///       tag name and attributes of their token.
+    pub(crate) fn create_element_for_the_token_that_created(
+        &'a self,
+        element: NodeRef<'a>,
+        namespace: Namespace,
+        intended_parent: NodeRef<'a>,
+    ) -> NodeRef<'a> {
+        let token = token_that_created(element);
+        self.create_element_for_token(&token, namespace, intended_parent)
+    }
+
+    /// Inserts an HTML element for the token for which `element` was created.
+    ///
+    /// See [`Parser::create_element_for_the_token_that_created`].
+    pub(crate) fn insert_html_element_for_the_token_that_created(
+        &'a self,
+        element: NodeRef<'a>,
+    ) -> NodeRef<'a> {
+        let token = token_that_created(element);
+        self.insert_html_element_for_token(&token)
+    }
+
     // https://html.spec.whatwg.org/multipage/parsing.html#insert-a-foreign-element
     pub(crate) fn insert_foreign_element_for_token(
         &'a self,
@@ -124,22 +218,19 @@ impl<'a> Parser<'a> {
 
         // 3. If it is possible to insert element at the adjusted insertion location, then:
 
-        match Node::ensure_pre_insertion_validity(
+        if Node::ensure_pre_insertion_validity(
             element,
             adjusted_insertion_location.parent,
             adjusted_insertion_location.child_node(),
         )
         .is_ok()
         {
-            true => {
-                // 3.1. FIXME: If the parser was not created as part of the HTML fragment parsing algorithm, then push a new element queue onto element's relevant agent's custom element reactions stack.
+            // 3.1. FIXME: If the parser was not created as part of the HTML fragment parsing algorithm, then push a new element queue onto element's relevant agent's custom element reactions stack.
 
-                // 3.2. Insert element at the adjusted insertion location.
-                adjusted_insertion_location.insert(element);
+            // 3.2. Insert element at the adjusted insertion location.
+            adjusted_insertion_location.insert(element);
 
-                // 3.3. FIXME: If the parser was not created as part of the HTML fragment parsing algorithm, then pop the element queue from element's relevant agent's custom element reactions stack, and invoke custom element reactions in that queue.
-            }
-            false => (),
+            // 3.3. FIXME: If the parser was not created as part of the HTML fragment parsing algorithm, then pop the element queue from element's relevant agent's custom element reactions stack, and invoke custom element reactions in that queue.
         }
 
         // 4. Push element onto the stack of open elements so that it is the new current node.
@@ -156,7 +247,8 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn insert_html_element_for_start_tag(&'a self, tag: &str) -> NodeRef<'a> {
         self.insert_html_element_for_token(&Token::StartTag {
-            name: tag.to_string(),
+            name: Atom::from(tag),
+            original_name: None,
             self_closing: false,
             self_closing_acknowledged: Cell::new(false),
             attributes: vec![],
@@ -174,8 +266,8 @@ impl<'a> Parser<'a> {
         }
 
         // 4. If there is a Text node immediately before the adjusted insertion location, then append data to that Text node's data.
-        if let Some(last_child) = adjusted_insertion_location.parent.last_child() {
-            if let NodeType::Text { data } = &last_child.node_type {
+        if let Some(node_before) = adjusted_insertion_location.node_before() {
+            if let NodeType::Text { data } = &node_before.node_type {
                 data.borrow_mut().push(character);
                 return;
             }
@@ -192,12 +284,63 @@ impl<'a> Parser<'a> {
     }
 
     // https://html.spec.whatwg.org/#insert-a-comment
-    pub(crate) fn insert_comment(&'a self, _data: &str) {
-        todo!()
+    pub(crate) fn insert_comment(&'a self, data: &str) {
+        // 2. If position was specified, then let the adjusted insertion location be position. Otherwise, let adjusted insertion location be the appropriate place for inserting a node.
+        let adjusted_insertion_location = self.appropriate_place_for_inserting_node(None);
+
+        self.insert_comment_at(data, adjusted_insertion_location);
     }
 
     // https://html.spec.whatwg.org/#insert-a-comment
-    pub(crate) fn insert_comment_as_last_child_of(&'a self, _data: &str, _node: NodeRef<'a>) {
-        todo!()
+    pub(crate) fn insert_comment_as_last_child_of(&'a self, data: &str, node: NodeRef<'a>) {
+        // 2. If position was specified, then let the adjusted insertion location be position.
+        let adjusted_insertion_location = AdjustedInsertionLocation {
+            parent: node,
+            child: InsertionLocation::AfterLastChildIfAny,
+        };
+
+        self.insert_comment_at(data, adjusted_insertion_location);
+    }
+
+    fn insert_comment_at(
+        &'a self,
+        data: &str,
+        adjusted_insertion_location: AdjustedInsertionLocation<'a>,
+    ) {
+        // 3. Create a Comment node whose data attribute is set to data and whose node document is the same as that of the node in which the adjusted insertion location finds itself.
+        let comment_node = self.allocate_node(Node::new(
+            Some(adjusted_insertion_location.parent.node_document()),
+            NodeType::Comment {
+                data: RefCell::new(data.to_string()),
+            },
+        ));
+
+        // 4. Insert the newly created node at the adjusted insertion location.
+        adjusted_insertion_location.insert(comment_node);
+    }
+}
+
+fn token_that_created(element: NodeRef) -> Token {
+    let element = element
+        .as_element()
+        .expect("only elements are created for tokens");
+
+    Token::StartTag {
+        name: element.local_name(),
+        original_name: None,
+        self_closing: false,
+        self_closing_acknowledged: Cell::new(false),
+        attributes: element
+            .attributes
+            .borrow()
+            .iter()
+            .map(|attr| Attribute {
+                name: attr.name,
+                original_name: None,
+                value: attr.value.clone(),
+                prefix: attr.prefix,
+                namespace: attr.namespace,
+            })
+            .collect(),
     }
 }
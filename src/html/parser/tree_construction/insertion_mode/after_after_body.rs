@@ -1,5 +1,6 @@
 use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
 use crate::html::tokenizer::Token;
+use crate::local_name;
 
 impl<'a> Parser<'a> {
     pub(crate) fn handle_after_after_body(&'a self, token: &Token) {
@@ -16,7 +17,7 @@ impl<'a> Parser<'a> {
                 // Process the token using the rules for the "in body" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InBody, token);
             }
-            Token::StartTag { name, .. } if name == "html" => {
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
                 // Process the token using the rules for the "in body" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InBody, token);
             }
@@ -28,6 +29,7 @@ impl<'a> Parser<'a> {
                 log_parser_error!();
                 // Switch the insertion mode to "in body" and reprocess the token.
                 self.switch_insertion_mode_to(InsertionMode::InBody);
+                self.process_token(token);
             }
         }
     }
@@ -0,0 +1,38 @@
+use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#the-after-after-frameset-insertion-mode
+    pub(crate) fn handle_after_after_frameset(&'a self, token: &Token) {
+        match token {
+            Token::Comment { data } => {
+                // Insert a comment as the last child of the Document object.
+                self.insert_comment_as_last_child_of(data, &self.document);
+            }
+            Token::Doctype { .. } => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::Character { data } if is_parser_whitespace(*data) => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::EndOfFile => {
+                self.stop_parsing();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("noframes") => {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            _ => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+        }
+    }
+}
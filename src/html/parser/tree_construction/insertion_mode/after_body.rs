@@ -1,6 +1,7 @@
 use crate::html::tokenizer::Token;
 
 use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
+use crate::local_name;
 
 impl<'a> Parser<'a> {
     pub(crate) fn handle_after_body(&'a self, token: &Token) {
@@ -17,11 +18,11 @@ impl<'a> Parser<'a> {
                 // Parse error. Ignore the token.
                 log_parser_error!();
             }
-            Token::StartTag { name, .. } if name == "html" => {
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
                 // Process the token using the rules for the "in body" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InBody, token);
             }
-            Token::EndTag { name, .. } if name == "html" => {
+            Token::EndTag { name, .. } if *name == local_name!("html") => {
                 // FIXME: If the parser was created as part of the HTML fragment parsing algorithm, this is a parse error; ignore the token. (fragment case)
 
                 // Otherwise, switch the insertion mode to "after after body".
@@ -35,6 +36,7 @@ impl<'a> Parser<'a> {
                 log_parser_error!();
                 // Switch the insertion mode to "in body" and reprocess the token.
                 self.switch_insertion_mode_to(InsertionMode::InBody);
+                self.process_token(token);
             }
         }
     }
@@ -0,0 +1,42 @@
+use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-afterframeset
+    pub(crate) fn handle_after_frameset(&'a self, token: &Token) {
+        match token {
+            Token::Character { data } if is_parser_whitespace(*data) => {
+                // Insert the character.
+                self.insert_character(*data);
+            }
+            Token::Comment { data } => {
+                // Insert a comment.
+                self.insert_comment(data);
+            }
+            Token::Doctype { .. } => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("html") => {
+                // Switch the insertion mode to "after after frameset".
+                self.switch_insertion_mode_to(InsertionMode::AfterAfterFrameset);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("noframes") => {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::EndOfFile => {
+                self.stop_parsing();
+            }
+            _ => {
+                // Parse error. Ignore the token.
+                log_parser_error!(format!("Unexpected token after frameset: {:?}", token));
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::html::tokenizer::Token;
 
 use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
+use crate::local_name;
 
 impl<'a> Parser<'a> {
     pub(crate) fn handle_after_head(&'a self, token: &Token) {
@@ -29,11 +30,11 @@ impl<'a> Parser<'a> {
                 // Parse error. Ignore the token.
                 log_parser_error!();
             }
-            Token::StartTag { name, .. } if name == "html" => {
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
                 // Process the token using the rules for the "in body" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InBody, token)
             }
-            Token::StartTag { name, .. } if name == "body" => {
+            Token::StartTag { name, .. } if *name == local_name!("body") => {
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
                 // Set the frameset-ok flag to "not ok".
@@ -41,23 +42,23 @@ impl<'a> Parser<'a> {
                 // Switch the insertion mode to "in body".
                 self.switch_insertion_mode_to(InsertionMode::InBody);
             }
-            Token::StartTag { name, .. } if name == "frameset" => {
+            Token::StartTag { name, .. } if *name == local_name!("frameset") => {
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
                 // Switch the insertion mode to "in frameset".
                 self.switch_insertion_mode_to(InsertionMode::InFrameset);
             }
             Token::StartTag { name, .. }
-                if name == "base"
-                    || name == "basefont"
-                    || name == "bgsound"
-                    || name == "link"
-                    || name == "meta"
-                    || name == "noframes"
-                    || name == "script"
-                    || name == "style"
-                    || name == "template"
-                    || name == "title" =>
+                if *name == local_name!("base")
+                    || *name == local_name!("basefont")
+                    || *name == local_name!("bgsound")
+                    || *name == local_name!("link")
+                    || *name == local_name!("meta")
+                    || *name == local_name!("noframes")
+                    || *name == local_name!("script")
+                    || *name == local_name!("style")
+                    || *name == local_name!("template")
+                    || *name == local_name!("title") =>
             {
                 // Parse error.
                 log_parser_error!();
@@ -69,15 +70,19 @@ impl<'a> Parser<'a> {
                 self.open_elements
                     .remove_element(self.head_element.get().unwrap());
             }
-            Token::EndTag { name, .. } if name == "template" => {
+            Token::EndTag { name, .. } if *name == local_name!("template") => {
                 // Process the token using the rules for the "in head" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InHead, token);
             }
-            Token::EndTag { name, .. } if name == "body" || name == "html" || name == "br" => {
+            Token::EndTag { name, .. }
+                if *name == local_name!("body")
+                    || *name == local_name!("html")
+                    || *name == local_name!("br") =>
+            {
                 // Act as described in the "anything else" entry below.
                 anything_else!();
             }
-            Token::StartTag { name, .. } if name == "head" => {
+            Token::StartTag { name, .. } if *name == local_name!("head") => {
                 // Parse error. Ignore the token.
                 log_parser_error!();
             }
@@ -1,6 +1,7 @@
 use crate::html::tokenizer::Token;
 
 use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
+use crate::local_name;
 
 impl<'a> Parser<'a> {
     pub(crate) fn handle_before_head(&'a self, token: &Token) {
@@ -31,11 +32,11 @@ impl<'a> Parser<'a> {
                 // Parse error. Ignore the token.
                 log_parser_error!();
             }
-            Token::StartTag { name, .. } if name == "html" => {
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
                 // Process the token using the rules for the "in body" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InBody, token);
             }
-            Token::StartTag { name, .. } if name == "head" => {
+            Token::StartTag { name, .. } if *name == local_name!("head") => {
                 // Insert an HTML element for the token.
                 let head_element = self.insert_html_element_for_token(token);
                 // Set the head element pointer to the newly created head element.
@@ -44,7 +45,10 @@ impl<'a> Parser<'a> {
                 self.switch_insertion_mode_to(InsertionMode::InHead);
             }
             Token::EndTag { name, .. }
-                if name == "head" || name == "body" || name == "html" || name == "br" =>
+                if *name == local_name!("head")
+                    || *name == local_name!("body")
+                    || *name == local_name!("html")
+                    || *name == local_name!("br") =>
             {
                 anything_else!();
             }
@@ -3,6 +3,7 @@ use crate::html::tokenizer::Token;
 use crate::dom::node::Node;
 use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
 use crate::html::Namespace;
+use crate::local_name;
 
 impl<'a> Parser<'a> {
     pub(crate) fn handle_before_html(&'a self, token: &Token) {
@@ -11,19 +12,21 @@ impl<'a> Parser<'a> {
                 // Create an html element whose node document is the Document object.
                 let html_element = self.create_element(
                     &self.document,
-                    &"html".to_string(),
+                    local_name!("html"),
                     Namespace::Html,
                     None,
                     None,
                     false,
                 );
                 // Append it to the Document object.
-                Node::append(html_element, &self.document, false);
+                Node::append(html_element, &self.document, false)
+                    .expect("the document should not have a document element yet");
                 // Put this element in the stack of open elements.
                 self.open_elements.push(html_element);
 
                 // Switch the insertion mode to "before head", then reprocess the token.
                 self.switch_insertion_mode_to(InsertionMode::BeforeHead);
+                self.process_token(token);
             };
         }
         match token {
@@ -38,12 +41,14 @@ impl<'a> Parser<'a> {
             Token::Character { data } if is_parser_whitespace(*data) => {
                 // Ignore the token.
             }
-            Token::StartTag { name, .. } if name == "html" => {
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
                 // Create an element for the token in the HTML namespace, with the Document as the intended parent.
                 let html_element =
                     self.create_element_for_token(token, Namespace::Html, &self.document);
                 // Append it to the Document object.
-                self.document.append_child(html_element);
+                self.document
+                    .append_child(html_element)
+                    .expect("the document should not have a document element yet");
                 // Put this element in the stack of open elements.
                 self.open_elements.push(html_element);
 
@@ -51,7 +56,10 @@ impl<'a> Parser<'a> {
                 self.switch_insertion_mode_to(InsertionMode::BeforeHead);
             }
             Token::EndTag { name, .. }
-                if name == "head" || name == "body" || name == "html" || name == "br" =>
+                if *name == local_name!("head")
+                    || *name == local_name!("body")
+                    || *name == local_name!("html")
+                    || *name == local_name!("br") =>
             {
                 // Act as described in the "anything else" entry below.
                 anything_else!();
@@ -66,3 +74,49 @@ impl<'a> Parser<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::html::parser::Parser;
+
+    fn parse_document(input: &str) -> String {
+        let arena = typed_arena::Arena::new();
+        let parser = Parser::new(arena, input);
+        let document = parser.parse();
+        let html = document
+            .child_nodes()
+            .iter()
+            .map(|child| child.serialize_html())
+            .collect();
+        html
+    }
+
+    #[test]
+    fn html_element_is_created_implicitly() {
+        assert_eq!(
+            parse_document("<p>hi</p>"),
+            "<html><head></head><body><p>hi</p></body></html>"
+        );
+    }
+
+    #[test]
+    fn explicit_html_element_keeps_its_attributes() {
+        assert_eq!(
+            parse_document("<!-- a -->\n<html lang=en><p>hi"),
+            r#"<!-- a --><html lang="en"><head></head><body><p>hi</p></body></html>"#
+        );
+    }
+
+    #[test]
+    fn end_tags_before_html() {
+        // NOTE: Only head, body, html and br end tags create the html element, others are ignored.
+        assert_eq!(
+            parse_document("</p></br>x"),
+            "<html><head></head><body><br>x</body></html>"
+        );
+        assert_eq!(
+            parse_document("</div></head>x"),
+            "<html><head></head><body>x</body></html>"
+        );
+    }
+}
@@ -1,9 +1,13 @@
-use crate::html::parser::tree_construction::{
-    list_of_active_formatting_elements, stack_of_open_elements,
+use crate::dom::node::{Node, NodeRef};
+use crate::dom::Attr;
+use crate::html::elements;
+use crate::html::parser::tree_construction::list_of_active_formatting_elements;
+use crate::html::parser::{
+    is_parser_whitespace, log_parser_error, GenericParsingAlgorithm, InsertionMode, Parser,
 };
-use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
-use crate::html::tokenizer::Token;
+use crate::html::tokenizer::{self, Attribute, Token};
 use crate::html::Namespace;
+use crate::local_name;
 
 impl<'a> Parser<'a> {
     pub(crate) fn handle_in_body(&'a self, token: &Token) {
@@ -19,16 +23,14 @@ impl<'a> Parser<'a> {
             }
             Token::Character { data } if is_parser_whitespace(*data) => {
                 // Reconstruct the active formatting elements, if any.
-                self.active_formatting_elements
-                    .reconstruct_if_any(&self.open_elements);
+                self.reconstruct_the_active_formatting_elements();
 
                 // Insert the token's character.
                 self.insert_character(*data);
             }
             Token::Character { data } => {
                 // Reconstruct the active formatting elements, if any.
-                self.active_formatting_elements
-                    .reconstruct_if_any(&self.open_elements);
+                self.reconstruct_the_active_formatting_elements();
 
                 // Insert the token's character.
                 self.insert_character(*data);
@@ -44,43 +46,120 @@ impl<'a> Parser<'a> {
                 // Parse error. Ignore the token.
                 log_parser_error!();
             }
-            Token::StartTag { name, .. } if name == "html" => todo!(),
+            Token::StartTag {
+                name, attributes, ..
+            } if *name == local_name!("html") => {
+                // Parse error.
+                log_parser_error!("Unexpected 'html' start tag in body");
+
+                // If there is a template element on the stack of open elements, then ignore the token.
+                if self.open_elements.contains_one_of_tags(&["template"]) {
+                    return;
+                }
+
+                // Otherwise, for each attribute on the token, check to see if the attribute is already present on the top element of the stack of open elements. If it is not, add the attribute and its corresponding value to that element.
+                let top_element = self
+                    .open_elements
+                    .first()
+                    .expect("there should be an html element on the stack of open elements");
+                add_attributes_not_already_present(top_element, attributes);
+            }
             Token::StartTag { name, .. }
-                if name == "base"
-                    || name == "basefont"
-                    || name == "bgsound"
-                    || name == "link"
-                    || name == "meta"
-                    || name == "noframes"
-                    || name == "script"
-                    || name == "style"
-                    || name == "template"
-                    || name == "title" =>
+                if *name == local_name!("base")
+                    || *name == local_name!("basefont")
+                    || *name == local_name!("bgsound")
+                    || *name == local_name!("link")
+                    || *name == local_name!("meta")
+                    || *name == local_name!("noframes")
+                    || *name == local_name!("script")
+                    || *name == local_name!("style")
+                    || *name == local_name!("template")
+                    || *name == local_name!("title") =>
             {
                 // Process the token using the rules for the "in head" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InHead, token);
             }
-            Token::EndTag { name, .. } if name == "template" => {
+            Token::EndTag { name, .. } if *name == local_name!("template") => {
                 // Process the token using the rules for the "in head" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InHead, token);
             }
-            Token::StartTag { name, .. } if name == "body" => todo!(),
-            Token::StartTag { name, .. } if name == "frameset" => todo!(),
+            Token::StartTag {
+                name, attributes, ..
+            } if *name == local_name!("body") => {
+                // Parse error.
+                log_parser_error!("Unexpected 'body' start tag in body");
+
+                // If the stack of open elements has only one node on it, if the second element on the stack of open elements is not a body element, or if there is a template element on the stack of open elements, then ignore the token. (fragment case or there is a template element on the stack)
+                let second_element = self.open_elements.elements.borrow().get(1).copied();
+                let Some(body_element) =
+                    second_element.filter(|element| element.is_element_with_tag("body"))
+                else {
+                    return;
+                };
+                if self.open_elements.contains_one_of_tags(&["template"]) {
+                    return;
+                }
+
+                // Otherwise, set the frameset-ok flag to "not ok"; then, for each attribute on the token, check to see if the attribute is already present on the body element (the second element) on the stack of open elements, and if it is not, add the attribute and its corresponding value to that element.
+                self.frameset_ok.set(false);
+                add_attributes_not_already_present(body_element, attributes);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("frameset") => {
+                // Parse error.
+                log_parser_error!("Unexpected frameset start tag in body");
+
+                // If the stack of open elements has only one node on it, or if the second element on the stack of open elements is not a body element, then ignore the token. (fragment case or there is a template element on the stack)
+                let Some(body_element) = self
+                    .open_elements
+                    .get(1)
+                    .filter(|element| element.is_element_with_tag("body"))
+                else {
+                    return;
+                };
+
+                // If the frameset-ok flag is set to "not ok", ignore the token.
+                if !self.frameset_ok.get() {
+                    return;
+                }
+
+                // Otherwise, run the following steps:
+                // 1. Remove the second element on the stack of open elements from its parent node, if it has one.
+                if body_element.parent_node().is_some() {
+                    Node::remove_node(body_element, false);
+                }
+
+                // 2. Pop all the nodes from the bottom of the stack of open elements, from the current node up to, but not including, the root html element.
+                while self.open_elements.len() > 1 {
+                    self.open_elements.pop();
+                }
+
+                // 3. Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+
+                // 4. Switch the insertion mode to "in frameset".
+                self.switch_insertion_mode_to(InsertionMode::InFrameset);
+            }
             Token::EndOfFile => {
                 // If the stack of template insertion modes is not empty, then process the token using the rules for the "in template" insertion mode.
-                // FIXME: Implement
+                if !self.template_insertion_modes.borrow().is_empty() {
+                    self.process_token_using_the_rules_for(InsertionMode::InTemplate, token);
+                    return;
+                }
 
                 // Otherwise, follow these steps:
 
                 // If there is a node in the stack of open elements that is not either a dd element, a dt element, an li element, an optgroup element, an option element, a p element, an rb element, an rp element, an rt element, an rtc element, a tbody element, a td element, a tfoot element, a th element, a thead element, a tr element, the body element, or the html element, then this is a parse error.
-                if !self.open_elements.contains_one_of_tags(INVALID_EOF_TAGS) {
+                if self
+                    .open_elements
+                    .contains_element_not_one_of_tags(INVALID_EOF_TAGS)
+                {
                     log_parser_error!();
                 };
 
                 // Stop parsing.
                 self.stop_parsing();
             }
-            Token::EndTag { name, .. } if name == "body" => {
+            Token::EndTag { name, .. } if *name == local_name!("body") => {
                 // If the stack of open elements does not have a body element in scope,
                 if !self
                     .open_elements
@@ -92,14 +171,17 @@ impl<'a> Parser<'a> {
                 }
 
                 // Otherwise, if there is a node in the stack of open elements that is not either a dd element, a dt element, an li element, an optgroup element, an option element, a p element, an rb element, an rp element, an rt element, an rtc element, a tbody element, a td element, a tfoot element, a th element, a thead element, a tr element, the body element, or the html element, then this is a parse error.
-                if !self.open_elements.contains_one_of_tags(INVALID_EOF_TAGS) {
+                if self
+                    .open_elements
+                    .contains_element_not_one_of_tags(INVALID_EOF_TAGS)
+                {
                     log_parser_error!();
                 }
 
                 // Switch the insertion mode to "after body".
                 self.switch_insertion_mode_to(InsertionMode::AfterBody);
             }
-            Token::EndTag { name, .. } if name == "html" => {
+            Token::EndTag { name, .. } if *name == local_name!("html") => {
                 // 1. If the stack of open elements does not have a body element in scope, this is a parse error; ignore the token.
                 if !self
                     .open_elements
@@ -109,6 +191,12 @@ impl<'a> Parser<'a> {
                     return;
                 }
                 // 2. Otherwise, if there is a node in the stack of open elements that is not either a dd element, a dt element, an li element, an optgroup element, an option element, a p element, an rb element, an rp element, an rt element, an rtc element, a tbody element, a td element, a tfoot element, a th element, a thead element, a tr element, the body element, or the html element, then this is a parse error.
+                if self
+                    .open_elements
+                    .contains_element_not_one_of_tags(INVALID_EOF_TAGS)
+                {
+                    log_parser_error!();
+                }
 
                 // 3. Switch the insertion mode to "after body".
                 self.switch_insertion_mode_to(InsertionMode::AfterBody);
@@ -116,31 +204,31 @@ impl<'a> Parser<'a> {
                 self.process_token(token);
             }
             Token::StartTag { name, .. }
-                if name == "address"
-                    || name == "article"
-                    || name == "aside"
-                    || name == "blockquote"
-                    || name == "center"
-                    || name == "details"
-                    || name == "dialog"
-                    || name == "dir"
-                    || name == "div"
-                    || name == "dl"
-                    || name == "fieldset"
-                    || name == "figcaption"
-                    || name == "figure"
-                    || name == "footer"
-                    || name == "header"
-                    || name == "hgroup"
-                    || name == "main"
-                    || name == "menu"
-                    || name == "nav"
-                    || name == "ol"
-                    || name == "p"
-                    || name == "search"
-                    || name == "section"
-                    || name == "summary"
-                    || name == "ul" =>
+                if *name == local_name!("address")
+                    || *name == local_name!("article")
+                    || *name == local_name!("aside")
+                    || *name == local_name!("blockquote")
+                    || *name == local_name!("center")
+                    || *name == local_name!("details")
+                    || *name == local_name!("dialog")
+                    || *name == local_name!("dir")
+                    || *name == local_name!("div")
+                    || *name == local_name!("dl")
+                    || *name == local_name!("fieldset")
+                    || *name == local_name!("figcaption")
+                    || *name == local_name!("figure")
+                    || *name == local_name!("footer")
+                    || *name == local_name!("header")
+                    || *name == local_name!("hgroup")
+                    || *name == local_name!("main")
+                    || *name == local_name!("menu")
+                    || *name == local_name!("nav")
+                    || *name == local_name!("ol")
+                    || *name == local_name!("p")
+                    || *name == local_name!("search")
+                    || *name == local_name!("section")
+                    || *name == local_name!("summary")
+                    || *name == local_name!("ul") =>
             {
                 // If the stack of open elements has a p element in button scope, then close a p element.
                 if self
@@ -153,12 +241,12 @@ impl<'a> Parser<'a> {
                 self.insert_html_element_for_token(token);
             }
             Token::StartTag { name, .. }
-                if name == "h1"
-                    || name == "h2"
-                    || name == "h3"
-                    || name == "h4"
-                    || name == "h5"
-                    || name == "h6" =>
+                if *name == local_name!("h1")
+                    || *name == local_name!("h2")
+                    || *name == local_name!("h3")
+                    || *name == local_name!("h4")
+                    || *name == local_name!("h5")
+                    || *name == local_name!("h6") =>
             {
                 // If the stack of open elements has a p element in button scope, then close a p element.
                 if self
@@ -178,9 +266,29 @@ impl<'a> Parser<'a> {
                     self.open_elements.pop();
                 }
                 // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
             }
-            Token::StartTag { name, .. } if name == "pre" || name == "listing" => todo!(),
-            Token::StartTag { name, .. } if name == "form" => {
+            Token::StartTag { name, .. }
+                if *name == local_name!("pre") || *name == local_name!("listing") =>
+            {
+                // If the stack of open elements has a p element in button scope, then close a p element.
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_button_scope("p")
+                {
+                    self.close_a_p_element();
+                }
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+
+                // If the next token is a U+000A LINE FEED (LF) character token, then ignore that token and move on to the next one. (Newlines at the start of pre blocks are ignored as an authoring convenience.)
+                self.ignore_next_line_feed.set(true);
+
+                // Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("form") => {
                 // If the form element pointer is not null, and there is no template element on the stack of open elements,
                 if self.form_element.get().is_some()
                     && self.open_elements.contains_one_of_tags(&["template"])
@@ -200,17 +308,22 @@ impl<'a> Parser<'a> {
                 // Insert an HTML element for the token,
                 self.insert_html_element_for_token(token);
                 // and, if there is no template element on the stack of open elements,
-                if self.open_elements.contains_one_of_tags(&["template"]) {
+                if !self.open_elements.contains_one_of_tags(&["template"]) {
                     // set the form element pointer to point to the element created.
                     self.form_element.set(Some(self.current_node()));
                 }
             }
-            Token::StartTag { name, .. } if name == "li" => {
+            Token::StartTag { name, .. } if *name == local_name!("li") => {
                 // 1. Set the frameset-ok flag to "not ok".
                 self.frameset_ok.set(false);
 
                 // 2. Initialize node to be the current node (the bottommost node of the stack).
-                for node in self.open_elements.elements.clone().borrow().iter().rev() {
+                for index in self.open_elements.iter_indices().rev() {
+                    let node = self
+                        .open_elements
+                        .get(index)
+                        .expect("stack should not change before we stop walking it");
+
                     // 3. Loop: If node is an li element, then run these substeps:
                     if node.is_element_with_tag("li") {
                         // 3.1. Generate implied end tags, except for li elements.
@@ -220,15 +333,14 @@ impl<'a> Parser<'a> {
                             log_parser_error!();
                         }
                         // 3.3. Pop elements from the stack of open elements until an li element has been popped from the stack.
-                        self.open_elements
-                            .pop_elements_until_element_with_tag_name_has_been_popped("li");
+                        self.open_elements.pop_until_tag_name("li");
 
                         // 3.4. Jump to the step labeled done below.
                         break;
                     }
 
                     // 4. If node is in the special category, but is not an address, div, or p element, then jump to the step labeled done below.
-                    if node.is_element_with_one_of_tags(stack_of_open_elements::SPECIAL_TAGS)
+                    if node.is_special_element()
                         && !node.is_element_with_one_of_tags(&["address", "div", "p"])
                     {
                         break;
@@ -248,37 +360,127 @@ impl<'a> Parser<'a> {
                 // 7. Finally, insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
             }
-            Token::StartTag { name, .. } if name == "dd" || name == "dt" => todo!(),
-            Token::StartTag { name, .. } if name == "plaintext" => todo!(),
-            Token::StartTag { name, .. } if name == "button" => todo!(),
+            Token::StartTag { name, .. }
+                if *name == local_name!("dd") || *name == local_name!("dt") =>
+            {
+                // 1. Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+
+                // 2. Initialize node to be the current node (the bottommost node of the stack).
+                for index in self.open_elements.iter_indices().rev() {
+                    let node = self
+                        .open_elements
+                        .get(index)
+                        .expect("stack should not change before we stop walking it");
+
+                    // 3. Loop: If node is a dd element, then run these substeps:
+                    // 4. If node is a dt element, then run these substeps:
+                    if let Some(tag) = ["dd", "dt"]
+                        .into_iter()
+                        .find(|tag| node.is_element_with_tag(tag))
+                    {
+                        // 4.1. Generate implied end tags, except for dd (or dt) elements.
+                        self.generate_implied_end_tags_except_for(Some(tag));
+                        // 4.2. If the current node is not a dd (or dt) element, then this is a parse error.
+                        if !self.open_elements.current_node().is_element_with_tag(tag) {
+                            log_parser_error!();
+                        }
+                        // 4.3. Pop elements from the stack of open elements until a dd (or dt) element has been popped from the stack.
+                        self.open_elements.pop_until_tag_name(tag);
+
+                        // 4.4. Jump to the step labeled done below.
+                        break;
+                    }
+
+                    // 5. If node is in the special category, but is not an address, div, or p element, then jump to the step labeled done below.
+                    if node.is_special_element()
+                        && !node.is_element_with_one_of_tags(&["address", "div", "p"])
+                    {
+                        break;
+                    }
+
+                    // 6. Otherwise, set node to the previous entry in the stack of open elements and return to the step labeled loop.
+                }
+
+                // 7. Done: If the stack of open elements has a p element in button scope, then close a p element.
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_button_scope("p")
+                {
+                    self.close_a_p_element();
+                }
+
+                // 8. Finally, insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("plaintext") => {
+                // If the stack of open elements has a p element in button scope, then close a p element.
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_button_scope("p")
+                {
+                    self.close_a_p_element();
+                }
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+
+                // Switch the tokenizer to the PLAINTEXT state.
+                self.switch_the_tokenizer_to(tokenizer::State::PlainText);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("button") => {
+                // 1. If the stack of open elements has a button element in scope, then run these substeps:
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_scope("button")
+                {
+                    // 1.1. Parse error.
+                    log_parser_error!("Unexpected button start tag while a button is in scope");
+
+                    // 1.2. Generate implied end tags.
+                    self.generate_implied_end_tags_except_for(None);
+
+                    // 1.3. Pop elements from the stack of open elements until a button element has been popped from the stack.
+                    self.open_elements.pop_until_tag_name("button");
+                }
+
+                // 2. Reconstruct the active formatting elements, if any.
+                self.reconstruct_the_active_formatting_elements();
+
+                // 3. Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+
+                // 4. Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+            }
             Token::EndTag { name, .. }
-                if name == "address"
-                    || name == "article"
-                    || name == "aside"
-                    || name == "blockquote"
-                    || name == "button"
-                    || name == "center"
-                    || name == "details"
-                    || name == "dialog"
-                    || name == "dir"
-                    || name == "div"
-                    || name == "dl"
-                    || name == "fieldset"
-                    || name == "figcaption"
-                    || name == "figure"
-                    || name == "footer"
-                    || name == "header"
-                    || name == "hgroup"
-                    || name == "listing"
-                    || name == "main"
-                    || name == "menu"
-                    || name == "nav"
-                    || name == "ol"
-                    || name == "pre"
-                    || name == "search"
-                    || name == "section"
-                    || name == "summary"
-                    || name == "ul" =>
+                if *name == local_name!("address")
+                    || *name == local_name!("article")
+                    || *name == local_name!("aside")
+                    || *name == local_name!("blockquote")
+                    || *name == local_name!("button")
+                    || *name == local_name!("center")
+                    || *name == local_name!("details")
+                    || *name == local_name!("dialog")
+                    || *name == local_name!("dir")
+                    || *name == local_name!("div")
+                    || *name == local_name!("dl")
+                    || *name == local_name!("fieldset")
+                    || *name == local_name!("figcaption")
+                    || *name == local_name!("figure")
+                    || *name == local_name!("footer")
+                    || *name == local_name!("header")
+                    || *name == local_name!("hgroup")
+                    || *name == local_name!("listing")
+                    || *name == local_name!("main")
+                    || *name == local_name!("menu")
+                    || *name == local_name!("nav")
+                    || *name == local_name!("ol")
+                    || *name == local_name!("pre")
+                    || *name == local_name!("search")
+                    || *name == local_name!("section")
+                    || *name == local_name!("summary")
+                    || *name == local_name!("ul") =>
             {
                 // If the stack of open elements does not have an element in scope that is an HTML element with the same tag name as that of the token,
                 if !self.open_elements.has_element_with_tag_name_in_scope(name) {
@@ -305,10 +507,9 @@ impl<'a> Parser<'a> {
                 }
 
                 // 3. Pop elements from the stack of open elements until an HTML element with the same tag name as the token has been popped from the stack.
-                self.open_elements
-                    .pop_elements_until_element_with_tag_name_has_been_popped(name);
+                self.open_elements.pop_until_tag_name(name);
             }
-            Token::EndTag { name, .. } if name == "form" => {
+            Token::EndTag { name, .. } if *name == local_name!("form") => {
                 // If there is no template element on the stack of open elements, then run these substeps:
                 if !self.open_elements.contains_one_of_tags(&["template"]) {
                     // 1. Let node be the element that the form element pointer is set to, or null if it is not set to an element.
@@ -316,7 +517,7 @@ impl<'a> Parser<'a> {
                     // 2. Set the form element pointer to null.
                     self.form_element.set(None);
                     // 3. If node is null or if the stack of open elements does not have node in scope,
-                    if node.is_none() || self.open_elements.has_element_in_scope(node.unwrap()) {
+                    if node.is_none() || !self.open_elements.has_element_in_scope(node.unwrap()) {
                         // then this is a parse error;
                         log_parser_error!();
                         // return and ignore the token.
@@ -325,7 +526,7 @@ impl<'a> Parser<'a> {
                     // 4. Generate implied end tags.
                     self.generate_implied_end_tags_except_for(None);
                     // 5. If the current node is not node,
-                    if self.current_node() == node.unwrap() {
+                    if !std::ptr::eq(self.current_node(), node.unwrap()) {
                         // then this is a parse error.
                         log_parser_error!();
                     }
@@ -333,13 +534,24 @@ impl<'a> Parser<'a> {
                     self.open_elements.remove_element(node.unwrap());
                 } else {
                     // 1. If the stack of open elements does not have a form element in scope, then this is a parse error; return and ignore the token.
+                    if !self
+                        .open_elements
+                        .has_element_with_tag_name_in_scope("form")
+                    {
+                        log_parser_error!();
+                        return;
+                    }
                     // 2. Generate implied end tags.
+                    self.generate_implied_end_tags_except_for(None);
                     // 3. If the current node is not a form element, then this is a parse error.
+                    if !self.current_node().is_element_with_tag("form") {
+                        log_parser_error!();
+                    }
                     // 4. Pop elements from the stack of open elements until a form element has been popped from the stack.
-                    todo!();
+                    self.open_elements.pop_until_tag_name("form");
                 }
             }
-            Token::EndTag { name, .. } if name == "p" => {
+            Token::EndTag { name, .. } if *name == local_name!("p") => {
                 // If the stack of open elements does not have a p element in button scope, then this is a parse error; insert an HTML element for a "p" start tag token with no attributes.
                 if !self
                     .open_elements
@@ -352,9 +564,9 @@ impl<'a> Parser<'a> {
                 // Close a p element.
                 self.close_a_p_element();
             }
-            Token::EndTag { name, .. } if name == "li" => {
+            Token::EndTag { name, .. } if *name == local_name!("li") => {
                 // If the stack of open elements does not have an li element in list item scope, then this is a parse error; ignore the token.
-                if self
+                if !self
                     .open_elements
                     .has_element_with_tag_name_in_list_item_scope("li")
                 {
@@ -372,19 +584,36 @@ impl<'a> Parser<'a> {
                 }
 
                 // 3. Pop elements from the stack of open elements until an li element has been popped from the stack.
-                self.open_elements
-                    .pop_elements_until_element_with_tag_name_has_been_popped("li");
+                self.open_elements.pop_until_tag_name("li");
             }
-            Token::EndTag { name, .. } if name == "dd" || name == "dt" => {
-                todo!()
+            Token::EndTag { name, .. }
+                if *name == local_name!("dd") || *name == local_name!("dt") =>
+            {
+                // If the stack of open elements does not have an element in scope that is an HTML element with the same tag name as that of the token, then this is a parse error; ignore the token.
+                if !self.open_elements.has_element_with_tag_name_in_scope(name) {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise, run these steps:
+                // 1. Generate implied end tags, except for HTML elements with the same tag name as the token.
+                self.generate_implied_end_tags_except_for(Some(name));
+
+                // 2. If the current node is not an HTML element with the same tag name as that of the token, then this is a parse error.
+                if !self.open_elements.current_node().is_element_with_tag(name) {
+                    log_parser_error!();
+                }
+
+                // 3. Pop elements from the stack of open elements until an HTML element with the same tag name as the token has been popped from the stack.
+                self.open_elements.pop_until_tag_name(name);
             }
             Token::EndTag { name, .. }
-                if name == "h1"
-                    || name == "h2"
-                    || name == "h3"
-                    || name == "h4"
-                    || name == "h5"
-                    || name == "h6" =>
+                if *name == local_name!("h1")
+                    || *name == local_name!("h2")
+                    || *name == local_name!("h3")
+                    || *name == local_name!("h4")
+                    || *name == local_name!("h5")
+                    || *name == local_name!("h6") =>
             {
                 let header_tags = &["h1", "h2", "h3", "h4", "h5", "h6"];
 
@@ -410,7 +639,7 @@ impl<'a> Parser<'a> {
                 self.open_elements
                     .pop_elements_until_element_with_one_of_tag_names_has_been_popped(header_tags);
             }
-            Token::StartTag { name, .. } if name == "a" => {
+            Token::StartTag { name, .. } if *name == local_name!("a") => {
                 use list_of_active_formatting_elements::Position;
 
                 // If the list of active formatting elements contains an a element between the end of the list and the last marker on the list (or the start of the list if there is no marker on the list),
@@ -422,77 +651,115 @@ impl<'a> Parser<'a> {
                     // then this is a parse error;
                     log_parser_error!();
 
+                    let a_element = self
+                        .active_formatting_elements
+                        .last_element_with_tag_name_before_marker("a");
+
                     // run the adoption agency algorithm for the token,
                     self.run_adoption_agency_algorithm_for_token(token);
 
                     // then remove that element from the list of active formatting elements and the stack of open elements if the adoption agency algorithm didn't already remove it (it might not have if the element is not in table scope).
-                    todo!()
+                    if let Some(a_element) = a_element {
+                        self.active_formatting_elements.remove(a_element);
+                        self.open_elements.remove_element(a_element);
+                    }
                 }
 
                 // Reconstruct the active formatting elements, if any.
-                self.active_formatting_elements
-                    .reconstruct_if_any(&self.open_elements);
+                self.reconstruct_the_active_formatting_elements();
                 // Insert an HTML element for the token.
                 let element = self.insert_html_element_for_token(token);
                 // Push onto the list of active formatting elements that element.
                 self.active_formatting_elements.push_element(element);
             }
+            // NOTE: These are the formatting elements other than a and nobr, which have their own
+            //       start tag rules.
             Token::StartTag { name, .. }
-                if name == "b"
-                    || name == "big"
-                    || name == "code"
-                    || name == "em"
-                    || name == "font"
-                    || name == "i"
-                    || name == "s"
-                    || name == "small"
-                    || name == "strike"
-                    || name == "strong"
-                    || name == "tt"
-                    || name == "u" =>
+                if elements::is_formatting(name)
+                    && *name != local_name!("a")
+                    && *name != local_name!("nobr") =>
             {
                 // Reconstruct the active formatting elements, if any.
-                self.active_formatting_elements
-                    .reconstruct_if_any(&self.open_elements);
+                self.reconstruct_the_active_formatting_elements();
                 // Insert an HTML element for the token.
                 let element = self.insert_html_element_for_token(token);
                 // Push onto the list of active formatting elements that element.
                 self.active_formatting_elements.push_element(element);
             }
-            Token::StartTag { name, .. } if name == "nobr" => todo!(),
-            Token::EndTag { name, .. }
-                if name == "a"
-                    || name == "b"
-                    || name == "big"
-                    || name == "code"
-                    || name == "em"
-                    || name == "font"
-                    || name == "i"
-                    || name == "nobr"
-                    || name == "s"
-                    || name == "small"
-                    || name == "strike"
-                    || name == "strong"
-                    || name == "tt"
-                    || name == "u" =>
-            {
+            Token::StartTag { name, .. } if *name == local_name!("nobr") => {
+                // Reconstruct the active formatting elements, if any.
+                self.reconstruct_the_active_formatting_elements();
+
+                // If the stack of open elements has a nobr element in scope, then this is a parse error; run the adoption agency algorithm for the token, then once again reconstruct the active formatting elements, if any.
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_scope("nobr")
+                {
+                    log_parser_error!();
+                    self.run_adoption_agency_algorithm_for_token(token);
+                    self.reconstruct_the_active_formatting_elements();
+                }
+
+                // Insert an HTML element for the token.
+                let element = self.insert_html_element_for_token(token);
+                // Push onto the list of active formatting elements that element.
+                self.active_formatting_elements.push_element(element);
+            }
+            Token::EndTag { name, .. } if elements::is_formatting(name) => {
                 // Run the adoption agency algorithm for the token.
                 self.run_adoption_agency_algorithm_for_token(token);
             }
 
             Token::StartTag { name, .. }
-                if name == "applet" || name == "marquee" || name == "object" =>
+                if *name == local_name!("applet")
+                    || *name == local_name!("marquee")
+                    || *name == local_name!("object") =>
             {
-                todo!()
+                // Reconstruct the active formatting elements, if any.
+                self.reconstruct_the_active_formatting_elements();
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+                // Insert a marker at the end of the list of active formatting elements.
+                self.active_formatting_elements.push_marker();
+                // Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
             }
             Token::EndTag { name, .. }
-                if name == "applet" || name == "marquee" || name == "object" =>
+                if *name == local_name!("applet")
+                    || *name == local_name!("marquee")
+                    || *name == local_name!("object") =>
             {
-                todo!()
+                // If the stack of open elements does not have an element in scope that is an HTML element with the same tag name as that of the token, then this is a parse error; ignore the token.
+                if !self.open_elements.has_element_with_tag_name_in_scope(name) {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise, run these steps:
+                // 1. Generate implied end tags.
+                self.generate_implied_end_tags_except_for(None);
+
+                // 2. If the current node is not an HTML element with the same tag name as that of the token, then this is a parse error.
+                if !self.open_elements.current_node().is_element_with_tag(name) {
+                    log_parser_error!();
+                }
+
+                // 3. Pop elements from the stack of open elements until an HTML element with the same tag name as the token has been popped from the stack.
+                self.open_elements.pop_until_tag_name(name);
+
+                // 4. Clear the list of active formatting elements up to the last marker.
+                self.active_formatting_elements
+                    .clear_up_to_the_last_marker();
             }
-            Token::StartTag { name, .. } if name == "table" => {
+            Token::StartTag { name, .. } if *name == local_name!("table") => {
                 // If the Document is not set to quirks mode, and the stack of open elements has a p element in button scope, then close a p element.
-                // FIXME: Implement
+                if !self.document().in_quirks_mode()
+                    && self
+                        .open_elements
+                        .has_element_with_tag_name_in_button_scope("p")
+                {
+                    self.close_a_p_element();
+                }
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
                 // Set the frameset-ok flag to "not ok".
@@ -500,18 +767,28 @@ impl<'a> Parser<'a> {
                 // Switch the insertion mode to "in table".
                 self.switch_insertion_mode_to(InsertionMode::InTable);
             }
-            Token::EndTag { name, .. } if name == "br" => todo!(),
+            Token::EndTag { name, .. } if *name == local_name!("br") => {
+                // Parse error. Drop the attributes from the token, and act as described in the next entry; i.e. act as if this was a "br" start tag token with no attributes, rather than the end tag token that it actually is.
+                log_parser_error!("Unexpected br end tag");
+                // Reconstruct the active formatting elements, if any.
+                self.reconstruct_the_active_formatting_elements();
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_start_tag("br");
+                // Immediately pop the current node off the stack of open elements.
+                self.open_elements.pop();
+                // Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+            }
             Token::StartTag { name, .. }
-                if name == "area"
-                    || name == "br"
-                    || name == "embed"
-                    || name == "img"
-                    || name == "keygen"
-                    || name == "wbr" =>
+                if *name == local_name!("area")
+                    || *name == local_name!("br")
+                    || *name == local_name!("embed")
+                    || *name == local_name!("img")
+                    || *name == local_name!("keygen")
+                    || *name == local_name!("wbr") =>
             {
                 // Reconstruct the active formatting elements, if any.
-                self.active_formatting_elements
-                    .reconstruct_if_any(&self.open_elements);
+                self.reconstruct_the_active_formatting_elements();
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
                 // Immediately pop the current node off the stack of open elements.
@@ -521,10 +798,9 @@ impl<'a> Parser<'a> {
                 // Set the frameset-ok flag to "not ok".
                 self.frameset_ok.set(false);
             }
-            Token::StartTag { name, .. } if name == "input" => {
+            Token::StartTag { name, .. } if *name == local_name!("input") => {
                 // Reconstruct the active formatting elements, if any.
-                self.active_formatting_elements
-                    .reconstruct_if_any(&self.open_elements);
+                self.reconstruct_the_active_formatting_elements();
 
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
@@ -537,9 +813,11 @@ impl<'a> Parser<'a> {
 
                 // If the token does not have an attribute with the name "type", or if it does, but that attribute's value is not an ASCII case-insensitive match for the string "hidden",
                 if let Token::StartTag { attributes, .. } = token {
-                    let type_attr = attributes.iter().find(|attr| attr.name == "type");
+                    let type_attr = attributes
+                        .iter()
+                        .find(|attr| attr.name == local_name!("type"));
                     if type_attr.is_none()
-                        || type_attr.unwrap().value.eq_ignore_ascii_case("hidden")
+                        || !type_attr.unwrap().value.eq_ignore_ascii_case("hidden")
                     {
                         // then: set the frameset-ok flag to "not ok".
                         self.frameset_ok.set(false);
@@ -547,45 +825,240 @@ impl<'a> Parser<'a> {
                 }
             }
             Token::StartTag { name, .. }
-                if name == "param" || name == "source" || name == "track" =>
+                if *name == local_name!("param")
+                    || *name == local_name!("source")
+                    || *name == local_name!("track") =>
+            {
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+                // Immediately pop the current node off the stack of open elements.
+                self.open_elements.pop();
+                // Acknowledge the token's self-closing flag, if it is set.
+                token.acknowledge_self_closing_flag_if_set();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("hr") => {
+                // If the stack of open elements has a p element in button scope, then close a p element.
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_button_scope("p")
+                {
+                    self.close_a_p_element();
+                }
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+
+                // Immediately pop the current node off the stack of open elements.
+                self.open_elements.pop();
+
+                // Acknowledge the token's self-closing flag, if it is set.
+                token.acknowledge_self_closing_flag_if_set();
+
+                // Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("image") => {
+                // Parse error.
+                log_parser_error!("Found 'image' start tag, treating it as 'img'");
+
+                // Change the token's tag name to "img" and reprocess it. (Don't ask.)
+                let mut img_token = token.clone();
+                if let Token::StartTag { name, .. } = &mut img_token {
+                    *name = local_name!("img");
+                }
+                self.process_token(&img_token);
+
+                if let Token::StartTag {
+                    self_closing_acknowledged,
+                    ..
+                } = &img_token
+                {
+                    if self_closing_acknowledged.get() {
+                        token.acknowledge_self_closing_flag_if_set();
+                    }
+                }
+            }
+            Token::StartTag { name, .. } if *name == local_name!("textarea") => {
+                // 1. Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+
+                // 2. If the next token is a U+000A LINE FEED (LF) character token, then ignore that token and move on to the next one. (Newlines at the start of textarea elements are ignored as an authoring convenience.)
+                self.ignore_next_line_feed.set(true);
+
+                // 3. Switch the tokenizer to the RCDATA state.
+                self.switch_the_tokenizer_to(tokenizer::State::RcData);
+
+                // 4. Let the original insertion mode be the current insertion mode.
+                self.original_insertion_mode
+                    .set(Some(self.insertion_mode.get()));
+
+                // 5. Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+
+                // 6. Switch the insertion mode to "text".
+                self.switch_insertion_mode_to(InsertionMode::Text);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("xmp") => {
+                // If the stack of open elements has a p element in button scope, then close a p element.
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_button_scope("p")
+                {
+                    self.close_a_p_element();
+                }
+
+                // Reconstruct the active formatting elements, if any.
+                self.reconstruct_the_active_formatting_elements();
+
+                // Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+
+                // Follow the generic raw text element parsing algorithm.
+                self.follow_generic_parsing_algorithm(GenericParsingAlgorithm::RawText, token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("iframe") => {
+                // Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+
+                // Follow the generic raw text element parsing algorithm.
+                self.follow_generic_parsing_algorithm(GenericParsingAlgorithm::RawText, token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("noembed")
+                    || (*name == local_name!("noscript") && self.scripting) =>
             {
-                todo!()
-            }
-            Token::StartTag { name, .. } if name == "hr" => todo!(),
-            Token::StartTag { name, .. } if name == "image" => todo!(),
-            Token::StartTag { name, .. } if name == "textarea" => todo!(),
-            Token::StartTag { name, .. } if name == "xmp" => todo!(),
-            Token::StartTag { name, .. } if name == "iframe" => todo!(),
-            Token::StartTag { name, .. } if name == "noembed" => todo!(),
-            Token::StartTag { name, .. } if name == "noscript" && self.scripting => {
-                todo!()
-            }
-            Token::StartTag { name, .. } if name == "select" => todo!(),
-            Token::StartTag { name, .. } if name == "optgroup" || name == "option" => todo!(),
-            Token::StartTag { name, .. } if name == "rb" || name == "rtc" => todo!(),
-            Token::StartTag { name, .. } if name == "rp" || name == "rt" => todo!(),
-            Token::StartTag { name, .. } if name == "math" => todo!(),
-            Token::StartTag { name, .. } if name == "svg" => todo!(),
+                // Follow the generic raw text element parsing algorithm.
+                self.follow_generic_parsing_algorithm(GenericParsingAlgorithm::RawText, token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("select") => {
+                // Reconstruct the active formatting elements, if any.
+                self.reconstruct_the_active_formatting_elements();
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+
+                // Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+
+                // If the insertion mode is one of "in table", "in caption", "in table body", "in row", or "in cell", then switch the insertion mode to "in select in table".
+                match self.insertion_mode.get() {
+                    InsertionMode::InTable
+                    | InsertionMode::InCaption
+                    | InsertionMode::InTableBody
+                    | InsertionMode::InRow
+                    | InsertionMode::InCell => {
+                        self.switch_insertion_mode_to(InsertionMode::InSelectInTable)
+                    }
+                    // Otherwise, switch the insertion mode to "in select".
+                    _ => self.switch_insertion_mode_to(InsertionMode::InSelect),
+                }
+            }
             Token::StartTag { name, .. }
-                if name == "caption"
-                    || name == "col"
-                    || name == "colgroup"
-                    || name == "frame"
-                    || name == "head"
-                    || name == "tbody"
-                    || name == "td"
-                    || name == "tfoot"
-                    || name == "th"
-                    || name == "thead"
-                    || name == "tr" =>
+                if *name == local_name!("optgroup") || *name == local_name!("option") =>
+            {
+                // If the current node is an option element, then pop the current node off the stack of open elements.
+                if self.current_node().is_element_with_tag("option") {
+                    self.open_elements.pop();
+                }
+
+                // Reconstruct the active formatting elements, if any.
+                self.reconstruct_the_active_formatting_elements();
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("rb") || *name == local_name!("rtc") =>
+            {
+                // If the stack of open elements has a ruby element in scope, then generate implied end tags.
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_scope("ruby")
+                {
+                    self.generate_implied_end_tags_except_for(None);
+
+                    // If the current node is not now a ruby element, this is a parse error.
+                    if !self.current_node().is_element_with_tag("ruby") {
+                        log_parser_error!(format!("Unexpected '{}' outside of ruby", name));
+                    }
+                }
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("rp") || *name == local_name!("rt") =>
+            {
+                // If the stack of open elements has a ruby element in scope, then generate implied end tags, except for rtc elements.
+                if self
+                    .open_elements
+                    .has_element_with_tag_name_in_scope("ruby")
+                {
+                    self.generate_implied_end_tags_except_for(Some("rtc"));
+
+                    // If the current node is not now a rtc element or a ruby element, this is a parse error.
+                    if !self
+                        .current_node()
+                        .is_element_with_one_of_tags(&["rtc", "ruby"])
+                    {
+                        log_parser_error!(format!("Unexpected '{}' outside of ruby", name));
+                    }
+                }
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("math") || *name == local_name!("svg") =>
+            {
+                // Reconstruct the active formatting elements, if any.
+                self.reconstruct_the_active_formatting_elements();
+
+                let mut adjusted_token = token.clone();
+                let namespace = if *name == local_name!("math") {
+                    // Adjust MathML attributes for the token. (This fixes the case of MathML attributes that are not all lowercase.)
+                    adjusted_token.adjust_mathml_attributes();
+                    Namespace::MathMl
+                } else {
+                    // Adjust SVG attributes for the token. (This fixes the case of SVG attributes that are not all lowercase.)
+                    adjusted_token.adjust_svg_attributes();
+                    Namespace::Svg
+                };
+
+                // Adjust foreign attributes for the token. (This fixes the use of namespaced attributes, in particular XLink.)
+                adjusted_token.adjust_foreign_attributes();
+
+                // Insert a foreign element for the token, in the MathML (or SVG) namespace.
+                self.insert_foreign_element_for_token(&adjusted_token, namespace);
+
+                // If the token has its self-closing flag set, pop the current node off the stack of open elements and acknowledge the token's self-closing flag.
+                if let Token::StartTag {
+                    self_closing: true, ..
+                } = token
+                {
+                    self.open_elements.pop();
+                    token.acknowledge_self_closing_flag_if_set();
+                }
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("frame")
+                    || *name == local_name!("head")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("td")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("th")
+                    || *name == local_name!("thead")
+                    || *name == local_name!("tr") =>
             {
                 // Parser error. Ignore the token.
                 log_parser_error!();
             }
             Token::StartTag { .. } => {
                 // Reconstruct the active formatting elements, if any.
-                self.active_formatting_elements
-                    .reconstruct_if_any(&self.open_elements);
+                self.reconstruct_the_active_formatting_elements();
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
             }
@@ -595,25 +1068,30 @@ impl<'a> Parser<'a> {
 
     fn in_body_any_other_end_tag(&'a self, token: &Token) {
         // 1. Initialize node to be the current node (the bottommost node of the stack).
-        for node in self.open_elements.elements.clone().borrow().iter().rev() {
+        for index in self.open_elements.iter_indices().rev() {
+            let node = self
+                .open_elements
+                .get(index)
+                .expect("stack should not change before we stop walking it");
+
             // 2. Loop: If node is an HTML element with the same tag name as the token, then:
             let token_tag_name = token.tag_name().expect("token should be EndTag");
             if node.is_element_with_tag(&token_tag_name) {
                 // 2.1. Generate implied end tags, except for HTML elements with the same tag name as the token.
                 self.generate_implied_end_tags_except_for(Some(&token_tag_name));
                 // 2.2. If node is not the current node, then this is a parse error.
-                if *node != self.current_node() {
+                if !std::ptr::eq(node, self.current_node()) {
                     log_parser_error!();
                 }
                 // 2.3. Pop all the nodes from the current node up to node, including node,
                 self.open_elements
-                    .pop_elements_until_element_has_been_popped(self.current_node());
+                    .pop_elements_until_element_has_been_popped(node);
 
                 // then stop these steps.
                 break;
             } else {
                 // 3. Otherwise, if node is in the special category,
-                if node.is_element_with_one_of_tags(stack_of_open_elements::SPECIAL_TAGS) {
+                if node.is_special_element() {
                     // then this is a parse error; ignore the token,
                     log_parser_error!();
                     // and return.
@@ -637,8 +1115,7 @@ impl<'a> Parser<'a> {
         }
 
         // Pop elements from the stack of open elements until a p element has been popped from the stack.
-        self.open_elements
-            .pop_elements_until_element_with_tag_name_has_been_popped("p");
+        self.open_elements.pop_until_tag_name("p");
     }
 
     // https://html.spec.whatwg.org/multipage/parsing.html#adoption-agency-algorithm
@@ -704,7 +1181,7 @@ impl<'a> Parser<'a> {
             }
 
             // 4.6 If formatting element is not the current node,
-            if formatting_element != self.current_node() {
+            if !std::ptr::eq(formatting_element, self.current_node()) {
                 // this is a parse error. (But do not return.)
                 log_parser_error!();
             }
@@ -716,7 +1193,7 @@ impl<'a> Parser<'a> {
 
             // 4.8 If there is no furthest block, then the UA must first pop all the nodes from the bottom of the stack of open elements, from the current node up to and including formatting element,
             if furthest_block.is_none() {
-                while formatting_element != self.current_node() {
+                while !std::ptr::eq(formatting_element, self.current_node()) {
                     self.open_elements.pop();
                 }
                 self.open_elements.pop();
@@ -734,16 +1211,17 @@ impl<'a> Parser<'a> {
                 .element_immediately_above(formatting_element);
 
             // 4.10 Let a bookmark note the position of formatting element in the list of active formatting elements relative to the elements on either side of it in the list.
-            let mut bookmark = self
-                .active_formatting_elements
-                .first_index_of(formatting_element)
-                .unwrap();
+            // NOTE: The bookmark is kept as the entry it follows, rather than as an index, because
+            //       removing entries below it later on in this algorithm would shift an index.
+            //       `None` means the bookmark is still at the position of formatting element.
+            let mut bookmark: Option<NodeRef> = None;
 
             // 4.11 Let node and last node be furthest block.
-            let mut node = furthest_block;
+            let mut node;
             let mut last_node = furthest_block;
 
-            let node_above_node = self.open_elements.element_immediately_above(node);
+            // NOTE: We look up the element above node before node can be removed from the stack.
+            let mut node_above_node = self.open_elements.element_immediately_above(furthest_block);
 
             // 4.12 Let inner loop counter be 0.
             let mut inner_loop_count = 0;
@@ -754,12 +1232,11 @@ impl<'a> Parser<'a> {
                 inner_loop_count += 1;
 
                 // 4.13.2 Let node be the element immediately above node in the stack of open elements, or if node is no longer in the stack of open elements (e.g. because it got removed by this algorithm), the element that was immediately above node in the stack of open elements before node was removed.
-                if let Some(node_above_node) = node_above_node {
-                    node = node_above_node;
-                }
+                node = node_above_node.unwrap();
+                node_above_node = self.open_elements.element_immediately_above(node);
 
                 // 4.13.3 If node is formatting element, then break.
-                if node == formatting_element {
+                if std::ptr::eq(node, formatting_element) {
                     break;
                 }
 
@@ -775,8 +1252,11 @@ impl<'a> Parser<'a> {
                 }
 
                 // 4.13.6 Create an element for the token for which the element node was created, in the HTML namespace, with common ancestor as the intended parent;
-                let new_element =
-                    self.create_element_for_token(token, Namespace::Html, common_ancestor.unwrap());
+                let new_element = self.create_element_for_the_token_that_created(
+                    node,
+                    Namespace::Html,
+                    common_ancestor.unwrap(),
+                );
 
                 // replace the entry for node in the list of active
                 //       formatting elements with an entry for the new element,
@@ -790,16 +1270,13 @@ impl<'a> Parser<'a> {
                 node = new_element;
 
                 // 4.13.7 If last node is furthest block, then move the aforementioned bookmark to be immediately after the new node in the list of active formatting elements.
-                if last_node == furthest_block {
-                    bookmark = self
-                        .active_formatting_elements
-                        .first_index_of(node)
-                        .unwrap()
-                        + 1
+                if std::ptr::eq(last_node, furthest_block) {
+                    bookmark = Some(node);
                 }
 
                 // 4.13.8 Append last node to node.
-                node.append_child(last_node);
+                node.append_child(last_node)
+                    .expect("last node should be insertable into node");
 
                 // 4.13.9 Set last node to node.
                 last_node = node;
@@ -811,21 +1288,41 @@ impl<'a> Parser<'a> {
             adjusted_insertion_location.insert(last_node);
 
             // 15. Create an element for the token for which formatting element was created, in the HTML namespace, with furthest block as the intended parent.
-            let new_element = self.create_element_for_token(token, Namespace::Html, furthest_block);
+            let new_element = self.create_element_for_the_token_that_created(
+                formatting_element,
+                Namespace::Html,
+                furthest_block,
+            );
 
             // 16. Take all of the child nodes of furthest block and append them to the element created in the last step.
-            for child in furthest_block.child_nodes().iter() {
-                new_element.append_child(child);
+            let children = furthest_block.child_nodes().clone();
+            for child in children {
+                new_element
+                    .append_child(child)
+                    .expect("the new element should accept the children of furthest block");
             }
 
             // 17. Append that new element to furthest block.
-            furthest_block.append_child(new_element);
+            furthest_block
+                .append_child(new_element)
+                .expect("furthest block should accept the new element");
 
             // 18. Remove formatting element from the list of active formatting elements,
-            self.active_formatting_elements.remove(formatting_element);
             // and insert the new element into the list of active formatting elements at the position of the aforementioned bookmark.
-            self.active_formatting_elements
-                .insert(bookmark, new_element);
+            match bookmark {
+                Some(entry_before_bookmark) => {
+                    self.active_formatting_elements.remove(formatting_element);
+                    let index = self
+                        .active_formatting_elements
+                        .first_index_of(entry_before_bookmark)
+                        .expect("the bookmarked entry should still be in the list");
+                    self.active_formatting_elements
+                        .insert(index + 1, new_element);
+                }
+                None => self
+                    .active_formatting_elements
+                    .replace(formatting_element, new_element),
+            }
 
             // 19. Remove formatting element from the stack of open elements,
             self.open_elements.remove_element(formatting_element);
@@ -836,3 +1333,154 @@ impl<'a> Parser<'a> {
         }
     }
 }
+
+fn add_attributes_not_already_present(element: NodeRef, attributes: &[Attribute]) {
+    let element = element
+        .as_element()
+        .expect("attributes can only be added to elements");
+    let mut element_attributes = element.attributes.borrow_mut();
+    for attribute in attributes {
+        if !element_attributes
+            .iter()
+            .any(|attr| attr.name == attribute.name)
+        {
+            element_attributes.push(Attr::new(attribute.name, &attribute.value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::parser::tests::parse_body;
+
+    #[test]
+    fn any_other_end_tag_pops_up_to_the_matched_element() {
+        assert_eq!(
+            parse_body("<span><abbr>x</span>y"),
+            "<span><abbr>x</abbr></span>y"
+        );
+    }
+
+    #[test]
+    fn ruby() {
+        assert_eq!(
+            parse_body("<ruby>漢<rt>kan</rt>字<rt>ji</rt></ruby>"),
+            "<ruby>漢<rt>kan</rt>字<rt>ji</rt></ruby>"
+        );
+        assert_eq!(
+            parse_body("<ruby>a<rt>b<rp>(<rt>c</ruby>"),
+            "<ruby>a<rt>b</rt><rp>(</rp><rt>c</rt></ruby>"
+        );
+        assert_eq!(
+            parse_body("<ruby>a<rtc>b<rt>c<rb>d</ruby>"),
+            "<ruby>a<rtc>b<rt>c</rt></rtc><rb>d</rb></ruby>"
+        );
+    }
+
+    #[test]
+    fn description_lists() {
+        assert_eq!(
+            parse_body("<dl><dt>a<dd>b<dt>c</dl>"),
+            "<dl><dt>a</dt><dd>b</dd><dt>c</dt></dl>"
+        );
+        assert_eq!(
+            parse_body("<dl><dd>a<div>b</dd>c</dl>"),
+            "<dl><dd>a<div>b</div></dd>c</dl>"
+        );
+    }
+
+    #[test]
+    fn list_item_end_tag() {
+        assert_eq!(
+            parse_body("<ul><li>a</li>b<li>c</ul>"),
+            "<ul><li>a</li>b<li>c</li></ul>"
+        );
+    }
+
+    #[test]
+    fn form_end_tag() {
+        assert_eq!(parse_body("<form>a</form>b"), "<form>a</form>b");
+        assert_eq!(
+            parse_body("<form><div></form>x</div>y"),
+            "<form><div>x</div></form>y"
+        );
+    }
+
+    #[test]
+    fn plaintext() {
+        assert_eq!(
+            parse_body("<p>a<plaintext></plaintext><b>"),
+            "<p>a</p><plaintext></plaintext><b></plaintext>"
+        );
+    }
+
+    #[test]
+    fn nested_formatting_start_tags() {
+        assert_eq!(parse_body("<a>1<a>2</a>"), "<a>1</a><a>2</a>");
+        assert_eq!(parse_body("<nobr>a<nobr>b"), "<nobr>a</nobr><nobr>b</nobr>");
+    }
+
+    #[test]
+    fn identical_formatting_elements_are_adopted_by_identity() {
+        assert_eq!(
+            parse_body("<b><b><p>x</b>y"),
+            "<b><b></b><p><b>x</b>y</p></b>"
+        );
+        assert_eq!(
+            parse_body("<b><b><p></b>x"),
+            "<b><b></b><p><b></b>x</p></b>"
+        );
+    }
+
+    #[test]
+    fn object_is_a_formatting_boundary() {
+        assert_eq!(
+            parse_body("<object><b>a</object>b"),
+            "<object><b>a</b></object>b"
+        );
+    }
+
+    #[test]
+    fn br_end_tag() {
+        assert_eq!(parse_body("a</br>b"), "a<br>b");
+    }
+
+    #[test]
+    fn media_void_elements() {
+        assert_eq!(
+            parse_body("<video><source src=a><track>x</video>"),
+            r#"<video><source src="a"><track>x</video>"#
+        );
+    }
+
+    #[test]
+    fn button_start_tag_closes_an_open_button() {
+        assert_eq!(
+            parse_body("<button>a<button>b"),
+            "<button>a</button><button>b</button>"
+        );
+        // NOTE: The b element is closed with the first button, and reconstructed around the
+        //       second one, so the second button is not nested in the first one.
+        assert_eq!(
+            parse_body("<button><b>a<button>b</button>c"),
+            "<button><b>a</b></button><b><button>b</button>c</b>"
+        );
+        // Implied end tags are generated before the old button is popped.
+        assert_eq!(
+            parse_body("<button><p>a<button>b"),
+            "<button><p>a</p></button><button>b</button>"
+        );
+    }
+
+    #[test]
+    fn self_closing_flag_on_non_void_elements_is_ignored() {
+        assert_eq!(parse_body("<div/>text</div>"), "<div>text</div>");
+        assert_eq!(parse_body("<span/>a<b/>b"), "<span>a<b>b</b></span>");
+        // Void elements and foreign elements acknowledge the flag, so they are closed.
+        assert_eq!(parse_body("<br/>a<img/>b"), "<br>a<img>b");
+        assert_eq!(
+            parse_body("<svg><g/>a</svg><math><mi/>b</math>"),
+            "<svg><g></g>a</svg><math><mi></mi>b</math>"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use crate::html::parser::{log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-incaption
+    pub(crate) fn handle_in_caption(&'a self, token: &Token) {
+        match token {
+            Token::EndTag { name, .. } if *name == local_name!("caption") => {
+                self.close_the_caption();
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("td")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("th")
+                    || *name == local_name!("thead")
+                    || *name == local_name!("tr") =>
+            {
+                if self.close_the_caption() {
+                    // Reprocess the token.
+                    self.process_token(token);
+                }
+            }
+            Token::EndTag { name, .. } if *name == local_name!("table") => {
+                if self.close_the_caption() {
+                    // Reprocess the token.
+                    self.process_token(token);
+                }
+            }
+            Token::EndTag { name, .. }
+                if *name == local_name!("body")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("html")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("td")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("th")
+                    || *name == local_name!("thead")
+                    || *name == local_name!("tr") =>
+            {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            _ => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+        }
+    }
+
+    /// Closes the caption element, returning `false` if there was none to close and
+    /// the token should be ignored.
+    fn close_the_caption(&'a self) -> bool {
+        // If the stack of open elements does not have a caption element in table scope, this is a parse error; ignore the token. (fragment case)
+        if !self
+            .open_elements
+            .has_element_with_tag_name_in_table_scope("caption")
+        {
+            log_parser_error!();
+            return false;
+        }
+
+        // Otherwise:
+        // 1. Generate implied end tags.
+        self.generate_implied_end_tags_except_for(None);
+
+        // 2. Now, if the current node is not a caption element, then this is a parse error.
+        if !self.current_node().is_element_with_tag("caption") {
+            log_parser_error!();
+        }
+
+        // 3. Pop elements from this stack until a caption element has been popped from the stack.
+        self.open_elements.pop_until_tag_name("caption");
+
+        // 4. Clear the list of active formatting elements up to the last marker.
+        self.active_formatting_elements
+            .clear_up_to_the_last_marker();
+
+        // 5. Switch the insertion mode to "in table".
+        self.switch_insertion_mode_to(InsertionMode::InTable);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::parser::tests::parse_body;
+
+    #[test]
+    fn caption() {
+        assert_eq!(
+            parse_body("<table><caption>x</caption></table>"),
+            "<table><caption>x</caption></table>"
+        );
+    }
+
+    #[test]
+    fn caption_closed_by_table_content() {
+        assert_eq!(
+            parse_body("<table><caption><b>x<tr><td>y</table>"),
+            "<table><caption><b>x</b></caption><tbody><tr><td>y</td></tr></tbody></table>"
+        );
+    }
+
+    #[test]
+    fn caption_closed_by_table_end_tag() {
+        assert_eq!(
+            parse_body("<table><caption>x</table>y"),
+            "<table><caption>x</caption></table>y"
+        );
+    }
+}
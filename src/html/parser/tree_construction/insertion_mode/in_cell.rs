@@ -0,0 +1,126 @@
+use crate::html::parser::{log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-intd
+    pub(crate) fn handle_in_cell(&'a self, token: &Token) {
+        match token {
+            Token::EndTag { name, .. }
+                if *name == local_name!("td") || *name == local_name!("th") =>
+            {
+                // If the stack of open elements does not have an element in table scope that is an HTML element with the same tag name as that of the token, then this is a parse error; ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope(name)
+                {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise:
+                // 1. Generate implied end tags.
+                self.generate_implied_end_tags_except_for(None);
+
+                // 2. Now, if the current node is not an HTML element with the same tag name as the token, then this is a parse error.
+                if !self.current_node().is_element_with_tag(name) {
+                    log_parser_error!();
+                }
+
+                // 3. Pop elements from the stack of open elements stack until an HTML element with the same tag name as the token has been popped from the stack.
+                self.open_elements.pop_until_tag_name(name);
+
+                // 4. Clear the list of active formatting elements up to the last marker.
+                self.active_formatting_elements
+                    .clear_up_to_the_last_marker();
+
+                // 5. Switch the insertion mode to "in row".
+                self.switch_insertion_mode_to(InsertionMode::InRow);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("td")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("th")
+                    || *name == local_name!("thead")
+                    || *name == local_name!("tr") =>
+            {
+                // Assert: The stack of open elements has a td or th element in table scope.
+                // NOTE: This only fails in the fragment case, where we ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_one_of_tag_names_in_table_scope(&["td", "th"])
+                {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Close the cell (see below) and reprocess the token.
+                self.close_the_cell();
+                self.process_token(token);
+            }
+            Token::EndTag { name, .. }
+                if *name == local_name!("body")
+                    || *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("html") =>
+            {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::EndTag { name, .. }
+                if *name == local_name!("table")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("thead")
+                    || *name == local_name!("tr") =>
+            {
+                // If the stack of open elements does not have an element in table scope that is an HTML element with the same tag name as that of the token, then this is a parse error; ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope(name)
+                {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise, close the cell (see below) and reprocess the token.
+                self.close_the_cell();
+                self.process_token(token);
+            }
+            _ => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#close-the-cell
+    pub(crate) fn close_the_cell(&'a self) {
+        // 1. Generate implied end tags.
+        self.generate_implied_end_tags_except_for(None);
+
+        // 2. If the current node is not now a td element or a th element, then this is a parse error.
+        if !self
+            .current_node()
+            .is_element_with_one_of_tags(&["td", "th"])
+        {
+            log_parser_error!();
+        }
+
+        // 3. Pop elements from the stack of open elements stack until a td element or a th element has been popped from the stack.
+        self.open_elements
+            .pop_elements_until_element_with_one_of_tag_names_has_been_popped(&["td", "th"]);
+
+        // 4. Clear the list of active formatting elements up to the last marker.
+        self.active_formatting_elements
+            .clear_up_to_the_last_marker();
+
+        // 5. Switch the insertion mode to "in row".
+        self.switch_insertion_mode_to(InsertionMode::InRow);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-incolgroup
+    pub(crate) fn handle_in_column_group(&'a self, token: &Token) {
+        match token {
+            Token::Character { data } if is_parser_whitespace(*data) => {
+                // Insert the character.
+                self.insert_character(*data);
+            }
+            Token::Comment { data } => {
+                // Insert a comment.
+                self.insert_comment(data);
+            }
+            Token::Doctype { .. } => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("col") => {
+                // Insert an HTML element for the token. Immediately pop the current node off the stack of open elements.
+                self.insert_html_element_for_token(token);
+                self.open_elements.pop();
+                // Acknowledge the token's self-closing flag, if it is set.
+                token.acknowledge_self_closing_flag_if_set();
+            }
+            Token::EndTag { name, .. } if *name == local_name!("colgroup") => {
+                // If the current node is not a colgroup element, then this is a parse error; ignore the token.
+                if !self.current_node().is_element_with_tag("colgroup") {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise, pop the current node from the stack of open elements. Switch the insertion mode to "in table".
+                self.open_elements.pop();
+                self.switch_insertion_mode_to(InsertionMode::InTable);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("col") => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::StartTag { name, .. } | Token::EndTag { name, .. }
+                if *name == local_name!("template") =>
+            {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::EndOfFile => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            _ => {
+                // If the current node is not a colgroup element, then this is a parse error; ignore the token.
+                if !self.current_node().is_element_with_tag("colgroup") {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise, pop the current node from the stack of open elements.
+                self.open_elements.pop();
+                // Switch the insertion mode to "in table".
+                self.switch_insertion_mode_to(InsertionMode::InTable);
+                // Reprocess the token.
+                self.process_token(token);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::parser::tests::parse_body;
+
+    #[test]
+    fn column_group() {
+        assert_eq!(
+            parse_body("<table><colgroup><col></table>"),
+            "<table><colgroup><col></colgroup></table>"
+        );
+    }
+
+    #[test]
+    fn col_implies_column_group() {
+        assert_eq!(
+            parse_body("<table><col><col/><tr><td>x</table>"),
+            "<table><colgroup><col><col></colgroup><tbody><tr><td>x</td></tr></tbody></table>"
+        );
+    }
+
+    #[test]
+    fn column_group_closed_by_end_tag() {
+        assert_eq!(
+            parse_body("<table><colgroup> <col></col></colgroup><caption>x</table>"),
+            "<table><colgroup> <col></colgroup><caption>x</caption></table>"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-inframeset
+    pub(crate) fn handle_in_frameset(&'a self, token: &Token) {
+        match token {
+            Token::Character { data } if is_parser_whitespace(*data) => {
+                // Insert the character.
+                self.insert_character(*data);
+            }
+            Token::Comment { data } => {
+                // Insert a comment.
+                self.insert_comment(data);
+            }
+            Token::Doctype { .. } => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("frameset") => {
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("frameset") => {
+                // If the current node is the root html element, then this is a parse error; ignore the token. (fragment case)
+                if self.open_elements.len() == 1 {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise, pop the current node from the stack of open elements.
+                self.open_elements.pop();
+
+                // If the parser was not created as part of the HTML fragment parsing algorithm (fragment case), and the current node is no longer a frameset element, then switch the insertion mode to "after frameset".
+                if self.context_element.get().is_none()
+                    && !self.current_node().is_element_with_tag("frameset")
+                {
+                    self.switch_insertion_mode_to(InsertionMode::AfterFrameset);
+                }
+            }
+            Token::StartTag { name, .. } if *name == local_name!("frame") => {
+                // Insert an HTML element for the token. Immediately pop the current node off the stack of open elements.
+                self.insert_html_element_for_token(token);
+                self.open_elements.pop();
+                // Acknowledge the token's self-closing flag, if it is set.
+                token.acknowledge_self_closing_flag_if_set();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("noframes") => {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::EndOfFile => {
+                // If the current node is not the root html element, then this is a parse error.
+                // NOTE: The current node can only be the root html element in the fragment case.
+                if self.open_elements.len() != 1 {
+                    log_parser_error!();
+                }
+
+                // Stop parsing.
+                self.stop_parsing();
+            }
+            _ => {
+                // Parse error. Ignore the token.
+                log_parser_error!(format!("Unexpected token in frameset: {:?}", token));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::parser::Parser;
+
+    fn parse_document(input: &str) -> String {
+        let arena = typed_arena::Arena::new();
+        let parser = Parser::new(arena, input);
+        let document = parser.parse();
+        let html = document.child_nodes()[0].serialize_html();
+        html
+    }
+
+    #[test]
+    fn frameset() {
+        assert_eq!(
+            parse_document("<frameset><frame src=a><frameset><frame/></frameset>x</frameset>y<noframes>z</noframes>"),
+            r#"<html><head></head><frameset><frame src="a"><frameset><frame></frameset></frameset><noframes>z</noframes></html>"#
+        );
+    }
+
+    #[test]
+    fn frameset_replaces_a_body_without_content() {
+        assert_eq!(
+            parse_document("<div>\n</div><frameset></frameset>"),
+            "<html><head></head><frameset></frameset></html>"
+        );
+    }
+
+    #[test]
+    fn frameset_after_content_is_ignored() {
+        assert_eq!(
+            parse_document("<body>x<frameset></frameset>"),
+            "<html><head></head><body>x</body></html>"
+        );
+    }
+}
@@ -1,14 +1,16 @@
 use crate::html::parser::{
     is_parser_whitespace, log_parser_error, GenericParsingAlgorithm, InsertionMode, Parser,
 };
-use crate::html::tokenizer::Token;
+use crate::html::tokenizer::{self, Token};
+use crate::html::Namespace;
+use crate::local_name;
 
 impl<'a> Parser<'a> {
     pub(crate) fn handle_in_head(&'a self, token: &Token) {
         macro_rules! anything_else {
             () => {
                 // Pop the current node (which will be the head element) off the stack of open elements.
-                self.open_elements.pop();
+                self.open_elements.pop_until_tag_name("head");
 
                 // Switch the insertion mode to "after head".
                 self.switch_insertion_mode_to(InsertionMode::AfterHead);
@@ -31,12 +33,15 @@ impl<'a> Parser<'a> {
                 // Parse error. Ignore the token.
                 log_parser_error!("Unexpected Doctype in head");
             }
-            Token::StartTag { name, .. } if name == "html" => {
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
                 // Process the token using the rules for the "in body" insertion mode.
                 self.process_token_using_the_rules_for(InsertionMode::InBody, token);
             }
             Token::StartTag { name, .. }
-                if name == "base" || name == "basefont" || name == "bgsound" || name == "link" =>
+                if *name == local_name!("base")
+                    || *name == local_name!("basefont")
+                    || *name == local_name!("bgsound")
+                    || *name == local_name!("link") =>
             {
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
@@ -47,7 +52,7 @@ impl<'a> Parser<'a> {
                 // Acknowledge the token's self-closing flag, if it is set.
                 token.acknowledge_self_closing_flag_if_set();
             }
-            Token::StartTag { name, .. } if name == "meta" => {
+            Token::StartTag { name, .. } if *name == local_name!("meta") => {
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
 
@@ -59,38 +64,114 @@ impl<'a> Parser<'a> {
 
                 // FIXME: If the active speculative HTML parser is null, then:
             }
-            Token::StartTag { name, .. } if name == "title" => {
+            Token::StartTag { name, .. } if *name == local_name!("title") => {
                 self.follow_generic_parsing_algorithm(GenericParsingAlgorithm::RcData, token);
             }
             Token::StartTag { name, .. }
-                if (name == "noscript" && self.scripting)
-                    || (name == "noframes" || name == "style") =>
+                if (*name == local_name!("noscript") && self.scripting)
+                    || (*name == local_name!("noframes") || *name == local_name!("style")) =>
             {
                 // Follow the generic raw text element parsing algorithm.
                 self.follow_generic_parsing_algorithm(GenericParsingAlgorithm::RawText, token);
             }
-            Token::StartTag { name, .. } if name == "noscript" && !self.scripting => {
+            Token::StartTag { name, .. } if *name == local_name!("noscript") && !self.scripting => {
                 // Insert an HTML element for the token.
                 self.insert_html_element_for_token(token);
 
                 // Switch the insertion mode to "in head noscript".
                 self.switch_insertion_mode_to(InsertionMode::InHeadNoscript);
             }
-            Token::StartTag { name, .. } if name == "script" => todo!(),
-            Token::EndTag { name, .. } if name == "head" => {
+            Token::StartTag { name, .. } if *name == local_name!("script") => {
+                // 1. Let the adjusted insertion location be the appropriate place for inserting a node.
+                let adjusted_insertion_location = self.appropriate_place_for_inserting_node(None);
+
+                // 2. Create an element for the token in the HTML namespace, with the intended parent being the element in which the adjusted insertion location finds itself.
+                let element = self.create_element_for_token(
+                    token,
+                    Namespace::Html,
+                    adjusted_insertion_location.parent,
+                );
+
+                // FIXME: 3. Set the element's parser document to the Document, and set the element's force async to false.
+                // FIXME: 4. If the parser was created as part of the HTML fragment parsing algorithm, then set the script element's already started to true. (fragment case)
+                // FIXME: 5. If the parser was invoked via the document.write() or document.writeln() methods, then optionally set the script element's already started to true.
+
+                // 6. Insert the newly created element at the adjusted insertion location.
+                adjusted_insertion_location.insert(element);
+
+                // 7. Push the element onto the stack of open elements so that it is the new current node.
+                self.open_elements.push(element);
+
+                // 8. Switch the tokenizer to the script data state.
+                self.switch_the_tokenizer_to(tokenizer::State::ScriptData);
+
+                // 9. Let the original insertion mode be the current insertion mode.
+                self.original_insertion_mode
+                    .set(Some(self.insertion_mode.get()));
+
+                // 10. Switch the insertion mode to "text".
+                self.switch_insertion_mode_to(InsertionMode::Text);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("head") => {
                 // Pop the current node (which will be the head element) off the stack of open elements.
-                self.open_elements.pop();
+                self.open_elements.pop_until_tag_name("head");
 
                 // Switch the insertion mode to "after head".
                 self.switch_insertion_mode_to(InsertionMode::AfterHead);
             }
-            Token::EndTag { name, .. } if name == "body" || name == "html" || name == "br" => {
+            Token::EndTag { name, .. }
+                if *name == local_name!("body")
+                    || *name == local_name!("html")
+                    || *name == local_name!("br") =>
+            {
                 // Act as described in the "anything else" entry below.
                 anything_else!();
             }
-            Token::StartTag { name, .. } if name == "template" => todo!(),
-            Token::EndTag { name, .. } if name == "template" => todo!(),
-            Token::StartTag { name, .. } if name == "head" => {
+            Token::StartTag { name, .. } if *name == local_name!("template") => {
+                // FIXME: Handle declarative shadow roots.
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+                // Insert a marker at the end of the list of active formatting elements.
+                self.active_formatting_elements.push_marker();
+                // Set the frameset-ok flag to "not ok".
+                self.frameset_ok.set(false);
+                // Switch the insertion mode to "in template".
+                self.switch_insertion_mode_to(InsertionMode::InTemplate);
+                // Push "in template" onto the stack of template insertion modes so that it is the new current template insertion mode.
+                self.template_insertion_modes
+                    .borrow_mut()
+                    .push(InsertionMode::InTemplate);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("template") => {
+                // If there is no template element on the stack of open elements, then this is a parse error; ignore the token.
+                if !self.open_elements.contains_one_of_tags(&["template"]) {
+                    log_parser_error!("Unexpected template end tag without an open template");
+                    return;
+                }
+
+                // Otherwise, run these steps:
+                // 1. Generate all implied end tags thoroughly.
+                self.generate_all_implied_end_tags_thoroughly();
+
+                // 2. If the current node is not a template element, then this is a parse error.
+                if !self.current_node().is_element_with_tag("template") {
+                    log_parser_error!();
+                }
+
+                // 3. Pop elements from the stack of open elements until a template element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("template");
+
+                // 4. Clear the list of active formatting elements up to the last marker.
+                self.active_formatting_elements
+                    .clear_up_to_the_last_marker();
+
+                // 5. Pop the current template insertion mode off the stack of template insertion modes.
+                self.template_insertion_modes.borrow_mut().pop();
+
+                // 6. Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("head") => {
                 // Parse error. Ignore the token.
                 log_parser_error!("Unexpected head start tag in head");
             }
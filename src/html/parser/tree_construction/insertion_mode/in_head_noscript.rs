@@ -0,0 +1,85 @@
+use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-inheadnoscript
+    pub(crate) fn handle_in_head_noscript(&'a self, token: &Token) {
+        match token {
+            Token::Doctype { .. } => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("noscript") => {
+                // Pop the current node (which will be a noscript element) from the stack of open elements; the new current node will be a head element.
+                self.open_elements.pop();
+                // Switch the insertion mode to "in head".
+                self.switch_insertion_mode_to(InsertionMode::InHead);
+            }
+            Token::Character { data } if is_parser_whitespace(*data) => {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::Comment { .. } => {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("basefont")
+                    || *name == local_name!("bgsound")
+                    || *name == local_name!("link")
+                    || *name == local_name!("meta")
+                    || *name == local_name!("noframes")
+                    || *name == local_name!("style") =>
+            {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("head") || *name == local_name!("noscript") =>
+            {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            // NOTE: A br end tag is handled like anything else below.
+            Token::EndTag { name, .. } if *name != local_name!("br") => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            _ => {
+                // Parse error.
+                log_parser_error!(format!("Unexpected token in noscript in head: {:?}", token));
+                // Pop the current node (which will be a noscript element) from the stack of open elements; the new current node will be a head element.
+                self.open_elements.pop();
+                // Switch the insertion mode to "in head".
+                self.switch_insertion_mode_to(InsertionMode::InHead);
+                // Reprocess the token.
+                self.process_token(token);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::parser::Parser;
+
+    #[test]
+    fn noscript_in_head() {
+        let arena = typed_arena::Arena::new();
+        let parser = Parser::new(
+            arena,
+            "<head><noscript><link rel=a><style>b</style>c</noscript></head>",
+        );
+        let document = parser.parse();
+        let html = document.child_nodes()[0].serialize_html();
+        assert_eq!(
+            html,
+            r#"<html><head><noscript><link rel="a"><style>b</style></noscript></head><body>c</body></html>"#
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::html::parser::{log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-intr
+    pub(crate) fn handle_in_row(&'a self, token: &Token) {
+        match token {
+            Token::StartTag { name, .. }
+                if *name == local_name!("th") || *name == local_name!("td") =>
+            {
+                // Clear the stack back to a table row context.
+                self.open_elements.clear_back_to_a_table_row_context();
+                // Insert an HTML element for the token, then switch the insertion mode to "in cell".
+                self.insert_html_element_for_token(token);
+                self.switch_insertion_mode_to(InsertionMode::InCell);
+                // Insert a marker at the end of the list of active formatting elements.
+                self.active_formatting_elements.push_marker();
+            }
+            Token::EndTag { name, .. } if *name == local_name!("tr") => {
+                // If the stack of open elements does not have a tr element in table scope, this is a parse error; ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope("tr")
+                {
+                    log_parser_error!();
+                    return;
+                }
+
+                self.close_the_row();
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("thead")
+                    || *name == local_name!("tr") =>
+            {
+                self.close_the_row_and_reprocess(token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("table") => {
+                self.close_the_row_and_reprocess(token);
+            }
+            Token::EndTag { name, .. }
+                if *name == local_name!("tbody")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("thead") =>
+            {
+                // If the stack of open elements does not have an element in table scope that is an HTML element with the same tag name as the token, this is a parse error; ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope(name)
+                {
+                    log_parser_error!();
+                    return;
+                }
+
+                // If the stack of open elements does not have a tr element in table scope, ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope("tr")
+                {
+                    return;
+                }
+
+                self.close_the_row();
+                // Reprocess the token.
+                self.process_token(token);
+            }
+            Token::EndTag { name, .. }
+                if *name == local_name!("body")
+                    || *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("html")
+                    || *name == local_name!("td")
+                    || *name == local_name!("th") =>
+            {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            _ => {
+                // Process the token using the rules for the "in table" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InTable, token);
+            }
+        }
+    }
+
+    fn close_the_row(&'a self) {
+        // Clear the stack back to a table row context.
+        self.open_elements.clear_back_to_a_table_row_context();
+        // Pop the current node (which will be a tr element) from the stack of open elements. Switch the insertion mode to "in table body".
+        self.open_elements.pop();
+        self.switch_insertion_mode_to(InsertionMode::InTableBody);
+    }
+
+    fn close_the_row_and_reprocess(&'a self, token: &Token) {
+        // If the stack of open elements does not have a tr element in table scope, this is a parse error; ignore the token.
+        if !self
+            .open_elements
+            .has_element_with_tag_name_in_table_scope("tr")
+        {
+            log_parser_error!();
+            return;
+        }
+
+        self.close_the_row();
+        // Reprocess the token.
+        self.process_token(token);
+    }
+}
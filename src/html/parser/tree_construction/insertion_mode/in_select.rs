@@ -0,0 +1,230 @@
+use crate::html::parser::{log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    pub(crate) fn handle_in_select(&'a self, token: &Token) {
+        match token {
+            Token::Character { data } if data == &'\u{0000}' => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::Character { data } => {
+                // Insert the token's character.
+                self.insert_character(*data);
+            }
+            Token::Comment { data } => {
+                // Insert a comment.
+                self.insert_comment(data);
+            }
+            Token::Doctype { .. } => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("html") => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("option") => {
+                // If the current node is an option element, pop that node from the stack of open elements.
+                if self.current_node().is_element_with_tag("option") {
+                    self.open_elements.pop();
+                }
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("optgroup") => {
+                // If the current node is an option element, pop that node from the stack of open elements.
+                if self.current_node().is_element_with_tag("option") {
+                    self.open_elements.pop();
+                }
+
+                // If the current node is an optgroup element, pop that node from the stack of open elements.
+                if self.current_node().is_element_with_tag("optgroup") {
+                    self.open_elements.pop();
+                }
+
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("hr") => {
+                // If the current node is an option element, pop that node from the stack of open elements.
+                if self.current_node().is_element_with_tag("option") {
+                    self.open_elements.pop();
+                }
+
+                // If the current node is an optgroup element, pop that node from the stack of open elements.
+                if self.current_node().is_element_with_tag("optgroup") {
+                    self.open_elements.pop();
+                }
+
+                // Insert an HTML element for the token. Immediately pop the current node off the stack of open elements.
+                self.insert_html_element_for_token(token);
+                self.open_elements.pop();
+
+                // Acknowledge the token's self-closing flag, if it is set.
+                token.acknowledge_self_closing_flag_if_set();
+            }
+            Token::EndTag { name, .. } if *name == local_name!("optgroup") => {
+                // First, if the current node is an option element, and the node immediately before it in the stack of open elements is an optgroup element, then pop the current node from the stack of open elements.
+                if self.current_node().is_element_with_tag("option")
+                    && self
+                        .open_elements
+                        .element_immediately_above(self.current_node())
+                        .is_some_and(|node| node.is_element_with_tag("optgroup"))
+                {
+                    self.open_elements.pop();
+                }
+
+                // If the current node is an optgroup element, then pop that node from the stack of open elements.
+                if self.current_node().is_element_with_tag("optgroup") {
+                    self.open_elements.pop();
+                } else {
+                    // Otherwise, this is a parse error; ignore the token.
+                    log_parser_error!();
+                }
+            }
+            Token::EndTag { name, .. } if *name == local_name!("option") => {
+                // If the current node is an option element, then pop that node from the stack of open elements.
+                if self.current_node().is_element_with_tag("option") {
+                    self.open_elements.pop();
+                } else {
+                    // Otherwise, this is a parse error; ignore the token.
+                    log_parser_error!();
+                }
+            }
+            Token::EndTag { name, .. } if *name == local_name!("select") => {
+                // If the stack of open elements does not have a select element in select scope, this is a parse error; ignore the token. (fragment case)
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_select_scope("select")
+                {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise:
+                // Pop elements from the stack of open elements until a select element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("select");
+
+                // Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("select") => {
+                // Parse error.
+                log_parser_error!("Unexpected select start tag inside select");
+
+                // If the stack of open elements does not have a select element in select scope, ignore the token. (fragment case)
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_select_scope("select")
+                {
+                    return;
+                }
+
+                // Otherwise:
+                // Pop elements from the stack of open elements until a select element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("select");
+
+                // Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+
+                // NOTE: It just gets treated like an end tag.
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("input")
+                    || *name == local_name!("keygen")
+                    || *name == local_name!("textarea") =>
+            {
+                // Parse error.
+                log_parser_error!(format!("Unexpected '{}' start tag inside select", name));
+
+                // If the stack of open elements does not have a select element in select scope, ignore the token. (fragment case)
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_select_scope("select")
+                {
+                    return;
+                }
+
+                // Otherwise:
+                // Pop elements from the stack of open elements until a select element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("select");
+
+                // Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+
+                // Reprocess the token.
+                self.process_token(token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("script") || *name == local_name!("template") =>
+            {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("template") => {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::EndOfFile => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            _ => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::parser::tests::parse_body;
+
+    #[test]
+    fn options_and_optgroups() {
+        assert_eq!(
+            parse_body("<select><option>a<option>b<optgroup><option>c</select>d"),
+            "<select><option>a</option><option>b</option><optgroup><option>c</option></optgroup></select>d"
+        );
+    }
+
+    #[test]
+    fn options_in_body() {
+        assert_eq!(
+            parse_body("<option>a<option>b"),
+            "<option>a</option><option>b</option>"
+        );
+    }
+
+    #[test]
+    fn input_closes_select() {
+        assert_eq!(
+            parse_body("<select><option>a<input>b"),
+            "<select><option>a</option></select><input>b"
+        );
+    }
+
+    #[test]
+    fn select_in_template() {
+        assert_eq!(
+            parse_body("<div><template><select><option>a</select>b</template></div>"),
+            "<div><template><select><option>a</option></select>b</template></div>"
+        );
+    }
+
+    #[test]
+    fn select_in_table() {
+        assert_eq!(
+            parse_body("<table><tr><td><select><option>a<td>b</table>"),
+            "<table><tbody><tr><td><select><option>a</option></select></td><td>b</td></tr></tbody></table>"
+        );
+        assert_eq!(
+            parse_body("<table><tr><td><select><option>a</tr></table>b"),
+            "<table><tbody><tr><td><select><option>a</option></select></td></tr></tbody></table>b"
+        );
+    }
+}
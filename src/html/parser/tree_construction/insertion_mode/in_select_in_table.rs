@@ -0,0 +1,61 @@
+use crate::html::parser::{log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-inselectintable
+    pub(crate) fn handle_in_select_in_table(&'a self, token: &Token) {
+        let is_table_tag = |name: &_| {
+            *name == local_name!("caption")
+                || *name == local_name!("table")
+                || *name == local_name!("tbody")
+                || *name == local_name!("tfoot")
+                || *name == local_name!("thead")
+                || *name == local_name!("tr")
+                || *name == local_name!("td")
+                || *name == local_name!("th")
+        };
+
+        match token {
+            Token::StartTag { name, .. } if is_table_tag(name) => {
+                // Parse error.
+                log_parser_error!(format!("Unexpected '{}' start tag inside select", name));
+
+                // Pop elements from the stack of open elements until a select element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("select");
+
+                // Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+
+                // Reprocess the token.
+                self.process_token(token);
+            }
+            Token::EndTag { name, .. } if is_table_tag(name) => {
+                // Parse error.
+                log_parser_error!(format!("Unexpected '{}' end tag inside select", name));
+
+                // If the stack of open elements does not have an element in table scope that is an HTML element with the same tag name as that of the token, then ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope(name)
+                {
+                    return;
+                }
+
+                // Otherwise:
+                // Pop elements from the stack of open elements until a select element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("select");
+
+                // Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+
+                // Reprocess the token.
+                self.process_token(token);
+            }
+            _ => {
+                // Process the token using the rules for the "in select" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InSelect, token);
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::html::parser::{log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-intable
+    pub(crate) fn handle_in_table(&'a self, token: &Token) {
+        match token {
+            Token::Character { .. }
+                if self.current_node().is_element_with_one_of_tags(&[
+                    "table", "tbody", "template", "tfoot", "thead", "tr",
+                ]) =>
+            {
+                // Let the pending table character tokens be an empty list of tokens.
+                self.pending_table_character_tokens.borrow_mut().clear();
+                // Let the original insertion mode be the current insertion mode.
+                self.original_insertion_mode
+                    .set(Some(self.insertion_mode.get()));
+                // Switch the insertion mode to "in table text" and reprocess the token.
+                self.switch_insertion_mode_to(InsertionMode::InTableText);
+                self.process_token(token);
+            }
+            Token::Comment { data } => {
+                // Insert a comment.
+                self.insert_comment(data);
+            }
+            Token::Doctype { .. } => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("caption") => {
+                // Clear the stack back to a table context.
+                self.open_elements.clear_back_to_a_table_context();
+                // Insert a marker at the end of the list of active formatting elements.
+                self.active_formatting_elements.push_marker();
+                // Insert an HTML element for the token, then switch the insertion mode to "in caption".
+                self.insert_html_element_for_token(token);
+                self.switch_insertion_mode_to(InsertionMode::InCaption);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("colgroup") => {
+                // Clear the stack back to a table context.
+                self.open_elements.clear_back_to_a_table_context();
+                // Insert an HTML element for the token, then switch the insertion mode to "in column group".
+                self.insert_html_element_for_token(token);
+                self.switch_insertion_mode_to(InsertionMode::InColumnGroup);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("col") => {
+                // Clear the stack back to a table context.
+                self.open_elements.clear_back_to_a_table_context();
+                // Insert an HTML element for a "colgroup" start tag token with no attributes, then switch the insertion mode to "in column group".
+                self.insert_html_element_for_start_tag("colgroup");
+                self.switch_insertion_mode_to(InsertionMode::InColumnGroup);
+                // Reprocess the current token.
+                self.process_token(token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("tbody")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("thead") =>
+            {
+                // Clear the stack back to a table context.
+                self.open_elements.clear_back_to_a_table_context();
+                // Insert an HTML element for the token, then switch the insertion mode to "in table body".
+                self.insert_html_element_for_token(token);
+                self.switch_insertion_mode_to(InsertionMode::InTableBody);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("td")
+                    || *name == local_name!("th")
+                    || *name == local_name!("tr") =>
+            {
+                // Clear the stack back to a table context.
+                self.open_elements.clear_back_to_a_table_context();
+                // Insert an HTML element for a "tbody" start tag token with no attributes, then switch the insertion mode to "in table body".
+                self.insert_html_element_for_start_tag("tbody");
+                self.switch_insertion_mode_to(InsertionMode::InTableBody);
+                // Reprocess the current token.
+                self.process_token(token);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("table") => {
+                // Parse error.
+                log_parser_error!("unexpected 'table' start tag in table");
+
+                // If the stack of open elements does not have a table element in table scope, ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope("table")
+                {
+                    return;
+                }
+
+                // Otherwise:
+                // Pop elements from this stack until a table element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("table");
+                // Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+                // Reprocess the token.
+                self.process_token(token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("table") => {
+                // If the stack of open elements does not have a table element in table scope, this is a parse error; ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope("table")
+                {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise:
+                // Pop elements from this stack until a table element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("table");
+                // Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+            }
+            Token::EndTag { name, .. }
+                if *name == local_name!("body")
+                    || *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("html")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("td")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("th")
+                    || *name == local_name!("thead")
+                    || *name == local_name!("tr") =>
+            {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("style")
+                    || *name == local_name!("script")
+                    || *name == local_name!("template") =>
+            {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("template") => {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::StartTag {
+                name, attributes, ..
+            } if *name == local_name!("input")
+                && attributes.iter().any(|attr| {
+                    attr.name == local_name!("type") && attr.value.eq_ignore_ascii_case("hidden")
+                }) =>
+            {
+                // If the token does not have an attribute with the name "type", or if it does, but that attribute's value is not an ASCII case-insensitive match for the string "hidden", then: act as described in the "anything else" entry below.
+                // Otherwise:
+                // Parse error.
+                log_parser_error!();
+                // Insert an HTML element for the token.
+                self.insert_html_element_for_token(token);
+                // Pop that input element off the stack of open elements.
+                self.open_elements.pop();
+                // Acknowledge the token's self-closing flag, if it is set.
+                token.acknowledge_self_closing_flag_if_set();
+            }
+            Token::StartTag { name, .. } if *name == local_name!("form") => {
+                // Parse error.
+                log_parser_error!();
+
+                // If there is a template element on the stack of open elements, or if the form element pointer is not null, ignore the token.
+                if self.open_elements.contains_one_of_tags(&["template"])
+                    || self.form_element.get().is_some()
+                {
+                    return;
+                }
+
+                // Otherwise:
+                // Insert an HTML element for the token, and set the form element pointer to point to the element created.
+                let form_element = self.insert_html_element_for_token(token);
+                self.form_element.set(Some(form_element));
+                // Pop that form element off the stack of open elements.
+                self.open_elements.pop();
+            }
+            Token::EndOfFile => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            _ => {
+                // Parse error.
+                log_parser_error!(format!("unexpected token in table: {:?}", token));
+                self.process_token_in_table_with_foster_parenting(token);
+            }
+        }
+    }
+
+    /// SPEC: Enable foster parenting, process the token using the rules for the "in body"
+    ///       insertion mode, and then disable foster parenting.
+    pub(crate) fn process_token_in_table_with_foster_parenting(&'a self, token: &Token) {
+        self.foster_parenting.set(true);
+        self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+        self.foster_parenting.set(false);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::html::parser::{log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-intbody
+    pub(crate) fn handle_in_table_body(&'a self, token: &Token) {
+        match token {
+            Token::StartTag { name, .. } if *name == local_name!("tr") => {
+                // Clear the stack back to a table body context.
+                self.open_elements.clear_back_to_a_table_body_context();
+                // Insert an HTML element for the token, then switch the insertion mode to "in row".
+                self.insert_html_element_for_token(token);
+                self.switch_insertion_mode_to(InsertionMode::InRow);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("th") || *name == local_name!("td") =>
+            {
+                // Parse error.
+                log_parser_error!(format!("unexpected '{}' start tag outside of a row", name));
+                // Clear the stack back to a table body context.
+                self.open_elements.clear_back_to_a_table_body_context();
+                // Insert an HTML element for a "tr" start tag token with no attributes, then switch the insertion mode to "in row".
+                self.insert_html_element_for_start_tag("tr");
+                self.switch_insertion_mode_to(InsertionMode::InRow);
+                // Reprocess the current token.
+                self.process_token(token);
+            }
+            Token::EndTag { name, .. }
+                if *name == local_name!("tbody")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("thead") =>
+            {
+                // If the stack of open elements does not have an element in table scope that is an HTML element with the same tag name as the token, this is a parse error; ignore the token.
+                if !self
+                    .open_elements
+                    .has_element_with_tag_name_in_table_scope(name)
+                {
+                    log_parser_error!();
+                    return;
+                }
+
+                // Otherwise:
+                // Clear the stack back to a table body context.
+                self.open_elements.clear_back_to_a_table_body_context();
+                // Pop the current node from the stack of open elements. Switch the insertion mode to "in table".
+                self.open_elements.pop();
+                self.switch_insertion_mode_to(InsertionMode::InTable);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("thead") =>
+            {
+                self.close_the_table_body(token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("table") => {
+                self.close_the_table_body(token);
+            }
+            Token::EndTag { name, .. }
+                if *name == local_name!("body")
+                    || *name == local_name!("caption")
+                    || *name == local_name!("col")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("html")
+                    || *name == local_name!("td")
+                    || *name == local_name!("th")
+                    || *name == local_name!("tr") =>
+            {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            _ => {
+                // Process the token using the rules for the "in table" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InTable, token);
+            }
+        }
+    }
+
+    fn close_the_table_body(&'a self, token: &Token) {
+        // If the stack of open elements does not have a tbody, thead, or tfoot element in table scope, this is a parse error; ignore the token.
+        if !self
+            .open_elements
+            .has_element_with_one_of_tag_names_in_table_scope(&["tbody", "thead", "tfoot"])
+        {
+            log_parser_error!();
+            return;
+        }
+
+        // Otherwise:
+        // Clear the stack back to a table body context.
+        self.open_elements.clear_back_to_a_table_body_context();
+        // Pop the current node from the stack of open elements. Switch the insertion mode to "in table".
+        self.open_elements.pop();
+        self.switch_insertion_mode_to(InsertionMode::InTable);
+        // Reprocess the token.
+        self.process_token(token);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::html::parser::{is_parser_whitespace, log_parser_error, Parser};
+use crate::html::tokenizer::Token;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-intabletext
+    pub(crate) fn handle_in_table_text(&'a self, token: &Token) {
+        match token {
+            Token::Character { data } if *data == '\u{0000}' => {
+                // Parse error. Ignore the token.
+                log_parser_error!();
+            }
+            Token::Character { data } => {
+                // Append the character token to the pending table character tokens list.
+                self.pending_table_character_tokens.borrow_mut().push(*data);
+            }
+            _ => {
+                let pending_table_character_tokens =
+                    std::mem::take(&mut *self.pending_table_character_tokens.borrow_mut());
+
+                // If any of the tokens in the pending table character tokens list are character tokens that are not ASCII whitespace,
+                if pending_table_character_tokens
+                    .iter()
+                    .any(|data| !is_parser_whitespace(*data))
+                {
+                    // then this is a parse error:
+                    log_parser_error!("non-whitespace characters in table");
+                    // reprocess the character tokens in the pending table character tokens list using the rules given in the "anything else" entry in the "in table" insertion mode.
+                    for data in pending_table_character_tokens {
+                        self.process_token_in_table_with_foster_parenting(&Token::Character {
+                            data,
+                        });
+                    }
+                } else {
+                    // Otherwise, insert the characters given by the pending table character tokens list.
+                    for data in pending_table_character_tokens {
+                        self.insert_character(data);
+                    }
+                }
+
+                // Switch the insertion mode to the original insertion mode and reprocess the token.
+                self.switch_insertion_mode_to(self.original_insertion_mode.get().unwrap());
+                self.process_token(token);
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::html::parser::{log_parser_error, InsertionMode, Parser};
+use crate::html::tokenizer::Token;
+use crate::local_name;
+
+impl<'a> Parser<'a> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-intemplate
+    pub(crate) fn handle_in_template(&'a self, token: &Token) {
+        // Pop the current template insertion mode off the stack of template insertion modes,
+        // push the given one onto the stack so that it is the new current template insertion mode,
+        // switch the insertion mode to it, and reprocess the token.
+        macro_rules! switch_template_insertion_mode_and_reprocess {
+            ($insertion_mode:expr) => {{
+                let mut template_insertion_modes = self.template_insertion_modes.borrow_mut();
+                template_insertion_modes.pop();
+                template_insertion_modes.push($insertion_mode);
+                drop(template_insertion_modes);
+
+                self.switch_insertion_mode_to($insertion_mode);
+                self.process_token(token);
+            }};
+        }
+
+        match token {
+            Token::Character { .. } | Token::Comment { .. } | Token::Doctype { .. } => {
+                // Process the token using the rules for the "in body" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InBody, token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("base")
+                    || *name == local_name!("basefont")
+                    || *name == local_name!("bgsound")
+                    || *name == local_name!("link")
+                    || *name == local_name!("meta")
+                    || *name == local_name!("noframes")
+                    || *name == local_name!("script")
+                    || *name == local_name!("style")
+                    || *name == local_name!("template")
+                    || *name == local_name!("title") =>
+            {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::EndTag { name, .. } if *name == local_name!("template") => {
+                // Process the token using the rules for the "in head" insertion mode.
+                self.process_token_using_the_rules_for(InsertionMode::InHead, token);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("caption")
+                    || *name == local_name!("colgroup")
+                    || *name == local_name!("tbody")
+                    || *name == local_name!("tfoot")
+                    || *name == local_name!("thead") =>
+            {
+                switch_template_insertion_mode_and_reprocess!(InsertionMode::InTable);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("col") => {
+                switch_template_insertion_mode_and_reprocess!(InsertionMode::InColumnGroup);
+            }
+            Token::StartTag { name, .. } if *name == local_name!("tr") => {
+                switch_template_insertion_mode_and_reprocess!(InsertionMode::InTableBody);
+            }
+            Token::StartTag { name, .. }
+                if *name == local_name!("td") || *name == local_name!("th") =>
+            {
+                switch_template_insertion_mode_and_reprocess!(InsertionMode::InRow);
+            }
+            Token::StartTag { .. } => {
+                switch_template_insertion_mode_and_reprocess!(InsertionMode::InBody);
+            }
+            Token::EndTag { name, .. } => {
+                // Parse error. Ignore the token.
+                log_parser_error!(format!("Unexpected end tag '{}' in template", name));
+            }
+            Token::EndOfFile => {
+                // If there is no template element on the stack of open elements, then stop parsing. (fragment case)
+                if !self.open_elements.contains_one_of_tags(&["template"]) {
+                    self.stop_parsing();
+                    return;
+                }
+
+                // Otherwise, this is a parse error.
+                log_parser_error!("Unexpected end of file in template");
+
+                // Pop elements from the stack of open elements until a template element has been popped from the stack.
+                self.open_elements.pop_until_tag_name("template");
+
+                // Clear the list of active formatting elements up to the last marker.
+                self.active_formatting_elements
+                    .clear_up_to_the_last_marker();
+
+                // Pop the current template insertion mode off the stack of template insertion modes.
+                self.template_insertion_modes.borrow_mut().pop();
+
+                // Reset the insertion mode appropriately.
+                self.reset_the_insertion_mode_appropriately();
+
+                // Reprocess the token.
+                self.process_token(token);
+            }
+        }
+    }
+}
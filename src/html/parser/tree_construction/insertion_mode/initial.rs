@@ -1,5 +1,5 @@
 use crate::dom::node::NodeType;
-use crate::dom::Node;
+use crate::dom::{Node, QuirksMode};
 use crate::html::parser::{is_parser_whitespace, log_parser_error, InsertionMode, Parser};
 use crate::html::tokenizer::Token;
 
@@ -17,14 +17,14 @@ impl<'a> Parser<'a> {
                 name,
                 public_identifier,
                 system_identifier,
-                ..
+                force_quirks,
             } => {
                 // If the DOCTYPE token's name is not "html", or the token's public identifier is not missing, or the token's system identifier is neither missing nor "about:legacy-compat", then there is a parse error.
                 if name != &Some("html".to_string())
                     || public_identifier.is_some()
-                    || (system_identifier
-                        .to_owned()
-                        .is_some_and(|id| id == "about:legacy-compat"))
+                    || system_identifier
+                        .as_ref()
+                        .is_some_and(|id| id != "about:legacy-compat")
                 {
                     log_parser_error!("Bad DOCTYPE");
                 }
@@ -34,21 +34,299 @@ impl<'a> Parser<'a> {
                     Some(&self.document),
                     NodeType::DocumentType {
                         name: name.clone().unwrap_or("".to_string()),
-                        public_identifier: public_identifier.clone().unwrap_or("".to_string()),
-                        system_identifier: system_identifier.clone().unwrap_or("".to_string()),
+                        public_id: public_identifier.clone().unwrap_or("".to_string()),
+                        system_id: system_identifier.clone().unwrap_or("".to_string()),
                     },
                 ));
-                Node::append(doctype_node, &self.document, false);
+                Node::append(doctype_node, &self.document, false)
+                    .expect("the document should not have any children before its doctype");
 
-                // FIXME: Then, if the document is not an iframe srcdoc document, and the parser cannot change the mode flag is false, and the DOCTYPE token matches one of the conditions in the following list, then set the Document to quirks mode:
-                // FIXME: Otherwise, if the document is not an iframe srcdoc document, and the parser cannot change the mode flag is false, and the DOCTYPE token matches one of the conditions in the following list, then then set the Document to limited-quirks mode:
+                // Then, if the document is not an iframe srcdoc document, and the parser cannot change the mode flag is false, and the DOCTYPE token matches one of the conditions in the following list, then set the Document to quirks mode:
+                // Otherwise, if the document is not an iframe srcdoc document, and the parser cannot change the mode flag is false, and the DOCTYPE token matches one of the conditions in the following list, then then set the Document to limited-quirks mode:
+                // FIXME: We don't support iframe srcdoc documents or the parser cannot change the mode flag yet.
+                self.document().set_quirks_mode(quirks_mode_for_doctype(
+                    name.as_deref(),
+                    public_identifier.as_deref(),
+                    system_identifier.as_deref(),
+                    *force_quirks,
+                ));
 
                 // Then, switch the insertion mode to "before html".
                 self.switch_insertion_mode_to(InsertionMode::BeforeHtml);
             }
             _ => {
-                todo!()
+                // If the document is not an iframe srcdoc document, then this is a parse error;
+                log_parser_error!("Expected DOCTYPE");
+                // if the parser cannot change the mode flag is false, set the Document to quirks mode.
+                // FIXME: We don't support the parser cannot change the mode flag yet.
+                self.document().set_quirks_mode(QuirksMode::Quirks);
+
+                // In any case, switch the insertion mode to "before html", then reprocess the token.
+                self.switch_insertion_mode_to(InsertionMode::BeforeHtml);
+                self.process_token(token);
             }
         }
     }
 }
+
+// https://html.spec.whatwg.org/multipage/parsing.html#the-initial-insertion-mode
+fn quirks_mode_for_doctype(
+    name: Option<&str>,
+    public_identifier: Option<&str>,
+    system_identifier: Option<&str>,
+    force_quirks: bool,
+) -> QuirksMode {
+    // NOTE: The public identifier and system identifier comparisons are ASCII case-insensitive.
+    let public_identifier = public_identifier.map(|id| id.to_ascii_lowercase());
+    let system_identifier = system_identifier.map(|id| id.to_ascii_lowercase());
+    let public_is = |expected: &str| public_identifier.as_deref() == Some(expected);
+    let public_starts_with = |prefix: &str| {
+        public_identifier
+            .as_deref()
+            .is_some_and(|id| id.starts_with(prefix))
+    };
+
+    // - The force-quirks flag is set to on.
+    // - The name is not "html".
+    // - The public identifier is set to one of the quirky public identifiers, or starts with one of the quirky public identifier prefixes.
+    // - The system identifier is set to "http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd".
+    // - The system identifier is missing and the public identifier starts with "-//W3C//DTD HTML 4.01 Frameset//" or "-//W3C//DTD HTML 4.01 Transitional//".
+    if force_quirks
+        || name != Some("html")
+        || QUIRKY_PUBLIC_IDENTIFIERS.iter().any(|id| public_is(id))
+        || QUIRKY_PUBLIC_IDENTIFIER_PREFIXES
+            .iter()
+            .any(|prefix| public_starts_with(prefix))
+        || system_identifier.as_deref()
+            == Some("http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd")
+        || (system_identifier.is_none()
+            && (public_starts_with("-//w3c//dtd html 4.01 frameset//")
+                || public_starts_with("-//w3c//dtd html 4.01 transitional//")))
+    {
+        return QuirksMode::Quirks;
+    }
+
+    // - The public identifier starts with: "-//W3C//DTD XHTML 1.0 Frameset//" or "-//W3C//DTD XHTML 1.0 Transitional//".
+    // - The system identifier is not missing and the public identifier starts with: "-//W3C//DTD HTML 4.01 Frameset//" or "-//W3C//DTD HTML 4.01 Transitional//".
+    if public_starts_with("-//w3c//dtd xhtml 1.0 frameset//")
+        || public_starts_with("-//w3c//dtd xhtml 1.0 transitional//")
+        || (system_identifier.is_some()
+            && (public_starts_with("-//w3c//dtd html 4.01 frameset//")
+                || public_starts_with("-//w3c//dtd html 4.01 transitional//")))
+    {
+        return QuirksMode::LimitedQuirks;
+    }
+
+    QuirksMode::NoQuirks
+}
+
+// NOTE: These are lowercased, because they are compared ASCII case-insensitively.
+static QUIRKY_PUBLIC_IDENTIFIERS: &[&str] = &[
+    "-//w3o//dtd w3 html strict 3.0//en//",
+    "-/w3c/dtd html 4.0 transitional/en",
+    "html",
+];
+
+static QUIRKY_PUBLIC_IDENTIFIER_PREFIXES: &[&str] = &[
+    "+//silmaril//dtd html pro v0r11 19970101//",
+    "-//as//dtd html 3.0 aswedit + extensions//",
+    "-//advasoft ltd//dtd html 3.0 aswedit + extensions//",
+    "-//ietf//dtd html 2.0 level 1//",
+    "-//ietf//dtd html 2.0 level 2//",
+    "-//ietf//dtd html 2.0 strict level 1//",
+    "-//ietf//dtd html 2.0 strict level 2//",
+    "-//ietf//dtd html 2.0 strict//",
+    "-//ietf//dtd html 2.0//",
+    "-//ietf//dtd html 2.1e//",
+    "-//ietf//dtd html 3.0//",
+    "-//ietf//dtd html 3.2 final//",
+    "-//ietf//dtd html 3.2//",
+    "-//ietf//dtd html 3//",
+    "-//ietf//dtd html level 0//",
+    "-//ietf//dtd html level 1//",
+    "-//ietf//dtd html level 2//",
+    "-//ietf//dtd html level 3//",
+    "-//ietf//dtd html strict level 0//",
+    "-//ietf//dtd html strict level 1//",
+    "-//ietf//dtd html strict level 2//",
+    "-//ietf//dtd html strict level 3//",
+    "-//ietf//dtd html strict//",
+    "-//ietf//dtd html//",
+    "-//metrius//dtd metrius presentational//",
+    "-//microsoft//dtd internet explorer 2.0 html strict//",
+    "-//microsoft//dtd internet explorer 2.0 html//",
+    "-//microsoft//dtd internet explorer 2.0 tables//",
+    "-//microsoft//dtd internet explorer 3.0 html strict//",
+    "-//microsoft//dtd internet explorer 3.0 html//",
+    "-//microsoft//dtd internet explorer 3.0 tables//",
+    "-//netscape comm. corp.//dtd html//",
+    "-//netscape comm. corp.//dtd strict html//",
+    "-//o'reilly and associates//dtd html 2.0//",
+    "-//o'reilly and associates//dtd html extended 1.0//",
+    "-//o'reilly and associates//dtd html extended relaxed 1.0//",
+    "-//sq//dtd html 2.0 hotmetal + extensions//",
+    "-//softquad software//dtd hotmetal pro 6.0::19990601::extensions to html 4.0//",
+    "-//softquad//dtd hotmetal pro 4.0::19971010::extensions to html 4.0//",
+    "-//spyglass//dtd html 2.0 extended//",
+    "-//sun microsystems corp.//dtd hotjava html//",
+    "-//sun microsystems corp.//dtd hotjava strict html//",
+    "-//w3c//dtd html 3 1995-03-24//",
+    "-//w3c//dtd html 3.2 draft//",
+    "-//w3c//dtd html 3.2 final//",
+    "-//w3c//dtd html 3.2//",
+    "-//w3c//dtd html 3.2s draft//",
+    "-//w3c//dtd html 4.0 frameset//",
+    "-//w3c//dtd html 4.0 transitional//",
+    "-//w3c//dtd html experimental 19960712//",
+    "-//w3c//dtd html experimental 970421//",
+    "-//w3c//dtd w3 html//",
+    "-//w3o//dtd w3 html 3.0//",
+    "-//webtechs//dtd mozilla html 2.0//",
+    "-//webtechs//dtd mozilla html//",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed_quirks_mode(input: &str) -> QuirksMode {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let quirks_mode = document
+            .as_document()
+            .expect("parse should return a Document")
+            .quirks_mode();
+        quirks_mode
+    }
+
+    #[test]
+    fn no_quirks() {
+        assert_eq!(parsed_quirks_mode("<!DOCTYPE html>"), QuirksMode::NoQuirks);
+        assert_eq!(parsed_quirks_mode("<!doctype HTML>"), QuirksMode::NoQuirks);
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html SYSTEM \"about:legacy-compat\">"),
+            QuirksMode::NoQuirks
+        );
+        assert_eq!(
+            parsed_quirks_mode(
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd\">"
+            ),
+            QuirksMode::NoQuirks
+        );
+        assert_eq!(
+            parsed_quirks_mode(
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Strict//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd\">"
+            ),
+            QuirksMode::NoQuirks
+        );
+    }
+
+    #[test]
+    fn quirks() {
+        assert_eq!(parsed_quirks_mode("<p>No doctype"), QuirksMode::Quirks);
+        assert_eq!(parsed_quirks_mode(""), QuirksMode::Quirks);
+        assert_eq!(parsed_quirks_mode("<!DOCTYPE>"), QuirksMode::Quirks);
+        assert_eq!(parsed_quirks_mode("<!DOCTYPE svg>"), QuirksMode::Quirks);
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 3.2 Final//EN\">"),
+            QuirksMode::Quirks
+        );
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html PUBLIC \"-//W3O//DTD W3 HTML Strict 3.0//EN//\">"),
+            QuirksMode::Quirks
+        );
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html PUBLIC \"HTML\">"),
+            QuirksMode::Quirks
+        );
+        assert_eq!(
+            parsed_quirks_mode(
+                "<!DOCTYPE html SYSTEM \"http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd\">"
+            ),
+            QuirksMode::Quirks
+        );
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01 Transitional//EN\">"),
+            QuirksMode::Quirks
+        );
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01 Frameset//EN\">"),
+            QuirksMode::Quirks
+        );
+    }
+
+    #[test]
+    fn limited_quirks() {
+        assert_eq!(
+            parsed_quirks_mode(
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">"
+            ),
+            QuirksMode::LimitedQuirks
+        );
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Frameset//EN\">"),
+            QuirksMode::LimitedQuirks
+        );
+        assert_eq!(
+            parsed_quirks_mode(
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01 Transitional//EN\" \"http://www.w3.org/TR/html4/loose.dtd\">"
+            ),
+            QuirksMode::LimitedQuirks
+        );
+        assert_eq!(
+            parsed_quirks_mode(
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01 Frameset//EN\" \"http://www.w3.org/TR/html4/frameset.dtd\">"
+            ),
+            QuirksMode::LimitedQuirks
+        );
+    }
+
+    #[test]
+    fn identifiers_are_compared_case_insensitively() {
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html PUBLIC \"-//w3c//dtd html 3.2 final//en\">"),
+            QuirksMode::Quirks
+        );
+        assert_eq!(
+            parsed_quirks_mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 TRANSITIONAL//EN\">"),
+            QuirksMode::LimitedQuirks
+        );
+    }
+
+    #[test]
+    fn a_doctype_after_the_first_token_does_not_change_the_mode() {
+        assert_eq!(parsed_quirks_mode("<p><!DOCTYPE html>"), QuirksMode::Quirks);
+    }
+
+    #[test]
+    fn quirks_mode_changes_whether_a_table_closes_a_p_element() {
+        let body = |input: &str| crate::html::parser::tests::parse_body(input);
+        assert_eq!(
+            body("<!DOCTYPE html><p><table></table>"),
+            "<p></p><table></table>"
+        );
+        assert_eq!(body("<p><table></table>"), "<p><table></table></p>");
+    }
+
+    #[test]
+    fn leading_whitespace_is_ignored_and_comments_go_to_the_document() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "\n\n<!-- c -->\n<!DOCTYPE html><html>",
+        );
+        let document = parser.parse();
+
+        // NOTE: The DOCTYPE is still handled by the initial insertion mode, which switches to
+        //       "before html" so the html start tag becomes the document element.
+        let children = document
+            .child_nodes()
+            .iter()
+            .map(|child| child.node_name())
+            .collect::<Vec<_>>();
+        assert_eq!(children, ["#comment", "html", "HTML"]);
+        assert_eq!(
+            document.as_document().unwrap().quirks_mode(),
+            QuirksMode::NoQuirks
+        );
+    }
+}
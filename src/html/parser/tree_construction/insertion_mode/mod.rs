@@ -1,12 +1,26 @@
 use crate::html::parser::{InsertionMode, Parser};
 
 pub(crate) mod after_after_body;
+pub(crate) mod after_after_frameset;
 pub(crate) mod after_body;
+pub(crate) mod after_frameset;
 pub(crate) mod after_head;
 pub(crate) mod before_head;
 pub(crate) mod before_html;
 pub(crate) mod in_body;
+pub(crate) mod in_caption;
+pub(crate) mod in_cell;
+pub(crate) mod in_column_group;
+pub(crate) mod in_frameset;
 pub(crate) mod in_head;
+pub(crate) mod in_head_noscript;
+pub(crate) mod in_row;
+pub(crate) mod in_select;
+pub(crate) mod in_select_in_table;
+pub(crate) mod in_table;
+pub(crate) mod in_table_body;
+pub(crate) mod in_table_text;
+pub(crate) mod in_template;
 pub(crate) mod initial;
 pub(crate) mod text;
 
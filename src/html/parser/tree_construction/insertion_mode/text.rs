@@ -1,5 +1,6 @@
 use crate::html::parser::{log_parser_error, Parser};
 use crate::html::tokenizer::Token;
+use crate::local_name;
 
 impl<'a> Parser<'a> {
     pub(crate) fn handle_text(&'a self, token: &Token) {
@@ -21,7 +22,23 @@ impl<'a> Parser<'a> {
                 self.switch_insertion_mode_to(self.original_insertion_mode.get().unwrap());
                 self.process_token(token);
             }
-            Token::EndTag { name, .. } if name == "script" => todo!(),
+            Token::EndTag { name, .. } if *name == local_name!("script") => {
+                // FIXME: If the active speculative HTML parser is null and the JavaScript execution context stack is empty, then perform a microtask checkpoint.
+
+                // Let script be the current node (which will be a script element).
+                // Pop the current node off the stack of open elements.
+                self.open_elements.pop();
+
+                // Switch the insertion mode to the original insertion mode.
+                self.switch_insertion_mode_to(self.original_insertion_mode.get().unwrap());
+
+                // FIXME: Let the old insertion point have the same value as the current insertion point. Let the insertion point be just before the next input character.
+                // FIXME: Increment the parser's script nesting level by one.
+                // FIXME: If the active speculative HTML parser is null, then prepare the script element script. This might cause some script to execute, which might cause new characters to be inserted into the tokenizer, and might cause the tokenizer to output more tokens, resulting in a reentrant invocation of the parser.
+                // FIXME: Decrement the parser's script nesting level by one. If the parser's script nesting level is zero, then set the parser pause flag to false.
+                // FIXME: Let the insertion point have the value of the old insertion point.
+                // FIXME: At this stage, if the pending parsing-blocking script is not null, then:
+            }
             _ => {
                 // Pop the current node off the stack of open elements.
                 self.open_elements.pop();
@@ -2,15 +2,26 @@ use std::cell::RefCell;
 
 use crate::dom::NodeRef;
 
-use super::stack_of_open_elements::StackOfOpenElements;
-
-#[derive(PartialEq, Eq, Debug, Clone, Copy)]
-#[allow(unused)]
+#[derive(Debug, Clone, Copy)]
 pub enum ActiveFormattingElement<'a> {
     Marker,
     Element(NodeRef<'a>),
 }
 
+// NOTE: Entries refer to elements by identity: two distinct elements with the same
+//       tag name and attributes are still different entries.
+impl PartialEq for ActiveFormattingElement<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Marker, Self::Marker) => true,
+            (Self::Element(a), Self::Element(b)) => std::ptr::eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ActiveFormattingElement<'_> {}
+
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum Position {
     End,
@@ -28,38 +39,6 @@ impl<'a> ListOfActiveFormattingElements<'a> {
         }
     }
 
-    // https://html.spec.whatwg.org/multipage/parsing.html#reconstruct-the-active-formatting-elements
-    pub fn reconstruct_if_any(&self, stack_of_open_elements: &'a StackOfOpenElements<'a>) {
-        let elements = self.elements.borrow_mut();
-
-        // If there are no entries in the list of active formatting elements, then there is nothing to reconstruct; stop this algorithm.
-        if elements.is_empty() {
-            return;
-        }
-
-        // If the last (most recently added) entry in the list of active formatting elements is a marker, or if it is an element that is in the stack of open elements, then there is nothing to reconstruct; stop this algorithm.
-        match elements.last().unwrap() {
-            ActiveFormattingElement::Marker => return,
-            ActiveFormattingElement::Element(element)
-                if stack_of_open_elements.contains(element) =>
-            {
-                return;
-            }
-            _ => {}
-        }
-
-        todo!();
-
-        // FIXME: Let entry be the last (most recently added) element in the list of active formatting elements.
-        // FIXME: Rewind: If there are no entries before entry in the list of active formatting elements, then jump to the step labeled create.
-        // FIXME: Let entry be the entry one earlier than entry in the list of active formatting elements.
-        // FIXME: If entry is neither a marker nor an element that is also in the stack of open elements, go to the step labeled rewind.
-        // FIXME: Advance: Let entry be the element one later than entry in the list of active formatting elements.
-        // FIXME: Create: Insert an HTML element for the token for which the element entry was created, to obtain new element.
-        // FIXME: Replace the entry for entry in the list with an entry for new element.
-        // FIXME: If the entry for new element in the list of active formatting elements is not the last entry in the list, return to the step labeled advance.
-    }
-
     //  https://html.spec.whatwg.org/multipage/parsing.html#push-onto-the-list-of-active-formatting-elements
     pub fn push_element(&self, element: NodeRef<'a>) {
         // FIXME: Implement Noah's Ark clause.
@@ -68,6 +47,27 @@ impl<'a> ListOfActiveFormattingElements<'a> {
             .push(ActiveFormattingElement::Element(element));
     }
 
+    // https://html.spec.whatwg.org/multipage/parsing.html#concept-parser-marker
+    pub fn push_marker(&self) {
+        self.elements
+            .borrow_mut()
+            .push(ActiveFormattingElement::Marker);
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#clear-the-list-of-active-formatting-elements-up-to-the-last-marker
+    pub fn clear_up_to_the_last_marker(&self) {
+        let mut elements = self.elements.borrow_mut();
+        // 1. Let entry be the last (most recently added) entry in the list of active formatting elements.
+        // 2. Remove entry from the list of active formatting elements.
+        // 3. If entry was a marker, then stop the algorithm at this point. The list has been cleared up to the last marker.
+        // 4. Go to step 1.
+        while let Some(entry) = elements.pop() {
+            if entry == ActiveFormattingElement::Marker {
+                break;
+            }
+        }
+    }
+
     pub fn first_index_of(&self, target: NodeRef<'a>) -> Option<usize> {
         self.elements
             .borrow()
@@ -76,13 +76,12 @@ impl<'a> ListOfActiveFormattingElements<'a> {
     }
 
     pub fn replace(&self, target: NodeRef<'a>, replacement: NodeRef<'a>) {
-        if let Some(index) = self
-            .elements
-            .borrow()
+        let mut elements = self.elements.borrow_mut();
+        if let Some(index) = elements
             .iter()
             .position(|e| *e == ActiveFormattingElement::Element(target))
         {
-            self.elements.borrow_mut()[index] = ActiveFormattingElement::Element(replacement);
+            elements[index] = ActiveFormattingElement::Element(replacement);
         }
     }
 
@@ -102,6 +101,14 @@ impl<'a> ListOfActiveFormattingElements<'a> {
         }
     }
 
+    pub fn entry(&self, index: usize) -> Option<ActiveFormattingElement<'a>> {
+        self.elements.borrow().get(index).copied()
+    }
+
+    pub fn replace_at(&self, index: usize, replacement: NodeRef<'a>) {
+        self.elements.borrow_mut()[index] = ActiveFormattingElement::Element(replacement);
+    }
+
     pub fn len(&self) -> usize {
         self.elements.borrow().len()
     }
@@ -123,40 +130,33 @@ impl<'a> ListOfActiveFormattingElements<'a> {
     pub fn contains(&self, target: NodeRef<'a>) -> bool {
         self.elements.borrow().iter().any(|element| {
             if let ActiveFormattingElement::Element(element) = element {
-                return *element == target;
+                return std::ptr::eq(*element, target);
             }
             false
         })
     }
 
     pub fn contains_element_between(&self, start: Position, end: Position, tag_name: &str) -> bool {
-        if let Some(start) = self.index_from_position(start) {
-            if let Some(end) = self.index_from_position(end) {
-                for i in start..end {
-                    if let Some(ActiveFormattingElement::Element(element)) =
-                        self.elements.borrow().get(i)
-                    {
-                        if element.is_element_with_one_of_tags(&[tag_name]) {
-                            return true;
-                        }
-                    }
-                }
-            }
-        }
-        false
+        let start = self.index_from_position(start);
+        let end = self.index_from_position(end);
+        self.elements.borrow()[start.min(end)..start.max(end)]
+            .iter()
+            .any(|element| {
+                matches!(element, ActiveFormattingElement::Element(element) if element.is_element_with_tag(tag_name))
+            })
     }
 
-    fn index_from_position(&self, position: Position) -> Option<usize> {
+    /// The index in the list that `position` refers to, where a range between two positions
+    /// includes the entry at the lower index but not the one at the higher index.
+    fn index_from_position(&self, position: Position) -> usize {
         match position {
-            Position::End => Some(self.len().saturating_sub(1)),
+            Position::End => self.len(),
             Position::LastMarkerOrElseStart => self
                 .elements
                 .borrow()
                 .iter()
-                .rev()
-                .enumerate()
-                .find(|(_, element)| matches!(element, ActiveFormattingElement::Marker))
-                .map(|element| element.0),
+                .rposition(|element| matches!(element, ActiveFormattingElement::Marker))
+                .map_or(0, |index| index + 1),
         }
     }
 }
@@ -1,4 +1,6 @@
-use super::Parser;
+use list_of_active_formatting_elements::ActiveFormattingElement;
+
+use super::{InsertionMode, Parser};
 
 mod creating_inserting;
 pub(crate) mod insertion_mode;
@@ -27,4 +29,207 @@ impl<'a> Parser<'a> {
             self.open_elements.pop();
         }
     }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#generate-all-implied-end-tags-thoroughly
+    pub(crate) fn generate_all_implied_end_tags_thoroughly(&'a self) {
+        // while the current node is a caption element, a colgroup element, a dd element, a dt element, an li element, an optgroup element, an option element, a p element, an rb element, an rp element, an rt element, an rtc element, a tbody element, a td element, a tfoot element, a th element, a thead element, or a tr element, the UA must pop the current node off the stack of open elements.
+        while self.current_node().is_element_with_one_of_tags(&[
+            "caption", "colgroup", "dd", "dt", "li", "optgroup", "option", "p", "rb", "rp", "rt",
+            "rtc", "tbody", "td", "tfoot", "th", "thead", "tr",
+        ]) {
+            self.open_elements.pop();
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#current-template-insertion-mode
+    pub(crate) fn current_template_insertion_mode(&self) -> Option<InsertionMode> {
+        self.template_insertion_modes.borrow().last().copied()
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#reconstruct-the-active-formatting-elements
+    pub(crate) fn reconstruct_the_active_formatting_elements(&'a self) {
+        let is_marker_or_open_element = |entry| match entry {
+            ActiveFormattingElement::Marker => true,
+            ActiveFormattingElement::Element(element) => self.open_elements.contains(element),
+        };
+
+        // 1. If there are no entries in the list of active formatting elements, then there is nothing to reconstruct; stop this algorithm.
+        let Some(last_index) = self.active_formatting_elements.len().checked_sub(1) else {
+            return;
+        };
+
+        // 2. If the last (most recently added) entry in the list of active formatting elements is a marker, or if it is an element that is in the stack of open elements, then there is nothing to reconstruct; stop this algorithm.
+        let entry_at = |index| {
+            self.active_formatting_elements
+                .entry(index)
+                .expect("index should be within the list of active formatting elements")
+        };
+        if is_marker_or_open_element(entry_at(last_index)) {
+            return;
+        }
+
+        // 3. Let entry be the last (most recently added) element in the list of active formatting elements.
+        let mut index = last_index;
+
+        // 4. Rewind: If there are no entries before entry in the list of active formatting elements, then jump to the step labeled create.
+        // 5. Let entry be the entry one earlier than entry in the list of active formatting elements.
+        // 6. If entry is neither a marker nor an element that is also in the stack of open elements, go to the step labeled rewind.
+        // 7. Advance: Let entry be the element one later than entry in the list of active formatting elements.
+        while index > 0 && !is_marker_or_open_element(entry_at(index - 1)) {
+            index -= 1;
+        }
+
+        for index in index..=last_index {
+            let ActiveFormattingElement::Element(element) = entry_at(index) else {
+                unreachable!("there are no markers after the entry we rewound to");
+            };
+
+            // 8. Create: Insert an HTML element for the token for which the element entry was created, to obtain new element.
+            let new_element = self.insert_html_element_for_the_token_that_created(element);
+
+            // 9. Replace the entry for entry in the list with an entry for new element.
+            self.active_formatting_elements
+                .replace_at(index, new_element);
+
+            // 10. If the entry for new element in the list of active formatting elements is not the last entry in the list, return to the step labeled advance.
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#reset-the-insertion-mode-appropriately
+    pub(crate) fn reset_the_insertion_mode_appropriately(&'a self) {
+        // 1. Let last be false.
+        let mut last = false;
+
+        // 2. Let node be the last node in the stack of open elements.
+        // 3. Loop: If node is the first node in the stack of open elements, then set last to true, and, if the parser was created as part of the HTML fragment parsing algorithm (fragment case), set node to the context element passed to that algorithm.
+        for index in self.open_elements.iter_indices().rev() {
+            let mut node = self
+                .open_elements
+                .get(index)
+                .expect("stack should not change while resetting the insertion mode");
+
+            if index == 0 {
+                last = true;
+                if let Some(context_element) = self.context_element.get() {
+                    node = context_element;
+                }
+            }
+
+            // 4. If node is a select element, run these substeps:
+            if node.is_html_element_with_tag("select") {
+                // 4.1. If last is true, jump to the step below labeled done.
+                if !last {
+                    // 4.2. Let ancestor be node.
+                    // 4.3. Loop: If ancestor is the first node in the stack of open elements, jump to the step below labeled done.
+                    // 4.4. Let ancestor be the node before ancestor in the stack of open elements.
+                    for ancestor_index in (0..index).rev() {
+                        let ancestor = self
+                            .open_elements
+                            .get(ancestor_index)
+                            .expect("stack should not change while resetting the insertion mode");
+
+                        // 4.5. If ancestor is a template node, jump to the step below labeled done.
+                        if ancestor.is_html_element_with_tag("template") {
+                            break;
+                        }
+
+                        // 4.6. If ancestor is a table node, switch the insertion mode to "in select in table" and return.
+                        if ancestor.is_html_element_with_tag("table") {
+                            self.switch_insertion_mode_to(InsertionMode::InSelectInTable);
+                            return;
+                        }
+
+                        // 4.7. Jump back to the step labeled loop.
+                    }
+                }
+
+                // 4.8. Done: Switch the insertion mode to "in select" and return.
+                self.switch_insertion_mode_to(InsertionMode::InSelect);
+                return;
+            }
+
+            // 5. If node is a td or th element and last is false, then switch the insertion mode to "in cell" and return.
+            if node.is_html_element_with_one_of_tags(&["td", "th"]) && !last {
+                self.switch_insertion_mode_to(InsertionMode::InCell);
+                return;
+            }
+
+            // 6. If node is a tr element, then switch the insertion mode to "in row" and return.
+            if node.is_html_element_with_tag("tr") {
+                self.switch_insertion_mode_to(InsertionMode::InRow);
+                return;
+            }
+
+            // 7. If node is a tbody, thead, or tfoot element, then switch the insertion mode to "in table body" and return.
+            if node.is_html_element_with_one_of_tags(&["tbody", "thead", "tfoot"]) {
+                self.switch_insertion_mode_to(InsertionMode::InTableBody);
+                return;
+            }
+
+            // 8. If node is a caption element, then switch the insertion mode to "in caption" and return.
+            if node.is_html_element_with_tag("caption") {
+                self.switch_insertion_mode_to(InsertionMode::InCaption);
+                return;
+            }
+
+            // 9. If node is a colgroup element, then switch the insertion mode to "in column group" and return.
+            if node.is_html_element_with_tag("colgroup") {
+                self.switch_insertion_mode_to(InsertionMode::InColumnGroup);
+                return;
+            }
+
+            // 10. If node is a table element, then switch the insertion mode to "in table" and return.
+            if node.is_html_element_with_tag("table") {
+                self.switch_insertion_mode_to(InsertionMode::InTable);
+                return;
+            }
+
+            // 11. If node is a template element, then switch the insertion mode to the current template insertion mode and return.
+            if node.is_html_element_with_tag("template") {
+                self.switch_insertion_mode_to(
+                    self.current_template_insertion_mode()
+                        .expect("there is a template insertion mode for every open template"),
+                );
+                return;
+            }
+
+            // 12. If node is a head element and last is false, then switch the insertion mode to "in head" and return.
+            if node.is_html_element_with_tag("head") && !last {
+                self.switch_insertion_mode_to(InsertionMode::InHead);
+                return;
+            }
+
+            // 13. If node is a body element, then switch the insertion mode to "in body" and return.
+            if node.is_element_with_tag("body") {
+                self.switch_insertion_mode_to(InsertionMode::InBody);
+                return;
+            }
+
+            // 14. If node is a frameset element, then switch the insertion mode to "in frameset" and return. (fragment case)
+            if node.is_element_with_tag("frameset") {
+                self.switch_insertion_mode_to(InsertionMode::InFrameset);
+                return;
+            }
+
+            // 15. If node is an html element, run these substeps:
+            if node.is_element_with_tag("html") {
+                match self.head_element.get() {
+                    // 15.1. If the head element pointer is null, switch the insertion mode to "before head" and return. (fragment case)
+                    None => self.switch_insertion_mode_to(InsertionMode::BeforeHead),
+                    // 15.2. Otherwise, the head element pointer is not null, switch the insertion mode to "after head" and return.
+                    Some(_) => self.switch_insertion_mode_to(InsertionMode::AfterHead),
+                }
+                return;
+            }
+
+            // 16. If last is true, then switch the insertion mode to "in body" and return. (fragment case)
+            if last {
+                self.switch_insertion_mode_to(InsertionMode::InBody);
+                return;
+            }
+
+            // 17. Let node now be the node before node in the stack of open elements.
+            // 18. Return to the step labeled loop.
+        }
+    }
 }
@@ -6,122 +6,6 @@ pub struct StackOfOpenElements<'a> {
     pub(crate) elements: RefCell<Vec<NodeRef<'a>>>,
 }
 
-pub(super) static SPECIAL_TAGS: &[&str] = &[
-    "address",
-    "applet",
-    "area",
-    "article",
-    "aside",
-    "base",
-    "basefont",
-    "bgsound",
-    "blockquote",
-    "body",
-    "br",
-    "button",
-    "caption",
-    "center",
-    "col",
-    "colgroup",
-    "dd",
-    "details",
-    "dir",
-    "div",
-    "dl",
-    "dt",
-    "embed",
-    "fieldset",
-    "figcaption",
-    "figure",
-    "footer",
-    "form",
-    "frame",
-    "frameset",
-    "h1",
-    "h2",
-    "h3",
-    "h4",
-    "h5",
-    "h6",
-    "head",
-    "header",
-    "hgroup",
-    "hr",
-    "html",
-    "iframe",
-    "img",
-    "input",
-    "keygen",
-    "li",
-    "link",
-    "listing",
-    "main",
-    "marquee",
-    "menu",
-    "meta",
-    "nav",
-    "noembed",
-    "noframes",
-    "noscript",
-    "object",
-    "ol",
-    "p",
-    "param",
-    "plaintext",
-    "pre",
-    "script",
-    "search",
-    "section",
-    "select",
-    "source",
-    "style",
-    "summary",
-    "table",
-    "tbody",
-    "td",
-    "template",
-    "textarea",
-    "tfoot",
-    "th",
-    "thead",
-    "title",
-    "tr",
-    "track",
-    "ul",
-    "wbr",
-    "xmp",
-    // FIXME: Implement MathML mi
-    // FIXME: Implement MathML mo
-    // FIXME: Implement MathML mn
-    // FIXME: Implement MathML ms
-    // FIXME: Implement MathML mtext
-    // FIXME: Implement MathML annotation-xml
-    // FIXME: Implement SVG foreignObject
-    // FIXME: Implement SVG desc
-    // FIXME: Implement SVG title
-];
-
-pub(super) static BASE_SCOPE_TAGS: &[&str] = &[
-    "applet",
-    "caption",
-    "html",
-    "table",
-    "td",
-    "th",
-    "marquee",
-    "object",
-    "template",
-    "mi",
-    "mo",
-    "mn",
-    "ms",
-    "mtext",
-    "annotation-xml",
-    "foreignObject",
-    "desc",
-    "title",
-];
-
 impl<'a> StackOfOpenElements<'a> {
     pub fn new() -> Self {
         Self {
@@ -134,10 +18,21 @@ impl<'a> StackOfOpenElements<'a> {
         self.elements.borrow().last().copied().expect("should always have a current node, as the parser will have finished after popping the last (html) element.")
     }
 
-    // https://html.spec.whatwg.org/#adjusted-current-node
-    pub fn adjusted_current_node(&self) -> NodeRef<'a> {
-        // FIXME: Implement
-        self.current_node()
+    pub fn len(&self) -> usize {
+        self.elements.borrow().len()
+    }
+
+    /// The element at `index`, where index 0 is the topmost (html) element.
+    pub fn get(&self, index: usize) -> Option<NodeRef<'a>> {
+        self.elements.borrow().get(index).copied()
+    }
+
+    /// The indices of the stack, from the topmost element to the current node.
+    ///
+    /// NOTE: Use this with [`Self::get`] to walk the stack without holding on to it, so that
+    ///       the stack can be changed while walking it. Indices past a change must be re-read.
+    pub fn iter_indices(&self) -> std::ops::Range<usize> {
+        0..self.len()
     }
 
     pub fn first(&self) -> Option<NodeRef<'a>> {
@@ -152,44 +47,106 @@ impl<'a> StackOfOpenElements<'a> {
         self.elements.borrow_mut().pop();
     }
 
-    pub fn pop_elements_until_element_with_tag_name_has_been_popped(&self, tag_name: &str) {
-        while !self.current_node().is_element_with_tag(tag_name) {
-            self.pop();
+    /// Pops elements from the stack until an HTML element with `tag_name` has been popped.
+    ///
+    /// NOTE: If there is no such element, the whole stack is popped.
+    pub fn pop_until_tag_name(&self, tag_name: &str) {
+        while let Some(element) = self.elements.borrow_mut().pop() {
+            if element.is_html_element_with_tag(tag_name) {
+                break;
+            }
         }
-        self.pop()
     }
 
     pub fn pop_elements_until_element_with_one_of_tag_names_has_been_popped(
         &self,
         tag_names: &[&str],
     ) {
-        while !self.current_node().is_element_with_one_of_tags(tag_names) {
+        while !self
+            .current_node()
+            .is_html_element_with_one_of_tags(tag_names)
+        {
             self.pop();
         }
         self.pop()
     }
 
     pub fn pop_elements_until_element_has_been_popped(&self, node: NodeRef<'a>) {
-        while self.current_node() != node {
+        while !std::ptr::eq(self.current_node(), node) {
             self.pop();
         }
         self.pop()
     }
 
+    /// The index and node of the last (most recently added) element with `tag_name`, if any.
+    pub fn last_element_with_tag_name(&self, tag_name: &str) -> Option<(usize, NodeRef<'a>)> {
+        self.elements
+            .borrow()
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, element)| element.is_html_element_with_tag(tag_name))
+            .map(|(index, element)| (index, *element))
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#clear-the-stack-back-to-a-table-context
+    pub fn clear_back_to_a_table_context(&self) {
+        // While the current node is not a table, template, or html element, pop elements from the stack of open elements.
+        self.pop_elements_until_current_node_is_one_of(&["table", "template", "html"]);
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#clear-the-stack-back-to-a-table-body-context
+    pub fn clear_back_to_a_table_body_context(&self) {
+        // While the current node is not a tbody, tfoot, thead, template, or html element, pop elements from the stack of open elements.
+        self.pop_elements_until_current_node_is_one_of(&[
+            "tbody", "tfoot", "thead", "template", "html",
+        ]);
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#clear-the-stack-back-to-a-table-row-context
+    pub fn clear_back_to_a_table_row_context(&self) {
+        // While the current node is not a tr, template, or html element, pop elements from the stack of open elements.
+        self.pop_elements_until_current_node_is_one_of(&["tr", "template", "html"]);
+    }
+
+    fn pop_elements_until_current_node_is_one_of(&self, tag_names: &[&str]) {
+        while !self
+            .current_node()
+            .is_html_element_with_one_of_tags(tag_names)
+        {
+            self.pop();
+        }
+    }
+
     pub fn insert_immediately_below(&self, element: NodeRef<'a>, target: NodeRef<'a>) {
-        if let Some(index) = self.elements.borrow().iter().position(|e| e == &target) {
+        let index = self
+            .elements
+            .borrow()
+            .iter()
+            .position(|e| std::ptr::eq(*e, target));
+        if let Some(index) = index {
             self.elements.borrow_mut().insert(index + 1, element);
         }
     }
 
     pub fn replace(&self, target: NodeRef<'a>, replacement: NodeRef<'a>) {
-        if let Some(index) = self.elements.borrow().iter().position(|e| e == &target) {
+        let index = self
+            .elements
+            .borrow()
+            .iter()
+            .position(|e| std::ptr::eq(*e, target));
+        if let Some(index) = index {
             self.elements.borrow_mut()[index] = replacement;
         }
     }
 
     pub fn remove_element(&self, element: NodeRef<'a>) {
-        if let Some(index) = self.elements.borrow().iter().position(|e| e == &element) {
+        let index = self
+            .elements
+            .borrow()
+            .iter()
+            .position(|e| std::ptr::eq(*e, element));
+        if let Some(index) = index {
             self.elements.borrow_mut().remove(index);
         }
     }
@@ -197,7 +154,7 @@ impl<'a> StackOfOpenElements<'a> {
     pub fn element_immediately_above(&self, target: NodeRef<'a>) -> Option<NodeRef<'a>> {
         let mut found = false;
         for element in self.elements.borrow().iter().rev() {
-            if *element == target {
+            if std::ptr::eq(*element, target) {
                 found = true;
             } else if found {
                 return Some(element);
@@ -209,10 +166,10 @@ impl<'a> StackOfOpenElements<'a> {
     pub fn topmost_special_node_below(&self, target: NodeRef<'a>) -> Option<NodeRef<'a>> {
         let mut best = None;
         for element in self.elements.borrow().iter().rev() {
-            if *element == target {
+            if std::ptr::eq(*element, target) {
                 break;
             }
-            if element.is_element_with_one_of_tags(SPECIAL_TAGS) {
+            if element.is_special_element() {
                 best = Some(*element);
             }
         }
@@ -223,14 +180,21 @@ impl<'a> StackOfOpenElements<'a> {
         self.elements
             .borrow()
             .iter()
-            .any(|element| *element == target)
+            .any(|e| std::ptr::eq(*e, target))
     }
 
     pub fn contains_one_of_tags(&self, tags: &[&str]) -> bool {
         self.elements
             .borrow()
             .iter()
-            .any(|node| node.is_element_with_one_of_tags(tags))
+            .any(|node| node.is_html_element_with_one_of_tags(tags))
+    }
+
+    pub fn contains_element_not_one_of_tags(&self, tags: &[&str]) -> bool {
+        self.elements
+            .borrow()
+            .iter()
+            .any(|node| !node.is_html_element_with_one_of_tags(tags))
     }
 
     pub fn is_empty(&self) -> bool {
@@ -242,15 +206,21 @@ impl<'a> StackOfOpenElements<'a> {
     }
 
     // https://html.spec.whatwg.org/multipage/parsing.html#has-an-element-in-the-specific-scope
-    fn has_tag_name_in_scope(&self, target: &str, list: &[&str]) -> bool {
+    // NOTE: `is_target` decides whether a node is the target node, and `is_in_list` whether it
+    //       is one of the element types in the list.
+    fn has_element_in_specific_scope(
+        &self,
+        is_target: impl Fn(NodeRef<'a>) -> bool,
+        is_in_list: impl Fn(NodeRef<'a>) -> bool,
+    ) -> bool {
         // 1. Initialize node to be the current node (the bottommost node of the stack).
         for node in self.elements.borrow().iter().rev() {
             // 2. If node is the target node, terminate in a match state.
-            if node.is_element_with_tag(target) {
+            if is_target(node) {
                 return true;
             }
             // 3. Otherwise, if node is one of the element types in list, terminate in a failure state.
-            if node.is_element_with_one_of_tags(list) {
+            if is_in_list(node) {
                 return false;
             }
             // 4. Otherwise, set node to the previous entry in the stack of open elements and return to step 2. (This will never fail, since the loop will always terminate in the previous step if the top of the stack — an html element — is reached.)
@@ -259,44 +229,66 @@ impl<'a> StackOfOpenElements<'a> {
         unreachable!();
     }
 
+    // https://html.spec.whatwg.org/multipage/parsing.html#has-an-element-in-scope
     pub fn has_element_in_scope(&self, target_node: NodeRef<'a>) -> bool {
-        // 1. Initialize node to be the current node (the bottommost node of the stack).
-        for node in self.elements.borrow().iter().rev() {
-            // 2. If node is the target node, terminate in a match state.
-            if *node == target_node {
-                return true;
-            }
-            // 3. Otherwise, if node is one of the element types in list, terminate in a failure state.
-            if node.is_element_with_one_of_tags(BASE_SCOPE_TAGS) {
-                return false;
-            }
-
-            // 4. Otherwise, set node to the previous entry in the stack of open elements and return to step 2. (This will never fail, since the loop will always terminate in the previous step if the top of the stack — an html element — is reached.)
-        }
-
-        unreachable!();
+        self.has_element_in_specific_scope(
+            |node| std::ptr::eq(node, target_node),
+            |node| node.is_scope_boundary_element(),
+        )
     }
 
     // https://html.spec.whatwg.org/multipage/parsing.html#has-an-element-in-scope
     pub fn has_element_with_tag_name_in_scope(&self, tag_name: &str) -> bool {
-        self.has_tag_name_in_scope(tag_name, BASE_SCOPE_TAGS)
+        self.has_element_with_one_of_tag_names_in_scope(&[tag_name])
     }
 
     pub fn has_element_with_one_of_tag_names_in_scope(&self, tag_names: &[&str]) -> bool {
-        self.elements
-            .borrow()
-            .iter()
-            .rev()
-            .any(|node| node.is_element_with_one_of_tags(tag_names))
+        self.has_element_in_specific_scope(
+            |node| node.is_html_element_with_one_of_tags(tag_names),
+            |node| node.is_scope_boundary_element(),
+        )
     }
 
     // https://html.spec.whatwg.org/#has-an-element-in-list-item-scope
     pub fn has_element_with_tag_name_in_list_item_scope(&self, tag_name: &str) -> bool {
-        self.has_tag_name_in_scope(tag_name, &[BASE_SCOPE_TAGS, &["ol", "ul"]].concat())
+        // The list of element types is the base list plus ol and ul in the HTML namespace.
+        self.has_element_in_specific_scope(
+            |node| node.is_html_element_with_tag(tag_name),
+            |node| {
+                node.is_scope_boundary_element()
+                    || node.is_html_element_with_one_of_tags(&["ol", "ul"])
+            },
+        )
     }
 
     // https://html.spec.whatwg.org/#has-an-element-in-button-scope
     pub fn has_element_with_tag_name_in_button_scope(&self, tag_name: &str) -> bool {
-        self.has_tag_name_in_scope(tag_name, &[BASE_SCOPE_TAGS, &["button"]].concat())
+        // The list of element types is the base list plus button in the HTML namespace.
+        self.has_element_in_specific_scope(
+            |node| node.is_html_element_with_tag(tag_name),
+            |node| node.is_scope_boundary_element() || node.is_html_element_with_tag("button"),
+        )
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#has-an-element-in-table-scope
+    pub fn has_element_with_tag_name_in_table_scope(&self, tag_name: &str) -> bool {
+        self.has_element_with_one_of_tag_names_in_table_scope(&[tag_name])
+    }
+
+    pub fn has_element_with_one_of_tag_names_in_table_scope(&self, tag_names: &[&str]) -> bool {
+        // The list of element types is html, table and template in the HTML namespace.
+        self.has_element_in_specific_scope(
+            |node| node.is_html_element_with_one_of_tags(tag_names),
+            |node| node.is_html_element_with_one_of_tags(&["html", "table", "template"]),
+        )
+    }
+
+    // https://html.spec.whatwg.org/#has-an-element-in-select-scope
+    pub fn has_element_with_tag_name_in_select_scope(&self, tag_name: &str) -> bool {
+        // This scope consists of all element types except the following: optgroup, option.
+        self.has_element_in_specific_scope(
+            |node| node.is_html_element_with_tag(tag_name),
+            |node| !node.is_html_element_with_one_of_tags(&["optgroup", "option"]),
+        )
     }
 }
@@ -0,0 +1,182 @@
+use crate::dom::node::NodeType;
+use crate::dom::{Attr, Node};
+use crate::html::{Atom, Namespace};
+
+use super::Parser;
+
+/// Receives the document as a stream of events instead of a DOM.
+///
+/// The events describe the tree after all tree construction fixups (implied tags, the adoption
+/// agency algorithm, foster parenting) have been applied, so every `start_element` is matched by
+/// exactly one `end_element` and the events are properly nested.
+pub trait TreeSink {
+    /// Identifies an element between its `start_element` and `end_element` events.
+    type Handle;
+
+    fn doctype(&mut self, name: &str, public_id: &str, system_id: &str);
+
+    fn start_element(
+        &mut self,
+        local_name: Atom,
+        namespace: Namespace,
+        attributes: &[Attr],
+    ) -> Self::Handle;
+
+    fn end_element(&mut self, handle: Self::Handle);
+
+    /// The data of a single text node. Adjacent character tokens are already merged.
+    fn text(&mut self, data: &str);
+
+    fn comment(&mut self, data: &str);
+}
+
+impl<'a> Parser<'a> {
+    /// Parses the input and reports the resulting document to `sink`.
+    ///
+    /// NOTE: Tree construction needs to look at and rearrange the nodes it has already inserted
+    ///       (e.g. the adoption agency algorithm moves elements that were already closed), so we
+    ///       still build the DOM internally and report it to the sink once parsing has stopped.
+    pub fn parse_with_sink<S: TreeSink>(&'a self, sink: &mut S) {
+        let document = self.parse();
+        for child in document.child_nodes().iter() {
+            report_node_to_sink(child, sink);
+        }
+    }
+}
+
+fn report_node_to_sink<S: TreeSink>(node: &Node, sink: &mut S) {
+    match &node.node_type {
+        NodeType::Element(element) => {
+            let handle = sink.start_element(
+                element.local_name(),
+                element.namespace(),
+                &element.attributes.borrow(),
+            );
+            // NOTE: The children of a template element live in its template contents.
+            let children = match element.content() {
+                Some(template_contents) => template_contents.child_nodes().clone(),
+                None => node.child_nodes().clone(),
+            };
+            for child in children {
+                report_node_to_sink(child, sink);
+            }
+            sink.end_element(handle);
+        }
+        NodeType::Text { data } => sink.text(&data.borrow()),
+        NodeType::Comment { data } => sink.comment(&data.borrow()),
+        NodeType::DocumentType {
+            name,
+            public_id,
+            system_id,
+        } => sink.doctype(name, public_id, system_id),
+        // NOTE: The parser never inserts any other kind of node.
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records every event as a string, and checks that each element is ended with its own
+    /// handle.
+    #[derive(Default)]
+    struct RecordingSink {
+        events: Vec<String>,
+        open_elements: Vec<usize>,
+        next_handle: usize,
+    }
+
+    impl TreeSink for RecordingSink {
+        type Handle = usize;
+
+        fn doctype(&mut self, name: &str, public_id: &str, system_id: &str) {
+            self.events
+                .push(format!("doctype {name} '{public_id}' '{system_id}'"));
+        }
+
+        fn start_element(
+            &mut self,
+            local_name: Atom,
+            namespace: Namespace,
+            attributes: &[Attr],
+        ) -> usize {
+            let mut event = match namespace {
+                Namespace::Html => local_name.as_str().to_string(),
+                _ => format!("{namespace:?} {}", local_name.as_str()),
+            };
+            for attr in attributes {
+                event.push_str(&format!(" {}={}", attr.name.as_str(), attr.value));
+            }
+            self.events.push(event);
+
+            let handle = self.next_handle;
+            self.next_handle += 1;
+            self.open_elements.push(handle);
+            handle
+        }
+
+        fn end_element(&mut self, handle: usize) {
+            assert_eq!(self.open_elements.pop(), Some(handle));
+            self.events.push("/".to_string());
+        }
+
+        fn text(&mut self, data: &str) {
+            self.events.push(format!("\"{data}\""));
+        }
+
+        fn comment(&mut self, data: &str) {
+            self.events.push(format!("<!--{data}-->"));
+        }
+    }
+
+    fn events(input: &str) -> Vec<String> {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let mut sink = RecordingSink::default();
+        parser.parse_with_sink(&mut sink);
+        assert!(sink.open_elements.is_empty());
+        sink.events
+    }
+
+    #[test]
+    fn misnested_formatting_element() {
+        assert_eq!(
+            events("<b><p></b>"),
+            ["html", "head", "/", "body", "b", "/", "p", "b", "/", "/", "/", "/"]
+        );
+    }
+
+    #[test]
+    fn doctype_comments_text_and_attributes() {
+        assert_eq!(
+            events("<!DOCTYPE html><!--a--><p id=x>one<br>two"),
+            [
+                "doctype html '' ''",
+                "<!--a-->",
+                "html",
+                "head",
+                "/",
+                "body",
+                "p id=x",
+                "\"one\"",
+                "br",
+                "/",
+                "\"two\"",
+                "/",
+                "/",
+                "/",
+            ]
+        );
+    }
+
+    #[test]
+    fn foreign_elements_and_template_contents() {
+        assert_eq!(
+            events("<template><i>a</i></template><svg><g/></svg>"),
+            [
+                "html", "head", "template", "i", "\"a\"", "/", "/", "/", "body", "Svg svg",
+                "Svg g", "/", "/", "/", "/",
+            ]
+        );
+    }
+}
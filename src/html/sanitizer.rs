@@ -0,0 +1,357 @@
+use std::collections::HashSet;
+
+use crate::dom::node::NodeType;
+use crate::dom::{Attr, Node, NodeRef};
+use crate::html::parser::Parser;
+use crate::html::Namespace;
+use crate::local_name;
+use crate::url;
+
+/// Decides which parts of the input [`sanitize`] keeps.
+///
+/// Everything that is not explicitly allowed is dropped. Comments, doctypes and event handler
+/// attributes (`on*`) are always dropped, whatever the policy says.
+#[derive(Debug, Clone)]
+pub struct SanitizePolicy {
+    /// Local names of the HTML elements that are kept. Elements in other namespaces (SVG and
+    /// MathML) are never kept.
+    pub allowed_elements: HashSet<String>,
+    /// Names of the attributes that are kept on allowed elements.
+    pub allowed_attributes: HashSet<String>,
+    /// Schemes (in lowercase, without the colon) that URL attributes like `href` and `src` may
+    /// use. Relative URLs are always allowed.
+    pub allowed_url_schemes: HashSet<String>,
+    /// Local names of the elements that are dropped together with their contents. Any other
+    /// element that is not allowed is replaced by its (sanitized) children.
+    pub removed_with_contents: HashSet<String>,
+}
+
+impl Default for SanitizePolicy {
+    fn default() -> Self {
+        fn set(names: &[&str]) -> HashSet<String> {
+            names.iter().map(|name| name.to_string()).collect()
+        }
+
+        Self {
+            allowed_elements: set(&[
+                "a",
+                "abbr",
+                "b",
+                "blockquote",
+                "br",
+                "caption",
+                "code",
+                "dd",
+                "del",
+                "div",
+                "dl",
+                "dt",
+                "em",
+                "h1",
+                "h2",
+                "h3",
+                "h4",
+                "h5",
+                "h6",
+                "hr",
+                "i",
+                "img",
+                "ins",
+                "kbd",
+                "li",
+                "ol",
+                "p",
+                "pre",
+                "q",
+                "s",
+                "small",
+                "span",
+                "strong",
+                "sub",
+                "sup",
+                "table",
+                "tbody",
+                "td",
+                "tfoot",
+                "th",
+                "thead",
+                "tr",
+                "u",
+                "ul",
+            ]),
+            allowed_attributes: set(&[
+                "alt", "cite", "colspan", "dir", "height", "href", "lang", "rowspan", "src",
+                "title", "width",
+            ]),
+            allowed_url_schemes: set(&["http", "https", "mailto"]),
+            // NOTE: Besides the obviously dangerous elements, this contains every element whose
+            //       contents are parsed as raw text in some context (like `noscript` with
+            //       scripting enabled), as unwrapping those would turn markup that was inert
+            //       text into real elements.
+            removed_with_contents: set(&[
+                "embed",
+                "iframe",
+                "math",
+                "noembed",
+                "noframes",
+                "noscript",
+                "object",
+                "plaintext",
+                "script",
+                "style",
+                "svg",
+                "template",
+                "textarea",
+                "title",
+                "xmp",
+            ]),
+        }
+    }
+}
+
+/// Attributes whose value is a URL, and thus have to use an allowed scheme.
+const URL_ATTRIBUTES: &[&str] = &[
+    "action",
+    "background",
+    "cite",
+    "formaction",
+    "href",
+    "longdesc",
+    "poster",
+    "src",
+    "xlink:href",
+];
+
+/// Parses `input` as the contents of a `body` element, drops everything `policy` does not allow
+/// and serializes the result back to HTML.
+///
+/// Because the input goes through the real parser, the output is what a browser would see:
+/// misnested and unclosed tags are fixed up before any filtering happens.
+pub fn sanitize(input: &str, policy: &SanitizePolicy) -> String {
+    let arena = typed_arena::Arena::new();
+    let parser = Parser::new(arena, input);
+    let nodes = parser.parse_fragment(local_name!("body"));
+
+    // NOTE: Unwrapping an element moves its children up into the parent, so we sanitize the
+    //       children of the fragment's root instead of the list of nodes we got back.
+    let Some(root) = nodes.first().and_then(|node| node.parent_node()) else {
+        return String::new();
+    };
+    sanitize_children(root, policy);
+
+    let children = root.child_nodes();
+    children
+        .iter()
+        .map(|child| child.serialize_html())
+        .collect()
+}
+
+/// Sanitizes `node` in place, removing it from its parent if the policy does not allow it.
+fn sanitize_node(node: NodeRef, policy: &SanitizePolicy) {
+    match &node.node_type {
+        NodeType::Text { .. } => {}
+        NodeType::Element(element) => {
+            let local_name = element.local_name();
+
+            if element.namespace() == Namespace::Html
+                && policy.allowed_elements.contains(local_name.as_str())
+            {
+                element
+                    .attributes
+                    .borrow_mut()
+                    .retain(|attr| attribute_is_allowed(attr, policy));
+
+                sanitize_children(node, policy);
+                // NOTE: The contents of a template are serialized instead of its children.
+                if let Some(template_contents) = element.content() {
+                    sanitize_children(template_contents, policy);
+                }
+                return;
+            }
+
+            if !policy.removed_with_contents.contains(local_name.as_str()) {
+                // Replace the element with its children.
+                sanitize_children(node, policy);
+                let parent = node
+                    .parent_node()
+                    .expect("the parser only returns nodes that have a parent");
+                let children = node.child_nodes().clone();
+                for child in children {
+                    Node::remove_node(child, false);
+                    Node::insert(child, parent, Some(node), false);
+                }
+            }
+
+            Node::remove_node(node, false);
+        }
+        _ => Node::remove_node(node, false),
+    }
+}
+
+fn sanitize_children(parent: NodeRef, policy: &SanitizePolicy) {
+    let children = parent.child_nodes().clone();
+    for child in children {
+        sanitize_node(child, policy);
+    }
+}
+
+fn attribute_is_allowed(attr: &Attr, policy: &SanitizePolicy) -> bool {
+    let name = attr.qualified_name();
+    if name.starts_with("on") || !policy.allowed_attributes.contains(name.as_str()) {
+        return false;
+    }
+
+    if URL_ATTRIBUTES.contains(&name.as_str()) {
+        return match url::scheme(&attr.value) {
+            Some(scheme) => policy.allowed_url_schemes.contains(&scheme),
+            None => true,
+        };
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize_default(input: &str) -> String {
+        let output = sanitize(input, &SanitizePolicy::default());
+        // NOTE: Sanitized output that changes when it is parsed again could be turned into
+        //       something the policy doesn't allow by the browser that parses it.
+        assert_eq!(
+            sanitize(&output, &SanitizePolicy::default()),
+            output,
+            "sanitizing {input:?} again changed the output"
+        );
+        output
+    }
+
+    #[test]
+    fn event_handler_attributes() {
+        assert_eq!(
+            sanitize_default("<img src=x onerror=alert(1) ONLOAD=y>"),
+            r#"<img src="x">"#
+        );
+        assert_eq!(
+            sanitize_default(r#"<div onclick="x" title=t>a</div>"#),
+            r#"<div title="t">a</div>"#
+        );
+        assert_eq!(sanitize_default("<div =onclick>a</div>"), "<div>a</div>");
+    }
+
+    #[test]
+    fn javascript_urls() {
+        for input in [
+            r#"<a href="javascript:alert(1)">x</a>"#,
+            r#"<a href="JaVaScRiPt:alert(1)">x</a>"#,
+            r#"<a href="  javascript:alert(1)">x</a>"#,
+            r#"<a href="&#106;avascript:alert(1)">x</a>"#,
+            r#"<a href="&#0000106&#0000097vascript:alert(1)">x</a>"#,
+            r#"<a href="&#x6A;avascript:alert(1)">x</a>"#,
+            r#"<a href="java&#x09;script:alert(1)">x</a>"#,
+            r#"<a href="&#x09;javascript:alert(1)">x</a>"#,
+        ] {
+            assert_eq!(sanitize_default(input), "<a>x</a>", "{input}");
+        }
+        assert_eq!(
+            sanitize_default(r#"<a href="https://example.com/">x</a>"#),
+            r#"<a href="https://example.com/">x</a>"#
+        );
+    }
+
+    #[test]
+    fn foreign_content_is_removed() {
+        assert_eq!(
+            sanitize_default(
+                "<svg><script>alert(1)</script><a xlink:href='javascript:x'>s</a></svg>t"
+            ),
+            "t"
+        );
+        assert_eq!(
+            sanitize_default("<svg><![CDATA[</svg><img src=x onerror=alert(1)>]]></svg>"),
+            ""
+        );
+        assert_eq!(sanitize_default("<math><mtext>x</mtext></math>y"), "y");
+        // NOTE: mi is a MathML text integration point, so the b element stays inside the math
+        //       element and is removed with it.
+        assert_eq!(sanitize_default("<math><mi><b>x</b></mi></math>y"), "y");
+    }
+
+    #[test]
+    fn html_in_integration_points_is_removed_with_the_foreign_element() {
+        assert_eq!(
+            sanitize_default(
+                "<svg><foreignObject><p onclick=x>a</p><img src=x onerror=y></foreignObject></svg>z"
+            ),
+            "z"
+        );
+        assert_eq!(
+            sanitize_default(
+                "<math><annotation-xml encoding=\"text/html\"><p>a</p><script>x</script></annotation-xml></math>z"
+            ),
+            "z"
+        );
+        assert_eq!(
+            sanitize_default("<svg><desc><b>a</b></desc></svg><i>b</i>"),
+            "<i>b</i>"
+        );
+        // NOTE: This is a well-known mutation XSS vector: the style element is parsed as MathML
+        //       inside mglyph, so its contents are markup instead of text.
+        assert_eq!(
+            sanitize_default("<math><mtext><table><mglyph><style><img src=x onerror=y>"),
+            ""
+        );
+    }
+
+    #[test]
+    fn script_and_template_contents_are_removed() {
+        assert_eq!(sanitize_default("<script>alert(1)</script>z"), "z");
+        assert_eq!(
+            sanitize_default("<template><img src=x onerror=alert(1)></template>w"),
+            "w"
+        );
+        assert_eq!(
+            sanitize_default("<noscript><img src=x onerror=y></noscript>n"),
+            "n"
+        );
+        assert_eq!(sanitize_default("<xmp><img src=x onerror=y></xmp>"), "");
+    }
+
+    #[test]
+    fn misnested_formatting_elements() {
+        assert_eq!(
+            sanitize_default("<b><i>a</b>c</i>"),
+            "<b><i>a</i></b><i>c</i>"
+        );
+        assert_eq!(
+            sanitize_default("<p><b>a<p>b</b>"),
+            "<p><b>a</b></p><p><b>b</b></p>"
+        );
+        assert_eq!(
+            sanitize_default("<a href=x><div><a href=javascript:y>q</a></div></a>"),
+            r#"<a href="x"></a><div><a href="x"></a><a>q</a></div>"#
+        );
+    }
+
+    #[test]
+    fn table_parts() {
+        assert_eq!(
+            sanitize_default("<table><caption>x</caption></table>"),
+            "<table><caption>x</caption></table>"
+        );
+        assert_eq!(
+            sanitize_default("<table><colgroup><col></table>"),
+            "<table></table>"
+        );
+    }
+
+    #[test]
+    fn input_that_used_to_panic() {
+        assert_eq!(sanitize_default("&#x09;"), "\t");
+        assert_eq!(sanitize_default("<frameset><frame src=x></frameset>"), "");
+        assert_eq!(sanitize_default("<object><param name=a></object>o"), "o");
+        assert_eq!(sanitize_default("<div"), "");
+        assert_eq!(sanitize_default("<![CDATA[x]]>y"), "y");
+    }
+}
@@ -0,0 +1,439 @@
+use crate::css::display::user_agent_display;
+use crate::dom::node::NodeType;
+use crate::dom::{Attr, Node, NodeRef};
+use crate::html::{elements, Atom, Namespace};
+
+impl<'a> Node<'a> {
+    /// Serializes this node and its descendants.
+    ///
+    /// https://html.spec.whatwg.org/multipage/parsing.html#serialising-html-fragments
+    pub fn serialize_html(&self) -> String {
+        let mut s = String::new();
+        serialize_node(self, &mut s);
+        s
+    }
+
+    /// Serializes this node and its descendants like [`Node::serialize_html`], but with every
+    /// block-level element on its own line, indented by `indent` spaces per level of nesting.
+    ///
+    /// Inline content and elements that preserve whitespace (like `<pre>`) are kept on a single
+    /// line as they are. Whitespace-only text between block-level elements is dropped, so the
+    /// output is meant for debugging and does not round-trip.
+    pub fn serialize_html_pretty(&self, indent: usize) -> String {
+        let mut lines = vec![];
+        pretty_print_node(self, indent, 0, &mut lines);
+        lines.join("\n")
+    }
+}
+
+/// A step of serializing a node.
+enum SerializeStep<'n, 'a> {
+    /// Serialize the node and its descendants.
+    Node(&'n Node<'a>),
+    /// Append the end tag for an element whose children have been serialized.
+    EndTag(Atom),
+}
+
+/// Serializes `node` and its descendants.
+///
+/// NOTE: The steps that are left are kept on an explicit stack instead of recursing, so
+///       arbitrarily deep trees don't overflow the call stack.
+fn serialize_node(node: &Node, s: &mut String) {
+    let mut steps = vec![SerializeStep::Node(node)];
+    while let Some(step) = steps.pop() {
+        match step {
+            SerializeStep::Node(node) => serialize_single_node(node, s, &mut steps),
+            SerializeStep::EndTag(tag_name) => {
+                s.push_str("</");
+                s.push_str(&tag_name);
+                s.push('>');
+            }
+        }
+    }
+}
+
+// SPEC: If current node is an element in the HTML namespace, the MathML namespace, or the SVG
+//       namespace, then let tagname be current node's local name.
+/// Serializes `node` itself, and pushes the steps to serialize what comes after it onto `steps`.
+fn serialize_single_node<'n, 'a>(
+    node: &'n Node<'a>,
+    s: &mut String,
+    steps: &mut Vec<SerializeStep<'n, 'a>>,
+) {
+    match &node.node_type {
+        NodeType::Element(element) => {
+            let tag_name = element.local_name();
+
+            // SPEC: Append a U+003C LESS-THAN SIGN character (<), followed by tagname.
+            s.push('<');
+            s.push_str(&tag_name);
+
+            // SPEC: For each attribute that the element has, append a U+0020 SPACE character, the
+            //       attribute's serialized name, a U+003D EQUALS SIGN character (=), a U+0022
+            //       QUOTATION MARK character ("), the attribute's value, escaped as described
+            //       below in attribute mode, and a second U+0022 QUOTATION MARK character (").
+            for attr in element.attributes.borrow().iter() {
+                s.push(' ');
+                s.push_str(&serialized_attribute_name(attr));
+                s.push_str("=\"");
+                escape_string(&attr.value, true, s);
+                s.push('"');
+            }
+
+            // SPEC: Append a U+003E GREATER-THAN SIGN character (>).
+            s.push('>');
+
+            // SPEC: If current node serializes as void, then continue on to the next child node
+            //       at this point.
+            if serializes_as_void(node) {
+                return;
+            }
+
+            // SPEC: Append the value of running the HTML fragment serialization algorithm on the
+            //       current node element, followed by a U+003C LESS-THAN SIGN character (<), a
+            //       U+002F SOLIDUS character (/), tagname again, and finally a U+003E GREATER-THAN
+            //       SIGN character (>).
+            steps.push(SerializeStep::EndTag(tag_name));
+            push_children(node, steps);
+        }
+        NodeType::Text { data } => {
+            // SPEC: If the parent of current node is a style, script, xmp, iframe, noembed,
+            //       noframes, or plaintext element, or if the parent of current node is a noscript
+            //       element and scripting is enabled for the node, then append the value of
+            //       current node's data IDL attribute literally.
+            // NOTE: Scripting is never enabled, so noscript is not in this list.
+            let parent_is_raw_text = node.parent_node().is_some_and(|parent| {
+                parent.is_element_with_namespace(Namespace::Html)
+                    && parent.is_element_with_one_of_tags(&[
+                        "style",
+                        "script",
+                        "xmp",
+                        "iframe",
+                        "noembed",
+                        "noframes",
+                        "plaintext",
+                    ])
+            });
+
+            match parent_is_raw_text {
+                true => s.push_str(&data.borrow()),
+                // SPEC: Otherwise, append the value of current node's data IDL attribute,
+                //       escaped as described below.
+                false => escape_string(&data.borrow(), false, s),
+            }
+        }
+        NodeType::Comment { data } => {
+            // SPEC: Append the literal string "<!--" (U+003C LESS-THAN SIGN, U+0021 EXCLAMATION
+            //       MARK, U+002D HYPHEN-MINUS, U+002D HYPHEN-MINUS), followed by the value of
+            //       current node's data IDL attribute, followed by the literal string "-->"
+            //       (U+002D HYPHEN-MINUS, U+002D HYPHEN-MINUS, U+003E GREATER-THAN SIGN).
+            s.push_str("<!--");
+            s.push_str(&data.borrow());
+            s.push_str("-->");
+        }
+        NodeType::DocumentType { name, .. } => {
+            // SPEC: Append the literal string "<!DOCTYPE" (U+003C LESS-THAN SIGN, U+0021
+            //       EXCLAMATION MARK, U+0044 LATIN CAPITAL LETTER D, U+004F LATIN CAPITAL LETTER
+            //       O, U+0043 LATIN CAPITAL LETTER C, U+0054 LATIN CAPITAL LETTER T, U+0059 LATIN
+            //       CAPITAL LETTER Y, U+0050 LATIN CAPITAL LETTER P, U+0045 LATIN CAPITAL LETTER
+            //       E), followed by a space (U+0020 SPACE), followed by the value of current
+            //       node's name IDL attribute, followed by the literal string ">" (U+003E
+            //       GREATER-THAN SIGN).
+            s.push_str("<!DOCTYPE ");
+            s.push_str(name);
+            s.push('>');
+        }
+        NodeType::Document(_) | NodeType::DocumentFragment => push_children(node, steps),
+        // FIXME: Serialize processing instructions once we have them in the DOM.
+        _ => {}
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/parsing.html#html-fragment-serialisation-algorithm
+fn push_children<'n, 'a>(node: &'n Node<'a>, steps: &mut Vec<SerializeStep<'n, 'a>>) {
+    // NOTE: The last step is taken first, so the children are pushed in reverse.
+    for child in children_to_serialize(node).into_iter().rev() {
+        steps.push(SerializeStep::Node(child));
+    }
+}
+
+fn children_to_serialize<'a>(node: &Node<'a>) -> Vec<NodeRef<'a>> {
+    // SPEC: If the node is a template element, then let the node instead be the template
+    //       element's template contents (a DocumentFragment node).
+    match node.as_element().and_then(|element| element.content()) {
+        Some(template_contents) => template_contents.child_nodes().clone(),
+        None => node.child_nodes().clone(),
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/parsing.html#serializes-as-void
+fn serializes_as_void(node: &Node) -> bool {
+    // SPEC: For the purposes of the following algorithm, an element serializes as void if its
+    //       element type is one of the void elements, or is basefont, bgsound, frame, keygen, or
+    //       param.
+    node.is_element_with_namespace(Namespace::Html)
+        && node
+            .element_tag_name()
+            .is_some_and(|name| elements::serializes_as_void(&name))
+}
+
+/// https://html.spec.whatwg.org/multipage/parsing.html#attribute's-serialized-name
+fn serialized_attribute_name(attr: &Attr) -> String {
+    match attr.namespace {
+        // SPEC: If the attribute has no namespace
+        //          The attribute's serialized name is the attribute's local name.
+        None => attr.name.to_string(),
+        // SPEC: If the attribute is in the XML namespace
+        //          The attribute's serialized name is the string "xml:" followed by the
+        //          attribute's local name.
+        Some(Namespace::Xml) => format!("xml:{}", attr.name),
+        // SPEC: If the attribute is in the XMLNS namespace and the attribute's local name is
+        //       xmlns
+        //          The attribute's serialized name is the string "xmlns".
+        Some(Namespace::XmlNs) if attr.name == "xmlns" => "xmlns".to_string(),
+        // SPEC: If the attribute is in the XMLNS namespace and the attribute's local name is not
+        //       xmlns
+        //          The attribute's serialized name is the string "xmlns:" followed by the
+        //          attribute's local name.
+        Some(Namespace::XmlNs) => format!("xmlns:{}", attr.name),
+        // SPEC: If the attribute is in the XLink namespace
+        //          The attribute's serialized name is the string "xlink:" followed by the
+        //          attribute's local name.
+        Some(Namespace::XLink) => format!("xlink:{}", attr.name),
+        // SPEC: If the attribute is in some other namespace
+        //          The attribute's serialized name is the attribute's qualified name.
+        Some(_) => attr.qualified_name(),
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/parsing.html#escapingString
+fn escape_string(string: &str, attribute_mode: bool, s: &mut String) {
+    for c in string.chars() {
+        match c {
+            // SPEC: Replace any occurrence of the "&" character by the string "&amp;".
+            '&' => s.push_str("&amp;"),
+            // SPEC: Replace any occurrences of the U+00A0 NO-BREAK SPACE character by the string
+            //       "&nbsp;".
+            '\u{00A0}' => s.push_str("&nbsp;"),
+            // SPEC: If the algorithm was invoked in the attribute mode, then replace any
+            //       occurrences of the """ character by the string "&quot;".
+            '"' if attribute_mode => s.push_str("&quot;"),
+            // SPEC: If the algorithm was not invoked in the attribute mode, then replace any
+            //       occurrences of the "<" character by the string "&lt;", and any occurrences of
+            //       the ">" character by the string "&gt;".
+            '<' if !attribute_mode => s.push_str("&lt;"),
+            '>' if !attribute_mode => s.push_str("&gt;"),
+            c => s.push(c),
+        }
+    }
+}
+
+fn pretty_print_node(node: &Node, indent: usize, depth: usize, lines: &mut Vec<String>) {
+    let indentation = " ".repeat(indent * depth);
+
+    match &node.node_type {
+        NodeType::Document(_) | NodeType::DocumentFragment => {
+            pretty_print_children(node, indent, depth, lines);
+        }
+        NodeType::Element(element)
+            if is_block_in_pretty_output(node) && !preserves_whitespace(node) =>
+        {
+            let children = children_to_serialize(node);
+            if !children
+                .iter()
+                .any(|child| is_block_in_pretty_output(child))
+            {
+                lines.push(format!("{indentation}{}", node.serialize_html()));
+                return;
+            }
+
+            let mut open_tag = node.serialize_html();
+            // NOTE: Elements with block-level children never serialize as void, so the
+            //       serialization always starts with the full start tag.
+            open_tag.truncate(open_tag.find('>').map_or(open_tag.len(), |end| end + 1));
+            lines.push(format!("{indentation}{open_tag}"));
+            pretty_print_children(node, indent, depth + 1, lines);
+            lines.push(format!("{indentation}</{}>", element.local_name()));
+        }
+        _ => {
+            let serialized = node.serialize_html();
+            if !serialized.trim().is_empty() {
+                lines.push(format!("{indentation}{}", serialized.trim()));
+            }
+        }
+    }
+}
+
+/// Puts every block child on its own line and groups runs of inline children onto a single line.
+fn pretty_print_children(node: &Node, indent: usize, depth: usize, lines: &mut Vec<String>) {
+    let indentation = " ".repeat(indent * depth);
+    let mut inline_run = String::new();
+
+    let flush_inline_run = |inline_run: &mut String, lines: &mut Vec<String>| {
+        let trimmed = inline_run.trim();
+        if !trimmed.is_empty() {
+            lines.push(format!("{indentation}{trimmed}"));
+        }
+        inline_run.clear();
+    };
+
+    for child in children_to_serialize(node) {
+        if is_block_in_pretty_output(child) {
+            flush_inline_run(&mut inline_run, lines);
+            pretty_print_node(child, indent, depth, lines);
+        } else {
+            serialize_node(child, &mut inline_run);
+        }
+    }
+    flush_inline_run(&mut inline_run, lines);
+}
+
+/// Whether `node` gets its own line in the pretty output.
+///
+/// NOTE: Elements that are not rendered (like `<head>` and `<script>`) are not inline either,
+///       so they get their own line too.
+fn is_block_in_pretty_output(node: &Node) -> bool {
+    match &node.node_type {
+        NodeType::Element(element) => {
+            element.namespace() != Namespace::Html
+                || !user_agent_display(&element.local_name(), element.namespace()).is_inline_level()
+        }
+        NodeType::DocumentType { .. } => true,
+        _ => false,
+    }
+}
+
+fn preserves_whitespace(node: &Node) -> bool {
+    node.is_element_with_namespace(Namespace::Html)
+        && node.is_element_with_one_of_tags(&[
+            "pre",
+            "listing",
+            "textarea",
+            "plaintext",
+            "xmp",
+            "script",
+            "style",
+        ])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dom::DomException;
+    use crate::html::parser::Parser;
+    use crate::html::Namespace;
+
+    fn pretty(input: &str) -> String {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let body = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("body"))
+            .unwrap();
+        body.serialize_html_pretty(2)
+    }
+
+    #[test]
+    fn block_children_get_their_own_indented_line() {
+        assert_eq!(
+            pretty("<ul><li>a</li><li>b</li></ul>"),
+            "<body>\n  <ul>\n    <li>a</li>\n    <li>b</li>\n  </ul>\n</body>"
+        );
+    }
+
+    #[test]
+    fn inline_content_stays_on_one_line() {
+        assert_eq!(
+            pretty("<div class=x>\n  a <b>b</b> <i>c</i>\n  <p>d <em>e</em></p>f</div>"),
+            "<body>\n  <div class=\"x\">\n    a <b>b</b> <i>c</i>\n    <p>d <em>e</em></p>\n    f\n  </div>\n</body>"
+        );
+    }
+
+    #[test]
+    fn preformatted_content_is_kept_as_is() {
+        assert_eq!(
+            pretty("<div><pre>a\n  <p>b</p></pre></div>"),
+            "<body>\n  <div>\n    <pre>a\n  <p>b</p></pre>\n  </div>\n</body>"
+        );
+    }
+
+    #[test]
+    fn doctype() {
+        use crate::dom::node::NodeType;
+
+        let doctype = |input: &str| {
+            let parser = Parser::new(typed_arena::Arena::new(), input);
+            let document = parser.parse();
+            let first_child = document
+                .first_child()
+                .expect("document should have children");
+            let ids = match &first_child.node_type {
+                NodeType::DocumentType {
+                    name,
+                    public_id,
+                    system_id,
+                } => (name.clone(), public_id.clone(), system_id.clone()),
+                node_type => panic!("expected a DocumentType, got {node_type:?}"),
+            };
+            (ids, document.serialize_html())
+        };
+
+        assert_eq!(
+            doctype("<!DOCTYPE html>"),
+            (
+                ("html".to_string(), String::new(), String::new()),
+                "<!DOCTYPE html><html><head></head><body></body></html>".to_string()
+            )
+        );
+
+        // NOTE: Only the name is serialized.
+        let ((name, public_id, system_id), html) = doctype(
+            "<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd\">",
+        );
+        assert_eq!(name, "html");
+        assert_eq!(public_id, "-//W3C//DTD HTML 4.01//EN");
+        assert_eq!(system_id, "http://www.w3.org/TR/html4/strict.dtd");
+        assert!(html.starts_with("<!DOCTYPE html><html>"));
+    }
+
+    #[test]
+    fn namespaced_attributes_keep_their_prefix() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<svg><use xlink:href=#a></use></svg>",
+        );
+        let document = parser.parse();
+        let svg = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("svg"))
+            .unwrap();
+        assert_eq!(
+            svg.serialize_html(),
+            "<svg><use xlink:href=\"#a\"></use></svg>"
+        );
+
+        let element = svg.as_element().unwrap();
+        element
+            .set_attribute_ns(Some(Namespace::XLink), "xlink:href", "#b")
+            .unwrap();
+        element
+            .set_attribute_ns(Some(Namespace::Svg), "foo:bar", "c")
+            .unwrap();
+        // NOTE: Setting the same namespace and local name again replaces the value.
+        element
+            .set_attribute_ns(Some(Namespace::XLink), "xlink:href", "#c")
+            .unwrap();
+        assert_eq!(
+            svg.serialize_html(),
+            "<svg xlink:href=\"#c\" foo:bar=\"c\"><use xlink:href=\"#a\"></use></svg>"
+        );
+
+        assert_eq!(
+            element.set_attribute_ns(None, "xlink:href", "#d"),
+            Err(DomException::NamespaceError)
+        );
+        assert_eq!(
+            element.set_attribute_ns(Some(Namespace::XLink), "xml:lang", "en"),
+            Err(DomException::NamespaceError)
+        );
+    }
+}
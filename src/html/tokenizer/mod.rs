@@ -1,8 +1,17 @@
+#![allow(clippy::manual_range_patterns)]
+
 use std::cell::Cell;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::html::parser::log_parser_error;
+use crate::html::{Atom, Namespace};
+use crate::local_name;
 
-use ncr::NamedCharacterReference;
+pub use parse_error::{ParseError, ParseErrorKind};
 
 mod ncr;
+mod parse_error;
 
 include!("macros.rs");
 
@@ -102,7 +111,7 @@ pub enum State {
     NumericCharacterReferenceEnd,
 }
 
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub enum Token {
     Doctype {
         name: Option<String>,
@@ -111,13 +120,19 @@ pub enum Token {
         force_quirks: bool,
     },
     StartTag {
-        name: String,
+        name: Atom,
+        /// The tag name as it was spelled in the input, if [`TokenizerOptions::preserve_case`]
+        /// is set.
+        original_name: Option<String>,
         self_closing: bool,
         self_closing_acknowledged: Cell<bool>,
         attributes: Vec<Attribute>,
     },
     EndTag {
-        name: String,
+        name: Atom,
+        /// The tag name as it was spelled in the input, if [`TokenizerOptions::preserve_case`]
+        /// is set.
+        original_name: Option<String>,
         self_closing: bool,
         attributes: Vec<Attribute>,
     },
@@ -130,6 +145,82 @@ pub enum Token {
     EndOfFile,
 }
 
+/// NOTE: Whether the self-closing flag of a start tag has been acknowledged is bookkeeping for the
+///       tree construction stage, not part of the token, so it is ignored when comparing tokens.
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Token::Doctype {
+                    name,
+                    public_identifier,
+                    system_identifier,
+                    force_quirks,
+                },
+                Token::Doctype {
+                    name: other_name,
+                    public_identifier: other_public_identifier,
+                    system_identifier: other_system_identifier,
+                    force_quirks: other_force_quirks,
+                },
+            ) => {
+                name == other_name
+                    && public_identifier == other_public_identifier
+                    && system_identifier == other_system_identifier
+                    && force_quirks == other_force_quirks
+            }
+            (
+                Token::StartTag {
+                    name,
+                    original_name,
+                    self_closing,
+                    attributes,
+                    ..
+                },
+                Token::StartTag {
+                    name: other_name,
+                    original_name: other_original_name,
+                    self_closing: other_self_closing,
+                    attributes: other_attributes,
+                    ..
+                },
+            ) => {
+                name == other_name
+                    && original_name == other_original_name
+                    && self_closing == other_self_closing
+                    && attributes == other_attributes
+            }
+            (
+                Token::EndTag {
+                    name,
+                    original_name,
+                    self_closing,
+                    attributes,
+                },
+                Token::EndTag {
+                    name: other_name,
+                    original_name: other_original_name,
+                    self_closing: other_self_closing,
+                    attributes: other_attributes,
+                },
+            ) => {
+                name == other_name
+                    && original_name == other_original_name
+                    && self_closing == other_self_closing
+                    && attributes == other_attributes
+            }
+            (Token::Comment { data }, Token::Comment { data: other_data }) => data == other_data,
+            (Token::Character { data }, Token::Character { data: other_data }) => {
+                data == other_data
+            }
+            (Token::EndOfFile, Token::EndOfFile) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Token {}
+
 impl Token {
     pub fn acknowledge_self_closing_flag_if_set(&self) {
         if let Token::StartTag {
@@ -146,55 +237,355 @@ impl Token {
         }
     }
 
-    pub fn tag_name(&self) -> Option<String> {
+    // https://html.spec.whatwg.org/multipage/parsing.html#adjust-mathml-attributes
+    pub fn adjust_mathml_attributes(&mut self) {
+        // When the steps below require the user agent to adjust MathML attributes for a token, then, if the token has an attribute named definitionurl, change its name to definitionURL (note the case difference).
+        if let Token::StartTag { attributes, .. } = self {
+            for attribute in attributes.iter_mut() {
+                if attribute.name == local_name!("definitionurl") {
+                    attribute.name = local_name!("definitionURL");
+                }
+            }
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#adjust-svg-attributes
+    pub fn adjust_svg_attributes(&mut self) {
+        // When the steps below require the user agent to adjust SVG attributes for a token, then, for each attribute on the token whose attribute name is one of the ones in the first column of the following table, change the attribute's name to the name given in the corresponding cell in the second column. (This fixes the case of SVG attributes that are not all lowercase.)
+        if let Token::StartTag { attributes, .. } = self {
+            for attribute in attributes.iter_mut() {
+                if let Some((_, adjusted)) = SVG_ATTRIBUTE_ADJUSTMENTS
+                    .iter()
+                    .find(|(name, _)| attribute.name == *name)
+                {
+                    attribute.name = Atom::from(*adjusted);
+                }
+            }
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#adjust-foreign-attributes
+    pub fn adjust_foreign_attributes(&mut self) {
+        // When the steps below require the user agent to adjust foreign attributes for a token, then, if any of the attributes on the token match the strings given in the first column of the following table, let the attribute be a namespaced attribute, with the prefix being the string given in the corresponding cell in the second column, the local name being the string given in the corresponding cell in the third column, and the namespace being the namespace given in the corresponding cell in the fourth column. (This fixes the use of namespaced attributes, in particular lang attributes in the XML namespace.)
+        if let Token::StartTag { attributes, .. } = self {
+            for attribute in attributes.iter_mut() {
+                if let Some((_, prefix, local_name, namespace)) = FOREIGN_ATTRIBUTE_ADJUSTMENTS
+                    .iter()
+                    .find(|(name, ..)| attribute.name == *name)
+                {
+                    attribute.prefix = prefix.map(Atom::from);
+                    attribute.name = Atom::from(*local_name);
+                    attribute.namespace = Some(*namespace);
+                }
+            }
+        }
+    }
+
+    // https://html.spec.whatwg.org/multipage/parsing.html#parsing-main-inforeign
+    pub fn adjust_svg_tag_name(&mut self) {
+        // If the token's tag name is one of the ones in the first column of the following table, change the tag name to the name given in the corresponding cell in the second column. (This fixes the case of SVG elements that are not all lowercase.)
+        if let Token::StartTag { name, .. } = self {
+            if let Some((_, adjusted)) = SVG_TAG_NAME_ADJUSTMENTS
+                .iter()
+                .find(|(lowercase, _)| name == lowercase)
+            {
+                *name = Atom::from(*adjusted);
+            }
+        }
+    }
+
+    pub fn tag_name(&self) -> Option<Atom> {
         match self {
-            Token::StartTag { name, .. } => Some(name.clone()),
-            Token::EndTag { name, .. } => Some(name.clone()),
+            Token::StartTag { name, .. } => Some(*name),
+            Token::EndTag { name, .. } => Some(*name),
             _ => None,
         }
     }
 }
 
+/// Code points of C1 controls that numeric character references map to the character that
+/// windows-1252 has at that position.
+///
+/// https://html.spec.whatwg.org/multipage/parsing.html#numeric-character-reference-end-state
+static CONTROL_CHARACTER_REFERENCE_REPLACEMENTS: &[(u32, u32)] = &[
+    (0x80, 0x20AC), // EURO SIGN (€)
+    (0x82, 0x201A), // SINGLE LOW-9 QUOTATION MARK (‚)
+    (0x83, 0x0192), // LATIN SMALL LETTER F WITH HOOK (ƒ)
+    (0x84, 0x201E), // DOUBLE LOW-9 QUOTATION MARK („)
+    (0x85, 0x2026), // HORIZONTAL ELLIPSIS (…)
+    (0x86, 0x2020), // DAGGER (†)
+    (0x87, 0x2021), // DOUBLE DAGGER (‡)
+    (0x88, 0x02C6), // MODIFIER LETTER CIRCUMFLEX ACCENT (ˆ)
+    (0x89, 0x2030), // PER MILLE SIGN (‰)
+    (0x8A, 0x0160), // LATIN CAPITAL LETTER S WITH CARON (Š)
+    (0x8B, 0x2039), // SINGLE LEFT-POINTING ANGLE QUOTATION MARK (‹)
+    (0x8C, 0x0152), // LATIN CAPITAL LIGATURE OE (Œ)
+    (0x8E, 0x017D), // LATIN CAPITAL LETTER Z WITH CARON (Ž)
+    (0x91, 0x2018), // LEFT SINGLE QUOTATION MARK (‘)
+    (0x92, 0x2019), // RIGHT SINGLE QUOTATION MARK (’)
+    (0x93, 0x201C), // LEFT DOUBLE QUOTATION MARK (“)
+    (0x94, 0x201D), // RIGHT DOUBLE QUOTATION MARK (”)
+    (0x95, 0x2022), // BULLET (•)
+    (0x96, 0x2013), // EN DASH (–)
+    (0x97, 0x2014), // EM DASH (—)
+    (0x98, 0x02DC), // SMALL TILDE (˜)
+    (0x99, 0x2122), // TRADE MARK SIGN (™)
+    (0x9A, 0x0161), // LATIN SMALL LETTER S WITH CARON (š)
+    (0x9B, 0x203A), // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK (›)
+    (0x9C, 0x0153), // LATIN SMALL LIGATURE OE (œ)
+    (0x9E, 0x017E), // LATIN SMALL LETTER Z WITH CARON (ž)
+    (0x9F, 0x0178), // LATIN CAPITAL LETTER Y WITH DIAERESIS (Ÿ)
+];
+
+/// The attribute name on the token, followed by the prefix, local name and namespace of the
+/// adjusted attribute.
+///
+/// https://html.spec.whatwg.org/multipage/parsing.html#adjust-foreign-attributes
+static FOREIGN_ATTRIBUTE_ADJUSTMENTS: &[(&str, Option<&str>, &str, Namespace)] = &[
+    ("xlink:actuate", Some("xlink"), "actuate", Namespace::XLink),
+    ("xlink:arcrole", Some("xlink"), "arcrole", Namespace::XLink),
+    ("xlink:href", Some("xlink"), "href", Namespace::XLink),
+    ("xlink:role", Some("xlink"), "role", Namespace::XLink),
+    ("xlink:show", Some("xlink"), "show", Namespace::XLink),
+    ("xlink:title", Some("xlink"), "title", Namespace::XLink),
+    ("xlink:type", Some("xlink"), "type", Namespace::XLink),
+    ("xml:lang", Some("xml"), "lang", Namespace::Xml),
+    ("xml:space", Some("xml"), "space", Namespace::Xml),
+    ("xmlns", None, "xmlns", Namespace::XmlNs),
+    ("xmlns:xlink", Some("xmlns"), "xlink", Namespace::XmlNs),
+];
+
+static SVG_ATTRIBUTE_ADJUSTMENTS: &[(&str, &str)] = &[
+    ("attributename", "attributeName"),
+    ("attributetype", "attributeType"),
+    ("basefrequency", "baseFrequency"),
+    ("baseprofile", "baseProfile"),
+    ("calcmode", "calcMode"),
+    ("clippathunits", "clipPathUnits"),
+    ("diffuseconstant", "diffuseConstant"),
+    ("edgemode", "edgeMode"),
+    ("filterunits", "filterUnits"),
+    ("glyphref", "glyphRef"),
+    ("gradienttransform", "gradientTransform"),
+    ("gradientunits", "gradientUnits"),
+    ("kernelmatrix", "kernelMatrix"),
+    ("kernelunitlength", "kernelUnitLength"),
+    ("keypoints", "keyPoints"),
+    ("keysplines", "keySplines"),
+    ("keytimes", "keyTimes"),
+    ("lengthadjust", "lengthAdjust"),
+    ("limitingconeangle", "limitingConeAngle"),
+    ("markerheight", "markerHeight"),
+    ("markerunits", "markerUnits"),
+    ("markerwidth", "markerWidth"),
+    ("maskcontentunits", "maskContentUnits"),
+    ("maskunits", "maskUnits"),
+    ("numoctaves", "numOctaves"),
+    ("pathlength", "pathLength"),
+    ("patterncontentunits", "patternContentUnits"),
+    ("patterntransform", "patternTransform"),
+    ("patternunits", "patternUnits"),
+    ("pointsatx", "pointsAtX"),
+    ("pointsaty", "pointsAtY"),
+    ("pointsatz", "pointsAtZ"),
+    ("preservealpha", "preserveAlpha"),
+    ("preserveaspectratio", "preserveAspectRatio"),
+    ("primitiveunits", "primitiveUnits"),
+    ("refx", "refX"),
+    ("refy", "refY"),
+    ("repeatcount", "repeatCount"),
+    ("repeatdur", "repeatDur"),
+    ("requiredextensions", "requiredExtensions"),
+    ("requiredfeatures", "requiredFeatures"),
+    ("specularconstant", "specularConstant"),
+    ("specularexponent", "specularExponent"),
+    ("spreadmethod", "spreadMethod"),
+    ("startoffset", "startOffset"),
+    ("stddeviation", "stdDeviation"),
+    ("stitchtiles", "stitchTiles"),
+    ("surfacescale", "surfaceScale"),
+    ("systemlanguage", "systemLanguage"),
+    ("tablevalues", "tableValues"),
+    ("targetx", "targetX"),
+    ("targety", "targetY"),
+    ("textlength", "textLength"),
+    ("viewbox", "viewBox"),
+    ("viewtarget", "viewTarget"),
+    ("xchannelselector", "xChannelSelector"),
+    ("ychannelselector", "yChannelSelector"),
+    ("zoomandpan", "zoomAndPan"),
+];
+
+static SVG_TAG_NAME_ADJUSTMENTS: &[(&str, &str)] = &[
+    ("altglyph", "altGlyph"),
+    ("altglyphdef", "altGlyphDef"),
+    ("altglyphitem", "altGlyphItem"),
+    ("animatecolor", "animateColor"),
+    ("animatemotion", "animateMotion"),
+    ("animatetransform", "animateTransform"),
+    ("clippath", "clipPath"),
+    ("feblend", "feBlend"),
+    ("fecolormatrix", "feColorMatrix"),
+    ("fecomponenttransfer", "feComponentTransfer"),
+    ("fecomposite", "feComposite"),
+    ("feconvolvematrix", "feConvolveMatrix"),
+    ("fediffuselighting", "feDiffuseLighting"),
+    ("fedisplacementmap", "feDisplacementMap"),
+    ("fedistantlight", "feDistantLight"),
+    ("fedropshadow", "feDropShadow"),
+    ("feflood", "feFlood"),
+    ("fefunca", "feFuncA"),
+    ("fefuncb", "feFuncB"),
+    ("fefuncg", "feFuncG"),
+    ("fefuncr", "feFuncR"),
+    ("fegaussianblur", "feGaussianBlur"),
+    ("feimage", "feImage"),
+    ("femerge", "feMerge"),
+    ("femergenode", "feMergeNode"),
+    ("femorphology", "feMorphology"),
+    ("feoffset", "feOffset"),
+    ("fepointlight", "fePointLight"),
+    ("fespecularlighting", "feSpecularLighting"),
+    ("fespotlight", "feSpotLight"),
+    ("fetile", "feTile"),
+    ("feturbulence", "feTurbulence"),
+    ("foreignobject", "foreignObject"),
+    ("glyphref", "glyphRef"),
+    ("lineargradient", "linearGradient"),
+    ("radialgradient", "radialGradient"),
+    ("textpath", "textPath"),
+];
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Attribute {
-    pub name: String,
+    pub name: Atom,
+    /// The attribute name as it was spelled in the input, if
+    /// [`TokenizerOptions::preserve_case`] is set.
+    pub original_name: Option<String>,
     pub value: String,
+    /// The namespace prefix, only set by [`Token::adjust_foreign_attributes`].
+    pub prefix: Option<Atom>,
+    /// The namespace, only set by [`Token::adjust_foreign_attributes`].
+    pub namespace: Option<Namespace>,
+}
+
+/// Options for tooling that needs more of the input than the tree builder does, like formatters.
+///
+/// NOTE: The parser doesn't set any of these, so they never change how documents are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TokenizerOptions {
+    /// Keep the original spelling of tag and attribute names next to the lowercased names, in
+    /// `original_name`.
+    pub preserve_case: bool,
+    /// Keep the part of the input that each token was tokenized from, see
+    /// [`Tokenizer::current_token_raw`].
+    pub record_raw: bool,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Tokenizer {
-    input: String,
+    /// The preprocessed input, one entry per character, so that looking up the character at the
+    /// insertion point (which is a character index) doesn't have to walk the whole input.
+    input: Vec<char>,
     state: State,
     return_state: Option<State>,
     temporary_buffer: String,
-    temporary_named_character_references_buffer: Vec<(String, NamedCharacterReference<'static>)>,
-    last_start_tag_name: Option<String>,
-    tokens: Vec<Token>,
+    last_start_tag_name: Option<Atom>,
+    options: TokenizerOptions,
+    // Some states emit multiple tokens at once, so emitted tokens are queued until they are handed
+    // out, together with the range of the input they were tokenized from.
+    pending_tokens: VecDeque<(Token, Range<usize>)>,
+    current_token: Option<Token>,
+    current_token_raw: Option<String>,
+    /// The index in the input up to which the emitted tokens were tokenized from.
+    emitted_up_to: usize,
     insertion_point: Option<usize>,
     current_input_character: Option<char>,
-    token_emitted: bool,
     current_building_token: Option<Token>,
     current_attribute: Option<Attribute>,
+    // NOTE: Tag and attribute names are built up in these buffers, and only interned
+    //       when the tag or attribute is complete.
+    current_tag_name: String,
+    current_attribute_name: String,
+    // NOTE: These are only filled if the preserve_case option is set.
+    current_original_tag_name: String,
+    current_original_attribute_name: String,
     character_reference_code: u32,
+    parse_errors: Vec<ParseError>,
+    /// The state the tokenizer was in when it stopped making progress, see
+    /// [`Tokenizer::stalled_state`].
+    stalled_state: Option<State>,
+    /// Whether the adjusted current node of the tree builder is an element that is not in the
+    /// HTML namespace, which is the only place where CDATA sections are recognized.
+    cdata_sections_allowed: bool,
+    /// A state that is replaced by one that never consumes a character, to test that the
+    /// tokenizer doesn't hang on a broken state.
+    #[cfg(test)]
+    pub(crate) broken_state: Option<State>,
 }
 
+/// How many times in a row the state machine may run without consuming a character it hasn't
+/// consumed before, before the tokenizer gives up on the input.
+///
+/// NOTE: Reconsuming and switching states take a few steps at most, so a state that hits this
+///       limit is stuck, usually because it handles a character (often EOF) without consuming it,
+///       switching state or emitting a token.
+const MAX_STEPS_WITHOUT_PROGRESS: usize = 100;
+
 impl Tokenizer {
     pub fn new(input: &str) -> Self {
+        Self::with_options(input, TokenizerOptions::default())
+    }
+
+    pub fn with_options(input: &str, options: TokenizerOptions) -> Self {
+        // SPEC: One leading U+FEFF BYTE ORDER MARK character must be ignored if any are present.
+        // https://html.spec.whatwg.org/multipage/parsing.html#the-input-byte-stream
+        let input = input.strip_prefix('\u{FEFF}').unwrap_or(input);
+
         Self {
-            input: String::from(input),
+            input: normalize_newlines(input).chars().collect(),
             state: State::Data,
             return_state: None,
             temporary_buffer: String::new(),
-            temporary_named_character_references_buffer: Vec::new(),
             last_start_tag_name: None,
-            tokens: Vec::new(),
+            options,
+            pending_tokens: VecDeque::new(),
+            current_token: None,
+            current_token_raw: None,
+            emitted_up_to: 0,
             insertion_point: None,
             current_input_character: None,
-            token_emitted: false,
             current_building_token: None,
             current_attribute: None,
+            current_tag_name: String::new(),
+            current_attribute_name: String::new(),
+            current_original_tag_name: String::new(),
+            current_original_attribute_name: String::new(),
             character_reference_code: 0,
+            parse_errors: Vec::new(),
+            stalled_state: None,
+            cdata_sections_allowed: false,
+            #[cfg(test)]
+            broken_state: None,
+        }
+    }
+
+    /// Tokenizes the whole of `input` at once. The returned tokens always end with a
+    /// [`Token::EndOfFile`], and nothing comes after it.
+    ///
+    /// NOTE: The tokenizer stays in the data state, unlike when the parser drives it, so the
+    ///       contents of elements like `<script>` and `<title>` are tokenized as markup.
+    pub fn collect_tokens(input: &str) -> Vec<Token> {
+        let mut tokenizer = Self::new(input);
+        let mut tokens = vec![];
+        while let Some(token) = tokenizer.next_token() {
+            let is_end_of_file = *token == Token::EndOfFile;
+            tokens.push(token.clone());
+            if is_end_of_file {
+                break;
+            }
         }
+        tokens
     }
 
     fn next_input_character(&mut self) -> Option<char> {
@@ -207,7 +598,7 @@ impl Tokenizer {
         }
 
         if let Some(insertion_point) = self.insertion_point {
-            return self.input.chars().nth(insertion_point);
+            return self.input.get(insertion_point).copied();
         }
         None
     }
@@ -221,37 +612,90 @@ impl Tokenizer {
 
     fn consume_characters(&mut self, characters: &str) {
         if let Some(insertion_point) = self.insertion_point {
-            self.insertion_point = Some(insertion_point + characters.len());
+            // The insertion point is a character index, not a byte index.
+            self.insertion_point = Some(insertion_point + characters.chars().count());
         }
     }
 
     fn next_characters_are_ascii_case_insensitive(&self, chars: &str) -> bool {
         if let Some(insertion_point) = self.insertion_point {
-            let next_characters = &self.input[insertion_point..(insertion_point + chars.len())];
-            return chars.eq_ignore_ascii_case(next_characters);
+            // NOTE: We compare characters instead of slicing the input, so we never split a
+            //       multi-byte character, and running out of input (e.g. `<!DOC` at the end of
+            //       the input) simply means the characters don't match.
+            let mut next_characters = self.input.iter().skip(insertion_point);
+            return chars.chars().all(|expected| {
+                next_characters
+                    .next()
+                    .is_some_and(|c| c.eq_ignore_ascii_case(&expected))
+            });
+        }
+        false
+    }
+
+    fn next_characters_are(&self, chars: &str) -> bool {
+        if let Some(insertion_point) = self.insertion_point {
+            let mut next_characters = self.input.iter().skip(insertion_point);
+            return chars
+                .chars()
+                .all(|expected| next_characters.next() == Some(&expected));
         }
         false
     }
 
     fn emit_token(&mut self, token: Token) {
         if let Token::StartTag { name, .. } = &token {
-            self.last_start_tag_name = Some(name.to_owned());
+            self.last_start_tag_name = Some(*name);
         }
 
-        self.tokens.push(token);
-        self.token_emitted = true;
+        // NOTE: A token is tokenized from everything that was consumed since the previous token
+        //       was emitted. If a state emits several tokens at once, the first one gets all of it.
+        let end = self.insertion_point.unwrap_or(0).min(self.input.len());
+        let start = self.emitted_up_to.min(end);
+        self.emitted_up_to = end;
+        self.pending_tokens.push_back((token, start..end));
     }
 
     fn create_new_token(&mut self, token: Token) {
+        if let Token::StartTag { .. } | Token::EndTag { .. } = token {
+            self.current_tag_name.clear();
+            self.current_original_tag_name.clear();
+        }
         self.current_building_token = Some(token);
     }
 
+    /// Appends `character` to the current tag token's tag name, lowercasing it if it is an ASCII
+    /// upper alpha.
+    ///
+    /// NOTE: Every state that appends to a tag name treats ASCII upper alphas this way, and leaves
+    ///       every other character as it is.
+    fn append_to_current_tag_name(&mut self, character: char) {
+        self.current_tag_name.push(character.to_ascii_lowercase());
+        if self.options.preserve_case {
+            self.current_original_tag_name.push(character);
+        }
+    }
+
+    /// Appends `character` to the current attribute's name, lowercasing it if it is an ASCII upper
+    /// alpha.
+    fn append_to_current_attribute_name(&mut self, character: char) {
+        self.current_attribute_name
+            .push(character.to_ascii_lowercase());
+        if self.options.preserve_case {
+            self.current_original_attribute_name.push(character);
+        }
+    }
+
     fn push_current_attribute_to_current_tag(&mut self) {
-        if let Some(current_attribute) = self.current_attribute.clone() {
+        if let Some(mut current_attribute) = self.current_attribute.take() {
+            current_attribute.name = Atom::from(self.current_attribute_name.as_str());
+            self.current_attribute_name.clear();
+            if self.options.preserve_case {
+                current_attribute.original_name =
+                    Some(std::mem::take(&mut self.current_original_attribute_name));
+            }
             if let Some(Token::StartTag { attributes, .. }) = &mut self.current_building_token {
                 attributes.push(current_attribute)
             }
-            self.current_attribute = None
         }
     }
 
@@ -259,12 +703,81 @@ impl Tokenizer {
         // If we have prepared an attribute, add it to the current tag.
         self.push_current_attribute_to_current_tag();
 
+        if let Some(
+            Token::StartTag {
+                name,
+                original_name,
+                ..
+            }
+            | Token::EndTag {
+                name,
+                original_name,
+                ..
+            },
+        ) = &mut self.current_building_token
+        {
+            *name = Atom::from(self.current_tag_name.as_str());
+            if self.options.preserve_case {
+                *original_name = Some(self.current_original_tag_name.clone());
+            }
+        }
+
         if let Some(current_token) = self.current_building_token.clone() {
             self.emit_token(current_token);
         }
         self.current_building_token = None
     }
 
+    fn append_to_current_comment_data(&mut self, string: &str) {
+        if let Some(Token::Comment { data }) = &mut self.current_building_token {
+            data.push_str(string);
+        }
+    }
+
+    fn set_current_doctype_force_quirks_flag(&mut self) {
+        if let Some(Token::Doctype { force_quirks, .. }) = &mut self.current_building_token {
+            *force_quirks = true;
+        }
+    }
+
+    fn set_current_doctype_public_identifier_to_the_empty_string(&mut self) {
+        if let Some(Token::Doctype {
+            public_identifier, ..
+        }) = &mut self.current_building_token
+        {
+            *public_identifier = Some(String::new());
+        }
+    }
+
+    fn append_to_current_doctype_public_identifier(&mut self, character: char) {
+        if let Some(Token::Doctype {
+            public_identifier: Some(public_identifier),
+            ..
+        }) = &mut self.current_building_token
+        {
+            public_identifier.push(character);
+        }
+    }
+
+    fn set_current_doctype_system_identifier_to_the_empty_string(&mut self) {
+        if let Some(Token::Doctype {
+            system_identifier, ..
+        }) = &mut self.current_building_token
+        {
+            *system_identifier = Some(String::new());
+        }
+    }
+
+    fn append_to_current_doctype_system_identifier(&mut self, character: char) {
+        if let Some(Token::Doctype {
+            system_identifier: Some(system_identifier),
+            ..
+        }) = &mut self.current_building_token
+        {
+            system_identifier.push(character);
+        }
+    }
+
     fn set_current_attribute(&mut self, attribute: Attribute) {
         // If an attribute already exists, we should first push it to
         // the attributes of the current tag, so we don't override the previous attribute.
@@ -277,6 +790,16 @@ impl Tokenizer {
         self.state = state;
     }
 
+    pub fn set_last_start_tag_name(&mut self, name: &str) {
+        self.last_start_tag_name = Some(Atom::from(name));
+    }
+
+    /// Tells the tokenizer whether `<![CDATA[` starts a CDATA section, which is the case when
+    /// the adjusted current node is an element that is not in the HTML namespace.
+    pub fn set_cdata_sections_allowed(&mut self, allowed: bool) {
+        self.cdata_sections_allowed = allowed;
+    }
+
     pub fn state(&self) -> State {
         self.state
     }
@@ -291,10 +814,26 @@ impl Tokenizer {
     fn reconsume_in(&mut self, state: State) {
         if let Some(insertion_point) = self.insertion_point {
             self.insertion_point = Some(insertion_point - 1);
+            self.unemit_reconsumed_character(insertion_point - 1);
         }
         self.switch_to(state);
     }
 
+    /// States that emit a token and then reconsume the current input character emit the token
+    /// before the character is given back, so this takes the character out of the range of the
+    /// input that the token was tokenized from.
+    fn unemit_reconsumed_character(&mut self, insertion_point: usize) {
+        if self.emitted_up_to <= insertion_point {
+            return;
+        }
+
+        self.emitted_up_to = insertion_point;
+        if let Some((_, range)) = self.pending_tokens.back_mut() {
+            range.end = range.end.min(insertion_point);
+            range.start = range.start.min(range.end);
+        }
+    }
+
     fn reconsume_in_return_state(&mut self) {
         if let Some(return_state) = self.return_state {
             self.reconsume_in(return_state);
@@ -306,15 +845,43 @@ impl Tokenizer {
         self.return_state = Some(state);
     }
 
+    /// https://html.spec.whatwg.org/multipage/parsing.html#charref-in-attribute
+    fn character_reference_is_consumed_as_part_of_an_attribute(&self) -> bool {
+        // SPEC: A character reference is said to be consumed as part of an attribute if the return
+        //       state is either attribute value (double-quoted) state, attribute value
+        //       (single-quoted) state, or attribute value (unquoted) state.
+        matches!(
+            self.return_state,
+            Some(
+                State::AttributeValueDoubleQuoted
+                    | State::AttributeValueSingleQuoted
+                    | State::AttributeValueUnquoted
+            )
+        )
+    }
+
+    /// Multiplies the character reference code by `base` and adds `digit` to it.
+    ///
+    /// NOTE: The code saturates instead of overflowing, so a reference with a lot of digits stays
+    ///       outside the Unicode range and is replaced like any other out-of-range reference.
+    fn add_to_character_reference_code(&mut self, base: u32, digit: u32) {
+        self.character_reference_code = self
+            .character_reference_code
+            .saturating_mul(base)
+            .saturating_add(digit);
+    }
+
     fn flush_code_points_consumed_as_a_character_reference(&mut self) {
         // SPEC: When a state says to flush code points consumed as a character reference,
         //       it means that for each code point in the temporary buffer
         //       (in the order they were added to the buffer) user agent must append the code point
         //       from the buffer to the current attribute's value if the character reference was
         //       consumed as part of an attribute,
-        if let Some(Attribute { value, .. }) = &mut self.current_attribute {
-            for character in self.temporary_buffer.chars() {
-                value.push(character);
+        // NOTE: The temporary buffer holds every code point of the replacement, so references
+        //       that expand to two code points, like &fjlig;, flush both in order.
+        if self.character_reference_is_consumed_as_part_of_an_attribute() {
+            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                value.push_str(&self.temporary_buffer);
             }
         } else {
             // SPEC: or emit the code point as a character token otherwise.
@@ -324,12 +891,80 @@ impl Tokenizer {
         }
     }
 
+    fn longest_named_character_reference_match(
+        &self,
+    ) -> Option<(&'static str, ncr::NamedCharacterReference)> {
+        let insertion_point = self.insertion_point.unwrap_or(0);
+        let remaining_input = self
+            .input
+            .iter()
+            .skip(insertion_point)
+            .take(ncr::LONGEST_IDENTIFIER_LENGTH)
+            .collect::<String>();
+
+        ncr::longest_match(&remaining_input)
+    }
+
+    /// The index of the current input character, counted in characters.
+    pub fn current_position(&self) -> usize {
+        self.insertion_point.unwrap_or(0).saturating_sub(1)
+    }
+
+    /// The state the tokenizer was stuck in, if it stopped making progress and ended the input
+    /// early.
+    pub fn stalled_state(&self) -> Option<State> {
+        self.stalled_state
+    }
+
+    /// Gives up on the rest of the input, because the current state doesn't make progress.
+    ///
+    /// NOTE: This is always a bug in the tokenizer, so debug builds panic, to make it easy to
+    ///       find. Release builds end the input instead of hanging.
+    fn stall(&mut self) {
+        // NOTE: The insertion point keeps moving past the end of the input at EOF.
+        let position = self.insertion_point.unwrap_or(0).min(self.input.len());
+        if cfg!(debug_assertions) {
+            panic!(
+                "tokenizer made no progress in {MAX_STEPS_WITHOUT_PROGRESS} steps in the {:?} state at position {position}",
+                self.state
+            );
+        }
+
+        log_parser_error!(format!(
+            "tokenizer made no progress in the {:?} state at position {position}, ending the input",
+            self.state
+        ));
+        self.stalled_state = Some(self.state);
+        self.emit_token(Token::EndOfFile);
+    }
+
+    pub fn parse_errors(&self) -> &[ParseError] {
+        &self.parse_errors
+    }
+
+    fn parse_error(&mut self, kind: ParseErrorKind) {
+        self.parse_errors.push(ParseError {
+            kind,
+            position: self.current_position(),
+        });
+    }
+
     pub fn set_insertion_point(&mut self, insertion_point: Option<usize>) {
         self.insertion_point = insertion_point
     }
 
     pub fn current_token(&self) -> Option<&Token> {
-        self.tokens.last()
+        self.current_token.as_ref()
+    }
+
+    /// The part of the input that the current token was tokenized from, if
+    /// [`TokenizerOptions::record_raw`] is set.
+    ///
+    /// NOTE: The input is preprocessed first, so newlines are normalized. If a state emits several
+    ///       character tokens at once, like for `&fjlig;`, the first one holds the whole reference
+    ///       and the others are empty. Concatenating the raw text of all tokens gives the input.
+    pub fn current_token_raw(&self) -> Option<&str> {
+        self.current_token_raw.as_deref()
     }
 
     pub fn current_end_tag_token_is_an_appropriate_end_tag_token(&self) -> bool {
@@ -338,26 +973,39 @@ impl Tokenizer {
             Some(Token::EndTag { .. })
         ), "Current token is not an EndTag. This is needed when checking for the appropriate EndTag!");
 
-        if let Some(Token::EndTag { name, .. }) = &self.current_building_token {
-            return self.last_start_tag_name.clone().is_some_and(|f| f == *name);
-        }
-        false
+        self.last_start_tag_name
+            .is_some_and(|name| name == self.current_tag_name)
     }
 
     pub fn next_token(&mut self) -> Option<&Token> {
-        self.token_emitted = false;
-
-        if self.current_token() == Some(&Token::EndOfFile) {
+        if self.pending_tokens.is_empty() && self.current_token() == Some(&Token::EndOfFile) {
             return None;
         }
 
-        loop {
+        let mut furthest_position = self.insertion_point.unwrap_or(0).min(self.input.len());
+        let mut steps_without_progress = 0;
+
+        // Hand out the tokens that were emitted before running the state machine again.
+        while self.pending_tokens.is_empty() {
             log_current_token!(self.state, self.current_token());
 
-            if self.token_emitted {
+            let position = self.insertion_point.unwrap_or(0).min(self.input.len());
+            if position > furthest_position {
+                furthest_position = position;
+                steps_without_progress = 0;
+            } else {
+                steps_without_progress += 1;
+            }
+            if steps_without_progress > MAX_STEPS_WITHOUT_PROGRESS {
+                self.stall();
                 break;
             }
 
+            #[cfg(test)]
+            if self.broken_state == Some(self.state) {
+                continue;
+            }
+
             match self.state {
                 // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#data-state
                 State::Data => {
@@ -373,7 +1021,12 @@ impl Tokenizer {
                             // SPEC: Switch to the tag open state.
                             self.switch_to(State::TagOpen)
                         }
-                        on_null!() => todo!(),
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: '\u{0000}' });
+                        }
                         on_eof!() => {
                             // SPEC: Emit an end-of-file token.
                             self.emit_token(Token::EndOfFile);
@@ -401,6 +1054,7 @@ impl Tokenizer {
                         }
                         on_null!() => {
                             // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
 
                             // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
                             self.emit_token(Token::Character { data: '\u{FFFD}' });
@@ -425,6 +1079,7 @@ impl Tokenizer {
                         }
                         on_null!() => {
                             // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
 
                             // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
                             self.emit_token(Token::Character { data: '\u{FFFD}' });
@@ -449,6 +1104,28 @@ impl Tokenizer {
                         }
                         on_null!() => {
                             // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+
+                            // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
+                            self.emit_token(Token::Character { data: '\u{FFFD}' });
+                        }
+                        on_eof!() => {
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#plaintext-state
+                State::PlainText => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
 
                             // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
                             self.emit_token(Token::Character { data: '\u{FFFD}' });
@@ -463,7 +1140,6 @@ impl Tokenizer {
                         }
                     }
                 }
-                State::PlainText => todo!(),
                 // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#tag-open-state
                 State::TagOpen => {
                     self.consume_next_input_character();
@@ -481,7 +1157,8 @@ impl Tokenizer {
                             // SPEC: Create a new start tag token.
                             self.create_new_token(Token::StartTag {
                                 // SPEC: Set its tag name to the empty string.
-                                name: String::new(),
+                                name: Atom::EMPTY,
+                                original_name: None,
                                 self_closing: false,
                                 self_closing_acknowledged: Cell::new(false),
                                 attributes: Vec::new(),
@@ -489,9 +1166,33 @@ impl Tokenizer {
                             // SPEC: Reconsume in the tag name state.
                             self.reconsume_in(State::TagName);
                         }
-                        on!('?') => todo!(),
-                        on_eof!() => todo!(),
-                        on_anything_else!() => todo!(),
+                        on!('?') => {
+                            // SPEC: This is an unexpected-question-mark-instead-of-tag-name parse error.
+                            self.parse_error(
+                                ParseErrorKind::UnexpectedQuestionMarkInsteadOfTagName,
+                            );
+                            // SPEC: Create a comment token whose data is the empty string.
+                            self.create_new_token(Token::Comment {
+                                data: String::new(),
+                            });
+                            // SPEC: Reconsume in the bogus comment state.
+                            self.reconsume_in(State::BogusComment);
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-before-tag-name parse error.
+                            self.parse_error(ParseErrorKind::EofBeforeTagName);
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token and an end-of-file token.
+                            self.emit_token(Token::Character { data: '<' });
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is an invalid-first-character-of-tag-name parse error.
+                            self.parse_error(ParseErrorKind::InvalidFirstCharacterOfTagName);
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token.
+                            self.emit_token(Token::Character { data: '<' });
+                            // SPEC: Reconsume in the data state.
+                            self.reconsume_in(State::Data);
+                        }
                     }
                 }
                 // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#end-tag-open-state
@@ -501,16 +1202,43 @@ impl Tokenizer {
                         on_ascii_alpha!() => {
                             // SPEC: Create a new end tag token, set its tag name to the empty string.
                             self.create_new_token(Token::EndTag {
-                                name: String::new(),
+                                name: Atom::EMPTY,
+                                original_name: None,
                                 self_closing: false,
                                 attributes: Vec::new(),
                             });
                             // SPEC: Reconsume in the tag name state.
                             self.reconsume_in(State::TagName);
                         }
-                        on!('>') => todo!(),
-                        on_eof!() => todo!(),
-                        on_anything_else!() => todo!(),
+                        on!('>') => {
+                            // SPEC: This is a missing-end-tag-name parse error.
+                            self.parse_error(ParseErrorKind::MissingEndTagName);
+
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-before-tag-name parse error.
+                            self.parse_error(ParseErrorKind::EofBeforeTagName);
+
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token,
+                            self.emit_token(Token::Character { data: '<' });
+                            // SPEC: a U+002F SOLIDUS character token
+                            self.emit_token(Token::Character { data: '/' });
+                            // SPEC: and an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is an invalid-first-character-of-tag-name parse error.
+                            self.parse_error(ParseErrorKind::InvalidFirstCharacterOfTagName);
+
+                            // SPEC: Create a comment token whose data is the empty string.
+                            self.create_new_token(Token::Comment {
+                                data: String::new(),
+                            });
+                            // SPEC: Reconsume in the bogus comment state.
+                            self.reconsume_in(State::BogusComment);
+                        }
                     }
                 }
                 // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#tag-name-state
@@ -531,24 +1259,25 @@ impl Tokenizer {
                             // SPEC: Emit the current tag token.
                             self.emit_current_token();
                         }
-                        on_null!() => todo!(),
-                        on_eof!() => todo!(),
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current tag token's tag name.
+                            self.append_to_current_tag_name('\u{FFFD}');
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-tag parse error.
+                            self.parse_error(ParseErrorKind::EofInTag);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_ascii_upper_alpha!(character) => {
+                            // SPEC: Append the lowercase version of the current input character (add 0x0020 to the character's code point) to the current tag token's tag name.
+                            self.append_to_current_tag_name(character);
+                        }
                         on_anything_else!(character) => {
-                            // SPEC: ASCII upper alpha
-                            //          Append the lowercase version of the current input character
-                            //          (add 0x0020 to the character's code point)
-                            //          to the current tag token's tag name.
-                            let character = character.to_ascii_lowercase();
                             // SPEC: Append the current input character to the current tag token's tag name.
-                            match &mut self.current_building_token {
-                                Some(Token::StartTag { name, .. }) => {
-                                    name.push(character.to_ascii_lowercase());
-                                }
-                                Some(Token::EndTag { name, .. }) => {
-                                    name.push(character.to_ascii_lowercase());
-                                }
-                                _ => {}
-                            }
+                            self.append_to_current_tag_name(character);
                         }
                     }
                 }
@@ -578,7 +1307,8 @@ impl Tokenizer {
                         on_ascii_alpha!() => {
                             // SPEC: Create a new end tag token, set its tag name to the empty string.
                             self.create_new_token(Token::EndTag {
-                                name: String::new(),
+                                name: Atom::EMPTY,
+                                original_name: None,
                                 self_closing: false,
                                 attributes: Vec::new(),
                             });
@@ -650,21 +1380,16 @@ impl Tokenizer {
                                 anything_else!();
                             }
                         }
-                        on_ascii_upper_alpha!() => {
-                            todo!()
+                        on_ascii_upper_alpha!(character) => {
+                            // SPEC: Append the lowercase version of the current input character (add 0x0020 to the character's code point) to the current tag token's tag name.
+                            self.append_to_current_tag_name(character);
+
+                            // SPEC: Append the current input character to the temporary buffer.
+                            self.temporary_buffer.push(character);
                         }
                         on_ascii_lower_alpha!(character) => {
                             // SPEC: Append the current input character to the current tag token's tag name.
-                            if let Some(Token::StartTag { name, .. }) =
-                                &mut self.current_building_token
-                            {
-                                name.push(character)
-                            }
-                            if let Some(Token::EndTag { name, .. }) =
-                                &mut self.current_building_token
-                            {
-                                name.push(character);
-                            }
+                            self.append_to_current_tag_name(character);
 
                             // SPEC: Append the current input character to the temporary buffer.
                             self.temporary_buffer.push(character);
@@ -699,7 +1424,8 @@ impl Tokenizer {
                         on_ascii_alpha!() => {
                             // SPEC: Create a new end tag token, set its tag name to the empty string.
                             self.create_new_token(Token::EndTag {
-                                name: String::new(),
+                                name: Atom::EMPTY,
+                                original_name: None,
                                 self_closing: false,
                                 attributes: vec![],
                             });
@@ -771,21 +1497,16 @@ impl Tokenizer {
                                 anything_else!();
                             }
                         }
-                        on_ascii_upper_alpha!() => {
-                            todo!();
+                        on_ascii_upper_alpha!(character) => {
+                            // SPEC: Append the lowercase version of the current input character (add 0x0020 to the character's code point) to the current tag token's tag name.
+                            self.append_to_current_tag_name(character);
+
+                            // SPEC: Append the current input character to the temporary buffer.
+                            self.temporary_buffer.push(character);
                         }
                         on_ascii_lower_alpha!(character) => {
                             // SPEC: Append the current input character to the current tag token's tag name.
-                            if let Some(Token::StartTag { name, .. }) =
-                                &mut self.current_building_token
-                            {
-                                name.push(character);
-                            }
-                            if let Some(Token::EndTag { name, .. }) =
-                                &mut self.current_building_token
-                            {
-                                name.push(character);
-                            }
+                            self.append_to_current_tag_name(character);
 
                             // SPEC: Append the current input character to the temporary buffer.
                             self.temporary_buffer.push(character);
@@ -828,7 +1549,8 @@ impl Tokenizer {
                         on_ascii_alpha!() => {
                             // SPEC: Create a new end tag token, set its tag name to the empty string.
                             self.create_new_token(Token::EndTag {
-                                name: String::new(),
+                                name: Atom::EMPTY,
+                                original_name: None,
                                 self_closing: false,
                                 attributes: vec![],
                             });
@@ -900,21 +1622,16 @@ impl Tokenizer {
                                 anything_else!();
                             }
                         }
-                        on_ascii_upper_alpha!() => {
-                            todo!();
+                        on_ascii_upper_alpha!(character) => {
+                            // SPEC: Append the lowercase version of the current input character (add 0x0020 to the character's code point) to the current tag token's tag name.
+                            self.append_to_current_tag_name(character);
+
+                            // SPEC: Append the current input character to the temporary buffer.
+                            self.temporary_buffer.push(character);
                         }
                         on_ascii_lower_alpha!(character) => {
                             // SPEC: Append the current input character to the current tag token's tag name.
-                            if let Some(Token::StartTag { name, .. }) =
-                                &mut self.current_building_token
-                            {
-                                name.push(character);
-                            }
-                            if let Some(Token::EndTag { name, .. }) =
-                                &mut self.current_building_token
-                            {
-                                name.push(character);
-                            }
+                            self.append_to_current_tag_name(character);
 
                             // SPEC: Append the current input character to the temporary buffer.
                             self.temporary_buffer.push(character);
@@ -924,436 +1641,1960 @@ impl Tokenizer {
                         }
                     }
                 }
-                State::ScriptDataEscapeStart => todo!(),
-                State::ScriptDataEscapeStartDash => todo!(),
-                State::ScriptDataEscaped => todo!(),
-                State::ScriptDataEscapedDash => todo!(),
-                State::ScriptDataEscapedDashDash => todo!(),
-                State::ScriptDataEscapedLessThanSign => todo!(),
-                State::ScriptDataEscapedEndTagOpen => todo!(),
-                State::ScriptDataEscapedEndTagName => todo!(),
-                State::ScriptDataDoubleEscapeStart => todo!(),
-                State::ScriptDataDoubleEscaped => todo!(),
-                State::ScriptDataDoubleEscapedDash => todo!(),
-                State::ScriptDataDoubleEscapedDashDash => todo!(),
-                State::ScriptDataDoubleEscapedLessThanSign => todo!(),
-                State::ScriptDataDoubleEscapeEnd => todo!(),
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#before-attribute-name-state
-                State::BeforeAttributeName => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-escape-start-state
+                State::ScriptDataEscapeStart => {
                     self.consume_next_input_character();
                     match self.current_input_character {
-                        on_whitespace!() => {
-                            // SPEC: Ignore the character
-                            continue;
-                        }
-                        on!('/') | on!('>') | on_eof!() => {
-                            // SPEC: Reconsume in the after attribute name state.
-                            self.reconsume_in(State::AfterAttributeName);
+                        on!('-') => {
+                            // SPEC: Switch to the script data escape start dash state.
+                            self.switch_to(State::ScriptDataEscapeStartDash);
+                            // SPEC: Emit a U+002D HYPHEN-MINUS character token.
+                            self.emit_token(Token::Character { data: '-' });
                         }
-                        on!('=') => todo!(),
-                        on_anything_else!() => {
-                            // SPEC: Start a new attribute in the current tag token.
-                            self.set_current_attribute(Attribute {
-                                // Set that attribute name and value to the empty string.
-                                name: String::new(),
-                                value: String::new(),
-                            });
-                            // SPEC: Reconsume in the attribute name state.
-                            self.reconsume_in(State::AttributeName);
+                        on_anything_else!() | None => {
+                            // SPEC: Reconsume in the script data state.
+                            self.reconsume_in(State::ScriptData);
                         }
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#attribute-name-state
-                State::AttributeName => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-escape-start-dash-state
+                State::ScriptDataEscapeStartDash => {
                     self.consume_next_input_character();
                     match self.current_input_character {
-                        on_whitespace!() | on!('/') | on!('>') | on_eof!() => {
-                            // SPEC: Reconsume in the after attribute name state.
-                            self.reconsume_in(State::AfterAttributeName);
+                        on!('-') => {
+                            // SPEC: Switch to the script data escaped dash dash state.
+                            self.switch_to(State::ScriptDataEscapedDashDash);
+                            // SPEC: Emit a U+002D HYPHEN-MINUS character token.
+                            self.emit_token(Token::Character { data: '-' });
                         }
-                        on!('=') => {
-                            // SPEC: Switch to the before attribute value state.
-                            self.switch_to(State::BeforeAttributeValue);
+                        on_anything_else!() | None => {
+                            // SPEC: Reconsume in the script data state.
+                            self.reconsume_in(State::ScriptData);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-escaped-state
+                State::ScriptDataEscaped => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the script data escaped dash state.
+                            self.switch_to(State::ScriptDataEscapedDash);
+                            // SPEC: Emit a U+002D HYPHEN-MINUS character token.
+                            self.emit_token(Token::Character { data: '-' });
+                        }
+                        on!('<') => {
+                            // SPEC: Switch to the script data escaped less-than sign state.
+                            self.switch_to(State::ScriptDataEscapedLessThanSign);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
+                            self.emit_token(Token::Character { data: '\u{FFFD}' });
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-script-html-comment-like-text parse error.
+                            self.parse_error(ParseErrorKind::EofInScriptHtmlCommentLikeText);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-escaped-dash-state
+                State::ScriptDataEscapedDash => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the script data escaped dash dash state.
+                            self.switch_to(State::ScriptDataEscapedDashDash);
+                            // SPEC: Emit a U+002D HYPHEN-MINUS character token.
+                            self.emit_token(Token::Character { data: '-' });
+                        }
+                        on!('<') => {
+                            // SPEC: Switch to the script data escaped less-than sign state.
+                            self.switch_to(State::ScriptDataEscapedLessThanSign);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Switch to the script data escaped state.
+                            self.switch_to(State::ScriptDataEscaped);
+                            // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
+                            self.emit_token(Token::Character { data: '\u{FFFD}' });
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-script-html-comment-like-text parse error.
+                            self.parse_error(ParseErrorKind::EofInScriptHtmlCommentLikeText);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Switch to the script data escaped state.
+                            self.switch_to(State::ScriptDataEscaped);
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-escaped-dash-dash-state
+                State::ScriptDataEscapedDashDash => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Emit a U+002D HYPHEN-MINUS character token.
+                            self.emit_token(Token::Character { data: '-' });
+                        }
+                        on!('<') => {
+                            // SPEC: Switch to the script data escaped less-than sign state.
+                            self.switch_to(State::ScriptDataEscapedLessThanSign);
+                        }
+                        on!('>') => {
+                            // SPEC: Switch to the script data state.
+                            self.switch_to(State::ScriptData);
+                            // SPEC: Emit a U+003E GREATER-THAN SIGN character token.
+                            self.emit_token(Token::Character { data: '>' });
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Switch to the script data escaped state.
+                            self.switch_to(State::ScriptDataEscaped);
+                            // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
+                            self.emit_token(Token::Character { data: '\u{FFFD}' });
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-script-html-comment-like-text parse error.
+                            self.parse_error(ParseErrorKind::EofInScriptHtmlCommentLikeText);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Switch to the script data escaped state.
+                            self.switch_to(State::ScriptDataEscaped);
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-escaped-less-than-sign-state
+                State::ScriptDataEscapedLessThanSign => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('/') => {
+                            // SPEC: Set the temporary buffer to the empty string.
+                            self.temporary_buffer.clear();
+                            // SPEC: Switch to the script data escaped end tag open state.
+                            self.switch_to(State::ScriptDataEscapedEndTagOpen);
+                        }
+                        on_ascii_alpha!() => {
+                            // SPEC: Set the temporary buffer to the empty string.
+                            self.temporary_buffer.clear();
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token.
+                            self.emit_token(Token::Character { data: '<' });
+                            // SPEC: Reconsume in the script data double escape start state.
+                            self.reconsume_in(State::ScriptDataDoubleEscapeStart);
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token.
+                            self.emit_token(Token::Character { data: '<' });
+                            // SPEC: Reconsume in the script data escaped state.
+                            self.reconsume_in(State::ScriptDataEscaped);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-escaped-end-tag-open-state
+                State::ScriptDataEscapedEndTagOpen => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_ascii_alpha!() => {
+                            // SPEC: Create a new end tag token, set its tag name to the empty string.
+                            self.create_new_token(Token::EndTag {
+                                name: Atom::EMPTY,
+                                original_name: None,
+                                self_closing: false,
+                                attributes: vec![],
+                            });
+                            // SPEC: Reconsume in the script data escaped end tag name state.
+                            self.reconsume_in(State::ScriptDataEscapedEndTagName);
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token
+                            self.emit_token(Token::Character { data: '<' });
+                            // SPEC: and a U+002F SOLIDUS character token.
+                            self.emit_token(Token::Character { data: '/' });
+                            // SPEC: Reconsume in the script data escaped state.
+                            self.reconsume_in(State::ScriptDataEscaped);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-escaped-end-tag-name-state
+                State::ScriptDataEscapedEndTagName => {
+                    macro_rules! anything_else {
+                        () => {
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token,
+                            self.emit_token(Token::Character { data: '<' });
+
+                            // SPEC: a U+002F SOLIDUS character token,
+                            self.emit_token(Token::Character { data: '/' });
+
+                            // SPEC: and a character token for each of the characters in the temporary buffer
+                            //       (in the order they were added to the buffer).
+                            for character in self.temporary_buffer.clone().chars() {
+                                self.emit_token(Token::Character { data: character });
+                            }
+
+                            // SPEC: Reconsume in the script data escaped state.
+                            self.reconsume_in(State::ScriptDataEscaped);
+                        };
+                    }
+
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: If the current end tag token is an appropriate end tag token,
+                            if self.current_end_tag_token_is_an_appropriate_end_tag_token() {
+                                // SPEC: then switch to the before attribute name state.
+                                self.switch_to(State::BeforeAttributeName);
+                            } else {
+                                // SPEC: Otherwise, treat it as per the "anything else" entry below.
+                                anything_else!();
+                            }
+                        }
+                        on!('/') => {
+                            // SPEC: If the current end tag token is an appropriate end tag token,
+                            if self.current_end_tag_token_is_an_appropriate_end_tag_token() {
+                                // SPEC: then switch to the self-closing start tag state.
+                                self.switch_to(State::SelfClosingStartTag);
+                            } else {
+                                // SPEC: Otherwise, treat it as per the "anything else" entry below.
+                                anything_else!();
+                            }
+                        }
+                        on!('>') => {
+                            // SPEC: If the current end tag token is an appropriate end tag token,
+                            if self.current_end_tag_token_is_an_appropriate_end_tag_token() {
+                                // SPEC: then switch to the data state
+                                self.switch_to(State::Data);
+                                // SPEC: and emit the current tag token.
+                                self.emit_current_token();
+                            } else {
+                                // SPEC: Otherwise, treat it as per the "anything else" entry below.
+                                anything_else!();
+                            }
+                        }
+                        on_ascii_upper_alpha!(character) => {
+                            // SPEC: Append the lowercase version of the current input character (add 0x0020 to the character's code point) to the current tag token's tag name.
+                            self.append_to_current_tag_name(character);
+
+                            // SPEC: Append the current input character to the temporary buffer.
+                            self.temporary_buffer.push(character);
+                        }
+                        on_ascii_lower_alpha!(character) => {
+                            // SPEC: Append the current input character to the current tag token's tag name.
+                            self.append_to_current_tag_name(character);
+
+                            // SPEC: Append the current input character to the temporary buffer.
+                            self.temporary_buffer.push(character);
+                        }
+                        on_anything_else!() | None => {
+                            anything_else!();
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-double-escape-start-state
+                State::ScriptDataDoubleEscapeStart => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!(character) | Some(character @ ('/' | '>')) => {
+                            // SPEC: If the temporary buffer is the string "script",
+                            //       then switch to the script data double escaped state.
+                            //       Otherwise, switch to the script data escaped state.
+                            match self.temporary_buffer == "script" {
+                                true => self.switch_to(State::ScriptDataDoubleEscaped),
+                                false => self.switch_to(State::ScriptDataEscaped),
+                            }
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                        on_ascii_upper_alpha!(character) => {
+                            // SPEC: Append the lowercase version of the current input character (add 0x0020 to the character's code point) to the temporary buffer.
+                            self.temporary_buffer.push(character.to_ascii_lowercase());
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                        on_ascii_lower_alpha!(character) => {
+                            // SPEC: Append the current input character to the temporary buffer.
+                            self.temporary_buffer.push(character);
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: Reconsume in the script data escaped state.
+                            self.reconsume_in(State::ScriptDataEscaped);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-double-escaped-state
+                State::ScriptDataDoubleEscaped => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the script data double escaped dash state.
+                            self.switch_to(State::ScriptDataDoubleEscapedDash);
+                            // SPEC: Emit a U+002D HYPHEN-MINUS character token.
+                            self.emit_token(Token::Character { data: '-' });
+                        }
+                        on!('<') => {
+                            // SPEC: Switch to the script data double escaped less-than sign state.
+                            self.switch_to(State::ScriptDataDoubleEscapedLessThanSign);
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token.
+                            self.emit_token(Token::Character { data: '<' });
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
+                            self.emit_token(Token::Character { data: '\u{FFFD}' });
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-script-html-comment-like-text parse error.
+                            self.parse_error(ParseErrorKind::EofInScriptHtmlCommentLikeText);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-double-escaped-dash-state
+                State::ScriptDataDoubleEscapedDash => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the script data double escaped dash dash state.
+                            self.switch_to(State::ScriptDataDoubleEscapedDashDash);
+                            // SPEC: Emit a U+002D HYPHEN-MINUS character token.
+                            self.emit_token(Token::Character { data: '-' });
+                        }
+                        on!('<') => {
+                            // SPEC: Switch to the script data double escaped less-than sign state.
+                            self.switch_to(State::ScriptDataDoubleEscapedLessThanSign);
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token.
+                            self.emit_token(Token::Character { data: '<' });
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Switch to the script data double escaped state.
+                            self.switch_to(State::ScriptDataDoubleEscaped);
+                            // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
+                            self.emit_token(Token::Character { data: '\u{FFFD}' });
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-script-html-comment-like-text parse error.
+                            self.parse_error(ParseErrorKind::EofInScriptHtmlCommentLikeText);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Switch to the script data double escaped state.
+                            self.switch_to(State::ScriptDataDoubleEscaped);
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-double-escaped-dash-dash-state
+                State::ScriptDataDoubleEscapedDashDash => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Emit a U+002D HYPHEN-MINUS character token.
+                            self.emit_token(Token::Character { data: '-' });
+                        }
+                        on!('<') => {
+                            // SPEC: Switch to the script data double escaped less-than sign state.
+                            self.switch_to(State::ScriptDataDoubleEscapedLessThanSign);
+                            // SPEC: Emit a U+003C LESS-THAN SIGN character token.
+                            self.emit_token(Token::Character { data: '<' });
+                        }
+                        on!('>') => {
+                            // SPEC: Switch to the script data state.
+                            self.switch_to(State::ScriptData);
+                            // SPEC: Emit a U+003E GREATER-THAN SIGN character token.
+                            self.emit_token(Token::Character { data: '>' });
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Switch to the script data double escaped state.
+                            self.switch_to(State::ScriptDataDoubleEscaped);
+                            // SPEC: Emit a U+FFFD REPLACEMENT CHARACTER character token.
+                            self.emit_token(Token::Character { data: '\u{FFFD}' });
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-script-html-comment-like-text parse error.
+                            self.parse_error(ParseErrorKind::EofInScriptHtmlCommentLikeText);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Switch to the script data double escaped state.
+                            self.switch_to(State::ScriptDataDoubleEscaped);
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-double-escaped-less-than-sign-state
+                State::ScriptDataDoubleEscapedLessThanSign => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('/') => {
+                            // SPEC: Set the temporary buffer to the empty string.
+                            self.temporary_buffer.clear();
+                            // SPEC: Switch to the script data double escape end state.
+                            self.switch_to(State::ScriptDataDoubleEscapeEnd);
+                            // SPEC: Emit a U+002F SOLIDUS character token.
+                            self.emit_token(Token::Character { data: '/' });
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: Reconsume in the script data double escaped state.
+                            self.reconsume_in(State::ScriptDataDoubleEscaped);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#script-data-double-escape-end-state
+                State::ScriptDataDoubleEscapeEnd => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!(character) | Some(character @ ('/' | '>')) => {
+                            // SPEC: If the temporary buffer is the string "script",
+                            //       then switch to the script data escaped state.
+                            //       Otherwise, switch to the script data double escaped state.
+                            match self.temporary_buffer == "script" {
+                                true => self.switch_to(State::ScriptDataEscaped),
+                                false => self.switch_to(State::ScriptDataDoubleEscaped),
+                            }
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                        on_ascii_upper_alpha!(character) => {
+                            // SPEC: Append the lowercase version of the current input character (add 0x0020 to the character's code point) to the temporary buffer.
+                            self.temporary_buffer.push(character.to_ascii_lowercase());
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                        on_ascii_lower_alpha!(character) => {
+                            // SPEC: Append the current input character to the temporary buffer.
+                            self.temporary_buffer.push(character);
+                            // SPEC: Emit the current input character as a character token.
+                            self.emit_token(Token::Character { data: character });
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: Reconsume in the script data double escaped state.
+                            self.reconsume_in(State::ScriptDataDoubleEscaped);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#before-attribute-name-state
+                State::BeforeAttributeName => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Ignore the character
+                            continue;
+                        }
+                        on!('/') | on!('>') | on_eof!() => {
+                            // SPEC: Reconsume in the after attribute name state.
+                            self.reconsume_in(State::AfterAttributeName);
+                        }
+                        on!('=') => {
+                            // SPEC: This is an unexpected-equals-sign-before-attribute-name parse error.
+                            self.parse_error(
+                                ParseErrorKind::UnexpectedEqualsSignBeforeAttributeName,
+                            );
+                            // SPEC: Start a new attribute in the current tag token.
+                            self.set_current_attribute(Attribute {
+                                name: Atom::EMPTY,
+                                original_name: None,
+                                value: String::new(),
+                                prefix: None,
+                                namespace: None,
+                            });
+                            // SPEC: Set that attribute's name to the current input character, and its value to the empty string.
+                            self.append_to_current_attribute_name('=');
+                            // SPEC: Switch to the attribute name state.
+                            self.switch_to(State::AttributeName);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: Start a new attribute in the current tag token.
+                            self.set_current_attribute(Attribute {
+                                // Set that attribute name and value to the empty string.
+                                name: Atom::EMPTY,
+                                original_name: None,
+                                value: String::new(),
+                                prefix: None,
+                                namespace: None,
+                            });
+                            // SPEC: Reconsume in the attribute name state.
+                            self.reconsume_in(State::AttributeName);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#attribute-name-state
+                State::AttributeName => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() | on!('/') | on!('>') | on_eof!() => {
+                            // SPEC: Reconsume in the after attribute name state.
+                            self.reconsume_in(State::AfterAttributeName);
+                        }
+                        on!('=') => {
+                            // SPEC: Switch to the before attribute value state.
+                            self.switch_to(State::BeforeAttributeValue);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current attribute's name.
+                            self.append_to_current_attribute_name('\u{FFFD}');
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: ASCII upper alpha
+                            //          Append the lowercase version of the current input character
+                            //          (add 0x0020 to the character's code point)
+                            //          to the current attribute's name.
+                            // NOTE: This happens in append_to_current_attribute_name.
+
+                            if let '"' | '\'' | '<' = character {
+                                // SPEC: This is an unexpected-character-in-attribute-name parse error.
+                                self.parse_error(
+                                    ParseErrorKind::UnexpectedCharacterInAttributeName,
+                                );
+
+                                // Treat it as per the "anything else" entry below.
+                                // FIXME: Implement
+                            }
+
+                            // Append the current input character to the current attribute's name.
+                            self.append_to_current_attribute_name(character);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-attribute-name-state
+                State::AfterAttributeName => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Ignore the character
+                            continue;
+                        }
+                        on!('/') => {
+                            // SPEC: Switch to the self-closing start tag state.
+                            self.switch_to(State::SelfClosingStartTag)
+                        }
+                        on!('=') => {
+                            // SPEC: Emit the current tag token.
+                            self.switch_to(State::BeforeAttributeValue)
+                        }
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current tag token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-tag parse error.
+                            self.parse_error(ParseErrorKind::EofInTag);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: Start a new attribute in the current tag token.
+                            self.set_current_attribute(Attribute {
+                                // SPEC: Set that attribute name and value to the empty string.
+                                name: Atom::EMPTY,
+                                original_name: None,
+                                value: String::new(),
+                                prefix: None,
+                                namespace: None,
+                            });
+                            // SPEC: Reconsume in the attribute name state.
+                            self.reconsume_in(State::AttributeName);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#before-attribute-value-state
+                State::BeforeAttributeValue => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Ignore the character
+                            continue;
+                        }
+                        on!('"') => {
+                            // SPEC: Switch to the attribute value (double-quoted) state.
+                            self.switch_to(State::AttributeValueDoubleQuoted);
+                        }
+                        on!('\'') => {
+                            // SPEC: Switch to the attribute value (double-quoted) state.
+                            self.switch_to(State::AttributeValueSingleQuoted);
+                        }
+                        on!('>') => {
+                            // SPEC: This is a missing-attribute-value parse error.
+                            self.parse_error(ParseErrorKind::MissingAttributeValue);
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current tag token.
+                            self.emit_current_token();
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: Reconsume in the attribute value (unquoted) state.
+                            self.reconsume_in(State::AttributeValueUnquoted);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#attribute-value-(double-quoted)-state
+                State::AttributeValueDoubleQuoted => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('"') => {
+                            // SPEC: Switch to the after attribute value (quoted) state.
+                            self.switch_to(State::AfterAttributeValueQuoted);
+                        }
+                        on!('&') => {
+                            // SPEC: Set the return state to the attribute value (double-quoted) state.
+                            self.set_return_state(State::AttributeValueDoubleQuoted);
+                            // SPEC: Switch to the character reference state.
+                            self.switch_to(State::CharacterReference);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current attribute's value.
+                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                                value.push('\u{FFFD}')
+                            }
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-tag parse error.
+                            self.parse_error(ParseErrorKind::EofInTag);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Append the current input character to the current attribute's value.
+                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                                value.push(character)
+                            }
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#attribute-value-(single-quoted)-state
+                State::AttributeValueSingleQuoted => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('\'') => {
+                            // SPEC: Switch to the after attribute value (quoted) state.
+                            self.switch_to(State::AfterAttributeValueQuoted);
+                        }
+                        on!('&') => {
+                            // SPEC: Set the return state to the attribute value (single-quoted) state.
+                            self.set_return_state(State::AttributeValueSingleQuoted);
+                            // SPEC: Switch to the character reference state.
+                            self.switch_to(State::CharacterReference);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current attribute's value.
+                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                                value.push('\u{FFFD}')
+                            }
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-tag parse error.
+                            self.parse_error(ParseErrorKind::EofInTag);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Append the current input character to the current attribute's value.
+                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                                value.push(character)
+                            }
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#attribute-value-(unquoted)-state
+                State::AttributeValueUnquoted => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Switch to the before attribute name state.
+                            self.switch_to(State::BeforeAttributeName);
+                        }
+                        on!('&') => {
+                            // SPEC: Set the return state to the attribute value (unquoted) state.
+                            self.set_return_state(State::AttributeValueUnquoted);
+                            // SPEC: Switch to the character reference state.
+                            self.switch_to(State::CharacterReference);
+                        }
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current tag token.
+                            self.emit_current_token();
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current attribute's value.
+                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                                value.push('\u{FFFD}')
+                            }
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-tag parse error.
+                            self.parse_error(ParseErrorKind::EofInTag);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            if let '"' | '\'' | '<' | '=' | '`' = character {
+                                // SPEC: This is an unexpected-character-in-unquoted-attribute-value parse error.
+                                self.parse_error(
+                                    ParseErrorKind::UnexpectedCharacterInUnquotedAttributeValue,
+                                );
+
+                                // SPEC: Treat it as per the "anything else" entry below.
+                            }
+
+                            // SPEC: Append the current input character to the current attribute's value.
+                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                                value.push(character)
+                            }
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-attribute-value-(quoted)-state
+                State::AfterAttributeValueQuoted => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Switch to the before attribute name state.
+                            self.switch_to(State::BeforeAttributeName);
+                        }
+                        on!('/') => {
+                            // SPEC: Switch to the self-closing start tag state.
+                            self.switch_to(State::SelfClosingStartTag);
+                        }
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current tag token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-tag parse error.
+                            self.parse_error(ParseErrorKind::EofInTag);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is a missing-whitespace-between-attributes parse error.
+                            self.parse_error(ParseErrorKind::MissingWhitespaceBetweenAttributes);
+                            // SPEC: Reconsume in the before attribute name state.
+                            self.reconsume_in(State::BeforeAttributeName);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#self-closing-start-tag-state
+                State::SelfClosingStartTag => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('>') => {
+                            // SPEC: Set the self-closing flag of the current tag token.
+                            if let Some(Token::StartTag { self_closing, .. }) =
+                                &mut self.current_building_token
+                            {
+                                *self_closing = true
+                            }
+                            // SPEC: Emit the current tag token.
+                            self.emit_current_token();
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-tag parse error.
+                            self.parse_error(ParseErrorKind::EofInTag);
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is an unexpected-solidus-in-tag parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedSolidusInTag);
+                            // SPEC: Reconsume in the before attribute name state.
+                            self.reconsume_in(State::BeforeAttributeName);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#bogus-comment-state
+                State::BogusComment => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: Emit the comment.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the comment token's data.
+                            if let Some(Token::Comment { data }) = &mut self.current_building_token
+                            {
+                                data.push('\u{FFFD}');
+                            }
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Append the current input character to the comment token's data.
+                            if let Some(Token::Comment { data }) = &mut self.current_building_token
+                            {
+                                data.push(character);
+                            }
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#markup-declaration-open-state
+                State::MarkupDeclarationOpen => {
+                    // SPEC: Two U+002D HYPHEN-MINUS characters (-)
+                    if self.next_characters_are_ascii_case_insensitive("--") {
+                        // SPEC: Consume those two characters,
+                        self.consume_characters("--");
+
+                        // SPEC: create a comment token whose data is the empty string,
+                        self.create_new_token(Token::Comment {
+                            data: String::new(),
+                        });
+
+                        // SPEC: and switch to the comment start state.
+                        self.switch_to(State::CommentStart);
+                        continue;
+                    }
+
+                    // SPEC: ASCII case-insensitive match for the word "DOCTYPE"
+                    if self.next_characters_are_ascii_case_insensitive("DOCTYPE") {
+                        self.consume_characters("DOCTYPE");
+                        self.switch_to(State::Doctype);
+                        continue;
+                    }
+
+                    // SPEC: The string "[CDATA[" (the five uppercase letters "CDATA" with a U+005B LEFT SQUARE BRACKET character before and after)
+                    if self.next_characters_are("[CDATA[") {
+                        // SPEC: Consume those characters.
+                        self.consume_characters("[CDATA[");
+
+                        // SPEC: If there is an adjusted current node and it is not an element in the HTML namespace, then switch to the CDATA section state.
+                        if self.cdata_sections_allowed {
+                            self.switch_to(State::CDataSection);
+                            continue;
+                        }
+
+                        // SPEC: Otherwise, this is a cdata-in-html-content parse error.
+                        self.parse_error(ParseErrorKind::CdataInHtmlContent);
+                        // SPEC: Create a comment token whose data is the "[CDATA[" string.
+                        self.create_new_token(Token::Comment {
+                            data: "[CDATA[".to_string(),
+                        });
+                        // SPEC: Switch to the bogus comment state.
+                        self.switch_to(State::BogusComment);
+                        continue;
+                    }
+
+                    // SPEC: Anything else
+                    //       This is an incorrectly-opened-comment parse error.
+                    self.parse_error(ParseErrorKind::IncorrectlyOpenedComment);
+
+                    // SPEC: Create a comment token whose data is the empty string.
+                    self.create_new_token(Token::Comment {
+                        data: String::new(),
+                    });
+                    // SPEC: Switch to the bogus comment state (don't consume anything in the current state).
+                    self.switch_to(State::BogusComment);
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-start-state
+                State::CommentStart => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the comment start dash state.
+                            self.switch_to(State::CommentStartDash);
+                        }
+                        on!('>') => {
+                            // SPEC: This is an abrupt-closing-of-empty-comment parse error.
+                            self.parse_error(ParseErrorKind::AbruptClosingOfEmptyComment);
+
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: Reconsume in the comment state.
+                            self.reconsume_in(State::Comment);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-start-dash-state
+                State::CommentStartDash => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the comment end state.
+                            self.switch_to(State::CommentEnd);
+                        }
+                        on!('>') => {
+                            // SPEC: This is an abrupt-closing-of-empty-comment parse error.
+                            self.parse_error(ParseErrorKind::AbruptClosingOfEmptyComment);
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-comment parse error.
+                            self.parse_error(ParseErrorKind::EofInComment);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: Append a U+002D HYPHEN-MINUS character (-) to the comment token's data.
+                            self.append_to_current_comment_data("-");
+                            // SPEC: Reconsume in the comment state.
+                            self.reconsume_in(State::Comment);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-state
+                State::Comment => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('<') => {
+                            // SPEC: Append the current input character to the comment token's data.
+                            self.append_to_current_comment_data("<");
+                            // SPEC: Switch to the comment less-than sign state.
+                            self.switch_to(State::CommentLessThanSign);
+                        }
+                        on!('-') => {
+                            // SPEC: Switch to the comment end dash state.
+                            self.switch_to(State::CommentEndDash);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the comment token's data.
+                            self.append_to_current_comment_data("\u{FFFD}");
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-comment parse error.
+                            self.parse_error(ParseErrorKind::EofInComment);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Append the current input character to the comment token's data.
+                            self.append_to_current_comment_data(&character.to_string());
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-less-than-sign-state
+                State::CommentLessThanSign => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('!') => {
+                            // SPEC: Append the current input character to the comment token's data.
+                            self.append_to_current_comment_data("!");
+                            // SPEC: Switch to the comment less-than sign bang state.
+                            self.switch_to(State::CommentLessThanSignBang);
+                        }
+                        on!('<') => {
+                            // SPEC: Append the current input character to the comment token's data.
+                            self.append_to_current_comment_data("<");
+                        }
+                        on_anything_else!() | on_eof!() => {
+                            // SPEC: Reconsume in the comment state.
+                            self.reconsume_in(State::Comment);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-less-than-sign-bang-state
+                State::CommentLessThanSignBang => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the comment less-than sign bang dash state.
+                            self.switch_to(State::CommentLessThanSignBangDash);
+                        }
+                        on_anything_else!() | on_eof!() => {
+                            // SPEC: Reconsume in the comment state.
+                            self.reconsume_in(State::Comment);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-less-than-sign-bang-dash-state
+                State::CommentLessThanSignBangDash => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the comment less-than sign bang dash dash state.
+                            self.switch_to(State::CommentLessThanSignBangDashDash);
+                        }
+                        on_anything_else!() | on_eof!() => {
+                            // SPEC: Reconsume in the comment end dash state.
+                            self.reconsume_in(State::CommentEndDash);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-less-than-sign-bang-dash-dash-state
+                State::CommentLessThanSignBangDashDash => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('>') | on_eof!() => {
+                            // SPEC: Reconsume in the comment end state.
+                            self.reconsume_in(State::CommentEnd);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is a nested-comment parse error.
+                            self.parse_error(ParseErrorKind::NestedComment);
+                            // SPEC: Reconsume in the comment end state.
+                            self.reconsume_in(State::CommentEnd);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-end-dash-state
+                State::CommentEndDash => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Switch to the comment end state.
+                            self.switch_to(State::CommentEnd);
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-comment parse error.
+                            self.parse_error(ParseErrorKind::EofInComment);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: Append a U+002D HYPHEN-MINUS character (-) to the comment token's data.
+                            self.append_to_current_comment_data("-");
+                            // SPEC: Reconsume in the comment state.
+                            self.reconsume_in(State::Comment);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-end-state
+                State::CommentEnd => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                        }
+                        on!('!') => {
+                            // SPEC: Switch to the comment end bang state.
+                            self.switch_to(State::CommentEndBang);
+                        }
+                        on!('-') => {
+                            // SPEC: Append a U+002D HYPHEN-MINUS character (-) to the comment token's data.
+                            self.append_to_current_comment_data("-");
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-comment parse error.
+                            self.parse_error(ParseErrorKind::EofInComment);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: Append two U+002D HYPHEN-MINUS characters (-) to the comment token's data.
+                            self.append_to_current_comment_data("--");
+                            // SPEC: Reconsume in the comment state.
+                            self.reconsume_in(State::Comment);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-end-bang-state
+                State::CommentEndBang => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('-') => {
+                            // SPEC: Append two U+002D HYPHEN-MINUS characters (-) and a U+0021 EXCLAMATION MARK character (!) to the comment token's data.
+                            self.append_to_current_comment_data("--!");
+                            // SPEC: Switch to the comment end dash state.
+                            self.switch_to(State::CommentEndDash);
+                        }
+                        on!('>') => {
+                            // SPEC: This is an incorrectly-closed-comment parse error.
+                            self.parse_error(ParseErrorKind::IncorrectlyClosedComment);
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-comment parse error.
+                            self.parse_error(ParseErrorKind::EofInComment);
+                            // SPEC: Emit the current comment token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: Append two U+002D HYPHEN-MINUS characters (-) and a U+0021 EXCLAMATION MARK character (!) to the comment token's data.
+                            self.append_to_current_comment_data("--!");
+                            // SPEC: Reconsume in the comment state.
+                            self.reconsume_in(State::Comment);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#doctype-state
+                State::Doctype => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Switch to the before DOCTYPE name state.
+                            self.switch_to(State::BeforeDoctypeName);
+                            continue;
+                        }
+                        on!('>') => {
+                            // SPEC: Reconsume in the before DOCTYPE name state.
+                            self.reconsume_in(State::BeforeDoctypeName);
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Create a new DOCTYPE token. Set its force-quirks flag to on.
+                            //       Emit the current token.
+                            self.emit_token(Token::Doctype {
+                                name: None,
+                                public_identifier: None,
+                                system_identifier: None,
+                                force_quirks: true,
+                            });
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is a missing-whitespace-before-doctype-name parse error.
+                            self.parse_error(ParseErrorKind::MissingWhitespaceBeforeDoctypeName);
+                            // SPEC: Reconsume in the before DOCTYPE name state.
+                            self.reconsume_in(State::BeforeDoctypeName);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#before-doctype-name-state
+                State::BeforeDoctypeName => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Ignore the character.
+                            continue;
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Create a new DOCTYPE token.
+                            self.create_new_token(Token::Doctype {
+                                // SPEC: Set the token's name to a U+FFFD REPLACEMENT CHARACTER character.
+                                name: Some(String::from('\u{FFFD}')),
+                                public_identifier: None,
+                                system_identifier: None,
+                                force_quirks: false,
+                            });
+                            // SPEC: Switch to the DOCTYPE name state.
+                            self.switch_to(State::DoctypeName);
+                        }
+                        on!('>') => {
+                            // SPEC: This is a missing-doctype-name parse error.
+                            self.parse_error(ParseErrorKind::MissingDoctypeName);
+                            // SPEC: Create a new DOCTYPE token. Set its force-quirks flag to on.
+                            self.create_new_token(Token::Doctype {
+                                name: None,
+                                public_identifier: None,
+                                system_identifier: None,
+                                force_quirks: true,
+                            });
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+
+                            // SPEC: Create a new DOCTYPE token.
+                            //       Emit the current token.
+                            self.emit_token(Token::Doctype {
+                                name: None,
+                                public_identifier: None,
+                                system_identifier: None,
+                                // SPEC: Set its force-quirks flag to on.
+                                force_quirks: true,
+                            });
+
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
                         }
-                        on_null!() => todo!(),
                         on_anything_else!(character) => {
                             // SPEC: ASCII upper alpha
-                            //          Append the lowercase version of the current input character
-                            //          (add 0x0020 to the character's code point)
-                            //          to the current attribute's name.
+                            //          Set the token's name to the lowercase version of the current input character
+                            //          (add 0x0020 to the character's code point).
                             let character = character.to_ascii_lowercase();
 
-                            if let '"' | '\'' | '<' = character {
-                                // SPEC: This is an unexpected-character-in-attribute-name parse error.
-                                // FIXME: Implement
-
-                                // Treat it as per the "anything else" entry below.
-                                // FIXME: Implement
+                            // SPEC: Create a new DOCTYPE token.
+                            self.create_new_token(Token::Doctype {
+                                // SPEC: Set the token's name to the current input character.
+                                name: Some(String::from(character)),
+                                public_identifier: None,
+                                system_identifier: None,
+                                force_quirks: false,
+                            });
+                            // SPEC: Switch to the DOCTYPE name state.
+                            self.switch_to(State::DoctypeName);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#doctype-name-state
+                State::DoctypeName => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Switch to the after DOCTYPE name state.
+                            self.switch_to(State::AfterDoctypeName);
+                            continue;
+                        }
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            continue;
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current DOCTYPE token's name.
+                            if let Some(Token::Doctype {
+                                name: Some(name), ..
+                            }) = &mut self.current_building_token
+                            {
+                                name.push('\u{FFFD}')
                             }
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: ASCII upper alpha
+                            //          Append the lowercase version of the current input character
+                            //          (add 0x0020 to the character's code point)
+                            //          to the current DOCTYPE token's name.
+                            let character = character.to_ascii_lowercase();
 
-                            // Append the current input character to the current attribute's name.
-                            if let Some(Attribute { name, .. }) = &mut self.current_attribute {
+                            // SPEC: Append the current input character to the current DOCTYPE token's name.
+                            if let Some(Token::Doctype {
+                                name: Some(name), ..
+                            }) = &mut self.current_building_token
+                            {
                                 name.push(character)
                             }
                         }
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-attribute-name-state
-                State::AfterAttributeName => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-doctype-name-state
+                State::AfterDoctypeName => {
                     self.consume_next_input_character();
                     match self.current_input_character {
                         on_whitespace!() => {
-                            // SPEC: Ignore the character
-                            continue;
+                            // SPEC: Ignore the character.
                         }
-                        on!('/') => {
-                            // SPEC: Switch to the self-closing start tag state.
-                            self.switch_to(State::SelfClosingStartTag)
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
                         }
-                        on!('=') => {
-                            // SPEC: Emit the current tag token.
-                            self.switch_to(State::BeforeAttributeValue)
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: If the six characters starting from the current input character are an ASCII case-insensitive match for the word "PUBLIC",
+                            //       then consume those characters and switch to the after DOCTYPE public keyword state.
+                            // SPEC: Otherwise, if the six characters starting from the current input character are an ASCII case-insensitive match for the word "SYSTEM",
+                            //       then consume those characters and switch to the after DOCTYPE system keyword state.
+                            // NOTE: We move the insertion point back to the current input character, so we can look at the six characters starting from it.
+                            self.reconsume_in(State::AfterDoctypeName);
+                            if self.next_characters_are_ascii_case_insensitive("PUBLIC") {
+                                self.consume_characters("PUBLIC");
+                                self.switch_to(State::AfterDoctypePublicKeyword);
+                            } else if self.next_characters_are_ascii_case_insensitive("SYSTEM") {
+                                self.consume_characters("SYSTEM");
+                                self.switch_to(State::AfterDoctypeSystemKeyword);
+                            } else {
+                                // SPEC: Otherwise, this is an invalid-character-sequence-after-doctype-name parse error.
+                                self.parse_error(
+                                    ParseErrorKind::InvalidCharacterSequenceAfterDoctypeName,
+                                );
+                                // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                                self.set_current_doctype_force_quirks_flag();
+                                // SPEC: Reconsume in the bogus DOCTYPE state.
+                                // NOTE: The insertion point has already been moved back to the current input character.
+                                self.switch_to(State::BogusDoctype);
+                            }
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-doctype-public-keyword-state
+                State::AfterDoctypePublicKeyword => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Switch to the before DOCTYPE public identifier state.
+                            self.switch_to(State::BeforeDoctypePublicIdentifier);
+                        }
+                        on!('"') => {
+                            // SPEC: This is a missing-whitespace-after-doctype-public-keyword parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingWhitespaceAfterDoctypePublicKeyword,
+                            );
+                            // SPEC: Set the current DOCTYPE token's public identifier to the empty string (not missing),
+                            self.set_current_doctype_public_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE public identifier (double-quoted) state.
+                            self.switch_to(State::DoctypePublicIdentifierDoubleQuoted);
+                        }
+                        on!('\'') => {
+                            // SPEC: This is a missing-whitespace-after-doctype-public-keyword parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingWhitespaceAfterDoctypePublicKeyword,
+                            );
+                            // SPEC: Set the current DOCTYPE token's public identifier to the empty string (not missing),
+                            self.set_current_doctype_public_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE public identifier (single-quoted) state.
+                            self.switch_to(State::DoctypePublicIdentifierSingleQuoted);
                         }
                         on!('>') => {
+                            // SPEC: This is a missing-doctype-public-identifier parse error.
+                            self.parse_error(ParseErrorKind::MissingDoctypePublicIdentifier);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
                             // SPEC: Switch to the data state.
                             self.switch_to(State::Data);
-                            // SPEC: Emit the current tag token.
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is a missing-quote-before-doctype-public-identifier parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingQuoteBeforeDoctypePublicIdentifier,
+                            );
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Reconsume in the bogus DOCTYPE state.
+                            self.reconsume_in(State::BogusDoctype);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#before-doctype-public-identifier-state
+                State::BeforeDoctypePublicIdentifier => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_whitespace!() => {
+                            // SPEC: Ignore the character.
+                        }
+                        on!('"') => {
+                            // SPEC: Set the current DOCTYPE token's public identifier to the empty string (not missing),
+                            self.set_current_doctype_public_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE public identifier (double-quoted) state.
+                            self.switch_to(State::DoctypePublicIdentifierDoubleQuoted);
+                        }
+                        on!('\'') => {
+                            // SPEC: Set the current DOCTYPE token's public identifier to the empty string (not missing),
+                            self.set_current_doctype_public_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE public identifier (single-quoted) state.
+                            self.switch_to(State::DoctypePublicIdentifierSingleQuoted);
+                        }
+                        on!('>') => {
+                            // SPEC: This is a missing-doctype-public-identifier parse error.
+                            self.parse_error(ParseErrorKind::MissingDoctypePublicIdentifier);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is a missing-quote-before-doctype-public-identifier parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingQuoteBeforeDoctypePublicIdentifier,
+                            );
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Reconsume in the bogus DOCTYPE state.
+                            self.reconsume_in(State::BogusDoctype);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#doctype-public-identifier-(double-quoted)-state
+                State::DoctypePublicIdentifierDoubleQuoted => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('"') => {
+                            // SPEC: Switch to the after DOCTYPE public identifier state.
+                            self.switch_to(State::AfterDoctypePublicIdentifier);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current DOCTYPE token's public identifier.
+                            self.append_to_current_doctype_public_identifier('\u{FFFD}');
+                        }
+                        on!('>') => {
+                            // SPEC: This is a abrupt-doctype-public-identifier parse error.
+                            self.parse_error(ParseErrorKind::AbruptDoctypePublicIdentifier);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Append the current input character to the current DOCTYPE token's public identifier.
+                            self.append_to_current_doctype_public_identifier(character);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#doctype-public-identifier-(single-quoted)-state
+                State::DoctypePublicIdentifierSingleQuoted => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!('\'') => {
+                            // SPEC: Switch to the after DOCTYPE public identifier state.
+                            self.switch_to(State::AfterDoctypePublicIdentifier);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current DOCTYPE token's public identifier.
+                            self.append_to_current_doctype_public_identifier('\u{FFFD}');
+                        }
+                        on!('>') => {
+                            // SPEC: This is a abrupt-doctype-public-identifier parse error.
+                            self.parse_error(ParseErrorKind::AbruptDoctypePublicIdentifier);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
                             self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
                         }
-                        on_eof!() => todo!(),
-                        on_anything_else!() => {
-                            // SPEC: Start a new attribute in the current tag token.
-                            self.set_current_attribute(Attribute {
-                                // SPEC: Set that attribute name and value to the empty string.
-                                name: String::new(),
-                                value: String::new(),
-                            });
-                            // SPEC: Reconsume in the attribute name state.
-                            self.reconsume_in(State::AttributeName);
+                        on_anything_else!(character) => {
+                            // SPEC: Append the current input character to the current DOCTYPE token's public identifier.
+                            self.append_to_current_doctype_public_identifier(character);
                         }
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#before-attribute-value-state
-                State::BeforeAttributeValue => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-doctype-public-identifier-state
+                State::AfterDoctypePublicIdentifier => {
                     self.consume_next_input_character();
                     match self.current_input_character {
                         on_whitespace!() => {
-                            // SPEC: Ignore the character
-                            continue;
+                            // SPEC: Switch to the between DOCTYPE public and system identifiers state.
+                            self.switch_to(State::BetweenDoctypePublicAndSystemIdentifiers);
+                        }
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
                         }
                         on!('"') => {
-                            // SPEC: Switch to the attribute value (double-quoted) state.
-                            self.switch_to(State::AttributeValueDoubleQuoted);
+                            // SPEC: This is a missing-whitespace-between-doctype-public-and-system-identifiers parse error.
+                            self.parse_error(ParseErrorKind::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers);
+                            // SPEC: Set the current DOCTYPE token's system identifier to the empty string (not missing),
+                            self.set_current_doctype_system_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE system identifier (double-quoted) state.
+                            self.switch_to(State::DoctypeSystemIdentifierDoubleQuoted);
                         }
                         on!('\'') => {
-                            // SPEC: Switch to the attribute value (double-quoted) state.
-                            self.switch_to(State::AttributeValueSingleQuoted);
+                            // SPEC: This is a missing-whitespace-between-doctype-public-and-system-identifiers parse error.
+                            self.parse_error(ParseErrorKind::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers);
+                            // SPEC: Set the current DOCTYPE token's system identifier to the empty string (not missing),
+                            self.set_current_doctype_system_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE system identifier (single-quoted) state.
+                            self.switch_to(State::DoctypeSystemIdentifierSingleQuoted);
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
                         }
-                        on!('>') => todo!(),
                         on_anything_else!() => {
-                            // SPEC: Reconsume in the attribute value (unquoted) state.
-                            self.reconsume_in(State::AttributeValueUnquoted);
+                            // SPEC: This is a missing-quote-before-doctype-system-identifier parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingQuoteBeforeDoctypeSystemIdentifier,
+                            );
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Reconsume in the bogus DOCTYPE state.
+                            self.reconsume_in(State::BogusDoctype);
                         }
-                        on_eof!() => {}
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#attribute-value-(double-quoted)-state
-                State::AttributeValueDoubleQuoted => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#between-doctype-public-and-system-identifiers-state
+                State::BetweenDoctypePublicAndSystemIdentifiers => {
                     self.consume_next_input_character();
                     match self.current_input_character {
-                        on!('"') => {
-                            // SPEC: Switch to the after attribute value (quoted) state.
-                            self.switch_to(State::AfterAttributeValueQuoted);
+                        on_whitespace!() => {
+                            // SPEC: Ignore the character.
                         }
-                        on!('&') => {
-                            // SPEC: Set the return state to the attribute value (double-quoted) state.
-                            self.set_return_state(State::AttributeValueDoubleQuoted);
-                            // SPEC: Switch to the character reference state.
-                            self.switch_to(State::CharacterReference);
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
                         }
-                        on_null!() => todo!(),
-                        on_eof!() => todo!(),
-                        on_anything_else!(character) => {
-                            // SPEC: Append the current input character to the current attribute's value.
-                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
-                                value.push(character)
-                            }
+                        on!('"') => {
+                            // SPEC: Set the current DOCTYPE token's system identifier to the empty string (not missing),
+                            self.set_current_doctype_system_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE system identifier (double-quoted) state.
+                            self.switch_to(State::DoctypeSystemIdentifierDoubleQuoted);
                         }
-                    }
-                }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#attribute-value-(single-quoted)-state
-                State::AttributeValueSingleQuoted => {
-                    self.consume_next_input_character();
-                    match self.current_input_character {
                         on!('\'') => {
-                            // SPEC: Switch to the after attribute value (quoted) state.
-                            self.switch_to(State::AfterAttributeValueQuoted);
+                            // SPEC: Set the current DOCTYPE token's system identifier to the empty string (not missing),
+                            self.set_current_doctype_system_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE system identifier (single-quoted) state.
+                            self.switch_to(State::DoctypeSystemIdentifierSingleQuoted);
                         }
-                        on!('&') => {
-                            // SPEC: Set the return state to the attribute value (single-quoted) state.
-                            self.set_return_state(State::AttributeValueSingleQuoted);
-                            // SPEC: Switch to the character reference state.
-                            self.switch_to(State::CharacterReference);
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
                         }
-                        on_null!() => todo!(),
-                        on_eof!() => todo!(),
-                        on_anything_else!(character) => {
-                            // SPEC: Append the current input character to the current attribute's value.
-                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
-                                value.push(character)
-                            }
+                        on_anything_else!() => {
+                            // SPEC: This is a missing-quote-before-doctype-system-identifier parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingQuoteBeforeDoctypeSystemIdentifier,
+                            );
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Reconsume in the bogus DOCTYPE state.
+                            self.reconsume_in(State::BogusDoctype);
                         }
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#attribute-value-(unquoted)-state
-                State::AttributeValueUnquoted => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-doctype-system-keyword-state
+                State::AfterDoctypeSystemKeyword => {
                     self.consume_next_input_character();
                     match self.current_input_character {
                         on_whitespace!() => {
-                            // SPEC: Switch to the before attribute name state.
-                            self.switch_to(State::BeforeAttributeName);
+                            // SPEC: Switch to the before DOCTYPE system identifier state.
+                            self.switch_to(State::BeforeDoctypeSystemIdentifier);
+                        }
+                        on!('"') => {
+                            // SPEC: This is a missing-whitespace-after-doctype-system-keyword parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingWhitespaceAfterDoctypeSystemKeyword,
+                            );
+                            // SPEC: Set the current DOCTYPE token's system identifier to the empty string (not missing),
+                            self.set_current_doctype_system_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE system identifier (double-quoted) state.
+                            self.switch_to(State::DoctypeSystemIdentifierDoubleQuoted);
+                        }
+                        on!('\'') => {
+                            // SPEC: This is a missing-whitespace-after-doctype-system-keyword parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingWhitespaceAfterDoctypeSystemKeyword,
+                            );
+                            // SPEC: Set the current DOCTYPE token's system identifier to the empty string (not missing),
+                            self.set_current_doctype_system_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE system identifier (single-quoted) state.
+                            self.switch_to(State::DoctypeSystemIdentifierSingleQuoted);
                         }
-                        on!('&') => todo!(),
                         on!('>') => {
+                            // SPEC: This is a missing-doctype-system-identifier parse error.
+                            self.parse_error(ParseErrorKind::MissingDoctypeSystemIdentifier);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
                             // SPEC: Switch to the data state.
                             self.switch_to(State::Data);
-                            // SPEC: Emit the current tag token.
+                            // SPEC: Emit the current DOCTYPE token.
                             self.emit_current_token();
                         }
-                        on_null!() => todo!(),
-                        on_eof!() => todo!(),
-                        on_anything_else!(character) => {
-                            if let '"' | '\'' | '<' | '=' | '`' = character {
-                                // SPEC: This is an unexpected-character-in-unquoted-attribute-value parse error.
-                                // FIXME: Implement
-
-                                // SPEC: Treat it as per the "anything else" entry below.
-                            }
-
-                            // SPEC: Append the current input character to the current attribute's value.
-                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
-                                value.push(character)
-                            }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is a missing-quote-before-doctype-system-identifier parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingQuoteBeforeDoctypeSystemIdentifier,
+                            );
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Reconsume in the bogus DOCTYPE state.
+                            self.reconsume_in(State::BogusDoctype);
                         }
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-attribute-value-(quoted)-state
-                State::AfterAttributeValueQuoted => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#before-doctype-system-identifier-state
+                State::BeforeDoctypeSystemIdentifier => {
                     self.consume_next_input_character();
                     match self.current_input_character {
                         on_whitespace!() => {
-                            // SPEC: Switch to the before attribute name state.
-                            self.switch_to(State::BeforeAttributeName);
+                            // SPEC: Ignore the character.
                         }
-                        on!('/') => {
-                            // SPEC: Switch to the self-closing start tag state.
-                            self.switch_to(State::SelfClosingStartTag);
+                        on!('"') => {
+                            // SPEC: Set the current DOCTYPE token's system identifier to the empty string (not missing),
+                            self.set_current_doctype_system_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE system identifier (double-quoted) state.
+                            self.switch_to(State::DoctypeSystemIdentifierDoubleQuoted);
+                        }
+                        on!('\'') => {
+                            // SPEC: Set the current DOCTYPE token's system identifier to the empty string (not missing),
+                            self.set_current_doctype_system_identifier_to_the_empty_string();
+                            //       then switch to the DOCTYPE system identifier (single-quoted) state.
+                            self.switch_to(State::DoctypeSystemIdentifierSingleQuoted);
                         }
                         on!('>') => {
+                            // SPEC: This is a missing-doctype-system-identifier parse error.
+                            self.parse_error(ParseErrorKind::MissingDoctypeSystemIdentifier);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
                             // SPEC: Switch to the data state.
                             self.switch_to(State::Data);
-                            // SPEC: Emit the current tag token.
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
                             self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
                         }
-                        on_eof!() => todo!(),
                         on_anything_else!() => {
-                            // SPEC: This is a missing-whitespace-between-attributes parse error.
-                            //       Reconsume in the before attribute name state.
-                            self.reconsume_in(State::BeforeAttributeName);
+                            // SPEC: This is a missing-quote-before-doctype-system-identifier parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingQuoteBeforeDoctypeSystemIdentifier,
+                            );
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Reconsume in the bogus DOCTYPE state.
+                            self.reconsume_in(State::BogusDoctype);
                         }
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#self-closing-start-tag-state
-                State::SelfClosingStartTag => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#doctype-system-identifier-(double-quoted)-state
+                State::DoctypeSystemIdentifierDoubleQuoted => {
                     self.consume_next_input_character();
                     match self.current_input_character {
+                        on!('"') => {
+                            // SPEC: Switch to the after DOCTYPE system identifier state.
+                            self.switch_to(State::AfterDoctypeSystemIdentifier);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current DOCTYPE token's system identifier.
+                            self.append_to_current_doctype_system_identifier('\u{FFFD}');
+                        }
                         on!('>') => {
-                            // SPEC: Set the self-closing flag of the current tag token.
-                            if let Some(Token::StartTag { self_closing, .. }) =
-                                &mut self.current_building_token
-                            {
-                                *self_closing = true
-                            }
-                            // SPEC: Emit the current tag token.
-                            self.emit_current_token();
+                            // SPEC: This is a abrupt-doctype-system-identifier parse error.
+                            self.parse_error(ParseErrorKind::AbruptDoctypeSystemIdentifier);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
                             // SPEC: Switch to the data state.
                             self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Append the current input character to the current DOCTYPE token's system identifier.
+                            self.append_to_current_doctype_system_identifier(character);
                         }
-                        on_eof!() => todo!(),
-                        on_anything_else!() => todo!(),
-                    }
-                }
-                State::BogusComment => todo!(),
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#markup-declaration-open-state
-                State::MarkupDeclarationOpen => {
-                    // SPEC: Two U+002D HYPHEN-MINUS characters (-)
-                    if self.next_characters_are_ascii_case_insensitive("--") {
-                        // SPEC: Consume those two characters,
-                        self.consume_characters("--");
-
-                        // SPEC: create a comment token whose data is the empty string,
-                        self.create_new_token(Token::Comment {
-                            data: String::new(),
-                        });
-
-                        // SPEC: and switch to the comment start state.
-                        self.switch_to(State::CommentStart);
-                        continue;
-                    }
-
-                    // SPEC: ASCII case-insensitive match for the word "DOCTYPE"
-                    if self.next_characters_are_ascii_case_insensitive("DOCTYPE") {
-                        self.consume_characters("DOCTYPE");
-                        self.switch_to(State::Doctype);
-                        continue;
                     }
-
-                    // SPEC: The string "[CDATA[" (the five uppercase letters "CDATA" with a U+005B LEFT SQUARE BRACKET character before and after)
-                    // FIXME: Implement
-
-                    // SPEC: Anything else
-                    // FIXME: Implement
-                    todo!()
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#comment-start-state
-                State::CommentStart => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#doctype-system-identifier-(single-quoted)-state
+                State::DoctypeSystemIdentifierSingleQuoted => {
                     self.consume_next_input_character();
                     match self.current_input_character {
-                        on!('-') => {
-                            // SPEC: Switch to the comment start dash state.
-                            self.switch_to(State::CommentStartDash);
+                        on!('\'') => {
+                            // SPEC: Switch to the after DOCTYPE system identifier state.
+                            self.switch_to(State::AfterDoctypeSystemIdentifier);
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                            // SPEC: Append a U+FFFD REPLACEMENT CHARACTER character to the current DOCTYPE token's system identifier.
+                            self.append_to_current_doctype_system_identifier('\u{FFFD}');
                         }
                         on!('>') => {
-                            // SPEC: This is an abrupt-closing-of-empty-comment parse error.
-
+                            // SPEC: This is a abrupt-doctype-system-identifier parse error.
+                            self.parse_error(ParseErrorKind::AbruptDoctypeSystemIdentifier);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
                             // SPEC: Switch to the data state.
                             self.switch_to(State::Data);
-
-                            // SPEC: Emit the current comment token.
+                            // SPEC: Emit the current DOCTYPE token.
                             self.emit_current_token();
                         }
-                        on_anything_else!() | None => {
-                            // SPEC: Reconsume in the comment state.
-                            self.reconsume_in(State::Comment);
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!(character) => {
+                            // SPEC: Append the current input character to the current DOCTYPE token's system identifier.
+                            self.append_to_current_doctype_system_identifier(character);
                         }
                     }
                 }
-                State::CommentStartDash => todo!(),
-                State::Comment => todo!(),
-                State::CommentLessThanSign => todo!(),
-                State::CommentLessThanSignBang => todo!(),
-                State::CommentLessThanSignBangDash => todo!(),
-                State::CommentLessThanSignBangDashDash => todo!(),
-                State::CommentEndDash => todo!(),
-                State::CommentEnd => todo!(),
-                State::CommentEndBang => todo!(),
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#doctype-state
-                State::Doctype => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#after-doctype-system-identifier-state
+                State::AfterDoctypeSystemIdentifier => {
                     self.consume_next_input_character();
                     match self.current_input_character {
                         on_whitespace!() => {
-                            // SPEC: Switch to the before DOCTYPE name state.
-                            self.switch_to(State::BeforeDoctypeName);
-                            continue;
+                            // SPEC: Ignore the character.
+                        }
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                        }
+                        on_eof!() => {
+                            // SPEC: This is an eof-in-doctype parse error.
+                            self.parse_error(ParseErrorKind::EofInDoctype);
+                            // SPEC: Set the current DOCTYPE token's force-quirks flag to on.
+                            self.set_current_doctype_force_quirks_flag();
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
+                            // SPEC: This is a unexpected-character-after-doctype-system-identifier parse error.
+                            self.parse_error(
+                                ParseErrorKind::UnexpectedCharacterAfterDoctypeSystemIdentifier,
+                            );
+                            // SPEC: (This does not set the current DOCTYPE token's force-quirks flag to on.)
+                            // SPEC: Reconsume in the bogus DOCTYPE state.
+                            self.reconsume_in(State::BogusDoctype);
                         }
-                        on!('>') => todo!(),
-                        on_eof!() => todo!(),
-                        on_anything_else!() => todo!(),
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#before-doctype-name-state
-                State::BeforeDoctypeName => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#bogus-doctype-state
+                State::BogusDoctype => {
                     self.consume_next_input_character();
                     match self.current_input_character {
-                        on_whitespace!() => {
+                        on!('>') => {
+                            // SPEC: Switch to the data state.
+                            self.switch_to(State::Data);
+                            // SPEC: Emit the current DOCTYPE token.
+                            self.emit_current_token();
+                        }
+                        on_null!() => {
+                            // SPEC: This is an unexpected-null-character parse error. Ignore the character.
+                            self.parse_error(ParseErrorKind::UnexpectedNullCharacter);
+                        }
+                        on_eof!() => {
+                            // SPEC: Emit the DOCTYPE token.
+                            self.emit_current_token();
+                            // SPEC: Emit an end-of-file token.
+                            self.emit_token(Token::EndOfFile);
+                        }
+                        on_anything_else!() => {
                             // SPEC: Ignore the character.
-                            continue;
                         }
-                        // FIXME: Implement ASCII upper alpha
-                        on_null!() => todo!(),
-                        on!('>') => todo!(),
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#cdata-section-state
+                State::CDataSection => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!(']') => {
+                            // SPEC: Switch to the CDATA section bracket state.
+                            self.switch_to(State::CDataSectionBracket);
+                        }
                         on_eof!() => {
-                            // SPEC: This is an eof-in-doctype parse error.
-                            // FIXME: Implement
-
-                            // SPEC: Create a new DOCTYPE token.
-                            //       Emit the current token.
-                            self.emit_token(Token::Doctype {
-                                name: None,
-                                public_identifier: None,
-                                system_identifier: None,
-                                // SPEC: Set its force-quirks flag to on.
-                                force_quirks: true,
-                            });
-
+                            // SPEC: This is an eof-in-cdata parse error.
+                            self.parse_error(ParseErrorKind::EofInCdata);
                             // SPEC: Emit an end-of-file token.
                             self.emit_token(Token::EndOfFile);
                         }
                         on_anything_else!(character) => {
-                            // SPEC: Create a new DOCTYPE token.
-                            self.create_new_token(Token::Doctype {
-                                // SPEC: Set the token's name to the current input character.
-                                name: Some(String::from(character)),
-                                public_identifier: None,
-                                system_identifier: None,
-                                force_quirks: false,
-                            });
-                            // SPEC: Switch to the DOCTYPE name state.
-                            self.switch_to(State::DoctypeName);
+                            // SPEC: Emit the current input character as a character token.
+                            // NOTE: U+0000 NULL characters are handled in the tree construction stage.
+                            self.emit_token(Token::Character { data: character });
                         }
                     }
                 }
-                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#doctype-name-state
-                State::DoctypeName => {
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#cdata-section-bracket-state
+                State::CDataSectionBracket => {
                     self.consume_next_input_character();
                     match self.current_input_character {
-                        on_whitespace!() => {
-                            // SPEC: Switch to the after DOCTYPE name state.
-                            self.switch_to(State::AfterDoctypeName);
-                            continue;
+                        on!(']') => {
+                            // SPEC: Switch to the CDATA section end state.
+                            self.switch_to(State::CDataSectionEnd);
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: Emit a U+005D RIGHT SQUARE BRACKET character token.
+                            self.emit_token(Token::Character { data: ']' });
+                            // SPEC: Reconsume in the CDATA section state.
+                            self.reconsume_in(State::CDataSection);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#cdata-section-end-state
+                State::CDataSectionEnd => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on!(']') => {
+                            // SPEC: Emit a U+005D RIGHT SQUARE BRACKET character token.
+                            self.emit_token(Token::Character { data: ']' });
                         }
                         on!('>') => {
                             // SPEC: Switch to the data state.
                             self.switch_to(State::Data);
-                            // SPEC: Emit the current DOCTYPE token.
-                            self.emit_current_token();
-                            continue;
                         }
-                        on_null!() => todo!(),
-                        on_eof!() => todo!(),
-                        on_anything_else!(character) => {
-                            // SPEC: ASCII upper alpha
-                            //          Append the lowercase version of the current input character
-                            //          (add 0x0020 to the character's code point)
-                            //          to the current DOCTYPE token's name.
-                            let character = character.to_ascii_lowercase();
-
-                            // SPEC: Append the current input character to the current DOCTYPE token's name.
-                            if let Some(Token::Doctype {
-                                name: Some(name), ..
-                            }) = &mut self.current_building_token
-                            {
-                                name.push(character)
-                            }
+                        on_anything_else!() | None => {
+                            // SPEC: Emit two U+005D RIGHT SQUARE BRACKET character tokens.
+                            self.emit_token(Token::Character { data: ']' });
+                            self.emit_token(Token::Character { data: ']' });
+                            // SPEC: Reconsume in the CDATA section state.
+                            self.reconsume_in(State::CDataSection);
                         }
                     }
                 }
-                State::AfterDoctypeName => todo!(),
-                State::AfterDoctypePublicKeyword => todo!(),
-                State::BeforeDoctypePublicIdentifier => todo!(),
-                State::DoctypePublicIdentifierDoubleQuoted => todo!(),
-                State::DoctypePublicIdentifierSingleQuoted => todo!(),
-                State::AfterDoctypePublicIdentifier => todo!(),
-                State::BetweenDoctypePublicAndSystemIdentifiers => todo!(),
-                State::AfterDoctypeSystemKeyword => todo!(),
-                State::BeforeDoctypeSystemIdentifier => todo!(),
-                State::DoctypeSystemIdentifierDoubleQuoted => todo!(),
-                State::DoctypeSystemIdentifierSingleQuoted => todo!(),
-                State::AfterDoctypeSystemIdentifier => todo!(),
-                State::BogusDoctype => todo!(),
-                State::CDataSection => todo!(),
-                State::CDataSectionBracket => todo!(),
-                State::CDataSectionEnd => todo!(),
                 // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#character-reference-state
                 State::CharacterReference => {
                     // SPEC: Set the temporary buffer to the empty string.
@@ -1385,47 +3626,49 @@ impl Tokenizer {
                 }
                 // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#named-character-reference-state
                 State::NamedCharacterReference => {
-                    self.temporary_named_character_references_buffer =
-                        ncr::NAMED_CHARACTER_REFERENCES
-                            .map(|ncr| (ncr.0.to_string(), ncr.1))
-                            .to_vec();
-
-                    while self.temporary_named_character_references_buffer.len() > 1 {
-                        self.consume_next_input_character();
-
-                        // SPEC: Consume the maximum number of characters possible,
-                        //       where the consumed characters are one of the identifiers
-                        //       in the first column of the named character references table.
-                        self.temporary_named_character_references_buffer
-                            .retain_mut(|ncr| {
-                                ncr.0.starts_with(self.current_input_character.unwrap())
-                            });
-                        for ncr in self.temporary_named_character_references_buffer.iter_mut() {
-                            ncr.0.remove(0);
-                        }
+                    // SPEC: Consume the maximum number of characters possible,
+                    //       where the consumed characters are one of the identifiers
+                    //       in the first column of the named character references table.
+                    let matched = self.longest_named_character_reference_match();
+                    if let Some((identifier, ncr)) = matched {
+                        self.consume_characters(identifier);
                         // SPEC: Append each character to the temporary buffer when it's consumed.
-                        self.temporary_buffer
-                            .push(self.current_input_character.unwrap());
-                    }
+                        self.temporary_buffer.push_str(identifier);
 
-                    if !self.temporary_named_character_references_buffer.is_empty() {
                         // SPEC: If there is a match
-
-                        // SPEC: If the character reference was consumed as part of an attribute,
-                        if self.current_attribute.is_some() {
-                            // SPEC: and the last character matched is not a U+003B SEMICOLON character (;),
-                            if !self.temporary_buffer.ends_with(';') {
-                                // SPEC: and the next input character is either a U+003D EQUALS SIGN character (=)
-                                //       or an ASCII alphanumeric, then, for historical reasons,
-                                if let Some('=') | ascii_alphanumeric!() =
-                                    self.next_input_character()
-                                {
-                                    // SPEC: flush code points consumed as a character reference
-                                    self.flush_code_points_consumed_as_a_character_reference();
-                                    // SPEC: and switch to the return state.
-                                    self.switch_to_return_state();
-                                }
+                        //       If the character reference was consumed as part of an attribute,
+                        //       and the last character matched is not a U+003B SEMICOLON character (;),
+                        //       and the next input character is either a U+003D EQUALS SIGN character (=)
+                        //       or an ASCII alphanumeric, then, for historical reasons,
+                        if self.character_reference_is_consumed_as_part_of_an_attribute()
+                            && !identifier.ends_with(';')
+                            && matches!(
+                                self.next_input_character(),
+                                Some('=') | ascii_alphanumeric!()
+                            )
+                        {
+                            // SPEC: flush code points consumed as a character reference
+                            self.flush_code_points_consumed_as_a_character_reference();
+                            // SPEC: and switch to the return state.
+                            self.switch_to_return_state();
+                        } else {
+                            // SPEC: Otherwise:
+                            // SPEC: 1. If the last character matched is not a U+003B SEMICOLON character (;),
+                            //          then this is a missing-semicolon-after-character-reference parse error.
+                            if !identifier.ends_with(';') {
+                                self.parse_error(
+                                    ParseErrorKind::MissingSemicolonAfterCharacterReference,
+                                );
                             }
+                            // SPEC: 2. Set the temporary buffer to the empty string.
+                            //          Append one or two characters corresponding to the character reference name
+                            //          (as given by the second column of the named character references table) to the temporary buffer.
+                            self.temporary_buffer.clear();
+                            self.temporary_buffer.push_str(ncr.characters());
+                            // SPEC: 3. Flush code points consumed as a character reference.
+                            self.flush_code_points_consumed_as_a_character_reference();
+                            // SPEC: Switch to the return state.
+                            self.switch_to_return_state();
                         }
                     } else {
                         // SPEC: Otherwise
@@ -1442,9 +3685,11 @@ impl Tokenizer {
                     match self.current_input_character {
                         ascii_alphanumeric!(character) => {
                             // SPEC: If the character reference was consumed as part of an attribute,
-                            if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                            if self.character_reference_is_consumed_as_part_of_an_attribute() {
                                 // SPEC: then append the current input character to the current attribute's value.
-                                value.push(character);
+                                if let Some(Attribute { value, .. }) = &mut self.current_attribute {
+                                    value.push(character);
+                                }
                             } else {
                                 // SPEC: Otherwise, emit the current input character as a character token.
                                 self.emit_token(Token::Character { data: character });
@@ -1452,6 +3697,7 @@ impl Tokenizer {
                         }
                         on!(';') => {
                             // SPEC: This is an unknown-named-character-reference parse error.
+                            self.parse_error(ParseErrorKind::UnknownNamedCharacterReference);
                             // SPEC: Reconsume in the return state.
                             self.reconsume_in_return_state();
                         }
@@ -1491,6 +3737,30 @@ impl Tokenizer {
                         }
                         on_anything_else!() | None => {
                             // SPEC: This is an absence-of-digits-in-numeric-character-reference parse error.
+                            self.parse_error(
+                                ParseErrorKind::AbsenceOfDigitsInNumericCharacterReference,
+                            );
+
+                            // SPEC: Flush code points consumed as a character reference.
+                            self.flush_code_points_consumed_as_a_character_reference();
+                            // SPEC: Reconsume in the return state.
+                            self.reconsume_in_return_state();
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#decimal-character-reference-start-state
+                State::DecimalCharacterReferenceStart => {
+                    self.consume_next_input_character();
+                    match self.current_input_character {
+                        on_ascii_digit!() => {
+                            // SPEC: Reconsume in the decimal character reference state.
+                            self.reconsume_in(State::DecimalCharacterReference);
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: This is an absence-of-digits-in-numeric-character-reference parse error.
+                            self.parse_error(
+                                ParseErrorKind::AbsenceOfDigitsInNumericCharacterReference,
+                            );
 
                             // SPEC: Flush code points consumed as a character reference.
                             self.flush_code_points_consumed_as_a_character_reference();
@@ -1499,7 +3769,6 @@ impl Tokenizer {
                         }
                     }
                 }
-                State::DecimalCharacterReferenceStart => todo!(),
                 // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#hexadecimal-character-reference-state
                 State::HexadecimalCharacterReference => {
                     self.consume_next_input_character();
@@ -1507,27 +3776,50 @@ impl Tokenizer {
                     match self.current_input_character {
                         on_ascii_digit!(character) => {
                             // SPEC: Multiply the character reference code by 16.
-                            self.character_reference_code *= 16;
                             // SPEC: Add a numeric version of the current input character
                             //      (subtract 0x0030 from the character's code point)
                             //      to the character reference code.
-                            self.character_reference_code += character as u32 - 0x0030;
+                            self.add_to_character_reference_code(16, character as u32 - 0x0030);
                         }
                         on_ascii_upper_hex_digit_alpha!(character) => {
                             // SPEC: Multiply the character reference code by 16.
-                            self.character_reference_code *= 16;
                             // SPEC: Add a numeric version of the current input character as a hexadecimal digit
                             //       (subtract 0x0037 from the character's code point)
                             //       to the character reference code.
-                            self.character_reference_code += character as u32 - 0x0037;
+                            self.add_to_character_reference_code(16, character as u32 - 0x0037);
                         }
                         on_ascii_lower_hex_digit_alpha!(character) => {
                             // SPEC: Multiply the character reference code by 16.
-                            self.character_reference_code *= 16;
                             // SPEC: Add a numeric version of the current input character as a hexadecimal digit
-                            //       (subtract 0x0037 from the character's code point)
+                            //       (subtract 0x0057 from the character's code point)
+                            //       to the character reference code.
+                            self.add_to_character_reference_code(16, character as u32 - 0x0057);
+                        }
+                        on!(';') => {
+                            // SPEC: Switch to the numeric character reference end state.
+                            self.switch_to(State::NumericCharacterReferenceEnd);
+                        }
+                        on_anything_else!() | None => {
+                            // SPEC: This is a missing-semicolon-after-character-reference parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingSemicolonAfterCharacterReference,
+                            );
+                            // SPEC: Reconsume in the numeric character reference end state.
+                            self.reconsume_in(State::NumericCharacterReferenceEnd);
+                        }
+                    }
+                }
+                // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#decimal-character-reference-state
+                State::DecimalCharacterReference => {
+                    self.consume_next_input_character();
+
+                    match self.current_input_character {
+                        on_ascii_digit!(character) => {
+                            // SPEC: Multiply the character reference code by 10.
+                            // SPEC: Add a numeric version of the current input character
+                            //       (subtract 0x0030 from the character's code point)
                             //       to the character reference code.
-                            self.character_reference_code += character as u32 - 0x0057;
+                            self.add_to_character_reference_code(10, character as u32 - 0x0030);
                         }
                         on!(';') => {
                             // SPEC: Switch to the numeric character reference end state.
@@ -1535,35 +3827,780 @@ impl Tokenizer {
                         }
                         on_anything_else!() | None => {
                             // SPEC: This is a missing-semicolon-after-character-reference parse error.
+                            self.parse_error(
+                                ParseErrorKind::MissingSemicolonAfterCharacterReference,
+                            );
                             // SPEC: Reconsume in the numeric character reference end state.
                             self.reconsume_in(State::NumericCharacterReferenceEnd);
                         }
                     }
                 }
-                State::DecimalCharacterReference => todo!(),
                 // SPECLINK: https://html.spec.whatwg.org/multipage/parsing.html#numeric-character-reference-end-state
-                State::NumericCharacterReferenceEnd => match self.character_reference_code {
-                    0x00 => todo!(),
-                    surrogate_codepoint!() => todo!(),
-                    noncharacter!() => todo!(),
-                    0x10ffff.. => todo!(),
-                    c @ 0x0d | c @ control_codepoint!() if c != whitespace_codepoint!() => todo!(),
-                    _ => {
-                        // SPEC: Set the temporary buffer to the empty string.
-                        self.temporary_buffer.clear();
-                        // SPEC: Append a code point equal to the character reference code to the temporary buffer.
-                        if let Some(character) = char::from_u32(self.character_reference_code) {
-                            self.temporary_buffer.push(character);
+                State::NumericCharacterReferenceEnd => {
+                    // SPEC: Check the character reference code:
+                    match self.character_reference_code {
+                        0x00 => {
+                            // SPEC: If the number is 0x00, then this is a null-character-reference parse error.
+                            //       Set the character reference code to 0xFFFD.
+                            self.parse_error(ParseErrorKind::NullCharacterReference);
+                            self.character_reference_code = 0xFFFD;
                         }
-                        // SPEC: Flush code points consumed as a character reference.
-                        self.flush_code_points_consumed_as_a_character_reference();
-                        // SPEC: Switch to the return state.
-                        self.switch_to_return_state();
+                        0x110000.. => {
+                            // SPEC: If the number is greater than 0x10FFFF, then this is a character-reference-outside-unicode-range parse error.
+                            //       Set the character reference code to 0xFFFD.
+                            self.parse_error(ParseErrorKind::CharacterReferenceOutsideUnicodeRange);
+                            self.character_reference_code = 0xFFFD;
+                        }
+                        surrogate_codepoint!() => {
+                            // SPEC: If the number is a surrogate, then this is a surrogate-character-reference parse error.
+                            //       Set the character reference code to 0xFFFD.
+                            self.parse_error(ParseErrorKind::SurrogateCharacterReference);
+                            self.character_reference_code = 0xFFFD;
+                        }
+                        noncharacter!() => {
+                            // SPEC: If the number is a noncharacter, then this is a noncharacter-character-reference parse error.
+                            self.parse_error(ParseErrorKind::NoncharacterCharacterReference);
+                        }
+                        code @ (0x0D | control_codepoint!()) => {
+                            // SPEC: If the number is 0x0D, or a control that's not ASCII whitespace, then this is a control-character-reference parse error.
+                            if code == 0x0D || !matches!(code, whitespace_codepoint!()) {
+                                self.parse_error(ParseErrorKind::ControlCharacterReference);
+                            }
+
+                            // SPEC: If the number is one of the numbers in the first column of the following table,
+                            //       then find the row with that number in the first column,
+                            //       and set the character reference code to the number in the second column of that row.
+                            if let Some((_, replacement)) = CONTROL_CHARACTER_REFERENCE_REPLACEMENTS
+                                .iter()
+                                .find(|(number, _)| *number == code)
+                            {
+                                self.character_reference_code = *replacement;
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    // SPEC: Set the temporary buffer to the empty string.
+                    self.temporary_buffer.clear();
+                    // SPEC: Append a code point equal to the character reference code to the temporary buffer.
+                    // NOTE: Every code point that isn't a valid char was replaced by U+FFFD above.
+                    if let Some(character) = char::from_u32(self.character_reference_code) {
+                        self.temporary_buffer.push(character);
                     }
+                    // SPEC: Flush code points consumed as a character reference.
+                    self.flush_code_points_consumed_as_a_character_reference();
+                    // SPEC: Switch to the return state.
+                    self.switch_to_return_state();
+                }
+            }
+        }
+
+        let (token, range) = self.pending_tokens.pop_front().unzip();
+        self.current_token = token;
+        self.current_token_raw = range
+            .filter(|_| self.options.record_raw)
+            .map(|range| self.input[range].iter().collect());
+        self.current_token()
+    }
+}
+
+// https://infra.spec.whatwg.org/#normalize-newlines
+/// SPEC: Before the tokenization stage, the input stream must be preprocessed by normalizing newlines.
+///
+/// https://html.spec.whatwg.org/multipage/parsing.html#preprocessing-the-input-stream
+fn normalize_newlines(input: &str) -> String {
+    // To normalize newlines in a string, replace every U+000D CR U+000A LF code point pair with a single U+000A LF code point, and then replace every remaining U+000D CR code point with a U+000A LF code point.
+    input.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start_tag_token(self_closing: bool, acknowledged: bool) -> Token {
+        Token::StartTag {
+            name: Atom::from("br"),
+            original_name: None,
+            self_closing,
+            self_closing_acknowledged: Cell::new(acknowledged),
+            attributes: vec![],
+        }
+    }
+
+    #[test]
+    fn token_equality_ignores_self_closing_acknowledgement() {
+        assert_eq!(start_tag_token(true, false), start_tag_token(true, true));
+
+        let token = start_tag_token(true, false);
+        let processed = token.clone();
+        processed.acknowledge_self_closing_flag_if_set();
+        assert_eq!(token, processed);
+    }
+
+    #[test]
+    fn token_equality_compares_everything_else() {
+        assert_ne!(start_tag_token(true, false), start_tag_token(false, false));
+
+        assert_ne!(
+            Tokenizer::collect_tokens("<br a=1>"),
+            Tokenizer::collect_tokens("<br a=2>")
+        );
+        assert_ne!(
+            Tokenizer::collect_tokens("<br>"),
+            Tokenizer::collect_tokens("</br>")
+        );
+    }
+
+    fn characters(text: &str) -> Vec<Token> {
+        text.chars().map(|data| Token::Character { data }).collect()
+    }
+
+    #[test]
+    fn collect_tokens_ends_with_a_single_end_of_file() {
+        assert_eq!(
+            Tokenizer::collect_tokens("ab"),
+            vec![
+                Token::Character { data: 'a' },
+                Token::Character { data: 'b' },
+                Token::EndOfFile,
+            ]
+        );
+    }
+
+    #[test]
+    fn end_tag_open_missing_end_tag_name() {
+        let mut expected = characters("ab");
+        expected.push(Token::EndOfFile);
+        assert_eq!(Tokenizer::collect_tokens("a</>b"), expected);
+        assert_eq!(Tokenizer::collect_tokens("</>"), vec![Token::EndOfFile]);
+    }
+
+    #[test]
+    fn end_tag_open_eof_before_tag_name() {
+        let mut expected = characters("a</");
+        expected.push(Token::EndOfFile);
+        assert_eq!(Tokenizer::collect_tokens("a</"), expected);
+    }
+
+    #[test]
+    fn end_tag_open_invalid_first_character() {
+        assert_eq!(
+            Tokenizer::collect_tokens("</3>"),
+            vec![comment("3"), Token::EndOfFile]
+        );
+    }
+
+    fn comment(data: &str) -> Token {
+        Token::Comment {
+            data: data.to_string(),
+        }
+    }
+
+    #[test]
+    fn bogus_comment() {
+        assert_eq!(
+            Tokenizer::collect_tokens("<!bogus>"),
+            vec![comment("bogus"), Token::EndOfFile]
+        );
+    }
+
+    #[test]
+    fn bogus_comment_eof() {
+        assert_eq!(
+            Tokenizer::collect_tokens("<!bogus"),
+            vec![comment("bogus"), Token::EndOfFile]
+        );
+    }
+
+    #[test]
+    fn bogus_comment_null() {
+        assert_eq!(
+            Tokenizer::collect_tokens("<!a\0b>"),
+            vec![comment("a\u{FFFD}b"), Token::EndOfFile]
+        );
+    }
+
+    #[test]
+    fn truncated_doctype_keyword() {
+        assert_eq!(
+            Tokenizer::collect_tokens("<!DOC"),
+            vec![comment("DOC"), Token::EndOfFile]
+        );
+    }
+
+    fn doctype(input: &str) -> (Option<String>, Option<String>, Option<String>, bool) {
+        match Tokenizer::collect_tokens(input).remove(0) {
+            Token::Doctype {
+                name,
+                public_identifier,
+                system_identifier,
+                force_quirks,
+            } => (name, public_identifier, system_identifier, force_quirks),
+            token => panic!("expected a DOCTYPE token, got {token:?}"),
+        }
+    }
+
+    #[test]
+    fn doctype_identifiers() {
+        assert_eq!(
+            doctype("<!DOCTYPE html>"),
+            (Some("html".to_string()), None, None, false)
+        );
+        assert_eq!(
+            doctype("<!doctype HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\" 'http://www.w3.org/TR/html4/strict.dtd'>"),
+            (
+                Some("html".to_string()),
+                Some("-//W3C//DTD HTML 4.01//EN".to_string()),
+                Some("http://www.w3.org/TR/html4/strict.dtd".to_string()),
+                false
+            )
+        );
+        assert_eq!(
+            doctype("<!DOCTYPE html SYSTEM \"about:legacy-compat\">"),
+            (
+                Some("html".to_string()),
+                None,
+                Some("about:legacy-compat".to_string()),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn doctype_force_quirks() {
+        assert_eq!(doctype("<!DOCTYPE>"), (None, None, None, true));
+        assert_eq!(
+            doctype("<!DOCTYPE html"),
+            (Some("html".to_string()), None, None, true)
+        );
+        assert_eq!(
+            doctype("<!DOCTYPE html PUBLIC>"),
+            (Some("html".to_string()), None, None, true)
+        );
+        assert_eq!(
+            doctype("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN"),
+            (
+                Some("html".to_string()),
+                Some("-//W3C//DTD HTML 4.01//EN".to_string()),
+                None,
+                true
+            )
+        );
+        assert_eq!(
+            doctype("<!DOCTYPE html bogus>"),
+            (Some("html".to_string()), None, None, true)
+        );
+    }
+
+    #[test]
+    fn multi_byte_character_before_comment_start() {
+        let mut expected = characters("é");
+        expected.extend([comment("x"), Token::EndOfFile]);
+        assert_eq!(Tokenizer::collect_tokens("é<!--x-->"), expected);
+        assert_eq!(
+            Tokenizer::collect_tokens("<!é--x-->"),
+            vec![comment("é--x--"), Token::EndOfFile]
+        );
+    }
+
+    /// Tokenizes `input` and returns the data of its character tokens.
+    fn text(input: &str) -> String {
+        Tokenizer::collect_tokens(input)
+            .into_iter()
+            .filter_map(|token| match token {
+                Token::Character { data } => Some(data),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decimal_character_references() {
+        assert_eq!(text("&#65;"), "A");
+        assert_eq!(text("&#65b"), "Ab");
+        assert_eq!(text("&#128512;"), "\u{1F600}");
+        assert_eq!(text("&#;"), "&#;");
+        assert_eq!(text("&#a"), "&#a");
+    }
+
+    #[test]
+    fn numeric_character_reference_replacements() {
+        assert_eq!(text("&#0;"), "\u{FFFD}");
+        assert_eq!(text("&#xD800;"), "\u{FFFD}");
+        assert_eq!(text("&#x110000;"), "\u{FFFD}");
+        assert_eq!(text("&#9999999999;"), "\u{FFFD}");
+        assert_eq!(text("&#xFFFFFFFFFFFF;"), "\u{FFFD}");
+        assert_eq!(text("&#xFFFF;"), "\u{FFFF}");
+        assert_eq!(text("&#x80;&#x9F;"), "€Ÿ");
+        assert_eq!(text("&#x81;"), "\u{81}");
+        assert_eq!(text("&#x09;&#x0D;"), "\t\r");
+    }
+
+    #[test]
+    fn numeric_character_reference_in_attribute() {
+        let tokens = Tokenizer::collect_tokens("<a href=\"&#106;avascript:&#x3a;\">");
+        let Some(Token::StartTag { attributes, .. }) = tokens.first() else {
+            panic!("expected a start tag, got {tokens:?}");
+        };
+        assert_eq!(attributes[0].value, "javascript::");
+    }
+
+    #[test]
+    fn eof_in_tag_drops_the_tag() {
+        for input in [
+            "<div",
+            "<div a",
+            "<div a=",
+            "<div a=\"x",
+            "<div a='x",
+            "<div a=x",
+            "<div a=\"x\"",
+            "<div /",
+        ] {
+            assert_eq!(
+                Tokenizer::collect_tokens(input),
+                vec![Token::EndOfFile],
+                "{input}"
+            );
+        }
+    }
+
+    /// Tokenizes `input`, which should start with a start tag, and returns its name, attributes
+    /// and self-closing flag.
+    fn start_tag(input: &str) -> (String, Vec<(String, String)>, bool) {
+        let tokens = Tokenizer::collect_tokens(input);
+        let Some(Token::StartTag {
+            name,
+            attributes,
+            self_closing,
+            ..
+        }) = tokens.first()
+        else {
+            panic!("expected a start tag, got {tokens:?}");
+        };
+        let attributes = attributes
+            .iter()
+            .map(|attr| (attr.name.to_string(), attr.value.clone()))
+            .collect();
+        (name.to_string(), attributes, *self_closing)
+    }
+
+    #[test]
+    fn null_characters_in_tags() {
+        let (name, attributes, _) = start_tag("<d\0v a\0=\"x\0\" b='\0' c=\0>");
+        assert_eq!(name, "d\u{FFFD}v");
+        assert_eq!(
+            attributes,
+            [
+                ("a\u{FFFD}".to_string(), "x\u{FFFD}".to_string()),
+                ("b".to_string(), "\u{FFFD}".to_string()),
+                ("c".to_string(), "\u{FFFD}".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn equals_sign_before_attribute_name() {
+        let (_, attributes, _) = start_tag("<div =a>");
+        assert_eq!(attributes, [("=a".to_string(), String::new())]);
+    }
+
+    #[test]
+    fn missing_attribute_value() {
+        let (_, attributes, _) = start_tag("<div a=>x");
+        assert_eq!(attributes, [("a".to_string(), String::new())]);
+    }
+
+    #[test]
+    fn unexpected_solidus_in_tag() {
+        let (_, attributes, self_closing) = start_tag("<div / a>");
+        assert_eq!(attributes, [("a".to_string(), String::new())]);
+        assert!(!self_closing);
+    }
+
+    #[test]
+    fn cdata_in_html_content_is_a_bogus_comment() {
+        assert_eq!(
+            Tokenizer::collect_tokens("<![CDATA[x]]>"),
+            vec![comment("[CDATA[x]]"), Token::EndOfFile]
+        );
+    }
+
+    #[test]
+    fn cdata_sections() {
+        let mut tokenizer = Tokenizer::new("<![CDATA[a]b]]c]]]>d<![CDATA[e");
+        tokenizer.set_cdata_sections_allowed(true);
+        let mut tokens = vec![];
+        while let Some(token) = tokenizer.next_token() {
+            tokens.push(token.clone());
+        }
+
+        let mut expected = characters("a]b]]c]de");
+        expected.push(Token::EndOfFile);
+        assert_eq!(tokens, expected);
+    }
+
+    /// Tokenizes `input` as the contents of a `last_start_tag` element whose contents are
+    /// tokenized in `state`.
+    fn tokens_in_state(state: State, last_start_tag: &str, input: &str) -> Vec<Token> {
+        let mut tokenizer = Tokenizer::new(input);
+        tokenizer.switch_to(state);
+        tokenizer.set_last_start_tag_name(last_start_tag);
+
+        let mut tokens = vec![];
+        while let Some(token) = tokenizer.next_token().cloned() {
+            let end_of_file = token == Token::EndOfFile;
+            tokens.push(token);
+            if end_of_file {
+                break;
+            }
+        }
+        tokens
+    }
+
+    #[test]
+    fn rcdata_flushes_the_temporary_buffer_of_a_wrong_end_tag() {
+        let mut expected = characters("</tit>x");
+        expected.push(Token::EndOfFile);
+        assert_eq!(tokens_in_state(State::RcData, "title", "</tit>x"), expected);
+
+        let mut expected = characters("</titles>");
+        expected.push(Token::EndOfFile);
+        assert_eq!(
+            tokens_in_state(State::RcData, "title", "</titles>"),
+            expected
+        );
+
+        // NOTE: The end tag is still recognized after a flushed one.
+        let mut expected = characters("</b>");
+        expected.extend(Tokenizer::collect_tokens("</TITLE >"));
+        assert_eq!(
+            tokens_in_state(State::RcData, "title", "</b></TITLE >"),
+            expected
+        );
+    }
+
+    #[test]
+    fn rawtext_flushes_the_temporary_buffer_at_the_end_of_the_file() {
+        for input in ["</sty", "</", "<", "</style"] {
+            let mut expected = characters(input);
+            expected.push(Token::EndOfFile);
+            assert_eq!(tokens_in_state(State::RawText, "style", input), expected);
+        }
+
+        assert_eq!(
+            tokens_in_state(State::RawText, "style", "</style>"),
+            Tokenizer::collect_tokens("</style>")
+        );
+    }
+
+    #[test]
+    fn stall_guard_stops_a_state_that_never_makes_progress() {
+        let mut tokenizer = Tokenizer::new("ab");
+        tokenizer.broken_state = Some(State::Data);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            tokenizer.next_token().cloned()
+        }));
+
+        // NOTE: Debug builds panic so the bug is easy to find, release builds end the input.
+        if cfg!(debug_assertions) {
+            let message = result.expect_err("debug builds should panic");
+            let message = message.downcast_ref::<String>().unwrap();
+            assert!(message.contains("in the Data state"), "{message}");
+        } else {
+            assert_eq!(result.unwrap(), Some(Token::EndOfFile));
+            assert_eq!(tokenizer.stalled_state(), Some(State::Data));
+        }
+    }
+
+    fn attribute_value(input: &str) -> String {
+        let (_, attributes, _) = start_tag(input);
+        attributes[0].1.clone()
+    }
+
+    #[test]
+    fn named_character_references_in_attribute_values() {
+        // An ampersand that doesn't start a character reference is kept as it is.
+        assert_eq!(attribute_value("<a href=\"?a=1&b=2\">"), "?a=1&b=2");
+        assert_eq!(attribute_value("<a href='&'>"), "&");
+        assert_eq!(attribute_value("<a title=\"&copy;\">"), "©");
+        assert_eq!(attribute_value("<a title='&copy; 2024'>"), "© 2024");
+        assert_eq!(attribute_value("<a title=&amp;&lt;>"), "&<");
+        // NOTE: For historical reasons, a reference without a semicolon that is followed by an
+        //       equals sign or an alphanumeric character is not decoded in an attribute value.
+        assert_eq!(attribute_value("<a href=\"?x=&amp=y\">"), "?x=&amp=y");
+        assert_eq!(attribute_value("<a href=\"?x=&notit\">"), "?x=&notit");
+        assert_eq!(attribute_value("<a href=\"?x=&copy2\">"), "?x=&copy2");
+        assert_eq!(attribute_value("<a title=\"&not it\">"), "¬ it");
+        assert_eq!(attribute_value("<a title=\"&notin;\">"), "∉");
+    }
+
+    #[test]
+    fn named_character_references_in_text() {
+        assert_eq!(text("&notit;"), "¬it;");
+        assert_eq!(text("&notin;"), "∉");
+        assert_eq!(text("&amp=y"), "&=y");
+        assert_eq!(text("&copy2"), "©2");
+        assert_eq!(text("&unknown;"), "&unknown;");
+    }
+
+    #[test]
+    fn two_code_point_character_references() {
+        // NOTE: Each code point is its own character token, in order.
+        let mut expected = characters("afjb\u{223E}\u{0333}");
+        expected.push(Token::EndOfFile);
+        assert_eq!(Tokenizer::collect_tokens("a&fjlig;b&acE;"), expected);
+
+        assert_eq!(attribute_value("<a title=\"&fjlig;\">"), "fj");
+        assert_eq!(attribute_value("<a title='x&acE;y'>"), "x\u{223E}\u{0333}y");
+        assert_eq!(attribute_value("<a title=&fjlig;&fjlig;>"), "fjfj");
+        // NOTE: The return state decides where the characters go, not whether the current tag
+        //       has attributes.
+        assert_eq!(text("<a title=x>&fjlig;"), "fj");
+    }
+
+    #[test]
+    fn look_ahead_after_multi_byte_characters() {
+        let tokens = Tokenizer::collect_tokens("é\n<!DOCTYPE html PUBLIC \"ü\"><p>");
+        assert_eq!(tokens[0], Token::Character { data: 'é' });
+        assert!(matches!(
+            &tokens[2],
+            Token::Doctype { name: Some(name), public_identifier: Some(public_identifier), .. }
+                if name == "html" && public_identifier == "ü"
+        ));
+        assert!(matches!(&tokens[3], Token::StartTag { name, .. } if name.as_str() == "p"));
+
+        let mut expected = characters("🚀 ok ");
+        expected.extend([comment(" 🌍 "), Token::EndOfFile]);
+        assert_eq!(Tokenizer::collect_tokens("🚀 ok <!-- 🌍 -->"), expected);
+    }
+
+    #[test]
+    fn flushed_characters_are_handed_out_one_at_a_time_in_order() {
+        let mut tokenizer = Tokenizer::new("a</titlx</title>");
+        tokenizer.switch_to(State::RcData);
+        tokenizer.set_last_start_tag_name("title");
+
+        let mut expected = characters("a</titlx");
+        expected.extend(Tokenizer::collect_tokens("</title>"));
+        for expected_token in expected {
+            assert_eq!(tokenizer.next_token(), Some(&expected_token));
+            // NOTE: The current token is the token that was just handed out, not the last one
+            //       that was emitted.
+            assert_eq!(tokenizer.current_token(), Some(&expected_token));
+        }
+        assert_eq!(tokenizer.next_token(), None);
+    }
+
+    #[test]
+    fn script_data_flushes_the_temporary_buffer_of_a_wrong_end_tag() {
+        let mut expected = characters("a</scripx");
+        expected.extend(Tokenizer::collect_tokens("</script>"));
+        assert_eq!(
+            tokens_in_state(State::ScriptData, "script", "a</scripx</script>"),
+            expected
+        );
+    }
+
+    #[test]
+    fn uppercase_end_tags_close_raw_text() {
+        for (state, tag_name, input) in [
+            (State::RcData, "title", "x</TITLE>"),
+            (State::RawText, "style", "x</StYlE>"),
+            (State::ScriptData, "script", "x</SCRIPT>"),
+        ] {
+            let mut expected = characters("x");
+            expected.extend(Tokenizer::collect_tokens(&format!("</{tag_name}>")));
+            assert_eq!(
+                tokens_in_state(state, tag_name, input),
+                expected,
+                "{input:?}"
+            );
+        }
+
+        // NOTE: The temporary buffer keeps the original case of a wrong end tag.
+        let mut expected = characters("</TITLES>");
+        expected.push(Token::EndOfFile);
+        assert_eq!(
+            tokens_in_state(State::RcData, "title", "</TITLES>"),
+            expected
+        );
+        assert_eq!(
+            crate::html::parser::tests::parse_body("<textarea>a</TEXTAREA>b"),
+            "<textarea>a</textarea>b"
+        );
+    }
+
+    #[test]
+    fn tag_names_are_lowercased() {
+        let tag_names = |input: &str, preserve_case: bool| {
+            let mut tokenizer = Tokenizer::with_options(
+                input,
+                TokenizerOptions {
+                    preserve_case,
+                    ..Default::default()
                 },
+            );
+            let mut names = vec![];
+            while let Some(token) = tokenizer.next_token() {
+                if let Token::StartTag {
+                    name,
+                    original_name,
+                    ..
+                }
+                | Token::EndTag {
+                    name,
+                    original_name,
+                    ..
+                } = token
+                {
+                    names.push((name.to_string(), original_name.clone()));
+                }
             }
+            names
+        };
+
+        assert_eq!(tag_names("<DIV>", false), [("div".to_string(), None)]);
+        assert_eq!(
+            tag_names("<dIv></Div>", false),
+            [("div".to_string(), None), ("div".to_string(), None)]
+        );
+        assert_eq!(
+            tag_names("<dIv></Div>", true),
+            [
+                ("div".to_string(), Some("dIv".to_string())),
+                ("div".to_string(), Some("Div".to_string()))
+            ]
+        );
+        // NOTE: Only ASCII upper alphas are lowercased.
+        assert_eq!(tag_names("<AÉ>", false), [("aÉ".to_string(), None)]);
+    }
+
+    #[test]
+    fn preserve_case_keeps_the_original_spelling() {
+        let options = TokenizerOptions {
+            preserve_case: true,
+            ..Default::default()
+        };
+        let mut tokenizer = Tokenizer::with_options("<DIV Class=\"X\">", options);
+        let Some(Token::StartTag {
+            name,
+            original_name,
+            attributes,
+            ..
+        }) = tokenizer.next_token().cloned()
+        else {
+            panic!("expected a start tag");
+        };
+        assert_eq!(name, Atom::from("div"));
+        assert_eq!(original_name.as_deref(), Some("DIV"));
+        assert_eq!(attributes[0].name, Atom::from("class"));
+        assert_eq!(attributes[0].original_name.as_deref(), Some("Class"));
+        assert_eq!(attributes[0].value, "X");
+
+        // NOTE: Without the option, and in the parser, only the lowercased names are kept.
+        let Some(Token::StartTag {
+            original_name,
+            attributes,
+            ..
+        }) = Tokenizer::new("<DIV Class=\"X\">").next_token().cloned()
+        else {
+            panic!("expected a start tag");
+        };
+        assert_eq!(original_name, None);
+        assert_eq!(attributes[0].original_name, None);
+        assert_eq!(
+            crate::html::parser::tests::parse_body("<DIV Class=\"X\">"),
+            "<div class=\"X\"></div>"
+        );
+    }
+
+    #[test]
+    fn record_raw_keeps_the_source_of_each_token() {
+        let input = "<DIV Class=\"X\">a&fjlig;<!-- c --></DIV >";
+        let options = TokenizerOptions {
+            record_raw: true,
+            ..Default::default()
+        };
+        let mut tokenizer = Tokenizer::with_options(input, options);
+        let mut raw = vec![];
+        while tokenizer.next_token().is_some() {
+            raw.push(tokenizer.current_token_raw().unwrap().to_string());
         }
+        assert_eq!(
+            raw,
+            [
+                "<DIV Class=\"X\">",
+                "a",
+                "&fjlig;",
+                "",
+                "<!-- c -->",
+                "</DIV >",
+                ""
+            ]
+        );
+        assert_eq!(raw.concat(), input);
+
+        let mut tokenizer = Tokenizer::new(input);
+        tokenizer.next_token();
+        assert_eq!(tokenizer.current_token_raw(), None);
+    }
+
+    fn parse_errors(input: &str) -> Vec<(ParseErrorKind, usize)> {
+        let mut tokenizer = Tokenizer::new(input);
+        while tokenizer.next_token().is_some() {}
+        tokenizer
+            .parse_errors()
+            .iter()
+            .map(|error| (error.kind, error.position))
+            .collect()
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_character() {
+        assert_eq!(
+            parse_errors("<a b='c'd>"),
+            vec![(ParseErrorKind::MissingWhitespaceBetweenAttributes, 8)]
+        );
+        assert_eq!(
+            parse_errors("ab</>"),
+            vec![(ParseErrorKind::MissingEndTagName, 4)]
+        );
+        assert_eq!(
+            parse_errors("<!DOCTYPE >"),
+            vec![(ParseErrorKind::MissingDoctypeName, 10)]
+        );
+        // NOTE: Positions count characters, not bytes.
+        assert_eq!(
+            parse_errors("ééé<a b='c'd>"),
+            vec![(ParseErrorKind::MissingWhitespaceBetweenAttributes, 11)]
+        );
+    }
+
+    #[test]
+    fn newlines_are_normalized() {
+        let mut expected = characters("a\nb\nc");
+        expected.push(Token::EndOfFile);
+        assert_eq!(Tokenizer::collect_tokens("a\r\nb\rc"), expected);
+
+        assert_eq!(text("\r\r\n\n\r"), "\n\n\n\n");
+        assert_eq!(attribute_value("<a title='x\r\ny\r'>"), "x\ny\n");
+        assert_eq!(
+            Tokenizer::collect_tokens("<!--a\r\nb-->")[0],
+            comment("a\nb")
+        );
+        // NOTE: Only the input is normalized, a character reference can still produce a CR.
+        assert_eq!(text("&#x0D;\r"), "\r\n");
+    }
 
-        self.tokens.last()
+    #[test]
+    fn leading_byte_order_mark_is_ignored() {
+        assert_eq!(
+            doctype("\u{FEFF}<!DOCTYPE html>"),
+            (Some("html".to_string()), None, None, false)
+        );
+        assert_eq!(Tokenizer::collect_tokens("\u{FEFF}").len(), 1);
+        // NOTE: Only a single leading BOM is ignored, others are regular characters.
+        assert_eq!(text("\u{FEFF}\u{FEFF}a\u{FEFF}"), "\u{FEFF}a\u{FEFF}");
     }
 }
@@ -1,2250 +1,94 @@
+/// The characters that a named character reference is replaced with, which are one or two code
+/// points.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
-pub(crate) struct NamedCharacterReference<'a>([u32; 1], &'a str);
+pub(crate) struct NamedCharacterReference(&'static str);
 
-impl<'a> NamedCharacterReference<'a> {
-    const fn new(codepoints: [u32; 1], characters: &'a str) -> Self {
-        Self(codepoints, characters)
+impl NamedCharacterReference {
+    const fn new(characters: &'static str) -> Self {
+        Self(characters)
+    }
+
+    pub(crate) fn characters(&self) -> &'static str {
+        self.0
     }
 }
 
-type Ncr<'a> = NamedCharacterReference<'a>;
+/// The length of the longest identifier in the named character references table.
+pub(crate) const LONGEST_IDENTIFIER_LENGTH: usize = 32;
+
+// NOTE: The table is generated by build.rs from entities.json.
+// https://html.spec.whatwg.org/multipage/named-characters.html#named-character-references
+include!(concat!(env!("OUT_DIR"), "/named_character_references.rs"));
+
+/// Returns the longest identifier in the named character references table that `input` starts
+/// with, and the named character reference it identifies.
+pub(crate) fn longest_match(input: &str) -> Option<(&'static str, NamedCharacterReference)> {
+    // NOTE: Identifiers are ASCII, so their length in bytes is their length in characters.
+    let longest_length = input.len().min(LONGEST_IDENTIFIER_LENGTH);
+    (1..=longest_length)
+        .rev()
+        .filter(|length| input.is_char_boundary(*length))
+        .find_map(|length| {
+            let prefix = &input[..length];
+            NAMED_CHARACTER_REFERENCES
+                .binary_search_by(|(identifier, _)| identifier.cmp(&prefix))
+                .ok()
+                .map(|index| NAMED_CHARACTER_REFERENCES[index])
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn characters(identifier: &str) -> Option<&'static str> {
+        longest_match(identifier)
+            .filter(|(matched, _)| *matched == identifier)
+            .map(|(_, reference)| reference.characters())
+    }
+
+    #[test]
+    fn table_is_sorted_and_bounded() {
+        assert!(NAMED_CHARACTER_REFERENCES
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(
+            NAMED_CHARACTER_REFERENCES
+                .iter()
+                .map(|(identifier, _)| identifier.len())
+                .max(),
+            Some(LONGEST_IDENTIFIER_LENGTH)
+        );
+    }
 
-pub(crate) const NAMED_CHARACTER_REFERENCES: [(&str, Ncr); 2005] = [
-    ("AElig", Ncr::new([198u32], "\u{00C6}")),
-    ("AElig;", Ncr::new([198u32], "\u{00C6}")),
-    ("AMP", Ncr::new([38u32], "\u{0026}")),
-    ("AMP;", Ncr::new([38u32], "\u{0026}")),
-    ("Aacute", Ncr::new([193u32], "\u{00C1}")),
-    ("Aacute;", Ncr::new([193u32], "\u{00C1}")),
-    ("Abreve;", Ncr::new([258u32], "\u{0102}")),
-    ("Acirc", Ncr::new([194u32], "\u{00C2}")),
-    ("Acirc;", Ncr::new([194u32], "\u{00C2}")),
-    ("Acy;", Ncr::new([1040u32], "\u{0410}")),
-    // FIXME: ("Afr;", Ncr::new(  [120068u32], "\u{D835}\u{DD04}")),
-    ("Agrave", Ncr::new([192u32], "\u{00C0}")),
-    ("Agrave;", Ncr::new([192u32], "\u{00C0}")),
-    ("Alpha;", Ncr::new([913u32], "\u{0391}")),
-    ("Amacr;", Ncr::new([256u32], "\u{0100}")),
-    ("And;", Ncr::new([10835u32], "\u{2A53}")),
-    ("Aogon;", Ncr::new([260u32], "\u{0104}")),
-    // FIXME: ("Aopf;", Ncr::new(   [120120u32], "\u{D835}\u{DD38}")),
-    ("ApplyFunction;", Ncr::new([8289u32], "\u{2061}")),
-    ("Aring", Ncr::new([197u32], "\u{00C5}")),
-    ("Aring;", Ncr::new([197u32], "\u{00C5}")),
-    // FIXME: ("Ascr;", Ncr::new(   [119964u32], "\u{D835}\u{DC9C}")),
-    ("Assign;", Ncr::new([8788u32], "\u{2254}")),
-    ("Atilde", Ncr::new([195u32], "\u{00C3}")),
-    ("Atilde;", Ncr::new([195u32], "\u{00C3}")),
-    ("Auml", Ncr::new([196u32], "\u{00C4}")),
-    ("Auml;", Ncr::new([196u32], "\u{00C4}")),
-    ("Backslash;", Ncr::new([8726u32], "\u{2216}")),
-    ("Barv;", Ncr::new([10983u32], "\u{2AE7}")),
-    ("Barwed;", Ncr::new([8966u32], "\u{2306}")),
-    ("Bcy;", Ncr::new([1041u32], "\u{0411}")),
-    ("Because;", Ncr::new([8757u32], "\u{2235}")),
-    ("Bernoullis;", Ncr::new([8492u32], "\u{212C}")),
-    ("Beta;", Ncr::new([914u32], "\u{0392}")),
-    // FIXME: ("Bfr;", Ncr::new(  [120069u32], "\u{D835}\u{DD05}")),
-    // FIXME: ("Bopf;", Ncr::new(   [120121u32], "\u{D835}\u{DD39}")),
-    ("Breve;", Ncr::new([728u32], "\u{02D8}")),
-    ("Bscr;", Ncr::new([8492u32], "\u{212C}")),
-    ("Bumpeq;", Ncr::new([8782u32], "\u{224E}")),
-    ("CHcy;", Ncr::new([1063u32], "\u{0427}")),
-    ("COPY", Ncr::new([169u32], "\u{00A9}")),
-    ("COPY;", Ncr::new([169u32], "\u{00A9}")),
-    ("Cacute;", Ncr::new([262u32], "\u{0106}")),
-    ("Cap;", Ncr::new([8914u32], "\u{22D2}")),
-    ("CapitalDifferentialD;", Ncr::new([8517u32], "\u{2145}")),
-    ("Cayleys;", Ncr::new([8493u32], "\u{212D}")),
-    ("Ccaron;", Ncr::new([268u32], "\u{010C}")),
-    ("Ccedil", Ncr::new([199u32], "\u{00C7}")),
-    ("Ccedil;", Ncr::new([199u32], "\u{00C7}")),
-    ("Ccirc;", Ncr::new([264u32], "\u{0108}")),
-    ("Cconint;", Ncr::new([8752u32], "\u{2230}")),
-    ("Cdot;", Ncr::new([266u32], "\u{010A}")),
-    ("Cedilla;", Ncr::new([184u32], "\u{00B8}")),
-    ("CenterDot;", Ncr::new([183u32], "\u{00B7}")),
-    ("Cfr;", Ncr::new([8493u32], "\u{212D}")),
-    ("Chi;", Ncr::new([935u32], "\u{03A7}")),
-    ("CircleDot;", Ncr::new([8857u32], "\u{2299}")),
-    ("CircleMinus;", Ncr::new([8854u32], "\u{2296}")),
-    ("CirclePlus;", Ncr::new([8853u32], "\u{2295}")),
-    ("CircleTimes;", Ncr::new([8855u32], "\u{2297}")),
-    ("ClockwiseContourIntegral;", Ncr::new([8754u32], "\u{2232}")),
-    ("CloseCurlyDoubleQuote;", Ncr::new([8221u32], "\u{201D}")),
-    ("CloseCurlyQuote;", Ncr::new([8217u32], "\u{2019}")),
-    ("Colon;", Ncr::new([8759u32], "\u{2237}")),
-    ("Colone;", Ncr::new([10868u32], "\u{2A74}")),
-    ("Congruent;", Ncr::new([8801u32], "\u{2261}")),
-    ("Conint;", Ncr::new([8751u32], "\u{222F}")),
-    ("ContourIntegral;", Ncr::new([8750u32], "\u{222E}")),
-    ("Copf;", Ncr::new([8450u32], "\u{2102}")),
-    ("Coproduct;", Ncr::new([8720u32], "\u{2210}")),
-    (
-        "CounterClockwiseContourIntegral;",
-        Ncr::new([8755u32], "\u{2233}"),
-    ),
-    ("Cross;", Ncr::new([10799u32], "\u{2A2F}")),
-    // FIXME: ("Cscr;", Ncr::new(   [119966u32], "\u{D835}\u{DC9E}")),
-    ("Cup;", Ncr::new([8915u32], "\u{22D3}")),
-    ("CupCap;", Ncr::new([8781u32], "\u{224D}")),
-    ("DD;", Ncr::new([8517u32], "\u{2145}")),
-    ("DDotrahd;", Ncr::new([10513u32], "\u{2911}")),
-    ("DJcy;", Ncr::new([1026u32], "\u{0402}")),
-    ("DScy;", Ncr::new([1029u32], "\u{0405}")),
-    ("DZcy;", Ncr::new([1039u32], "\u{040F}")),
-    ("Dagger;", Ncr::new([8225u32], "\u{2021}")),
-    ("Darr;", Ncr::new([8609u32], "\u{21A1}")),
-    ("Dashv;", Ncr::new([10980u32], "\u{2AE4}")),
-    ("Dcaron;", Ncr::new([270u32], "\u{010E}")),
-    ("Dcy;", Ncr::new([1044u32], "\u{0414}")),
-    ("Del;", Ncr::new([8711u32], "\u{2207}")),
-    ("Delta;", Ncr::new([916u32], "\u{0394}")),
-    // FIXME: ("Dfr;", Ncr::new(  [120071u32], "\u{D835}\u{DD07}")),
-    ("DiacriticalAcute;", Ncr::new([180u32], "\u{00B4}")),
-    ("DiacriticalDot;", Ncr::new([729u32], "\u{02D9}")),
-    ("DiacriticalDoubleAcute;", Ncr::new([733u32], "\u{02DD}")),
-    ("DiacriticalGrave;", Ncr::new([96u32], "\u{0060}")),
-    ("DiacriticalTilde;", Ncr::new([732u32], "\u{02DC}")),
-    ("Diamond;", Ncr::new([8900u32], "\u{22C4}")),
-    ("DifferentialD;", Ncr::new([8518u32], "\u{2146}")),
-    // FIXME: ("Dopf;", Ncr::new(   [120123u32], "\u{D835}\u{DD3B}")),
-    ("Dot;", Ncr::new([168u32], "\u{00A8}")),
-    ("DotDot;", Ncr::new([8412u32], "\u{20DC}")),
-    ("DotEqual;", Ncr::new([8784u32], "\u{2250}")),
-    ("DoubleContourIntegral;", Ncr::new([8751u32], "\u{222F}")),
-    ("DoubleDot;", Ncr::new([168u32], "\u{00A8}")),
-    ("DoubleDownArrow;", Ncr::new([8659u32], "\u{21D3}")),
-    ("DoubleLeftArrow;", Ncr::new([8656u32], "\u{21D0}")),
-    ("DoubleLeftRightArrow;", Ncr::new([8660u32], "\u{21D4}")),
-    ("DoubleLeftTee;", Ncr::new([10980u32], "\u{2AE4}")),
-    ("DoubleLongLeftArrow;", Ncr::new([10232u32], "\u{27F8}")),
-    (
-        "DoubleLongLeftRightArrow;",
-        Ncr::new([10234u32], "\u{27FA}"),
-    ),
-    ("DoubleLongRightArrow;", Ncr::new([10233u32], "\u{27F9}")),
-    ("DoubleRightArrow;", Ncr::new([8658u32], "\u{21D2}")),
-    ("DoubleRightTee;", Ncr::new([8872u32], "\u{22A8}")),
-    ("DoubleUpArrow;", Ncr::new([8657u32], "\u{21D1}")),
-    ("DoubleUpDownArrow;", Ncr::new([8661u32], "\u{21D5}")),
-    ("DoubleVerticalBar;", Ncr::new([8741u32], "\u{2225}")),
-    ("DownArrow;", Ncr::new([8595u32], "\u{2193}")),
-    ("DownArrowBar;", Ncr::new([10515u32], "\u{2913}")),
-    ("DownArrowUpArrow;", Ncr::new([8693u32], "\u{21F5}")),
-    ("DownBreve;", Ncr::new([785u32], "\u{0311}")),
-    ("DownLeftRightVector;", Ncr::new([10576u32], "\u{2950}")),
-    ("DownLeftTeeVector;", Ncr::new([10590u32], "\u{295E}")),
-    ("DownLeftVector;", Ncr::new([8637u32], "\u{21BD}")),
-    ("DownLeftVectorBar;", Ncr::new([10582u32], "\u{2956}")),
-    ("DownRightTeeVector;", Ncr::new([10591u32], "\u{295F}")),
-    ("DownRightVector;", Ncr::new([8641u32], "\u{21C1}")),
-    ("DownRightVectorBar;", Ncr::new([10583u32], "\u{2957}")),
-    ("DownTee;", Ncr::new([8868u32], "\u{22A4}")),
-    ("DownTeeArrow;", Ncr::new([8615u32], "\u{21A7}")),
-    ("Downarrow;", Ncr::new([8659u32], "\u{21D3}")),
-    // FIXME: ("Dscr;", Ncr::new(   [119967u32], "\u{D835}\u{DC9F}")),
-    ("Dstrok;", Ncr::new([272u32], "\u{0110}")),
-    ("ENG;", Ncr::new([330u32], "\u{014A}")),
-    ("ETH", Ncr::new([208u32], "\u{00D0}")),
-    ("ETH;", Ncr::new([208u32], "\u{00D0}")),
-    ("Eacute", Ncr::new([201u32], "\u{00C9}")),
-    ("Eacute;", Ncr::new([201u32], "\u{00C9}")),
-    ("Ecaron;", Ncr::new([282u32], "\u{011A}")),
-    ("Ecirc", Ncr::new([202u32], "\u{00CA}")),
-    ("Ecirc;", Ncr::new([202u32], "\u{00CA}")),
-    ("Ecy;", Ncr::new([1069u32], "\u{042D}")),
-    ("Edot;", Ncr::new([278u32], "\u{0116}")),
-    // FIXME: ("Efr;", Ncr::new(  [120072u32], "\u{D835}\u{DD08}")),
-    ("Egrave", Ncr::new([200u32], "\u{00C8}")),
-    ("Egrave;", Ncr::new([200u32], "\u{00C8}")),
-    ("Element;", Ncr::new([8712u32], "\u{2208}")),
-    ("Emacr;", Ncr::new([274u32], "\u{0112}")),
-    ("EmptySmallSquare;", Ncr::new([9723u32], "\u{25FB}")),
-    ("EmptyVerySmallSquare;", Ncr::new([9643u32], "\u{25AB}")),
-    ("Eogon;", Ncr::new([280u32], "\u{0118}")),
-    // FIXME: ("Eopf;", Ncr::new(   [120124u32], "\u{D835}\u{DD3C}")),
-    ("Epsilon;", Ncr::new([917u32], "\u{0395}")),
-    ("Equal;", Ncr::new([10869u32], "\u{2A75}")),
-    ("EqualTilde;", Ncr::new([8770u32], "\u{2242}")),
-    ("Equilibrium;", Ncr::new([8652u32], "\u{21CC}")),
-    ("Escr;", Ncr::new([8496u32], "\u{2130}")),
-    ("Esim;", Ncr::new([10867u32], "\u{2A73}")),
-    ("Eta;", Ncr::new([919u32], "\u{0397}")),
-    ("Euml", Ncr::new([203u32], "\u{00CB}")),
-    ("Euml;", Ncr::new([203u32], "\u{00CB}")),
-    ("Exists;", Ncr::new([8707u32], "\u{2203}")),
-    ("ExponentialE;", Ncr::new([8519u32], "\u{2147}")),
-    ("Fcy;", Ncr::new([1060u32], "\u{0424}")),
-    // FIXME: ("Ffr;", Ncr::new(  [120073u32], "\u{D835}\u{DD09}")),
-    ("FilledSmallSquare;", Ncr::new([9724u32], "\u{25FC}")),
-    ("FilledVerySmallSquare;", Ncr::new([9642u32], "\u{25AA}")),
-    // FIXME: ("Fopf;", Ncr::new(   [120125u32], "\u{D835}\u{DD3D}")),
-    ("ForAll;", Ncr::new([8704u32], "\u{2200}")),
-    ("Fouriertrf;", Ncr::new([8497u32], "\u{2131}")),
-    ("Fscr;", Ncr::new([8497u32], "\u{2131}")),
-    ("GJcy;", Ncr::new([1027u32], "\u{0403}")),
-    ("GT", Ncr::new([62u32], "\u{003E}")),
-    ("GT;", Ncr::new([62u32], "\u{003E}")),
-    ("Gamma;", Ncr::new([915u32], "\u{0393}")),
-    ("Gammad;", Ncr::new([988u32], "\u{03DC}")),
-    ("Gbreve;", Ncr::new([286u32], "\u{011E}")),
-    ("Gcedil;", Ncr::new([290u32], "\u{0122}")),
-    ("Gcirc;", Ncr::new([284u32], "\u{011C}")),
-    ("Gcy;", Ncr::new([1043u32], "\u{0413}")),
-    ("Gdot;", Ncr::new([288u32], "\u{0120}")),
-    // FIXME: ("Gfr;", Ncr::new(  [120074u32], "\u{D835}\u{DD0A}")),
-    ("Gg;", Ncr::new([8921u32], "\u{22D9}")),
-    // FIXME: ("Gopf;", Ncr::new(   [120126u32], "\u{D835}\u{DD3E}")),
-    ("GreaterEqual;", Ncr::new([8805u32], "\u{2265}")),
-    ("GreaterEqualLess;", Ncr::new([8923u32], "\u{22DB}")),
-    ("GreaterFullEqual;", Ncr::new([8807u32], "\u{2267}")),
-    ("GreaterGreater;", Ncr::new([10914u32], "\u{2AA2}")),
-    ("GreaterLess;", Ncr::new([8823u32], "\u{2277}")),
-    ("GreaterSlantEqual;", Ncr::new([10878u32], "\u{2A7E}")),
-    ("GreaterTilde;", Ncr::new([8819u32], "\u{2273}")),
-    // FIXME: ("Gscr;", Ncr::new(   [119970u32], "\u{D835}\u{DCA2}")),
-    ("Gt;", Ncr::new([8811u32], "\u{226B}")),
-    ("HARDcy;", Ncr::new([1066u32], "\u{042A}")),
-    ("Hacek;", Ncr::new([711u32], "\u{02C7}")),
-    ("Hat;", Ncr::new([94u32], "\u{005E}")),
-    ("Hcirc;", Ncr::new([292u32], "\u{0124}")),
-    ("Hfr;", Ncr::new([8460u32], "\u{210C}")),
-    ("HilbertSpace;", Ncr::new([8459u32], "\u{210B}")),
-    ("Hopf;", Ncr::new([8461u32], "\u{210D}")),
-    ("HorizontalLine;", Ncr::new([9472u32], "\u{2500}")),
-    ("Hscr;", Ncr::new([8459u32], "\u{210B}")),
-    ("Hstrok;", Ncr::new([294u32], "\u{0126}")),
-    ("HumpDownHump;", Ncr::new([8782u32], "\u{224E}")),
-    ("HumpEqual;", Ncr::new([8783u32], "\u{224F}")),
-    ("IEcy;", Ncr::new([1045u32], "\u{0415}")),
-    ("IJlig;", Ncr::new([306u32], "\u{0132}")),
-    ("IOcy;", Ncr::new([1025u32], "\u{0401}")),
-    ("Iacute", Ncr::new([205u32], "\u{00CD}")),
-    ("Iacute;", Ncr::new([205u32], "\u{00CD}")),
-    ("Icirc", Ncr::new([206u32], "\u{00CE}")),
-    ("Icirc;", Ncr::new([206u32], "\u{00CE}")),
-    ("Icy;", Ncr::new([1048u32], "\u{0418}")),
-    ("Idot;", Ncr::new([304u32], "\u{0130}")),
-    ("Ifr;", Ncr::new([8465u32], "\u{2111}")),
-    ("Igrave", Ncr::new([204u32], "\u{00CC}")),
-    ("Igrave;", Ncr::new([204u32], "\u{00CC}")),
-    ("Im;", Ncr::new([8465u32], "\u{2111}")),
-    ("Imacr;", Ncr::new([298u32], "\u{012A}")),
-    ("ImaginaryI;", Ncr::new([8520u32], "\u{2148}")),
-    ("Implies;", Ncr::new([8658u32], "\u{21D2}")),
-    ("Int;", Ncr::new([8748u32], "\u{222C}")),
-    ("Integral;", Ncr::new([8747u32], "\u{222B}")),
-    ("Intersection;", Ncr::new([8898u32], "\u{22C2}")),
-    ("InvisibleComma;", Ncr::new([8291u32], "\u{2063}")),
-    ("InvisibleTimes;", Ncr::new([8290u32], "\u{2062}")),
-    ("Iogon;", Ncr::new([302u32], "\u{012E}")),
-    // FIXME: ("Iopf;", Ncr::new(   [120128u32], "\u{D835}\u{DD40}")),
-    ("Iota;", Ncr::new([921u32], "\u{0399}")),
-    ("Iscr;", Ncr::new([8464u32], "\u{2110}")),
-    ("Itilde;", Ncr::new([296u32], "\u{0128}")),
-    ("Iukcy;", Ncr::new([1030u32], "\u{0406}")),
-    ("Iuml", Ncr::new([207u32], "\u{00CF}")),
-    ("Iuml;", Ncr::new([207u32], "\u{00CF}")),
-    ("Jcirc;", Ncr::new([308u32], "\u{0134}")),
-    ("Jcy;", Ncr::new([1049u32], "\u{0419}")),
-    // FIXME: ("Jfr;", Ncr::new(  [120077u32], "\u{D835}\u{DD0D}")),
-    // FIXME: ("Jopf;", Ncr::new(   [120129u32], "\u{D835}\u{DD41}")),
-    // FIXME: ("Jscr;", Ncr::new(   [119973u32], "\u{D835}\u{DCA5}")),
-    ("Jsercy;", Ncr::new([1032u32], "\u{0408}")),
-    ("Jukcy;", Ncr::new([1028u32], "\u{0404}")),
-    ("KHcy;", Ncr::new([1061u32], "\u{0425}")),
-    ("KJcy;", Ncr::new([1036u32], "\u{040C}")),
-    ("Kappa;", Ncr::new([922u32], "\u{039A}")),
-    ("Kcedil;", Ncr::new([310u32], "\u{0136}")),
-    ("Kcy;", Ncr::new([1050u32], "\u{041A}")),
-    // FIXME: ("Kfr;", Ncr::new(  [120078u32], "\u{D835}\u{DD0E}")),
-    // FIXME: ("Kopf;", Ncr::new(   [120130u32], "\u{D835}\u{DD42}")),
-    // FIXME: ("Kscr;", Ncr::new(   [119974u32], "\u{D835}\u{DCA6}")),
-    ("LJcy;", Ncr::new([1033u32], "\u{0409}")),
-    ("LT", Ncr::new([60u32], "\u{003C}")),
-    ("LT;", Ncr::new([60u32], "\u{003C}")),
-    ("Lacute;", Ncr::new([313u32], "\u{0139}")),
-    ("Lambda;", Ncr::new([923u32], "\u{039B}")),
-    ("Lang;", Ncr::new([10218u32], "\u{27EA}")),
-    ("Laplacetrf;", Ncr::new([8466u32], "\u{2112}")),
-    ("Larr;", Ncr::new([8606u32], "\u{219E}")),
-    ("Lcaron;", Ncr::new([317u32], "\u{013D}")),
-    ("Lcedil;", Ncr::new([315u32], "\u{013B}")),
-    ("Lcy;", Ncr::new([1051u32], "\u{041B}")),
-    ("LeftAngleBracket;", Ncr::new([10216u32], "\u{27E8}")),
-    ("LeftArrow;", Ncr::new([8592u32], "\u{2190}")),
-    ("LeftArrowBar;", Ncr::new([8676u32], "\u{21E4}")),
-    ("LeftArrowRightArrow;", Ncr::new([8646u32], "\u{21C6}")),
-    ("LeftCeiling;", Ncr::new([8968u32], "\u{2308}")),
-    ("LeftDoubleBracket;", Ncr::new([10214u32], "\u{27E6}")),
-    ("LeftDownTeeVector;", Ncr::new([10593u32], "\u{2961}")),
-    ("LeftDownVector;", Ncr::new([8643u32], "\u{21C3}")),
-    ("LeftDownVectorBar;", Ncr::new([10585u32], "\u{2959}")),
-    ("LeftFloor;", Ncr::new([8970u32], "\u{230A}")),
-    ("LeftRightArrow;", Ncr::new([8596u32], "\u{2194}")),
-    ("LeftRightVector;", Ncr::new([10574u32], "\u{294E}")),
-    ("LeftTee;", Ncr::new([8867u32], "\u{22A3}")),
-    ("LeftTeeArrow;", Ncr::new([8612u32], "\u{21A4}")),
-    ("LeftTeeVector;", Ncr::new([10586u32], "\u{295A}")),
-    ("LeftTriangle;", Ncr::new([8882u32], "\u{22B2}")),
-    ("LeftTriangleBar;", Ncr::new([10703u32], "\u{29CF}")),
-    ("LeftTriangleEqual;", Ncr::new([8884u32], "\u{22B4}")),
-    ("LeftUpDownVector;", Ncr::new([10577u32], "\u{2951}")),
-    ("LeftUpTeeVector;", Ncr::new([10592u32], "\u{2960}")),
-    ("LeftUpVector;", Ncr::new([8639u32], "\u{21BF}")),
-    ("LeftUpVectorBar;", Ncr::new([10584u32], "\u{2958}")),
-    ("LeftVector;", Ncr::new([8636u32], "\u{21BC}")),
-    ("LeftVectorBar;", Ncr::new([10578u32], "\u{2952}")),
-    ("Leftarrow;", Ncr::new([8656u32], "\u{21D0}")),
-    ("Leftrightarrow;", Ncr::new([8660u32], "\u{21D4}")),
-    ("LessEqualGreater;", Ncr::new([8922u32], "\u{22DA}")),
-    ("LessFullEqual;", Ncr::new([8806u32], "\u{2266}")),
-    ("LessGreater;", Ncr::new([8822u32], "\u{2276}")),
-    ("LessLess;", Ncr::new([10913u32], "\u{2AA1}")),
-    ("LessSlantEqual;", Ncr::new([10877u32], "\u{2A7D}")),
-    ("LessTilde;", Ncr::new([8818u32], "\u{2272}")),
-    // FIXME: ("Lfr;", Ncr::new(  [120079u32], "\u{D835}\u{DD0F}")),
-    ("Ll;", Ncr::new([8920u32], "\u{22D8}")),
-    ("Lleftarrow;", Ncr::new([8666u32], "\u{21DA}")),
-    ("Lmidot;", Ncr::new([319u32], "\u{013F}")),
-    ("LongLeftArrow;", Ncr::new([10229u32], "\u{27F5}")),
-    ("LongLeftRightArrow;", Ncr::new([10231u32], "\u{27F7}")),
-    ("LongRightArrow;", Ncr::new([10230u32], "\u{27F6}")),
-    ("Longleftarrow;", Ncr::new([10232u32], "\u{27F8}")),
-    ("Longleftrightarrow;", Ncr::new([10234u32], "\u{27FA}")),
-    ("Longrightarrow;", Ncr::new([10233u32], "\u{27F9}")),
-    // FIXME: ("Lopf;", Ncr::new(   [120131u32], "\u{D835}\u{DD43}")),
-    ("LowerLeftArrow;", Ncr::new([8601u32], "\u{2199}")),
-    ("LowerRightArrow;", Ncr::new([8600u32], "\u{2198}")),
-    ("Lscr;", Ncr::new([8466u32], "\u{2112}")),
-    ("Lsh;", Ncr::new([8624u32], "\u{21B0}")),
-    ("Lstrok;", Ncr::new([321u32], "\u{0141}")),
-    ("Lt;", Ncr::new([8810u32], "\u{226A}")),
-    ("Map;", Ncr::new([10501u32], "\u{2905}")),
-    ("Mcy;", Ncr::new([1052u32], "\u{041C}")),
-    ("MediumSpace;", Ncr::new([8287u32], "\u{205F}")),
-    ("Mellintrf;", Ncr::new([8499u32], "\u{2133}")),
-    // FIXME: ("Mfr;", Ncr::new(  [120080u32], "\u{D835}\u{DD10}")),
-    ("MinusPlus;", Ncr::new([8723u32], "\u{2213}")),
-    // FIXME: ("Mopf;", Ncr::new(   [120132u32], "\u{D835}\u{DD44}")),
-    ("Mscr;", Ncr::new([8499u32], "\u{2133}")),
-    ("Mu;", Ncr::new([924u32], "\u{039C}")),
-    ("NJcy;", Ncr::new([1034u32], "\u{040A}")),
-    ("Nacute;", Ncr::new([323u32], "\u{0143}")),
-    ("Ncaron;", Ncr::new([327u32], "\u{0147}")),
-    ("Ncedil;", Ncr::new([325u32], "\u{0145}")),
-    ("Ncy;", Ncr::new([1053u32], "\u{041D}")),
-    ("NegativeMediumSpace;", Ncr::new([8203u32], "\u{200B}")),
-    ("NegativeThickSpace;", Ncr::new([8203u32], "\u{200B}")),
-    ("NegativeThinSpace;", Ncr::new([8203u32], "\u{200B}")),
-    ("NegativeVeryThinSpace;", Ncr::new([8203u32], "\u{200B}")),
-    ("NestedGreaterGreater;", Ncr::new([8811u32], "\u{226B}")),
-    ("NestedLessLess;", Ncr::new([8810u32], "\u{226A}")),
-    ("NewLine;", Ncr::new([10u32], "\u{000A}")),
-    // FIXME: ("Nfr;", Ncr::new(  [120081u32], "\u{D835}\u{DD11}")),
-    ("NoBreak;", Ncr::new([8288u32], "\u{2060}")),
-    ("NonBreakingSpace;", Ncr::new([160u32], "\u{00A0}")),
-    ("Nopf;", Ncr::new([8469u32], "\u{2115}")),
-    ("Not;", Ncr::new([10988u32], "\u{2AEC}")),
-    ("NotCongruent;", Ncr::new([8802u32], "\u{2262}")),
-    ("NotCupCap;", Ncr::new([8813u32], "\u{226D}")),
-    ("NotDoubleVerticalBar;", Ncr::new([8742u32], "\u{2226}")),
-    ("NotElement;", Ncr::new([8713u32], "\u{2209}")),
-    ("NotEqual;", Ncr::new([8800u32], "\u{2260}")),
-    // FIXME: ("NotEqualTilde;", Ncr::new(  [8770, 824u32], "\u{2242}\u{0338}")),
-    ("NotExists;", Ncr::new([8708u32], "\u{2204}")),
-    ("NotGreater;", Ncr::new([8815u32], "\u{226F}")),
-    ("NotGreaterEqual;", Ncr::new([8817u32], "\u{2271}")),
-    // FIXME: ("NotGreaterFullEqual;", Ncr::new(  [8807, 824u32], "\u{2267}\u{0338}")),
-    // FIXME: ("NotGreaterGreater;", Ncr::new(  [8811, 824u32], "\u{226B}\u{0338}")),
-    ("NotGreaterLess;", Ncr::new([8825u32], "\u{2279}")),
-    // FIXME: ("NotGreaterSlantEqual;", Ncr::new(   [10878, 824u32], "\u{2A7E}\u{0338}")),
-    ("NotGreaterTilde;", Ncr::new([8821u32], "\u{2275}")),
-    // FIXME: ("NotHumpDownHump;", Ncr::new(  [8782, 824u32], "\u{224E}\u{0338}")),
-    // FIXME: ("NotHumpEqual;", Ncr::new(   [8783, 824u32], "\u{224F}\u{0338}")),
-    ("NotLeftTriangle;", Ncr::new([8938u32], "\u{22EA}")),
-    // FIXME: ("NotLeftTriangleBar;", Ncr::new(   [10703, 824u32], "\u{29CF}\u{0338}")),
-    ("NotLeftTriangleEqual;", Ncr::new([8940u32], "\u{22EC}")),
-    ("NotLess;", Ncr::new([8814u32], "\u{226E}")),
-    ("NotLessEqual;", Ncr::new([8816u32], "\u{2270}")),
-    ("NotLessGreater;", Ncr::new([8824u32], "\u{2278}")),
-    // FIXME: ("NotLessLess;", Ncr::new(  [8810, 824u32], "\u{226A}\u{0338}")),
-    // FIXME: ("NotLessSlantEqual;", Ncr::new(  [10877, 824u32], "\u{2A7D}\u{0338}")),
-    ("NotLessTilde;", Ncr::new([8820u32], "\u{2274}")),
-    // FIXME: ("NotNestedGreaterGreater;", Ncr::new(  [10914, 824u32], "\u{2AA2}\u{0338}")),
-    // FIXME: ("NotNestedLessLess;", Ncr::new(  [10913, 824u32], "\u{2AA1}\u{0338}")),
-    ("NotPrecedes;", Ncr::new([8832u32], "\u{2280}")),
-    // FIXME: ("NotPrecedesEqual;", Ncr::new(   [10927, 824u32], "\u{2AAF}\u{0338}")),
-    ("NotPrecedesSlantEqual;", Ncr::new([8928u32], "\u{22E0}")),
-    ("NotReverseElement;", Ncr::new([8716u32], "\u{220C}")),
-    ("NotRightTriangle;", Ncr::new([8939u32], "\u{22EB}")),
-    // FIXME: ("NotRightTriangleBar;", Ncr::new(  [10704, 824u32], "\u{29D0}\u{0338}")),
-    ("NotRightTriangleEqual;", Ncr::new([8941u32], "\u{22ED}")),
-    // FIXME: ("NotSquareSubset;", Ncr::new(  [8847, 824u32], "\u{228F}\u{0338}")),
-    ("NotSquareSubsetEqual;", Ncr::new([8930u32], "\u{22E2}")),
-    // FIXME: ("NotSquareSuperset;", Ncr::new(  [8848, 824u32], "\u{2290}\u{0338}")),
-    ("NotSquareSupersetEqual;", Ncr::new([8931u32], "\u{22E3}")),
-    // FIXME: ("NotSubset;", Ncr::new(  [8834, 8402u32], "\u{2282}\u{20D2}")),
-    ("NotSubsetEqual;", Ncr::new([8840u32], "\u{2288}")),
-    ("NotSucceeds;", Ncr::new([8833u32], "\u{2281}")),
-    // FIXME: ("NotSucceedsEqual;", Ncr::new(   [10928, 824u32], "\u{2AB0}\u{0338}")),
-    ("NotSucceedsSlantEqual;", Ncr::new([8929u32], "\u{22E1}")),
-    // FIXME: ("NotSucceedsTilde;", Ncr::new(   [8831, 824u32], "\u{227F}\u{0338}")),
-    // FIXME: ("NotSuperset;", Ncr::new(  [8835, 8402u32], "\u{2283}\u{20D2}")),
-    ("NotSupersetEqual;", Ncr::new([8841u32], "\u{2289}")),
-    ("NotTilde;", Ncr::new([8769u32], "\u{2241}")),
-    ("NotTildeEqual;", Ncr::new([8772u32], "\u{2244}")),
-    ("NotTildeFullEqual;", Ncr::new([8775u32], "\u{2247}")),
-    ("NotTildeTilde;", Ncr::new([8777u32], "\u{2249}")),
-    ("NotVerticalBar;", Ncr::new([8740u32], "\u{2224}")),
-    // FIXME: ("Nscr;", Ncr::new(   [119977u32], "\u{D835}\u{DCA9}")),
-    ("Ntilde", Ncr::new([209u32], "\u{00D1}")),
-    ("Ntilde;", Ncr::new([209u32], "\u{00D1}")),
-    ("Nu;", Ncr::new([925u32], "\u{039D}")),
-    ("OElig;", Ncr::new([338u32], "\u{0152}")),
-    ("Oacute", Ncr::new([211u32], "\u{00D3}")),
-    ("Oacute;", Ncr::new([211u32], "\u{00D3}")),
-    ("Ocirc", Ncr::new([212u32], "\u{00D4}")),
-    ("Ocirc;", Ncr::new([212u32], "\u{00D4}")),
-    ("Ocy;", Ncr::new([1054u32], "\u{041E}")),
-    ("Odblac;", Ncr::new([336u32], "\u{0150}")),
-    // FIXME: ("Ofr;", Ncr::new(  [120082u32], "\u{D835}\u{DD12}")),
-    ("Ograve", Ncr::new([210u32], "\u{00D2}")),
-    ("Ograve;", Ncr::new([210u32], "\u{00D2}")),
-    ("Omacr;", Ncr::new([332u32], "\u{014C}")),
-    ("Omega;", Ncr::new([937u32], "\u{03A9}")),
-    ("Omicron;", Ncr::new([927u32], "\u{039F}")),
-    // FIXME: ("Oopf;", Ncr::new(   [120134u32], "\u{D835}\u{DD46}")),
-    ("OpenCurlyDoubleQuote;", Ncr::new([8220u32], "\u{201C}")),
-    ("OpenCurlyQuote;", Ncr::new([8216u32], "\u{2018}")),
-    ("Or;", Ncr::new([10836u32], "\u{2A54}")),
-    // FIXME: ("Oscr;", Ncr::new(   [119978u32], "\u{D835}\u{DCAA}")),
-    ("Oslash", Ncr::new([216u32], "\u{00D8}")),
-    ("Oslash;", Ncr::new([216u32], "\u{00D8}")),
-    ("Otilde", Ncr::new([213u32], "\u{00D5}")),
-    ("Otilde;", Ncr::new([213u32], "\u{00D5}")),
-    ("Otimes;", Ncr::new([10807u32], "\u{2A37}")),
-    ("Ouml", Ncr::new([214u32], "\u{00D6}")),
-    ("Ouml;", Ncr::new([214u32], "\u{00D6}")),
-    ("OverBar;", Ncr::new([8254u32], "\u{203E}")),
-    ("OverBrace;", Ncr::new([9182u32], "\u{23DE}")),
-    ("OverBracket;", Ncr::new([9140u32], "\u{23B4}")),
-    ("OverParenthesis;", Ncr::new([9180u32], "\u{23DC}")),
-    ("PartialD;", Ncr::new([8706u32], "\u{2202}")),
-    ("Pcy;", Ncr::new([1055u32], "\u{041F}")),
-    // FIXME: ("Pfr;", Ncr::new(  [120083u32], "\u{D835}\u{DD13}")),
-    ("Phi;", Ncr::new([934u32], "\u{03A6}")),
-    ("Pi;", Ncr::new([928u32], "\u{03A0}")),
-    ("PlusMinus;", Ncr::new([177u32], "\u{00B1}")),
-    ("Poincareplane;", Ncr::new([8460u32], "\u{210C}")),
-    ("Popf;", Ncr::new([8473u32], "\u{2119}")),
-    ("Pr;", Ncr::new([10939u32], "\u{2ABB}")),
-    ("Precedes;", Ncr::new([8826u32], "\u{227A}")),
-    ("PrecedesEqual;", Ncr::new([10927u32], "\u{2AAF}")),
-    ("PrecedesSlantEqual;", Ncr::new([8828u32], "\u{227C}")),
-    ("PrecedesTilde;", Ncr::new([8830u32], "\u{227E}")),
-    ("Prime;", Ncr::new([8243u32], "\u{2033}")),
-    ("Product;", Ncr::new([8719u32], "\u{220F}")),
-    ("Proportion;", Ncr::new([8759u32], "\u{2237}")),
-    ("Proportional;", Ncr::new([8733u32], "\u{221D}")),
-    // FIXME: ("Pscr;", Ncr::new(   [119979u32], "\u{D835}\u{DCAB}")),
-    ("Psi;", Ncr::new([936u32], "\u{03A8}")),
-    ("QUOT", Ncr::new([34u32], "\u{0022}")),
-    ("QUOT;", Ncr::new([34u32], "\u{0022}")),
-    // FIXME: ("Qfr;", Ncr::new(  [120084u32], "\u{D835}\u{DD14}")),
-    ("Qopf;", Ncr::new([8474u32], "\u{211A}")),
-    // FIXME: ("Qscr;", Ncr::new(   [119980u32], "\u{D835}\u{DCAC}")),
-    ("RBarr;", Ncr::new([10512u32], "\u{2910}")),
-    ("REG", Ncr::new([174u32], "\u{00AE}")),
-    ("REG;", Ncr::new([174u32], "\u{00AE}")),
-    ("Racute;", Ncr::new([340u32], "\u{0154}")),
-    ("Rang;", Ncr::new([10219u32], "\u{27EB}")),
-    ("Rarr;", Ncr::new([8608u32], "\u{21A0}")),
-    ("Rarrtl;", Ncr::new([10518u32], "\u{2916}")),
-    ("Rcaron;", Ncr::new([344u32], "\u{0158}")),
-    ("Rcedil;", Ncr::new([342u32], "\u{0156}")),
-    ("Rcy;", Ncr::new([1056u32], "\u{0420}")),
-    ("Re;", Ncr::new([8476u32], "\u{211C}")),
-    ("ReverseElement;", Ncr::new([8715u32], "\u{220B}")),
-    ("ReverseEquilibrium;", Ncr::new([8651u32], "\u{21CB}")),
-    ("ReverseUpEquilibrium;", Ncr::new([10607u32], "\u{296F}")),
-    ("Rfr;", Ncr::new([8476u32], "\u{211C}")),
-    ("Rho;", Ncr::new([929u32], "\u{03A1}")),
-    ("RightAngleBracket;", Ncr::new([10217u32], "\u{27E9}")),
-    ("RightArrow;", Ncr::new([8594u32], "\u{2192}")),
-    ("RightArrowBar;", Ncr::new([8677u32], "\u{21E5}")),
-    ("RightArrowLeftArrow;", Ncr::new([8644u32], "\u{21C4}")),
-    ("RightCeiling;", Ncr::new([8969u32], "\u{2309}")),
-    ("RightDoubleBracket;", Ncr::new([10215u32], "\u{27E7}")),
-    ("RightDownTeeVector;", Ncr::new([10589u32], "\u{295D}")),
-    ("RightDownVector;", Ncr::new([8642u32], "\u{21C2}")),
-    ("RightDownVectorBar;", Ncr::new([10581u32], "\u{2955}")),
-    ("RightFloor;", Ncr::new([8971u32], "\u{230B}")),
-    ("RightTee;", Ncr::new([8866u32], "\u{22A2}")),
-    ("RightTeeArrow;", Ncr::new([8614u32], "\u{21A6}")),
-    ("RightTeeVector;", Ncr::new([10587u32], "\u{295B}")),
-    ("RightTriangle;", Ncr::new([8883u32], "\u{22B3}")),
-    ("RightTriangleBar;", Ncr::new([10704u32], "\u{29D0}")),
-    ("RightTriangleEqual;", Ncr::new([8885u32], "\u{22B5}")),
-    ("RightUpDownVector;", Ncr::new([10575u32], "\u{294F}")),
-    ("RightUpTeeVector;", Ncr::new([10588u32], "\u{295C}")),
-    ("RightUpVector;", Ncr::new([8638u32], "\u{21BE}")),
-    ("RightUpVectorBar;", Ncr::new([10580u32], "\u{2954}")),
-    ("RightVector;", Ncr::new([8640u32], "\u{21C0}")),
-    ("RightVectorBar;", Ncr::new([10579u32], "\u{2953}")),
-    ("Rightarrow;", Ncr::new([8658u32], "\u{21D2}")),
-    ("Ropf;", Ncr::new([8477u32], "\u{211D}")),
-    ("RoundImplies;", Ncr::new([10608u32], "\u{2970}")),
-    ("Rrightarrow;", Ncr::new([8667u32], "\u{21DB}")),
-    ("Rscr;", Ncr::new([8475u32], "\u{211B}")),
-    ("Rsh;", Ncr::new([8625u32], "\u{21B1}")),
-    ("RuleDelayed;", Ncr::new([10740u32], "\u{29F4}")),
-    ("SHCHcy;", Ncr::new([1065u32], "\u{0429}")),
-    ("SHcy;", Ncr::new([1064u32], "\u{0428}")),
-    ("SOFTcy;", Ncr::new([1068u32], "\u{042C}")),
-    ("Sacute;", Ncr::new([346u32], "\u{015A}")),
-    ("Sc;", Ncr::new([10940u32], "\u{2ABC}")),
-    ("Scaron;", Ncr::new([352u32], "\u{0160}")),
-    ("Scedil;", Ncr::new([350u32], "\u{015E}")),
-    ("Scirc;", Ncr::new([348u32], "\u{015C}")),
-    ("Scy;", Ncr::new([1057u32], "\u{0421}")),
-    // FIXME: ("Sfr;", Ncr::new(  [120086u32], "\u{D835}\u{DD16}")),
-    ("ShortDownArrow;", Ncr::new([8595u32], "\u{2193}")),
-    ("ShortLeftArrow;", Ncr::new([8592u32], "\u{2190}")),
-    ("ShortRightArrow;", Ncr::new([8594u32], "\u{2192}")),
-    ("ShortUpArrow;", Ncr::new([8593u32], "\u{2191}")),
-    ("Sigma;", Ncr::new([931u32], "\u{03A3}")),
-    ("SmallCircle;", Ncr::new([8728u32], "\u{2218}")),
-    // FIXME: ("Sopf;", Ncr::new(   [120138u32], "\u{D835}\u{DD4A}")),
-    ("Sqrt;", Ncr::new([8730u32], "\u{221A}")),
-    ("Square;", Ncr::new([9633u32], "\u{25A1}")),
-    ("SquareIntersection;", Ncr::new([8851u32], "\u{2293}")),
-    ("SquareSubset;", Ncr::new([8847u32], "\u{228F}")),
-    ("SquareSubsetEqual;", Ncr::new([8849u32], "\u{2291}")),
-    ("SquareSuperset;", Ncr::new([8848u32], "\u{2290}")),
-    ("SquareSupersetEqual;", Ncr::new([8850u32], "\u{2292}")),
-    ("SquareUnion;", Ncr::new([8852u32], "\u{2294}")),
-    // FIXME: ("Sscr;", Ncr::new(   [119982u32], "\u{D835}\u{DCAE}")),
-    ("Star;", Ncr::new([8902u32], "\u{22C6}")),
-    ("Sub;", Ncr::new([8912u32], "\u{22D0}")),
-    ("Subset;", Ncr::new([8912u32], "\u{22D0}")),
-    ("SubsetEqual;", Ncr::new([8838u32], "\u{2286}")),
-    ("Succeeds;", Ncr::new([8827u32], "\u{227B}")),
-    ("SucceedsEqual;", Ncr::new([10928u32], "\u{2AB0}")),
-    ("SucceedsSlantEqual;", Ncr::new([8829u32], "\u{227D}")),
-    ("SucceedsTilde;", Ncr::new([8831u32], "\u{227F}")),
-    ("SuchThat;", Ncr::new([8715u32], "\u{220B}")),
-    ("Sum;", Ncr::new([8721u32], "\u{2211}")),
-    ("Sup;", Ncr::new([8913u32], "\u{22D1}")),
-    ("Superset;", Ncr::new([8835u32], "\u{2283}")),
-    ("SupersetEqual;", Ncr::new([8839u32], "\u{2287}")),
-    ("Supset;", Ncr::new([8913u32], "\u{22D1}")),
-    ("THORN", Ncr::new([222u32], "\u{00DE}")),
-    ("THORN;", Ncr::new([222u32], "\u{00DE}")),
-    ("TRADE;", Ncr::new([8482u32], "\u{2122}")),
-    ("TSHcy;", Ncr::new([1035u32], "\u{040B}")),
-    ("TScy;", Ncr::new([1062u32], "\u{0426}")),
-    ("Tab;", Ncr::new([9u32], "\u{0009}")),
-    ("Tau;", Ncr::new([932u32], "\u{03A4}")),
-    ("Tcaron;", Ncr::new([356u32], "\u{0164}")),
-    ("Tcedil;", Ncr::new([354u32], "\u{0162}")),
-    ("Tcy;", Ncr::new([1058u32], "\u{0422}")),
-    // FIXME: ("Tfr;", Ncr::new(  [120087u32], "\u{D835}\u{DD17}")),
-    ("Therefore;", Ncr::new([8756u32], "\u{2234}")),
-    ("Theta;", Ncr::new([920u32], "\u{0398}")),
-    // FIXME: ("ThickSpace;", Ncr::new(   [8287, 8202u32], "\u{205F}\u{200A}")),
-    ("ThinSpace;", Ncr::new([8201u32], "\u{2009}")),
-    ("Tilde;", Ncr::new([8764u32], "\u{223C}")),
-    ("TildeEqual;", Ncr::new([8771u32], "\u{2243}")),
-    ("TildeFullEqual;", Ncr::new([8773u32], "\u{2245}")),
-    ("TildeTilde;", Ncr::new([8776u32], "\u{2248}")),
-    // FIXME: ("Topf;", Ncr::new(   [120139u32], "\u{D835}\u{DD4B}")),
-    ("TripleDot;", Ncr::new([8411u32], "\u{20DB}")),
-    // FIXME: ("Tscr;", Ncr::new(   [119983u32], "\u{D835}\u{DCAF}")),
-    ("Tstrok;", Ncr::new([358u32], "\u{0166}")),
-    ("Uacute", Ncr::new([218u32], "\u{00DA}")),
-    ("Uacute;", Ncr::new([218u32], "\u{00DA}")),
-    ("Uarr;", Ncr::new([8607u32], "\u{219F}")),
-    ("Uarrocir;", Ncr::new([10569u32], "\u{2949}")),
-    ("Ubrcy;", Ncr::new([1038u32], "\u{040E}")),
-    ("Ubreve;", Ncr::new([364u32], "\u{016C}")),
-    ("Ucirc", Ncr::new([219u32], "\u{00DB}")),
-    ("Ucirc;", Ncr::new([219u32], "\u{00DB}")),
-    ("Ucy;", Ncr::new([1059u32], "\u{0423}")),
-    ("Udblac;", Ncr::new([368u32], "\u{0170}")),
-    // FIXME: ("Ufr;", Ncr::new(  [120088u32], "\u{D835}\u{DD18}")),
-    ("Ugrave", Ncr::new([217u32], "\u{00D9}")),
-    ("Ugrave;", Ncr::new([217u32], "\u{00D9}")),
-    ("Umacr;", Ncr::new([362u32], "\u{016A}")),
-    ("UnderBar;", Ncr::new([95u32], "\u{005F}")),
-    ("UnderBrace;", Ncr::new([9183u32], "\u{23DF}")),
-    ("UnderBracket;", Ncr::new([9141u32], "\u{23B5}")),
-    ("UnderParenthesis;", Ncr::new([9181u32], "\u{23DD}")),
-    ("Union;", Ncr::new([8899u32], "\u{22C3}")),
-    ("UnionPlus;", Ncr::new([8846u32], "\u{228E}")),
-    ("Uogon;", Ncr::new([370u32], "\u{0172}")),
-    // FIXME: ("Uopf;", Ncr::new(   [120140u32], "\u{D835}\u{DD4C}")),
-    ("UpArrow;", Ncr::new([8593u32], "\u{2191}")),
-    ("UpArrowBar;", Ncr::new([10514u32], "\u{2912}")),
-    ("UpArrowDownArrow;", Ncr::new([8645u32], "\u{21C5}")),
-    ("UpDownArrow;", Ncr::new([8597u32], "\u{2195}")),
-    ("UpEquilibrium;", Ncr::new([10606u32], "\u{296E}")),
-    ("UpTee;", Ncr::new([8869u32], "\u{22A5}")),
-    ("UpTeeArrow;", Ncr::new([8613u32], "\u{21A5}")),
-    ("Uparrow;", Ncr::new([8657u32], "\u{21D1}")),
-    ("Updownarrow;", Ncr::new([8661u32], "\u{21D5}")),
-    ("UpperLeftArrow;", Ncr::new([8598u32], "\u{2196}")),
-    ("UpperRightArrow;", Ncr::new([8599u32], "\u{2197}")),
-    ("Upsi;", Ncr::new([978u32], "\u{03D2}")),
-    ("Upsilon;", Ncr::new([933u32], "\u{03A5}")),
-    ("Uring;", Ncr::new([366u32], "\u{016E}")),
-    // FIXME: ("Uscr;", Ncr::new(   [119984u32], "\u{D835}\u{DCB0}")),
-    ("Utilde;", Ncr::new([360u32], "\u{0168}")),
-    ("Uuml", Ncr::new([220u32], "\u{00DC}")),
-    ("Uuml;", Ncr::new([220u32], "\u{00DC}")),
-    ("VDash;", Ncr::new([8875u32], "\u{22AB}")),
-    ("Vbar;", Ncr::new([10987u32], "\u{2AEB}")),
-    ("Vcy;", Ncr::new([1042u32], "\u{0412}")),
-    ("Vdash;", Ncr::new([8873u32], "\u{22A9}")),
-    ("Vdashl;", Ncr::new([10982u32], "\u{2AE6}")),
-    ("Vee;", Ncr::new([8897u32], "\u{22C1}")),
-    ("Verbar;", Ncr::new([8214u32], "\u{2016}")),
-    ("Vert;", Ncr::new([8214u32], "\u{2016}")),
-    ("VerticalBar;", Ncr::new([8739u32], "\u{2223}")),
-    ("VerticalLine;", Ncr::new([124u32], "\u{007C}")),
-    ("VerticalSeparator;", Ncr::new([10072u32], "\u{2758}")),
-    ("VerticalTilde;", Ncr::new([8768u32], "\u{2240}")),
-    ("VeryThinSpace;", Ncr::new([8202u32], "\u{200A}")),
-    // FIXME: ("Vfr;", Ncr::new(  [120089u32], "\u{D835}\u{DD19}")),
-    // FIXME: ("Vopf;", Ncr::new(   [120141u32], "\u{D835}\u{DD4D}")),
-    // FIXME: ("Vscr;", Ncr::new(   [119985u32], "\u{D835}\u{DCB1}")),
-    ("Vvdash;", Ncr::new([8874u32], "\u{22AA}")),
-    ("Wcirc;", Ncr::new([372u32], "\u{0174}")),
-    ("Wedge;", Ncr::new([8896u32], "\u{22C0}")),
-    // FIXME: ("Wfr;", Ncr::new(  [120090u32], "\u{D835}\u{DD1A}")),
-    // FIXME: ("Wopf;", Ncr::new(   [120142u32], "\u{D835}\u{DD4E}")),
-    // FIXME: ("Wscr;", Ncr::new(   [119986u32], "\u{D835}\u{DCB2}")),
-    // FIXME: ("Xfr;", Ncr::new(  [120091u32], "\u{D835}\u{DD1B}")),
-    ("Xi;", Ncr::new([926u32], "\u{039E}")),
-    // FIXME: ("Xopf;", Ncr::new(   [120143u32], "\u{D835}\u{DD4F}")),
-    // FIXME: ("Xscr;", Ncr::new(   [119987u32], "\u{D835}\u{DCB3}")),
-    ("YAcy;", Ncr::new([1071u32], "\u{042F}")),
-    ("YIcy;", Ncr::new([1031u32], "\u{0407}")),
-    ("YUcy;", Ncr::new([1070u32], "\u{042E}")),
-    ("Yacute", Ncr::new([221u32], "\u{00DD}")),
-    ("Yacute;", Ncr::new([221u32], "\u{00DD}")),
-    ("Ycirc;", Ncr::new([374u32], "\u{0176}")),
-    ("Ycy;", Ncr::new([1067u32], "\u{042B}")),
-    // FIXME: ("Yfr;", Ncr::new(  [120092u32], "\u{D835}\u{DD1C}")),
-    // FIXME: ("Yopf;", Ncr::new(   [120144u32], "\u{D835}\u{DD50}")),
-    // FIXME: ("Yscr;", Ncr::new(   [119988u32], "\u{D835}\u{DCB4}")),
-    ("Yuml;", Ncr::new([376u32], "\u{0178}")),
-    ("ZHcy;", Ncr::new([1046u32], "\u{0416}")),
-    ("Zacute;", Ncr::new([377u32], "\u{0179}")),
-    ("Zcaron;", Ncr::new([381u32], "\u{017D}")),
-    ("Zcy;", Ncr::new([1047u32], "\u{0417}")),
-    ("Zdot;", Ncr::new([379u32], "\u{017B}")),
-    ("ZeroWidthSpace;", Ncr::new([8203u32], "\u{200B}")),
-    ("Zeta;", Ncr::new([918u32], "\u{0396}")),
-    ("Zfr;", Ncr::new([8488u32], "\u{2128}")),
-    ("Zopf;", Ncr::new([8484u32], "\u{2124}")),
-    // FIXME: ("Zscr;", Ncr::new(   [119989u32], "\u{D835}\u{DCB5}")),
-    ("aacute", Ncr::new([225u32], "\u{00E1}")),
-    ("aacute;", Ncr::new([225u32], "\u{00E1}")),
-    ("abreve;", Ncr::new([259u32], "\u{0103}")),
-    ("ac;", Ncr::new([8766u32], "\u{223E}")),
-    // FIXME: ("acE;", Ncr::new(  [8766, 819u32], "\u{223E}\u{0333}")),
-    ("acd;", Ncr::new([8767u32], "\u{223F}")),
-    ("acirc", Ncr::new([226u32], "\u{00E2}")),
-    ("acirc;", Ncr::new([226u32], "\u{00E2}")),
-    ("acute", Ncr::new([180u32], "\u{00B4}")),
-    ("acute;", Ncr::new([180u32], "\u{00B4}")),
-    ("acy;", Ncr::new([1072u32], "\u{0430}")),
-    ("aelig", Ncr::new([230u32], "\u{00E6}")),
-    ("aelig;", Ncr::new([230u32], "\u{00E6}")),
-    ("af;", Ncr::new([8289u32], "\u{2061}")),
-    // FIXME: ("afr;", Ncr::new(  [120094u32], "\u{D835}\u{DD1E}")),
-    ("agrave", Ncr::new([224u32], "\u{00E0}")),
-    ("agrave;", Ncr::new([224u32], "\u{00E0}")),
-    ("alefsym;", Ncr::new([8501u32], "\u{2135}")),
-    ("aleph;", Ncr::new([8501u32], "\u{2135}")),
-    ("alpha;", Ncr::new([945u32], "\u{03B1}")),
-    ("amacr;", Ncr::new([257u32], "\u{0101}")),
-    ("amalg;", Ncr::new([10815u32], "\u{2A3F}")),
-    ("amp", Ncr::new([38u32], "\u{0026}")),
-    ("amp;", Ncr::new([38u32], "\u{0026}")),
-    ("and;", Ncr::new([8743u32], "\u{2227}")),
-    ("andand;", Ncr::new([10837u32], "\u{2A55}")),
-    ("andd;", Ncr::new([10844u32], "\u{2A5C}")),
-    ("andslope;", Ncr::new([10840u32], "\u{2A58}")),
-    ("andv;", Ncr::new([10842u32], "\u{2A5A}")),
-    ("ang;", Ncr::new([8736u32], "\u{2220}")),
-    ("ange;", Ncr::new([10660u32], "\u{29A4}")),
-    ("angle;", Ncr::new([8736u32], "\u{2220}")),
-    ("angmsd;", Ncr::new([8737u32], "\u{2221}")),
-    ("angmsdaa;", Ncr::new([10664u32], "\u{29A8}")),
-    ("angmsdab;", Ncr::new([10665u32], "\u{29A9}")),
-    ("angmsdac;", Ncr::new([10666u32], "\u{29AA}")),
-    ("angmsdad;", Ncr::new([10667u32], "\u{29AB}")),
-    ("angmsdae;", Ncr::new([10668u32], "\u{29AC}")),
-    ("angmsdaf;", Ncr::new([10669u32], "\u{29AD}")),
-    ("angmsdag;", Ncr::new([10670u32], "\u{29AE}")),
-    ("angmsdah;", Ncr::new([10671u32], "\u{29AF}")),
-    ("angrt;", Ncr::new([8735u32], "\u{221F}")),
-    ("angrtvb;", Ncr::new([8894u32], "\u{22BE}")),
-    ("angrtvbd;", Ncr::new([10653u32], "\u{299D}")),
-    ("angsph;", Ncr::new([8738u32], "\u{2222}")),
-    ("angst;", Ncr::new([197u32], "\u{00C5}")),
-    ("angzarr;", Ncr::new([9084u32], "\u{237C}")),
-    ("aogon;", Ncr::new([261u32], "\u{0105}")),
-    // FIXME: ("aopf;", Ncr::new(   [120146u32], "\u{D835}\u{DD52}")),
-    ("ap;", Ncr::new([8776u32], "\u{2248}")),
-    ("apE;", Ncr::new([10864u32], "\u{2A70}")),
-    ("apacir;", Ncr::new([10863u32], "\u{2A6F}")),
-    ("ape;", Ncr::new([8778u32], "\u{224A}")),
-    ("apid;", Ncr::new([8779u32], "\u{224B}")),
-    ("apos;", Ncr::new([39u32], "\u{0027}")),
-    ("approx;", Ncr::new([8776u32], "\u{2248}")),
-    ("approxeq;", Ncr::new([8778u32], "\u{224A}")),
-    ("aring", Ncr::new([229u32], "\u{00E5}")),
-    ("aring;", Ncr::new([229u32], "\u{00E5}")),
-    // FIXME: ("ascr;", Ncr::new(   [119990u32], "\u{D835}\u{DCB6}")),
-    ("ast;", Ncr::new([42u32], "\u{002A}")),
-    ("asymp;", Ncr::new([8776u32], "\u{2248}")),
-    ("asympeq;", Ncr::new([8781u32], "\u{224D}")),
-    ("atilde", Ncr::new([227u32], "\u{00E3}")),
-    ("atilde;", Ncr::new([227u32], "\u{00E3}")),
-    ("auml", Ncr::new([228u32], "\u{00E4}")),
-    ("auml;", Ncr::new([228u32], "\u{00E4}")),
-    ("awconint;", Ncr::new([8755u32], "\u{2233}")),
-    ("awint;", Ncr::new([10769u32], "\u{2A11}")),
-    ("bNot;", Ncr::new([10989u32], "\u{2AED}")),
-    ("backcong;", Ncr::new([8780u32], "\u{224C}")),
-    ("backepsilon;", Ncr::new([1014u32], "\u{03F6}")),
-    ("backprime;", Ncr::new([8245u32], "\u{2035}")),
-    ("backsim;", Ncr::new([8765u32], "\u{223D}")),
-    ("backsimeq;", Ncr::new([8909u32], "\u{22CD}")),
-    ("barvee;", Ncr::new([8893u32], "\u{22BD}")),
-    ("barwed;", Ncr::new([8965u32], "\u{2305}")),
-    ("barwedge;", Ncr::new([8965u32], "\u{2305}")),
-    ("bbrk;", Ncr::new([9141u32], "\u{23B5}")),
-    ("bbrktbrk;", Ncr::new([9142u32], "\u{23B6}")),
-    ("bcong;", Ncr::new([8780u32], "\u{224C}")),
-    ("bcy;", Ncr::new([1073u32], "\u{0431}")),
-    ("bdquo;", Ncr::new([8222u32], "\u{201E}")),
-    ("becaus;", Ncr::new([8757u32], "\u{2235}")),
-    ("because;", Ncr::new([8757u32], "\u{2235}")),
-    ("bemptyv;", Ncr::new([10672u32], "\u{29B0}")),
-    ("bepsi;", Ncr::new([1014u32], "\u{03F6}")),
-    ("bernou;", Ncr::new([8492u32], "\u{212C}")),
-    ("beta;", Ncr::new([946u32], "\u{03B2}")),
-    ("beth;", Ncr::new([8502u32], "\u{2136}")),
-    ("between;", Ncr::new([8812u32], "\u{226C}")),
-    // FIXME: ("bfr;", Ncr::new(  [120095u32], "\u{D835}\u{DD1F}")),
-    ("bigcap;", Ncr::new([8898u32], "\u{22C2}")),
-    ("bigcirc;", Ncr::new([9711u32], "\u{25EF}")),
-    ("bigcup;", Ncr::new([8899u32], "\u{22C3}")),
-    ("bigodot;", Ncr::new([10752u32], "\u{2A00}")),
-    ("bigoplus;", Ncr::new([10753u32], "\u{2A01}")),
-    ("bigotimes;", Ncr::new([10754u32], "\u{2A02}")),
-    ("bigsqcup;", Ncr::new([10758u32], "\u{2A06}")),
-    ("bigstar;", Ncr::new([9733u32], "\u{2605}")),
-    ("bigtriangledown;", Ncr::new([9661u32], "\u{25BD}")),
-    ("bigtriangleup;", Ncr::new([9651u32], "\u{25B3}")),
-    ("biguplus;", Ncr::new([10756u32], "\u{2A04}")),
-    ("bigvee;", Ncr::new([8897u32], "\u{22C1}")),
-    ("bigwedge;", Ncr::new([8896u32], "\u{22C0}")),
-    ("bkarow;", Ncr::new([10509u32], "\u{290D}")),
-    ("blacklozenge;", Ncr::new([10731u32], "\u{29EB}")),
-    ("blacksquare;", Ncr::new([9642u32], "\u{25AA}")),
-    ("blacktriangle;", Ncr::new([9652u32], "\u{25B4}")),
-    ("blacktriangledown;", Ncr::new([9662u32], "\u{25BE}")),
-    ("blacktriangleleft;", Ncr::new([9666u32], "\u{25C2}")),
-    ("blacktriangleright;", Ncr::new([9656u32], "\u{25B8}")),
-    ("blank;", Ncr::new([9251u32], "\u{2423}")),
-    ("blk12;", Ncr::new([9618u32], "\u{2592}")),
-    ("blk14;", Ncr::new([9617u32], "\u{2591}")),
-    ("blk34;", Ncr::new([9619u32], "\u{2593}")),
-    ("block;", Ncr::new([9608u32], "\u{2588}")),
-    // FIXME: ("bne;", Ncr::new(  [61, 8421u32], "\u{003D}\u{20E5}")),
-    // FIXME: ("bnequiv;", Ncr::new(  [8801, 8421u32], "\u{2261}\u{20E5}")),
-    ("bnot;", Ncr::new([8976u32], "\u{2310}")),
-    // FIXME: ("bopf;", Ncr::new(   [120147u32], "\u{D835}\u{DD53}")),
-    ("bot;", Ncr::new([8869u32], "\u{22A5}")),
-    ("bottom;", Ncr::new([8869u32], "\u{22A5}")),
-    ("bowtie;", Ncr::new([8904u32], "\u{22C8}")),
-    ("boxDL;", Ncr::new([9559u32], "\u{2557}")),
-    ("boxDR;", Ncr::new([9556u32], "\u{2554}")),
-    ("boxDl;", Ncr::new([9558u32], "\u{2556}")),
-    ("boxDr;", Ncr::new([9555u32], "\u{2553}")),
-    ("boxH;", Ncr::new([9552u32], "\u{2550}")),
-    ("boxHD;", Ncr::new([9574u32], "\u{2566}")),
-    ("boxHU;", Ncr::new([9577u32], "\u{2569}")),
-    ("boxHd;", Ncr::new([9572u32], "\u{2564}")),
-    ("boxHu;", Ncr::new([9575u32], "\u{2567}")),
-    ("boxUL;", Ncr::new([9565u32], "\u{255D}")),
-    ("boxUR;", Ncr::new([9562u32], "\u{255A}")),
-    ("boxUl;", Ncr::new([9564u32], "\u{255C}")),
-    ("boxUr;", Ncr::new([9561u32], "\u{2559}")),
-    ("boxV;", Ncr::new([9553u32], "\u{2551}")),
-    ("boxVH;", Ncr::new([9580u32], "\u{256C}")),
-    ("boxVL;", Ncr::new([9571u32], "\u{2563}")),
-    ("boxVR;", Ncr::new([9568u32], "\u{2560}")),
-    ("boxVh;", Ncr::new([9579u32], "\u{256B}")),
-    ("boxVl;", Ncr::new([9570u32], "\u{2562}")),
-    ("boxVr;", Ncr::new([9567u32], "\u{255F}")),
-    ("boxbox;", Ncr::new([10697u32], "\u{29C9}")),
-    ("boxdL;", Ncr::new([9557u32], "\u{2555}")),
-    ("boxdR;", Ncr::new([9554u32], "\u{2552}")),
-    ("boxdl;", Ncr::new([9488u32], "\u{2510}")),
-    ("boxdr;", Ncr::new([9484u32], "\u{250C}")),
-    ("boxh;", Ncr::new([9472u32], "\u{2500}")),
-    ("boxhD;", Ncr::new([9573u32], "\u{2565}")),
-    ("boxhU;", Ncr::new([9576u32], "\u{2568}")),
-    ("boxhd;", Ncr::new([9516u32], "\u{252C}")),
-    ("boxhu;", Ncr::new([9524u32], "\u{2534}")),
-    ("boxminus;", Ncr::new([8863u32], "\u{229F}")),
-    ("boxplus;", Ncr::new([8862u32], "\u{229E}")),
-    ("boxtimes;", Ncr::new([8864u32], "\u{22A0}")),
-    ("boxuL;", Ncr::new([9563u32], "\u{255B}")),
-    ("boxuR;", Ncr::new([9560u32], "\u{2558}")),
-    ("boxul;", Ncr::new([9496u32], "\u{2518}")),
-    ("boxur;", Ncr::new([9492u32], "\u{2514}")),
-    ("boxv;", Ncr::new([9474u32], "\u{2502}")),
-    ("boxvH;", Ncr::new([9578u32], "\u{256A}")),
-    ("boxvL;", Ncr::new([9569u32], "\u{2561}")),
-    ("boxvR;", Ncr::new([9566u32], "\u{255E}")),
-    ("boxvh;", Ncr::new([9532u32], "\u{253C}")),
-    ("boxvl;", Ncr::new([9508u32], "\u{2524}")),
-    ("boxvr;", Ncr::new([9500u32], "\u{251C}")),
-    ("bprime;", Ncr::new([8245u32], "\u{2035}")),
-    ("breve;", Ncr::new([728u32], "\u{02D8}")),
-    ("brvbar", Ncr::new([166u32], "\u{00A6}")),
-    ("brvbar;", Ncr::new([166u32], "\u{00A6}")),
-    // FIXME: ("bscr;", Ncr::new(   [119991u32], "\u{D835}\u{DCB7}")),
-    ("bsemi;", Ncr::new([8271u32], "\u{204F}")),
-    ("bsim;", Ncr::new([8765u32], "\u{223D}")),
-    ("bsime;", Ncr::new([8909u32], "\u{22CD}")),
-    ("bsol;", Ncr::new([92u32], "\u{005C}")),
-    ("bsolb;", Ncr::new([10693u32], "\u{29C5}")),
-    ("bsolhsub;", Ncr::new([10184u32], "\u{27C8}")),
-    ("bull;", Ncr::new([8226u32], "\u{2022}")),
-    ("bullet;", Ncr::new([8226u32], "\u{2022}")),
-    ("bump;", Ncr::new([8782u32], "\u{224E}")),
-    ("bumpE;", Ncr::new([10926u32], "\u{2AAE}")),
-    ("bumpe;", Ncr::new([8783u32], "\u{224F}")),
-    ("bumpeq;", Ncr::new([8783u32], "\u{224F}")),
-    ("cacute;", Ncr::new([263u32], "\u{0107}")),
-    ("cap;", Ncr::new([8745u32], "\u{2229}")),
-    ("capand;", Ncr::new([10820u32], "\u{2A44}")),
-    ("capbrcup;", Ncr::new([10825u32], "\u{2A49}")),
-    ("capcap;", Ncr::new([10827u32], "\u{2A4B}")),
-    ("capcup;", Ncr::new([10823u32], "\u{2A47}")),
-    ("capdot;", Ncr::new([10816u32], "\u{2A40}")),
-    // FIXME: ("caps;", Ncr::new(   [8745, 65024u32], "\u{2229}\u{FE00}")),
-    ("caret;", Ncr::new([8257u32], "\u{2041}")),
-    ("caron;", Ncr::new([711u32], "\u{02C7}")),
-    ("ccaps;", Ncr::new([10829u32], "\u{2A4D}")),
-    ("ccaron;", Ncr::new([269u32], "\u{010D}")),
-    ("ccedil", Ncr::new([231u32], "\u{00E7}")),
-    ("ccedil;", Ncr::new([231u32], "\u{00E7}")),
-    ("ccirc;", Ncr::new([265u32], "\u{0109}")),
-    ("ccups;", Ncr::new([10828u32], "\u{2A4C}")),
-    ("ccupssm;", Ncr::new([10832u32], "\u{2A50}")),
-    ("cdot;", Ncr::new([267u32], "\u{010B}")),
-    ("cedil", Ncr::new([184u32], "\u{00B8}")),
-    ("cedil;", Ncr::new([184u32], "\u{00B8}")),
-    ("cemptyv;", Ncr::new([10674u32], "\u{29B2}")),
-    ("cent", Ncr::new([162u32], "\u{00A2}")),
-    ("cent;", Ncr::new([162u32], "\u{00A2}")),
-    ("centerdot;", Ncr::new([183u32], "\u{00B7}")),
-    // FIXME: ("cfr;", Ncr::new(  [120096u32], "\u{D835}\u{DD20}")),
-    ("chcy;", Ncr::new([1095u32], "\u{0447}")),
-    ("check;", Ncr::new([10003u32], "\u{2713}")),
-    ("checkmark;", Ncr::new([10003u32], "\u{2713}")),
-    ("chi;", Ncr::new([967u32], "\u{03C7}")),
-    ("cir;", Ncr::new([9675u32], "\u{25CB}")),
-    ("cirE;", Ncr::new([10691u32], "\u{29C3}")),
-    ("circ;", Ncr::new([710u32], "\u{02C6}")),
-    ("circeq;", Ncr::new([8791u32], "\u{2257}")),
-    ("circlearrowleft;", Ncr::new([8634u32], "\u{21BA}")),
-    ("circlearrowright;", Ncr::new([8635u32], "\u{21BB}")),
-    ("circledR;", Ncr::new([174u32], "\u{00AE}")),
-    ("circledS;", Ncr::new([9416u32], "\u{24C8}")),
-    ("circledast;", Ncr::new([8859u32], "\u{229B}")),
-    ("circledcirc;", Ncr::new([8858u32], "\u{229A}")),
-    ("circleddash;", Ncr::new([8861u32], "\u{229D}")),
-    ("cire;", Ncr::new([8791u32], "\u{2257}")),
-    ("cirfnint;", Ncr::new([10768u32], "\u{2A10}")),
-    ("cirmid;", Ncr::new([10991u32], "\u{2AEF}")),
-    ("cirscir;", Ncr::new([10690u32], "\u{29C2}")),
-    ("clubs;", Ncr::new([9827u32], "\u{2663}")),
-    ("clubsuit;", Ncr::new([9827u32], "\u{2663}")),
-    ("colon;", Ncr::new([58u32], "\u{003A}")),
-    ("colone;", Ncr::new([8788u32], "\u{2254}")),
-    ("coloneq;", Ncr::new([8788u32], "\u{2254}")),
-    ("comma;", Ncr::new([44u32], "\u{002C}")),
-    ("commat;", Ncr::new([64u32], "\u{0040}")),
-    ("comp;", Ncr::new([8705u32], "\u{2201}")),
-    ("compfn;", Ncr::new([8728u32], "\u{2218}")),
-    ("complement;", Ncr::new([8705u32], "\u{2201}")),
-    ("complexes;", Ncr::new([8450u32], "\u{2102}")),
-    ("cong;", Ncr::new([8773u32], "\u{2245}")),
-    ("congdot;", Ncr::new([10861u32], "\u{2A6D}")),
-    ("conint;", Ncr::new([8750u32], "\u{222E}")),
-    // FIXME: ("copf;", Ncr::new(   [120148u32], "\u{D835}\u{DD54}")),
-    ("coprod;", Ncr::new([8720u32], "\u{2210}")),
-    ("copy", Ncr::new([169u32], "\u{00A9}")),
-    ("copy;", Ncr::new([169u32], "\u{00A9}")),
-    ("copysr;", Ncr::new([8471u32], "\u{2117}")),
-    ("crarr;", Ncr::new([8629u32], "\u{21B5}")),
-    ("cross;", Ncr::new([10007u32], "\u{2717}")),
-    // FIXME: ("cscr;", Ncr::new(   [119992u32], "\u{D835}\u{DCB8}")),
-    ("csub;", Ncr::new([10959u32], "\u{2ACF}")),
-    ("csube;", Ncr::new([10961u32], "\u{2AD1}")),
-    ("csup;", Ncr::new([10960u32], "\u{2AD0}")),
-    ("csupe;", Ncr::new([10962u32], "\u{2AD2}")),
-    ("ctdot;", Ncr::new([8943u32], "\u{22EF}")),
-    ("cudarrl;", Ncr::new([10552u32], "\u{2938}")),
-    ("cudarrr;", Ncr::new([10549u32], "\u{2935}")),
-    ("cuepr;", Ncr::new([8926u32], "\u{22DE}")),
-    ("cuesc;", Ncr::new([8927u32], "\u{22DF}")),
-    ("cularr;", Ncr::new([8630u32], "\u{21B6}")),
-    ("cularrp;", Ncr::new([10557u32], "\u{293D}")),
-    ("cup;", Ncr::new([8746u32], "\u{222A}")),
-    ("cupbrcap;", Ncr::new([10824u32], "\u{2A48}")),
-    ("cupcap;", Ncr::new([10822u32], "\u{2A46}")),
-    ("cupcup;", Ncr::new([10826u32], "\u{2A4A}")),
-    ("cupdot;", Ncr::new([8845u32], "\u{228D}")),
-    ("cupor;", Ncr::new([10821u32], "\u{2A45}")),
-    // FIXME: ("cups;", Ncr::new(   [8746, 65024u32], "\u{222A}\u{FE00}")),
-    ("curarr;", Ncr::new([8631u32], "\u{21B7}")),
-    ("curarrm;", Ncr::new([10556u32], "\u{293C}")),
-    ("curlyeqprec;", Ncr::new([8926u32], "\u{22DE}")),
-    ("curlyeqsucc;", Ncr::new([8927u32], "\u{22DF}")),
-    ("curlyvee;", Ncr::new([8910u32], "\u{22CE}")),
-    ("curlywedge;", Ncr::new([8911u32], "\u{22CF}")),
-    ("curren", Ncr::new([164u32], "\u{00A4}")),
-    ("curren;", Ncr::new([164u32], "\u{00A4}")),
-    ("curvearrowleft;", Ncr::new([8630u32], "\u{21B6}")),
-    ("curvearrowright;", Ncr::new([8631u32], "\u{21B7}")),
-    ("cuvee;", Ncr::new([8910u32], "\u{22CE}")),
-    ("cuwed;", Ncr::new([8911u32], "\u{22CF}")),
-    ("cwconint;", Ncr::new([8754u32], "\u{2232}")),
-    ("cwint;", Ncr::new([8753u32], "\u{2231}")),
-    ("cylcty;", Ncr::new([9005u32], "\u{232D}")),
-    ("dArr;", Ncr::new([8659u32], "\u{21D3}")),
-    ("dHar;", Ncr::new([10597u32], "\u{2965}")),
-    ("dagger;", Ncr::new([8224u32], "\u{2020}")),
-    ("daleth;", Ncr::new([8504u32], "\u{2138}")),
-    ("darr;", Ncr::new([8595u32], "\u{2193}")),
-    ("dash;", Ncr::new([8208u32], "\u{2010}")),
-    ("dashv;", Ncr::new([8867u32], "\u{22A3}")),
-    ("dbkarow;", Ncr::new([10511u32], "\u{290F}")),
-    ("dblac;", Ncr::new([733u32], "\u{02DD}")),
-    ("dcaron;", Ncr::new([271u32], "\u{010F}")),
-    ("dcy;", Ncr::new([1076u32], "\u{0434}")),
-    ("dd;", Ncr::new([8518u32], "\u{2146}")),
-    ("ddagger;", Ncr::new([8225u32], "\u{2021}")),
-    ("ddarr;", Ncr::new([8650u32], "\u{21CA}")),
-    ("ddotseq;", Ncr::new([10871u32], "\u{2A77}")),
-    ("deg", Ncr::new([176u32], "\u{00B0}")),
-    ("deg;", Ncr::new([176u32], "\u{00B0}")),
-    ("delta;", Ncr::new([948u32], "\u{03B4}")),
-    ("demptyv;", Ncr::new([10673u32], "\u{29B1}")),
-    ("dfisht;", Ncr::new([10623u32], "\u{297F}")),
-    // FIXME: ("dfr;", Ncr::new(  [120097u32], "\u{D835}\u{DD21}")),
-    ("dharl;", Ncr::new([8643u32], "\u{21C3}")),
-    ("dharr;", Ncr::new([8642u32], "\u{21C2}")),
-    ("diam;", Ncr::new([8900u32], "\u{22C4}")),
-    ("diamond;", Ncr::new([8900u32], "\u{22C4}")),
-    ("diamondsuit;", Ncr::new([9830u32], "\u{2666}")),
-    ("diams;", Ncr::new([9830u32], "\u{2666}")),
-    ("die;", Ncr::new([168u32], "\u{00A8}")),
-    ("digamma;", Ncr::new([989u32], "\u{03DD}")),
-    ("disin;", Ncr::new([8946u32], "\u{22F2}")),
-    ("div;", Ncr::new([247u32], "\u{00F7}")),
-    ("divide", Ncr::new([247u32], "\u{00F7}")),
-    ("divide;", Ncr::new([247u32], "\u{00F7}")),
-    ("divideontimes;", Ncr::new([8903u32], "\u{22C7}")),
-    ("divonx;", Ncr::new([8903u32], "\u{22C7}")),
-    ("djcy;", Ncr::new([1106u32], "\u{0452}")),
-    ("dlcorn;", Ncr::new([8990u32], "\u{231E}")),
-    ("dlcrop;", Ncr::new([8973u32], "\u{230D}")),
-    ("dollar;", Ncr::new([36u32], "\u{0024}")),
-    // FIXME: ("dopf;", Ncr::new(   [120149u32], "\u{D835}\u{DD55}")),
-    ("dot;", Ncr::new([729u32], "\u{02D9}")),
-    ("doteq;", Ncr::new([8784u32], "\u{2250}")),
-    ("doteqdot;", Ncr::new([8785u32], "\u{2251}")),
-    ("dotminus;", Ncr::new([8760u32], "\u{2238}")),
-    ("dotplus;", Ncr::new([8724u32], "\u{2214}")),
-    ("dotsquare;", Ncr::new([8865u32], "\u{22A1}")),
-    ("doublebarwedge;", Ncr::new([8966u32], "\u{2306}")),
-    ("downarrow;", Ncr::new([8595u32], "\u{2193}")),
-    ("downdownarrows;", Ncr::new([8650u32], "\u{21CA}")),
-    ("downharpoonleft;", Ncr::new([8643u32], "\u{21C3}")),
-    ("downharpoonright;", Ncr::new([8642u32], "\u{21C2}")),
-    ("drbkarow;", Ncr::new([10512u32], "\u{2910}")),
-    ("drcorn;", Ncr::new([8991u32], "\u{231F}")),
-    ("drcrop;", Ncr::new([8972u32], "\u{230C}")),
-    // FIXME: ("dscr;", Ncr::new(   [119993u32], "\u{D835}\u{DCB9}")),
-    ("dscy;", Ncr::new([1109u32], "\u{0455}")),
-    ("dsol;", Ncr::new([10742u32], "\u{29F6}")),
-    ("dstrok;", Ncr::new([273u32], "\u{0111}")),
-    ("dtdot;", Ncr::new([8945u32], "\u{22F1}")),
-    ("dtri;", Ncr::new([9663u32], "\u{25BF}")),
-    ("dtrif;", Ncr::new([9662u32], "\u{25BE}")),
-    ("duarr;", Ncr::new([8693u32], "\u{21F5}")),
-    ("duhar;", Ncr::new([10607u32], "\u{296F}")),
-    ("dwangle;", Ncr::new([10662u32], "\u{29A6}")),
-    ("dzcy;", Ncr::new([1119u32], "\u{045F}")),
-    ("dzigrarr;", Ncr::new([10239u32], "\u{27FF}")),
-    ("eDDot;", Ncr::new([10871u32], "\u{2A77}")),
-    ("eDot;", Ncr::new([8785u32], "\u{2251}")),
-    ("eacute", Ncr::new([233u32], "\u{00E9}")),
-    ("eacute;", Ncr::new([233u32], "\u{00E9}")),
-    ("easter;", Ncr::new([10862u32], "\u{2A6E}")),
-    ("ecaron;", Ncr::new([283u32], "\u{011B}")),
-    ("ecir;", Ncr::new([8790u32], "\u{2256}")),
-    ("ecirc", Ncr::new([234u32], "\u{00EA}")),
-    ("ecirc;", Ncr::new([234u32], "\u{00EA}")),
-    ("ecolon;", Ncr::new([8789u32], "\u{2255}")),
-    ("ecy;", Ncr::new([1101u32], "\u{044D}")),
-    ("edot;", Ncr::new([279u32], "\u{0117}")),
-    ("ee;", Ncr::new([8519u32], "\u{2147}")),
-    ("efDot;", Ncr::new([8786u32], "\u{2252}")),
-    // FIXME: ("efr;", Ncr::new(  [120098u32], "\u{D835}\u{DD22}")),
-    ("eg;", Ncr::new([10906u32], "\u{2A9A}")),
-    ("egrave", Ncr::new([232u32], "\u{00E8}")),
-    ("egrave;", Ncr::new([232u32], "\u{00E8}")),
-    ("egs;", Ncr::new([10902u32], "\u{2A96}")),
-    ("egsdot;", Ncr::new([10904u32], "\u{2A98}")),
-    ("el;", Ncr::new([10905u32], "\u{2A99}")),
-    ("elinters;", Ncr::new([9191u32], "\u{23E7}")),
-    ("ell;", Ncr::new([8467u32], "\u{2113}")),
-    ("els;", Ncr::new([10901u32], "\u{2A95}")),
-    ("elsdot;", Ncr::new([10903u32], "\u{2A97}")),
-    ("emacr;", Ncr::new([275u32], "\u{0113}")),
-    ("empty;", Ncr::new([8709u32], "\u{2205}")),
-    ("emptyset;", Ncr::new([8709u32], "\u{2205}")),
-    ("emptyv;", Ncr::new([8709u32], "\u{2205}")),
-    ("emsp13;", Ncr::new([8196u32], "\u{2004}")),
-    ("emsp14;", Ncr::new([8197u32], "\u{2005}")),
-    ("emsp;", Ncr::new([8195u32], "\u{2003}")),
-    ("eng;", Ncr::new([331u32], "\u{014B}")),
-    ("ensp;", Ncr::new([8194u32], "\u{2002}")),
-    ("eogon;", Ncr::new([281u32], "\u{0119}")),
-    // FIXME: ("eopf;", Ncr::new(   [120150u32], "\u{D835}\u{DD56}")),
-    ("epar;", Ncr::new([8917u32], "\u{22D5}")),
-    ("eparsl;", Ncr::new([10723u32], "\u{29E3}")),
-    ("eplus;", Ncr::new([10865u32], "\u{2A71}")),
-    ("epsi;", Ncr::new([949u32], "\u{03B5}")),
-    ("epsilon;", Ncr::new([949u32], "\u{03B5}")),
-    ("epsiv;", Ncr::new([1013u32], "\u{03F5}")),
-    ("eqcirc;", Ncr::new([8790u32], "\u{2256}")),
-    ("eqcolon;", Ncr::new([8789u32], "\u{2255}")),
-    ("eqsim;", Ncr::new([8770u32], "\u{2242}")),
-    ("eqslantgtr;", Ncr::new([10902u32], "\u{2A96}")),
-    ("eqslantless;", Ncr::new([10901u32], "\u{2A95}")),
-    ("equals;", Ncr::new([61u32], "\u{003D}")),
-    ("equest;", Ncr::new([8799u32], "\u{225F}")),
-    ("equiv;", Ncr::new([8801u32], "\u{2261}")),
-    ("equivDD;", Ncr::new([10872u32], "\u{2A78}")),
-    ("eqvparsl;", Ncr::new([10725u32], "\u{29E5}")),
-    ("erDot;", Ncr::new([8787u32], "\u{2253}")),
-    ("erarr;", Ncr::new([10609u32], "\u{2971}")),
-    ("escr;", Ncr::new([8495u32], "\u{212F}")),
-    ("esdot;", Ncr::new([8784u32], "\u{2250}")),
-    ("esim;", Ncr::new([8770u32], "\u{2242}")),
-    ("eta;", Ncr::new([951u32], "\u{03B7}")),
-    ("eth", Ncr::new([240u32], "\u{00F0}")),
-    ("eth;", Ncr::new([240u32], "\u{00F0}")),
-    ("euml", Ncr::new([235u32], "\u{00EB}")),
-    ("euml;", Ncr::new([235u32], "\u{00EB}")),
-    ("euro;", Ncr::new([8364u32], "\u{20AC}")),
-    ("excl;", Ncr::new([33u32], "\u{0021}")),
-    ("exist;", Ncr::new([8707u32], "\u{2203}")),
-    ("expectation;", Ncr::new([8496u32], "\u{2130}")),
-    ("exponentiale;", Ncr::new([8519u32], "\u{2147}")),
-    ("fallingdotseq;", Ncr::new([8786u32], "\u{2252}")),
-    ("fcy;", Ncr::new([1092u32], "\u{0444}")),
-    ("female;", Ncr::new([9792u32], "\u{2640}")),
-    ("ffilig;", Ncr::new([64259u32], "\u{FB03}")),
-    ("fflig;", Ncr::new([64256u32], "\u{FB00}")),
-    ("ffllig;", Ncr::new([64260u32], "\u{FB04}")),
-    // FIXME: ("ffr;", Ncr::new(  [120099u32], "\u{D835}\u{DD23}")),
-    ("filig;", Ncr::new([64257u32], "\u{FB01}")),
-    // FIXME: ("fjlig;", Ncr::new(  [102, 106u32], "\u{0066}\u{006A}")),
-    ("flat;", Ncr::new([9837u32], "\u{266D}")),
-    ("fllig;", Ncr::new([64258u32], "\u{FB02}")),
-    ("fltns;", Ncr::new([9649u32], "\u{25B1}")),
-    ("fnof;", Ncr::new([402u32], "\u{0192}")),
-    // FIXME: ("fopf;", Ncr::new(   [120151u32], "\u{D835}\u{DD57}")),
-    ("forall;", Ncr::new([8704u32], "\u{2200}")),
-    ("fork;", Ncr::new([8916u32], "\u{22D4}")),
-    ("forkv;", Ncr::new([10969u32], "\u{2AD9}")),
-    ("fpartint;", Ncr::new([10765u32], "\u{2A0D}")),
-    ("frac12", Ncr::new([189u32], "\u{00BD}")),
-    ("frac12;", Ncr::new([189u32], "\u{00BD}")),
-    ("frac13;", Ncr::new([8531u32], "\u{2153}")),
-    ("frac14", Ncr::new([188u32], "\u{00BC}")),
-    ("frac14;", Ncr::new([188u32], "\u{00BC}")),
-    ("frac15;", Ncr::new([8533u32], "\u{2155}")),
-    ("frac16;", Ncr::new([8537u32], "\u{2159}")),
-    ("frac18;", Ncr::new([8539u32], "\u{215B}")),
-    ("frac23;", Ncr::new([8532u32], "\u{2154}")),
-    ("frac25;", Ncr::new([8534u32], "\u{2156}")),
-    ("frac34", Ncr::new([190u32], "\u{00BE}")),
-    ("frac34;", Ncr::new([190u32], "\u{00BE}")),
-    ("frac35;", Ncr::new([8535u32], "\u{2157}")),
-    ("frac38;", Ncr::new([8540u32], "\u{215C}")),
-    ("frac45;", Ncr::new([8536u32], "\u{2158}")),
-    ("frac56;", Ncr::new([8538u32], "\u{215A}")),
-    ("frac58;", Ncr::new([8541u32], "\u{215D}")),
-    ("frac78;", Ncr::new([8542u32], "\u{215E}")),
-    ("frasl;", Ncr::new([8260u32], "\u{2044}")),
-    ("frown;", Ncr::new([8994u32], "\u{2322}")),
-    // FIXME: ("fscr;", Ncr::new(   [119995u32], "\u{D835}\u{DCBB}")),
-    ("gE;", Ncr::new([8807u32], "\u{2267}")),
-    ("gEl;", Ncr::new([10892u32], "\u{2A8C}")),
-    ("gacute;", Ncr::new([501u32], "\u{01F5}")),
-    ("gamma;", Ncr::new([947u32], "\u{03B3}")),
-    ("gammad;", Ncr::new([989u32], "\u{03DD}")),
-    ("gap;", Ncr::new([10886u32], "\u{2A86}")),
-    ("gbreve;", Ncr::new([287u32], "\u{011F}")),
-    ("gcirc;", Ncr::new([285u32], "\u{011D}")),
-    ("gcy;", Ncr::new([1075u32], "\u{0433}")),
-    ("gdot;", Ncr::new([289u32], "\u{0121}")),
-    ("ge;", Ncr::new([8805u32], "\u{2265}")),
-    ("gel;", Ncr::new([8923u32], "\u{22DB}")),
-    ("geq;", Ncr::new([8805u32], "\u{2265}")),
-    ("geqq;", Ncr::new([8807u32], "\u{2267}")),
-    ("geqslant;", Ncr::new([10878u32], "\u{2A7E}")),
-    ("ges;", Ncr::new([10878u32], "\u{2A7E}")),
-    ("gescc;", Ncr::new([10921u32], "\u{2AA9}")),
-    ("gesdot;", Ncr::new([10880u32], "\u{2A80}")),
-    ("gesdoto;", Ncr::new([10882u32], "\u{2A82}")),
-    ("gesdotol;", Ncr::new([10884u32], "\u{2A84}")),
-    // FIXME: ("gesl;", Ncr::new(   [8923, 65024u32], "\u{22DB}\u{FE00}")),
-    ("gesles;", Ncr::new([10900u32], "\u{2A94}")),
-    // FIXME: ("gfr;", Ncr::new(  [120100u32], "\u{D835}\u{DD24}")),
-    ("gg;", Ncr::new([8811u32], "\u{226B}")),
-    ("ggg;", Ncr::new([8921u32], "\u{22D9}")),
-    ("gimel;", Ncr::new([8503u32], "\u{2137}")),
-    ("gjcy;", Ncr::new([1107u32], "\u{0453}")),
-    ("gl;", Ncr::new([8823u32], "\u{2277}")),
-    ("glE;", Ncr::new([10898u32], "\u{2A92}")),
-    ("gla;", Ncr::new([10917u32], "\u{2AA5}")),
-    ("glj;", Ncr::new([10916u32], "\u{2AA4}")),
-    ("gnE;", Ncr::new([8809u32], "\u{2269}")),
-    ("gnap;", Ncr::new([10890u32], "\u{2A8A}")),
-    ("gnapprox;", Ncr::new([10890u32], "\u{2A8A}")),
-    ("gne;", Ncr::new([10888u32], "\u{2A88}")),
-    ("gneq;", Ncr::new([10888u32], "\u{2A88}")),
-    ("gneqq;", Ncr::new([8809u32], "\u{2269}")),
-    ("gnsim;", Ncr::new([8935u32], "\u{22E7}")),
-    // FIXME: ("gopf;", Ncr::new(   [120152u32], "\u{D835}\u{DD58}")),
-    ("grave;", Ncr::new([96u32], "\u{0060}")),
-    ("gscr;", Ncr::new([8458u32], "\u{210A}")),
-    ("gsim;", Ncr::new([8819u32], "\u{2273}")),
-    ("gsime;", Ncr::new([10894u32], "\u{2A8E}")),
-    ("gsiml;", Ncr::new([10896u32], "\u{2A90}")),
-    ("gt", Ncr::new([62u32], "\u{003E}")),
-    ("gt;", Ncr::new([62u32], "\u{003E}")),
-    ("gtcc;", Ncr::new([10919u32], "\u{2AA7}")),
-    ("gtcir;", Ncr::new([10874u32], "\u{2A7A}")),
-    ("gtdot;", Ncr::new([8919u32], "\u{22D7}")),
-    ("gtlPar;", Ncr::new([10645u32], "\u{2995}")),
-    ("gtquest;", Ncr::new([10876u32], "\u{2A7C}")),
-    ("gtrapprox;", Ncr::new([10886u32], "\u{2A86}")),
-    ("gtrarr;", Ncr::new([10616u32], "\u{2978}")),
-    ("gtrdot;", Ncr::new([8919u32], "\u{22D7}")),
-    ("gtreqless;", Ncr::new([8923u32], "\u{22DB}")),
-    ("gtreqqless;", Ncr::new([10892u32], "\u{2A8C}")),
-    ("gtrless;", Ncr::new([8823u32], "\u{2277}")),
-    ("gtrsim;", Ncr::new([8819u32], "\u{2273}")),
-    // FIXME: ("gvertneqq;", Ncr::new(  [8809, 65024u32], "\u{2269}\u{FE00}")),
-    // FIXME: ("gvnE;", Ncr::new(   [8809, 65024u32], "\u{2269}\u{FE00}")),
-    ("hArr;", Ncr::new([8660u32], "\u{21D4}")),
-    ("hairsp;", Ncr::new([8202u32], "\u{200A}")),
-    ("half;", Ncr::new([189u32], "\u{00BD}")),
-    ("hamilt;", Ncr::new([8459u32], "\u{210B}")),
-    ("hardcy;", Ncr::new([1098u32], "\u{044A}")),
-    ("harr;", Ncr::new([8596u32], "\u{2194}")),
-    ("harrcir;", Ncr::new([10568u32], "\u{2948}")),
-    ("harrw;", Ncr::new([8621u32], "\u{21AD}")),
-    ("hbar;", Ncr::new([8463u32], "\u{210F}")),
-    ("hcirc;", Ncr::new([293u32], "\u{0125}")),
-    ("hearts;", Ncr::new([9829u32], "\u{2665}")),
-    ("heartsuit;", Ncr::new([9829u32], "\u{2665}")),
-    ("hellip;", Ncr::new([8230u32], "\u{2026}")),
-    ("hercon;", Ncr::new([8889u32], "\u{22B9}")),
-    // FIXME: ("hfr;", Ncr::new(  [120101u32], "\u{D835}\u{DD25}")),
-    ("hksearow;", Ncr::new([10533u32], "\u{2925}")),
-    ("hkswarow;", Ncr::new([10534u32], "\u{2926}")),
-    ("hoarr;", Ncr::new([8703u32], "\u{21FF}")),
-    ("homtht;", Ncr::new([8763u32], "\u{223B}")),
-    ("hookleftarrow;", Ncr::new([8617u32], "\u{21A9}")),
-    ("hookrightarrow;", Ncr::new([8618u32], "\u{21AA}")),
-    // FIXME: ("hopf;", Ncr::new(   [120153u32], "\u{D835}\u{DD59}")),
-    ("horbar;", Ncr::new([8213u32], "\u{2015}")),
-    // FIXME: ("hscr;", Ncr::new(   [119997u32], "\u{D835}\u{DCBD}")),
-    ("hslash;", Ncr::new([8463u32], "\u{210F}")),
-    ("hstrok;", Ncr::new([295u32], "\u{0127}")),
-    ("hybull;", Ncr::new([8259u32], "\u{2043}")),
-    ("hyphen;", Ncr::new([8208u32], "\u{2010}")),
-    ("iacute", Ncr::new([237u32], "\u{00ED}")),
-    ("iacute;", Ncr::new([237u32], "\u{00ED}")),
-    ("ic;", Ncr::new([8291u32], "\u{2063}")),
-    ("icirc", Ncr::new([238u32], "\u{00EE}")),
-    ("icirc;", Ncr::new([238u32], "\u{00EE}")),
-    ("icy;", Ncr::new([1080u32], "\u{0438}")),
-    ("iecy;", Ncr::new([1077u32], "\u{0435}")),
-    ("iexcl", Ncr::new([161u32], "\u{00A1}")),
-    ("iexcl;", Ncr::new([161u32], "\u{00A1}")),
-    ("iff;", Ncr::new([8660u32], "\u{21D4}")),
-    // FIXME: ("ifr;", Ncr::new(  [120102u32], "\u{D835}\u{DD26}")),
-    ("igrave", Ncr::new([236u32], "\u{00EC}")),
-    ("igrave;", Ncr::new([236u32], "\u{00EC}")),
-    ("ii;", Ncr::new([8520u32], "\u{2148}")),
-    ("iiiint;", Ncr::new([10764u32], "\u{2A0C}")),
-    ("iiint;", Ncr::new([8749u32], "\u{222D}")),
-    ("iinfin;", Ncr::new([10716u32], "\u{29DC}")),
-    ("iiota;", Ncr::new([8489u32], "\u{2129}")),
-    ("ijlig;", Ncr::new([307u32], "\u{0133}")),
-    ("imacr;", Ncr::new([299u32], "\u{012B}")),
-    ("image;", Ncr::new([8465u32], "\u{2111}")),
-    ("imagline;", Ncr::new([8464u32], "\u{2110}")),
-    ("imagpart;", Ncr::new([8465u32], "\u{2111}")),
-    ("imath;", Ncr::new([305u32], "\u{0131}")),
-    ("imof;", Ncr::new([8887u32], "\u{22B7}")),
-    ("imped;", Ncr::new([437u32], "\u{01B5}")),
-    ("in;", Ncr::new([8712u32], "\u{2208}")),
-    ("incare;", Ncr::new([8453u32], "\u{2105}")),
-    ("infin;", Ncr::new([8734u32], "\u{221E}")),
-    ("infintie;", Ncr::new([10717u32], "\u{29DD}")),
-    ("inodot;", Ncr::new([305u32], "\u{0131}")),
-    ("int;", Ncr::new([8747u32], "\u{222B}")),
-    ("intcal;", Ncr::new([8890u32], "\u{22BA}")),
-    ("integers;", Ncr::new([8484u32], "\u{2124}")),
-    ("intercal;", Ncr::new([8890u32], "\u{22BA}")),
-    ("intlarhk;", Ncr::new([10775u32], "\u{2A17}")),
-    ("intprod;", Ncr::new([10812u32], "\u{2A3C}")),
-    ("iocy;", Ncr::new([1105u32], "\u{0451}")),
-    ("iogon;", Ncr::new([303u32], "\u{012F}")),
-    // FIXME: ("iopf;", Ncr::new(   [120154u32], "\u{D835}\u{DD5A}")),
-    ("iota;", Ncr::new([953u32], "\u{03B9}")),
-    ("iprod;", Ncr::new([10812u32], "\u{2A3C}")),
-    ("iquest", Ncr::new([191u32], "\u{00BF}")),
-    ("iquest;", Ncr::new([191u32], "\u{00BF}")),
-    // FIXME: ("iscr;", Ncr::new(   [119998u32], "\u{D835}\u{DCBE}")),
-    ("isin;", Ncr::new([8712u32], "\u{2208}")),
-    ("isinE;", Ncr::new([8953u32], "\u{22F9}")),
-    ("isindot;", Ncr::new([8949u32], "\u{22F5}")),
-    ("isins;", Ncr::new([8948u32], "\u{22F4}")),
-    ("isinsv;", Ncr::new([8947u32], "\u{22F3}")),
-    ("isinv;", Ncr::new([8712u32], "\u{2208}")),
-    ("it;", Ncr::new([8290u32], "\u{2062}")),
-    ("itilde;", Ncr::new([297u32], "\u{0129}")),
-    ("iukcy;", Ncr::new([1110u32], "\u{0456}")),
-    ("iuml", Ncr::new([239u32], "\u{00EF}")),
-    ("iuml;", Ncr::new([239u32], "\u{00EF}")),
-    ("jcirc;", Ncr::new([309u32], "\u{0135}")),
-    ("jcy;", Ncr::new([1081u32], "\u{0439}")),
-    // FIXME: ("jfr;", Ncr::new(  [120103u32], "\u{D835}\u{DD27}")),
-    ("jmath;", Ncr::new([567u32], "\u{0237}")),
-    // FIXME: ("jopf;", Ncr::new(   [120155u32], "\u{D835}\u{DD5B}")),
-    // FIXME: ("jscr;", Ncr::new(   [119999u32], "\u{D835}\u{DCBF}")),
-    ("jsercy;", Ncr::new([1112u32], "\u{0458}")),
-    ("jukcy;", Ncr::new([1108u32], "\u{0454}")),
-    ("kappa;", Ncr::new([954u32], "\u{03BA}")),
-    ("kappav;", Ncr::new([1008u32], "\u{03F0}")),
-    ("kcedil;", Ncr::new([311u32], "\u{0137}")),
-    ("kcy;", Ncr::new([1082u32], "\u{043A}")),
-    // FIXME: ("kfr;", Ncr::new(  [120104u32], "\u{D835}\u{DD28}")),
-    ("kgreen;", Ncr::new([312u32], "\u{0138}")),
-    ("khcy;", Ncr::new([1093u32], "\u{0445}")),
-    ("kjcy;", Ncr::new([1116u32], "\u{045C}")),
-    // FIXME: ("kopf;", Ncr::new(   [120156u32], "\u{D835}\u{DD5C}")),
-    // FIXME: ("kscr;", Ncr::new(   [120000u32], "\u{D835}\u{DCC0}")),
-    ("lAarr;", Ncr::new([8666u32], "\u{21DA}")),
-    ("lArr;", Ncr::new([8656u32], "\u{21D0}")),
-    ("lAtail;", Ncr::new([10523u32], "\u{291B}")),
-    ("lBarr;", Ncr::new([10510u32], "\u{290E}")),
-    ("lE;", Ncr::new([8806u32], "\u{2266}")),
-    ("lEg;", Ncr::new([10891u32], "\u{2A8B}")),
-    ("lHar;", Ncr::new([10594u32], "\u{2962}")),
-    ("lacute;", Ncr::new([314u32], "\u{013A}")),
-    ("laemptyv;", Ncr::new([10676u32], "\u{29B4}")),
-    ("lagran;", Ncr::new([8466u32], "\u{2112}")),
-    ("lambda;", Ncr::new([955u32], "\u{03BB}")),
-    ("lang;", Ncr::new([10216u32], "\u{27E8}")),
-    ("langd;", Ncr::new([10641u32], "\u{2991}")),
-    ("langle;", Ncr::new([10216u32], "\u{27E8}")),
-    ("lap;", Ncr::new([10885u32], "\u{2A85}")),
-    ("laquo", Ncr::new([171u32], "\u{00AB}")),
-    ("laquo;", Ncr::new([171u32], "\u{00AB}")),
-    ("larr;", Ncr::new([8592u32], "\u{2190}")),
-    ("larrb;", Ncr::new([8676u32], "\u{21E4}")),
-    ("larrbfs;", Ncr::new([10527u32], "\u{291F}")),
-    ("larrfs;", Ncr::new([10525u32], "\u{291D}")),
-    ("larrhk;", Ncr::new([8617u32], "\u{21A9}")),
-    ("larrlp;", Ncr::new([8619u32], "\u{21AB}")),
-    ("larrpl;", Ncr::new([10553u32], "\u{2939}")),
-    ("larrsim;", Ncr::new([10611u32], "\u{2973}")),
-    ("larrtl;", Ncr::new([8610u32], "\u{21A2}")),
-    ("lat;", Ncr::new([10923u32], "\u{2AAB}")),
-    ("latail;", Ncr::new([10521u32], "\u{2919}")),
-    ("late;", Ncr::new([10925u32], "\u{2AAD}")),
-    // FIXME: ("lates;", Ncr::new(  [10925, 65024u32], "\u{2AAD}\u{FE00}")),
-    ("lbarr;", Ncr::new([10508u32], "\u{290C}")),
-    ("lbbrk;", Ncr::new([10098u32], "\u{2772}")),
-    ("lbrace;", Ncr::new([123u32], "\u{007B}")),
-    ("lbrack;", Ncr::new([91u32], "\u{005B}")),
-    ("lbrke;", Ncr::new([10635u32], "\u{298B}")),
-    ("lbrksld;", Ncr::new([10639u32], "\u{298F}")),
-    ("lbrkslu;", Ncr::new([10637u32], "\u{298D}")),
-    ("lcaron;", Ncr::new([318u32], "\u{013E}")),
-    ("lcedil;", Ncr::new([316u32], "\u{013C}")),
-    ("lceil;", Ncr::new([8968u32], "\u{2308}")),
-    ("lcub;", Ncr::new([123u32], "\u{007B}")),
-    ("lcy;", Ncr::new([1083u32], "\u{043B}")),
-    ("ldca;", Ncr::new([10550u32], "\u{2936}")),
-    ("ldquo;", Ncr::new([8220u32], "\u{201C}")),
-    ("ldquor;", Ncr::new([8222u32], "\u{201E}")),
-    ("ldrdhar;", Ncr::new([10599u32], "\u{2967}")),
-    ("ldrushar;", Ncr::new([10571u32], "\u{294B}")),
-    ("ldsh;", Ncr::new([8626u32], "\u{21B2}")),
-    ("le;", Ncr::new([8804u32], "\u{2264}")),
-    ("leftarrow;", Ncr::new([8592u32], "\u{2190}")),
-    ("leftarrowtail;", Ncr::new([8610u32], "\u{21A2}")),
-    ("leftharpoondown;", Ncr::new([8637u32], "\u{21BD}")),
-    ("leftharpoonup;", Ncr::new([8636u32], "\u{21BC}")),
-    ("leftleftarrows;", Ncr::new([8647u32], "\u{21C7}")),
-    ("leftrightarrow;", Ncr::new([8596u32], "\u{2194}")),
-    ("leftrightarrows;", Ncr::new([8646u32], "\u{21C6}")),
-    ("leftrightharpoons;", Ncr::new([8651u32], "\u{21CB}")),
-    ("leftrightsquigarrow;", Ncr::new([8621u32], "\u{21AD}")),
-    ("leftthreetimes;", Ncr::new([8907u32], "\u{22CB}")),
-    ("leg;", Ncr::new([8922u32], "\u{22DA}")),
-    ("leq;", Ncr::new([8804u32], "\u{2264}")),
-    ("leqq;", Ncr::new([8806u32], "\u{2266}")),
-    ("leqslant;", Ncr::new([10877u32], "\u{2A7D}")),
-    ("les;", Ncr::new([10877u32], "\u{2A7D}")),
-    ("lescc;", Ncr::new([10920u32], "\u{2AA8}")),
-    ("lesdot;", Ncr::new([10879u32], "\u{2A7F}")),
-    ("lesdoto;", Ncr::new([10881u32], "\u{2A81}")),
-    ("lesdotor;", Ncr::new([10883u32], "\u{2A83}")),
-    // FIXME: ("lesg;", Ncr::new(   [8922, 65024u32], "\u{22DA}\u{FE00}")),
-    ("lesges;", Ncr::new([10899u32], "\u{2A93}")),
-    ("lessapprox;", Ncr::new([10885u32], "\u{2A85}")),
-    ("lessdot;", Ncr::new([8918u32], "\u{22D6}")),
-    ("lesseqgtr;", Ncr::new([8922u32], "\u{22DA}")),
-    ("lesseqqgtr;", Ncr::new([10891u32], "\u{2A8B}")),
-    ("lessgtr;", Ncr::new([8822u32], "\u{2276}")),
-    ("lesssim;", Ncr::new([8818u32], "\u{2272}")),
-    ("lfisht;", Ncr::new([10620u32], "\u{297C}")),
-    ("lfloor;", Ncr::new([8970u32], "\u{230A}")),
-    // FIXME: ("lfr;", Ncr::new(  [120105u32], "\u{D835}\u{DD29}")),
-    ("lg;", Ncr::new([8822u32], "\u{2276}")),
-    ("lgE;", Ncr::new([10897u32], "\u{2A91}")),
-    ("lhard;", Ncr::new([8637u32], "\u{21BD}")),
-    ("lharu;", Ncr::new([8636u32], "\u{21BC}")),
-    ("lharul;", Ncr::new([10602u32], "\u{296A}")),
-    ("lhblk;", Ncr::new([9604u32], "\u{2584}")),
-    ("ljcy;", Ncr::new([1113u32], "\u{0459}")),
-    ("ll;", Ncr::new([8810u32], "\u{226A}")),
-    ("llarr;", Ncr::new([8647u32], "\u{21C7}")),
-    ("llcorner;", Ncr::new([8990u32], "\u{231E}")),
-    ("llhard;", Ncr::new([10603u32], "\u{296B}")),
-    ("lltri;", Ncr::new([9722u32], "\u{25FA}")),
-    ("lmidot;", Ncr::new([320u32], "\u{0140}")),
-    ("lmoust;", Ncr::new([9136u32], "\u{23B0}")),
-    ("lmoustache;", Ncr::new([9136u32], "\u{23B0}")),
-    ("lnE;", Ncr::new([8808u32], "\u{2268}")),
-    ("lnap;", Ncr::new([10889u32], "\u{2A89}")),
-    ("lnapprox;", Ncr::new([10889u32], "\u{2A89}")),
-    ("lne;", Ncr::new([10887u32], "\u{2A87}")),
-    ("lneq;", Ncr::new([10887u32], "\u{2A87}")),
-    ("lneqq;", Ncr::new([8808u32], "\u{2268}")),
-    ("lnsim;", Ncr::new([8934u32], "\u{22E6}")),
-    ("loang;", Ncr::new([10220u32], "\u{27EC}")),
-    ("loarr;", Ncr::new([8701u32], "\u{21FD}")),
-    ("lobrk;", Ncr::new([10214u32], "\u{27E6}")),
-    ("longleftarrow;", Ncr::new([10229u32], "\u{27F5}")),
-    ("longleftrightarrow;", Ncr::new([10231u32], "\u{27F7}")),
-    ("longmapsto;", Ncr::new([10236u32], "\u{27FC}")),
-    ("longrightarrow;", Ncr::new([10230u32], "\u{27F6}")),
-    ("looparrowleft;", Ncr::new([8619u32], "\u{21AB}")),
-    ("looparrowright;", Ncr::new([8620u32], "\u{21AC}")),
-    ("lopar;", Ncr::new([10629u32], "\u{2985}")),
-    // FIXME: ("lopf;", Ncr::new(   [120157u32], "\u{D835}\u{DD5D}")),
-    ("loplus;", Ncr::new([10797u32], "\u{2A2D}")),
-    ("lotimes;", Ncr::new([10804u32], "\u{2A34}")),
-    ("lowast;", Ncr::new([8727u32], "\u{2217}")),
-    ("lowbar;", Ncr::new([95u32], "\u{005F}")),
-    ("loz;", Ncr::new([9674u32], "\u{25CA}")),
-    ("lozenge;", Ncr::new([9674u32], "\u{25CA}")),
-    ("lozf;", Ncr::new([10731u32], "\u{29EB}")),
-    ("lpar;", Ncr::new([40u32], "\u{0028}")),
-    ("lparlt;", Ncr::new([10643u32], "\u{2993}")),
-    ("lrarr;", Ncr::new([8646u32], "\u{21C6}")),
-    ("lrcorner;", Ncr::new([8991u32], "\u{231F}")),
-    ("lrhar;", Ncr::new([8651u32], "\u{21CB}")),
-    ("lrhard;", Ncr::new([10605u32], "\u{296D}")),
-    ("lrm;", Ncr::new([8206u32], "\u{200E}")),
-    ("lrtri;", Ncr::new([8895u32], "\u{22BF}")),
-    ("lsaquo;", Ncr::new([8249u32], "\u{2039}")),
-    // FIXME: ("lscr;", Ncr::new(   [120001u32], "\u{D835}\u{DCC1}")),
-    ("lsh;", Ncr::new([8624u32], "\u{21B0}")),
-    ("lsim;", Ncr::new([8818u32], "\u{2272}")),
-    ("lsime;", Ncr::new([10893u32], "\u{2A8D}")),
-    ("lsimg;", Ncr::new([10895u32], "\u{2A8F}")),
-    ("lsqb;", Ncr::new([91u32], "\u{005B}")),
-    ("lsquo;", Ncr::new([8216u32], "\u{2018}")),
-    ("lsquor;", Ncr::new([8218u32], "\u{201A}")),
-    ("lstrok;", Ncr::new([322u32], "\u{0142}")),
-    ("lt", Ncr::new([60u32], "\u{003C}")),
-    ("lt;", Ncr::new([60u32], "\u{003C}")),
-    ("ltcc;", Ncr::new([10918u32], "\u{2AA6}")),
-    ("ltcir;", Ncr::new([10873u32], "\u{2A79}")),
-    ("ltdot;", Ncr::new([8918u32], "\u{22D6}")),
-    ("lthree;", Ncr::new([8907u32], "\u{22CB}")),
-    ("ltimes;", Ncr::new([8905u32], "\u{22C9}")),
-    ("ltlarr;", Ncr::new([10614u32], "\u{2976}")),
-    ("ltquest;", Ncr::new([10875u32], "\u{2A7B}")),
-    ("ltrPar;", Ncr::new([10646u32], "\u{2996}")),
-    ("ltri;", Ncr::new([9667u32], "\u{25C3}")),
-    ("ltrie;", Ncr::new([8884u32], "\u{22B4}")),
-    ("ltrif;", Ncr::new([9666u32], "\u{25C2}")),
-    ("lurdshar;", Ncr::new([10570u32], "\u{294A}")),
-    ("luruhar;", Ncr::new([10598u32], "\u{2966}")),
-    // FIXME: ("lvertneqq;", Ncr::new(  [8808, 65024u32], "\u{2268}\u{FE00}")),
-    // FIXME: ("lvnE;", Ncr::new(   [8808, 65024u32], "\u{2268}\u{FE00}")),
-    ("mDDot;", Ncr::new([8762u32], "\u{223A}")),
-    ("macr", Ncr::new([175u32], "\u{00AF}")),
-    ("macr;", Ncr::new([175u32], "\u{00AF}")),
-    ("male;", Ncr::new([9794u32], "\u{2642}")),
-    ("malt;", Ncr::new([10016u32], "\u{2720}")),
-    ("maltese;", Ncr::new([10016u32], "\u{2720}")),
-    ("map;", Ncr::new([8614u32], "\u{21A6}")),
-    ("mapsto;", Ncr::new([8614u32], "\u{21A6}")),
-    ("mapstodown;", Ncr::new([8615u32], "\u{21A7}")),
-    ("mapstoleft;", Ncr::new([8612u32], "\u{21A4}")),
-    ("mapstoup;", Ncr::new([8613u32], "\u{21A5}")),
-    ("marker;", Ncr::new([9646u32], "\u{25AE}")),
-    ("mcomma;", Ncr::new([10793u32], "\u{2A29}")),
-    ("mcy;", Ncr::new([1084u32], "\u{043C}")),
-    ("mdash;", Ncr::new([8212u32], "\u{2014}")),
-    ("measuredangle;", Ncr::new([8737u32], "\u{2221}")),
-    // FIXME: ("mfr;", Ncr::new(  [120106u32], "\u{D835}\u{DD2A}")),
-    ("mho;", Ncr::new([8487u32], "\u{2127}")),
-    ("micro", Ncr::new([181u32], "\u{00B5}")),
-    ("micro;", Ncr::new([181u32], "\u{00B5}")),
-    ("mid;", Ncr::new([8739u32], "\u{2223}")),
-    ("midast;", Ncr::new([42u32], "\u{002A}")),
-    ("midcir;", Ncr::new([10992u32], "\u{2AF0}")),
-    ("middot", Ncr::new([183u32], "\u{00B7}")),
-    ("middot;", Ncr::new([183u32], "\u{00B7}")),
-    ("minus;", Ncr::new([8722u32], "\u{2212}")),
-    ("minusb;", Ncr::new([8863u32], "\u{229F}")),
-    ("minusd;", Ncr::new([8760u32], "\u{2238}")),
-    ("minusdu;", Ncr::new([10794u32], "\u{2A2A}")),
-    ("mlcp;", Ncr::new([10971u32], "\u{2ADB}")),
-    ("mldr;", Ncr::new([8230u32], "\u{2026}")),
-    ("mnplus;", Ncr::new([8723u32], "\u{2213}")),
-    ("models;", Ncr::new([8871u32], "\u{22A7}")),
-    // FIXME: ("mopf;", Ncr::new(   [120158u32], "\u{D835}\u{DD5E}")),
-    ("mp;", Ncr::new([8723u32], "\u{2213}")),
-    // FIXME: ("mscr;", Ncr::new(   [120002u32], "\u{D835}\u{DCC2}")),
-    ("mstpos;", Ncr::new([8766u32], "\u{223E}")),
-    ("mu;", Ncr::new([956u32], "\u{03BC}")),
-    ("multimap;", Ncr::new([8888u32], "\u{22B8}")),
-    ("mumap;", Ncr::new([8888u32], "\u{22B8}")),
-    // FIXME: ("nGg;", Ncr::new(  [8921, 824u32], "\u{22D9}\u{0338}")),
-    // FIXME: ("nGt;", Ncr::new(  [8811, 8402u32], "\u{226B}\u{20D2}")),
-    // FIXME: ("nGtv;", Ncr::new(   [8811, 824u32], "\u{226B}\u{0338}")),
-    ("nLeftarrow;", Ncr::new([8653u32], "\u{21CD}")),
-    ("nLeftrightarrow;", Ncr::new([8654u32], "\u{21CE}")),
-    // FIXME: ("nLl;", Ncr::new(  [8920, 824u32], "\u{22D8}\u{0338}")),
-    // FIXME: ("nLt;", Ncr::new(  [8810, 8402u32], "\u{226A}\u{20D2}")),
-    // FIXME: ("nLtv;", Ncr::new(   [8810, 824u32], "\u{226A}\u{0338}")),
-    ("nRightarrow;", Ncr::new([8655u32], "\u{21CF}")),
-    ("nVDash;", Ncr::new([8879u32], "\u{22AF}")),
-    ("nVdash;", Ncr::new([8878u32], "\u{22AE}")),
-    ("nabla;", Ncr::new([8711u32], "\u{2207}")),
-    ("nacute;", Ncr::new([324u32], "\u{0144}")),
-    // FIXME: ("nang;", Ncr::new(   [8736, 8402u32], "\u{2220}\u{20D2}")),
-    ("nap;", Ncr::new([8777u32], "\u{2249}")),
-    // FIXME: ("napE;", Ncr::new(   [10864, 824u32], "\u{2A70}\u{0338}")),
-    // FIXME: ("napid;", Ncr::new(  [8779, 824u32], "\u{224B}\u{0338}")),
-    ("napos;", Ncr::new([329u32], "\u{0149}")),
-    ("napprox;", Ncr::new([8777u32], "\u{2249}")),
-    ("natur;", Ncr::new([9838u32], "\u{266E}")),
-    ("natural;", Ncr::new([9838u32], "\u{266E}")),
-    ("naturals;", Ncr::new([8469u32], "\u{2115}")),
-    ("nbsp", Ncr::new([160u32], "\u{00A0}")),
-    ("nbsp;", Ncr::new([160u32], "\u{00A0}")),
-    // FIXME: ("nbump;", Ncr::new(  [8782, 824u32], "\u{224E}\u{0338}")),
-    // FIXME: ("nbumpe;", Ncr::new(   [8783, 824u32], "\u{224F}\u{0338}")),
-    ("ncap;", Ncr::new([10819u32], "\u{2A43}")),
-    ("ncaron;", Ncr::new([328u32], "\u{0148}")),
-    ("ncedil;", Ncr::new([326u32], "\u{0146}")),
-    ("ncong;", Ncr::new([8775u32], "\u{2247}")),
-    // FIXME: ("ncongdot;", Ncr::new(   [10861, 824u32], "\u{2A6D}\u{0338}")),
-    ("ncup;", Ncr::new([10818u32], "\u{2A42}")),
-    ("ncy;", Ncr::new([1085u32], "\u{043D}")),
-    ("ndash;", Ncr::new([8211u32], "\u{2013}")),
-    ("ne;", Ncr::new([8800u32], "\u{2260}")),
-    ("neArr;", Ncr::new([8663u32], "\u{21D7}")),
-    ("nearhk;", Ncr::new([10532u32], "\u{2924}")),
-    ("nearr;", Ncr::new([8599u32], "\u{2197}")),
-    ("nearrow;", Ncr::new([8599u32], "\u{2197}")),
-    // FIXME: ("nedot;", Ncr::new(  [8784, 824u32], "\u{2250}\u{0338}")),
-    ("nequiv;", Ncr::new([8802u32], "\u{2262}")),
-    ("nesear;", Ncr::new([10536u32], "\u{2928}")),
-    // FIXME: ("nesim;", Ncr::new(  [8770, 824u32], "\u{2242}\u{0338}")),
-    ("nexist;", Ncr::new([8708u32], "\u{2204}")),
-    ("nexists;", Ncr::new([8708u32], "\u{2204}")),
-    // FIXME: ("nfr;", Ncr::new(  [120107u32], "\u{D835}\u{DD2B}")),
-    // FIXME: ("ngE;", Ncr::new(  [8807, 824u32], "\u{2267}\u{0338}")),
-    ("nge;", Ncr::new([8817u32], "\u{2271}")),
-    ("ngeq;", Ncr::new([8817u32], "\u{2271}")),
-    // FIXME: ("ngeqq;", Ncr::new(  [8807, 824u32], "\u{2267}\u{0338}")),
-    // FIXME: ("ngeqslant;", Ncr::new(  [10878, 824u32], "\u{2A7E}\u{0338}")),
-    // FIXME: ("nges;", Ncr::new(   [10878, 824u32], "\u{2A7E}\u{0338}")),
-    ("ngsim;", Ncr::new([8821u32], "\u{2275}")),
-    ("ngt;", Ncr::new([8815u32], "\u{226F}")),
-    ("ngtr;", Ncr::new([8815u32], "\u{226F}")),
-    ("nhArr;", Ncr::new([8654u32], "\u{21CE}")),
-    ("nharr;", Ncr::new([8622u32], "\u{21AE}")),
-    ("nhpar;", Ncr::new([10994u32], "\u{2AF2}")),
-    ("ni;", Ncr::new([8715u32], "\u{220B}")),
-    ("nis;", Ncr::new([8956u32], "\u{22FC}")),
-    ("nisd;", Ncr::new([8954u32], "\u{22FA}")),
-    ("niv;", Ncr::new([8715u32], "\u{220B}")),
-    ("njcy;", Ncr::new([1114u32], "\u{045A}")),
-    ("nlArr;", Ncr::new([8653u32], "\u{21CD}")),
-    // FIXME: ("nlE;", Ncr::new(  [8806, 824u32], "\u{2266}\u{0338}")),
-    ("nlarr;", Ncr::new([8602u32], "\u{219A}")),
-    ("nldr;", Ncr::new([8229u32], "\u{2025}")),
-    ("nle;", Ncr::new([8816u32], "\u{2270}")),
-    ("nleftarrow;", Ncr::new([8602u32], "\u{219A}")),
-    ("nleftrightarrow;", Ncr::new([8622u32], "\u{21AE}")),
-    ("nleq;", Ncr::new([8816u32], "\u{2270}")),
-    // FIXME: ("nleqq;", Ncr::new(  [8806, 824u32], "\u{2266}\u{0338}")),
-    // FIXME: ("nleqslant;", Ncr::new(  [10877, 824u32], "\u{2A7D}\u{0338}")),
-    // FIXME: ("nles;", Ncr::new(   [10877, 824u32], "\u{2A7D}\u{0338}")),
-    ("nless;", Ncr::new([8814u32], "\u{226E}")),
-    ("nlsim;", Ncr::new([8820u32], "\u{2274}")),
-    ("nlt;", Ncr::new([8814u32], "\u{226E}")),
-    ("nltri;", Ncr::new([8938u32], "\u{22EA}")),
-    ("nltrie;", Ncr::new([8940u32], "\u{22EC}")),
-    ("nmid;", Ncr::new([8740u32], "\u{2224}")),
-    // FIXME: ("nopf;", Ncr::new(   [120159u32], "\u{D835}\u{DD5F}")),
-    ("not", Ncr::new([172u32], "\u{00AC}")),
-    ("not;", Ncr::new([172u32], "\u{00AC}")),
-    ("notin;", Ncr::new([8713u32], "\u{2209}")),
-    // FIXME: ("notinE;", Ncr::new(   [8953, 824u32], "\u{22F9}\u{0338}")),
-    // FIXME: ("notindot;", Ncr::new(   [8949, 824u32], "\u{22F5}\u{0338}")),
-    ("notinva;", Ncr::new([8713u32], "\u{2209}")),
-    ("notinvb;", Ncr::new([8951u32], "\u{22F7}")),
-    ("notinvc;", Ncr::new([8950u32], "\u{22F6}")),
-    ("notni;", Ncr::new([8716u32], "\u{220C}")),
-    ("notniva;", Ncr::new([8716u32], "\u{220C}")),
-    ("notnivb;", Ncr::new([8958u32], "\u{22FE}")),
-    ("notnivc;", Ncr::new([8957u32], "\u{22FD}")),
-    ("npar;", Ncr::new([8742u32], "\u{2226}")),
-    ("nparallel;", Ncr::new([8742u32], "\u{2226}")),
-    // FIXME: ("nparsl;", Ncr::new(   [11005, 8421u32], "\u{2AFD}\u{20E5}")),
-    // FIXME: ("npart;", Ncr::new(  [8706, 824u32], "\u{2202}\u{0338}")),
-    ("npolint;", Ncr::new([10772u32], "\u{2A14}")),
-    ("npr;", Ncr::new([8832u32], "\u{2280}")),
-    ("nprcue;", Ncr::new([8928u32], "\u{22E0}")),
-    // FIXME: ("npre;", Ncr::new(   [10927, 824u32], "\u{2AAF}\u{0338}")),
-    ("nprec;", Ncr::new([8832u32], "\u{2280}")),
-    // FIXME: ("npreceq;", Ncr::new(  [10927, 824u32], "\u{2AAF}\u{0338}")),
-    ("nrArr;", Ncr::new([8655u32], "\u{21CF}")),
-    ("nrarr;", Ncr::new([8603u32], "\u{219B}")),
-    // FIXME: ("nrarrc;", Ncr::new(   [10547, 824u32], "\u{2933}\u{0338}")),
-    // FIXME: ("nrarrw;", Ncr::new(   [8605, 824u32], "\u{219D}\u{0338}")),
-    ("nrightarrow;", Ncr::new([8603u32], "\u{219B}")),
-    ("nrtri;", Ncr::new([8939u32], "\u{22EB}")),
-    ("nrtrie;", Ncr::new([8941u32], "\u{22ED}")),
-    ("nsc;", Ncr::new([8833u32], "\u{2281}")),
-    ("nsccue;", Ncr::new([8929u32], "\u{22E1}")),
-    // FIXME: ("nsce;", Ncr::new(   [10928, 824u32], "\u{2AB0}\u{0338}")),
-    // FIXME: ("nscr;", Ncr::new(   [120003u32], "\u{D835}\u{DCC3}")),
-    ("nshortmid;", Ncr::new([8740u32], "\u{2224}")),
-    ("nshortparallel;", Ncr::new([8742u32], "\u{2226}")),
-    ("nsim;", Ncr::new([8769u32], "\u{2241}")),
-    ("nsime;", Ncr::new([8772u32], "\u{2244}")),
-    ("nsimeq;", Ncr::new([8772u32], "\u{2244}")),
-    ("nsmid;", Ncr::new([8740u32], "\u{2224}")),
-    ("nspar;", Ncr::new([8742u32], "\u{2226}")),
-    ("nsqsube;", Ncr::new([8930u32], "\u{22E2}")),
-    ("nsqsupe;", Ncr::new([8931u32], "\u{22E3}")),
-    ("nsub;", Ncr::new([8836u32], "\u{2284}")),
-    // FIXME: ("nsubE;", Ncr::new(  [10949, 824u32], "\u{2AC5}\u{0338}")),
-    ("nsube;", Ncr::new([8840u32], "\u{2288}")),
-    // FIXME: ("nsubset;", Ncr::new(  [8834, 8402u32], "\u{2282}\u{20D2}")),
-    ("nsubseteq;", Ncr::new([8840u32], "\u{2288}")),
-    // FIXME: ("nsubseteqq;", Ncr::new(   [10949, 824u32], "\u{2AC5}\u{0338}")),
-    ("nsucc;", Ncr::new([8833u32], "\u{2281}")),
-    // FIXME: ("nsucceq;", Ncr::new(  [10928, 824u32], "\u{2AB0}\u{0338}")),
-    ("nsup;", Ncr::new([8837u32], "\u{2285}")),
-    // FIXME: ("nsupE;", Ncr::new(  [10950, 824u32], "\u{2AC6}\u{0338}")),
-    ("nsupe;", Ncr::new([8841u32], "\u{2289}")),
-    // FIXME: ("nsupset;", Ncr::new(  [8835, 8402u32], "\u{2283}\u{20D2}")),
-    ("nsupseteq;", Ncr::new([8841u32], "\u{2289}")),
-    // FIXME: ("nsupseteqq;", Ncr::new(   [10950, 824u32], "\u{2AC6}\u{0338}")),
-    ("ntgl;", Ncr::new([8825u32], "\u{2279}")),
-    ("ntilde", Ncr::new([241u32], "\u{00F1}")),
-    ("ntilde;", Ncr::new([241u32], "\u{00F1}")),
-    ("ntlg;", Ncr::new([8824u32], "\u{2278}")),
-    ("ntriangleleft;", Ncr::new([8938u32], "\u{22EA}")),
-    ("ntrianglelefteq;", Ncr::new([8940u32], "\u{22EC}")),
-    ("ntriangleright;", Ncr::new([8939u32], "\u{22EB}")),
-    ("ntrianglerighteq;", Ncr::new([8941u32], "\u{22ED}")),
-    ("nu;", Ncr::new([957u32], "\u{03BD}")),
-    ("num;", Ncr::new([35u32], "\u{0023}")),
-    ("numero;", Ncr::new([8470u32], "\u{2116}")),
-    ("numsp;", Ncr::new([8199u32], "\u{2007}")),
-    ("nvDash;", Ncr::new([8877u32], "\u{22AD}")),
-    ("nvHarr;", Ncr::new([10500u32], "\u{2904}")),
-    // FIXME: ("nvap;", Ncr::new(   [8781, 8402u32], "\u{224D}\u{20D2}")),
-    ("nvdash;", Ncr::new([8876u32], "\u{22AC}")),
-    // FIXME: ("nvge;", Ncr::new(   [8805, 8402u32], "\u{2265}\u{20D2}")),
-    // FIXME: ("nvgt;", Ncr::new(   [62, 8402u32], "\u{003E}\u{20D2}")),
-    ("nvinfin;", Ncr::new([10718u32], "\u{29DE}")),
-    ("nvlArr;", Ncr::new([10498u32], "\u{2902}")),
-    // FIXME: ("nvle;", Ncr::new(   [8804, 8402u32], "\u{2264}\u{20D2}")),
-    // FIXME: ("nvlt;", Ncr::new(   [60, 8402u32], "\u{003C}\u{20D2}")),
-    // FIXME: ("nvltrie;", Ncr::new(  [8884, 8402u32], "\u{22B4}\u{20D2}")),
-    ("nvrArr;", Ncr::new([10499u32], "\u{2903}")),
-    // FIXME: ("nvrtrie;", Ncr::new(  [8885, 8402u32], "\u{22B5}\u{20D2}")),
-    // FIXME: ("nvsim;", Ncr::new(  [8764, 8402u32], "\u{223C}\u{20D2}")),
-    ("nwArr;", Ncr::new([8662u32], "\u{21D6}")),
-    ("nwarhk;", Ncr::new([10531u32], "\u{2923}")),
-    ("nwarr;", Ncr::new([8598u32], "\u{2196}")),
-    ("nwarrow;", Ncr::new([8598u32], "\u{2196}")),
-    ("nwnear;", Ncr::new([10535u32], "\u{2927}")),
-    ("oS;", Ncr::new([9416u32], "\u{24C8}")),
-    ("oacute", Ncr::new([243u32], "\u{00F3}")),
-    ("oacute;", Ncr::new([243u32], "\u{00F3}")),
-    ("oast;", Ncr::new([8859u32], "\u{229B}")),
-    ("ocir;", Ncr::new([8858u32], "\u{229A}")),
-    ("ocirc", Ncr::new([244u32], "\u{00F4}")),
-    ("ocirc;", Ncr::new([244u32], "\u{00F4}")),
-    ("ocy;", Ncr::new([1086u32], "\u{043E}")),
-    ("odash;", Ncr::new([8861u32], "\u{229D}")),
-    ("odblac;", Ncr::new([337u32], "\u{0151}")),
-    ("odiv;", Ncr::new([10808u32], "\u{2A38}")),
-    ("odot;", Ncr::new([8857u32], "\u{2299}")),
-    ("odsold;", Ncr::new([10684u32], "\u{29BC}")),
-    ("oelig;", Ncr::new([339u32], "\u{0153}")),
-    ("ofcir;", Ncr::new([10687u32], "\u{29BF}")),
-    // FIXME: ("ofr;", Ncr::new(  [120108u32], "\u{D835}\u{DD2C}")),
-    ("ogon;", Ncr::new([731u32], "\u{02DB}")),
-    ("ograve", Ncr::new([242u32], "\u{00F2}")),
-    ("ograve;", Ncr::new([242u32], "\u{00F2}")),
-    ("ogt;", Ncr::new([10689u32], "\u{29C1}")),
-    ("ohbar;", Ncr::new([10677u32], "\u{29B5}")),
-    ("ohm;", Ncr::new([937u32], "\u{03A9}")),
-    ("oint;", Ncr::new([8750u32], "\u{222E}")),
-    ("olarr;", Ncr::new([8634u32], "\u{21BA}")),
-    ("olcir;", Ncr::new([10686u32], "\u{29BE}")),
-    ("olcross;", Ncr::new([10683u32], "\u{29BB}")),
-    ("oline;", Ncr::new([8254u32], "\u{203E}")),
-    ("olt;", Ncr::new([10688u32], "\u{29C0}")),
-    ("omacr;", Ncr::new([333u32], "\u{014D}")),
-    ("omega;", Ncr::new([969u32], "\u{03C9}")),
-    ("omicron;", Ncr::new([959u32], "\u{03BF}")),
-    ("omid;", Ncr::new([10678u32], "\u{29B6}")),
-    ("ominus;", Ncr::new([8854u32], "\u{2296}")),
-    // FIXME: ("oopf;", Ncr::new(   [120160u32], "\u{D835}\u{DD60}")),
-    ("opar;", Ncr::new([10679u32], "\u{29B7}")),
-    ("operp;", Ncr::new([10681u32], "\u{29B9}")),
-    ("oplus;", Ncr::new([8853u32], "\u{2295}")),
-    ("or;", Ncr::new([8744u32], "\u{2228}")),
-    ("orarr;", Ncr::new([8635u32], "\u{21BB}")),
-    ("ord;", Ncr::new([10845u32], "\u{2A5D}")),
-    ("order;", Ncr::new([8500u32], "\u{2134}")),
-    ("orderof;", Ncr::new([8500u32], "\u{2134}")),
-    ("ordf", Ncr::new([170u32], "\u{00AA}")),
-    ("ordf;", Ncr::new([170u32], "\u{00AA}")),
-    ("ordm", Ncr::new([186u32], "\u{00BA}")),
-    ("ordm;", Ncr::new([186u32], "\u{00BA}")),
-    ("origof;", Ncr::new([8886u32], "\u{22B6}")),
-    ("oror;", Ncr::new([10838u32], "\u{2A56}")),
-    ("orslope;", Ncr::new([10839u32], "\u{2A57}")),
-    ("orv;", Ncr::new([10843u32], "\u{2A5B}")),
-    ("oscr;", Ncr::new([8500u32], "\u{2134}")),
-    ("oslash", Ncr::new([248u32], "\u{00F8}")),
-    ("oslash;", Ncr::new([248u32], "\u{00F8}")),
-    ("osol;", Ncr::new([8856u32], "\u{2298}")),
-    ("otilde", Ncr::new([245u32], "\u{00F5}")),
-    ("otilde;", Ncr::new([245u32], "\u{00F5}")),
-    ("otimes;", Ncr::new([8855u32], "\u{2297}")),
-    ("otimesas;", Ncr::new([10806u32], "\u{2A36}")),
-    ("ouml", Ncr::new([246u32], "\u{00F6}")),
-    ("ouml;", Ncr::new([246u32], "\u{00F6}")),
-    ("ovbar;", Ncr::new([9021u32], "\u{233D}")),
-    ("par;", Ncr::new([8741u32], "\u{2225}")),
-    ("para", Ncr::new([182u32], "\u{00B6}")),
-    ("para;", Ncr::new([182u32], "\u{00B6}")),
-    ("parallel;", Ncr::new([8741u32], "\u{2225}")),
-    ("parsim;", Ncr::new([10995u32], "\u{2AF3}")),
-    ("parsl;", Ncr::new([11005u32], "\u{2AFD}")),
-    ("part;", Ncr::new([8706u32], "\u{2202}")),
-    ("pcy;", Ncr::new([1087u32], "\u{043F}")),
-    ("percnt;", Ncr::new([37u32], "\u{0025}")),
-    ("period;", Ncr::new([46u32], "\u{002E}")),
-    ("permil;", Ncr::new([8240u32], "\u{2030}")),
-    ("perp;", Ncr::new([8869u32], "\u{22A5}")),
-    ("pertenk;", Ncr::new([8241u32], "\u{2031}")),
-    // FIXME: ("pfr;", Ncr::new(  [120109u32], "\u{D835}\u{DD2D}")),
-    ("phi;", Ncr::new([966u32], "\u{03C6}")),
-    ("phiv;", Ncr::new([981u32], "\u{03D5}")),
-    ("phmmat;", Ncr::new([8499u32], "\u{2133}")),
-    ("phone;", Ncr::new([9742u32], "\u{260E}")),
-    ("pi;", Ncr::new([960u32], "\u{03C0}")),
-    ("pitchfork;", Ncr::new([8916u32], "\u{22D4}")),
-    ("piv;", Ncr::new([982u32], "\u{03D6}")),
-    ("planck;", Ncr::new([8463u32], "\u{210F}")),
-    ("planckh;", Ncr::new([8462u32], "\u{210E}")),
-    ("plankv;", Ncr::new([8463u32], "\u{210F}")),
-    ("plus;", Ncr::new([43u32], "\u{002B}")),
-    ("plusacir;", Ncr::new([10787u32], "\u{2A23}")),
-    ("plusb;", Ncr::new([8862u32], "\u{229E}")),
-    ("pluscir;", Ncr::new([10786u32], "\u{2A22}")),
-    ("plusdo;", Ncr::new([8724u32], "\u{2214}")),
-    ("plusdu;", Ncr::new([10789u32], "\u{2A25}")),
-    ("pluse;", Ncr::new([10866u32], "\u{2A72}")),
-    ("plusmn", Ncr::new([177u32], "\u{00B1}")),
-    ("plusmn;", Ncr::new([177u32], "\u{00B1}")),
-    ("plussim;", Ncr::new([10790u32], "\u{2A26}")),
-    ("plustwo;", Ncr::new([10791u32], "\u{2A27}")),
-    ("pm;", Ncr::new([177u32], "\u{00B1}")),
-    ("pointint;", Ncr::new([10773u32], "\u{2A15}")),
-    // FIXME: ("popf;", Ncr::new(   [120161u32], "\u{D835}\u{DD61}")),
-    ("pound", Ncr::new([163u32], "\u{00A3}")),
-    ("pound;", Ncr::new([163u32], "\u{00A3}")),
-    ("pr;", Ncr::new([8826u32], "\u{227A}")),
-    ("prE;", Ncr::new([10931u32], "\u{2AB3}")),
-    ("prap;", Ncr::new([10935u32], "\u{2AB7}")),
-    ("prcue;", Ncr::new([8828u32], "\u{227C}")),
-    ("pre;", Ncr::new([10927u32], "\u{2AAF}")),
-    ("prec;", Ncr::new([8826u32], "\u{227A}")),
-    ("precapprox;", Ncr::new([10935u32], "\u{2AB7}")),
-    ("preccurlyeq;", Ncr::new([8828u32], "\u{227C}")),
-    ("preceq;", Ncr::new([10927u32], "\u{2AAF}")),
-    ("precnapprox;", Ncr::new([10937u32], "\u{2AB9}")),
-    ("precneqq;", Ncr::new([10933u32], "\u{2AB5}")),
-    ("precnsim;", Ncr::new([8936u32], "\u{22E8}")),
-    ("precsim;", Ncr::new([8830u32], "\u{227E}")),
-    ("prime;", Ncr::new([8242u32], "\u{2032}")),
-    ("primes;", Ncr::new([8473u32], "\u{2119}")),
-    ("prnE;", Ncr::new([10933u32], "\u{2AB5}")),
-    ("prnap;", Ncr::new([10937u32], "\u{2AB9}")),
-    ("prnsim;", Ncr::new([8936u32], "\u{22E8}")),
-    ("prod;", Ncr::new([8719u32], "\u{220F}")),
-    ("profalar;", Ncr::new([9006u32], "\u{232E}")),
-    ("profline;", Ncr::new([8978u32], "\u{2312}")),
-    ("profsurf;", Ncr::new([8979u32], "\u{2313}")),
-    ("prop;", Ncr::new([8733u32], "\u{221D}")),
-    ("propto;", Ncr::new([8733u32], "\u{221D}")),
-    ("prsim;", Ncr::new([8830u32], "\u{227E}")),
-    ("prurel;", Ncr::new([8880u32], "\u{22B0}")),
-    // FIXME: ("pscr;", Ncr::new(   [120005u32], "\u{D835}\u{DCC5}")),
-    ("psi;", Ncr::new([968u32], "\u{03C8}")),
-    ("puncsp;", Ncr::new([8200u32], "\u{2008}")),
-    // FIXME: ("qfr;", Ncr::new(  [120110u32], "\u{D835}\u{DD2E}")),
-    ("qint;", Ncr::new([10764u32], "\u{2A0C}")),
-    // FIXME: ("qopf;", Ncr::new(   [120162u32], "\u{D835}\u{DD62}")),
-    ("qprime;", Ncr::new([8279u32], "\u{2057}")),
-    // FIXME: ("qscr;", Ncr::new(   [120006u32], "\u{D835}\u{DCC6}")),
-    ("quaternions;", Ncr::new([8461u32], "\u{210D}")),
-    ("quatint;", Ncr::new([10774u32], "\u{2A16}")),
-    ("quest;", Ncr::new([63u32], "\u{003F}")),
-    ("questeq;", Ncr::new([8799u32], "\u{225F}")),
-    ("quot", Ncr::new([34u32], "\u{0022}")),
-    ("quot;", Ncr::new([34u32], "\u{0022}")),
-    ("rAarr;", Ncr::new([8667u32], "\u{21DB}")),
-    ("rArr;", Ncr::new([8658u32], "\u{21D2}")),
-    ("rAtail;", Ncr::new([10524u32], "\u{291C}")),
-    ("rBarr;", Ncr::new([10511u32], "\u{290F}")),
-    ("rHar;", Ncr::new([10596u32], "\u{2964}")),
-    // FIXME: ("race;", Ncr::new(   [8765, 817u32], "\u{223D}\u{0331}")),
-    ("racute;", Ncr::new([341u32], "\u{0155}")),
-    ("radic;", Ncr::new([8730u32], "\u{221A}")),
-    ("raemptyv;", Ncr::new([10675u32], "\u{29B3}")),
-    ("rang;", Ncr::new([10217u32], "\u{27E9}")),
-    ("rangd;", Ncr::new([10642u32], "\u{2992}")),
-    ("range;", Ncr::new([10661u32], "\u{29A5}")),
-    ("rangle;", Ncr::new([10217u32], "\u{27E9}")),
-    ("raquo", Ncr::new([187u32], "\u{00BB}")),
-    ("raquo;", Ncr::new([187u32], "\u{00BB}")),
-    ("rarr;", Ncr::new([8594u32], "\u{2192}")),
-    ("rarrap;", Ncr::new([10613u32], "\u{2975}")),
-    ("rarrb;", Ncr::new([8677u32], "\u{21E5}")),
-    ("rarrbfs;", Ncr::new([10528u32], "\u{2920}")),
-    ("rarrc;", Ncr::new([10547u32], "\u{2933}")),
-    ("rarrfs;", Ncr::new([10526u32], "\u{291E}")),
-    ("rarrhk;", Ncr::new([8618u32], "\u{21AA}")),
-    ("rarrlp;", Ncr::new([8620u32], "\u{21AC}")),
-    ("rarrpl;", Ncr::new([10565u32], "\u{2945}")),
-    ("rarrsim;", Ncr::new([10612u32], "\u{2974}")),
-    ("rarrtl;", Ncr::new([8611u32], "\u{21A3}")),
-    ("rarrw;", Ncr::new([8605u32], "\u{219D}")),
-    ("ratail;", Ncr::new([10522u32], "\u{291A}")),
-    ("ratio;", Ncr::new([8758u32], "\u{2236}")),
-    ("rationals;", Ncr::new([8474u32], "\u{211A}")),
-    ("rbarr;", Ncr::new([10509u32], "\u{290D}")),
-    ("rbbrk;", Ncr::new([10099u32], "\u{2773}")),
-    ("rbrace;", Ncr::new([125u32], "\u{007D}")),
-    ("rbrack;", Ncr::new([93u32], "\u{005D}")),
-    ("rbrke;", Ncr::new([10636u32], "\u{298C}")),
-    ("rbrksld;", Ncr::new([10638u32], "\u{298E}")),
-    ("rbrkslu;", Ncr::new([10640u32], "\u{2990}")),
-    ("rcaron;", Ncr::new([345u32], "\u{0159}")),
-    ("rcedil;", Ncr::new([343u32], "\u{0157}")),
-    ("rceil;", Ncr::new([8969u32], "\u{2309}")),
-    ("rcub;", Ncr::new([125u32], "\u{007D}")),
-    ("rcy;", Ncr::new([1088u32], "\u{0440}")),
-    ("rdca;", Ncr::new([10551u32], "\u{2937}")),
-    ("rdldhar;", Ncr::new([10601u32], "\u{2969}")),
-    ("rdquo;", Ncr::new([8221u32], "\u{201D}")),
-    ("rdquor;", Ncr::new([8221u32], "\u{201D}")),
-    ("rdsh;", Ncr::new([8627u32], "\u{21B3}")),
-    ("real;", Ncr::new([8476u32], "\u{211C}")),
-    ("realine;", Ncr::new([8475u32], "\u{211B}")),
-    ("realpart;", Ncr::new([8476u32], "\u{211C}")),
-    ("reals;", Ncr::new([8477u32], "\u{211D}")),
-    ("rect;", Ncr::new([9645u32], "\u{25AD}")),
-    ("reg", Ncr::new([174u32], "\u{00AE}")),
-    ("reg;", Ncr::new([174u32], "\u{00AE}")),
-    ("rfisht;", Ncr::new([10621u32], "\u{297D}")),
-    ("rfloor;", Ncr::new([8971u32], "\u{230B}")),
-    // FIXME: ("rfr;", Ncr::new(  [120111u32], "\u{D835}\u{DD2F}")),
-    ("rhard;", Ncr::new([8641u32], "\u{21C1}")),
-    ("rharu;", Ncr::new([8640u32], "\u{21C0}")),
-    ("rharul;", Ncr::new([10604u32], "\u{296C}")),
-    ("rho;", Ncr::new([961u32], "\u{03C1}")),
-    ("rhov;", Ncr::new([1009u32], "\u{03F1}")),
-    ("rightarrow;", Ncr::new([8594u32], "\u{2192}")),
-    ("rightarrowtail;", Ncr::new([8611u32], "\u{21A3}")),
-    ("rightharpoondown;", Ncr::new([8641u32], "\u{21C1}")),
-    ("rightharpoonup;", Ncr::new([8640u32], "\u{21C0}")),
-    ("rightleftarrows;", Ncr::new([8644u32], "\u{21C4}")),
-    ("rightleftharpoons;", Ncr::new([8652u32], "\u{21CC}")),
-    ("rightrightarrows;", Ncr::new([8649u32], "\u{21C9}")),
-    ("rightsquigarrow;", Ncr::new([8605u32], "\u{219D}")),
-    ("rightthreetimes;", Ncr::new([8908u32], "\u{22CC}")),
-    ("ring;", Ncr::new([730u32], "\u{02DA}")),
-    ("risingdotseq;", Ncr::new([8787u32], "\u{2253}")),
-    ("rlarr;", Ncr::new([8644u32], "\u{21C4}")),
-    ("rlhar;", Ncr::new([8652u32], "\u{21CC}")),
-    ("rlm;", Ncr::new([8207u32], "\u{200F}")),
-    ("rmoust;", Ncr::new([9137u32], "\u{23B1}")),
-    ("rmoustache;", Ncr::new([9137u32], "\u{23B1}")),
-    ("rnmid;", Ncr::new([10990u32], "\u{2AEE}")),
-    ("roang;", Ncr::new([10221u32], "\u{27ED}")),
-    ("roarr;", Ncr::new([8702u32], "\u{21FE}")),
-    ("robrk;", Ncr::new([10215u32], "\u{27E7}")),
-    ("ropar;", Ncr::new([10630u32], "\u{2986}")),
-    // FIXME: ("ropf;", Ncr::new(   [120163u32], "\u{D835}\u{DD63}")),
-    ("roplus;", Ncr::new([10798u32], "\u{2A2E}")),
-    ("rotimes;", Ncr::new([10805u32], "\u{2A35}")),
-    ("rpar;", Ncr::new([41u32], "\u{0029}")),
-    ("rpargt;", Ncr::new([10644u32], "\u{2994}")),
-    ("rppolint;", Ncr::new([10770u32], "\u{2A12}")),
-    ("rrarr;", Ncr::new([8649u32], "\u{21C9}")),
-    ("rsaquo;", Ncr::new([8250u32], "\u{203A}")),
-    // FIXME: ("rscr;", Ncr::new(   [120007u32], "\u{D835}\u{DCC7}")),
-    ("rsh;", Ncr::new([8625u32], "\u{21B1}")),
-    ("rsqb;", Ncr::new([93u32], "\u{005D}")),
-    ("rsquo;", Ncr::new([8217u32], "\u{2019}")),
-    ("rsquor;", Ncr::new([8217u32], "\u{2019}")),
-    ("rthree;", Ncr::new([8908u32], "\u{22CC}")),
-    ("rtimes;", Ncr::new([8906u32], "\u{22CA}")),
-    ("rtri;", Ncr::new([9657u32], "\u{25B9}")),
-    ("rtrie;", Ncr::new([8885u32], "\u{22B5}")),
-    ("rtrif;", Ncr::new([9656u32], "\u{25B8}")),
-    ("rtriltri;", Ncr::new([10702u32], "\u{29CE}")),
-    ("ruluhar;", Ncr::new([10600u32], "\u{2968}")),
-    ("rx;", Ncr::new([8478u32], "\u{211E}")),
-    ("sacute;", Ncr::new([347u32], "\u{015B}")),
-    ("sbquo;", Ncr::new([8218u32], "\u{201A}")),
-    ("sc;", Ncr::new([8827u32], "\u{227B}")),
-    ("scE;", Ncr::new([10932u32], "\u{2AB4}")),
-    ("scap;", Ncr::new([10936u32], "\u{2AB8}")),
-    ("scaron;", Ncr::new([353u32], "\u{0161}")),
-    ("sccue;", Ncr::new([8829u32], "\u{227D}")),
-    ("sce;", Ncr::new([10928u32], "\u{2AB0}")),
-    ("scedil;", Ncr::new([351u32], "\u{015F}")),
-    ("scirc;", Ncr::new([349u32], "\u{015D}")),
-    ("scnE;", Ncr::new([10934u32], "\u{2AB6}")),
-    ("scnap;", Ncr::new([10938u32], "\u{2ABA}")),
-    ("scnsim;", Ncr::new([8937u32], "\u{22E9}")),
-    ("scpolint;", Ncr::new([10771u32], "\u{2A13}")),
-    ("scsim;", Ncr::new([8831u32], "\u{227F}")),
-    ("scy;", Ncr::new([1089u32], "\u{0441}")),
-    ("sdot;", Ncr::new([8901u32], "\u{22C5}")),
-    ("sdotb;", Ncr::new([8865u32], "\u{22A1}")),
-    ("sdote;", Ncr::new([10854u32], "\u{2A66}")),
-    ("seArr;", Ncr::new([8664u32], "\u{21D8}")),
-    ("searhk;", Ncr::new([10533u32], "\u{2925}")),
-    ("searr;", Ncr::new([8600u32], "\u{2198}")),
-    ("searrow;", Ncr::new([8600u32], "\u{2198}")),
-    ("sect", Ncr::new([167u32], "\u{00A7}")),
-    ("sect;", Ncr::new([167u32], "\u{00A7}")),
-    ("semi;", Ncr::new([59u32], "\u{003B}")),
-    ("seswar;", Ncr::new([10537u32], "\u{2929}")),
-    ("setminus;", Ncr::new([8726u32], "\u{2216}")),
-    ("setmn;", Ncr::new([8726u32], "\u{2216}")),
-    ("sext;", Ncr::new([10038u32], "\u{2736}")),
-    // FIXME: ("sfr;", Ncr::new(  [120112u32], "\u{D835}\u{DD30}")),
-    ("sfrown;", Ncr::new([8994u32], "\u{2322}")),
-    ("sharp;", Ncr::new([9839u32], "\u{266F}")),
-    ("shchcy;", Ncr::new([1097u32], "\u{0449}")),
-    ("shcy;", Ncr::new([1096u32], "\u{0448}")),
-    ("shortmid;", Ncr::new([8739u32], "\u{2223}")),
-    ("shortparallel;", Ncr::new([8741u32], "\u{2225}")),
-    ("shy", Ncr::new([173u32], "\u{00AD}")),
-    ("shy;", Ncr::new([173u32], "\u{00AD}")),
-    ("sigma;", Ncr::new([963u32], "\u{03C3}")),
-    ("sigmaf;", Ncr::new([962u32], "\u{03C2}")),
-    ("sigmav;", Ncr::new([962u32], "\u{03C2}")),
-    ("sim;", Ncr::new([8764u32], "\u{223C}")),
-    ("simdot;", Ncr::new([10858u32], "\u{2A6A}")),
-    ("sime;", Ncr::new([8771u32], "\u{2243}")),
-    ("simeq;", Ncr::new([8771u32], "\u{2243}")),
-    ("simg;", Ncr::new([10910u32], "\u{2A9E}")),
-    ("simgE;", Ncr::new([10912u32], "\u{2AA0}")),
-    ("siml;", Ncr::new([10909u32], "\u{2A9D}")),
-    ("simlE;", Ncr::new([10911u32], "\u{2A9F}")),
-    ("simne;", Ncr::new([8774u32], "\u{2246}")),
-    ("simplus;", Ncr::new([10788u32], "\u{2A24}")),
-    ("simrarr;", Ncr::new([10610u32], "\u{2972}")),
-    ("slarr;", Ncr::new([8592u32], "\u{2190}")),
-    ("smallsetminus;", Ncr::new([8726u32], "\u{2216}")),
-    ("smashp;", Ncr::new([10803u32], "\u{2A33}")),
-    ("smeparsl;", Ncr::new([10724u32], "\u{29E4}")),
-    ("smid;", Ncr::new([8739u32], "\u{2223}")),
-    ("smile;", Ncr::new([8995u32], "\u{2323}")),
-    ("smt;", Ncr::new([10922u32], "\u{2AAA}")),
-    ("smte;", Ncr::new([10924u32], "\u{2AAC}")),
-    // FIXME: ("smtes;", Ncr::new(  [10924, 65024u32], "\u{2AAC}\u{FE00}")),
-    ("softcy;", Ncr::new([1100u32], "\u{044C}")),
-    ("sol;", Ncr::new([47u32], "\u{002F}")),
-    ("solb;", Ncr::new([10692u32], "\u{29C4}")),
-    ("solbar;", Ncr::new([9023u32], "\u{233F}")),
-    // FIXME: ("sopf;", Ncr::new(   [120164u32], "\u{D835}\u{DD64}")),
-    ("spades;", Ncr::new([9824u32], "\u{2660}")),
-    ("spadesuit;", Ncr::new([9824u32], "\u{2660}")),
-    ("spar;", Ncr::new([8741u32], "\u{2225}")),
-    ("sqcap;", Ncr::new([8851u32], "\u{2293}")),
-    // FIXME: ("sqcaps;", Ncr::new(   [8851, 65024u32], "\u{2293}\u{FE00}")),
-    ("sqcup;", Ncr::new([8852u32], "\u{2294}")),
-    // FIXME: ("sqcups;", Ncr::new(   [8852, 65024u32], "\u{2294}\u{FE00}")),
-    ("sqsub;", Ncr::new([8847u32], "\u{228F}")),
-    ("sqsube;", Ncr::new([8849u32], "\u{2291}")),
-    ("sqsubset;", Ncr::new([8847u32], "\u{228F}")),
-    ("sqsubseteq;", Ncr::new([8849u32], "\u{2291}")),
-    ("sqsup;", Ncr::new([8848u32], "\u{2290}")),
-    ("sqsupe;", Ncr::new([8850u32], "\u{2292}")),
-    ("sqsupset;", Ncr::new([8848u32], "\u{2290}")),
-    ("sqsupseteq;", Ncr::new([8850u32], "\u{2292}")),
-    ("squ;", Ncr::new([9633u32], "\u{25A1}")),
-    ("square;", Ncr::new([9633u32], "\u{25A1}")),
-    ("squarf;", Ncr::new([9642u32], "\u{25AA}")),
-    ("squf;", Ncr::new([9642u32], "\u{25AA}")),
-    ("srarr;", Ncr::new([8594u32], "\u{2192}")),
-    // FIXME: ("sscr;", Ncr::new(   [120008u32], "\u{D835}\u{DCC8}")),
-    ("ssetmn;", Ncr::new([8726u32], "\u{2216}")),
-    ("ssmile;", Ncr::new([8995u32], "\u{2323}")),
-    ("sstarf;", Ncr::new([8902u32], "\u{22C6}")),
-    ("star;", Ncr::new([9734u32], "\u{2606}")),
-    ("starf;", Ncr::new([9733u32], "\u{2605}")),
-    ("straightepsilon;", Ncr::new([1013u32], "\u{03F5}")),
-    ("straightphi;", Ncr::new([981u32], "\u{03D5}")),
-    ("strns;", Ncr::new([175u32], "\u{00AF}")),
-    ("sub;", Ncr::new([8834u32], "\u{2282}")),
-    ("subE;", Ncr::new([10949u32], "\u{2AC5}")),
-    ("subdot;", Ncr::new([10941u32], "\u{2ABD}")),
-    ("sube;", Ncr::new([8838u32], "\u{2286}")),
-    ("subedot;", Ncr::new([10947u32], "\u{2AC3}")),
-    ("submult;", Ncr::new([10945u32], "\u{2AC1}")),
-    ("subnE;", Ncr::new([10955u32], "\u{2ACB}")),
-    ("subne;", Ncr::new([8842u32], "\u{228A}")),
-    ("subplus;", Ncr::new([10943u32], "\u{2ABF}")),
-    ("subrarr;", Ncr::new([10617u32], "\u{2979}")),
-    ("subset;", Ncr::new([8834u32], "\u{2282}")),
-    ("subseteq;", Ncr::new([8838u32], "\u{2286}")),
-    ("subseteqq;", Ncr::new([10949u32], "\u{2AC5}")),
-    ("subsetneq;", Ncr::new([8842u32], "\u{228A}")),
-    ("subsetneqq;", Ncr::new([10955u32], "\u{2ACB}")),
-    ("subsim;", Ncr::new([10951u32], "\u{2AC7}")),
-    ("subsub;", Ncr::new([10965u32], "\u{2AD5}")),
-    ("subsup;", Ncr::new([10963u32], "\u{2AD3}")),
-    ("succ;", Ncr::new([8827u32], "\u{227B}")),
-    ("succapprox;", Ncr::new([10936u32], "\u{2AB8}")),
-    ("succcurlyeq;", Ncr::new([8829u32], "\u{227D}")),
-    ("succeq;", Ncr::new([10928u32], "\u{2AB0}")),
-    ("succnapprox;", Ncr::new([10938u32], "\u{2ABA}")),
-    ("succneqq;", Ncr::new([10934u32], "\u{2AB6}")),
-    ("succnsim;", Ncr::new([8937u32], "\u{22E9}")),
-    ("succsim;", Ncr::new([8831u32], "\u{227F}")),
-    ("sum;", Ncr::new([8721u32], "\u{2211}")),
-    ("sung;", Ncr::new([9834u32], "\u{266A}")),
-    ("sup1", Ncr::new([185u32], "\u{00B9}")),
-    ("sup1;", Ncr::new([185u32], "\u{00B9}")),
-    ("sup2", Ncr::new([178u32], "\u{00B2}")),
-    ("sup2;", Ncr::new([178u32], "\u{00B2}")),
-    ("sup3", Ncr::new([179u32], "\u{00B3}")),
-    ("sup3;", Ncr::new([179u32], "\u{00B3}")),
-    ("sup;", Ncr::new([8835u32], "\u{2283}")),
-    ("supE;", Ncr::new([10950u32], "\u{2AC6}")),
-    ("supdot;", Ncr::new([10942u32], "\u{2ABE}")),
-    ("supdsub;", Ncr::new([10968u32], "\u{2AD8}")),
-    ("supe;", Ncr::new([8839u32], "\u{2287}")),
-    ("supedot;", Ncr::new([10948u32], "\u{2AC4}")),
-    ("suphsol;", Ncr::new([10185u32], "\u{27C9}")),
-    ("suphsub;", Ncr::new([10967u32], "\u{2AD7}")),
-    ("suplarr;", Ncr::new([10619u32], "\u{297B}")),
-    ("supmult;", Ncr::new([10946u32], "\u{2AC2}")),
-    ("supnE;", Ncr::new([10956u32], "\u{2ACC}")),
-    ("supne;", Ncr::new([8843u32], "\u{228B}")),
-    ("supplus;", Ncr::new([10944u32], "\u{2AC0}")),
-    ("supset;", Ncr::new([8835u32], "\u{2283}")),
-    ("supseteq;", Ncr::new([8839u32], "\u{2287}")),
-    ("supseteqq;", Ncr::new([10950u32], "\u{2AC6}")),
-    ("supsetneq;", Ncr::new([8843u32], "\u{228B}")),
-    ("supsetneqq;", Ncr::new([10956u32], "\u{2ACC}")),
-    ("supsim;", Ncr::new([10952u32], "\u{2AC8}")),
-    ("supsub;", Ncr::new([10964u32], "\u{2AD4}")),
-    ("supsup;", Ncr::new([10966u32], "\u{2AD6}")),
-    ("swArr;", Ncr::new([8665u32], "\u{21D9}")),
-    ("swarhk;", Ncr::new([10534u32], "\u{2926}")),
-    ("swarr;", Ncr::new([8601u32], "\u{2199}")),
-    ("swarrow;", Ncr::new([8601u32], "\u{2199}")),
-    ("swnwar;", Ncr::new([10538u32], "\u{292A}")),
-    ("szlig", Ncr::new([223u32], "\u{00DF}")),
-    ("szlig;", Ncr::new([223u32], "\u{00DF}")),
-    ("target;", Ncr::new([8982u32], "\u{2316}")),
-    ("tau;", Ncr::new([964u32], "\u{03C4}")),
-    ("tbrk;", Ncr::new([9140u32], "\u{23B4}")),
-    ("tcaron;", Ncr::new([357u32], "\u{0165}")),
-    ("tcedil;", Ncr::new([355u32], "\u{0163}")),
-    ("tcy;", Ncr::new([1090u32], "\u{0442}")),
-    ("tdot;", Ncr::new([8411u32], "\u{20DB}")),
-    ("telrec;", Ncr::new([8981u32], "\u{2315}")),
-    // FIXME: ("tfr;", Ncr::new(  [120113u32], "\u{D835}\u{DD31}")),
-    ("there4;", Ncr::new([8756u32], "\u{2234}")),
-    ("therefore;", Ncr::new([8756u32], "\u{2234}")),
-    ("theta;", Ncr::new([952u32], "\u{03B8}")),
-    ("thetasym;", Ncr::new([977u32], "\u{03D1}")),
-    ("thetav;", Ncr::new([977u32], "\u{03D1}")),
-    ("thickapprox;", Ncr::new([8776u32], "\u{2248}")),
-    ("thicksim;", Ncr::new([8764u32], "\u{223C}")),
-    ("thinsp;", Ncr::new([8201u32], "\u{2009}")),
-    ("thkap;", Ncr::new([8776u32], "\u{2248}")),
-    ("thksim;", Ncr::new([8764u32], "\u{223C}")),
-    ("thorn", Ncr::new([254u32], "\u{00FE}")),
-    ("thorn;", Ncr::new([254u32], "\u{00FE}")),
-    ("tilde;", Ncr::new([732u32], "\u{02DC}")),
-    ("times", Ncr::new([215u32], "\u{00D7}")),
-    ("times;", Ncr::new([215u32], "\u{00D7}")),
-    ("timesb;", Ncr::new([8864u32], "\u{22A0}")),
-    ("timesbar;", Ncr::new([10801u32], "\u{2A31}")),
-    ("timesd;", Ncr::new([10800u32], "\u{2A30}")),
-    ("tint;", Ncr::new([8749u32], "\u{222D}")),
-    ("toea;", Ncr::new([10536u32], "\u{2928}")),
-    ("top;", Ncr::new([8868u32], "\u{22A4}")),
-    ("topbot;", Ncr::new([9014u32], "\u{2336}")),
-    ("topcir;", Ncr::new([10993u32], "\u{2AF1}")),
-    // FIXME: ("topf;", Ncr::new(   [120165u32], "\u{D835}\u{DD65}")),
-    ("topfork;", Ncr::new([10970u32], "\u{2ADA}")),
-    ("tosa;", Ncr::new([10537u32], "\u{2929}")),
-    ("tprime;", Ncr::new([8244u32], "\u{2034}")),
-    ("trade;", Ncr::new([8482u32], "\u{2122}")),
-    ("triangle;", Ncr::new([9653u32], "\u{25B5}")),
-    ("triangledown;", Ncr::new([9663u32], "\u{25BF}")),
-    ("triangleleft;", Ncr::new([9667u32], "\u{25C3}")),
-    ("trianglelefteq;", Ncr::new([8884u32], "\u{22B4}")),
-    ("triangleq;", Ncr::new([8796u32], "\u{225C}")),
-    ("triangleright;", Ncr::new([9657u32], "\u{25B9}")),
-    ("trianglerighteq;", Ncr::new([8885u32], "\u{22B5}")),
-    ("tridot;", Ncr::new([9708u32], "\u{25EC}")),
-    ("trie;", Ncr::new([8796u32], "\u{225C}")),
-    ("triminus;", Ncr::new([10810u32], "\u{2A3A}")),
-    ("triplus;", Ncr::new([10809u32], "\u{2A39}")),
-    ("trisb;", Ncr::new([10701u32], "\u{29CD}")),
-    ("tritime;", Ncr::new([10811u32], "\u{2A3B}")),
-    ("trpezium;", Ncr::new([9186u32], "\u{23E2}")),
-    // FIXME: ("tscr;", Ncr::new(   [120009u32], "\u{D835}\u{DCC9}")),
-    ("tscy;", Ncr::new([1094u32], "\u{0446}")),
-    ("tshcy;", Ncr::new([1115u32], "\u{045B}")),
-    ("tstrok;", Ncr::new([359u32], "\u{0167}")),
-    ("twixt;", Ncr::new([8812u32], "\u{226C}")),
-    ("twoheadleftarrow;", Ncr::new([8606u32], "\u{219E}")),
-    ("twoheadrightarrow;", Ncr::new([8608u32], "\u{21A0}")),
-    ("uArr;", Ncr::new([8657u32], "\u{21D1}")),
-    ("uHar;", Ncr::new([10595u32], "\u{2963}")),
-    ("uacute", Ncr::new([250u32], "\u{00FA}")),
-    ("uacute;", Ncr::new([250u32], "\u{00FA}")),
-    ("uarr;", Ncr::new([8593u32], "\u{2191}")),
-    ("ubrcy;", Ncr::new([1118u32], "\u{045E}")),
-    ("ubreve;", Ncr::new([365u32], "\u{016D}")),
-    ("ucirc", Ncr::new([251u32], "\u{00FB}")),
-    ("ucirc;", Ncr::new([251u32], "\u{00FB}")),
-    ("ucy;", Ncr::new([1091u32], "\u{0443}")),
-    ("udarr;", Ncr::new([8645u32], "\u{21C5}")),
-    ("udblac;", Ncr::new([369u32], "\u{0171}")),
-    ("udhar;", Ncr::new([10606u32], "\u{296E}")),
-    ("ufisht;", Ncr::new([10622u32], "\u{297E}")),
-    // FIXME: ("ufr;", Ncr::new(  [120114u32], "\u{D835}\u{DD32}")),
-    ("ugrave", Ncr::new([249u32], "\u{00F9}")),
-    ("ugrave;", Ncr::new([249u32], "\u{00F9}")),
-    ("uharl;", Ncr::new([8639u32], "\u{21BF}")),
-    ("uharr;", Ncr::new([8638u32], "\u{21BE}")),
-    ("uhblk;", Ncr::new([9600u32], "\u{2580}")),
-    ("ulcorn;", Ncr::new([8988u32], "\u{231C}")),
-    ("ulcorner;", Ncr::new([8988u32], "\u{231C}")),
-    ("ulcrop;", Ncr::new([8975u32], "\u{230F}")),
-    ("ultri;", Ncr::new([9720u32], "\u{25F8}")),
-    ("umacr;", Ncr::new([363u32], "\u{016B}")),
-    ("uml", Ncr::new([168u32], "\u{00A8}")),
-    ("uml;", Ncr::new([168u32], "\u{00A8}")),
-    ("uogon;", Ncr::new([371u32], "\u{0173}")),
-    // FIXME: ("uopf;", Ncr::new(   [120166u32], "\u{D835}\u{DD66}")),
-    ("uparrow;", Ncr::new([8593u32], "\u{2191}")),
-    ("updownarrow;", Ncr::new([8597u32], "\u{2195}")),
-    ("upharpoonleft;", Ncr::new([8639u32], "\u{21BF}")),
-    ("upharpoonright;", Ncr::new([8638u32], "\u{21BE}")),
-    ("uplus;", Ncr::new([8846u32], "\u{228E}")),
-    ("upsi;", Ncr::new([965u32], "\u{03C5}")),
-    ("upsih;", Ncr::new([978u32], "\u{03D2}")),
-    ("upsilon;", Ncr::new([965u32], "\u{03C5}")),
-    ("upuparrows;", Ncr::new([8648u32], "\u{21C8}")),
-    ("urcorn;", Ncr::new([8989u32], "\u{231D}")),
-    ("urcorner;", Ncr::new([8989u32], "\u{231D}")),
-    ("urcrop;", Ncr::new([8974u32], "\u{230E}")),
-    ("uring;", Ncr::new([367u32], "\u{016F}")),
-    ("urtri;", Ncr::new([9721u32], "\u{25F9}")),
-    // FIXME: ("uscr;", Ncr::new(   [120010u32], "\u{D835}\u{DCCA}")),
-    ("utdot;", Ncr::new([8944u32], "\u{22F0}")),
-    ("utilde;", Ncr::new([361u32], "\u{0169}")),
-    ("utri;", Ncr::new([9653u32], "\u{25B5}")),
-    ("utrif;", Ncr::new([9652u32], "\u{25B4}")),
-    ("uuarr;", Ncr::new([8648u32], "\u{21C8}")),
-    ("uuml", Ncr::new([252u32], "\u{00FC}")),
-    ("uuml;", Ncr::new([252u32], "\u{00FC}")),
-    ("uwangle;", Ncr::new([10663u32], "\u{29A7}")),
-    ("vArr;", Ncr::new([8661u32], "\u{21D5}")),
-    ("vBar;", Ncr::new([10984u32], "\u{2AE8}")),
-    ("vBarv;", Ncr::new([10985u32], "\u{2AE9}")),
-    ("vDash;", Ncr::new([8872u32], "\u{22A8}")),
-    ("vangrt;", Ncr::new([10652u32], "\u{299C}")),
-    ("varepsilon;", Ncr::new([1013u32], "\u{03F5}")),
-    ("varkappa;", Ncr::new([1008u32], "\u{03F0}")),
-    ("varnothing;", Ncr::new([8709u32], "\u{2205}")),
-    ("varphi;", Ncr::new([981u32], "\u{03D5}")),
-    ("varpi;", Ncr::new([982u32], "\u{03D6}")),
-    ("varpropto;", Ncr::new([8733u32], "\u{221D}")),
-    ("varr;", Ncr::new([8597u32], "\u{2195}")),
-    ("varrho;", Ncr::new([1009u32], "\u{03F1}")),
-    ("varsigma;", Ncr::new([962u32], "\u{03C2}")),
-    // FIXME: ("varsubsetneq;", Ncr::new(   [8842, 65024u32], "\u{228A}\u{FE00}")),
-    // FIXME: ("varsubsetneqq;", Ncr::new(  [10955, 65024u32], "\u{2ACB}\u{FE00}")),
-    // FIXME: ("varsupsetneq;", Ncr::new(   [8843, 65024u32], "\u{228B}\u{FE00}")),
-    // FIXME: ("varsupsetneqq;", Ncr::new(  [10956, 65024u32], "\u{2ACC}\u{FE00}")),
-    ("vartheta;", Ncr::new([977u32], "\u{03D1}")),
-    ("vartriangleleft;", Ncr::new([8882u32], "\u{22B2}")),
-    ("vartriangleright;", Ncr::new([8883u32], "\u{22B3}")),
-    ("vcy;", Ncr::new([1074u32], "\u{0432}")),
-    ("vdash;", Ncr::new([8866u32], "\u{22A2}")),
-    ("vee;", Ncr::new([8744u32], "\u{2228}")),
-    ("veebar;", Ncr::new([8891u32], "\u{22BB}")),
-    ("veeeq;", Ncr::new([8794u32], "\u{225A}")),
-    ("vellip;", Ncr::new([8942u32], "\u{22EE}")),
-    ("verbar;", Ncr::new([124u32], "\u{007C}")),
-    ("vert;", Ncr::new([124u32], "\u{007C}")),
-    // FIXME: ("vfr;", Ncr::new(  [120115u32], "\u{D835}\u{DD33}")),
-    ("vltri;", Ncr::new([8882u32], "\u{22B2}")),
-    // FIXME: ("vnsub;", Ncr::new(  [8834, 8402u32], "\u{2282}\u{20D2}")),
-    // FIXME: ("vnsup;", Ncr::new(  [8835, 8402u32], "\u{2283}\u{20D2}")),
-    // FIXME: ("vopf;", Ncr::new(   [120167u32], "\u{D835}\u{DD67}")),
-    ("vprop;", Ncr::new([8733u32], "\u{221D}")),
-    ("vrtri;", Ncr::new([8883u32], "\u{22B3}")),
-    // FIXME: ("vscr;", Ncr::new(   [120011u32], "\u{D835}\u{DCCB}")),
-    // FIXME: ("vsubnE;", Ncr::new(   [10955, 65024u32], "\u{2ACB}\u{FE00}")),
-    // FIXME: ("vsubne;", Ncr::new(   [8842, 65024u32], "\u{228A}\u{FE00}")),
-    // FIXME: ("vsupnE;", Ncr::new(   [10956, 65024u32], "\u{2ACC}\u{FE00}")),
-    // FIXME: ("vsupne;", Ncr::new(   [8843, 65024u32], "\u{228B}\u{FE00}")),
-    ("vzigzag;", Ncr::new([10650u32], "\u{299A}")),
-    ("wcirc;", Ncr::new([373u32], "\u{0175}")),
-    ("wedbar;", Ncr::new([10847u32], "\u{2A5F}")),
-    ("wedge;", Ncr::new([8743u32], "\u{2227}")),
-    ("wedgeq;", Ncr::new([8793u32], "\u{2259}")),
-    ("weierp;", Ncr::new([8472u32], "\u{2118}")),
-    // FIXME: ("wfr;", Ncr::new(  [120116u32], "\u{D835}\u{DD34}")),
-    // FIXME: ("wopf;", Ncr::new(   [120168u32], "\u{D835}\u{DD68}")),
-    ("wp;", Ncr::new([8472u32], "\u{2118}")),
-    ("wr;", Ncr::new([8768u32], "\u{2240}")),
-    ("wreath;", Ncr::new([8768u32], "\u{2240}")),
-    // FIXME: ("wscr;", Ncr::new(   [120012u32], "\u{D835}\u{DCCC}")),
-    ("xcap;", Ncr::new([8898u32], "\u{22C2}")),
-    ("xcirc;", Ncr::new([9711u32], "\u{25EF}")),
-    ("xcup;", Ncr::new([8899u32], "\u{22C3}")),
-    ("xdtri;", Ncr::new([9661u32], "\u{25BD}")),
-    // FIXME: ("xfr;", Ncr::new(  [120117u32], "\u{D835}\u{DD35}")),
-    ("xhArr;", Ncr::new([10234u32], "\u{27FA}")),
-    ("xharr;", Ncr::new([10231u32], "\u{27F7}")),
-    ("xi;", Ncr::new([958u32], "\u{03BE}")),
-    ("xlArr;", Ncr::new([10232u32], "\u{27F8}")),
-    ("xlarr;", Ncr::new([10229u32], "\u{27F5}")),
-    ("xmap;", Ncr::new([10236u32], "\u{27FC}")),
-    ("xnis;", Ncr::new([8955u32], "\u{22FB}")),
-    ("xodot;", Ncr::new([10752u32], "\u{2A00}")),
-    // FIXME: ("xopf;", Ncr::new(   [120169u32], "\u{D835}\u{DD69}")),
-    ("xoplus;", Ncr::new([10753u32], "\u{2A01}")),
-    ("xotime;", Ncr::new([10754u32], "\u{2A02}")),
-    ("xrArr;", Ncr::new([10233u32], "\u{27F9}")),
-    ("xrarr;", Ncr::new([10230u32], "\u{27F6}")),
-    // FIXME: ("xscr;", Ncr::new(   [120013u32], "\u{D835}\u{DCCD}")),
-    ("xsqcup;", Ncr::new([10758u32], "\u{2A06}")),
-    ("xuplus;", Ncr::new([10756u32], "\u{2A04}")),
-    ("xutri;", Ncr::new([9651u32], "\u{25B3}")),
-    ("xvee;", Ncr::new([8897u32], "\u{22C1}")),
-    ("xwedge;", Ncr::new([8896u32], "\u{22C0}")),
-    ("yacute", Ncr::new([253u32], "\u{00FD}")),
-    ("yacute;", Ncr::new([253u32], "\u{00FD}")),
-    ("yacy;", Ncr::new([1103u32], "\u{044F}")),
-    ("ycirc;", Ncr::new([375u32], "\u{0177}")),
-    ("ycy;", Ncr::new([1099u32], "\u{044B}")),
-    ("yen", Ncr::new([165u32], "\u{00A5}")),
-    ("yen;", Ncr::new([165u32], "\u{00A5}")),
-    // FIXME: ("yfr;", Ncr::new(  [120118u32], "\u{D835}\u{DD36}")),
-    ("yicy;", Ncr::new([1111u32], "\u{0457}")),
-    // FIXME: ("yopf;", Ncr::new(   [120170u32], "\u{D835}\u{DD6A}")),
-    // FIXME: ("yscr;", Ncr::new(   [120014u32], "\u{D835}\u{DCCE}")),
-    ("yucy;", Ncr::new([1102u32], "\u{044E}")),
-    ("yuml", Ncr::new([255u32], "\u{00FF}")),
-    ("yuml;", Ncr::new([255u32], "\u{00FF}")),
-    ("zacute;", Ncr::new([378u32], "\u{017A}")),
-    ("zcaron;", Ncr::new([382u32], "\u{017E}")),
-    ("zcy;", Ncr::new([1079u32], "\u{0437}")),
-    ("zdot;", Ncr::new([380u32], "\u{017C}")),
-    ("zeetrf;", Ncr::new([8488u32], "\u{2128}")),
-    ("zeta;", Ncr::new([950u32], "\u{03B6}")),
-    // FIXME: ("zfr;", Ncr::new(  [120119u32], "\u{D835}\u{DD37}")),
-    ("zhcy;", Ncr::new([1078u32], "\u{0436}")),
-    ("zigrarr;", Ncr::new([8669u32], "\u{21DD}")),
-    // FIXME: ("zopf;", Ncr::new(   [120171u32], "\u{D835}\u{DD6B}")),
-    // FIXME: ("zscr;", Ncr::new(   [120015u32], "\u{D835}\u{DCCF}")),
-    ("zwj;", Ncr::new([8205u32], "\u{200D}")),
-    ("zwnj;", Ncr::new([8204u32], "\u{200C}")),
-];
+    #[test]
+    fn tricky_entries() {
+        assert_eq!(characters("NotSquareSupersetEqual;"), Some("\u{22E3}"));
+        // NOTE: Some references expand to two code points.
+        assert_eq!(characters("fjlig;"), Some("fj"));
+        assert_eq!(characters("acE;"), Some("\u{223E}\u{0333}"));
+        // NOTE: Only some legacy references may leave out the semicolon.
+        assert_eq!(characters("not"), Some("\u{00AC}"));
+        assert_eq!(characters("AMP"), Some("&"));
+        assert_eq!(characters("notin"), None);
+        assert_eq!(characters("fjlig"), None);
+    }
+
+    #[test]
+    fn longest_match_prefers_the_longest_identifier() {
+        assert_eq!(
+            longest_match("notin;x").map(|(matched, _)| matched),
+            Some("notin;")
+        );
+        assert_eq!(
+            longest_match("notit;").map(|(matched, _)| matched),
+            Some("not")
+        );
+        assert_eq!(
+            longest_match("copyé").map(|(matched, _)| matched),
+            Some("copy")
+        );
+        assert_eq!(longest_match("unknown;"), None);
+        assert_eq!(longest_match(""), None);
+    }
+}
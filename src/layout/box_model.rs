@@ -0,0 +1,649 @@
+use crate::css::length::{LengthPercentage, LengthPercentageAuto, Sides};
+use crate::css::position::Position;
+
+use super::float::FloatContext;
+use super::{LayoutBox, LayoutTree};
+
+/// A rectangle in CSS pixels, with its origin at the top left.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// A point in CSS pixels, relative to the top left of the canvas.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Rect {
+    /// Whether `point` is inside of this rectangle.
+    ///
+    /// NOTE: The left and top edges are inside of the rectangle, and the right and bottom edges
+    ///       are not, so a point on the edge between two adjacent rectangles is in only one of them.
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.x
+            && point.x < self.x + self.width
+            && point.y >= self.y
+            && point.y < self.y + self.height
+    }
+
+    /// Returns this rectangle grown by `edges` on every side.
+    pub fn expanded_by(&self, edges: Sides<f64>) -> Self {
+        Self {
+            x: self.x - edges.left,
+            y: self.y - edges.top,
+            width: self.width + edges.left + edges.right,
+            height: self.height + edges.top + edges.bottom,
+        }
+    }
+}
+
+/// The used sizes of the areas of a box.
+///
+/// https://drafts.csswg.org/css-box-4/#box-model
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dimensions {
+    /// The position and size of the content area.
+    pub content: Rect,
+    pub padding: Sides<f64>,
+    pub border: Sides<f64>,
+    pub margin: Sides<f64>,
+}
+
+impl Dimensions {
+    /// https://drafts.csswg.org/css-box-4/#padding-box
+    pub fn padding_box(&self) -> Rect {
+        self.content.expanded_by(self.padding)
+    }
+
+    /// https://drafts.csswg.org/css-box-4/#border-box
+    pub fn border_box(&self) -> Rect {
+        self.padding_box().expanded_by(self.border)
+    }
+
+    /// https://drafts.csswg.org/css-box-4/#margin-box
+    pub fn margin_box(&self) -> Rect {
+        self.border_box().expanded_by(self.margin)
+    }
+}
+
+impl LayoutTree<'_> {
+    /// Lays out the tree in a viewport that is `viewport_width` CSS pixels wide.
+    ///
+    /// https://www.w3.org/TR/CSS2/visudet.html#containing-block-details
+    pub fn layout(&mut self, viewport_width: f64) {
+        // SPEC: The containing block in which the root element lives is a rectangle called the
+        //       initial containing block. For continuous media, it has the dimensions of the
+        //       viewport and is anchored at the canvas origin.
+        let initial_containing_block = Dimensions {
+            content: Rect {
+                width: viewport_width,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // FIXME: We don't know the height of the viewport, so percentage heights of the root
+        //        element behave as if they were 'auto'.
+        if let Some(root) = &mut self.root {
+            root.layout(initial_containing_block, None, &mut FloatContext::default());
+
+            // NOTE: Absolutely positioned boxes without a positioned ancestor have the initial
+            //       containing block as their containing block.
+            // FIXME: Use the height of the viewport instead of the height of the root element.
+            let initial_containing_block = Rect {
+                height: root.dimensions.margin_box().height,
+                ..initial_containing_block.content
+            };
+            root.layout_absolutely_positioned_boxes_in(initial_containing_block);
+        }
+    }
+}
+
+impl LayoutBox<'_> {
+    /// Computes the dimensions of this box and its descendants.
+    ///
+    /// `containing_block` is the content area of the containing block, with its height set to
+    /// how much of it is already taken up by the preceding siblings of this box.
+    /// `containing_block_height` is the height of the containing block if it doesn't depend on
+    /// its content, which percentage heights are resolved against. `floats` are the floats in the
+    /// block formatting context this box takes part in.
+    ///
+    /// FIXME: Only block-level boxes are laid out. Inline-level content doesn't take up any space
+    ///        until we have inline layout.
+    fn layout(
+        &mut self,
+        containing_block: Dimensions,
+        containing_block_height: Option<f64>,
+        floats: &mut FloatContext,
+    ) {
+        if !self.is_block_level() {
+            return;
+        }
+
+        self.calculate_block_width(containing_block);
+        self.calculate_block_position(containing_block);
+        let height = self.calculate_block_height(containing_block_height);
+        match self.establishes_block_formatting_context() {
+            true => self.layout_block_formatting_context(height),
+            false => self.layout_block_children(height, floats),
+        }
+
+        if let Some(height) = height {
+            self.dimensions.content.height = height;
+        }
+
+        if self.is_positioned() {
+            self.layout_absolutely_positioned_descendants();
+        }
+        if self.style.position == Position::Relative {
+            self.apply_relative_offset(containing_block.content.width, containing_block_height);
+        }
+    }
+
+    /// https://www.w3.org/TR/CSS2/visudet.html#blockwidth
+    fn calculate_block_width(&mut self, containing_block: Dimensions) {
+        let style = &self.style;
+        let containing_block_width = containing_block.content.width;
+
+        // NOTE: Percentages of margins and paddings, even the vertical ones, refer to the width
+        //       of the containing block.
+        let width = style.width.resolve(containing_block_width);
+        let margin_left = style.margin.left.resolve(containing_block_width);
+        let margin_right = style.margin.right.resolve(containing_block_width);
+        let padding_left = style.padding.left.resolve(containing_block_width);
+        let padding_right = style.padding.right.resolve(containing_block_width);
+        let border = style.used_border_width();
+        let border_left = border.left;
+        let border_right = border.right;
+
+        let borders_and_paddings = border_left + padding_left + padding_right + border_right;
+        let solve_width = match self.is_floating() {
+            true => solve_float_width,
+            false => solve_block_width,
+        };
+        let solve = |width: Option<f64>| {
+            solve_width(
+                width,
+                margin_left,
+                margin_right,
+                borders_and_paddings,
+                containing_block_width,
+            )
+        };
+
+        // https://www.w3.org/TR/CSS2/visudet.html#min-max-widths
+        // 1. The tentative used width is calculated (without 'min-width' and 'max-width')
+        //    following the rules under "Calculating widths and margins" above.
+        let mut used = solve(width);
+
+        // 2. If the tentative used width is greater than 'max-width', the rules above are applied
+        //    again, but this time using the computed value of 'max-width' as the computed value
+        //    for 'width'.
+        if let Some(max_width) = style.max_width {
+            let max_width = max_width.resolve(containing_block_width);
+            if used.0 > max_width {
+                used = solve(Some(max_width));
+            }
+        }
+
+        // 3. If the resulting width is smaller than 'min-width', the rules above are applied
+        //    again, but this time using the value of 'min-width' as the computed value for
+        //    'width'.
+        // NOTE: A 'min-width' of 'auto' is zero for block boxes.
+        let min_width = style
+            .min_width
+            .resolve(containing_block_width)
+            .unwrap_or(0.0);
+        if used.0 < min_width {
+            used = solve(Some(min_width));
+        }
+
+        let (width, margin_left, margin_right) = used;
+        let dimensions = &mut self.dimensions;
+        dimensions.content.width = width;
+        dimensions.margin.left = margin_left;
+        dimensions.margin.right = margin_right;
+        dimensions.padding.left = padding_left;
+        dimensions.padding.right = padding_right;
+        dimensions.border.left = border_left;
+        dimensions.border.right = border_right;
+    }
+
+    /// Returns the used height of the content area if it doesn't depend on the content.
+    ///
+    /// https://www.w3.org/TR/CSS2/visudet.html#the-height-property
+    fn calculate_block_height(&self, containing_block_height: Option<f64>) -> Option<f64> {
+        match self.style.height {
+            LengthPercentageAuto::Auto => None,
+            LengthPercentageAuto::LengthPercentage(LengthPercentage::Px(height)) => Some(height),
+            // SPEC: The percentage is calculated with respect to the height of the generated
+            //       box's containing block. If the height of the containing block is not
+            //       specified explicitly (i.e., it depends on content height), and this element
+            //       is not absolutely positioned, the value computes to 'auto'.
+            LengthPercentageAuto::LengthPercentage(height) => containing_block_height
+                .map(|containing_block_height| height.resolve(containing_block_height)),
+        }
+    }
+
+    /// Places this box below the boxes that precede it in the containing block.
+    ///
+    /// FIXME: Collapse adjoining vertical margins.
+    ///        https://www.w3.org/TR/CSS2/box.html#collapsing-margins
+    fn calculate_block_position(&mut self, containing_block: Dimensions) {
+        let style = &self.style;
+        let containing_block_width = containing_block.content.width;
+        let dimensions = &mut self.dimensions;
+
+        // SPEC: If 'margin-top', or 'margin-bottom' are 'auto', their used value is 0.
+        dimensions.margin.top = style
+            .margin
+            .top
+            .resolve(containing_block_width)
+            .unwrap_or(0.0);
+        dimensions.margin.bottom = style
+            .margin
+            .bottom
+            .resolve(containing_block_width)
+            .unwrap_or(0.0);
+        dimensions.padding.top = style.padding.top.resolve(containing_block_width);
+        dimensions.padding.bottom = style.padding.bottom.resolve(containing_block_width);
+        let border = style.used_border_width();
+        dimensions.border.top = border.top;
+        dimensions.border.bottom = border.bottom;
+
+        dimensions.content.x = containing_block.content.x
+            + dimensions.margin.left
+            + dimensions.border.left
+            + dimensions.padding.left;
+        dimensions.content.y = containing_block.content.y
+            + containing_block.content.height
+            + dimensions.margin.top
+            + dimensions.border.top
+            + dimensions.padding.top;
+    }
+
+    /// https://www.w3.org/TR/CSS2/visudet.html#normal-block
+    ///
+    /// SPEC: If 'height' is 'auto', the height depends on whether the element has any block-level
+    ///       children and whether it has padding or borders: [...] the bottom edge of the bottom
+    ///       (possibly collapsed) margin of its last in-flow child.
+    ///
+    /// `height` is the used height of this box if it doesn't depend on its content.
+    fn layout_block_children(&mut self, height: Option<f64>, floats: &mut FloatContext) {
+        let mut dimensions = self.dimensions;
+        dimensions.content.height = 0.0;
+
+        for child in self.children.iter_mut() {
+            if !child.is_block_level() {
+                continue;
+            }
+
+            // SPEC: In the absolute positioning model, a box is removed from the normal flow
+            //       entirely (it has no impact on later siblings) and assigned a position with
+            //       respect to a containing block.
+            // NOTE: We only remember its static position here. It is laid out once the size of
+            //       its containing block is known.
+            if child.is_absolutely_positioned() {
+                child.dimensions.content.x = dimensions.content.x;
+                child.dimensions.content.y = dimensions.content.y + dimensions.content.height;
+                continue;
+            }
+
+            // SPEC: A float is a box that is shifted to the left or right on the current line.
+            //       [...] Since a float is not in the flow, non-positioned block boxes created
+            //       before and after the float box flow vertically as if the float did not exist.
+            if child.is_floating() {
+                child.layout(dimensions, height, floats);
+                child.place_float(dimensions, floats);
+                continue;
+            }
+
+            // SPEC: Then the amount of clearance is set to the greater of:
+            //       1. The amount necessary to place the border edge of the block even with the
+            //          bottom outer edge of the lowest float that is to be cleared.
+            //       2. The amount necessary to place the top border edge of the block at its
+            //          hypothetical position.
+            // FIXME: Take collapsed margins into account once we collapse them.
+            if let Some(float_bottom) = floats.clearance_edge(child.style.clear) {
+                let margin_top = child
+                    .style
+                    .margin
+                    .top
+                    .resolve(dimensions.content.width)
+                    .unwrap_or(0.0);
+                let border_top = dimensions.content.y + dimensions.content.height + margin_top;
+                if border_top < float_bottom {
+                    dimensions.content.height += float_bottom - border_top;
+                }
+            }
+
+            child.layout(dimensions, height, floats);
+            dimensions.content.height += child.dimensions.margin_box().height;
+        }
+
+        // NOTE: A block container with only inline-level content establishes an inline
+        //       formatting context, where the content is made up of a stack of line boxes.
+        //       https://www.w3.org/TR/CSS2/visuren.html#inline-formatting
+        self.layout_line_boxes(dimensions.content, floats);
+        if let Some(last_line) = self.line_boxes.last() {
+            dimensions.content.height =
+                last_line.rect.y + last_line.rect.height - dimensions.content.y;
+        }
+
+        self.dimensions.content.height = dimensions.content.height;
+    }
+
+    /// The height of each line box in the inline formatting context of this box.
+    ///
+    /// FIXME: Take the line heights of the inline boxes on the line into account.
+    ///        https://www.w3.org/TR/CSS2/visudet.html#line-height
+    pub(super) fn line_height(&self) -> f64 {
+        self.style.line_height.used_value(self.style.font_size)
+    }
+
+    /// Lays out the children of this box in the new block formatting context it establishes.
+    ///
+    /// https://www.w3.org/TR/CSS2/visudet.html#root-height
+    pub(super) fn layout_block_formatting_context(&mut self, height: Option<f64>) {
+        let mut floats = FloatContext::default();
+        self.layout_block_children(height, &mut floats);
+
+        // SPEC: In addition, if the element has any floating descendants whose bottom margin edge
+        //       is below the element's bottom content edge, then the height is increased to
+        //       include those edges.
+        if let Some(float_bottom) = floats.bottom() {
+            let content = &mut self.dimensions.content;
+            content.height = content.height.max(float_bottom - content.y);
+        }
+    }
+
+    /// https://www.w3.org/TR/CSS2/visuren.html#block-formatting
+    ///
+    /// SPEC: Floats, absolutely positioned elements, block containers (such as inline-blocks,
+    ///       table-cells, and table-captions) that are not block boxes, and block boxes with
+    ///       'overflow' other than 'visible' (except when that value has been propagated to the
+    ///       viewport) establish new block formatting contexts for their contents.
+    /// NOTE: The root element establishes the initial block formatting context.
+    fn establishes_block_formatting_context(&self) -> bool {
+        let is_root = self
+            .node
+            .is_some_and(|node| node.is_element() && node.parent_element().is_none());
+        is_root || self.is_floating() || self.is_absolutely_positioned()
+    }
+
+    /// Moves this float, which has already been laid out where its containing block's content
+    /// ends, to where the float rules put it in the block formatting context of `floats`.
+    ///
+    /// https://www.w3.org/TR/CSS2/visuren.html#float-position
+    fn place_float(&mut self, containing_block: Dimensions, floats: &mut FloatContext) {
+        let margin_box = self.dimensions.margin_box();
+        let mut min_top = containing_block.content.y + containing_block.content.height;
+
+        // SPEC: For floats: [...] the top outer edge of the float must be below the bottom outer
+        //       edge of all earlier left-floating boxes (in the case of 'clear: left'), or all
+        //       earlier right-floating boxes (in the case of 'clear: right'), or both ('clear:
+        //       both').
+        if let Some(float_bottom) = floats.clearance_edge(self.style.clear) {
+            min_top = min_top.max(float_bottom);
+        }
+
+        let (x, y) = floats.place(
+            self.style.float,
+            margin_box.width,
+            margin_box.height,
+            min_top,
+            containing_block.content,
+        );
+        self.translate(x - margin_box.x, y - margin_box.y);
+    }
+
+    /// Moves this box and its descendants by `dx` to the right and `dy` down.
+    pub(super) fn translate(&mut self, dx: f64, dy: f64) {
+        self.dimensions.content.x += dx;
+        self.dimensions.content.y += dy;
+        for line_box in self.line_boxes.iter_mut() {
+            line_box.rect.x += dx;
+            line_box.rect.y += dy;
+        }
+        for child in self.children.iter_mut() {
+            child.translate(dx, dy);
+        }
+    }
+}
+
+/// Solves the constraint on the horizontal dimensions of a block-level box for the used values
+/// of `width`, `margin-left` and `margin-right`, where `None` means 'auto'.
+///
+/// https://www.w3.org/TR/CSS2/visudet.html#blockwidth
+fn solve_block_width(
+    width: Option<f64>,
+    mut margin_left: Option<f64>,
+    mut margin_right: Option<f64>,
+    borders_and_paddings: f64,
+    containing_block_width: f64,
+) -> (f64, f64, f64) {
+    // SPEC: The following constraints must hold among the used values of the other properties:
+    //       'margin-left' + 'border-left-width' + 'padding-left' + 'width' + 'padding-right' +
+    //       'border-right-width' + 'margin-right' = width of containing block
+    let total = margin_left.unwrap_or(0.0)
+        + borders_and_paddings
+        + width.unwrap_or(0.0)
+        + margin_right.unwrap_or(0.0);
+
+    // SPEC: If 'width' is not 'auto' and 'border-left-width' + 'padding-left' + 'width' +
+    //       'padding-right' + 'border-right-width' (plus any of 'margin-left' or
+    //       'margin-right' that are not 'auto') is larger than the width of the containing
+    //       block, then any 'auto' values for 'margin-left' or 'margin-right' are, for the
+    //       following rules, treated as zero.
+    if width.is_some() && total > containing_block_width {
+        margin_left.get_or_insert(0.0);
+        margin_right.get_or_insert(0.0);
+    }
+
+    let underflow = containing_block_width - total;
+    match (width, margin_left, margin_right) {
+        // SPEC: If all of the above have a computed value other than 'auto', the values are
+        //       said to be "over-constrained" and one of the used values will have to be
+        //       different from its computed value. If the 'direction' property of the
+        //       containing block has the value 'ltr', the specified value of 'margin-right'
+        //       is ignored and the value is calculated so as to make the equality true.
+        // FIXME: Use 'margin-left' instead if the direction is 'rtl'.
+        (Some(width), Some(margin_left), Some(margin_right)) => {
+            (width, margin_left, margin_right + underflow)
+        }
+        // SPEC: If there is exactly one value specified as 'auto', its used value follows
+        //       from the equality.
+        (Some(width), Some(margin_left), None) => (width, margin_left, underflow),
+        (Some(width), None, Some(margin_right)) => (width, underflow, margin_right),
+        // SPEC: If 'width' is set to 'auto', any other 'auto' values become '0' and 'width'
+        //       follows from the resulting equality.
+        // NOTE: The width can't become negative, so the right margin takes up the rest.
+        (None, margin_left, margin_right) => {
+            let margin_left = margin_left.unwrap_or(0.0);
+            let margin_right = margin_right.unwrap_or(0.0);
+            match underflow >= 0.0 {
+                true => (underflow, margin_left, margin_right),
+                false => (0.0, margin_left, margin_right + underflow),
+            }
+        }
+        // SPEC: If both 'margin-left' and 'margin-right' are 'auto', their used values are
+        //       equal. This horizontally centers the element with respect to the edges of
+        //       the containing block.
+        (Some(width), None, None) => (width, underflow / 2.0, underflow / 2.0),
+    }
+}
+
+/// Like [`solve_block_width`], but for floating boxes, which don't have to fill the width of
+/// their containing block.
+///
+/// https://www.w3.org/TR/CSS2/visudet.html#float-width
+fn solve_float_width(
+    width: Option<f64>,
+    margin_left: Option<f64>,
+    margin_right: Option<f64>,
+    borders_and_paddings: f64,
+    containing_block_width: f64,
+) -> (f64, f64, f64) {
+    // SPEC: If 'margin-left', or 'margin-right' are computed as 'auto', their used value is '0'.
+    let margin_left = margin_left.unwrap_or(0.0);
+    let margin_right = margin_right.unwrap_or(0.0);
+
+    // SPEC: If 'width' is computed as 'auto', the used value is the "shrink-to-fit" width.
+    // FIXME: Shrink to the preferred width of the content once we can measure it. For now we use
+    //        the available width, which is what the shrink-to-fit width is for content that
+    //        would fill a whole line.
+    let width = width.unwrap_or_else(|| {
+        (containing_block_width - margin_left - borders_and_paddings - margin_right).max(0.0)
+    });
+
+    (width, margin_left, margin_right)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::html::parser::Parser;
+
+    /// The used dimensions of the box of the element with the id `id` in `input`, laid out in a
+    /// viewport that is `viewport_width` wide.
+    pub(in crate::layout) fn dimensions(input: &str, viewport_width: f64, id: &str) -> Dimensions {
+        fn find(layout_box: &LayoutBox, id: &str) -> Option<Dimensions> {
+            let has_id = layout_box.node.is_some_and(|node| {
+                node.as_element()
+                    .is_some_and(|element| element.get_attribute("id").as_deref() == Some(id))
+            });
+            if has_id {
+                return Some(layout_box.dimensions);
+            }
+            layout_box.children.iter().find_map(|child| find(child, id))
+        }
+
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let loaded = crate::load_with_parser(&parser);
+        let mut tree = LayoutTree::new(loaded.document, &loaded.stylesheets);
+        tree.layout(viewport_width);
+        find(tree.root.as_ref().unwrap(), id).expect("the element should generate a box")
+    }
+
+    fn container(child_style: &str) -> Dimensions {
+        let input = format!(
+            "<body style=\"margin: 0\"><div style=\"width: 100px\"><div id=child style=\"{child_style}\"></div></div></body>"
+        );
+        dimensions(&input, 800.0, "child")
+    }
+
+    #[test]
+    fn auto_width_fills_the_containing_block() {
+        let child = container("width: auto; margin: 0 10px");
+        assert_eq!(child.content.width, 80.0);
+        assert_eq!(child.content.x, 10.0);
+
+        let child = container("padding: 5px; border: 2px solid; margin-right: 3px");
+        assert_eq!(child.content.width, 83.0);
+        assert_eq!(child.content.x, 7.0);
+        assert_eq!(child.border_box().width, 97.0);
+        assert_eq!(child.margin_box().width, 100.0);
+    }
+
+    #[test]
+    fn auto_margins_center_the_box() {
+        let child = container("width: 50px; margin: 0 auto");
+        assert_eq!((child.margin.left, child.margin.right), (25.0, 25.0));
+        assert_eq!(child.content.x, 25.0);
+
+        let child = container("width: 50px; margin-left: auto; margin-right: 10px");
+        assert_eq!((child.margin.left, child.margin.right), (40.0, 10.0));
+    }
+
+    #[test]
+    fn over_constrained_boxes_adjust_the_right_margin() {
+        let child = container("width: 80px; margin-left: 10px; margin-right: 30px");
+        assert_eq!((child.margin.left, child.margin.right), (10.0, 10.0));
+
+        // NOTE: A box that is too wide has auto margins treated as zero.
+        let child = container("width: 150px; margin: 0 auto");
+        assert_eq!((child.margin.left, child.margin.right), (0.0, -50.0));
+    }
+
+    #[test]
+    fn percentages_resolve_against_the_containing_block_width() {
+        let child = container("width: 50%; padding: 0 10%; margin-top: 5%");
+        assert_eq!(child.content.width, 50.0);
+        assert_eq!((child.padding.left, child.padding.right), (10.0, 10.0));
+        assert_eq!(child.margin.top, 5.0);
+    }
+
+    #[test]
+    fn min_and_max_width_clamp_the_used_width() {
+        let input = "<body style=\"margin: 0\"><div style=\"width: 400px\">\
+                     <div id=child style=\"width: 50%\"></div></div></body>";
+        assert_eq!(dimensions(input, 800.0, "child").content.width, 200.0);
+
+        // NOTE: The width is computed again with max-width as the width, so the auto margins
+        //       center the narrower box.
+        let child = container("max-width: 60px; margin: 0 auto");
+        assert_eq!(child.content.width, 60.0);
+        assert_eq!((child.margin.left, child.margin.right), (20.0, 20.0));
+
+        let child = container("width: 20px; min-width: 40%; margin: 0 auto");
+        assert_eq!(child.content.width, 40.0);
+        assert_eq!((child.margin.left, child.margin.right), (30.0, 30.0));
+
+        // SPEC: If the resulting width is smaller than 'min-width', the rules above are applied
+        //       again, even if 'max-width' is smaller.
+        let child = container("min-width: 70px; max-width: 50px");
+        assert_eq!(child.content.width, 70.0);
+    }
+
+    #[test]
+    fn percentage_heights_need_a_definite_containing_block_height() {
+        let input = "<body style=\"margin: 0\"><div style=\"height: 200px\">\
+                     <div id=child style=\"height: 25%\"></div></div></body>";
+        assert_eq!(dimensions(input, 800.0, "child").content.height, 50.0);
+
+        // NOTE: The parent's height depends on its content, so the percentage behaves as auto
+        //       and the child is as tall as its own content.
+        let input = "<body style=\"margin: 0\"><div>\
+                     <div id=child style=\"height: 50%\"><div style=\"height: 30px\"></div></div>\
+                     </div></body>";
+        assert_eq!(dimensions(input, 800.0, "child").content.height, 30.0);
+
+        let child = container("height: 40px");
+        assert_eq!(child.content.height, 40.0);
+    }
+
+    #[test]
+    fn borders_without_a_style_have_no_width() {
+        let child = container("border: solid");
+        // NOTE: The initial border width is medium, which is 3px.
+        assert_eq!(child.border, Sides::all(3.0));
+        assert_eq!(child.content.width, 94.0);
+
+        let child = container("border-width: 5px; border-style: none");
+        assert_eq!(child.border, Sides::all(0.0));
+        assert_eq!(child.content.width, 100.0);
+
+        let child = container("border-width: 5px; border-style: hidden");
+        assert_eq!(child.border, Sides::all(0.0));
+
+        let child = container(
+            "border: thin solid red; border-left-width: thick; border-right-style: none; border-top-width: 4px",
+        );
+        assert_eq!(
+            child.border,
+            Sides {
+                top: 4.0,
+                right: 0.0,
+                bottom: 1.0,
+                left: 5.0,
+            }
+        );
+        assert_eq!(child.content.width, 95.0);
+    }
+}
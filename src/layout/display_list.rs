@@ -0,0 +1,243 @@
+use crate::css::color::Color;
+use crate::css::display::Visibility;
+
+use super::box_model::Rect;
+use super::{LayoutBox, LayoutTree};
+
+/// A single drawing operation, in the order it has to be painted.
+///
+/// All rectangles are in CSS pixels, in the same coordinate space as the layout.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DisplayItem {
+    /// A rectangle filled with a single color.
+    SolidRect { rect: Rect, color: Color },
+    /// A run of text, drawn from the top left of `rect` and clipped to it.
+    Text {
+        rect: Rect,
+        text: String,
+        color: Color,
+    },
+}
+
+impl LayoutTree<'_> {
+    /// Returns the display list of the laid out tree, in painting order.
+    ///
+    /// FIXME: Boxes are painted in tree order. Follow the stacking order of Appendix E, where
+    ///        floats and positioned boxes are painted after the in-flow boxes.
+    ///
+    /// https://www.w3.org/TR/CSS2/zindex.html#painting-order
+    pub fn to_display_list(&self) -> Vec<DisplayItem> {
+        let mut display_list = vec![];
+        if let Some(root) = &self.root {
+            root.build_display_list(&mut display_list);
+        }
+        display_list
+    }
+}
+
+impl LayoutBox<'_> {
+    fn build_display_list(&self, display_list: &mut Vec<DisplayItem>) {
+        // SPEC: hidden: The box is invisible (fully transparent, nothing is drawn), but still
+        //       affects layout. Descendants of the element will be visible if they have
+        //       'visibility: visible'.
+        if self.style.visibility == Visibility::Visible {
+            self.push_background(display_list);
+            self.push_borders(display_list);
+            self.push_text(display_list);
+        }
+
+        for child in &self.children {
+            child.build_display_list(display_list);
+        }
+    }
+
+    /// https://drafts.csswg.org/css-backgrounds-3/#background-color
+    ///
+    /// SPEC: The background color is drawn behind any background images.
+    /// NOTE: The initial 'background-clip' is border-box, so the background extends underneath
+    ///       the border.
+    /// FIXME: Propagate the background of the root element (or the body) to the canvas.
+    ///        https://drafts.csswg.org/css-backgrounds-3/#special-backgrounds
+    fn push_background(&self, display_list: &mut Vec<DisplayItem>) {
+        let rect = self.dimensions.border_box();
+        let color = self.style.background_color.to_computed(self.style.color);
+        if rect.width > 0.0 && rect.height > 0.0 && color.a > 0 {
+            display_list.push(DisplayItem::SolidRect { rect, color });
+        }
+    }
+
+    /// https://drafts.csswg.org/css-backgrounds-3/#borders
+    fn push_borders(&self, display_list: &mut Vec<DisplayItem>) {
+        let border_box = self.dimensions.border_box();
+        let border = self.dimensions.border;
+        let color = &self.style.border_color;
+        let current_color = self.style.color;
+
+        // FIXME: Draw the line styles other than solid, and join the corners diagonally.
+        let sides = [
+            (
+                color.top,
+                Rect {
+                    height: border.top,
+                    ..border_box
+                },
+            ),
+            (
+                color.bottom,
+                Rect {
+                    y: border_box.y + border_box.height - border.bottom,
+                    height: border.bottom,
+                    ..border_box
+                },
+            ),
+            (
+                color.left,
+                Rect {
+                    y: border_box.y + border.top,
+                    width: border.left,
+                    height: border_box.height - border.top - border.bottom,
+                    ..border_box
+                },
+            ),
+            (
+                color.right,
+                Rect {
+                    x: border_box.x + border_box.width - border.right,
+                    y: border_box.y + border.top,
+                    width: border.right,
+                    height: border_box.height - border.top - border.bottom,
+                },
+            ),
+        ];
+
+        for (color, rect) in sides {
+            let color = color.to_computed(current_color);
+            if rect.width > 0.0 && rect.height > 0.0 && color.a > 0 {
+                display_list.push(DisplayItem::SolidRect { rect, color });
+            }
+        }
+    }
+
+    /// Pushes the text of the line boxes of the inline formatting context of this box.
+    ///
+    /// FIXME: Paint every text run in its own color and visibility, and inline boxes with their
+    ///        own borders, once we have inline layout to place them on the line.
+    fn push_text(&self, display_list: &mut Vec<DisplayItem>) {
+        for line_box in &self.line_boxes {
+            if line_box.text.is_empty() {
+                continue;
+            }
+
+            display_list.push(DisplayItem::Text {
+                rect: line_box.rect,
+                text: line_box.text.clone(),
+                color: self.style.color,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::parser::Parser;
+
+    /// The display list of `input`, laid out in a viewport that is 800px wide.
+    fn display_list(input: &str) -> Vec<DisplayItem> {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let loaded = crate::load_with_parser(&parser);
+        let mut tree = LayoutTree::new(loaded.document, &loaded.stylesheets);
+        tree.layout(800.0);
+        tree.to_display_list()
+    }
+
+    #[test]
+    fn backgrounds_are_painted_before_their_text() {
+        let display_list = display_list(
+            "<body style=\"margin: 0\"><div style=\"background-color: blue; color: red\">hi</div>",
+        );
+        let [DisplayItem::SolidRect { rect, color }, DisplayItem::Text {
+            rect: text_rect,
+            text,
+            color: text_color,
+        }] = display_list.as_slice()
+        else {
+            panic!("expected a background and a text item: {display_list:?}");
+        };
+
+        assert_eq!(*color, Color::rgb(0, 0, 255));
+        assert_eq!((rect.x, rect.y, rect.width), (0.0, 0.0, 800.0));
+        assert!(rect.height > 0.0);
+        assert_eq!(text, "hi");
+        assert_eq!(*text_color, Color::rgb(255, 0, 0));
+        assert!(text_rect.y >= rect.y && text_rect.y + text_rect.height <= rect.y + rect.height);
+    }
+
+    #[test]
+    fn parents_are_painted_before_their_children() {
+        let display_list = display_list(
+            "<body style=\"margin: 0\"><div style=\"background-color: red\">\
+             <p style=\"margin: 0; background-color: lime\">a</p></div>\
+             <div style=\"visibility: hidden; background-color: blue\">b\
+             <p style=\"visibility: visible; margin: 0; background-color: yellow\">c</p></div>",
+        );
+        let items = display_list
+            .iter()
+            .map(|item| match item {
+                DisplayItem::SolidRect { color, .. } => format!("rect {color:?}"),
+                DisplayItem::Text { text, .. } => format!("text {text}"),
+            })
+            .collect::<Vec<_>>();
+        // NOTE: The hidden box paints nothing, but its visible descendants still do.
+        assert_eq!(
+            items,
+            [
+                format!("rect {:?}", Color::rgb(255, 0, 0)),
+                format!("rect {:?}", Color::rgb(0, 255, 0)),
+                "text a".to_string(),
+                format!("rect {:?}", Color::rgb(255, 255, 0)),
+                "text c".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn background_color_covers_the_border_box() {
+        let green = display_list(
+            "<style>div { background-color: #00ff00; width: 100px; height: 50px; padding: 5px; \
+             margin: 10px }</style><body style=\"margin: 0\"><div></div>",
+        );
+        assert_eq!(
+            green,
+            [DisplayItem::SolidRect {
+                rect: Rect {
+                    x: 10.0,
+                    y: 10.0,
+                    width: 110.0,
+                    height: 60.0
+                },
+                color: Color::rgb(0, 255, 0),
+            }]
+        );
+
+        // NOTE: Transparent and empty backgrounds are not painted.
+        for style in [
+            "background-color: transparent; height: 10px",
+            "background-color: rgb(0 0 0 / 0); height: 10px",
+            "background-color: red; height: 0",
+            "height: 10px",
+        ] {
+            let input = format!("<body style=\"margin: 0\"><div style=\"{style}\"></div>");
+            assert_eq!(display_list(&input), [], "{style}");
+        }
+
+        let current_color = display_list(
+            "<body style=\"margin: 0\"><div style=\"color: blue; background-color: currentcolor; \
+             height: 10px\"></div>",
+        );
+        assert!(matches!(
+            current_color.as_slice(),
+            [DisplayItem::SolidRect { color, .. }] if *color == Color::rgb(0, 0, 255)
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::css::float::{Clear, Float};
+
+use super::box_model::Rect;
+
+/// The floats that have been placed in a block formatting context, in the order they were placed.
+///
+/// All rectangles are margin boxes in the same coordinate space as the rest of the layout.
+///
+/// https://www.w3.org/TR/CSS2/visuren.html#block-formatting
+#[derive(Debug, Clone, Default)]
+pub(super) struct FloatContext {
+    floats: Vec<(Float, Rect)>,
+}
+
+impl FloatContext {
+    /// Places a float whose margin box is `width` by `height` in `containing_block`, not higher
+    /// than `min_top`, and returns the position of the top left corner of its margin box.
+    ///
+    /// https://www.w3.org/TR/CSS2/visuren.html#float-rules
+    pub(super) fn place(
+        &mut self,
+        side: Float,
+        width: f64,
+        height: f64,
+        min_top: f64,
+        containing_block: Rect,
+    ) -> (f64, f64) {
+        // SPEC: 4. A floating box's outer top may not be higher than the top of its containing
+        //          block.
+        //       5. The outer top of a floating box may not be higher than the outer top of any
+        //          block or floated box generated by an element earlier in the source document.
+        // NOTE: Floats are placed in source order and never move up, so the last one is the
+        //       highest earlier float we have to stay below.
+        let mut top = min_top.max(containing_block.y);
+        if let Some((_, last)) = self.floats.last() {
+            top = top.max(last.y);
+        }
+
+        // SPEC: 8. A floating box must be placed as high as possible.
+        //       9. A left-floating box must be put as far to the left as possible, a
+        //          right-floating box as far to the right as possible. A higher position is
+        //          preferred over one that is further to the left/right.
+        // NOTE: If the float doesn't fit next to the floats it overlaps, we move it down to where
+        //       the first of them ends and try again. Once it overlaps no floats it goes there,
+        //       even if it is wider than the containing block.
+        let (left, right) = loop {
+            let (left, right) = self.available_span(top, height, containing_block);
+            if width <= right - left {
+                break (left, right);
+            }
+
+            match self.first_overlapping_bottom(top, height) {
+                Some(bottom) => top = bottom,
+                None => break (left, right),
+            }
+        };
+
+        let x = match side {
+            Float::Right => right - width,
+            _ => left,
+        };
+        self.floats.push((
+            side,
+            Rect {
+                x,
+                y: top,
+                width,
+                height,
+            },
+        ));
+        (x, top)
+    }
+
+    /// The horizontal span of `containing_block` that is not covered by the floats that overlap
+    /// the band from `top` to `top + height`, as `(left, right)`.
+    ///
+    /// SPEC: A float can overlap other boxes in the normal flow. [...] line boxes created next
+    ///       to the float are shortened to make room for the margin box of the float.
+    pub(super) fn available_span(
+        &self,
+        top: f64,
+        height: f64,
+        containing_block: Rect,
+    ) -> (f64, f64) {
+        let mut left = containing_block.x;
+        let mut right = containing_block.x + containing_block.width;
+
+        for (side, rect) in self.overlapping(top, height) {
+            match side {
+                Float::Left => left = left.max(rect.x + rect.width),
+                Float::Right => right = right.min(rect.x),
+                Float::None => {}
+            }
+        }
+
+        (left, right)
+    }
+
+    /// The bottom of the lowest margin box of the floats that `clear` applies to, if any.
+    ///
+    /// SPEC: Clearance is introduced as spacing above the margin-top of an element. It is used to
+    ///       push the element vertically past the float.
+    pub(super) fn clearance_edge(&self, clear: Clear) -> Option<f64> {
+        self.floats
+            .iter()
+            .filter(|(side, _)| clear.clears(*side))
+            .map(|(_, rect)| rect.y + rect.height)
+            .reduce(f64::max)
+    }
+
+    /// The bottom of the lowest margin box of all floats.
+    pub(super) fn bottom(&self) -> Option<f64> {
+        self.clearance_edge(Clear::Both)
+    }
+
+    /// The floats that vertically overlap the band from `top` to `top + height`.
+    ///
+    /// NOTE: A band without height still overlaps the floats that are next to its top.
+    fn overlapping(&self, top: f64, height: f64) -> impl Iterator<Item = &(Float, Rect)> {
+        self.floats.iter().filter(move |(_, rect)| {
+            let bottom = rect.y + rect.height;
+            bottom > top && (rect.y < top + height || rect.y <= top)
+        })
+    }
+
+    /// The highest bottom edge of the floats that overlap the band from `top` to `top + height`,
+    /// which is where the band has to move to for the first of them to stop overlapping it.
+    fn first_overlapping_bottom(&self, top: f64, height: f64) -> Option<f64> {
+        self.overlapping(top, height)
+            .map(|(_, rect)| rect.y + rect.height)
+            .reduce(f64::min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::text::tests::line_boxes;
+
+    #[test]
+    fn line_boxes_next_to_floats_are_shortened() {
+        let line_boxes = line_boxes(
+            "<body style=\"margin: 0; line-height: 20px\">\
+             <div style=\"float: left; width: 50px; height: 30px\"></div>\
+             <div style=\"float: right; width: 30px; height: 10px\"></div>\
+             a<br>b<br>c</body>",
+            200.0,
+        );
+
+        let spans: Vec<_> = line_boxes
+            .iter()
+            .map(|line_box| (line_box.text.as_str(), line_box.rect.x, line_box.rect.width))
+            .collect();
+        assert_eq!(
+            spans,
+            [("a", 50.0, 120.0), ("b", 50.0, 150.0), ("c", 0.0, 200.0)]
+        );
+
+        let tops: Vec<_> = line_boxes.iter().map(|line_box| line_box.rect.y).collect();
+        assert_eq!(tops, [0.0, 20.0, 40.0]);
+    }
+}
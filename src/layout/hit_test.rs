@@ -0,0 +1,125 @@
+use crate::css::display::Visibility;
+use crate::dom::NodeRef;
+
+use super::box_model::Point;
+use super::{LayoutBox, LayoutTree};
+
+impl<'a> LayoutTree<'a> {
+    /// Returns the node of the topmost, deepest box whose border box contains `point`.
+    ///
+    /// Boxes that are painted later are on top: later siblings are above earlier ones, and
+    /// positioned boxes are above the non-positioned boxes among their siblings. Anonymous boxes
+    /// and text runs don't have a node of their own, so a hit on them is a hit on their parent.
+    ///
+    /// NOTE: The tree has to be laid out first.
+    pub fn hit_test(&self, point: Point) -> Option<NodeRef<'a>> {
+        self.hit_test_all(point).first().copied()
+    }
+
+    /// Like [`LayoutTree::hit_test`], but also returns the nodes of the ancestors of the box that
+    /// was hit, from the node that was hit up to the document element.
+    pub fn hit_test_all(&self, point: Point) -> Vec<NodeRef<'a>> {
+        let mut chain = vec![];
+        if let Some(root) = &self.root {
+            root.hit_test(point, &mut chain);
+        }
+        chain
+    }
+}
+
+impl<'a> LayoutBox<'a> {
+    /// Hit tests this box and its descendants. If one of them is hit, this appends the nodes of
+    /// the box that was hit and its ancestors up to and including this box to `chain`, deepest
+    /// first, and returns `true`.
+    fn hit_test(&self, point: Point, chain: &mut Vec<NodeRef<'a>>) -> bool {
+        // NOTE: Children can overflow this box, so we test them even if the point is outside of
+        //       our border box.
+        let hit_child = self
+            .children_in_hit_testing_order()
+            .any(|child| child.hit_test(point, chain));
+
+        // SPEC: hidden: [...] the element is not visible [...] and cannot be the target of
+        //       pointer events.
+        // NOTE: Descendants with visibility: visible can still be hit.
+        let hit = hit_child
+            || (self.style.visibility == Visibility::Visible
+                && self.node.is_some()
+                && self.dimensions.border_box().contains(point));
+        if hit {
+            chain.extend(self.node);
+        }
+        hit
+    }
+
+    /// The children of this box from the topmost to the bottommost, which is the reverse of the
+    /// order they are painted in.
+    ///
+    /// FIXME: Use the stacking order of Appendix E, where positioned descendants are painted
+    ///        above all in-flow content of the stacking context, not just above their siblings.
+    ///        https://www.w3.org/TR/CSS2/zindex.html#painting-order
+    fn children_in_hit_testing_order(&self) -> impl Iterator<Item = &LayoutBox<'a>> {
+        let positioned = self.children.iter().filter(|child| child.is_positioned());
+        let non_positioned = self.children.iter().filter(|child| !child.is_positioned());
+        positioned.rev().chain(non_positioned.rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::parser::Parser;
+
+    const FIXTURE: &str = "<body style=\"margin: 0\">\
+        <div id=outer style=\"width: 200px; height: 100px; padding: 10px\">\
+            <div id=inner style=\"height: 20px\"></div>\
+        </div>\
+        <div id=relative style=\"position: relative; top: 20px; height: 50px\"></div>\
+        <div id=static style=\"height: 50px\"></div>\
+        <div id=first style=\"position: absolute; left: 0; top: 300px; width: 50px; height: 50px\"></div>\
+        <div id=second style=\"position: absolute; left: 25px; top: 300px; width: 50px; height: 50px\"></div>\
+        <div id=hidden style=\"visibility: hidden; height: 50px\"></div>\
+        </body>";
+
+    /// The ids, or otherwise the local names, of the nodes that `hit_test_all` returns for the
+    /// point (`x`, `y`) in the laid out `FIXTURE`.
+    fn hits(x: f64, y: f64) -> Vec<String> {
+        let parser = Parser::new(typed_arena::Arena::new(), FIXTURE);
+        let loaded = crate::load_with_parser(&parser);
+        let mut tree = LayoutTree::new(loaded.document, &loaded.stylesheets);
+        tree.layout(800.0);
+
+        let hits = tree.hit_test_all(Point { x, y });
+        assert_eq!(tree.hit_test(Point { x, y }), hits.first().copied());
+        hits.iter()
+            .map(|node| {
+                let element = node.as_element().unwrap();
+                element
+                    .get_attribute("id")
+                    .unwrap_or_else(|| element.local_name().to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn the_deepest_box_is_hit_first() {
+        assert_eq!(hits(15.0, 15.0), ["inner", "outer", "body", "html"]);
+        // NOTE: The padding is part of the border box.
+        assert_eq!(hits(5.0, 5.0), ["outer", "body", "html"]);
+        assert_eq!(hits(225.0, 50.0), ["body", "html"]);
+    }
+
+    #[test]
+    fn later_and_positioned_boxes_are_on_top() {
+        assert_eq!(hits(10.0, 310.0), ["first", "body", "html"]);
+        assert_eq!(hits(30.0, 310.0), ["second", "body", "html"]);
+
+        // NOTE: The relatively positioned box overlaps the top of the box after it.
+        assert_eq!(hits(10.0, 180.0), ["relative", "body", "html"]);
+        assert_eq!(hits(10.0, 200.0), ["static", "body", "html"]);
+    }
+
+    #[test]
+    fn hidden_boxes_are_not_hit() {
+        assert_eq!(hits(10.0, 230.0), ["body", "html"]);
+    }
+}
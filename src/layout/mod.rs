@@ -0,0 +1,12 @@
+pub use box_model::{Dimensions, Point, Rect};
+pub use display_list::DisplayItem;
+pub use text::LineBox;
+pub use tree::{BoxType, LayoutBox, LayoutTree};
+
+pub mod box_model;
+pub mod display_list;
+mod float;
+mod hit_test;
+mod positioned;
+mod text;
+pub mod tree;
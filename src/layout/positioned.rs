@@ -0,0 +1,332 @@
+use crate::css::length::{LengthPercentage, LengthPercentageAuto};
+
+use super::box_model::Rect;
+use super::LayoutBox;
+
+impl LayoutBox<'_> {
+    /// Shifts this box and its descendants by its relative offsets, without affecting the
+    /// position of any other box.
+    ///
+    /// https://www.w3.org/TR/CSS2/visuren.html#relative-positioning
+    pub(super) fn apply_relative_offset(
+        &mut self,
+        containing_block_width: f64,
+        containing_block_height: Option<f64>,
+    ) {
+        let inset = &self.style.inset;
+        let left = inset.left.resolve(containing_block_width);
+        let right = inset.right.resolve(containing_block_width);
+        // NOTE: Percentages of 'top' and 'bottom' behave like 'auto' if the height of the
+        //       containing block depends on its content.
+        let resolve_vertical =
+            |offset: LengthPercentageAuto| match (offset, containing_block_height) {
+                (LengthPercentageAuto::LengthPercentage(LengthPercentage::Percentage(_)), None) => {
+                    None
+                }
+                (offset, height) => offset.resolve(height.unwrap_or(0.0)),
+            };
+        let top = resolve_vertical(inset.top);
+        let bottom = resolve_vertical(inset.bottom);
+
+        // SPEC: If both 'left' and 'right' are 'auto' (their initial values), the used values are
+        //       '0' (i.e., the boxes stay in their original position).
+        //       If 'left' is 'auto', its used value is minus the value of 'right' (i.e., the boxes
+        //       move to the left by the value of 'right').
+        //       If 'right' is specified as 'auto', its used value is minus the value of 'left'.
+        //       If neither 'left' nor 'right' is 'auto', the position is over-constrained, and one
+        //       of them has to be ignored. If the 'direction' property of the containing block is
+        //       'ltr', the value of 'left' wins and 'right' becomes -'left'.
+        // FIXME: Let 'right' win if the direction is 'rtl'.
+        let dx = match (left, right) {
+            (Some(left), _) => left,
+            (None, Some(right)) => -right,
+            (None, None) => 0.0,
+        };
+
+        // SPEC: The 'top' and 'bottom' properties move relatively positioned element(s) up or
+        //       down without changing their size. [...] If both are 'auto', their used values are
+        //       both '0'. If one of them is 'auto', it becomes the negative of the other. If
+        //       neither is 'auto', 'bottom' is ignored (i.e., the used value of 'bottom' will be
+        //       minus the value of 'top').
+        let dy = match (top, bottom) {
+            (Some(top), _) => top,
+            (None, Some(bottom)) => -bottom,
+            (None, None) => 0.0,
+        };
+
+        self.translate(dx, dy);
+    }
+
+    /// Lays out the absolutely positioned boxes for which this positioned box forms the
+    /// containing block.
+    ///
+    /// SPEC: If the element has 'position: absolute', the containing block is established by the
+    ///       nearest ancestor with a 'position' of 'absolute', 'relative' or 'fixed', [...] the
+    ///       containing block is formed by the padding edge of the ancestor.
+    ///
+    /// https://www.w3.org/TR/CSS2/visudet.html#containing-block-details
+    pub(super) fn layout_absolutely_positioned_descendants(&mut self) {
+        let containing_block = self.dimensions.padding_box();
+        for child in self.children.iter_mut() {
+            child.layout_absolutely_positioned_boxes_in(containing_block);
+        }
+    }
+
+    /// Lays out this box if it is absolutely positioned, or otherwise the absolutely positioned
+    /// boxes among its descendants that have `containing_block` as their containing block.
+    pub(super) fn layout_absolutely_positioned_boxes_in(&mut self, containing_block: Rect) {
+        if self.is_absolutely_positioned() {
+            self.layout_absolutely_positioned(containing_block);
+            return;
+        }
+
+        // NOTE: Other positioned boxes are the containing block of their own absolutely
+        //       positioned descendants, which they lay out themselves.
+        if self.is_positioned() {
+            return;
+        }
+
+        for child in self.children.iter_mut() {
+            child.layout_absolutely_positioned_boxes_in(containing_block);
+        }
+    }
+
+    /// Lays out this absolutely positioned box and its descendants in `containing_block`.
+    ///
+    /// NOTE: Block layout left the static position of the box, the top left corner of its margin
+    ///       box had it been in the normal flow, in the position of its content area.
+    fn layout_absolutely_positioned(&mut self, containing_block: Rect) {
+        let static_position = (self.dimensions.content.x, self.dimensions.content.y);
+        self.calculate_absolute_width(containing_block, static_position.0);
+
+        let style = &self.style;
+        let dimensions = &mut self.dimensions;
+
+        // NOTE: Percentages of margins and paddings refer to the width of the containing block,
+        //       the ones of 'top', 'bottom' and 'height' to its height.
+        let top = style.inset.top.resolve(containing_block.height);
+        let bottom = style.inset.bottom.resolve(containing_block.height);
+        let height = style.height.resolve(containing_block.height);
+        // FIXME: Center the box vertically if both margins are 'auto' and none of 'top', 'height'
+        //        and 'bottom' are.
+        dimensions.margin.top = style
+            .margin
+            .top
+            .resolve(containing_block.width)
+            .unwrap_or(0.0);
+        dimensions.margin.bottom = style
+            .margin
+            .bottom
+            .resolve(containing_block.width)
+            .unwrap_or(0.0);
+        dimensions.padding.top = style.padding.top.resolve(containing_block.width);
+        dimensions.padding.bottom = style.padding.bottom.resolve(containing_block.width);
+        let border = style.used_border_width();
+        dimensions.border.top = border.top;
+        dimensions.border.bottom = border.bottom;
+        let above_content = dimensions.margin.top + dimensions.border.top + dimensions.padding.top;
+        let below_content =
+            dimensions.padding.bottom + dimensions.border.bottom + dimensions.margin.bottom;
+
+        // https://www.w3.org/TR/CSS2/visudet.html#abs-non-replaced-height
+        // SPEC: 5. 'height' is 'auto', 'top' and 'bottom' are not 'auto', then 'auto' values for
+        //          'margin-top' and 'margin-bottom' are set to 0 and solve for 'height'
+        let height = match (top, height, bottom) {
+            (Some(top), None, Some(bottom)) => Some(
+                (containing_block.height - top - above_content - below_content - bottom).max(0.0),
+            ),
+            (_, height, _) => height,
+        };
+
+        // SPEC: If all three of 'top', 'height', and 'bottom' are auto, set 'top' to the static
+        //       position [...]. If none of the three are 'auto': [...] If the values are
+        //       over-constrained, ignore the value for 'bottom' and solve for that value.
+        // NOTE: If only 'top' is 'auto' we can only solve for it once we know the height, so we
+        //       lay out the content at the static position first and move it afterwards.
+        let used_top = top.unwrap_or(static_position.1 - containing_block.y);
+        dimensions.content.y = containing_block.y + used_top + above_content;
+
+        self.layout_block_formatting_context(height);
+        if let Some(height) = height {
+            self.dimensions.content.height = height;
+        }
+
+        // SPEC: 4. 'top' is 'auto', 'height' and 'bottom' are not 'auto', then [...] solve for
+        //          'top'
+        //       1. 'top' and 'height' are 'auto' and 'bottom' is not 'auto', then the height is
+        //          based on the content per 10.6.7, [...] and solve for 'top'
+        if let (None, Some(bottom)) = (top, bottom) {
+            let content_y = containing_block.y + containing_block.height
+                - bottom
+                - below_content
+                - self.dimensions.content.height;
+            self.translate(0.0, content_y - self.dimensions.content.y);
+        }
+
+        self.layout_absolutely_positioned_descendants();
+    }
+
+    /// https://www.w3.org/TR/CSS2/visudet.html#abs-non-replaced-width
+    fn calculate_absolute_width(&mut self, containing_block: Rect, static_x: f64) {
+        let style = &self.style;
+        let containing_block_width = containing_block.width;
+
+        let left = style.inset.left.resolve(containing_block_width);
+        let right = style.inset.right.resolve(containing_block_width);
+        let width = style.width.resolve(containing_block_width);
+        let margin_left = style.margin.left.resolve(containing_block_width);
+        let margin_right = style.margin.right.resolve(containing_block_width);
+        let padding_left = style.padding.left.resolve(containing_block_width);
+        let padding_right = style.padding.right.resolve(containing_block_width);
+        let border = style.used_border_width();
+        let borders_and_paddings = border.left + padding_left + padding_right + border.right;
+
+        // SPEC: The constraint that determines the used values for these elements is:
+        //       'left' + 'margin-left' + 'border-left-width' + 'padding-left' + 'width' +
+        //       'padding-right' + 'border-right-width' + 'margin-right' + 'right' = width of
+        //       containing block
+        let remaining = |values: &[f64]| {
+            containing_block_width - borders_and_paddings - values.iter().sum::<f64>()
+        };
+
+        // SPEC: Otherwise, set 'auto' values for 'margin-left' and 'margin-right' to 0, and pick
+        //       the one of the following six rules that applies.
+        let used_margin_left = margin_left.unwrap_or(0.0);
+        let used_margin_right = margin_right.unwrap_or(0.0);
+        let margins = used_margin_left + used_margin_right;
+        // SPEC: [...] then the width is shrink-to-fit.
+        // FIXME: Shrink to the preferred width of the content once we can measure it. For now we
+        //        use the available width.
+        let shrink_to_fit = |offset: f64| remaining(&[offset, margins]).max(0.0);
+
+        let (left, width, margin_left, margin_right) = match (left, width, right) {
+            // SPEC: If none of the three is 'auto': If both 'margin-left' and 'margin-right' are
+            //       'auto', solve the equation under the extra constraint that the two margins get
+            //       equal values, unless this would make them negative, in which case when
+            //       direction of the containing block is 'ltr' [...], set 'margin-left' [...] to
+            //       zero and solve for 'margin-right'. If one of 'margin-left' or 'margin-right'
+            //       is 'auto', solve the equation for that value. If the values are
+            //       over-constrained, ignore the value for [...] 'right' (in case 'direction' is
+            //       'ltr') and solve for that value.
+            (Some(left), Some(width), Some(right)) => match (margin_left, margin_right) {
+                (None, None) => {
+                    let margin = (remaining(&[left, width, right]) / 2.0).max(0.0);
+                    (left, width, margin, margin)
+                }
+                (None, Some(margin_right)) => {
+                    let margin_left = remaining(&[left, width, margin_right, right]);
+                    (left, width, margin_left, margin_right)
+                }
+                (Some(margin_left), None) => {
+                    let margin_right = remaining(&[left, width, margin_left, right]);
+                    (left, width, margin_left, margin_right)
+                }
+                (Some(margin_left), Some(margin_right)) => (left, width, margin_left, margin_right),
+            },
+            // SPEC: 1. 'left' and 'width' are 'auto' and 'right' is not 'auto', then the width is
+            //          shrink-to-fit. Then solve for 'left'
+            (None, None, Some(right)) => {
+                let width = shrink_to_fit(right);
+                let left = remaining(&[width, margins, right]);
+                (left, width, used_margin_left, used_margin_right)
+            }
+            // SPEC: 2. 'left' and 'right' are 'auto' and 'width' is not 'auto', then if the
+            //          'direction' property of the element establishing the static-position
+            //          containing block is 'ltr' set 'left' to the static position [...]. Then
+            //          solve for [...] 'right' (if 'direction' is 'ltr').
+            // NOTE: If all three are 'auto', 'left' is also set to the static position, after
+            //       which rule 3 applies.
+            (None, width, None) => {
+                let left = static_x - containing_block.x;
+                let width = width.unwrap_or_else(|| shrink_to_fit(left));
+                (left, width, used_margin_left, used_margin_right)
+            }
+            // SPEC: 3. 'width' and 'right' are 'auto' and 'left' is not 'auto', then the width is
+            //          shrink-to-fit . Then solve for 'right'
+            (Some(left), None, None) => {
+                let width = shrink_to_fit(left);
+                (left, width, used_margin_left, used_margin_right)
+            }
+            // SPEC: 4. 'left' is 'auto', 'width' and 'right' are not 'auto', then solve for 'left'
+            (None, Some(width), Some(right)) => {
+                let left = remaining(&[width, margins, right]);
+                (left, width, used_margin_left, used_margin_right)
+            }
+            // SPEC: 5. 'width' is 'auto', 'left' and 'right' are not 'auto', then solve for
+            //          'width'
+            (Some(left), None, Some(right)) => {
+                let width = remaining(&[left, margins, right]).max(0.0);
+                (left, width, used_margin_left, used_margin_right)
+            }
+            // SPEC: 6. 'right' is 'auto', 'left' and 'width' are not 'auto', then solve for
+            //          'right'
+            (Some(left), Some(width), None) => (left, width, used_margin_left, used_margin_right),
+        };
+
+        let dimensions = &mut self.dimensions;
+        dimensions.content.width = width;
+        dimensions.margin.left = margin_left;
+        dimensions.margin.right = margin_right;
+        dimensions.padding.left = padding_left;
+        dimensions.padding.right = padding_right;
+        dimensions.border.left = border.left;
+        dimensions.border.right = border.right;
+        dimensions.content.x = containing_block.x + left + margin_left + border.left + padding_left;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::box_model::tests::dimensions;
+
+    #[test]
+    fn relative_offsets_do_not_move_siblings() {
+        let input = "<body style=\"margin: 0\">\
+                     <div id=shifted style=\"position: relative; top: 5px; left: 10px; height: 20px\"></div>\
+                     <div id=sibling style=\"height: 20px\"></div></body>";
+        let shifted = dimensions(input, 800.0, "shifted");
+        assert_eq!((shifted.content.x, shifted.content.y), (10.0, 5.0));
+        let sibling = dimensions(input, 800.0, "sibling");
+        assert_eq!((sibling.content.x, sibling.content.y), (0.0, 20.0));
+
+        // NOTE: 'left' wins over 'right', and 'top' over 'bottom'.
+        let input = "<body style=\"margin: 0\"><div id=shifted style=\"position: relative; \
+                     top: 5px; bottom: 50px; left: 10px; right: 50px\"></div></body>";
+        let shifted = dimensions(input, 800.0, "shifted");
+        assert_eq!((shifted.content.x, shifted.content.y), (10.0, 5.0));
+    }
+
+    #[test]
+    fn absolute_boxes_are_positioned_against_the_nearest_positioned_ancestor() {
+        let input = "<body style=\"margin: 0\"><div style=\"height: 30px\"></div>\
+                     <div style=\"position: relative; margin-left: 20px; padding: 5px; width: 200px; height: 100px\">\
+                     <div style=\"height: 10px\"><div id=child style=\"position: absolute; top: 10px; left: 15px\">x</div></div>\
+                     <div id=after style=\"height: 10px\"></div>\
+                     </div></body>";
+        // NOTE: The containing block is the padding box of the relatively positioned parent.
+        let child = dimensions(input, 800.0, "child");
+        assert_eq!((child.content.x, child.content.y), (35.0, 40.0));
+        // NOTE: An absolutely positioned box with an auto width shrinks to fit its content.
+        assert!(child.content.width > 0.0 && child.content.width < 200.0);
+
+        // NOTE: The absolutely positioned box is taken out of the normal flow.
+        let after = dimensions(input, 800.0, "after");
+        assert_eq!(after.content.y, 45.0);
+    }
+
+    #[test]
+    fn right_and_bottom_anchor_the_box() {
+        let input = "<body style=\"margin: 0\"><div style=\"position: relative; width: 200px; height: 100px\">\
+                     <div id=child style=\"position: absolute; right: 10px; bottom: 20px; width: 50px; height: 30px\"></div>\
+                     </div></body>";
+        let child = dimensions(input, 800.0, "child");
+        assert_eq!((child.content.x, child.content.y), (140.0, 50.0));
+
+        // NOTE: Without a positioned ancestor, the initial containing block is used.
+        let input =
+            "<body style=\"margin: 0\"><div id=child style=\"position: absolute; right: 0; \
+                     width: 100px\"></div></body>";
+        let child = dimensions(input, 800.0, "child");
+        assert_eq!(child.content.x, 700.0);
+    }
+}
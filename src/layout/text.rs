@@ -0,0 +1,324 @@
+use super::box_model::Rect;
+use super::float::FloatContext;
+use super::{BoxType, LayoutBox, LayoutTree};
+
+/// The advance width of every character, as a fraction of the font size.
+///
+/// FIXME: Measure the text with the glyphs of the used font once we have fonts. Until then every
+///        character is as wide as a cell of the built-in block font of the painter at the initial
+///        font size.
+const CHARACTER_ADVANCE: f64 = 0.5;
+
+/// A line box in an inline formatting context, together with the text on it.
+///
+/// https://www.w3.org/TR/CSS2/visuren.html#inline-formatting
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineBox {
+    /// The position and size of the line box, in the same coordinate space as the rest of the
+    /// layout.
+    pub rect: Rect,
+    pub text: String,
+}
+
+impl LayoutTree<'_> {
+    /// Returns the text a reader would see when this tree is rendered.
+    ///
+    /// Whitespace is collapsed or preserved according to the `white-space` of the text, every
+    /// block-level box starts on a new line and a `<br>` ends the current line. Elements that
+    /// don't generate boxes (like `script`, `style` and everything in `head`) don't contribute
+    /// any text. List items start with the contents of their marker.
+    pub fn visible_text(&self) -> String {
+        let mut text = String::new();
+        if let Some(root) = &self.root {
+            append_visible_text(root, &mut text);
+        }
+        text.trim_end().to_string()
+    }
+}
+
+impl LayoutBox<'_> {
+    /// Stacks the lines of the inline content of this box in line boxes from the top of
+    /// `content`, the content area of this box, next to the floats of `floats`. Lines that don't
+    /// fit in their line box are broken at soft wrap opportunities if the `white-space` of this
+    /// box allows it.
+    ///
+    /// SPEC: In an inline formatting context, boxes are laid out horizontally, one after the
+    ///       other, beginning at the top of a containing block. [...] In general, the left edge
+    ///       of a line box touches the left edge of its containing block and the right edge
+    ///       touches the right edge of its containing block. However, floating boxes may come
+    ///       between the containing block edge and the line box edge.
+    /// FIXME: Break according to the `white-space` of every text run instead of the one of this
+    ///        box.
+    pub(super) fn layout_line_boxes(&mut self, content: Rect, floats: &FloatContext) {
+        let line_height = self.line_height();
+        let advance = CHARACTER_ADVANCE * self.style.font_size;
+        let white_space = self.style.white_space;
+
+        let mut line_boxes = vec![];
+        let mut top = content.y;
+        for line in self.inline_content_lines() {
+            // NOTE: Every forced line break starts a new line box, even if the line is empty.
+            let mut rest = line.as_str();
+            loop {
+                let (left, right) = floats.available_span(top, line_height, content);
+                let rect = Rect {
+                    x: left,
+                    y: top,
+                    width: (right - left).max(0.0),
+                    height: line_height,
+                };
+                top += line_height;
+
+                let text = match white_space.wraps() {
+                    true => {
+                        let columns = rect.width / advance;
+                        let (text, next) =
+                            break_line(rest, columns, white_space.collapses_spaces());
+                        rest = next;
+                        text
+                    }
+                    false => std::mem::take(&mut rest),
+                };
+                line_boxes.push(LineBox {
+                    rect,
+                    text: text.to_string(),
+                });
+
+                if rest.is_empty() {
+                    break;
+                }
+            }
+        }
+        self.line_boxes = line_boxes;
+    }
+
+    /// Returns the lines of text of this box if it is a block container that only contains
+    /// inline-level content, or an empty list otherwise.
+    ///
+    /// NOTE: Whitespace is processed like in [`LayoutTree::visible_text`], and a new line only
+    ///       starts after a `<br>` or a preserved newline. Lines that don't fit are broken later,
+    ///       in [`LayoutBox::layout_line_boxes`].
+    pub(super) fn inline_content_lines(&self) -> Vec<String> {
+        if !self.is_block_container() || self.children.iter().any(LayoutBox::is_block_level) {
+            return vec![];
+        }
+
+        let mut text = String::new();
+        for child in &self.children {
+            append_visible_text(child, &mut text);
+        }
+        end_line(&mut text);
+        if text.is_empty() {
+            return vec![];
+        }
+
+        // NOTE: A <br> at the end of the content ends the last line instead of starting a new one.
+        let text = text.strip_suffix('\n').unwrap_or(&text);
+        text.split('\n').map(str::to_string).collect()
+    }
+}
+
+fn append_visible_text(layout_box: &LayoutBox, text: &mut String) {
+    match &layout_box.box_type {
+        BoxType::Text(data) => {
+            let white_space = layout_box.style.white_space;
+            for c in data.chars() {
+                if c == '\n' && white_space.preserves_segment_breaks() {
+                    // SPEC: If white-space is set to pre, pre-wrap, or pre-line, segment breaks
+                    //       are not collapsible and are instead transformed into a preserved line
+                    //       feed (U+000A).
+                    // NOTE: Collapsible spaces before the segment break are removed, preserved
+                    //       spaces are kept.
+                    if white_space.collapses_spaces() {
+                        end_line(text);
+                    }
+                    text.push('\n');
+                } else if c.is_ascii_whitespace() && white_space.collapses_spaces() {
+                    // https://drafts.csswg.org/css-text-3/#white-space-phase-1
+                    // SPEC: Any sequence of collapsible spaces and tabs immediately preceding or
+                    //       following a segment break is removed. Any collapsible space
+                    //       immediately following another collapsible space—even one outside the
+                    //       boundary of the inline containing that space, provided both spaces
+                    //       are within the same inline formatting context—is collapsed to have
+                    //       zero advance width.
+                    // https://drafts.csswg.org/css-text-3/#white-space-phase-2
+                    // SPEC: A sequence of collapsible spaces at the beginning of a line is
+                    //       removed.
+                    if !text.is_empty() && !text.ends_with([' ', '\n']) {
+                        text.push(' ');
+                    }
+                } else {
+                    // FIXME: Align preserved tabs to tab stops.
+                    //        https://drafts.csswg.org/css-text-3/#tab-size-property
+                    text.push(c);
+                }
+            }
+        }
+        BoxType::Inline
+            if layout_box
+                .node
+                .is_some_and(|node| node.is_element_with_tag("br")) =>
+        {
+            // https://html.spec.whatwg.org/multipage/rendering.html#phrasing-content-3
+            // SPEC: br { display-outside: newline; } /* this also has bidi implications */
+            end_line(text);
+            text.push('\n');
+        }
+        BoxType::Block | BoxType::AnonymousBlock => {
+            start_new_line(text);
+            for child in &layout_box.children {
+                append_visible_text(child, text);
+            }
+            start_new_line(text);
+        }
+        BoxType::Inline | BoxType::InlineBlock => {
+            for child in &layout_box.children {
+                append_visible_text(child, text);
+            }
+        }
+        BoxType::Marker(marker) => text.push_str(marker),
+    }
+}
+
+/// Breaks `text` at the last soft wrap opportunity where the text before it fits in `columns`
+/// characters, and returns the text on the line and the text that is left for the next lines.
+///
+/// https://drafts.csswg.org/css-text-3/#line-breaking
+/// SPEC: For most writing systems, in the absence of hyphenation a soft wrap opportunity occurs
+///       only at word boundaries.
+/// NOTE: We break after every sequence of spaces that isn't at the start of the line. If no
+///       such break fits, the line ends at the first one and overflows.
+/// FIXME: Break at the other soft wrap opportunities of UAX #14.
+///        https://www.unicode.org/reports/tr14/
+fn break_line(text: &str, columns: f64, collapses_spaces: bool) -> (&str, &str) {
+    let fits = |text: &str| text.chars().count() as f64 <= columns;
+    if fits(text.trim_end_matches(' ')) {
+        return (text, "");
+    }
+
+    let mut opportunities = text
+        .char_indices()
+        .filter(|(index, c)| *c == ' ' && *index > 0 && !text[..*index].ends_with(' '))
+        .map(|(start, _)| {
+            let end = text[start..]
+                .find(|c| c != ' ')
+                .map_or(text.len(), |length| start + length);
+            (start, end)
+        })
+        .peekable();
+
+    let Some(&first) = opportunities.peek() else {
+        return (text, "");
+    };
+    let (start, end) = opportunities
+        .take_while(|(start, _)| fits(&text[..*start]))
+        .last()
+        .unwrap_or(first);
+
+    // https://drafts.csswg.org/css-text-3/#white-space-phase-2
+    // SPEC: A sequence of collapsible spaces at the end of a line is removed.
+    //       If there remains any sequence of white space [...] the UA must (unconditionally)
+    //       hang this sequence [if white-space is pre-wrap].
+    match collapses_spaces {
+        true => (&text[..start], &text[end..]),
+        false => (&text[..end], &text[end..]),
+    }
+}
+
+/// Removes the collapsible spaces at the end of the current line.
+///
+/// https://drafts.csswg.org/css-text-3/#white-space-phase-2
+fn end_line(text: &mut String) {
+    text.truncate(text.trim_end_matches(' ').len());
+}
+
+/// Ends the current line, unless we are already at the start of a line.
+fn start_new_line(text: &mut String) {
+    end_line(text);
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::LineBox;
+    use crate::html::parser::Parser;
+    use crate::layout::{LayoutBox, LayoutTree};
+
+    /// Lays out `input` as a document in a viewport that is `viewport_width` wide and returns
+    /// all of its line boxes in tree order.
+    pub(in crate::layout) fn line_boxes(input: &str, viewport_width: f64) -> Vec<LineBox> {
+        fn collect(layout_box: &LayoutBox, line_boxes: &mut Vec<LineBox>) {
+            line_boxes.extend(layout_box.line_boxes.iter().cloned());
+            for child in &layout_box.children {
+                collect(child, line_boxes);
+            }
+        }
+
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let loaded = crate::load_with_parser(&parser);
+        let mut tree = LayoutTree::new(loaded.document, &loaded.stylesheets);
+        tree.layout(viewport_width);
+
+        let mut line_boxes = vec![];
+        if let Some(root) = &tree.root {
+            collect(root, &mut line_boxes);
+        }
+        line_boxes
+    }
+
+    /// The text of the line boxes of `text` in a body with the given `white-space`, where a line
+    /// fits five characters.
+    fn lines(text: &str, white_space: &str) -> Vec<String> {
+        let input = format!("<body style=\"margin: 0; white-space: {white_space}\">{text}</body>");
+        line_boxes(&input, 40.0)
+            .into_iter()
+            .map(|line_box| line_box.text)
+            .collect()
+    }
+
+    #[test]
+    fn normal_wraps_and_collapses_spaces() {
+        assert_eq!(lines("aa bb cc", "normal"), ["aa bb", "cc"]);
+        assert_eq!(lines("aa  \n  bb   cc", "normal"), ["aa bb", "cc"]);
+    }
+
+    #[test]
+    fn words_that_dont_fit_overflow_their_line() {
+        assert_eq!(lines("abcdefgh ij", "normal"), ["abcdefgh", "ij"]);
+        assert_eq!(lines("abcdefgh", "normal"), ["abcdefgh"]);
+    }
+
+    #[test]
+    fn nowrap_doesnt_wrap() {
+        assert_eq!(lines("aa bb cc", "nowrap"), ["aa bb cc"]);
+        assert_eq!(lines("aa bb<br>cc dd ee", "nowrap"), ["aa bb", "cc dd ee"]);
+    }
+
+    #[test]
+    fn pre_only_breaks_at_preserved_newlines() {
+        assert_eq!(lines("aa  bb cc\ndd", "pre"), ["aa  bb cc", "dd"]);
+    }
+
+    #[test]
+    fn pre_wrap_hangs_preserved_spaces() {
+        assert_eq!(lines("aa  bb cc", "pre-wrap"), ["aa  ", "bb cc"]);
+        assert_eq!(lines("aa bb cc\ndd", "pre-wrap"), ["aa bb ", "cc", "dd"]);
+    }
+
+    #[test]
+    fn pre_line_wraps_and_keeps_newlines() {
+        assert_eq!(lines("aa   bb cc\ndd", "pre-line"), ["aa bb", "cc", "dd"]);
+    }
+
+    #[test]
+    fn wrapped_lines_are_stacked() {
+        let line_boxes = line_boxes(
+            "<body style=\"margin: 0; line-height: 20px\">aa bb cc</body>",
+            40.0,
+        );
+        let tops: Vec<_> = line_boxes.iter().map(|line_box| line_box.rect.y).collect();
+        assert_eq!(tops, [0.0, 20.0]);
+    }
+}
@@ -0,0 +1,439 @@
+use crate::css::display::Display;
+use crate::css::float::Float;
+use crate::css::list::ListItemOrdinals;
+use crate::css::parser::types::StyleSheet;
+use crate::css::position::Position;
+use crate::dom::node::{DumpSettings, NodeType};
+use crate::dom::NodeRef;
+use crate::style::{ComputedStyle, StyleComputer};
+
+use super::box_model::Dimensions;
+use super::text::LineBox;
+
+/// The box tree generated from a document.
+///
+/// https://drafts.csswg.org/css-display-3/#box-tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutTree<'a> {
+    /// The box generated by the document element, if it generates one.
+    pub root: Option<LayoutBox<'a>>,
+}
+
+/// https://drafts.csswg.org/css-display-3/#box
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutBox<'a> {
+    pub box_type: BoxType,
+    /// The node that generated this box, or `None` for anonymous boxes.
+    pub node: Option<NodeRef<'a>>,
+    pub style: ComputedStyle,
+    /// The used dimensions of the box, which are only known after [`LayoutTree::layout`].
+    pub dimensions: Dimensions,
+    /// The line boxes of the inline formatting context this box establishes, if it only contains
+    /// inline-level content, which are only known after [`LayoutTree::layout`].
+    pub line_boxes: Vec<LineBox>,
+    pub children: Vec<LayoutBox<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoxType {
+    Block,
+    Inline,
+    InlineBlock,
+    /// https://drafts.csswg.org/css-display-3/#anonymous
+    AnonymousBlock,
+    /// https://drafts.csswg.org/css-display-3/#text-run
+    Text(String),
+    /// The ::marker pseudo-element of a list item, with its marker string.
+    ///
+    /// FIXME: Lay this out as an inline box at the start of the first line box once we have
+    ///        inline layout.
+    ///
+    /// https://drafts.csswg.org/css-lists-3/#marker-pseudo
+    Marker(String),
+}
+
+impl<'a> LayoutTree<'a> {
+    /// Generates the box tree for `document`, styled by `stylesheets` on top of the user agent defaults.
+    pub fn new(document: NodeRef<'a>, stylesheets: &[StyleSheet]) -> Self {
+        let style_computer = StyleComputer::new(stylesheets);
+
+        let root = document.first_element_child().and_then(|document_element| {
+            let mut ordinals = ListItemOrdinals::new(document);
+            build_box(document_element, None, &style_computer, &mut ordinals)
+        });
+
+        Self { root }
+    }
+
+    pub fn dump(&self, settings: DumpSettings) {
+        if let Some(root) = &self.root {
+            root.internal_dump("", &settings);
+        }
+    }
+}
+
+impl<'a> From<NodeRef<'a>> for LayoutTree<'a> {
+    fn from(document: NodeRef<'a>) -> Self {
+        Self::new(document, &[])
+    }
+}
+
+impl<'a> LayoutBox<'a> {
+    fn new(box_type: BoxType, node: Option<NodeRef<'a>>, style: ComputedStyle) -> Self {
+        Self {
+            box_type,
+            node,
+            style,
+            dimensions: Dimensions::default(),
+            line_boxes: vec![],
+            children: vec![],
+        }
+    }
+
+    /// SPEC: The properties of anonymous boxes are inherited from the enclosing non-anonymous
+    ///       box.
+    fn anonymous_block(children: Vec<LayoutBox<'a>>, parent_style: &ComputedStyle) -> Self {
+        let style = ComputedStyle {
+            display: Display::Block,
+            ..ComputedStyle::inherit(parent_style)
+        };
+
+        Self {
+            box_type: BoxType::AnonymousBlock,
+            node: None,
+            style,
+            dimensions: Dimensions::default(),
+            line_boxes: vec![],
+            children,
+        }
+    }
+
+    /// https://drafts.csswg.org/css-display-3/#block-level
+    pub fn is_block_level(&self) -> bool {
+        matches!(self.box_type, BoxType::Block | BoxType::AnonymousBlock)
+    }
+
+    /// https://www.w3.org/TR/CSS2/visuren.html#floats
+    pub fn is_floating(&self) -> bool {
+        self.style.float != Float::None
+    }
+
+    /// https://www.w3.org/TR/CSS2/visuren.html#positioned-element
+    pub fn is_positioned(&self) -> bool {
+        self.style.position != Position::Static
+    }
+
+    /// https://www.w3.org/TR/CSS2/visuren.html#absolutely-positioned
+    pub fn is_absolutely_positioned(&self) -> bool {
+        self.style.position == Position::Absolute
+    }
+
+    /// https://drafts.csswg.org/css-display-3/#inline-level
+    pub fn is_inline_level(&self) -> bool {
+        matches!(
+            self.box_type,
+            BoxType::Inline | BoxType::InlineBlock | BoxType::Text(_)
+        )
+    }
+
+    /// https://drafts.csswg.org/css-display-3/#block-container
+    pub fn is_block_container(&self) -> bool {
+        matches!(
+            self.box_type,
+            BoxType::Block | BoxType::InlineBlock | BoxType::AnonymousBlock
+        )
+    }
+
+    fn is_collapsible_whitespace(&self) -> bool {
+        let white_space = self.style.white_space;
+        match &self.box_type {
+            BoxType::Text(text) => text.chars().all(|c| {
+                c.is_ascii_whitespace()
+                    && white_space.collapses_spaces()
+                    && !(c == '\n' && white_space.preserves_segment_breaks())
+            }),
+            _ => false,
+        }
+    }
+
+    fn internal_dump(&self, indentation: &str, settings: &DumpSettings) {
+        let (yellow, gray, white, reset) = match settings.color {
+            true => ("\x1b[33m", "\x1b[90m", "\x1b[37m", "\x1b[0m"),
+            false => ("", "", "", ""),
+        };
+
+        let name = match &self.box_type {
+            BoxType::Block => "BlockBox",
+            BoxType::Inline => "InlineBox",
+            BoxType::InlineBlock => "InlineBlockBox",
+            BoxType::AnonymousBlock => "AnonymousBlockBox",
+            BoxType::Text(_) => "TextRun",
+            BoxType::Marker(_) => "Marker",
+        };
+
+        let description = match (&self.box_type, self.node) {
+            (BoxType::Marker(marker), _) => format!(" {gray}\"{white}{marker}{gray}\""),
+            (BoxType::Text(text), _) => {
+                let text = match settings.trim_text {
+                    true => text.trim(),
+                    false => text.as_str(),
+                };
+                format!(" {gray}\"{white}{text}{gray}\"")
+            }
+            (_, Some(node)) => format!(" {gray}<{}>", node.node_name().to_ascii_lowercase()),
+            (_, None) => String::new(),
+        };
+
+        println!("{indentation}{yellow}{name}{description}{reset}");
+        for child in &self.children {
+            let mut indentation = indentation.to_string();
+            indentation.push_str(settings.indentation);
+            child.internal_dump(&indentation, settings);
+        }
+        if let Some(closing_marker) = settings.closing_marker {
+            println!("{indentation}{closing_marker}");
+        }
+    }
+}
+
+/// Generates the box for `node` and its descendants, if it generates one.
+///
+/// `parent_style` is the computed style of the parent element, which `node` inherits from, and
+/// `ordinals` numbers the list items among the children of that parent.
+///
+/// https://drafts.csswg.org/css-display-3/#box-generation
+fn build_box<'a>(
+    node: NodeRef<'a>,
+    parent_style: Option<&ComputedStyle>,
+    style_computer: &StyleComputer,
+    ordinals: &mut ListItemOrdinals,
+) -> Option<LayoutBox<'a>> {
+    let mut layout_box = match &node.node_type {
+        NodeType::Element(_) => {
+            let style = style_computer.compute_style(node, parent_style);
+            let box_type = match style.display {
+                // SPEC: The element and its descendants generate no boxes or text runs.
+                Display::None => return None,
+                Display::Block | Display::ListItem => BoxType::Block,
+                Display::Inline => BoxType::Inline,
+                Display::InlineBlock => BoxType::InlineBlock,
+            };
+            LayoutBox::new(box_type, Some(node), style)
+        }
+        // SPEC: [...] the text run inherits from its parent element.
+        NodeType::Text { data } => {
+            let style = parent_style.map(ComputedStyle::inherit).unwrap_or_default();
+            return Some(LayoutBox::new(
+                BoxType::Text(data.borrow().clone()),
+                Some(node),
+                style,
+            ));
+        }
+        _ => return None,
+    };
+
+    let mut child_ordinals = ListItemOrdinals::new(node);
+    let mut children = node
+        .child_nodes()
+        .iter()
+        .filter_map(|child| {
+            build_box(
+                child,
+                Some(&layout_box.style),
+                style_computer,
+                &mut child_ordinals,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if layout_box.style.display.generates_marker() {
+        // NOTE: A list item is numbered even if it has no marker, so that its siblings keep
+        //       their ordinals.
+        let ordinal = ordinals.next(node);
+        // NOTE: The marker is inline-level, so it ends up in the same anonymous block as the
+        //       inline content that follows it.
+        // FIXME: Put the marker in the first line of a block-level first child.
+        if let Some(marker) = layout_box.style.list_style_type.marker_text(ordinal) {
+            children.insert(
+                0,
+                LayoutBox::new(
+                    BoxType::Marker(marker),
+                    None,
+                    ComputedStyle::inherit(&layout_box.style),
+                ),
+            );
+        }
+    }
+
+    layout_box.children = match layout_box.is_block_container() {
+        true => wrap_inline_level_runs_in_anonymous_blocks(children, &layout_box.style),
+        // FIXME: Split inline boxes that contain block-level boxes.
+        //        https://www.w3.org/TR/CSS2/visuren.html#anonymous-block-level
+        false => children,
+    };
+
+    Some(layout_box)
+}
+
+/// SPEC: If a block container box has a block-level box inside it, then we force it to have only
+///       block-level boxes inside it.
+///
+/// https://www.w3.org/TR/CSS2/visuren.html#anonymous-block-level
+fn wrap_inline_level_runs_in_anonymous_blocks<'a>(
+    children: Vec<LayoutBox<'a>>,
+    parent_style: &ComputedStyle,
+) -> Vec<LayoutBox<'a>> {
+    if !children.iter().any(LayoutBox::is_block_level) {
+        return children;
+    }
+
+    let mut wrapped = vec![];
+    let mut inline_run = vec![];
+
+    for child in children {
+        if child.is_block_level() {
+            flush_inline_run(&mut inline_run, &mut wrapped, parent_style);
+            wrapped.push(child);
+        } else {
+            inline_run.push(child);
+        }
+    }
+    flush_inline_run(&mut inline_run, &mut wrapped, parent_style);
+
+    wrapped
+}
+
+fn flush_inline_run<'a>(
+    inline_run: &mut Vec<LayoutBox<'a>>,
+    wrapped: &mut Vec<LayoutBox<'a>>,
+    parent_style: &ComputedStyle,
+) {
+    // NOTE: A run of only collapsible whitespace would generate an empty anonymous block, so we drop it.
+    if inline_run.iter().all(LayoutBox::is_collapsible_whitespace) {
+        inline_run.clear();
+        return;
+    }
+
+    wrapped.push(LayoutBox::anonymous_block(
+        std::mem::take(inline_run),
+        parent_style,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::parser::Parser;
+
+    /// The box tree generated for the children of the body of `input`, in a compact form.
+    fn body_boxes(input: &str) -> String {
+        fn describe(layout_box: &LayoutBox) -> String {
+            let name = match (&layout_box.box_type, layout_box.node) {
+                (BoxType::Text(text), _) => return format!("{text:?}"),
+                (BoxType::Marker(marker), _) => return format!("marker({marker:?})"),
+                (BoxType::AnonymousBlock, _) => "anonymous".to_string(),
+                (box_type, Some(node)) => {
+                    format!("{box_type:?}<{}>", node.node_name().to_ascii_lowercase())
+                }
+                (box_type, None) => format!("{box_type:?}"),
+            };
+            let children = layout_box
+                .children
+                .iter()
+                .map(describe)
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!("{name}[{children}]")
+        }
+
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let loaded = crate::load_with_parser(&parser);
+        let tree = LayoutTree::new(loaded.document, &loaded.stylesheets);
+        let html = tree.root.expect("the html element should generate a box");
+        let body = html
+            .children
+            .iter()
+            .find(|child| {
+                child
+                    .node
+                    .is_some_and(|node| node.is_element_with_tag("body"))
+            })
+            .expect("the body should generate a box");
+        body.children
+            .iter()
+            .map(describe)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn display_block_makes_inline_elements_block_level() {
+        assert_eq!(
+            body_boxes("<span>a</span><span>b</span>"),
+            r#"Inline<span>["a"] Inline<span>["b"]"#
+        );
+        assert_eq!(
+            body_boxes("<style>span { display: block }</style><span>a</span><span>b</span>"),
+            r#"Block<span>["a"] Block<span>["b"]"#
+        );
+    }
+
+    #[test]
+    fn inline_content_next_to_blocks_is_wrapped_in_anonymous_blocks() {
+        assert_eq!(
+            body_boxes("<style>div { display: inline }</style><p>a</p><div>b</div>c<p>d</p>"),
+            r#"Block<p>["a"] anonymous[Inline<div>["b"] "c"] Block<p>["d"]"#
+        );
+        assert_eq!(
+            body_boxes("<style>b { display: inline-block }</style>a<b>b</b><p>c</p>"),
+            r#"anonymous["a" InlineBlock<b>["b"]] Block<p>["c"]"#
+        );
+    }
+
+    #[test]
+    fn display_none_prunes_the_subtree() {
+        assert_eq!(
+            body_boxes(
+                "<style>.hidden { display: none }</style>\
+                 <p>a</p><div class=hidden><p>b</p></div><p class=hidden>c</p>"
+            ),
+            r#"Block<p>["a"]"#
+        );
+    }
+
+    #[test]
+    fn list_items_generate_a_marker() {
+        assert_eq!(
+            body_boxes("<ol><li>a</li><li style=\"display: block\">b</li><li>c</li></ol>"),
+            r#"Block<ol>[Block<li>[marker("1. ") "a"] Block<li>["b"] Block<li>[marker("2. ") "c"]]"#
+        );
+    }
+
+    #[test]
+    fn list_items_are_numbered_from_the_start_and_value_attributes() {
+        assert_eq!(
+            body_boxes("<ol start=3><li>a</li><li>b</li><li>c</li></ol>"),
+            r#"Block<ol>[Block<li>[marker("3. ") "a"] Block<li>[marker("4. ") "b"] Block<li>[marker("5. ") "c"]]"#
+        );
+        assert_eq!(
+            body_boxes("<ol start=x><li>a</li><li value=-2>b</li><li>c</li></ol>"),
+            r#"Block<ol>[Block<li>[marker("1. ") "a"] Block<li>[marker("-2. ") "b"] Block<li>[marker("-1. ") "c"]]"#
+        );
+    }
+
+    #[test]
+    fn nested_lists_use_different_markers() {
+        // NOTE: The marker is inline content, so it ends up in the anonymous block with the text
+        //       in front of a nested list.
+        assert_eq!(
+            body_boxes("<ul><li>a<ul><li>b<ol><li>c<ul><li>d</li></ul></li></ol></li></ul></li></ul>"),
+            "Block<ul>[Block<li>[anonymous[marker(\"\u{2022} \") \"a\"] Block<ul>[Block<li>[\
+             anonymous[marker(\"\u{25E6} \") \"b\"] Block<ol>[Block<li>[\
+             anonymous[marker(\"1. \") \"c\"] Block<ul>[Block<li>[marker(\"\u{25AA} \") \"d\"]]]]]]]]"
+        );
+        assert_eq!(
+            body_boxes("<ul style=\"list-style-type: none\"><li>a</li></ul>"),
+            r#"Block<ul>[Block<li>["a"]]"#
+        );
+    }
+}
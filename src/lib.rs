@@ -0,0 +1,12 @@
+pub mod arena_tree;
+pub mod css;
+pub mod dom;
+pub mod html;
+pub mod layout;
+mod load;
+#[cfg(feature = "paint")]
+pub mod paint;
+pub mod style;
+pub mod url;
+
+pub use load::{load_with_parser, LoadedDocument};
@@ -0,0 +1,68 @@
+use crate::css::parser::types::StyleSheet;
+use crate::dom::NodeRef;
+use crate::html::parser::Parser;
+
+/// A parsed document together with the author styles it carries.
+#[derive(Debug)]
+pub struct LoadedDocument<'a> {
+    pub document: NodeRef<'a>,
+    /// The stylesheets of the `style` elements in the document, in tree order.
+    ///
+    /// NOTE: The `style` attributes of elements are not part of these, because the cascade reads
+    ///       them from the element itself. See [`StyleComputer`](crate::style::StyleComputer).
+    ///
+    /// See [`Node::stylesheets`](crate::dom::Node::stylesheets).
+    pub stylesheets: Vec<StyleSheet>,
+}
+
+/// Parses the input of `parser` as a document, together with the CSS in its `style` elements.
+///
+/// NOTE: The document is allocated in the arena of `parser`, so the parser has to outlive it.
+pub fn load_with_parser<'a>(parser: &'a Parser<'a>) -> LoadedDocument<'a> {
+    let document = parser.allocate_node(parser.parse());
+    let stylesheets = document.stylesheets();
+
+    LoadedDocument {
+        document,
+        stylesheets,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::load_with_parser;
+    use crate::css::color::Color;
+    use crate::css::parser::types::Rule;
+    use crate::html::parser::Parser;
+    use crate::style::StyleComputer;
+
+    #[test]
+    fn style_elements_and_style_attributes() {
+        let parser = Parser::new(
+            typed_arena::Arena::new(),
+            "<style>p { color: red } #p { color: green }</style><p id=p style=\"color: blue\">Hello</p>",
+        );
+        let loaded = load_with_parser(&parser);
+
+        let paragraph = loaded
+            .document
+            .descendants()
+            .find(|node| node.is_element_with_tag("p"))
+            .expect("document should have a paragraph");
+        assert_eq!(paragraph.text_content().as_deref(), Some("Hello"));
+
+        assert_eq!(loaded.stylesheets.len(), 1);
+        let [Rule::QualifiedRule(_), Rule::QualifiedRule(_)] =
+            loaded.stylesheets[0].rules.as_slice()
+        else {
+            panic!(
+                "expected two qualified rules: {:?}",
+                loaded.stylesheets[0].rules
+            );
+        };
+
+        // NOTE: The style attribute wins over every selector, however specific.
+        let style = StyleComputer::new(&loaded.stylesheets).compute_style(paragraph, None);
+        assert_eq!(style.color, Color::rgb(0, 0, 255));
+    }
+}
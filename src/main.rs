@@ -1,61 +1,134 @@
 use std::time::Instant;
 
-use crate::arena_tree::ArenaTree;
-
-pub mod arena_tree;
-pub mod css;
-pub mod dom;
-pub mod html;
+#[cfg(feature = "paint")]
+use sputnik::paint;
+use sputnik::{css, html, layout};
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Usage: sputnik <path-to-html-file>");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let extract_text = args.iter().any(|arg| arg == "--extract-text");
+    let outline = args.iter().any(|arg| arg == "--outline");
+    let render = args
+        .iter()
+        .position(|arg| arg == "--render")
+        .map(|index| args.get(index + 1));
+    let Some(path) = args.iter().find(|arg| !arg.starts_with("--")) else {
+        eprintln!(
+            "Usage: sputnik <path-to-html-file> [--extract-text | --outline | --render <output.png>]"
+        );
         std::process::exit(1);
-    }
-    let path = args[1].clone();
+    };
 
     if path.ends_with(".html") {
-        parse_html_file(&path);
+        if let Some(output) = render {
+            let Some(output) = output else {
+                eprintln!("--render needs the path of the PNG file to write");
+                std::process::exit(1);
+            };
+            render_html_file(path, output);
+        } else if extract_text {
+            extract_text_from_html_file(path);
+        } else if outline {
+            print_outline_of_html_file(path);
+        } else {
+            parse_html_file(path);
+        }
         return;
     }
 
     if path.ends_with(".css") {
-        parse_css_file(&path);
+        parse_css_file(path);
     }
 }
 
 fn parse_html_file(path: &str) {
-    let arena = typed_arena::Arena::new();
-
-    let html = std::fs::read_to_string(path.clone()).unwrap();
-    let parser = html::parser::Parser::new(arena, html.as_str());
+    let html = std::fs::read_to_string(path).unwrap();
+    let parser = html::parser::Parser::new(typed_arena::Arena::new(), html.as_str());
 
     eprintln!("Started parsing '{}'", path);
     let before = Instant::now();
-    let document = parser.parse();
+    let loaded = sputnik::load_with_parser(&parser);
     let after = Instant::now();
     let time = after.duration_since(before);
     eprintln!("Finished parsing document! Took {:?}!", time);
     eprintln!();
 
     eprintln!("---- DOM Tree ----");
-    document.dump(Default::default());
+    loaded.document.dump(Default::default());
     eprintln!();
 
-    // eprintln!("---- Layout Tree ----");
-    // let layout_tree = layout::tree::LayoutTree::from(&document);
-    // layout_tree.dump(Default::default());
+    eprintln!("---- Layout Tree ----");
+    let layout_tree = layout::tree::LayoutTree::new(loaded.document, &loaded.stylesheets);
+    layout_tree.dump(Default::default());
+}
+
+fn extract_text_from_html_file(path: &str) {
+    let html = std::fs::read_to_string(path).unwrap();
+    let parser = html::parser::Parser::new(typed_arena::Arena::new(), html.as_str());
+    let loaded = sputnik::load_with_parser(&parser);
+
+    let layout_tree = layout::tree::LayoutTree::new(loaded.document, &loaded.stylesheets);
+    println!("{}", layout_tree.visible_text());
+}
+
+#[cfg(feature = "paint")]
+fn render_html_file(path: &str, output: &str) {
+    const VIEWPORT_WIDTH: usize = 800;
+    const VIEWPORT_HEIGHT: usize = 600;
+
+    let html = std::fs::read_to_string(path).unwrap();
+    let parser = html::parser::Parser::new(typed_arena::Arena::new(), html.as_str());
+    let loaded = sputnik::load_with_parser(&parser);
+
+    let mut layout_tree = layout::tree::LayoutTree::new(loaded.document, &loaded.stylesheets);
+    layout_tree.layout(VIEWPORT_WIDTH as f64);
+
+    let display_list = layout_tree.to_display_list();
+    let canvas = paint::Canvas::from_display_list(&display_list, VIEWPORT_WIDTH, VIEWPORT_HEIGHT);
+    std::fs::write(output, canvas.to_png()).unwrap();
+    eprintln!("Rendered '{}' to '{}'", path, output);
+}
+
+#[cfg(not(feature = "paint"))]
+fn render_html_file(_path: &str, _output: &str) {
+    eprintln!("Rendering needs sputnik to be built with the `paint` feature");
+    std::process::exit(1);
+}
+
+fn print_outline_of_html_file(path: &str) {
+    let arena = typed_arena::Arena::new();
+
+    let html = std::fs::read_to_string(path).unwrap();
+    let parser = html::parser::Parser::new(arena, html.as_str());
+    let document = parser.parse();
+
+    // NOTE: Headings are indented by how many enclosing headings they have, not by their level,
+    //       so an h3 directly below an h1 is only indented once.
+    let mut enclosing_levels: Vec<u8> = vec![];
+    for entry in document.outline() {
+        while enclosing_levels
+            .last()
+            .is_some_and(|level| *level >= entry.level)
+        {
+            enclosing_levels.pop();
+        }
+        println!(
+            "{}{} (h{})",
+            "  ".repeat(enclosing_levels.len()),
+            entry.text,
+            entry.level
+        );
+        enclosing_levels.push(entry.level);
+    }
 }
 
 fn parse_css_file(path: &str) {
-    let css = std::fs::read_to_string(path.clone()).unwrap();
-    let mut tokenizer = css::tokenizer::Tokenizer::new(css.as_str());
+    let css = std::fs::read_to_string(path).unwrap();
+    let tokenizer = css::tokenizer::Tokenizer::new(css.as_str());
 
     eprintln!("Started parsing '{}'", path);
     let before = Instant::now();
-    let tokens = tokenizer.tokenize();
-    let input = css::parser::token_streams::TokenStream::new(tokens);
+    let input = css::parser::token_streams::TokenStream::from_tokenizer(tokenizer);
     let parsed_stylesheet = css::parser::Parser::parse_a_stylesheet(&input, None);
     let after = Instant::now();
     let time = after.duration_since(before);
@@ -0,0 +1,276 @@
+use crate::css::color::Color;
+use crate::layout::{DisplayItem, Rect};
+
+mod png;
+
+/// The size of a glyph cell of the built-in block font, in pixels.
+const GLYPH_SIZE: usize = 8;
+
+/// An RGBA image that display lists are painted into.
+///
+/// Every pixel is one CSS pixel. Anything painted outside of the canvas is clipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    /// The pixels in rows from top to bottom, four bytes per pixel, with straight alpha.
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    /// Creates a canvas of `width` by `height` pixels, filled with `background`.
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        let pixels =
+            [background.r, background.g, background.b, background.a].repeat(width * height);
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Paints `display_list` on a canvas the size of the viewport, on top of the white canvas
+    /// background.
+    ///
+    /// https://www.w3.org/TR/CSS2/zindex.html#painting-order
+    pub fn from_display_list(display_list: &[DisplayItem], width: usize, height: usize) -> Self {
+        let mut canvas = Self::new(width, height, Color::rgb(255, 255, 255));
+        for item in display_list {
+            canvas.paint(item);
+        }
+        canvas
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The color of the pixel at `x`, `y`, or `None` if it is outside of the canvas.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Color> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let index = (y * self.width + x) * 4;
+        let [r, g, b, a] = self.pixels[index..index + 4] else {
+            unreachable!();
+        };
+        Some(Color::rgba(r, g, b, a))
+    }
+
+    /// Paints a single display item on top of what is already on the canvas.
+    pub fn paint(&mut self, item: &DisplayItem) {
+        match item {
+            DisplayItem::SolidRect { rect, color } => self.fill_rect(*rect, *color),
+            DisplayItem::Text { rect, text, color } => self.draw_text(*rect, text, *color),
+        }
+    }
+
+    /// Fills `rect` with `color`.
+    ///
+    /// NOTE: A pixel is covered if its center is inside of the rectangle, so rectangles that
+    ///       share an edge don't overlap or leave a gap.
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        let (left, right) = pixel_span(rect.x, rect.x + rect.width, self.width);
+        let (top, bottom) = pixel_span(rect.y, rect.y + rect.height, self.height);
+
+        for y in top..bottom {
+            for x in left..right {
+                self.blend_pixel(x, y, color);
+            }
+        }
+    }
+
+    /// Draws `text` with the built-in block font, starting at the top left of `rect`, wrapping
+    /// to the next line at the right edge of `rect`, and clipped to it.
+    ///
+    /// NOTE: Every character that isn't whitespace is drawn as a filled block, which is enough to
+    ///       see where text ends up without a font stack.
+    pub fn draw_text(&mut self, rect: Rect, text: &str, color: Color) {
+        let columns = ((rect.width / GLYPH_SIZE as f64).floor() as usize).max(1);
+        let rows = (rect.height / GLYPH_SIZE as f64).ceil() as usize;
+
+        let cells = text.chars().enumerate().filter(|(_, c)| !c.is_whitespace());
+        for (index, _) in cells {
+            let (row, column) = (index / columns, index % columns);
+            if row >= rows {
+                break;
+            }
+
+            // NOTE: Leave a pixel of space between glyphs, like a bitmap font would.
+            let glyph = Rect {
+                x: rect.x + (column * GLYPH_SIZE) as f64,
+                y: rect.y + (row * GLYPH_SIZE) as f64,
+                width: (GLYPH_SIZE - 1) as f64,
+                height: (GLYPH_SIZE - 1) as f64,
+            };
+            self.fill_rect(clip_rect(glyph, rect), color);
+        }
+    }
+
+    /// Encodes the canvas as a PNG image.
+    ///
+    /// https://www.w3.org/TR/png/
+    pub fn to_png(&self) -> Vec<u8> {
+        png::encode_rgba(self.width, self.height, &self.pixels)
+    }
+
+    /// Composites `color` over the pixel at `x`, `y`.
+    ///
+    /// https://drafts.fxtf.org/compositing-1/#porterduffcompositingoperators_srcover
+    fn blend_pixel(&mut self, x: usize, y: usize, color: Color) {
+        let index = (y * self.width + x) * 4;
+        let destination = &mut self.pixels[index..index + 4];
+
+        let source_alpha = color.a as f64 / 255.0;
+        let destination_alpha = destination[3] as f64 / 255.0;
+        // SPEC: αo = αs + αb x (1 - αs)
+        let alpha = source_alpha + destination_alpha * (1.0 - source_alpha);
+        if alpha == 0.0 {
+            return;
+        }
+
+        // SPEC: co = cs + cb x (1 - αs), with premultiplied colors.
+        let channel = |source: u8, destination: u8| {
+            let premultiplied = source as f64 * source_alpha
+                + destination as f64 * destination_alpha * (1.0 - source_alpha);
+            (premultiplied / alpha).round() as u8
+        };
+        destination[0] = channel(color.r, destination[0]);
+        destination[1] = channel(color.g, destination[1]);
+        destination[2] = channel(color.b, destination[2]);
+        destination[3] = (alpha * 255.0).round() as u8;
+    }
+}
+
+/// The range of pixels from `start` to `end`, rounded to pixel centers and clipped to a canvas
+/// that is `size` pixels long.
+fn pixel_span(start: f64, end: f64, size: usize) -> (usize, usize) {
+    let clamp = |edge: f64| edge.round().clamp(0.0, size as f64) as usize;
+    (clamp(start), clamp(end))
+}
+
+/// The part of `rect` that is inside of `clip`, which is empty if they don't overlap.
+fn clip_rect(rect: Rect, clip: Rect) -> Rect {
+    let left = rect.x.max(clip.x);
+    let top = rect.y.max(clip.y);
+    let right = (rect.x + rect.width).min(clip.x + clip.width);
+    let bottom = (rect.y + rect.height).min(clip.y + clip.height);
+    Rect {
+        x: left,
+        y: top,
+        width: (right - left).max(0.0),
+        height: (bottom - top).max(0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::parser::Parser;
+    use crate::layout::tree::LayoutTree;
+
+    const WHITE: Color = Color::rgb(255, 255, 255);
+    const RED: Color = Color::rgb(255, 0, 0);
+
+    /// Lays out and paints `input` on a canvas of `width` by `height` pixels.
+    fn render(input: &str, width: usize, height: usize) -> Canvas {
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let loaded = crate::load_with_parser(&parser);
+        let mut tree = LayoutTree::new(loaded.document, &loaded.stylesheets);
+        tree.layout(width as f64);
+        Canvas::from_display_list(&tree.to_display_list(), width, height)
+    }
+
+    #[test]
+    fn paint_a_colored_box() {
+        let canvas = render(
+            "<body style=\"margin: 0\">\
+             <div style=\"margin: 5px 0 0 10px; width: 20px; height: 10px; background-color: red\"></div>",
+            40,
+            20,
+        );
+
+        // The corners of the box.
+        for (x, y) in [(10, 5), (29, 5), (10, 14), (29, 14)] {
+            assert_eq!(canvas.pixel(x, y), Some(RED), "pixel {x}, {y}");
+        }
+        // The pixels just outside of the box.
+        for (x, y) in [(9, 5), (30, 5), (10, 4), (10, 15), (0, 0), (39, 19)] {
+            assert_eq!(canvas.pixel(x, y), Some(WHITE), "pixel {x}, {y}");
+        }
+        assert_eq!(canvas.pixel(40, 0), None);
+    }
+
+    #[test]
+    fn paint_text_as_blocks() {
+        let canvas = render(
+            "<body style=\"margin: 0; color: red; line-height: 8px; font-size: 16px\">a b</body>",
+            40,
+            20,
+        );
+
+        // NOTE: Every glyph is a 7x7 block with a pixel of space after it.
+        assert_eq!(canvas.pixel(0, 0), Some(RED));
+        assert_eq!(canvas.pixel(6, 6), Some(RED));
+        assert_eq!(canvas.pixel(7, 0), Some(WHITE));
+        assert_eq!(canvas.pixel(0, 7), Some(WHITE));
+        // The space.
+        assert_eq!(canvas.pixel(8, 0), Some(WHITE));
+        assert_eq!(canvas.pixel(16, 0), Some(RED));
+        assert_eq!(canvas.pixel(24, 0), Some(WHITE));
+    }
+
+    #[test]
+    fn later_items_are_painted_on_top() {
+        let mut canvas = Canvas::new(4, 1, WHITE);
+        let rect = |x: f64, width: f64| Rect {
+            x,
+            y: 0.0,
+            width,
+            height: 1.0,
+        };
+        canvas.fill_rect(rect(0.0, 3.0), RED);
+        canvas.fill_rect(rect(1.0, 3.0), Color::rgba(0, 0, 255, 128));
+
+        assert_eq!(canvas.pixel(0, 0), Some(RED));
+        assert_eq!(canvas.pixel(1, 0), Some(Color::rgb(127, 0, 128)));
+        assert_eq!(canvas.pixel(3, 0), Some(Color::rgb(127, 127, 255)));
+    }
+
+    #[test]
+    fn rectangles_cover_the_pixels_whose_centers_they_contain() {
+        let mut canvas = Canvas::new(4, 1, WHITE);
+        canvas.fill_rect(
+            Rect {
+                x: 0.6,
+                y: 0.0,
+                width: 1.8,
+                height: 1.0,
+            },
+            RED,
+        );
+
+        assert_eq!(canvas.pixel(0, 0), Some(WHITE));
+        assert_eq!(canvas.pixel(1, 0), Some(RED));
+        assert_eq!(canvas.pixel(2, 0), Some(WHITE));
+    }
+
+    #[test]
+    fn encode_as_png() {
+        let png = Canvas::new(3, 2, RED).to_png();
+
+        assert_eq!(
+            png[..8],
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']
+        );
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(png[16..20], 3u32.to_be_bytes());
+        assert_eq!(png[20..24], 2u32.to_be_bytes());
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+}
@@ -0,0 +1,109 @@
+//! A minimal PNG encoder for 8-bit RGBA images.
+//!
+//! NOTE: The image data is stored without compression, which keeps the encoder small at the cost
+//!       of larger files.
+//!
+//! https://www.w3.org/TR/png/
+
+/// https://www.w3.org/TR/png/#5PNG-file-signature
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// The largest amount of data a stored deflate block can hold.
+///
+/// https://www.rfc-editor.org/rfc/rfc1951#section-3.2.4
+const MAX_STORED_BLOCK_LENGTH: usize = 0xffff;
+
+/// Encodes `pixels`, `width` by `height` pixels of four bytes each, as a PNG image.
+pub(super) fn encode_rgba(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    assert_eq!(pixels.len(), width * height * 4, "pixels should be RGBA");
+
+    let mut png = SIGNATURE.to_vec();
+
+    // https://www.w3.org/TR/png/#11IHDR
+    let mut header = vec![];
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // NOTE: Bit depth 8, color type 6 (truecolor with alpha), deflate compression, adaptive
+    //       filtering and no interlacing.
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+    write_chunk(&mut png, b"IHDR", &header);
+
+    // https://www.w3.org/TR/png/#7Filtering
+    // NOTE: Every scanline starts with its filter type, which is always None (0) here.
+    let mut scanlines = Vec::with_capacity(height * (width * 4 + 1));
+    for row in pixels.chunks(width * 4).take(height) {
+        scanlines.push(0);
+        scanlines.extend_from_slice(row);
+    }
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+
+    // https://www.w3.org/TR/png/#11IEND
+    write_chunk(&mut png, b"IEND", &[]);
+
+    png
+}
+
+/// https://www.w3.org/TR/png/#5Chunk-layout
+fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+    // SPEC: The CRC is calculated on the preceding bytes in the chunk, including the chunk type
+    //       field and the chunk data fields, but not including the length field.
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream made of uncompressed deflate blocks.
+///
+/// https://www.rfc-editor.org/rfc/rfc1950
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // NOTE: Deflate with a 32K window, no preset dictionary and the fastest compression level.
+    //       The check bits make 0x7801 a multiple of 31.
+    let mut stream = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(MAX_STORED_BLOCK_LENGTH).peekable();
+    if blocks.peek().is_none() {
+        // NOTE: An empty stream still needs a final block.
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        let length = block.len() as u16;
+        stream.push(is_final as u8);
+        stream.extend_from_slice(&length.to_le_bytes());
+        stream.extend_from_slice(&(!length).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+/// https://www.w3.org/TR/png/#D-CRCAppendix
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffff_u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => 0xedb8_8320 ^ (crc >> 1),
+                _ => crc >> 1,
+            };
+        }
+    }
+    !crc
+}
+
+/// https://www.rfc-editor.org/rfc/rfc1950#section-8
+fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for byte in data {
+        a = (a + *byte as u32) % MOD_ADLER;
+        b = (b + a) % MOD_ADLER;
+    }
+    (b << 16) | a
+}
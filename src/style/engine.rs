@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::css::parser::types::StyleSheet;
+use crate::dom::node::Node;
+use crate::dom::NodeRef;
+
+use super::{ComputedStyle, StyleComputer};
+
+/// Keeps the computed styles of the elements of a document up to date as it changes.
+///
+/// Styles are cached between updates, and only elements that were marked as style-dirty (see
+/// [`crate::dom::Element::mark_style_dirty`]) are recomputed, together with their descendants,
+/// which inherit from them. Every other element shares its cached style.
+pub struct StyleEngine<'s> {
+    style_computer: StyleComputer<'s>,
+    /// The computed styles of the elements, by the address of their node.
+    ///
+    /// NOTE: Nodes live in an arena, so their addresses don't change while the document is alive.
+    ///       A new node that happens to reuse the address of an old one is style-dirty anyway.
+    styles: HashMap<*const (), Rc<ComputedStyle>>,
+    /// How many times the style of an element was computed, over all updates.
+    restyle_count: usize,
+}
+
+impl<'s> StyleEngine<'s> {
+    pub fn new(stylesheets: &'s [StyleSheet]) -> Self {
+        Self {
+            style_computer: StyleComputer::new(stylesheets),
+            styles: HashMap::new(),
+            restyle_count: 0,
+        }
+    }
+
+    /// Brings the computed styles of all elements in `document` up to date.
+    pub fn update(&mut self, document: NodeRef) {
+        // NOTE: We build a new cache, so the styles of elements that were removed are dropped.
+        let mut previous_styles = std::mem::take(&mut self.styles);
+        for child in document.child_nodes().iter() {
+            self.update_subtree(child, None, false, &mut previous_styles);
+        }
+    }
+
+    /// The computed style of `element` as of the last update, if it had one.
+    pub fn style(&self, element: NodeRef) -> Option<Rc<ComputedStyle>> {
+        self.styles.get(&key(element)).cloned()
+    }
+
+    /// How many times the style of an element was computed, over all updates so far.
+    pub fn restyle_count(&self) -> usize {
+        self.restyle_count
+    }
+
+    /// Updates the style of `node` and its descendants. `parent_restyled` is whether the style of
+    /// the parent element was recomputed in this update, which the style of `node` can depend on.
+    fn update_subtree(
+        &mut self,
+        node: NodeRef,
+        parent_style: Option<&Rc<ComputedStyle>>,
+        parent_restyled: bool,
+        previous_styles: &mut HashMap<*const (), Rc<ComputedStyle>>,
+    ) {
+        let Some(element) = node.as_element() else {
+            return;
+        };
+
+        let cached = previous_styles.remove(&key(node));
+        let (style, restyled) = match cached {
+            Some(style) if !parent_restyled && !element.is_style_dirty() => (style, false),
+            _ => {
+                let style = self
+                    .style_computer
+                    .compute_style(node, parent_style.map(Rc::as_ref));
+                self.restyle_count += 1;
+                element.clear_style_dirty();
+                (Rc::new(style), true)
+            }
+        };
+
+        for child in node.child_nodes().iter() {
+            self.update_subtree(child, Some(&style), restyled, previous_styles);
+        }
+        self.styles.insert(key(node), style);
+    }
+}
+
+fn key(node: NodeRef) -> *const () {
+    node as *const Node as *const ()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::parser::Parser;
+
+    #[test]
+    fn only_dirty_subtrees_are_restyled() {
+        let mut input = String::from("<style>.hot span { color: red }</style>");
+        for _ in 0..100 {
+            input.push_str("<div>");
+            input.push_str(&"<span>x</span>".repeat(99));
+            input.push_str("</div>");
+        }
+        let parser = Parser::new(typed_arena::Arena::new(), &input);
+        let document = parser.parse();
+        let stylesheets = document.stylesheets();
+        let divs: Vec<_> = document
+            .descendants()
+            .filter(|node| node.is_element_with_tag("div"))
+            .collect();
+        let element_count = document
+            .descendants()
+            .filter(|node| node.is_element())
+            .count();
+        assert!(element_count > 10_000);
+
+        let mut engine = StyleEngine::new(&stylesheets);
+        engine.update(&document);
+        assert_eq!(engine.restyle_count(), element_count);
+
+        engine.update(&document);
+        assert_eq!(engine.restyle_count(), element_count);
+
+        // Changing the class of a div restyles exactly that div and its 99 spans.
+        let hot_span = divs[10].first_child().unwrap();
+        let cold_span = divs[20].first_child().unwrap();
+        let cold_style = engine.style(cold_span).unwrap();
+        divs[10].as_element().unwrap().set_attribute("class", "hot");
+        engine.update(&document);
+        assert_eq!(engine.restyle_count(), element_count + 100);
+        assert_ne!(engine.style(hot_span).unwrap().color, cold_style.color);
+        assert!(Rc::ptr_eq(&engine.style(cold_span).unwrap(), &cold_style));
+
+        // Changing an attribute of a leaf restyles only that leaf.
+        cold_span.as_element().unwrap().set_attribute("title", "x");
+        engine.update(&document);
+        assert_eq!(engine.restyle_count(), element_count + 101);
+    }
+}
@@ -0,0 +1,639 @@
+use crate::css::border::{LineStyle, OutlineStyle};
+use crate::css::color::{Color, CssColorValue};
+use crate::css::display::{user_agent_display, Display, Visibility};
+use crate::css::float::{Clear, Float};
+use crate::css::font::{LineHeight, MEDIUM_FONT_SIZE};
+use crate::css::length::{LengthPercentage, LengthPercentageAuto, Sides};
+use crate::css::list::{user_agent_list_style_type_for_html_element, ListStyleType};
+use crate::css::origin::Origin;
+use crate::css::parser::types::{Declaration, Rule, StyleSheet};
+use crate::css::parser::Parser;
+use crate::css::position::Position;
+use crate::css::selectors::{SelectorList, Specificity};
+use crate::css::supports::SupportsCondition;
+use crate::css::text::WhiteSpace;
+use crate::dom::NodeRef;
+use crate::html::Namespace;
+
+pub use engine::StyleEngine;
+
+pub mod engine;
+pub mod properties;
+
+/// The computed values of the properties we support for a single element.
+///
+/// https://drafts.csswg.org/css-cascade-5/#computed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputedStyle {
+    pub display: Display,
+    /// https://drafts.csswg.org/css-display-3/#visibility
+    pub visibility: Visibility,
+    /// https://drafts.csswg.org/css-color-4/#the-color-property
+    pub color: Color,
+    /// The preferred font families, in order.
+    ///
+    /// https://drafts.csswg.org/css-fonts-4/#font-family-prop
+    pub font_family: Vec<String>,
+    /// The font size in CSS pixels.
+    ///
+    /// https://drafts.csswg.org/css-fonts-4/#font-size-prop
+    pub font_size: f64,
+    /// https://drafts.csswg.org/css-inline-3/#line-height-property
+    pub line_height: LineHeight,
+    /// https://drafts.csswg.org/css-text-3/#white-space-property
+    pub white_space: WhiteSpace,
+    /// https://www.w3.org/TR/CSS2/visuren.html#float-position
+    pub float: Float,
+    /// https://www.w3.org/TR/CSS2/visuren.html#flow-control
+    pub clear: Clear,
+    /// https://www.w3.org/TR/CSS2/visuren.html#choose-position
+    pub position: Position,
+    /// The `top`, `right`, `bottom` and `left` offsets of a positioned box.
+    ///
+    /// https://drafts.csswg.org/css-position-3/#insets
+    pub inset: Sides<LengthPercentageAuto>,
+    /// https://drafts.csswg.org/css-sizing-3/#preferred-size-properties
+    pub width: LengthPercentageAuto,
+    /// https://drafts.csswg.org/css-sizing-3/#preferred-size-properties
+    pub height: LengthPercentageAuto,
+    /// https://drafts.csswg.org/css-sizing-3/#min-size-properties
+    pub min_width: LengthPercentageAuto,
+    /// The maximum width, or `None` if it is `none`.
+    ///
+    /// https://drafts.csswg.org/css-sizing-3/#max-size-properties
+    pub max_width: Option<LengthPercentage>,
+    /// https://drafts.csswg.org/css-box-4/#margins
+    pub margin: Sides<LengthPercentageAuto>,
+    /// https://drafts.csswg.org/css-box-4/#paddings
+    pub padding: Sides<LengthPercentage>,
+    /// https://drafts.csswg.org/css-backgrounds-3/#background-color
+    pub background_color: CssColorValue,
+    /// The border widths in CSS pixels, as specified.
+    ///
+    /// NOTE: A side whose border style is `none` or `hidden` has no border, no matter its width.
+    ///       Use [`ComputedStyle::used_border_width`] for the widths that take up space.
+    ///
+    /// https://drafts.csswg.org/css-backgrounds-3/#border-width
+    pub border_width: Sides<f64>,
+    /// https://drafts.csswg.org/css-backgrounds-3/#border-style
+    pub border_style: Sides<LineStyle>,
+    /// https://drafts.csswg.org/css-backgrounds-3/#border-color
+    pub border_color: Sides<CssColorValue>,
+    /// The outline width in CSS pixels.
+    ///
+    /// https://drafts.csswg.org/css-ui-4/#outline-width
+    pub outline_width: f64,
+    /// https://drafts.csswg.org/css-ui-4/#outline-style
+    pub outline_style: OutlineStyle,
+    /// https://drafts.csswg.org/css-ui-4/#outline-color
+    pub outline_color: CssColorValue,
+    /// https://drafts.csswg.org/css-lists-3/#text-markers
+    pub list_style_type: ListStyleType,
+}
+
+impl ComputedStyle {
+    /// A style with the values of the inherited properties taken from `parent`, and the initial
+    /// values for all other properties.
+    ///
+    /// SPEC: If there is no cascaded value, the specified value of an inherited property is the
+    ///       inherited value, and of a non-inherited property is the initial value.
+    ///
+    /// https://drafts.csswg.org/css-cascade-5/#inheriting
+    pub fn inherit(parent: &ComputedStyle) -> Self {
+        let mut style = Self::default();
+        properties::inherit_from(&mut style, parent);
+        style
+    }
+
+    /// The border widths in CSS pixels that are actually drawn and laid out.
+    ///
+    /// SPEC: border-width computed value: absolute length, snapped as a border width;
+    ///       zero if the border style is none or hidden
+    pub fn used_border_width(&self) -> Sides<f64> {
+        let used = |width: f64, style: LineStyle| if style.hides_border() { 0.0 } else { width };
+        Sides {
+            top: used(self.border_width.top, self.border_style.top),
+            right: used(self.border_width.right, self.border_style.right),
+            bottom: used(self.border_width.bottom, self.border_style.bottom),
+            left: used(self.border_width.left, self.border_style.left),
+        }
+    }
+}
+
+impl Default for ComputedStyle {
+    fn default() -> Self {
+        Self {
+            display: Display::default(),
+            visibility: Visibility::Visible,
+            color: Color::BLACK,
+            font_family: vec!["serif".to_string()],
+            font_size: MEDIUM_FONT_SIZE,
+            line_height: LineHeight::Normal,
+            white_space: WhiteSpace::Normal,
+            float: Float::None,
+            clear: Clear::None,
+            position: Position::Static,
+            inset: Sides::all(LengthPercentageAuto::Auto),
+            width: LengthPercentageAuto::Auto,
+            height: LengthPercentageAuto::Auto,
+            min_width: LengthPercentageAuto::Auto,
+            max_width: None,
+            margin: Sides::all(LengthPercentageAuto::px(0.0)),
+            padding: Sides::all(LengthPercentage::Px(0.0)),
+            background_color: CssColorValue::Transparent,
+            // NOTE: The initial width is `medium`.
+            border_width: Sides::all(3.0),
+            border_style: Sides::all(LineStyle::None),
+            border_color: Sides::all(CssColorValue::CurrentColor),
+            outline_width: 3.0,
+            outline_style: OutlineStyle::default(),
+            // NOTE: The initial value is `auto`, which for anything but an `auto` outline style
+            //       means `currentcolor`.
+            outline_color: CssColorValue::CurrentColor,
+            list_style_type: ListStyleType::Disc,
+        }
+    }
+}
+
+/// Computes the styles of elements from the user agent defaults and a list of stylesheets.
+pub struct StyleComputer<'s> {
+    /// The style rules of all stylesheets, in order of appearance, with their selectors already parsed.
+    rules: Vec<(SelectorList, &'s [Declaration], Origin)>,
+}
+
+impl<'s> StyleComputer<'s> {
+    pub fn new(stylesheets: &'s [StyleSheet]) -> Self {
+        let mut rules = vec![];
+        for stylesheet in stylesheets {
+            collect_style_rules(&stylesheet.rules, &mut rules);
+        }
+
+        Self { rules }
+    }
+
+    /// Computes the style of `element`, inheriting from `parent_style`, the computed style of
+    /// its parent element, if it has one.
+    ///
+    /// NOTE: The declarations in the `style` attribute of `element` are part of the cascade too.
+    pub fn compute_style(
+        &self,
+        element: NodeRef,
+        parent_style: Option<&ComputedStyle>,
+    ) -> ComputedStyle {
+        // NOTE: Declarations override the inherited values as they are applied, so we start
+        //       from them.
+        let mut style = parent_style.map(ComputedStyle::inherit).unwrap_or_default();
+
+        // NOTE: The user agent stylesheet has the lowest precedence, so we start from its values.
+        if let Some(element) = element.as_element() {
+            style.display = user_agent_display(&element.local_name(), element.namespace());
+        }
+
+        let html_local_name = element
+            .as_element()
+            .filter(|element| element.namespace() == Namespace::Html)
+            .map(|element| element.local_name());
+        if let Some(local_name) = html_local_name {
+            // https://html.spec.whatwg.org/multipage/rendering.html#lists
+            // SPEC: dir, dd, menu, ol, ul { padding-inline-start: 40px; }
+            // NOTE: We only support horizontal writing modes, where the inline start is left.
+            if matches!(&*local_name, "dir" | "dd" | "menu" | "ol" | "ul") {
+                style.padding.left = LengthPercentage::Px(40.0);
+            }
+            if let Some(list_style_type) = user_agent_list_style_type_for_html_element(element) {
+                style.list_style_type = list_style_type;
+            }
+
+            // https://html.spec.whatwg.org/multipage/rendering.html#flow-content-3
+            // SPEC: listing, plaintext, pre, xmp {
+            //         display: block; font-family: monospace; white-space: pre; margin-block: 1em;
+            //       }
+            // https://html.spec.whatwg.org/multipage/rendering.html#the-textarea-element-2
+            // SPEC: textarea { white-space: pre-wrap; }
+            // https://html.spec.whatwg.org/multipage/rendering.html#phrasing-content-3
+            // SPEC: nobr { white-space: nowrap; }
+            match &*local_name {
+                "listing" | "plaintext" | "pre" | "xmp" => style.white_space = WhiteSpace::Pre,
+                "textarea" => style.white_space = WhiteSpace::PreWrap,
+                "nobr" => style.white_space = WhiteSpace::Nowrap,
+                _ => {}
+            }
+        }
+
+        // https://drafts.csswg.org/css-style-attr/#interpret
+        // FIXME: Only HTML, SVG and MathML elements have a style attribute.
+        let style_attribute = element
+            .as_element()
+            .and_then(|element| element.get_attribute("style"))
+            .map(|style| Parser::parse_a_list_of_declarations(style.as_str()))
+            .unwrap_or_default();
+
+        for declaration in self.cascaded_declarations(element, &style_attribute) {
+            apply_declaration(&mut style, declaration, parent_style);
+        }
+
+        style.line_height = style.line_height.to_computed(style.font_size);
+
+        // https://www.w3.org/TR/CSS2/visuren.html#dis-pos-flo
+        // SPEC: Otherwise, if 'position' has the value 'absolute' or 'fixed', the box is
+        //       absolutely positioned, the computed value of 'float' is 'none', and display is
+        //       set according to the table below.
+        if style.position == Position::Absolute {
+            style.float = Float::None;
+            style.display = style.display.blockified();
+        }
+        // SPEC: Otherwise, if 'float' has a value other than 'none', the box is floated and
+        //       'display' is set according to the table below.
+        if style.float != Float::None {
+            style.display = style.display.blockified();
+        }
+
+        style
+    }
+
+    /// All declarations from the stylesheets and the `style_attribute` of `element` that apply
+    /// to it, sorted from lowest to highest precedence.
+    ///
+    /// https://drafts.csswg.org/css-cascade-5/#cascade-sort
+    fn cascaded_declarations<'d>(
+        &'d self,
+        element: NodeRef,
+        style_attribute: &'d [Declaration],
+    ) -> Vec<&'d Declaration> {
+        let mut declarations: Vec<(u8, bool, Specificity, &Declaration)> = vec![];
+
+        for (selectors, rule_declarations, origin) in &self.rules {
+            // SPEC: The specificity of a selector list is the specificity of the most specific
+            //       selector in the list that matches the element.
+            let Some(specificity) = selectors
+                .selectors
+                .iter()
+                .filter(|selector| selector.matches(element))
+                .map(|selector| selector.specificity())
+                .max()
+            else {
+                continue;
+            };
+
+            for declaration in rule_declarations.iter() {
+                declarations.push((
+                    origin.precedence(declaration.important),
+                    false,
+                    specificity,
+                    declaration,
+                ));
+            }
+        }
+
+        // SPEC: Declarations from style attributes are author declarations that are attached to
+        //       the element, which don't have a selector.
+        for declaration in style_attribute {
+            declarations.push((
+                Origin::Author.precedence(declaration.important),
+                true,
+                (0, 0, 0),
+                declaration,
+            ));
+        }
+
+        // SPEC: Origin and importance, then whether the declaration is attached to the element,
+        //       then specificity, then order of appearance.
+        // NOTE: The sort is stable, so declarations keep their order of appearance otherwise.
+        declarations.sort_by_key(|(precedence, element_attached, specificity, _)| {
+            (*precedence, *element_attached, *specificity)
+        });
+        declarations
+            .into_iter()
+            .map(|(_, _, _, declaration)| declaration)
+            .collect()
+    }
+}
+
+/// Appends the style rules in `rules` to `style_rules`, including the ones nested in conditional
+/// group rules whose condition is true.
+fn collect_style_rules<'s>(
+    rules: &'s [Rule],
+    style_rules: &mut Vec<(SelectorList, &'s [Declaration], Origin)>,
+) {
+    for rule in rules {
+        match rule {
+            // SPEC: If the selector list of a style rule fails to parse, the whole rule is invalid.
+            Rule::QualifiedRule(rule) => {
+                if let Some(selectors) = SelectorList::parse(&rule.prelude) {
+                    style_rules.push((selectors, rule.declarations.as_slice(), rule.origin));
+                }
+            }
+            // https://drafts.csswg.org/css-conditional-3/#at-supports
+            // SPEC: If the <supports-condition> is true, the rules inside the @supports rule apply,
+            //       otherwise they are ignored. A prelude that doesn't parse makes the whole rule
+            //       invalid.
+            Rule::AtRule(rule) if rule.name.eq_ignore_ascii_case("supports") => {
+                if SupportsCondition::parse(&rule.prelude)
+                    .is_some_and(|condition| condition.evaluate(&supports_declaration))
+                {
+                    collect_style_rules(&rule.child_rules, style_rules);
+                }
+            }
+            // FIXME: Apply the rules inside other conditional group rules like @media.
+            Rule::AtRule(_) => {}
+        }
+    }
+}
+
+/// Whether we support the property and value of `declaration`, as `@supports` asks.
+///
+/// https://drafts.csswg.org/css-conditional-3/#dfn-support
+fn supports_declaration(declaration: &Declaration) -> bool {
+    let Some(property) = properties::lookup(&declaration.name) else {
+        return false;
+    };
+
+    properties::CssWideKeyword::parse(&declaration.value).is_some()
+        || (property.apply)(&mut ComputedStyle::default(), &declaration.value)
+}
+
+fn apply_declaration(
+    style: &mut ComputedStyle,
+    declaration: &Declaration,
+    parent_style: Option<&ComputedStyle>,
+) {
+    // NOTE: Unsupported properties and invalid values are ignored, so the previous value stays in effect.
+    let Some(property) = properties::lookup(&declaration.name) else {
+        return;
+    };
+
+    match properties::CssWideKeyword::parse(&declaration.value) {
+        Some(keyword) => property.apply_css_wide_keyword(style, keyword, parent_style),
+        None => {
+            (property.apply)(style, &declaration.value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::parser::Parser;
+
+    /// The computed display of the `p` element in `<style>{css}</style><p>`.
+    fn paragraph_display(css: &str) -> Display {
+        let input = format!("<style>{css}</style><p>x</p>");
+        let parser = Parser::new(typed_arena::Arena::new(), &input);
+        let document = parser.parse();
+        let stylesheets = document.stylesheets();
+        let paragraph = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("p"))
+            .unwrap();
+        let display = StyleComputer::new(&stylesheets)
+            .compute_style(paragraph, None)
+            .display;
+        display
+    }
+
+    #[test]
+    fn supports_rules_with_a_supported_condition_apply() {
+        assert_eq!(
+            paragraph_display("@supports (display: block) { p { display: none } }"),
+            Display::None
+        );
+        assert_eq!(
+            paragraph_display(
+                "@supports (color: red) and (not (float: sideways)) { p { display: none } }"
+            ),
+            Display::None
+        );
+    }
+
+    #[test]
+    fn supports_rules_with_an_unsupported_condition_are_ignored() {
+        let block = paragraph_display("p { display: block }");
+        assert_ne!(block, Display::None);
+        assert_eq!(
+            paragraph_display(
+                "p { display: block } @supports (display: bogus) { p { display: none } }"
+            ),
+            block
+        );
+        assert_eq!(
+            paragraph_display("p { display: block } @supports (bogus: 1) { p { display: none } }"),
+            block
+        );
+        assert_eq!(
+            paragraph_display("p { display: block } @supports bogus { p { display: none } }"),
+            block
+        );
+    }
+
+    #[test]
+    fn supports_rules_with_a_negated_unsupported_property_apply() {
+        assert_eq!(
+            paragraph_display("@supports not (bogus: 1) { p { display: none } }"),
+            Display::None
+        );
+    }
+
+    #[test]
+    fn current_color_inherits_through_the_cascade() {
+        let input =
+            "<style>div { color: red } p { color: currentColor } span { color: blue }</style>\
+                     <div><p><span>x</span></p></div>";
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let stylesheets = document.stylesheets();
+        let element = |tag: &str| {
+            document
+                .descendants()
+                .find(|node| node.is_element_with_tag(tag))
+                .unwrap()
+        };
+
+        let computer = StyleComputer::new(&stylesheets);
+        let div = computer.compute_style(element("div"), None);
+        let p = computer.compute_style(element("p"), Some(&div));
+        let span = computer.compute_style(element("span"), Some(&p));
+
+        // NOTE: color: currentColor is treated as inherit.
+        assert_eq!(p.color, Color::rgb(255, 0, 0));
+        assert_eq!(span.color, Color::rgb(0, 0, 255));
+
+        // NOTE: The initial border-color is currentColor, which uses the color of the element itself.
+        assert_eq!(
+            p.border_color.top.to_computed(p.color),
+            Color::rgb(255, 0, 0)
+        );
+        assert_eq!(
+            span.border_color.left.to_computed(span.color),
+            Color::rgb(0, 0, 255)
+        );
+    }
+
+    #[test]
+    fn inherited_properties_come_from_the_parent() {
+        let input = "<style>div { color: blue; font-size: 20px; font-family: monospace; \
+                     line-height: 150%; visibility: hidden; margin-left: 5px; display: inline } \
+                     span { font-size: 50% }</style><div><p><span>x</span></p></div>";
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let stylesheets = document.stylesheets();
+        let element = |tag: &str| {
+            document
+                .descendants()
+                .find(|node| node.is_element_with_tag(tag))
+                .unwrap()
+        };
+
+        let computer = StyleComputer::new(&stylesheets);
+        let div = computer.compute_style(element("div"), None);
+        let p = computer.compute_style(element("p"), Some(&div));
+        let span = computer.compute_style(element("span"), Some(&p));
+
+        assert_eq!(p.color, Color::rgb(0, 0, 255));
+        assert_eq!(p.font_size, 20.0);
+        assert_eq!(p.font_family, ["monospace"]);
+        assert_eq!(p.visibility, Visibility::Hidden);
+        // NOTE: A percentage line height computes to a length, and descendants inherit the length.
+        assert_eq!(p.line_height, LineHeight::Length(30.0));
+        assert_eq!(span.line_height, LineHeight::Length(30.0));
+        assert_eq!(span.font_size, 10.0);
+
+        // NOTE: Properties that are not inherited get their initial value.
+        assert_eq!(p.margin.left, ComputedStyle::default().margin.left);
+        assert_eq!(p.display, Display::Block);
+    }
+
+    #[test]
+    fn css_wide_keywords() {
+        let input = "<style>div { color: red; display: inline; margin-left: 5px } \
+                     p { color: blue; display: initial } p { color: inherit; margin-left: inherit } \
+                     span { display: block } span { color: unset; display: unset; font-size: initial }</style>\
+                     <div style=\"font-size: 20px\"><p><span>x</span></p></div>";
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let stylesheets = document.stylesheets();
+        let element = |tag: &str| {
+            document
+                .descendants()
+                .find(|node| node.is_element_with_tag(tag))
+                .unwrap()
+        };
+
+        let computer = StyleComputer::new(&stylesheets);
+        let div = computer.compute_style(element("div"), None);
+        let p = computer.compute_style(element("p"), Some(&div));
+        let span = computer.compute_style(element("span"), Some(&p));
+
+        assert_eq!(p.color, Color::rgb(255, 0, 0));
+        // NOTE: inherit also works for properties that are not inherited.
+        assert_eq!(p.margin.left, div.margin.left);
+        // NOTE: The initial value of display is inline, not the block of the user agent stylesheet.
+        assert_eq!(p.display, Display::Inline);
+
+        // NOTE: unset is inherit for inherited properties, and initial for the others.
+        assert_eq!(span.color, Color::rgb(255, 0, 0));
+        assert_eq!(span.display, Display::Inline);
+        assert_eq!(p.font_size, 20.0);
+        assert_eq!(span.font_size, MEDIUM_FONT_SIZE);
+    }
+
+    #[test]
+    fn merged_user_stylesheets_cascade_by_origin() {
+        let paragraph_color = |author_css: &str, user_css: &str| {
+            let stylesheet = |css: &str| {
+                let input = crate::css::parser::token_streams::TokenStream::from_tokenizer(
+                    crate::css::tokenizer::Tokenizer::new(css),
+                );
+                crate::css::parser::Parser::parse_a_stylesheet(&input, None)
+            };
+            let mut author = stylesheet(author_css);
+            let mut user = stylesheet(user_css);
+            user.set_origin(Origin::User);
+            author.merge(&user);
+
+            let parser = Parser::new(typed_arena::Arena::new(), "<p id=p class=c>x</p>");
+            let document = parser.parse();
+            let paragraph = document
+                .descendants()
+                .find(|node| node.is_element_with_tag("p"))
+                .unwrap();
+            let color = StyleComputer::new(&[author])
+                .compute_style(paragraph, None)
+                .color;
+            color
+        };
+        let (red, green) = (Color::rgb(255, 0, 0), Color::rgb(0, 128, 0));
+
+        // NOTE: Normal author declarations win over normal user declarations, whatever their specificity.
+        assert_eq!(
+            paragraph_color("p { color: red }", "#p { color: green }"),
+            red
+        );
+        assert_eq!(
+            paragraph_color("p { color: red }", "p { color: green !important }"),
+            green
+        );
+        assert_eq!(
+            paragraph_color(
+                "#p.c { color: red !important }",
+                "p { color: green !important }"
+            ),
+            green
+        );
+        assert_eq!(
+            paragraph_color("p { color: red !important }", "p { color: green }"),
+            red
+        );
+    }
+
+    #[test]
+    fn border_and_outline_longhands() {
+        let input = "<style>p { color: blue; border: 2px dashed; border-left: thick solid red; \
+                     border-bottom-color: green; border-top-style: double; \
+                     outline-width: 1px; outline-style: dotted }</style><p>x</p>";
+        let parser = Parser::new(typed_arena::Arena::new(), input);
+        let document = parser.parse();
+        let stylesheets = document.stylesheets();
+        let paragraph = document
+            .descendants()
+            .find(|node| node.is_element_with_tag("p"))
+            .unwrap();
+        let style = StyleComputer::new(&stylesheets).compute_style(paragraph, None);
+
+        assert_eq!(
+            style.border_style,
+            Sides {
+                top: LineStyle::Double,
+                right: LineStyle::Dashed,
+                bottom: LineStyle::Dashed,
+                left: LineStyle::Solid,
+            }
+        );
+        assert_eq!(style.border_width.left, 5.0);
+        assert_eq!(style.border_width.top, 2.0);
+
+        // NOTE: A border color that isn't given is currentColor, which resolves to the color property.
+        let resolved_colors = [
+            style.border_color.top,
+            style.border_color.right,
+            style.border_color.bottom,
+            style.border_color.left,
+        ]
+        .map(|color| color.to_computed(style.color));
+        assert_eq!(
+            resolved_colors,
+            [
+                Color::rgb(0, 0, 255),
+                Color::rgb(0, 0, 255),
+                Color::rgb(0, 128, 0),
+                Color::rgb(255, 0, 0),
+            ]
+        );
+
+        assert_eq!(style.outline_width, 1.0);
+        assert_eq!(
+            style.outline_style,
+            OutlineStyle::LineStyle(LineStyle::Dotted)
+        );
+        assert_eq!(style.outline_color, CssColorValue::CurrentColor);
+    }
+}
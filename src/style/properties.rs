@@ -0,0 +1,942 @@
+use crate::css::border::{BorderSide, LineStyle, OutlineStyle};
+use crate::css::color::CssColorValue;
+use crate::css::display::{Display, Visibility};
+use crate::css::float::{Clear, Float};
+use crate::css::font::{parse_font_family, parse_font_size, LineHeight};
+use crate::css::length::{
+    parse_line_width, parse_single_value, LengthPercentage, LengthPercentageAuto, Sides,
+};
+use crate::css::list::ListStyleType;
+use crate::css::parser::types::ComponentValue;
+use crate::css::parser::Parser;
+use crate::css::position::Position;
+use crate::css::text::WhiteSpace;
+use crate::css::tokenizer::Token;
+
+use super::ComputedStyle;
+
+/// A CSS property we support.
+///
+/// https://drafts.csswg.org/css-cascade-5/#css-property
+#[derive(Debug)]
+pub struct Property {
+    /// The name of the property, in lowercase.
+    pub name: &'static str,
+    /// https://drafts.csswg.org/css-cascade-5/#inherited-property
+    pub inherited: bool,
+    /// The initial value of the property, as CSS text.
+    ///
+    /// https://drafts.csswg.org/css-cascade-5/#initial-value
+    pub initial: &'static str,
+    /// Parses a value for this property, and sets it on the style if it is valid.
+    /// Returns whether the value was valid.
+    pub apply: fn(&mut ComputedStyle, &[ComponentValue]) -> bool,
+    /// Copies the computed value of this property from the second style to the first, like
+    /// inheriting it does.
+    pub copy: fn(&mut ComputedStyle, &ComputedStyle),
+    /// The longhands this property sets, if it is a shorthand.
+    ///
+    /// https://drafts.csswg.org/css-cascade-5/#shorthand-property
+    pub longhands: &'static [&'static str],
+}
+
+impl Property {
+    /// Whether this is a shorthand property.
+    pub fn is_shorthand(&self) -> bool {
+        !self.longhands.is_empty()
+    }
+
+    /// The initial value of this property, parsed into component values.
+    pub fn initial_value(&self) -> Vec<ComponentValue> {
+        Parser::parse_a_list_of_component_values(self.initial)
+    }
+}
+
+/// The keywords that every property accepts as its whole value.
+///
+/// https://drafts.csswg.org/css-values-4/#common-keywords
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssWideKeyword {
+    /// https://drafts.csswg.org/css-cascade-5/#initial
+    Initial,
+    /// https://drafts.csswg.org/css-cascade-5/#inherit
+    Inherit,
+    /// https://drafts.csswg.org/css-cascade-5/#inherit-initial
+    Unset,
+}
+
+impl CssWideKeyword {
+    /// Parses a declaration value that consists of only a CSS-wide keyword.
+    ///
+    /// FIXME: Support `revert` and `revert-layer` once we keep track of cascade origins per value.
+    pub fn parse(value: &[ComponentValue]) -> Option<Self> {
+        parse_single_value(value, |value| {
+            let ComponentValue::PreservedToken(Token::Ident { value }) = value else {
+                return None;
+            };
+
+            match value.to_ascii_lowercase().as_str() {
+                "initial" => Some(Self::Initial),
+                "inherit" => Some(Self::Inherit),
+                "unset" => Some(Self::Unset),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl Property {
+    /// Sets this property on `style` to the value that `keyword` stands for, where `parent` is the
+    /// computed style of the parent element, if there is one.
+    pub fn apply_css_wide_keyword(
+        &self,
+        style: &mut ComputedStyle,
+        keyword: CssWideKeyword,
+        parent: Option<&ComputedStyle>,
+    ) {
+        // SPEC: If a shorthand is specified as one of the CSS-wide keywords, it sets all of its
+        //       sub-properties to that keyword.
+        if self.is_shorthand() {
+            for longhand in self.longhands {
+                let longhand = lookup(longhand).expect("longhand should be in the property table");
+                longhand.apply_css_wide_keyword(style, keyword, parent);
+            }
+            return;
+        }
+
+        // SPEC: unset: If the cascaded value of a property is the unset keyword, then if it is an
+        //       inherited property, this is treated as inherit, and if it is not, this is treated
+        //       as initial.
+        let keyword = match keyword {
+            CssWideKeyword::Unset if self.inherited => CssWideKeyword::Inherit,
+            CssWideKeyword::Unset => CssWideKeyword::Initial,
+            keyword => keyword,
+        };
+
+        match (keyword, parent) {
+            // SPEC: inherit: If the cascaded value of a property is the inherit keyword, the
+            //       property's specified and computed values are the inherited value.
+            (CssWideKeyword::Inherit, Some(parent)) => (self.copy)(style, parent),
+            // SPEC: initial: If the cascaded value of a property is the initial keyword, the
+            //       property's specified value is its initial value.
+            // SPEC: For the root element, which has no parent element, the inherited value is the
+            //       initial value of the property.
+            _ => {
+                let valid = (self.apply)(style, &self.initial_value());
+                debug_assert!(valid, "initial value of {} should be valid", self.name);
+            }
+        }
+    }
+}
+
+/// Copies the computed values of all inherited properties from `parent` to `style`.
+///
+/// https://drafts.csswg.org/css-cascade-5/#inheriting
+pub fn inherit_from(style: &mut ComputedStyle, parent: &ComputedStyle) {
+    for property in PROPERTIES {
+        if property.inherited && !property.is_shorthand() {
+            (property.copy)(style, parent);
+        }
+    }
+}
+
+/// Looks up the property with `name`, which is matched ASCII case-insensitively.
+pub fn lookup(name: &str) -> Option<&'static Property> {
+    PROPERTIES
+        .iter()
+        .find(|property| property.name.eq_ignore_ascii_case(name))
+}
+
+macro_rules! properties {
+    // NOTE: A longhand names the field of ComputedStyle that holds its value, a shorthand copies
+    //       the values of its longhands.
+    (@copy $name:literal, $($field:ident).+) => {
+        |style, parent| style.$($field).+ = Clone::clone(&parent.$($field).+)
+    };
+    (@copy $name:literal) => {
+        |style, parent| copy_longhands($name, style, parent)
+    };
+
+    ($(
+        $name:literal {
+            inherited: $inherited:literal,
+            initial: $initial:literal,
+            $(field: $($field:ident).+,)?
+            apply: $apply:expr
+            $(, longhands: [$($longhand:literal),* $(,)?])?
+            $(,)?
+        }
+    )*) => {
+        /// Every property we support.
+        pub static PROPERTIES: &[Property] = &[$(
+            Property {
+                name: $name,
+                inherited: $inherited,
+                initial: $initial,
+                apply: $apply,
+                copy: properties!(@copy $name $(, $($field).+)?),
+                longhands: &[$($($longhand),*)?],
+            },
+        )*];
+    };
+}
+
+properties! {
+    // https://drafts.csswg.org/css-display-3/#the-display-properties
+    "display" {
+        inherited: false,
+        initial: "inline",
+        field: display,
+        apply: |style, value| set_if_valid(&mut style.display, Display::parse(value)),
+    }
+
+    // https://drafts.csswg.org/css-display-3/#visibility
+    "visibility" {
+        inherited: true,
+        initial: "visible",
+        field: visibility,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.visibility,
+                parse_single_value(value, Visibility::parse_component_value),
+            )
+        },
+    }
+
+    // https://drafts.csswg.org/css-color-4/#the-color-property
+    "color" {
+        inherited: true,
+        // NOTE: The initial value is CanvasText, which is black in our light color scheme.
+        initial: "black",
+        field: color,
+        apply: |style, value| {
+            // SPEC: If currentcolor is the specified value of the color property, it is treated
+            //       as if the specified value was inherit.
+            // NOTE: The style holds the inherited color until a color declaration applies.
+            let color = CssColorValue::parse(value).map(|color| color.to_computed(style.color));
+            set_if_valid(&mut style.color, color)
+        },
+    }
+
+    // https://drafts.csswg.org/css-fonts-4/#font-family-prop
+    "font-family" {
+        inherited: true,
+        initial: "serif",
+        field: font_family,
+        apply: |style, value| set_if_valid(&mut style.font_family, parse_font_family(value)),
+    }
+
+    // https://drafts.csswg.org/css-fonts-4/#font-size-prop
+    "font-size" {
+        inherited: true,
+        initial: "medium",
+        field: font_size,
+        apply: |style, value| {
+            // NOTE: The style holds the inherited font size until a font-size declaration
+            //       applies, which relative sizes are relative to.
+            // FIXME: Relative sizes compound if more than one font-size declaration applies.
+            let parent_font_size = style.font_size;
+            let font_size = parse_single_value(value, |value| {
+                parse_font_size(value, parent_font_size)
+            });
+            set_if_valid(&mut style.font_size, font_size)
+        },
+    }
+
+    // https://drafts.csswg.org/css-inline-3/#line-height-property
+    "line-height" {
+        inherited: true,
+        initial: "normal",
+        field: line_height,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.line_height,
+                parse_single_value(value, LineHeight::parse_component_value),
+            )
+        },
+    }
+
+    // https://drafts.csswg.org/css-text-3/#white-space-property
+    "white-space" {
+        inherited: true,
+        initial: "normal",
+        field: white_space,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.white_space,
+                parse_single_value(value, WhiteSpace::parse_component_value),
+            )
+        },
+    }
+
+    // https://www.w3.org/TR/CSS2/visuren.html#float-position
+    "float" {
+        inherited: false,
+        initial: "none",
+        field: float,
+        apply: |style, value| {
+            set_if_valid(&mut style.float, parse_single_value(value, Float::parse_component_value))
+        },
+    }
+
+    // https://www.w3.org/TR/CSS2/visuren.html#flow-control
+    "clear" {
+        inherited: false,
+        initial: "none",
+        field: clear,
+        apply: |style, value| {
+            set_if_valid(&mut style.clear, parse_single_value(value, Clear::parse_component_value))
+        },
+    }
+
+    // https://www.w3.org/TR/CSS2/visuren.html#choose-position
+    "position" {
+        inherited: false,
+        initial: "static",
+        field: position,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.position,
+                parse_single_value(value, Position::parse_component_value),
+            )
+        },
+    }
+
+    // https://drafts.csswg.org/css-position-3/#insets
+    "inset" {
+        inherited: false,
+        initial: "auto",
+        apply: |style, value| {
+            set_if_valid(&mut style.inset, Sides::parse_shorthand(value, inset))
+        },
+        longhands: ["top", "right", "bottom", "left"],
+    }
+    "top" {
+        inherited: false,
+        initial: "auto",
+        field: inset.top,
+        apply: |style, value| {
+            set_if_valid(&mut style.inset.top, parse_single_value(value, inset))
+        },
+    }
+    "right" {
+        inherited: false,
+        initial: "auto",
+        field: inset.right,
+        apply: |style, value| {
+            set_if_valid(&mut style.inset.right, parse_single_value(value, inset))
+        },
+    }
+    "bottom" {
+        inherited: false,
+        initial: "auto",
+        field: inset.bottom,
+        apply: |style, value| {
+            set_if_valid(&mut style.inset.bottom, parse_single_value(value, inset))
+        },
+    }
+    "left" {
+        inherited: false,
+        initial: "auto",
+        field: inset.left,
+        apply: |style, value| {
+            set_if_valid(&mut style.inset.left, parse_single_value(value, inset))
+        },
+    }
+
+    // https://drafts.csswg.org/css-sizing-3/#preferred-size-properties
+    "width" {
+        inherited: false,
+        initial: "auto",
+        field: width,
+        // SPEC: Negative values for width and height are invalid.
+        apply: |style, value| set_if_valid(&mut style.width, parse_single_value(value, size)),
+    }
+    "height" {
+        inherited: false,
+        initial: "auto",
+        field: height,
+        apply: |style, value| set_if_valid(&mut style.height, parse_single_value(value, size)),
+    }
+
+    // https://drafts.csswg.org/css-sizing-3/#min-size-properties
+    "min-width" {
+        inherited: false,
+        initial: "auto",
+        field: min_width,
+        apply: |style, value| set_if_valid(&mut style.min_width, parse_single_value(value, size)),
+    }
+
+    // https://drafts.csswg.org/css-sizing-3/#max-size-properties
+    "max-width" {
+        inherited: false,
+        initial: "none",
+        field: max_width,
+        apply: |style, value| {
+            set_if_valid(&mut style.max_width, parse_single_value(value, max_size))
+        },
+    }
+
+    // https://drafts.csswg.org/css-box-4/#margin-physical
+    "margin" {
+        inherited: false,
+        initial: "0",
+        apply: |style, value| {
+            set_if_valid(&mut style.margin, Sides::parse_shorthand(value, margin))
+        },
+        longhands: ["margin-top", "margin-right", "margin-bottom", "margin-left"],
+    }
+    "margin-top" {
+        inherited: false,
+        initial: "0",
+        field: margin.top,
+        apply: |style, value| {
+            set_if_valid(&mut style.margin.top, parse_single_value(value, margin))
+        },
+    }
+    "margin-right" {
+        inherited: false,
+        initial: "0",
+        field: margin.right,
+        apply: |style, value| {
+            set_if_valid(&mut style.margin.right, parse_single_value(value, margin))
+        },
+    }
+    "margin-bottom" {
+        inherited: false,
+        initial: "0",
+        field: margin.bottom,
+        apply: |style, value| {
+            set_if_valid(&mut style.margin.bottom, parse_single_value(value, margin))
+        },
+    }
+    "margin-left" {
+        inherited: false,
+        initial: "0",
+        field: margin.left,
+        apply: |style, value| {
+            set_if_valid(&mut style.margin.left, parse_single_value(value, margin))
+        },
+    }
+
+    // https://drafts.csswg.org/css-box-4/#padding-physical
+    "padding" {
+        inherited: false,
+        initial: "0",
+        apply: |style, value| {
+            set_if_valid(&mut style.padding, Sides::parse_shorthand(value, padding))
+        },
+        longhands: ["padding-top", "padding-right", "padding-bottom", "padding-left"],
+    }
+    "padding-top" {
+        inherited: false,
+        initial: "0",
+        field: padding.top,
+        apply: |style, value| {
+            set_if_valid(&mut style.padding.top, parse_single_value(value, padding))
+        },
+    }
+    "padding-right" {
+        inherited: false,
+        initial: "0",
+        field: padding.right,
+        apply: |style, value| {
+            set_if_valid(&mut style.padding.right, parse_single_value(value, padding))
+        },
+    }
+    "padding-bottom" {
+        inherited: false,
+        initial: "0",
+        field: padding.bottom,
+        apply: |style, value| {
+            set_if_valid(&mut style.padding.bottom, parse_single_value(value, padding))
+        },
+    }
+    "padding-left" {
+        inherited: false,
+        initial: "0",
+        field: padding.left,
+        apply: |style, value| {
+            set_if_valid(&mut style.padding.left, parse_single_value(value, padding))
+        },
+    }
+
+    // https://drafts.csswg.org/css-backgrounds-3/#background-color
+    "background-color" {
+        inherited: false,
+        initial: "transparent",
+        field: background_color,
+        apply: |style, value| {
+            set_if_valid(&mut style.background_color, parse_single_value(value, color))
+        },
+    }
+
+    // https://drafts.csswg.org/css-backgrounds-3/#border-width
+    "border-width" {
+        inherited: false,
+        initial: "medium",
+        apply: |style, value| {
+            set_if_valid(&mut style.border_width, Sides::parse_shorthand(value, parse_line_width))
+        },
+        longhands: [
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+    }
+    "border-top-width" {
+        inherited: false,
+        initial: "medium",
+        field: border_width.top,
+        apply: |style, value| {
+            set_if_valid(&mut style.border_width.top, parse_single_value(value, parse_line_width))
+        },
+    }
+    "border-right-width" {
+        inherited: false,
+        initial: "medium",
+        field: border_width.right,
+        apply: |style, value| {
+            set_if_valid(&mut style.border_width.right, parse_single_value(value, parse_line_width))
+        },
+    }
+    "border-bottom-width" {
+        inherited: false,
+        initial: "medium",
+        field: border_width.bottom,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.border_width.bottom,
+                parse_single_value(value, parse_line_width),
+            )
+        },
+    }
+    "border-left-width" {
+        inherited: false,
+        initial: "medium",
+        field: border_width.left,
+        apply: |style, value| {
+            set_if_valid(&mut style.border_width.left, parse_single_value(value, parse_line_width))
+        },
+    }
+
+    // https://drafts.csswg.org/css-backgrounds-3/#border-style
+    "border-style" {
+        inherited: false,
+        initial: "none",
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.border_style,
+                Sides::parse_shorthand(value, LineStyle::parse_component_value),
+            )
+        },
+        longhands: [
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+    }
+    "border-top-style" {
+        inherited: false,
+        initial: "none",
+        field: border_style.top,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.border_style.top,
+                parse_single_value(value, LineStyle::parse_component_value),
+            )
+        },
+    }
+    "border-right-style" {
+        inherited: false,
+        initial: "none",
+        field: border_style.right,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.border_style.right,
+                parse_single_value(value, LineStyle::parse_component_value),
+            )
+        },
+    }
+    "border-bottom-style" {
+        inherited: false,
+        initial: "none",
+        field: border_style.bottom,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.border_style.bottom,
+                parse_single_value(value, LineStyle::parse_component_value),
+            )
+        },
+    }
+    "border-left-style" {
+        inherited: false,
+        initial: "none",
+        field: border_style.left,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.border_style.left,
+                parse_single_value(value, LineStyle::parse_component_value),
+            )
+        },
+    }
+
+    // https://drafts.csswg.org/css-backgrounds-3/#border-color
+    "border-color" {
+        inherited: false,
+        initial: "currentcolor",
+        apply: |style, value| {
+            set_if_valid(&mut style.border_color, Sides::parse_shorthand(value, color))
+        },
+        longhands: [
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+    }
+    "border-top-color" {
+        inherited: false,
+        initial: "currentcolor",
+        field: border_color.top,
+        apply: |style, value| {
+            set_if_valid(&mut style.border_color.top, parse_single_value(value, color))
+        },
+    }
+    "border-right-color" {
+        inherited: false,
+        initial: "currentcolor",
+        field: border_color.right,
+        apply: |style, value| {
+            set_if_valid(&mut style.border_color.right, parse_single_value(value, color))
+        },
+    }
+    "border-bottom-color" {
+        inherited: false,
+        initial: "currentcolor",
+        field: border_color.bottom,
+        apply: |style, value| {
+            set_if_valid(&mut style.border_color.bottom, parse_single_value(value, color))
+        },
+    }
+    "border-left-color" {
+        inherited: false,
+        initial: "currentcolor",
+        field: border_color.left,
+        apply: |style, value| {
+            set_if_valid(&mut style.border_color.left, parse_single_value(value, color))
+        },
+    }
+
+    // https://drafts.csswg.org/css-backgrounds-3/#border-shorthands
+    "border" {
+        inherited: false,
+        initial: "medium none currentcolor",
+        apply: |style, value| {
+            let Some(border) = BorderSide::parse(value) else {
+                return false;
+            };
+            style.border_width = Sides::all(border.width);
+            style.border_style = Sides::all(border.style);
+            style.border_color = Sides::all(border.color);
+            true
+        },
+        longhands: [
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+    }
+    "border-top" {
+        inherited: false,
+        initial: "medium none currentcolor",
+        apply: |style, value| {
+            let Some(border) = BorderSide::parse(value) else {
+                return false;
+            };
+            style.border_width.top = border.width;
+            style.border_style.top = border.style;
+            style.border_color.top = border.color;
+            true
+        },
+        longhands: ["border-top-width", "border-top-style", "border-top-color"],
+    }
+    "border-right" {
+        inherited: false,
+        initial: "medium none currentcolor",
+        apply: |style, value| {
+            let Some(border) = BorderSide::parse(value) else {
+                return false;
+            };
+            style.border_width.right = border.width;
+            style.border_style.right = border.style;
+            style.border_color.right = border.color;
+            true
+        },
+        longhands: ["border-right-width", "border-right-style", "border-right-color"],
+    }
+    "border-bottom" {
+        inherited: false,
+        initial: "medium none currentcolor",
+        apply: |style, value| {
+            let Some(border) = BorderSide::parse(value) else {
+                return false;
+            };
+            style.border_width.bottom = border.width;
+            style.border_style.bottom = border.style;
+            style.border_color.bottom = border.color;
+            true
+        },
+        longhands: ["border-bottom-width", "border-bottom-style", "border-bottom-color"],
+    }
+    "border-left" {
+        inherited: false,
+        initial: "medium none currentcolor",
+        apply: |style, value| {
+            let Some(border) = BorderSide::parse(value) else {
+                return false;
+            };
+            style.border_width.left = border.width;
+            style.border_style.left = border.style;
+            style.border_color.left = border.color;
+            true
+        },
+        longhands: ["border-left-width", "border-left-style", "border-left-color"],
+    }
+
+    // https://drafts.csswg.org/css-ui-4/#outline-width
+    "outline-width" {
+        inherited: false,
+        initial: "medium",
+        field: outline_width,
+        apply: |style, value| {
+            set_if_valid(&mut style.outline_width, parse_single_value(value, parse_line_width))
+        },
+    }
+
+    // https://drafts.csswg.org/css-ui-4/#outline-style
+    "outline-style" {
+        inherited: false,
+        initial: "none",
+        field: outline_style,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.outline_style,
+                parse_single_value(value, OutlineStyle::parse_component_value),
+            )
+        },
+    }
+
+    // https://drafts.csswg.org/css-ui-4/#outline-color
+    "outline-color" {
+        inherited: false,
+        initial: "auto",
+        field: outline_color,
+        apply: |style, value| {
+            set_if_valid(&mut style.outline_color, parse_single_value(value, outline_color))
+        },
+    }
+
+    // https://drafts.csswg.org/css-lists-3/#text-markers
+    "list-style-type" {
+        inherited: true,
+        initial: "disc",
+        field: list_style_type,
+        apply: |style, value| {
+            set_if_valid(
+                &mut style.list_style_type,
+                parse_single_value(value, ListStyleType::parse_component_value),
+            )
+        },
+    }
+}
+
+fn size(value: &ComponentValue) -> Option<LengthPercentageAuto> {
+    LengthPercentageAuto::parse_component_value(value, false)
+}
+
+fn max_size(value: &ComponentValue) -> Option<Option<LengthPercentage>> {
+    match value {
+        ComponentValue::PreservedToken(Token::Ident { value })
+            if value.eq_ignore_ascii_case("none") =>
+        {
+            Some(None)
+        }
+        // SPEC: Negative values are invalid.
+        value => LengthPercentage::parse_component_value(value, false).map(Some),
+    }
+}
+
+fn inset(value: &ComponentValue) -> Option<LengthPercentageAuto> {
+    LengthPercentageAuto::parse_component_value(value, true)
+}
+
+fn margin(value: &ComponentValue) -> Option<LengthPercentageAuto> {
+    LengthPercentageAuto::parse_component_value(value, true)
+}
+
+fn padding(value: &ComponentValue) -> Option<LengthPercentage> {
+    // SPEC: Negative values for padding properties are invalid.
+    LengthPercentage::parse_component_value(value, false)
+}
+
+fn color(value: &ComponentValue) -> Option<CssColorValue> {
+    CssColorValue::parse(std::slice::from_ref(value))
+}
+
+fn outline_color(value: &ComponentValue) -> Option<CssColorValue> {
+    // NOTE: For the `auto` outline style, `auto` lets us pick any color. For every other style it
+    //       means `currentcolor`, so we always use that.
+    match value {
+        ComponentValue::PreservedToken(Token::Ident { value })
+            if value.eq_ignore_ascii_case("auto") =>
+        {
+            Some(CssColorValue::CurrentColor)
+        }
+        value => color(value),
+    }
+}
+
+fn copy_longhands(shorthand: &str, style: &mut ComputedStyle, parent: &ComputedStyle) {
+    let shorthand = lookup(shorthand).expect("shorthand should be in the property table");
+    for longhand in shorthand.longhands {
+        let longhand = lookup(longhand).expect("longhand should be in the property table");
+        (longhand.copy)(style, parent);
+    }
+}
+
+fn set_if_valid<T>(property: &mut T, value: Option<T>) -> bool {
+    match value {
+        Some(value) => {
+            *property = value;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initial_values_parse_with_their_own_parser() {
+        for property in PROPERTIES {
+            let mut style = ComputedStyle::default();
+            assert!(
+                (property.apply)(&mut style, &property.initial_value()),
+                "the initial value of {} should be valid",
+                property.name
+            );
+            // NOTE: The default style holds the initial value of every property.
+            assert_eq!(
+                style,
+                ComputedStyle::default(),
+                "the initial value of {} should be the default",
+                property.name
+            );
+        }
+    }
+
+    #[test]
+    fn property_table_is_consistent() {
+        for (index, property) in PROPERTIES.iter().enumerate() {
+            assert_eq!(property.name, property.name.to_ascii_lowercase());
+            assert!(
+                PROPERTIES[..index]
+                    .iter()
+                    .all(|other| other.name != property.name),
+                "{} is in the table more than once",
+                property.name
+            );
+            for longhand in property.longhands {
+                let longhand = lookup(longhand)
+                    .unwrap_or_else(|| panic!("{longhand} of {} is missing", property.name));
+                assert!(!longhand.is_shorthand());
+                assert_eq!(longhand.inherited, property.inherited, "{}", longhand.name);
+            }
+        }
+        assert!(std::ptr::eq(
+            lookup("COLOR").unwrap(),
+            lookup("color").unwrap()
+        ));
+        assert!(lookup("not-a-property").is_none());
+    }
+
+    #[test]
+    fn only_inherited_properties_are_inherited() {
+        let parent = {
+            let mut parent = ComputedStyle::default();
+            for (name, value) in [
+                ("color", "red"),
+                ("display", "block"),
+                ("margin-left", "5px"),
+            ] {
+                let property = lookup(name).unwrap();
+                assert!((property.apply)(
+                    &mut parent,
+                    &Parser::parse_a_list_of_component_values(value)
+                ));
+            }
+            parent
+        };
+
+        let mut style = ComputedStyle::default();
+        inherit_from(&mut style, &parent);
+        assert_eq!(style.color, parent.color);
+        assert_eq!(style.display, ComputedStyle::default().display);
+        assert_eq!(style.margin, ComputedStyle::default().margin);
+    }
+
+    #[test]
+    fn border_shorthands_set_their_longhands() {
+        let apply = |style: &mut ComputedStyle, name: &str, value: &str| {
+            let property = lookup(name).unwrap();
+            (property.apply)(style, &Parser::parse_a_list_of_component_values(value))
+        };
+        let red = CssColorValue::Absolute(crate::css::color::Color::rgb(255, 0, 0));
+
+        assert_eq!(lookup("border").unwrap().longhands.len(), 12);
+        // NOTE: The components can be given in any order.
+        for value in ["1px solid red", "red 1px solid", "solid red 1px"] {
+            let mut style = ComputedStyle::default();
+            assert!(apply(&mut style, "border", value), "{value:?}");
+            assert_eq!(style.border_width, Sides::all(1.0));
+            assert_eq!(style.border_style, Sides::all(LineStyle::Solid));
+            assert_eq!(style.border_color, Sides::all(red));
+        }
+
+        let mut style = ComputedStyle {
+            border_color: Sides::all(red),
+            ..Default::default()
+        };
+        assert!(apply(&mut style, "border-left", "thick dashed"));
+        assert_eq!(style.border_width.left, 5.0);
+        assert_eq!(style.border_style.left, LineStyle::Dashed);
+        assert_eq!(style.border_color.left, CssColorValue::CurrentColor);
+        assert_eq!(style.border_color.top, red);
+
+        for value in [
+            "",
+            "1px 2px",
+            "solid dashed",
+            "red blue",
+            "1px solid red auto",
+        ] {
+            let mut style = ComputedStyle::default();
+            assert!(!apply(&mut style, "border", value), "{value:?}");
+            assert_eq!(style, ComputedStyle::default());
+        }
+    }
+}
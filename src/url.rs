@@ -0,0 +1,318 @@
+//! Just enough URL handling to resolve the URLs found in documents.
+//!
+//! FIXME: This follows the reference resolution of RFC 3986 instead of the URL Standard's basic
+//!        URL parser, so it doesn't percent-encode, validate hosts or know about special schemes
+//!        beyond treating an empty path as `/`.
+//!
+//! https://url.spec.whatwg.org/
+//! https://datatracker.ietf.org/doc/html/rfc3986
+
+/// The parts of a URL reference.
+///
+/// https://datatracker.ietf.org/doc/html/rfc3986#section-3
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Components<'s> {
+    scheme: Option<&'s str>,
+    authority: Option<&'s str>,
+    path: &'s str,
+    query: Option<&'s str>,
+    fragment: Option<&'s str>,
+}
+
+impl<'s> Components<'s> {
+    // https://datatracker.ietf.org/doc/html/rfc3986#appendix-B
+    fn parse(reference: &'s str) -> Self {
+        let (rest, fragment) = match reference.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (reference, None),
+        };
+        let (rest, query) = match rest.split_once('?') {
+            Some((rest, query)) => (rest, Some(query)),
+            None => (rest, None),
+        };
+        let (scheme, rest) = match rest.split_once(':') {
+            Some((scheme, rest)) if is_scheme(scheme) => (Some(scheme), rest),
+            _ => (None, rest),
+        };
+        let (authority, path) = match rest.strip_prefix("//") {
+            Some(rest) => match rest.find('/') {
+                Some(index) => (Some(&rest[..index]), &rest[index..]),
+                None => (Some(rest), ""),
+            },
+            None => (None, rest),
+        };
+
+        Self {
+            scheme,
+            authority,
+            path,
+            query,
+            fragment,
+        }
+    }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc3986#section-3.1
+// scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+fn is_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Removes what the URL parser ignores before it starts: leading and trailing C0 controls and
+/// spaces, and tabs and newlines anywhere in the input.
+///
+/// https://url.spec.whatwg.org/#concept-basic-url-parser
+fn strip_ignored_code_points(input: &str) -> String {
+    input
+        .trim_matches(|c: char| c <= '\u{0020}')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect()
+}
+
+/// Returns the scheme of `url` in ASCII lowercase, or `None` if it is a relative reference.
+///
+/// The input is cleaned up the way the URL parser does first, so `" java\tscript:"` has the
+/// `javascript` scheme.
+pub fn scheme(url: &str) -> Option<String> {
+    let url = strip_ignored_code_points(url);
+    Components::parse(&url)
+        .scheme
+        .map(|scheme| scheme.to_ascii_lowercase())
+}
+
+/// Resolves `reference` against the absolute URL `base`.
+///
+/// Returns `None` if `base` is not an absolute URL.
+///
+/// https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.2
+pub fn resolve(base: &str, reference: &str) -> Option<String> {
+    let base = strip_ignored_code_points(base);
+    let reference = strip_ignored_code_points(reference);
+    let base = Components::parse(&base);
+    let reference = Components::parse(&reference);
+
+    let base_scheme = base.scheme?;
+
+    let merged_path;
+    let (scheme, authority, path, query) = if reference.scheme.is_some() {
+        (
+            reference.scheme,
+            reference.authority,
+            remove_dot_segments(reference.path),
+            reference.query,
+        )
+    } else if reference.authority.is_some() {
+        (
+            Some(base_scheme),
+            reference.authority,
+            remove_dot_segments(reference.path),
+            reference.query,
+        )
+    } else if reference.path.is_empty() {
+        (
+            Some(base_scheme),
+            base.authority,
+            base.path.to_string(),
+            reference.query.or(base.query),
+        )
+    } else if reference.path.starts_with('/') {
+        (
+            Some(base_scheme),
+            base.authority,
+            remove_dot_segments(reference.path),
+            reference.query,
+        )
+    } else {
+        merged_path = merge(&base, reference.path);
+        (
+            Some(base_scheme),
+            base.authority,
+            remove_dot_segments(&merged_path),
+            reference.query,
+        )
+    };
+
+    // https://datatracker.ietf.org/doc/html/rfc3986#section-5.3
+    let mut result = String::new();
+    if let Some(scheme) = scheme {
+        result.push_str(&scheme.to_ascii_lowercase());
+        result.push(':');
+    }
+    if let Some(authority) = authority {
+        result.push_str("//");
+        result.push_str(authority);
+        // NOTE: URLs with an authority always have a path in the URL Standard.
+        if path.is_empty() {
+            result.push('/');
+        }
+    }
+    result.push_str(&path);
+    if let Some(query) = query {
+        result.push('?');
+        result.push_str(query);
+    }
+    if let Some(fragment) = reference.fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+    Some(result)
+}
+
+// https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.3
+fn merge(base: &Components, reference_path: &str) -> String {
+    // SPEC: If the base URI has a defined authority component and an empty path, then return a
+    //       string consisting of "/" concatenated with the reference's path;
+    if base.authority.is_some() && base.path.is_empty() {
+        return format!("/{}", reference_path);
+    }
+
+    // SPEC: otherwise, return a string consisting of the reference's path component appended to
+    //       all but the last segment of the base URI's path (i.e., excluding any characters after
+    //       the right-most "/" in the base URI path, or excluding the entire base URI path if it
+    //       does not contain any "/" characters).
+    match base.path.rfind('/') {
+        Some(index) => format!("{}{}", &base.path[..=index], reference_path),
+        None => reference_path.to_string(),
+    }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.4
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path;
+    let mut output = String::new();
+
+    while !input.is_empty() {
+        // A. If the input buffer begins with a prefix of "../" or "./", then remove that prefix
+        //    from the input buffer; otherwise,
+        if let Some(rest) = input
+            .strip_prefix("../")
+            .or_else(|| input.strip_prefix("./"))
+        {
+            input = rest;
+        }
+        // B. if the input buffer begins with a prefix of "/./" or "/.", where "." is a complete
+        //    path segment, then replace that prefix with "/" in the input buffer; otherwise,
+        else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        }
+        // C. if the input buffer begins with a prefix of "/../" or "/..", where ".." is a complete
+        //    path segment, then replace that prefix with "/" in the input buffer and remove the
+        //    last segment and its preceding "/" (if any) from the output buffer; otherwise,
+        else if input.starts_with("/../") || input == "/.." {
+            input = if input == "/.." { "/" } else { &input[3..] };
+            output.truncate(output.rfind('/').unwrap_or(0));
+        }
+        // D. if the input buffer consists only of "." or "..", then remove that from the input
+        //    buffer; otherwise,
+        else if input == "." || input == ".." {
+            input = "";
+        }
+        // E. move the first path segment in the input buffer to the end of the output buffer,
+        //    including the initial "/" character (if any) and any subsequent characters up to,
+        //    but not including, the next "/" character or the end of the input buffer.
+        else {
+            let start = usize::from(input.starts_with('/'));
+            let segment_end = input[start..]
+                .find('/')
+                .map_or(input.len(), |index| index + start);
+            output.push_str(&input[..segment_end]);
+            input = &input[segment_end..];
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "http://a/b/c/d;p?q";
+
+    // https://datatracker.ietf.org/doc/html/rfc3986#section-5.4.1
+    #[test]
+    fn normal_examples() {
+        for (reference, expected) in [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            // NOTE: Like the URL Standard, an empty path becomes "/".
+            ("//g", "http://g/"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("g;x", "http://a/b/c/g;x"),
+            ("g;x?y#s", "http://a/b/c/g;x?y#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+        ] {
+            assert_eq!(
+                resolve(BASE, reference).as_deref(),
+                Some(expected),
+                "{reference:?}"
+            );
+        }
+    }
+
+    // https://datatracker.ietf.org/doc/html/rfc3986#section-5.4.2
+    #[test]
+    fn abnormal_examples() {
+        for (reference, expected) in [
+            ("../../../g", "http://a/g"),
+            ("../../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            (".g", "http://a/b/c/.g"),
+            ("g..", "http://a/b/c/g.."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g#s/../x", "http://a/b/c/g#s/../x"),
+            ("http:g", "http:g"),
+        ] {
+            assert_eq!(
+                resolve(BASE, reference).as_deref(),
+                Some(expected),
+                "{reference:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ignored_code_points_and_relative_bases() {
+        assert_eq!(
+            resolve("https://example.com", " \tpage\n.html ").as_deref(),
+            Some("https://example.com/page.html")
+        );
+        assert_eq!(resolve("relative/base", "g"), None);
+        assert_eq!(
+            scheme(" java\tscript:alert(1)").as_deref(),
+            Some("javascript")
+        );
+        assert_eq!(scheme("HTTPS://a").as_deref(), Some("https"));
+        assert_eq!(scheme("/path:with-colon"), None);
+    }
+}
@@ -0,0 +1,585 @@
+//! Deterministic HTML and CSS inputs for benchmarks, fuzzing seeds and stress tests.
+//!
+//! Every generator takes a seed, so the same input can be reproduced anywhere without committing
+//! large fixtures. The output only depends on the arguments, never on the platform or the time.
+
+use std::fmt::Write;
+
+/// A small, fast pseudo-random number generator (SplitMix64).
+///
+/// NOTE: This is not cryptographically secure, it only has to be deterministic and spread its
+///       output well enough to give the generated documents some variety.
+///
+/// https://prng.di.unimi.it/splitmix64.c
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`. `bound` must not be zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// A number from `low` to `high`, including both.
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        low + self.below(high - low + 1)
+    }
+
+    /// `true` with a probability of `percent` percent.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+}
+
+/// The kind of markup a generated document mostly consists of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Paragraphs of prose with some inline formatting.
+    TextHeavy,
+    /// Elements with many attributes, in every quoting style.
+    AttributeHeavy,
+    /// Text full of named and numeric character references, valid and invalid.
+    EntityHeavy,
+    /// Deeply nested and misnested elements.
+    DeeplyNested,
+    /// Tables, with and without their optional tags.
+    TableHeavy,
+}
+
+impl Profile {
+    pub const ALL: [Profile; 5] = [
+        Profile::TextHeavy,
+        Profile::AttributeHeavy,
+        Profile::EntityHeavy,
+        Profile::DeeplyNested,
+        Profile::TableHeavy,
+    ];
+
+    /// The name of the profile, like `text-heavy`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Profile::TextHeavy => "text-heavy",
+            Profile::AttributeHeavy => "attribute-heavy",
+            Profile::EntityHeavy => "entity-heavy",
+            Profile::DeeplyNested => "deeply-nested",
+            Profile::TableHeavy => "table-heavy",
+        }
+    }
+
+    /// Looks up a profile by its name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|profile| profile.name() == name)
+    }
+}
+
+const WORDS: &[&str] = &[
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "ex",
+    "ea",
+    "commodo",
+    "consequat",
+    "Sputnik",
+    "orbit",
+    "päivää",
+    "наука",
+    "宇宙",
+];
+
+const INLINE_TAGS: &[&str] = &["a", "b", "i", "em", "strong", "span", "code", "small", "u"];
+
+const BLOCK_TAGS: &[&str] = &[
+    "div",
+    "p",
+    "section",
+    "article",
+    "aside",
+    "blockquote",
+    "ul",
+    "li",
+    "h2",
+    "pre",
+];
+
+const ATTRIBUTE_NAMES: &[&str] = &[
+    "id",
+    "class",
+    "title",
+    "href",
+    "src",
+    "alt",
+    "style",
+    "lang",
+    "dir",
+    "hidden",
+    "role",
+    "tabindex",
+    "aria-label",
+    "aria-hidden",
+    "onclick",
+    "xlink:href",
+    "xml:lang",
+];
+
+const NAMED_REFERENCES: &[&str] = &[
+    "&amp;",
+    "&lt;",
+    "&gt;",
+    "&quot;",
+    "&apos;",
+    "&nbsp;",
+    "&copy;",
+    "&reg;",
+    "&mdash;",
+    "&hellip;",
+    "&euro;",
+    "&notin;",
+    "&NotEqualTilde;",
+    "&acE;",
+    "&ThickSpace;",
+    "&fjlig;",
+    // NOTE: References without a semicolon, that are a prefix of a longer name, or that don't
+    //       exist at all take the error paths of the tokenizer.
+    "&amp",
+    "&copy",
+    "&notit;",
+    "&ampx",
+    "&nosuchentity;",
+    "&",
+    "&;",
+];
+
+const NUMERIC_REFERENCES: &[&str] = &[
+    "&#65;",
+    "&#x41;",
+    "&#X1F600;",
+    "&#128512;",
+    "&#x20AC;",
+    "&#0;",
+    "&#x80;",
+    "&#xD800;",
+    "&#x110000;",
+    "&#9999999999;",
+    "&#;",
+    "&#x;",
+    "&#65",
+    "&#x3c",
+];
+
+/// Generates an HTML document of roughly `size` bytes in the style of `profile`.
+///
+/// The document always starts with a doctype and ends with the closing `body` and `html` tags,
+/// so its actual length can be a bit larger than `size`.
+pub fn generate_document(seed: u64, size: usize, profile: Profile) -> String {
+    let mut rng = Rng::new(seed);
+    let mut document = String::with_capacity(size + 256);
+
+    document.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    let _ = writeln!(
+        document,
+        "<title>{} corpus {}</title>",
+        profile.name(),
+        seed
+    );
+    document.push_str("<meta charset=\"utf-8\">\n</head>\n<body>\n");
+
+    while document.len() < size {
+        match profile {
+            Profile::TextHeavy => push_text_block(&mut rng, &mut document),
+            Profile::AttributeHeavy => push_attribute_block(&mut rng, &mut document),
+            Profile::EntityHeavy => push_entity_block(&mut rng, &mut document),
+            Profile::DeeplyNested => push_nested_block(&mut rng, &mut document),
+            Profile::TableHeavy => push_table_block(&mut rng, &mut document),
+        }
+
+        if rng.chance(10) {
+            push_comment(&mut rng, &mut document);
+        }
+        document.push('\n');
+    }
+
+    document.push_str("</body>\n</html>\n");
+    document
+}
+
+/// Generates a stylesheet of roughly `size` bytes, with a mix of style rules, at-rules and
+/// comments.
+pub fn generate_stylesheet(seed: u64, size: usize) -> String {
+    const PROPERTIES: &[(&str, &[&str])] = &[
+        (
+            "color",
+            &[
+                "red",
+                "#0d6efd",
+                "rgb(0 128 0 / 50%)",
+                "currentcolor",
+                "inherit",
+            ],
+        ),
+        (
+            "display",
+            &["block", "inline", "none", "inline-block", "list-item"],
+        ),
+        ("margin", &["0", "1px 2px", "auto", "1em 0 2em", "-5%"]),
+        ("padding", &["0", ".5rem", "10px 20px 30px 40px"]),
+        ("border", &["1px solid red", "thick double", "none"]),
+        (
+            "font-family",
+            &["serif", "\"Open Sans\", Arial, sans-serif", "'a\\'b'"],
+        ),
+        ("width", &["100%", "calc(100% - 2em)", "auto", "12.5e1px"]),
+        (
+            "background",
+            &["url(image.png)", "url( \"a b.png\" )", "none"],
+        ),
+        ("--custom", &["{ a: b }", "[1, 2]", "  spaced  "]),
+    ];
+    const SELECTORS: &[&str] = &[
+        "div",
+        "p > a",
+        ".btn.primary",
+        "#main",
+        "ul li + li",
+        "a:hover",
+        "input[type=\"text\"]",
+        "h1 ~ p",
+        "*",
+        ":root",
+        "[data-x|=en]",
+        "a::before",
+        "table td:nth-child(2n+1)",
+    ];
+
+    let mut rng = Rng::new(seed);
+    let mut stylesheet = String::with_capacity(size + 128);
+
+    while stylesheet.len() < size {
+        match rng.below(10) {
+            0 => {
+                let _ = writeln!(stylesheet, "/* {} */", rng.pick(WORDS));
+            }
+            1 => {
+                let _ = write!(
+                    stylesheet,
+                    "@media (min-width: {}px) {{ ",
+                    rng.between(320, 1440)
+                );
+                push_style_rule(&mut rng, &mut stylesheet, SELECTORS, PROPERTIES);
+                stylesheet.push_str("}\n");
+            }
+            2 => {
+                let _ = writeln!(stylesheet, "@import url(\"{}.css\");", rng.pick(WORDS));
+            }
+            _ => push_style_rule(&mut rng, &mut stylesheet, SELECTORS, PROPERTIES),
+        }
+    }
+
+    stylesheet
+}
+
+/// Every prefix of `document` that ends on a character boundary, from the empty string up to and
+/// including the whole document.
+///
+/// Cutting a document off at every point exercises the end-of-file handling of every state the
+/// tokenizer and tree builder can be in.
+pub fn all_prefixes(document: &str) -> impl Iterator<Item = &str> {
+    document
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(document.len()))
+        .map(move |end| &document[..end])
+}
+
+fn push_words(rng: &mut Rng, output: &mut String, count: usize) {
+    for index in 0..count {
+        if index > 0 {
+            output.push(if rng.chance(5) { '\n' } else { ' ' });
+        }
+        output.push_str(rng.pick(WORDS));
+    }
+}
+
+fn push_text_block(rng: &mut Rng, output: &mut String) {
+    let tag = rng.pick(&["p", "p", "p", "blockquote", "pre", "h2"]);
+    let _ = write!(output, "<{tag}>");
+    for _ in 0..rng.between(2, 8) {
+        let word_count = rng.between(3, 20);
+        push_words(rng, output, word_count);
+        output.push(' ');
+        if rng.chance(40) {
+            let inline = rng.pick(INLINE_TAGS);
+            match inline {
+                "a" => {
+                    let _ = write!(output, "<a href=\"/{}\">", rng.pick(WORDS));
+                }
+                _ => {
+                    let _ = write!(output, "<{inline}>");
+                }
+            }
+            let word_count = rng.between(1, 4);
+            push_words(rng, output, word_count);
+            let _ = write!(output, "</{inline}> ");
+        }
+        if rng.chance(10) {
+            output.push_str("<br>");
+        }
+    }
+    // NOTE: Paragraphs can be implicitly closed by the next block.
+    if tag != "p" || rng.chance(70) {
+        let _ = write!(output, "</{tag}>");
+    }
+}
+
+fn push_attribute_name(rng: &mut Rng, output: &mut String) {
+    if rng.chance(60) {
+        output.push_str(rng.pick(ATTRIBUTE_NAMES));
+        return;
+    }
+
+    // NOTE: Random but seeded names, sometimes with uppercase letters that have to be lowercased.
+    output.push_str(if rng.chance(50) { "data-" } else { "x-" });
+    for _ in 0..rng.between(1, 12) {
+        let letter = (b'a' + rng.below(26) as u8) as char;
+        output.push(if rng.chance(10) {
+            letter.to_ascii_uppercase()
+        } else {
+            letter
+        });
+    }
+}
+
+fn push_attribute_value(rng: &mut Rng, output: &mut String) {
+    let word_count = rng.between(1, 3);
+    let mut value = String::new();
+    push_words(rng, &mut value, word_count);
+    if rng.chance(20) {
+        value.push_str(rng.pick(NAMED_REFERENCES));
+    }
+
+    match rng.below(4) {
+        0 => {
+            let _ = write!(output, "=\"{value}\"");
+        }
+        1 => {
+            let _ = write!(output, "='{}'", value.replace('\'', "&apos;"));
+        }
+        // NOTE: Unquoted values end at whitespace.
+        2 => {
+            let _ = write!(output, "={}", value.replace(char::is_whitespace, "-"));
+        }
+        _ => {
+            let _ = write!(output, " = \"{value}\"");
+        }
+    }
+}
+
+fn push_attribute_block(rng: &mut Rng, output: &mut String) {
+    let tag = rng.pick(&["div", "a", "img", "input", "span", "svg", "button"]);
+    let _ = write!(output, "<{tag}");
+    for _ in 0..rng.between(1, 12) {
+        output.push(if rng.chance(10) { '\n' } else { ' ' });
+        push_attribute_name(rng, output);
+        // NOTE: Attributes without a value have the empty string as their value.
+        if rng.chance(85) {
+            push_attribute_value(rng, output);
+        }
+    }
+
+    match tag {
+        "img" | "input" => output.push_str(if rng.chance(50) { " />" } else { ">" }),
+        _ => {
+            output.push('>');
+            let word_count = rng.between(0, 5);
+            push_words(rng, output, word_count);
+            let _ = write!(output, "</{tag}>");
+        }
+    }
+}
+
+fn push_entity_block(rng: &mut Rng, output: &mut String) {
+    output.push_str("<p>");
+    for _ in 0..rng.between(5, 30) {
+        match rng.below(3) {
+            0 => output.push_str(rng.pick(NAMED_REFERENCES)),
+            1 => output.push_str(rng.pick(NUMERIC_REFERENCES)),
+            _ => push_words(rng, output, 1),
+        }
+        if rng.chance(50) {
+            output.push(' ');
+        }
+    }
+    output.push_str("</p>");
+
+    // NOTE: Character references in attribute values are handled by their own states.
+    if rng.chance(30) {
+        let _ = write!(
+            output,
+            "<a href=\"?a=1{}b=2\" title='{}'>link</a>",
+            rng.pick(NAMED_REFERENCES),
+            rng.pick(NUMERIC_REFERENCES)
+        );
+    }
+}
+
+fn push_nested_block(rng: &mut Rng, output: &mut String) {
+    let depth = rng.between(10, 200);
+    let mut open = Vec::with_capacity(depth);
+    for _ in 0..depth {
+        let tag = if rng.chance(30) {
+            rng.pick(INLINE_TAGS)
+        } else {
+            rng.pick(BLOCK_TAGS)
+        };
+        let _ = write!(output, "<{tag}>");
+        open.push(tag);
+        if rng.chance(10) {
+            push_words(rng, output, 1);
+        }
+    }
+
+    push_words(rng, output, 3);
+
+    // NOTE: Closing some elements out of order runs the adoption agency algorithm, and leaving
+    //       some open lets the parser close them implicitly.
+    while let Some(tag) = open.pop() {
+        if rng.chance(5) && !open.is_empty() {
+            let misnested = open.swap_remove(rng.below(open.len()));
+            let _ = write!(output, "</{misnested}>");
+        }
+        if rng.chance(90) {
+            let _ = write!(output, "</{tag}>");
+        }
+    }
+}
+
+fn push_table_block(rng: &mut Rng, output: &mut String) {
+    output.push_str("<table>");
+    if rng.chance(50) {
+        let _ = write!(output, "<caption>{}</caption>", rng.pick(WORDS));
+    }
+
+    let columns = rng.between(1, 8);
+    if rng.chance(50) {
+        output.push_str("<thead><tr>");
+        for _ in 0..columns {
+            let _ = write!(output, "<th>{}", rng.pick(WORDS));
+        }
+        output.push_str("</thead>");
+    }
+
+    // NOTE: The tbody, tr and td end tags are optional, so we leave them out now and then.
+    let explicit_tbody = rng.chance(50);
+    if explicit_tbody {
+        output.push_str("<tbody>");
+    }
+    for _ in 0..rng.between(1, 20) {
+        output.push_str("<tr>");
+        for _ in 0..columns {
+            output.push_str("<td>");
+            let word_count = rng.between(0, 4);
+            push_words(rng, output, word_count);
+            if rng.chance(60) {
+                output.push_str("</td>");
+            }
+        }
+        if rng.chance(60) {
+            output.push_str("</tr>");
+        }
+        // NOTE: Text directly in a table is foster parented.
+        if rng.chance(3) {
+            push_words(rng, output, 2);
+        }
+    }
+    if explicit_tbody && rng.chance(50) {
+        output.push_str("</tbody>");
+    }
+
+    output.push_str("</table>");
+}
+
+fn push_comment(rng: &mut Rng, output: &mut String) {
+    let comment = rng.pick(&[
+        "<!-- comment -->",
+        "<!---->",
+        "<!-->",
+        "<!--->",
+        "<!-- -- -->",
+        "<!--!>",
+        "<!-- a --!>",
+        "<? processing instruction ?>",
+        "<!bogus comment>",
+    ]);
+    output.push_str(comment);
+}
+
+fn push_style_rule(
+    rng: &mut Rng,
+    output: &mut String,
+    selectors: &[&str],
+    properties: &[(&str, &[&str])],
+) {
+    for index in 0..rng.between(1, 3) {
+        if index > 0 {
+            output.push_str(", ");
+        }
+        output.push_str(rng.pick(selectors));
+    }
+
+    output.push_str(" {");
+    for _ in 0..rng.between(1, 6) {
+        let (property, values) = rng.pick(properties);
+        let _ = write!(output, " {}: {}", property, rng.pick(values));
+        if rng.chance(10) {
+            output.push_str(" !important");
+        }
+        output.push(';');
+    }
+    output.push_str(" }\n");
+}
@@ -0,0 +1,37 @@
+//! Golden tests for `sputnik <page.html> --outline`.
+//!
+//! Every `.html` file in `tests/fixtures/outline` is run through the CLI and its output is
+//! compared with the `.txt` file next to it.
+
+use std::path::Path;
+use std::process::Command;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/outline");
+
+fn outline(path: &Path) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_sputnik"))
+        .arg(path)
+        .arg("--outline")
+        .output()
+        .expect("sputnik should run");
+    assert!(output.status.success(), "sputnik failed on {path:?}");
+    String::from_utf8(output.stdout).expect("the outline should be UTF-8")
+}
+
+#[test]
+fn outlines_match_the_golden_files() {
+    let mut fixtures = 0;
+    for entry in std::fs::read_dir(FIXTURES).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_none_or(|extension| extension != "html") {
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(path.with_extension("txt")).unwrap_or_else(|_| {
+            panic!("{path:?} should have a .txt file with the expected outline")
+        });
+        assert_eq!(outline(&path), expected, "outline of {path:?}");
+        fixtures += 1;
+    }
+    assert!(fixtures > 0, "no fixtures found in {FIXTURES}");
+}